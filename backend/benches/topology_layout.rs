@@ -0,0 +1,227 @@
+//! Topology build times on synthetic networks.
+//!
+//! `full_layout` builds from scratch, as on create or reset. `rebuild_skipping_optimizer` adds
+//! one host to an already laid out network, as on a rebuild after discovery finds something
+//! new, so the previous layout is reused instead of running the optimizer.
+//!
+//! Run with `cargo bench --bench topology_layout`.
+
+use std::{
+    hint::black_box,
+    net::{IpAddr, Ipv4Addr},
+};
+
+use cidr::{IpCidr, Ipv4Cidr};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use scanopy::server::{
+    bindings::r#impl::base::Binding,
+    groups::r#impl::{
+        base::{Group, GroupBase},
+        types::GroupType,
+    },
+    hosts::r#impl::base::{Host, HostBase},
+    interfaces::r#impl::base::{Interface, InterfaceBase},
+    services::{
+        definitions::ServiceDefinitionRegistry,
+        r#impl::{
+            base::{Service, ServiceBase},
+            definitions::ServiceDefinition,
+        },
+    },
+    shared::{
+        storage::traits::StorableEntity,
+        types::{Color, entities::EntitySource},
+    },
+    subnets::r#impl::{
+        base::{Subnet, SubnetBase},
+        types::SubnetType,
+    },
+    topology::{
+        service::main::{BuildGraphParams, TopologyService},
+        types::{
+            base::TopologyOptions,
+            edges::{Edge, EdgeStyle},
+            nodes::Node,
+        },
+    },
+};
+use uuid::Uuid;
+
+const HOSTS_PER_SUBNET: usize = 50;
+const SERVICES_PER_HOST: usize = 2;
+/// One request path group for every this many hosts, chaining services across subnets
+const HOSTS_PER_GROUP: usize = 20;
+const NETWORK_SIZES: [usize; 3] = [500, 2000, 5000];
+
+struct SyntheticNetwork {
+    network_id: Uuid,
+    options: TopologyOptions,
+    hosts: Vec<Host>,
+    interfaces: Vec<Interface>,
+    subnets: Vec<Subnet>,
+    services: Vec<Service>,
+    groups: Vec<Group>,
+    definitions: Vec<Box<dyn ServiceDefinition>>,
+}
+
+impl SyntheticNetwork {
+    fn new(host_count: usize) -> Self {
+        let mut network = Self {
+            network_id: Uuid::new_v4(),
+            options: TopologyOptions::default(),
+            hosts: Vec::new(),
+            interfaces: Vec::new(),
+            subnets: Vec::new(),
+            services: Vec::new(),
+            groups: Vec::new(),
+            definitions: ServiceDefinitionRegistry::all_service_definitions(),
+        };
+
+        let subnet_count = host_count.div_ceil(HOSTS_PER_SUBNET);
+        for i in 0..subnet_count {
+            network.subnets.push(Subnet::new(SubnetBase {
+                name: format!("Subnet {}", i),
+                description: None,
+                network_id: network.network_id,
+                cidr: IpCidr::V4(Ipv4Cidr::new(Self::subnet_address(i, 0), 24).unwrap()),
+                subnet_type: SubnetType::Lan,
+                source: EntitySource::System,
+                tags: Vec::new(),
+            }));
+        }
+
+        for i in 0..host_count {
+            network.add_host(i % subnet_count);
+        }
+
+        // Chain one service from hosts in consecutive subnets, so groups cross subnets
+        for i in 0..host_count / HOSTS_PER_GROUP {
+            let binding_ids = (0..3)
+                .filter_map(|hop| {
+                    let host = (i + hop * HOSTS_PER_SUBNET) % host_count;
+                    network.services[host * SERVICES_PER_HOST]
+                        .base
+                        .bindings
+                        .first()
+                        .map(|b| b.id())
+                })
+                .collect();
+
+            network.groups.push(Group::new(GroupBase {
+                name: format!("Path {}", i),
+                description: None,
+                network_id: network.network_id,
+                color: Color::default(),
+                group_type: GroupType::RequestPath,
+                binding_ids,
+                source: EntitySource::System,
+                edge_style: EdgeStyle::Bezier,
+                tags: Vec::new(),
+            }));
+        }
+
+        network
+    }
+
+    fn subnet_address(subnet: usize, host: usize) -> Ipv4Addr {
+        Ipv4Addr::new(10, (subnet / 256) as u8, (subnet % 256) as u8, host as u8)
+    }
+
+    fn add_host(&mut self, subnet_index: usize) {
+        let subnet_id = self.subnets[subnet_index].id;
+        let position = self.hosts.len() / self.subnets.len() + 1;
+
+        let host = Host::new(HostBase {
+            name: format!("host-{}", self.hosts.len()),
+            hostname: None,
+            network_id: self.network_id,
+            description: None,
+            source: EntitySource::System,
+            virtualization: None,
+            hidden: false,
+            tags: Vec::new(),
+        });
+
+        let interface = Interface::new(InterfaceBase {
+            network_id: self.network_id,
+            subnet_id,
+            ip_address: IpAddr::V4(Self::subnet_address(subnet_index, position)),
+            mac_address: None,
+            position: 0,
+            name: Some("eth0".to_string()),
+            host_id: host.id,
+        });
+
+        for position in 0..SERVICES_PER_HOST {
+            let definition = &self.definitions[self.services.len() % self.definitions.len()];
+            self.services.push(Service::new(ServiceBase {
+                name: format!("{} service {}", host.base.name, position),
+                host_id: host.id,
+                bindings: vec![Binding::new_interface_serviceless(interface.id)],
+                network_id: self.network_id,
+                service_definition: definition.clone(),
+                virtualization: None,
+                source: EntitySource::System,
+                tags: Vec::new(),
+                position: position as i32,
+            }));
+        }
+
+        self.hosts.push(host);
+        self.interfaces.push(interface);
+    }
+
+    fn build(&self, old_nodes: &[Node], old_edges: &[Edge]) -> (Vec<Node>, Vec<Edge>) {
+        TopologyService::build_graph(BuildGraphParams {
+            options: &self.options,
+            hosts: &self.hosts,
+            interfaces: &self.interfaces,
+            subnets: &self.subnets,
+            services: &self.services,
+            groups: &self.groups,
+            ports: &[],
+            bindings: &[],
+            traceroutes: &[],
+            old_nodes,
+            old_edges,
+        })
+    }
+}
+
+fn full_layout(c: &mut Criterion) {
+    let mut group = c.benchmark_group("full_layout");
+    group.sample_size(10);
+
+    for host_count in NETWORK_SIZES {
+        let network = SyntheticNetwork::new(host_count);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(host_count),
+            &network,
+            |b, network| b.iter(|| black_box(network.build(&[], &[]))),
+        );
+    }
+
+    group.finish();
+}
+
+fn rebuild_skipping_optimizer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rebuild_skipping_optimizer");
+    group.sample_size(10);
+
+    for host_count in NETWORK_SIZES {
+        let mut network = SyntheticNetwork::new(host_count);
+        let (old_nodes, old_edges) = network.build(&[], &[]);
+        network.add_host(0);
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(host_count),
+            &network,
+            |b, network| b.iter(|| black_box(network.build(&old_nodes, &old_edges))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, full_layout, rebuild_skipping_optimizer);
+criterion_main!(benches);
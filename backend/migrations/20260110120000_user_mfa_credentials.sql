@@ -0,0 +1,18 @@
+-- Multi-factor authentication credentials
+-- One row per TOTP authenticator, passkey, or unused recovery code
+
+CREATE TABLE user_mfa_credentials (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    credential_type VARCHAR(50) NOT NULL,
+    name TEXT NOT NULL,
+    data TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ
+);
+
+-- Index for loading all credentials for a user at login
+CREATE INDEX idx_user_mfa_credentials_user ON user_mfa_credentials(user_id, credential_type);
+
+-- Organization-wide MFA policy
+ALTER TABLE organizations ADD COLUMN require_mfa BOOLEAN NOT NULL DEFAULT FALSE;
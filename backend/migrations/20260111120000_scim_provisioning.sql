@@ -0,0 +1,28 @@
+-- SCIM 2.0 provisioning
+-- Organization-scoped bearer tokens and IdP-pushed groups mapped onto roles and network access
+
+CREATE TABLE scim_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    key TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used TIMESTAMPTZ
+);
+
+CREATE INDEX idx_scim_tokens_organization ON scim_tokens(organization_id);
+
+CREATE TABLE scim_groups (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    display_name TEXT NOT NULL,
+    external_id TEXT,
+    permissions VARCHAR(50),
+    network_ids UUID[] NOT NULL DEFAULT '{}',
+    member_ids UUID[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (organization_id, display_name)
+);
+
+-- Deactivated users keep their account but can't sign in
+ALTER TABLE users ADD COLUMN deactivated_at TIMESTAMPTZ;
@@ -0,0 +1,7 @@
+-- Fine-grained user API keys
+-- Scopes restrict a key to specific resource:action grants, allowed_ips to specific client CIDRs.
+-- Empty arrays keep existing keys unrestricted.
+
+ALTER TABLE user_api_keys ADD COLUMN scopes TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE user_api_keys ADD COLUMN allowed_ips TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE user_api_keys ADD COLUMN rate_limit_per_minute INTEGER;
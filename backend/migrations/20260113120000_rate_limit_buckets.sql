@@ -0,0 +1,12 @@
+-- Shared rate limit counters, used when rate_limits.store = "postgres" so that
+-- every server replica enforces the same per-minute budgets.
+-- Unlogged: counters are short-lived and don't need to survive a crash.
+
+CREATE UNLOGGED TABLE IF NOT EXISTS rate_limit_buckets (
+    key TEXT NOT NULL,
+    window_start TIMESTAMPTZ NOT NULL,
+    count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (key, window_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_rate_limit_buckets_window_start ON rate_limit_buckets (window_start);
@@ -0,0 +1,15 @@
+-- Signed-in browser sessions
+-- One row per login, so users can see where they're signed in and revoke sessions.
+-- The session cookie store keeps the session data; deleting a row here revokes it.
+
+CREATE TABLE user_sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    ip_address INET NOT NULL,
+    user_agent TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_activity_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_user_sessions_user ON user_sessions(user_id);
+CREATE INDEX idx_user_sessions_last_activity ON user_sessions(last_activity_at);
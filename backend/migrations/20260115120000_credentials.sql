@@ -0,0 +1,18 @@
+-- Credentials used by discovery, such as SNMP communities, API tokens and SSH keys
+-- Secrets are encrypted by the server before they're stored and are never returned by the API.
+
+CREATE TABLE credentials (
+    id UUID PRIMARY KEY,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    credential_type TEXT NOT NULL,
+    encrypted_secret TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_credentials_network ON credentials(network_id);
+
+-- Credentials a discovery hands to its daemon when a session starts
+ALTER TABLE discovery ADD COLUMN credential_ids UUID[] NOT NULL DEFAULT '{}';
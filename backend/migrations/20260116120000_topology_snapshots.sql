@@ -0,0 +1,33 @@
+-- Immutable copies of a topology's graph and entities at a point in time
+-- Taken manually, after discovery sessions complete, or on the server's snapshot schedule.
+
+CREATE TABLE topology_snapshots (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    topology_id UUID NOT NULL REFERENCES topologies(id) ON DELETE CASCADE,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    trigger TEXT NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    options JSONB NOT NULL,
+    nodes JSONB NOT NULL,
+    edges JSONB NOT NULL,
+    hosts JSONB NOT NULL,
+    interfaces JSONB NOT NULL,
+    ports JSONB NOT NULL,
+    bindings JSONB NOT NULL,
+    subnets JSONB NOT NULL,
+    services JSONB NOT NULL,
+    groups JSONB NOT NULL,
+    removed_hosts UUID[] NOT NULL DEFAULT '{}',
+    removed_interfaces UUID[] NOT NULL DEFAULT '{}',
+    removed_ports UUID[] NOT NULL DEFAULT '{}',
+    removed_bindings UUID[] NOT NULL DEFAULT '{}',
+    removed_subnets UUID[] NOT NULL DEFAULT '{}',
+    removed_services UUID[] NOT NULL DEFAULT '{}',
+    removed_groups UUID[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_topology_snapshots_topology ON topology_snapshots(topology_id, created_at DESC);
+CREATE INDEX idx_topology_snapshots_network ON topology_snapshots(network_id);
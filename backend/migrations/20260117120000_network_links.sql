@@ -0,0 +1,16 @@
+-- User-defined connections between networks in the same organization, drawn in the
+-- organization topology alongside links found from shared subnets and hosts.
+
+CREATE TABLE network_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    target_network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    label TEXT,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT network_links_distinct_networks CHECK (network_id <> target_network_id)
+);
+
+CREATE INDEX idx_network_links_network ON network_links(network_id);
+CREATE INDEX idx_network_links_target_network ON network_links(target_network_id);
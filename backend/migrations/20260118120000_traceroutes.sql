@@ -0,0 +1,18 @@
+-- Hop-by-hop paths measured by daemons, to subnet gateways and to external targets.
+-- A daemon keeps one row per target, replaced each time discovery traces it again.
+
+CREATE TABLE traceroutes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    daemon_id UUID NOT NULL REFERENCES daemons(id) ON DELETE CASCADE,
+    subnet_id UUID REFERENCES subnets(id) ON DELETE CASCADE,
+    target INET NOT NULL,
+    protocol TEXT NOT NULL,
+    hops JSONB NOT NULL DEFAULT '[]',
+    reached BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT traceroutes_daemon_target UNIQUE (daemon_id, target)
+);
+
+CREATE INDEX idx_traceroutes_network ON traceroutes(network_id);
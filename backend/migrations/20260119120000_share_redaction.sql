@@ -0,0 +1,2 @@
+-- Per-share redaction of the shared topology: hidden addresses, hidden hosts and aliases
+ALTER TABLE shares ADD COLUMN redaction JSONB NOT NULL DEFAULT '{}';
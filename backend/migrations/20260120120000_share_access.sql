@@ -0,0 +1,26 @@
+-- Named, individually revocable access tokens for shares
+CREATE TABLE share_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    share_id UUID NOT NULL REFERENCES shares(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    key TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_share_tokens_share ON share_tokens(share_id);
+
+-- One row per share view. Addresses are stored as a coarse network bucket, never in full.
+CREATE TABLE share_views (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    share_id UUID NOT NULL REFERENCES shares(id) ON DELETE CASCADE,
+    token_id UUID REFERENCES share_tokens(id) ON DELETE SET NULL,
+    referrer_domain TEXT,
+    ip_bucket TEXT NOT NULL,
+    country TEXT,
+    embed BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_share_views_share_created ON share_views(share_id, created_at);
@@ -70,6 +70,7 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!(target: LOG_TARGET, "  Services initialized");
 
     let discovery_service = state.services.discovery_service.clone();
+    let snapshot_service = state.services.snapshot_service.clone();
     let billing_service = state.services.billing_service.clone();
     let deployment_type = get_deployment_type(state.clone());
 
@@ -113,6 +114,16 @@ async fn main() -> anyhow::Result<()> {
                 .auth_service
                 .cleanup_old_login_attempts()
                 .await;
+            auth_cleanup_state
+                .services
+                .mfa_service
+                .cleanup_old_verify_attempts()
+                .await;
+            auth_cleanup_state
+                .services
+                .session_service
+                .cleanup_expired()
+                .await;
         }
     });
 
@@ -126,6 +137,16 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Create share view cleanup task
+    let share_service_cleanup = state.services.share_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60 * 60)); // Hourly
+        loop {
+            interval.tick().await;
+            share_service_cleanup.cleanup_old_views().await;
+        }
+    });
+
     tracing::info!(target: LOG_TARGET, "  Background tasks started");
 
     let (base_router, _openapi) = create_router(state.clone());
@@ -261,6 +282,9 @@ async fn main() -> anyhow::Result<()> {
     // Start discovery scheduler
     discovery_service.start_scheduler().await?;
 
+    // Start scheduled topology snapshots, if configured
+    snapshot_service.start_scheduler().await?;
+
     // Initialize billing if configured
     if let Some(billing_service) = billing_service {
         billing_service
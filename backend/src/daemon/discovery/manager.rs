@@ -7,8 +7,13 @@ use crate::daemon::discovery::service::base::{
     DaemonDiscoveryService, DiscoveryRunner, RunsDiscovery,
 };
 use crate::daemon::discovery::service::docker::DockerScanDiscovery;
+use crate::daemon::discovery::service::kubernetes::KubernetesDiscovery;
 use crate::daemon::discovery::service::network::NetworkScanDiscovery;
+use crate::daemon::discovery::service::proxmox::ProxmoxDiscovery;
 use crate::daemon::discovery::service::self_report::SelfReportDiscovery;
+use crate::daemon::discovery::service::ssh_inventory::SshInventoryDiscovery;
+use crate::daemon::discovery::service::traceroute::TracerouteDiscovery;
+use crate::daemon::discovery::service::winrm::WinRmDiscovery;
 use crate::daemon::runtime::service::LOG_TARGET;
 use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
 use crate::server::discovery::r#impl::types::DiscoveryType;
@@ -59,6 +64,65 @@ impl DaemonDiscoverySessionManager {
                 request.clone(),
                 cancel_token,
             ),
+            DiscoveryType::Kubernetes {
+                namespaces,
+                host_naming_fallback,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    KubernetesDiscovery::new(namespaces.clone(), *host_naming_fallback),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Proxmox {
+                api_url,
+                skip_tls_verify,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    ProxmoxDiscovery::new(api_url.clone(), *skip_tls_verify),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::SshInventory { targets, port } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    SshInventoryDiscovery::new(targets.clone(), *port),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::WinRm {
+                targets,
+                use_https,
+                skip_tls_verify,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    WinRmDiscovery::new(targets.clone(), *use_https, *skip_tls_verify),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Traceroute {
+                external_targets,
+                protocol,
+                max_hops,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    TracerouteDiscovery::new(external_targets.clone(), *protocol, *max_hops),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
             DiscoveryType::Network {
                 subnet_ids,
                 host_naming_fallback,
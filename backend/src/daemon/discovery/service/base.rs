@@ -1,7 +1,7 @@
 use std::{
     net::IpAddr,
     sync::{
-        Arc,
+        Arc, Mutex,
         atomic::{AtomicU8, AtomicU64, Ordering},
     },
 };
@@ -10,22 +10,39 @@ use crate::{
     daemon::{
         discovery::{manager::DaemonDiscoverySessionManager, types::base::DiscoveryCriticalError},
         shared::api_client::DaemonApiClient,
+        utils::proxies::ReverseProxy,
     },
     server::{
+        bindings::handlers::{DiscoveryResolveBindingsRequest, DiscoveryResolveBindingsResponse},
         discovery::r#impl::types::{DiscoveryType, HostNamingFallback},
-        groups::r#impl::base::Group,
+        groups::r#impl::{
+            base::{Group, GroupBase},
+            types::GroupType,
+        },
         services::{
-            definitions::{docker_container::DockerContainer, open_ports::OpenPorts},
+            definitions::{
+                docker_container::DockerContainer, kubernetes_pod::KubernetesPod,
+                open_ports::OpenPorts,
+            },
             r#impl::{
                 base::{
                     DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
                     ServiceMatchServiceParams,
                 },
                 patterns::MatchConfidence,
-                virtualization::{DockerVirtualization, ServiceVirtualization},
             },
         },
-        shared::types::entities::{DiscoveryMetadata, EntitySource},
+        shared::{
+            entities::EntityDiscriminants,
+            storage::traits::StorableEntity,
+            types::{
+                Color,
+                entities::{DiscoveryMetadata, EntitySource},
+            },
+        },
+        tags::{handlers::DiscoveryTagRequest, r#impl::base::Tag},
+        topology::types::edges::EdgeStyle,
+        traceroutes::r#impl::base::Traceroute,
     },
 };
 use anyhow::{Error, anyhow};
@@ -43,6 +60,7 @@ use crate::{
         utils::base::{PlatformDaemonUtils, create_system_utils},
     },
     server::{
+        credentials::r#impl::api::DiscoveryCredential,
         daemons::r#impl::api::{DaemonDiscoveryRequest, DiscoveryUpdatePayload},
         hosts::r#impl::{
             api::{DiscoveryHostRequest, HostResponse},
@@ -88,6 +106,9 @@ pub struct DiscoverySession {
     pub gateway_ips: Vec<IpAddr>,
     pub last_progress: Arc<AtomicU8>,
     pub last_progress_report_time: Arc<AtomicU64>,
+    /// Reverse proxies created during the session, whose routes are read once every host has
+    /// been created so their backends can be resolved
+    pub reverse_proxies: Arc<Mutex<Vec<ReverseProxy>>>,
 }
 
 impl DiscoverySession {
@@ -97,6 +118,7 @@ impl DiscoverySession {
             gateway_ips,
             last_progress: Arc::new(AtomicU8::new(0)),
             last_progress_report_time: Arc::new(AtomicU64::new(0)),
+            reverse_proxies: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -144,6 +166,24 @@ pub trait RunsDiscovery: AsRef<DaemonDiscoveryService> + Send + Sync {
         cancel: CancellationToken,
     ) -> Result<(), Error>;
 
+    /// Fetch the credentials attached to a session. The server only hands them out
+    /// once, so call this a single time at the start of the session.
+    async fn fetch_credentials(
+        &self,
+        request: &DaemonDiscoveryRequest,
+    ) -> Result<Vec<DiscoveryCredential>, Error> {
+        if request.credential_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let path = format!("/api/v1/discovery/{}/credentials", request.session_id);
+
+        self.as_ref()
+            .api_client
+            .post(&path, &(), "Failed to fetch discovery credentials")
+            .await
+    }
+
     /// Report scanning progress with automatic time-based throttling.
     /// Reports if progress has changed OR at least 30 seconds have passed (heartbeat).
     /// Percent should be 0-100.
@@ -481,8 +521,11 @@ pub trait DiscoversNetworkedEntities:
             } else if s.id() == OpenPorts.id() {
                 // Catch-all for open ports, should be dead last
                 3
-            } else if s.id() == DockerContainer.id() || s.id() == Gateway.id() {
-                // Docker Containers and Gateways need to go second to last last
+            } else if s.id() == DockerContainer.id()
+                || s.id() == KubernetesPod.id()
+                || s.id() == Gateway.id()
+            {
+                // Containers, pods and Gateways need to go second to last last
                 // Other generic services should be able to get matched first
                 2
             } else {
@@ -514,10 +557,11 @@ pub trait DiscoversNetworkedEntities:
                 && !container_matched
             {
                 // If a container was matched w the provided virtualization, no others can be matched
-                if let Some(ServiceVirtualization::Docker(DockerVirtualization {
-                    container_id: Some(_),
-                    ..
-                })) = &service.base.virtualization
+                if service
+                    .base
+                    .virtualization
+                    .as_ref()
+                    .is_some_and(|v| v.container_id().is_some())
                 {
                     container_matched = true
                 }
@@ -570,13 +614,22 @@ pub trait CreatesDiscoveredEntities:
         ports: Vec<Port>,
         services: Vec<Service>,
     ) -> Result<HostResponse, Error> {
-        let request = DiscoveryHostRequest {
+        self.create_host_from_request(DiscoveryHostRequest {
             host,
             interfaces,
             ports,
             services,
-        };
-        self.as_ref()
+            mac_addresses: Vec::new(),
+        })
+        .await
+    }
+
+    async fn create_host_from_request(
+        &self,
+        request: DiscoveryHostRequest,
+    ) -> Result<HostResponse, Error> {
+        let host_response: HostResponse = self
+            .as_ref()
             .api_client
             .post_with_retry(
                 "/api/v1/hosts/discovery",
@@ -584,7 +637,17 @@ pub trait CreatesDiscoveredEntities:
                 "Failed to create host",
                 ENTITY_CREATION_MAX_RETRIES,
             )
-            .await
+            .await?;
+
+        let reverse_proxies = ReverseProxy::from_host_response(&host_response);
+        if !reverse_proxies.is_empty()
+            && let Ok(session) = self.as_ref().get_session().await
+            && let Ok(mut session_proxies) = session.reverse_proxies.lock()
+        {
+            session_proxies.extend(reverse_proxies);
+        }
+
+        Ok(host_response)
     }
 
     async fn create_subnet(&self, subnet: &Subnet) -> Result<Subnet, Error> {
@@ -611,15 +674,164 @@ pub trait CreatesDiscoveredEntities:
             .await
     }
 
+    async fn apply_tag(
+        &self,
+        name: &str,
+        entity_type: EntityDiscriminants,
+        entity_ids: Vec<Uuid>,
+    ) -> Result<Tag, Error> {
+        let request = DiscoveryTagRequest {
+            name: name.to_string(),
+            color: None,
+            entity_type,
+            entity_ids,
+        };
+        self.as_ref()
+            .api_client
+            .post_with_retry(
+                "/api/v1/tags/discovery",
+                &request,
+                "Failed to apply tag",
+                ENTITY_CREATION_MAX_RETRIES,
+            )
+            .await
+    }
+
     async fn create_group(&self, group: &Group) -> Result<Group, Error> {
         self.as_ref()
             .api_client
             .post_with_retry(
-                "/api/v1/groups",
+                "/api/v1/groups/discovery",
                 group,
                 "Failed to create group",
                 ENTITY_CREATION_MAX_RETRIES,
             )
             .await
     }
+
+    async fn create_traceroute(&self, traceroute: &Traceroute) -> Result<Traceroute, Error> {
+        self.as_ref()
+            .api_client
+            .post_with_retry(
+                "/api/v1/traceroutes/discovery",
+                traceroute,
+                "Failed to create traceroute",
+                ENTITY_CREATION_MAX_RETRIES,
+            )
+            .await
+    }
+
+    /// Read the routes of every reverse proxy created during the session and create a
+    /// request path group per route, from the proxy to the bindings of its backends
+    async fn discover_reverse_proxy_routes(&self) {
+        let Ok(session) = self.as_ref().get_session().await else {
+            return;
+        };
+        let reverse_proxies = match session.reverse_proxies.lock() {
+            Ok(mut proxies) => std::mem::take(&mut *proxies),
+            Err(_) => return,
+        };
+        if reverse_proxies.is_empty() {
+            return;
+        }
+
+        let npm_credentials = self
+            .as_ref()
+            .config_store
+            .get_npm_credentials()
+            .await
+            .ok()
+            .flatten();
+
+        for proxy in reverse_proxies {
+            let routes = match proxy.fetch_routes(npm_credentials.as_ref()).await {
+                Ok(routes) => routes,
+                Err(e) => {
+                    tracing::warn!(
+                        proxy = %proxy.name,
+                        ip = %proxy.ip,
+                        port = %proxy.port,
+                        error = %e,
+                        "Failed to read reverse proxy routes"
+                    );
+                    continue;
+                }
+            };
+
+            tracing::info!(
+                proxy = %proxy.name,
+                ip = %proxy.ip,
+                routes = routes.len(),
+                "Read reverse proxy routes"
+            );
+
+            for route in routes {
+                let request = DiscoveryResolveBindingsRequest {
+                    targets: route.backends.clone(),
+                };
+                let resolved: DiscoveryResolveBindingsResponse = match self
+                    .as_ref()
+                    .api_client
+                    .post(
+                        "/api/v1/bindings/discovery/resolve",
+                        &request,
+                        "Failed to resolve route backends",
+                    )
+                    .await
+                {
+                    Ok(resolved) => resolved,
+                    Err(e) => {
+                        tracing::warn!(route = %route.display_name(), error = %e, "Failed to resolve route backends");
+                        continue;
+                    }
+                };
+
+                let mut binding_ids = vec![proxy.binding_id];
+                for binding_id in resolved.binding_ids.into_iter().flatten() {
+                    if !binding_ids.contains(&binding_id) {
+                        binding_ids.push(binding_id);
+                    }
+                }
+
+                // Backends which haven't been discovered can't be drawn
+                if binding_ids.len() < 2 {
+                    tracing::debug!(route = %route.display_name(), "No discovered backends for route");
+                    continue;
+                }
+
+                let backends = route
+                    .backends
+                    .iter()
+                    .map(|b| format!("{}:{}", b.address, b.port))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let group = Group::new(GroupBase {
+                    name: route.display_name(),
+                    network_id: session.info.network_id,
+                    description: Some(format!(
+                        "{} routes {} to {}",
+                        proxy.name,
+                        route.display_name(),
+                        backends
+                    )),
+                    group_type: GroupType::RequestPath,
+                    binding_ids,
+                    source: EntitySource::Discovery {
+                        metadata: vec![DiscoveryMetadata::new(
+                            self.discovery_type(),
+                            session.info.daemon_id,
+                        )],
+                    },
+                    color: Color::Green,
+                    edge_style: EdgeStyle::default(),
+                    tags: Vec::new(),
+                });
+
+                if let Err(e) = self.create_group(&group).await {
+                    tracing::warn!(route = %route.display_name(), error = %e, "Failed to create route group");
+                }
+            }
+        }
+    }
 }
@@ -1,41 +1,52 @@
 use anyhow::anyhow;
 use anyhow::{Error, Result};
 use async_trait::async_trait;
-use bollard::{
-    Docker,
-    query_parameters::{InspectContainerOptions, ListContainersOptions, ListNetworksOptions},
-    secret::{ContainerInspectResponse, ContainerSummary, PortTypeEnum},
-};
+use bollard::secret::{ContainerInspectResponse, ContainerSummary, PortTypeEnum};
 use cidr::IpCidr;
 use futures::future::try_join_all;
 use futures::stream::{self, StreamExt};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::{collections::HashMap, net::IpAddr, sync::OnceLock};
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    sync::OnceLock,
+};
 use strum::IntoDiscriminant;
 use tokio_util::sync::CancellationToken;
 
 use crate::daemon::discovery::service::base::RunsDiscovery;
 use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
 use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::containers::{
+    ContainerClient, ContainerStack, POD_NAME_LABEL, StackMember, infer_stack_layout,
+};
 use crate::daemon::utils::scanner::scan_endpoints;
 use crate::server::bindings::r#impl::base::{Binding, BindingDiscriminants};
 use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::groups::r#impl::base::{Group, GroupBase};
+use crate::server::groups::r#impl::types::GroupType;
 use crate::server::hosts::r#impl::base::HostBase;
 use crate::server::interfaces::r#impl::base::ALL_INTERFACES_IP;
 use crate::server::ports::r#impl::base::Port;
+use crate::server::services::definitions::containerd::Containerd;
+use crate::server::services::definitions::docker_daemon::Docker;
+use crate::server::services::definitions::podman::Podman;
 use crate::server::services::r#impl::base::{Service, ServiceBase, ServiceMatchBaselineParams};
 use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
 use crate::server::services::r#impl::patterns::MatchDetails;
 use crate::server::services::r#impl::virtualization::{
-    DockerVirtualization, ServiceVirtualization,
+    ContainerRuntime, DockerVirtualization, ServiceVirtualization,
 };
+use crate::server::shared::entities::EntityDiscriminants;
 use crate::server::shared::storage::traits::StorableEntity;
+use crate::server::shared::types::Color;
 use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
 use crate::server::subnets::r#impl::base::Subnet;
 use crate::server::subnets::r#impl::types::SubnetTypeDiscriminants;
+use crate::server::topology::types::edges::EdgeStyle;
 use crate::{
     daemon::discovery::service::base::{
         CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner,
@@ -52,16 +63,46 @@ use uuid::Uuid;
 
 type IpPortHashMap = HashMap<IpAddr, Vec<PortType>>;
 
+/// Service definition for the engine behind a container runtime
+fn runtime_service_definition(runtime: ContainerRuntime) -> Box<dyn ServiceDefinition> {
+    match runtime {
+        ContainerRuntime::Docker => Box::new(Docker),
+        ContainerRuntime::Podman => Box::new(Podman),
+        ContainerRuntime::Containerd => Box::new(Containerd),
+    }
+}
+
 pub struct DockerScanDiscovery {
-    docker_client: OnceLock<Docker>,
+    container_clients: OnceLock<Vec<Arc<ContainerClient>>>,
     host_id: Uuid,
     host_naming_fallback: HostNamingFallback,
 }
 
+/// A running container and the runtime client it was listed from
+pub struct RuntimeContainer {
+    pub client: Arc<ContainerClient>,
+    pub container: ContainerInspectResponse,
+    pub summary: ContainerSummary,
+    /// Pod the container belongs to, if it shares a network namespace with other containers
+    pub pod_name: Option<String>,
+    /// ID of the container whose network namespace this container joined
+    pub network_owner_id: Option<String>,
+}
+
+/// Compose project or Swarm stack membership of a container, captured before the containers
+/// are consumed by the scan
+struct StackContainer {
+    stack: ContainerStack,
+    networks: Vec<String>,
+    publishes_ports: bool,
+}
+
 pub struct ProcessContainerParams<'a> {
     pub containers_interfaces_and_subnets: &'a HashMap<String, Vec<(Interface, Subnet)>>,
     pub container: &'a ContainerInspectResponse,
     pub container_summary: &'a ContainerSummary,
+    pub client: &'a ContainerClient,
+    pub pod_name: Option<&'a str>,
     pub docker_service_id: &'a Uuid,
     pub cancel: CancellationToken,
 }
@@ -91,17 +132,30 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
         let docker_proxy = self.as_ref().config_store.get_docker_proxy().await;
         let docker_proxy_ssl_info = self.as_ref().config_store.get_docker_proxy_ssl_info().await;
 
-        let docker = self
+        let clients = self
             .as_ref()
             .utils
-            .new_local_docker_client(docker_proxy, docker_proxy_ssl_info)
-            .await?;
+            .new_local_container_clients(docker_proxy, docker_proxy_ssl_info)
+            .await;
+
+        if clients.is_empty() {
+            return Err(anyhow!(
+                "No container runtime available (checked Docker, Podman and containerd)"
+            ));
+        }
+
+        for client in &clients {
+            tracing::info!(client = %client.description(), "Using container runtime");
+        }
+
         self.domain
-            .docker_client
-            .set(docker.clone())
-            .map_err(|_| anyhow!("Failed to set docker client"))?;
+            .container_clients
+            .set(clients.into_iter().map(Arc::new).collect())
+            .map_err(|_| anyhow!("Failed to set container clients"))?;
 
-        let container_list = self.get_containers_to_scan().await?;
+        // Get container info
+        let containers = Self::resolve_pods(self.get_containers_and_summaries().await?);
+        let total_containers = containers.len();
 
         self.start_discovery(request).await?;
 
@@ -125,15 +179,10 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
             }
         }
 
-        // Create service for docker daemon (pass interfaces for proper host matching)
-        let (_, services) = self.create_docker_daemon_service(&host_interfaces).await?;
-
-        let docker_daemon_service = services
-            .first()
-            .ok_or_else(|| anyhow!("Docker daemon service was not created, aborting"))?;
+        let stack_containers = Self::get_stack_containers(&containers);
 
-        // Get container info
-        let containers = self.get_containers_and_summaries().await?;
+        // Create a service for each container runtime (pass interfaces for proper host matching)
+        let runtime_service_ids = self.create_runtime_services(&host_interfaces).await?;
 
         // Combine host interfaces + subnets to get a map of containers to the interfaces they have + subnets those interfaces are for
         let containers_interfaces_and_subnets =
@@ -144,16 +193,21 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
                 cancel.clone(),
                 containers,
                 &containers_interfaces_and_subnets,
-                &docker_daemon_service.id,
+                &runtime_service_ids,
             )
             .await;
 
         if let Ok(ref container_data) = discovered_hosts_services {
             tracing::info!(
-                total_containers = %container_list.len(),
+                total_containers = %total_containers,
                 discovered = %container_data.len(),
                 "Docker scan complete"
             );
+
+            self.create_pod_groups(container_data).await;
+            self.create_stack_groups(container_data, &stack_containers)
+                .await;
+            self.discover_reverse_proxy_routes().await;
         }
 
         let discovery_result = if discovered_hosts_services.is_ok() {
@@ -172,7 +226,7 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
 impl DockerScanDiscovery {
     pub fn new(host_id: Uuid, host_naming_fallback: HostNamingFallback) -> Self {
         Self {
-            docker_client: OnceLock::new(),
+            container_clients: OnceLock::new(),
             host_id,
             host_naming_fallback,
         }
@@ -184,15 +238,12 @@ impl CreatesDiscoveredEntities for DiscoveryRunner<DockerScanDiscovery> {}
 #[async_trait]
 impl DiscoversNetworkedEntities for DiscoveryRunner<DockerScanDiscovery> {
     async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
-        let docker = self
-            .domain
-            .docker_client
-            .get()
-            .ok_or_else(|| anyhow!("Docker client unavailable"))?;
+        let mut networks = Vec::new();
+        for client in self.container_clients()? {
+            networks.extend(client.list_networks().await?);
+        }
 
-        let gateway_ips: Vec<IpAddr> = docker
-            .list_networks(None::<ListNetworksOptions>)
-            .await?
+        let gateway_ips: Vec<IpAddr> = networks
             .iter()
             .filter_map(|n| {
                 if let Some(ipam) = &n.ipam
@@ -230,17 +281,26 @@ impl DiscoversNetworkedEntities for DiscoveryRunner<DockerScanDiscovery> {
             .get_own_interfaces(self.discovery_type(), daemon_id, network_id)
             .await?;
 
-        let docker = self
-            .domain
-            .docker_client
-            .get()
-            .ok_or_else(|| anyhow!("Docker client unavailable"))?;
+        let mut docker_subnets: Vec<Subnet> = Vec::new();
+        for client in self.container_clients()? {
+            let client_subnets = self
+                .as_ref()
+                .utils
+                .get_subnets_from_docker_networks(
+                    daemon_id,
+                    network_id,
+                    client,
+                    self.discovery_type(),
+                )
+                .await?;
 
-        let docker_subnets = self
-            .as_ref()
-            .utils
-            .get_subnets_from_docker_networks(daemon_id, network_id, docker, self.discovery_type())
-            .await?;
+            // Runtimes on the same host can pick the same default bridge range
+            let new_subnets: Vec<Subnet> = client_subnets
+                .into_iter()
+                .filter(|s| !docker_subnets.iter().any(|d| d.base.cidr == s.base.cidr))
+                .collect();
+            docker_subnets.extend(new_subnets);
+        }
 
         // Extract host CIDRs - host interfaces take precedence over Docker networks
         let host_cidrs: std::collections::HashSet<IpCidr> =
@@ -263,12 +323,19 @@ impl DiscoversNetworkedEntities for DiscoveryRunner<DockerScanDiscovery> {
 }
 
 impl DiscoveryRunner<DockerScanDiscovery> {
-    /// Create docker daemon service which has container relationship with docker daemon service
+    fn container_clients(&self) -> Result<&Vec<Arc<ContainerClient>>, Error> {
+        self.domain
+            .container_clients
+            .get()
+            .ok_or_else(|| anyhow!("Container clients unavailable"))
+    }
+
+    /// Create a service for each container runtime, which has container relationship with the runtime's containers
     /// Takes host_interfaces to enable proper host matching via MAC/IP addresses
-    pub async fn create_docker_daemon_service(
+    pub async fn create_runtime_services(
         &self,
         host_interfaces: &[Interface],
-    ) -> Result<(Host, Vec<Service>), Error> {
+    ) -> Result<HashMap<ContainerRuntime, Uuid>, Error> {
         let daemon_id = self.as_ref().config_store.get_id().await?;
         let network_id = self
             .as_ref()
@@ -279,25 +346,38 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
         let host_id = self.domain.host_id;
 
-        let docker_service_definition = crate::server::services::definitions::docker_daemon::Docker;
+        let mut runtimes: Vec<ContainerRuntime> = Vec::new();
+        for client in self.container_clients()? {
+            if !runtimes.contains(&client.runtime()) {
+                runtimes.push(client.runtime());
+            }
+        }
 
-        let docker_service = Service::new(ServiceBase {
-            name: ServiceDefinition::name(&docker_service_definition).to_string(),
-            service_definition: Box::new(docker_service_definition),
-            bindings: vec![],
-            host_id,
-            tags: Vec::new(),
-            network_id,
-            virtualization: None,
-            source: EntitySource::DiscoveryWithMatch {
-                metadata: vec![DiscoveryMetadata::new(
-                    DiscoveryType::SelfReport { host_id },
-                    daemon_id,
-                )],
-                details: MatchDetails::new_certain("Docker daemon self-report"),
-            },
-            position: 0,
-        });
+        let runtime_services: Vec<Service> = runtimes
+            .iter()
+            .map(|runtime| {
+                let service_definition = runtime_service_definition(*runtime);
+                let name = ServiceDefinition::name(&*service_definition).to_string();
+
+                Service::new(ServiceBase {
+                    name: name.clone(),
+                    service_definition,
+                    bindings: vec![],
+                    host_id,
+                    tags: Vec::new(),
+                    network_id,
+                    virtualization: None,
+                    source: EntitySource::DiscoveryWithMatch {
+                        metadata: vec![DiscoveryMetadata::new(
+                            DiscoveryType::SelfReport { host_id },
+                            daemon_id,
+                        )],
+                        details: MatchDetails::new_certain(&format!("{} daemon self-report", name)),
+                    },
+                    position: 0,
+                })
+            })
+            .collect();
 
         let mut temp_docker_daemon_host = Host::new(HostBase {
             name: "Docker Daemon Host".to_string(),
@@ -319,19 +399,37 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 temp_docker_daemon_host,
                 host_interfaces.to_vec(),
                 vec![], // No ports for docker daemon host
-                vec![docker_service],
+                runtime_services,
             )
             .await?;
 
-        Ok((host_response.to_host(), host_response.services))
+        let runtime_service_ids: HashMap<ContainerRuntime, Uuid> = runtimes
+            .into_iter()
+            .filter_map(|runtime| {
+                let definition_id = runtime_service_definition(runtime).id();
+                host_response
+                    .services
+                    .iter()
+                    .find(|s| s.base.service_definition.id() == definition_id)
+                    .map(|s| (runtime, s.id))
+            })
+            .collect();
+
+        if runtime_service_ids.is_empty() {
+            return Err(anyhow!(
+                "Container runtime services were not created, aborting"
+            ));
+        }
+
+        Ok(runtime_service_ids)
     }
 
     async fn scan_and_process_containers(
         &self,
         cancel: CancellationToken,
-        containers: Vec<(ContainerInspectResponse, ContainerSummary)>,
+        containers: Vec<RuntimeContainer>,
         containers_interfaces_and_subnets: &HashMap<String, Vec<(Interface, Subnet)>>,
-        docker_service_id: &Uuid,
+        runtime_service_ids: &HashMap<ContainerRuntime, Uuid>,
     ) -> Result<Vec<(Host, Vec<Service>)>> {
         let total_containers = containers.len();
 
@@ -346,20 +444,27 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
         // Process containers concurrently using streams
         let results = stream::iter(containers.into_iter())
-            .map(|(container, container_summary)| {
+            .map(|runtime_container| {
                 let cancel = cancel.clone();
                 let processed_count = processed_count.clone();
 
                 async move {
-                    let result = self
-                        .process_single_container(&ProcessContainerParams {
-                            containers_interfaces_and_subnets,
-                            container: &container,
-                            container_summary: &container_summary,
-                            docker_service_id,
-                            cancel,
-                        })
-                        .await;
+                    let result = match runtime_service_ids.get(&runtime_container.client.runtime())
+                    {
+                        Some(docker_service_id) => {
+                            self.process_single_container(&ProcessContainerParams {
+                                containers_interfaces_and_subnets,
+                                container: &runtime_container.container,
+                                container_summary: &runtime_container.summary,
+                                client: &runtime_container.client,
+                                pod_name: runtime_container.pod_name.as_deref(),
+                                docker_service_id,
+                                cancel,
+                            })
+                            .await
+                        }
+                        None => Ok(None),
+                    };
 
                     // Update progress after each container
                     let done = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
@@ -448,6 +553,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         let ProcessContainerParams {
             containers_interfaces_and_subnets,
             container,
+            client,
+            pod_name,
             cancel,
             docker_service_id,
             ..
@@ -502,6 +609,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                             .map(|n| n.trim_start_matches("/").to_string()),
                         container_id: container.id.clone(),
                         service_id: **docker_service_id,
+                        runtime: client.runtime(),
+                        pod_name: pod_name.map(str::to_string),
                     })),
                 };
 
@@ -535,6 +644,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             containers_interfaces_and_subnets,
             container,
             container_summary,
+            client,
+            pod_name,
             cancel,
             docker_service_id,
             ..
@@ -564,6 +675,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
             let endpoint_responses = if let Some(name) = &container.name {
                 self.scan_container_endpoints(
+                    client,
                     interface,
                     &host_to_container_port_map,
                     name.trim_start_matches("/"),
@@ -602,6 +714,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                                     .map(|n| n.trim_start_matches("/").to_string()),
                                 container_id: container.id.clone(),
                                 service_id: **docker_service_id,
+                                runtime: client.runtime(),
+                                pod_name: pod_name.map(str::to_string),
                             },
                         )),
                     },
@@ -783,51 +897,399 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         Ok(None)
     }
 
-    pub async fn get_containers_to_scan(&self) -> Result<Vec<ContainerSummary>, Error> {
-        let docker = self
-            .domain
-            .docker_client
-            .get()
-            .ok_or_else(|| anyhow!("Docker client unavailable"))?;
+    /// Running containers from every connected runtime
+    pub async fn get_containers_and_summaries(&self) -> Result<Vec<RuntimeContainer>, Error> {
+        let mut containers = Vec::new();
+
+        for client in self.container_clients()? {
+            match client.list_containers().await {
+                Ok(client_containers) => {
+                    containers.extend(client_containers.into_iter().map(|(container, summary)| {
+                        RuntimeContainer {
+                            client: client.clone(),
+                            container,
+                            summary,
+                            pod_name: None,
+                            network_owner_id: None,
+                        }
+                    }))
+                }
+                Err(e) => tracing::warn!(
+                    client = %client.description(),
+                    error = %e,
+                    "Failed to list containers"
+                ),
+            }
+        }
 
-        docker
-            .list_containers(None::<ListContainersOptions>)
-            .await
-            .map_err(|e| anyhow!(e))
+        Ok(containers)
     }
 
-    pub async fn get_containers_and_summaries(
-        &self,
-    ) -> Result<Vec<(ContainerInspectResponse, ContainerSummary)>, Error> {
-        let docker = self
-            .domain
-            .docker_client
-            .get()
-            .ok_or_else(|| anyhow!("Docker client unavailable"))?;
+    /// Find containers which joined another container's network namespace (Podman pods,
+    /// `--network container:<id>`) and give them the pod's name. Published ports belong to the
+    /// namespace owner (the pod's infra container), so they're split between members by the
+    /// ports each member's image exposes.
+    fn resolve_pods(mut containers: Vec<RuntimeContainer>) -> Vec<RuntimeContainer> {
+        let owner_indexes: Vec<Option<usize>> = containers
+            .iter()
+            .map(|c| {
+                let target = c
+                    .container
+                    .host_config
+                    .as_ref()?
+                    .network_mode
+                    .as_ref()?
+                    .strip_prefix("container:")?;
+
+                containers.iter().position(|owner| {
+                    Arc::ptr_eq(&owner.client, &c.client)
+                        && (owner
+                            .container
+                            .id
+                            .as_ref()
+                            .is_some_and(|id| id.starts_with(target))
+                            || owner
+                                .container
+                                .name
+                                .as_ref()
+                                .is_some_and(|n| n.trim_start_matches('/') == target))
+                })
+            })
+            .collect();
+
+        let mut members_by_owner: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (member_index, owner_index) in owner_indexes.iter().enumerate() {
+            if let Some(owner_index) = owner_index {
+                members_by_owner
+                    .entry(*owner_index)
+                    .or_default()
+                    .push(member_index);
+            }
+        }
+
+        for (owner_index, member_indexes) in members_by_owner {
+            let pod_name = std::iter::once(owner_index)
+                .chain(member_indexes.iter().copied())
+                .find_map(|i| {
+                    containers[i]
+                        .container
+                        .config
+                        .as_ref()?
+                        .labels
+                        .as_ref()?
+                        .get(POD_NAME_LABEL)
+                        .cloned()
+                })
+                .or_else(|| {
+                    containers[owner_index].container.name.as_ref().map(|n| {
+                        let name = n.trim_start_matches('/');
+                        name.strip_suffix("-infra").unwrap_or(name).to_string()
+                    })
+                });
+
+            let owner_id = containers[owner_index].container.id.clone();
+            let pod_ports = containers[owner_index]
+                .summary
+                .ports
+                .clone()
+                .unwrap_or_default();
+            let mut claimed_ports: Vec<bollard::secret::Port> = Vec::new();
+
+            for member_index in member_indexes {
+                let member = &mut containers[member_index];
+
+                let exposed_ports: Vec<String> = member
+                    .container
+                    .config
+                    .as_ref()
+                    .and_then(|c| c.exposed_ports.as_ref())
+                    .map(|p| p.keys().cloned().collect())
+                    .unwrap_or_default();
+
+                let member_ports: Vec<bollard::secret::Port> = pod_ports
+                    .iter()
+                    .filter(|p| {
+                        let protocol = p.typ.map(|t| t.to_string()).unwrap_or_default();
+                        exposed_ports.contains(&format!("{}/{}", p.private_port, protocol))
+                    })
+                    .cloned()
+                    .collect();
 
-        let container_summaries = self.get_containers_to_scan().await?;
+                claimed_ports.extend(member_ports.iter().cloned());
+                member.summary.ports = Some(member_ports);
+                member.pod_name = pod_name.clone();
+                member.network_owner_id = owner_id.clone();
+            }
+
+            let owner = &mut containers[owner_index];
+            owner.pod_name = pod_name;
+            owner.summary.ports = Some(
+                pod_ports
+                    .into_iter()
+                    .filter(|p| !claimed_ports.contains(p))
+                    .collect(),
+            );
+        }
+
+        // Containers which don't share a network namespace can still be labelled as part of a pod
+        for c in containers.iter_mut().filter(|c| c.pod_name.is_none()) {
+            c.pod_name = c
+                .container
+                .config
+                .as_ref()
+                .and_then(|config| config.labels.as_ref())
+                .and_then(|labels| labels.get(POD_NAME_LABEL))
+                .cloned();
+        }
+
+        containers
+    }
+
+    /// Group the services of containers which share a pod. The first container's service is
+    /// the hub.
+    async fn create_pod_groups(&self, container_data: &[(Host, Vec<Service>)]) {
+        // Each created host returns all of the host's services, so dedupe across containers
+        let mut seen_service_ids: HashSet<Uuid> = HashSet::new();
+        let mut pods: Vec<(String, Vec<Uuid>)> = Vec::new();
+
+        for service in container_data.iter().flat_map(|(_, services)| services) {
+            if !seen_service_ids.insert(service.id) {
+                continue;
+            }
+
+            if let Some(ServiceVirtualization::Docker(DockerVirtualization {
+                pod_name: Some(pod_name),
+                ..
+            })) = &service.base.virtualization
+                && let Some(binding) = service.base.bindings.first()
+            {
+                match pods.iter_mut().find(|(name, _)| name == pod_name) {
+                    Some((_, binding_ids)) => binding_ids.push(binding.id()),
+                    None => pods.push((pod_name.clone(), vec![binding.id()])),
+                }
+            }
+        }
 
-        let containers_to_inspect: Vec<_> = container_summaries
+        for (pod_name, binding_ids) in pods {
+            if binding_ids.len() < 2 {
+                continue;
+            }
+
+            let description = format!(
+                "Containers sharing the network namespace of pod {}",
+                pod_name
+            );
+
+            let result = match self
+                .new_group(
+                    format!("{} pod", pod_name),
+                    format!("pod:{}/{}", self.domain.host_id, pod_name),
+                    description,
+                    GroupType::HubAndSpoke,
+                    Color::Purple,
+                    binding_ids,
+                )
+                .await
+            {
+                Ok(group) => self.create_group(&group).await,
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                tracing::warn!(pod = %pod_name, error = %e, "Failed to create pod group");
+            }
+        }
+    }
+
+    /// Stack membership of every container deployed by Docker Compose or as a Swarm stack,
+    /// keyed by container ID
+    fn get_stack_containers(containers: &[RuntimeContainer]) -> HashMap<String, StackContainer> {
+        containers
             .iter()
             .filter_map(|c| {
-                if let Some(id) = &c.id {
-                    return Some(docker.inspect_container(id, None::<InspectContainerOptions>));
-                }
-                None
+                let id = c.container.id.clone()?;
+                let labels = c.container.config.as_ref()?.labels.as_ref()?;
+                let stack = ContainerStack::from_labels(labels)?;
+
+                let networks = c
+                    .container
+                    .network_settings
+                    .as_ref()
+                    .and_then(|n| n.networks.as_ref())
+                    .map(|n| n.keys().cloned().collect())
+                    .unwrap_or_default();
+
+                let publishes_ports = c
+                    .summary
+                    .ports
+                    .as_ref()
+                    .is_some_and(|ports| ports.iter().any(|p| p.public_port.is_some()));
+
+                Some((
+                    id,
+                    StackContainer {
+                        stack,
+                        networks,
+                        publishes_ports,
+                    },
+                ))
             })
-            .collect();
+            .collect()
+    }
+
+    /// Tag the services of each compose project or Swarm stack with the stack name, and group
+    /// them so the topology shows the application rather than its individual containers
+    async fn create_stack_groups(
+        &self,
+        container_data: &[(Host, Vec<Service>)],
+        stack_containers: &HashMap<String, StackContainer>,
+    ) {
+        struct Stack<'a> {
+            stack: &'a ContainerStack,
+            service_ids: Vec<Uuid>,
+            container_ids: HashSet<&'a str>,
+            members: Vec<StackMember<Uuid>>,
+        }
 
-        let inspected_containers: Vec<ContainerInspectResponse> =
-            try_join_all(containers_to_inspect).await?;
+        let mut seen_service_ids: HashSet<Uuid> = HashSet::new();
+        let mut stacks: Vec<Stack> = Vec::new();
 
-        Ok(inspected_containers
-            .into_iter()
-            .zip(container_summaries)
-            .collect())
+        for service in container_data.iter().flat_map(|(_, services)| services) {
+            if !seen_service_ids.insert(service.id) {
+                continue;
+            }
+
+            let Some((container_id, stack_container)) = service
+                .base
+                .virtualization
+                .as_ref()
+                .and_then(|v| v.container_id())
+                .and_then(|id| stack_containers.get_key_value(&id))
+            else {
+                continue;
+            };
+
+            let index = match stacks
+                .iter()
+                .position(|s| s.stack.same_stack(&stack_container.stack))
+            {
+                Some(index) => index,
+                None => {
+                    stacks.push(Stack {
+                        stack: &stack_container.stack,
+                        service_ids: Vec::new(),
+                        container_ids: HashSet::new(),
+                        members: Vec::new(),
+                    });
+                    stacks.len() - 1
+                }
+            };
+            let stack = &mut stacks[index];
+
+            stack.service_ids.push(service.id);
+
+            // One binding per container, so replicas and multi-service containers show once each
+            if let Some(binding) = service.base.bindings.first()
+                && stack.container_ids.insert(container_id.as_str())
+            {
+                stack.members.push(StackMember {
+                    item: binding.id(),
+                    networks: stack_container.networks.clone(),
+                    publishes_ports: stack_container.publishes_ports,
+                });
+            }
+        }
+
+        for Stack {
+            stack,
+            service_ids,
+            members,
+            ..
+        } in stacks
+        {
+            let tag_name = stack.tag_name();
+            if let Err(e) = self
+                .apply_tag(&tag_name, EntityDiscriminants::Service, service_ids)
+                .await
+            {
+                tracing::warn!(stack = %stack.name, error = %e, "Failed to tag stack services");
+            }
+
+            if members.len() < 2 {
+                continue;
+            }
+
+            let (group_type, binding_ids) = infer_stack_layout(members);
+
+            let result = match self
+                .new_group(
+                    stack.name.clone(),
+                    stack.identity(self.domain.host_id),
+                    stack.description(),
+                    group_type,
+                    Color::Teal,
+                    binding_ids,
+                )
+                .await
+            {
+                Ok(group) => self.create_group(&group).await,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(group) => {
+                    if let Err(e) = self
+                        .apply_tag(&tag_name, EntityDiscriminants::Group, vec![group.id])
+                        .await
+                    {
+                        tracing::warn!(stack = %stack.name, error = %e, "Failed to tag stack group");
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(stack = %stack.name, error = %e, "Failed to create stack group")
+                }
+            }
+        }
+    }
+
+    async fn new_group(
+        &self,
+        name: String,
+        identity: String,
+        description: String,
+        group_type: GroupType,
+        color: Color,
+        binding_ids: Vec<Uuid>,
+    ) -> Result<Group, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        Ok(Group::new(GroupBase {
+            name,
+            network_id,
+            description: Some(description),
+            group_type,
+            binding_ids,
+            source: EntitySource::Discovery {
+                metadata: vec![
+                    DiscoveryMetadata::new(self.discovery_type(), daemon_id)
+                        .with_identity(identity),
+                ],
+            },
+            color,
+            edge_style: EdgeStyle::default(),
+            tags: Vec::new(),
+        }))
     }
 
     async fn scan_container_endpoints(
         &self,
+        client: &ContainerClient,
         interface: &Interface,
         host_to_container_port_map: &HashMap<(IpAddr, u16), u16>,
         container_name: &str,
@@ -844,12 +1306,6 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 .push((*host_ip, *host_port));
         }
 
-        let docker = self
-            .domain
-            .docker_client
-            .get()
-            .ok_or_else(|| anyhow!("Docker client unavailable"))?;
-
         let all_endpoints = Service::all_discovery_endpoints();
 
         let mut endpoint_responses = Vec::new();
@@ -926,94 +1382,49 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             let command = format!("{} || echo ''", requests.join(" 2>/dev/null || "));
 
             // Execute curl with command that works for environment
-            let exec = docker
-                .create_exec(
-                    container_name,
-                    bollard::exec::CreateExecOptions {
-                        cmd: Some(vec!["sh", "-c", &command]),
-                        attach_stdout: Some(true),
-                        attach_stderr: Some(true),
-                        ..Default::default()
-                    },
-                )
-                .await;
-
-            let Ok(exec_result) = exec else {
+            let Ok(full_response) = client.exec(container_name, &command, &cancel).await else {
                 continue;
             };
 
-            if let Ok(bollard::exec::StartExecResults::Attached { mut output, .. }) =
-                docker.start_exec(&exec_result.id, None).await
-            {
-                use futures::StreamExt;
-                let mut full_response = String::new();
-
-                loop {
-                    tokio::select! {
-                        _ = cancel.cancelled() => {
-                            tracing::debug!("Endpoint scan cancelled for container {}", container_name);
-                            break;
-                        }
-                        msg = output.next() => {
-                            match msg {
-                                Some(Ok(bollard::container::LogOutput::StdOut { message })) => {
-                                    full_response.push_str(&String::from_utf8_lossy(&message));
-                                }
-                                Some(Ok(bollard::container::LogOutput::StdErr { message })) => {
-                                    // wget outputs headers to stderr with -S flag
-                                    full_response.push_str(&String::from_utf8_lossy(&message));
-                                }
-                                Some(Ok(_)) => {}
-                                Some(Err(e)) => {
-                                    tracing::warn!("Error reading docker exec output: {}", e);
-                                    break;
-                                }
-                                None => break,
-                            }
-                        }
-                    }
-                }
+            let full_response = full_response.trim();
 
-                let full_response = full_response.trim();
+            // Parse response to check status code and extract body
+            if let Some((status, body, headers)) = Self::parse_http_response(full_response) {
+                // Map back to the host-visible endpoint
+                if let Some(host_mappings) =
+                    container_to_host_port_map.get(&endpoint.port_type.number())
+                {
+                    for (host_ip, host_port) in host_mappings {
+                        let host_endpoint = Endpoint {
+                            ip: Some(*host_ip),
+                            port_type: PortType::new_tcp(*host_port),
+                            protocol: endpoint.protocol,
+                            path: endpoint.path.clone(),
+                        };
 
-                // Parse response to check status code and extract body
-                if let Some((status, body, headers)) = Self::parse_http_response(full_response) {
-                    // Map back to the host-visible endpoint
-                    if let Some(host_mappings) =
-                        container_to_host_port_map.get(&endpoint.port_type.number())
-                    {
-                        for (host_ip, host_port) in host_mappings {
-                            let host_endpoint = Endpoint {
-                                ip: Some(*host_ip),
-                                port_type: PortType::new_tcp(*host_port),
-                                protocol: endpoint.protocol,
-                                path: endpoint.path.clone(),
-                            };
-
-                            endpoint_responses.push(EndpointResponse {
-                                endpoint: host_endpoint,
-                                body: body.clone(),
-                                status,
-                                headers: headers.clone(),
-                            });
-                        }
+                        endpoint_responses.push(EndpointResponse {
+                            endpoint: host_endpoint,
+                            body: body.clone(),
+                            status,
+                            headers: headers.clone(),
+                        });
                     }
+                }
 
-                    // Also add the container-internal endpoint
-                    let container_endpoint = Endpoint {
-                        ip: Some(interface.base.ip_address), // Container's IP on the bridge network
-                        port_type: PortType::new_tcp(endpoint.port_type.number()), // Container port, not host port
-                        protocol: endpoint.protocol,
-                        path: endpoint.path.clone(),
-                    };
+                // Also add the container-internal endpoint
+                let container_endpoint = Endpoint {
+                    ip: Some(interface.base.ip_address), // Container's IP on the bridge network
+                    port_type: PortType::new_tcp(endpoint.port_type.number()), // Container port, not host port
+                    protocol: endpoint.protocol,
+                    path: endpoint.path.clone(),
+                };
 
-                    endpoint_responses.push(EndpointResponse {
-                        endpoint: container_endpoint,
-                        body: body.clone(),
-                        status,
-                        headers: headers.clone(),
-                    });
-                }
+                endpoint_responses.push(EndpointResponse {
+                    endpoint: container_endpoint,
+                    body: body.clone(),
+                    status,
+                    headers: headers.clone(),
+                });
             }
         }
 
@@ -1124,7 +1535,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
     fn get_container_interfaces(
         &self,
-        containers: &[(ContainerInspectResponse, ContainerSummary)],
+        containers: &[RuntimeContainer],
         subnets: &[Subnet],
         host_interfaces: &mut [Interface],
     ) -> HashMap<String, Vec<(Interface, Subnet)>> {
@@ -1146,23 +1557,26 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             .collect::<Vec<(Interface, Subnet)>>();
 
         // Collect interfaces from containers
-        containers
-            .iter()
-            .filter_map(|(container, _)| {
-                let host_networking_mode = container
-                    .host_config
-                    .as_ref()
-                    .and_then(|c| c.network_mode.clone())
-                    .unwrap_or_default()
-                    == "host";
-
-                let mut interfaces_and_subnets: Vec<(Interface, Subnet)> = if host_networking_mode {
-                    host_interfaces_and_subnets.clone()
-                }
-                // Containers not in host networking mode
-                else if let Some(network_settings) = &container.network_settings {
-                    if let Some(networks) = &network_settings.networks {
-                        networks
+        let mut containers_interfaces_and_subnets: HashMap<String, Vec<(Interface, Subnet)>> =
+            containers
+                .iter()
+                .map(|c| &c.container)
+                .filter_map(|container| {
+                    let host_networking_mode = container
+                        .host_config
+                        .as_ref()
+                        .and_then(|c| c.network_mode.clone())
+                        .unwrap_or_default()
+                        == "host";
+
+                    let mut interfaces_and_subnets: Vec<(Interface, Subnet)> =
+                        if host_networking_mode {
+                            host_interfaces_and_subnets.clone()
+                        }
+                        // Containers not in host networking mode
+                        else if let Some(network_settings) = &container.network_settings {
+                            if let Some(networks) = &network_settings.networks {
+                                networks
                             .iter()
                             .filter_map(|(network_name, endpoint)| {
                                 // Parse interface if IP
@@ -1205,21 +1619,33 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                                 None
                             })
                             .collect::<Vec<(Interface, Subnet)>>()
-                    } else {
-                        Vec::new()
-                    }
-                } else {
-                    Vec::new()
-                };
+                            } else {
+                                Vec::new()
+                            }
+                        } else {
+                            Vec::new()
+                        };
 
-                // Merge in host interfaces
-                interfaces_and_subnets.extend(host_interfaces_and_subnets.clone());
+                    // Merge in host interfaces
+                    interfaces_and_subnets.extend(host_interfaces_and_subnets.clone());
 
-                container
-                    .id
-                    .as_ref()
-                    .map(|id| (id.clone(), interfaces_and_subnets))
-            })
-            .collect()
+                    container
+                        .id
+                        .as_ref()
+                        .map(|id| (id.clone(), interfaces_and_subnets))
+                })
+                .collect();
+
+        // Containers which joined another container's network namespace have its interfaces
+        for c in containers {
+            if let (Some(id), Some(owner_id)) = (&c.container.id, &c.network_owner_id)
+                && let Some(owner_interfaces) =
+                    containers_interfaces_and_subnets.get(owner_id).cloned()
+            {
+                containers_interfaces_and_subnets.insert(id.clone(), owner_interfaces);
+            }
+        }
+
+        containers_interfaces_and_subnets
     }
 }
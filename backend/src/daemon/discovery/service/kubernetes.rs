@@ -0,0 +1,909 @@
+use anyhow::{Error, Result, anyhow, bail};
+use async_trait::async_trait;
+use cidr::{IpCidr, IpInet};
+use futures::future::try_join_all;
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::kubernetes::{
+    Ingress, IntOrString, KubeService, KubernetesClient, Node, Pod, port_type,
+};
+use crate::daemon::utils::scanner::scan_endpoints;
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::groups::r#impl::base::{Group, GroupBase};
+use crate::server::groups::r#impl::types::GroupType;
+use crate::server::hosts::r#impl::base::{Host, HostBase};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::{Port, PortType};
+use crate::server::services::definitions::kubernetes::Kubernetes;
+use crate::server::services::r#impl::base::{Service, ServiceBase, ServiceMatchBaselineParams};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::services::r#impl::virtualization::{
+    KubernetesVirtualization, ServiceVirtualization,
+};
+use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::storage::traits::StorableEntity;
+use crate::server::shared::types::Color;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::{Subnet, SubnetBase};
+use crate::server::subnets::r#impl::types::SubnetType;
+use crate::server::topology::types::edges::EdgeStyle;
+
+/// Prefix for tags created from namespaces
+const NAMESPACE_TAG_PREFIX: &str = "k8s:";
+
+/// Label set by most ingress controller charts, used to find the controller for an ingress class
+const APP_NAME_LABEL: &str = "app.kubernetes.io/name";
+
+pub struct KubernetesDiscovery {
+    client: OnceLock<KubernetesClient>,
+    cluster: OnceLock<ClusterState>,
+    namespaces: Option<Vec<String>>,
+    host_naming_fallback: HostNamingFallback,
+}
+
+/// Objects read from the API server at the start of a session
+#[derive(Default)]
+pub struct ClusterState {
+    pub nodes: Vec<Node>,
+    pub pods: Vec<Pod>,
+    pub services: Vec<KubeService>,
+    pub ingresses: Vec<Ingress>,
+}
+
+/// A node after its host has been created on the server
+struct DiscoveredNode {
+    host_id: Uuid,
+    /// Kubernetes service on the node, which virtualizes the node's pods
+    kubernetes_service_id: Uuid,
+    /// Interfaces for the node's own addresses (excludes the pod network)
+    interfaces: Vec<Interface>,
+}
+
+/// A pod after its services have been created on the server
+struct DiscoveredPod<'a> {
+    pod: &'a Pod,
+    services: Vec<Service>,
+    /// Port types of the ports referenced by the pod's bindings
+    ports: HashMap<Uuid, PortType>,
+}
+
+impl DiscoveredPod<'_> {
+    fn bindings(&self) -> impl Iterator<Item = &Binding> {
+        self.services.iter().flat_map(|s| s.base.bindings.iter())
+    }
+
+    /// Binding of the pod on a given container port
+    fn binding_on_port(&self, number: u16) -> Option<Uuid> {
+        self.bindings()
+            .find(|b| {
+                b.port_id()
+                    .and_then(|id| self.ports.get(&id))
+                    .is_some_and(|p| p.number() == number)
+            })
+            .map(|b| b.id())
+    }
+
+    /// Binding for the container port a request is forwarded to. Falls back to the first
+    /// binding of the pod if the port can't be resolved.
+    fn binding_for_port(&self, port: Option<u16>) -> Option<Uuid> {
+        port.and_then(|number| self.binding_on_port(number))
+            .or_else(|| self.bindings().next().map(|b| b.id()))
+    }
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<KubernetesDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Kubernetes {
+            namespaces: self.domain.namespaces.clone(),
+            host_naming_fallback: self.domain.host_naming_fallback,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let kubeconfig = self.as_ref().config_store.get_kubeconfig().await?;
+        let client = KubernetesClient::new(kubeconfig).await?;
+        self.domain
+            .client
+            .set(client)
+            .map_err(|_| anyhow!("Failed to set Kubernetes client"))?;
+
+        let cluster = self.fetch_cluster_state().await?;
+        self.domain
+            .cluster
+            .set(cluster)
+            .map_err(|_| anyhow!("Failed to set Kubernetes cluster state"))?;
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.discover_cluster(cancel.clone()).await;
+
+        match &discovery_result {
+            Ok(_) => self.discover_reverse_proxy_routes().await,
+            Err(e) => tracing::warn!(error = %e, "Kubernetes discovery failed"),
+        }
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl KubernetesDiscovery {
+    pub fn new(namespaces: Option<Vec<String>>, host_naming_fallback: HostNamingFallback) -> Self {
+        Self {
+            client: OnceLock::new(),
+            cluster: OnceLock::new(),
+            namespaces,
+            host_naming_fallback,
+        }
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<KubernetesDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<KubernetesDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        // Pod networks are routed by the CNI, there's no gateway device to detect
+        Ok(Vec::new())
+    }
+
+    /// Returns the network's existing subnets plus pod network subnets. Node addresses are
+    /// expected to be on subnets that already exist (from self report or network discovery).
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let cluster = self.cluster()?;
+
+        let existing: Vec<Subnet> = self
+            .as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await?;
+
+        let new_subnet = |cidr: IpCidr, name: String, description: String| {
+            Subnet::new(SubnetBase {
+                cidr,
+                network_id,
+                name,
+                description: Some(description),
+                // Pod networks are container bridges, so they're modelled like docker bridges
+                subnet_type: SubnetType::DockerBridge,
+                source: EntitySource::Discovery {
+                    metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                },
+                tags: Vec::new(),
+            })
+        };
+
+        let mut pod_subnets: Vec<Subnet> = Vec::new();
+
+        for node in &cluster.nodes {
+            for cidr in node.pod_cidrs() {
+                if !existing.iter().any(|s| s.base.cidr == cidr)
+                    && !pod_subnets.iter().any(|s| s.base.cidr == cidr)
+                {
+                    pod_subnets.push(new_subnet(
+                        cidr,
+                        format!("{} pods", node.metadata.name),
+                        format!("Pod network for Kubernetes node {}", node.metadata.name),
+                    ));
+                }
+            }
+        }
+
+        // Some CNIs (eg Calico) don't allocate from the node's podCIDR, so fall back to
+        // a subnet around each uncovered pod IP
+        for pod in cluster.pods.iter().filter(|p| !p.spec.host_network) {
+            if let Some(ip) = pod.ip()
+                && !existing.iter().any(|s| s.base.cidr.contains(&ip))
+                && !pod_subnets.iter().any(|s| s.base.cidr.contains(&ip))
+                && let Some(cidr) = containing_cidr(ip)
+            {
+                pod_subnets.push(new_subnet(
+                    cidr,
+                    format!("Kubernetes pods {}", cidr),
+                    "Pod network inferred from Kubernetes pod addresses".to_string(),
+                ));
+            }
+        }
+
+        let created = try_join_all(pod_subnets.iter().map(|s| self.create_subnet(s))).await?;
+
+        Ok([existing, created].concat())
+    }
+}
+
+impl DiscoveryRunner<KubernetesDiscovery> {
+    fn client(&self) -> Result<&KubernetesClient, Error> {
+        self.domain
+            .client
+            .get()
+            .ok_or_else(|| anyhow!("Kubernetes client unavailable"))
+    }
+
+    fn cluster(&self) -> Result<&ClusterState, Error> {
+        self.domain
+            .cluster
+            .get()
+            .ok_or_else(|| anyhow!("Kubernetes cluster state unavailable"))
+    }
+
+    async fn fetch_cluster_state(&self) -> Result<ClusterState, Error> {
+        let client = self.client()?;
+
+        let nodes = client.list_nodes().await?;
+
+        let namespaces: Vec<Option<&str>> = match &self.domain.namespaces {
+            Some(namespaces) if !namespaces.is_empty() => {
+                namespaces.iter().map(|n| Some(n.as_str())).collect()
+            }
+            _ => vec![None],
+        };
+
+        let mut state = ClusterState {
+            nodes,
+            ..Default::default()
+        };
+
+        for namespace in namespaces {
+            state.pods.extend(client.list_pods(namespace).await?);
+
+            // Services and ingresses only add groups and bindings, so missing RBAC
+            // permissions for them shouldn't fail the whole session
+            match client.list_services(namespace).await {
+                Ok(services) => state.services.extend(services),
+                Err(e) => tracing::warn!(error = %e, "Failed to list Kubernetes services"),
+            }
+            match client.list_ingresses(namespace).await {
+                Ok(ingresses) => state.ingresses.extend(ingresses),
+                Err(e) => tracing::warn!(error = %e, "Failed to list Kubernetes ingresses"),
+            }
+        }
+
+        tracing::info!(
+            nodes = %state.nodes.len(),
+            pods = %state.pods.len(),
+            services = %state.services.len(),
+            ingresses = %state.ingresses.len(),
+            "Read Kubernetes cluster state"
+        );
+
+        Ok(state)
+    }
+
+    async fn discover_cluster(&self, cancel: CancellationToken) -> Result<(), Error> {
+        let cluster = self.cluster()?;
+        let subnets = self.discover_create_subnets().await?;
+
+        self.report_discovery_update(DiscoverySessionUpdate::scanning(0))
+            .await?;
+
+        let mut nodes: HashMap<String, DiscoveredNode> = HashMap::new();
+        for node in &cluster.nodes {
+            if cancel.is_cancelled() {
+                bail!("Kubernetes discovery session was cancelled");
+            }
+
+            match self.create_node_host(node, &subnets).await {
+                Ok(Some(discovered)) => {
+                    nodes.insert(node.metadata.name.clone(), discovered);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    node = %node.metadata.name,
+                    error = %e,
+                    "Failed to create host for Kubernetes node"
+                ),
+            }
+        }
+
+        let pods: Vec<&Pod> = cluster.pods.iter().filter(|p| p.is_running()).collect();
+        let total_pods = pods.len();
+        let processed_count = AtomicUsize::new(0);
+        let concurrent_scans = self.as_ref().config_store.get_concurrent_scans().await?;
+
+        // Stream indices rather than `&Pod`s, so the closure isn't generic over the borrow's
+        // lifetime and the future stays Send
+        let results = stream::iter(0..total_pods)
+            .map(|index| {
+                let pod = pods[index];
+                let cancel = cancel.clone();
+                let nodes = &nodes;
+                let subnets = &subnets;
+                let processed_count = &processed_count;
+
+                async move {
+                    let result = self.process_pod(pod, nodes, subnets, cancel).await;
+
+                    let done = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
+                    let pct = (done * 100 / total_pods.max(1)) as u8;
+                    let _ = self.report_scanning_progress(pct).await;
+
+                    (pod, result)
+                }
+            })
+            .buffer_unordered(concurrent_scans);
+
+        let mut stream_pin = Box::pin(results);
+        let mut discovered_pods = Vec::new();
+
+        while let Some((pod, result)) = stream_pin.next().await {
+            if cancel.is_cancelled() {
+                bail!("Kubernetes discovery session was cancelled");
+            }
+
+            match result {
+                Ok(Some(discovered)) => discovered_pods.push(discovered),
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    pod = %pod.metadata.name,
+                    namespace = %pod.namespace(),
+                    error = %e,
+                    "Pod processing error"
+                ),
+            }
+        }
+
+        self.create_ingress_groups(cluster, &discovered_pods).await;
+        self.tag_namespaces(&discovered_pods).await;
+
+        tracing::info!(
+            total_pods = %total_pods,
+            discovered = %discovered_pods.len(),
+            "Kubernetes scan complete"
+        );
+
+        Ok(())
+    }
+
+    /// Create a host for a node, with a Kubernetes service which pods are virtualized by
+    async fn create_node_host(
+        &self,
+        node: &Node,
+        subnets: &[Subnet],
+    ) -> Result<Option<DiscoveredNode>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let node_ips = node.internal_ips();
+
+        let mut interfaces: Vec<Interface> = node_ips
+            .iter()
+            .filter_map(|ip| {
+                let subnet = subnets.iter().find(|s| s.base.cidr.contains(ip))?;
+                Some(Interface::new(InterfaceBase {
+                    network_id,
+                    host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                    subnet_id: subnet.id,
+                    ip_address: *ip,
+                    mac_address: None,
+                    name: None,
+                    position: 0,
+                }))
+            })
+            .collect();
+
+        if interfaces.is_empty() {
+            tracing::warn!(
+                node = %node.metadata.name,
+                addresses = ?node_ips,
+                "No subnet found for Kubernetes node addresses, skipping node. Run network discovery on the node's subnet first."
+            );
+            return Ok(None);
+        }
+
+        let kubernetes_service_bindings: Vec<Binding> = interfaces
+            .iter()
+            .map(|i| Binding::new_interface_serviceless(i.id))
+            .collect();
+
+        // The node's end of its pod network, so containerized service edges can be drawn
+        for cidr in node.pod_cidrs() {
+            if let Some(subnet) = subnets.iter().find(|s| s.base.cidr == cidr)
+                && let Some(ip_address) = first_host_address(&cidr)
+            {
+                interfaces.push(Interface::new(InterfaceBase {
+                    network_id,
+                    host_id: Uuid::nil(),
+                    subnet_id: subnet.id,
+                    ip_address,
+                    mac_address: None,
+                    name: Some(subnet.base.name.clone()),
+                    position: 0,
+                }));
+            }
+        }
+
+        let info = &node.status.node_info;
+        let description = (!info.kubelet_version.is_empty()).then(|| {
+            format!(
+                "Kubernetes node ({}, kubelet {})",
+                info.os_image, info.kubelet_version
+            )
+        });
+
+        let host = Host::new(HostBase {
+            name: node.metadata.name.clone(),
+            hostname: node.hostname(),
+            network_id,
+            description,
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            virtualization: None,
+            hidden: false,
+            tags: Vec::new(),
+        });
+
+        let kubernetes_service = Service::new(ServiceBase {
+            name: ServiceDefinition::name(&Kubernetes).to_string(),
+            service_definition: Box::new(Kubernetes),
+            bindings: kubernetes_service_bindings,
+            host_id: host.id,
+            tags: Vec::new(),
+            network_id,
+            virtualization: None,
+            source: EntitySource::DiscoveryWithMatch {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                details: MatchDetails::new_certain("Node registered with Kubernetes API server"),
+            },
+            position: 0,
+        });
+
+        let host_response = self
+            .create_host(host, interfaces, vec![], vec![kubernetes_service])
+            .await?;
+
+        let kubernetes_service_id = host_response
+            .services
+            .iter()
+            .find(|s| s.base.service_definition.id() == Kubernetes.id())
+            .map(|s| s.id)
+            .ok_or_else(|| anyhow!("Kubernetes service was not created for node"))?;
+
+        Ok(Some(DiscoveredNode {
+            host_id: host_response.id,
+            kubernetes_service_id,
+            interfaces: host_response
+                .interfaces
+                .into_iter()
+                .filter(|i| node_ips.contains(&i.base.ip_address))
+                .collect(),
+        }))
+    }
+
+    /// Create services for each container in a pod on its node's host
+    async fn process_pod<'a>(
+        &self,
+        pod: &'a Pod,
+        nodes: &HashMap<String, DiscoveredNode>,
+        subnets: &[Subnet],
+        cancel: CancellationToken,
+    ) -> Result<Option<DiscoveredPod<'a>>, Error> {
+        let cluster = self.cluster()?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let Some(node) = pod.spec.node_name.as_ref().and_then(|n| nodes.get(n)) else {
+            return Ok(None);
+        };
+        let Some(pod_ip) = pod.ip() else {
+            return Ok(None);
+        };
+
+        tracing::info!("Processing pod {}/{}", pod.namespace(), pod.metadata.name);
+
+        // Host network pods listen on the node's own interfaces
+        let (interface, subnet) = if pod.spec.host_network {
+            let Some(interface) = node.interfaces.iter().find(|i| i.base.ip_address == pod_ip)
+            else {
+                return Ok(None);
+            };
+            let subnet = subnets
+                .iter()
+                .find(|s| s.id == interface.base.subnet_id)
+                .ok_or_else(|| anyhow!("Subnet for node interface not found"))?;
+            (interface.clone(), subnet)
+        } else {
+            let Some(subnet) = subnets.iter().find(|s| s.base.cidr.contains(&pod_ip)) else {
+                tracing::warn!(ip = %pod_ip, "No matching subnet found for pod");
+                return Ok(None);
+            };
+            let interface = Interface::new(InterfaceBase {
+                network_id,
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                subnet_id: subnet.id,
+                ip_address: pod_ip,
+                mac_address: None,
+                name: Some(pod.metadata.name.clone()),
+                position: 0,
+            });
+            (interface, subnet)
+        };
+
+        let pod_ports: Vec<PortType> = pod
+            .spec
+            .containers
+            .iter()
+            .flat_map(|c| c.ports.iter())
+            .filter_map(|p| port_type(p.protocol.as_deref(), p.container_port))
+            .collect();
+
+        // Endpoints can only be probed if the daemon can route to the pod network,
+        // otherwise services are matched on ports alone
+        let endpoint_responses = if pod_ports.is_empty() {
+            Vec::new()
+        } else {
+            let port_scan_batch_size = self.as_ref().utils.get_optimal_port_batch_size().await?;
+            tokio::spawn(scan_endpoints(
+                pod_ip,
+                cancel.clone(),
+                Some(pod_ports.clone()),
+                None,
+                port_scan_batch_size,
+            ))
+            .await
+            .map_err(|e| anyhow!("Scan task panicked: {}", e))
+            .and_then(|r| r)
+            .unwrap_or_else(|e| {
+                tracing::debug!(ip = %pod_ip, error = %e, "Pod endpoints not reachable from daemon");
+                Vec::new()
+            })
+        };
+
+        // NodePort and LoadBalancer services expose container ports on every node
+        let node_ports: Vec<(u16, PortType)> = cluster
+            .services
+            .iter()
+            .filter(|s| s.selects(pod))
+            .flat_map(|s| {
+                s.spec.ports.iter().filter_map(|p| {
+                    let node_port = port_type(p.protocol.as_deref(), p.node_port?)?;
+                    Some((s.target_port(p, pod)?, node_port))
+                })
+            })
+            .collect();
+
+        let mut discovered = DiscoveredPod {
+            pod,
+            services: Vec::new(),
+            ports: HashMap::new(),
+        };
+
+        for container in &pod.spec.containers {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Discovery was cancelled"));
+            }
+
+            let container_ports: Vec<PortType> = container
+                .ports
+                .iter()
+                .filter_map(|p| port_type(p.protocol.as_deref(), p.container_port))
+                .collect();
+
+            let virtualization = Some(ServiceVirtualization::Kubernetes(
+                KubernetesVirtualization {
+                    namespace: pod.namespace().to_string(),
+                    pod_name: Some(pod.metadata.name.clone()),
+                    workload_name: pod.workload_name(),
+                    container_name: Some(container.name.clone()),
+                    node_name: pod.spec.node_name.clone(),
+                    service_id: node.kubernetes_service_id,
+                },
+            ));
+
+            let Some((mut host, mut interfaces, mut ports, mut services)) = self
+                .process_host(
+                    ServiceMatchBaselineParams {
+                        subnet,
+                        interface: &interface,
+                        all_ports: &container_ports,
+                        endpoint_responses: &endpoint_responses,
+                        virtualization: &virtualization,
+                    },
+                    None,
+                    self.domain.host_naming_fallback,
+                )
+                .await?
+            else {
+                continue;
+            };
+
+            host.id = node.host_id;
+
+            for (target_port, node_port) in &node_ports {
+                if !container_ports.iter().any(|p| p.number() == *target_port) {
+                    continue;
+                }
+
+                // Bind the node port to the service listening on the target port
+                let service = services.iter_mut().find(|s| {
+                    s.base.bindings.iter().any(|b| {
+                        b.port_id()
+                            .and_then(|id| ports.iter().find(|p| p.id == id))
+                            .is_some_and(|p| p.base.port_type.number() == *target_port)
+                    })
+                });
+
+                if let Some(service) = service {
+                    let port = Port::new_hostless(*node_port);
+                    for node_interface in &node.interfaces {
+                        service.base.bindings.push(Binding::new_port_serviceless(
+                            port.id,
+                            Some(node_interface.id),
+                        ));
+                        if !interfaces.contains(node_interface) {
+                            interfaces.push(node_interface.clone());
+                        }
+                    }
+                    ports.push(port);
+                }
+            }
+
+            let host_response = self.create_host(host, interfaces, ports, services).await?;
+
+            discovered
+                .services
+                .extend(host_response.services.into_iter().filter(
+                    |s| match &s.base.virtualization {
+                        Some(ServiceVirtualization::Kubernetes(kv)) => {
+                            kv.namespace == pod.namespace()
+                                && kv.pod_name.as_ref() == Some(&pod.metadata.name)
+                                && kv.container_name.as_ref() == Some(&container.name)
+                        }
+                        _ => false,
+                    },
+                ));
+            discovered.ports.extend(
+                host_response
+                    .ports
+                    .into_iter()
+                    .map(|p| (p.id, p.base.port_type)),
+            );
+        }
+
+        Ok(Some(discovered))
+    }
+
+    /// Create a RequestPath group for each ingress route: controller -> backend pods
+    async fn create_ingress_groups(&self, cluster: &ClusterState, pods: &[DiscoveredPod<'_>]) {
+        for ingress in &cluster.ingresses {
+            let controller_binding = self.ingress_controller_binding(cluster, ingress, pods);
+
+            for route in ingress.routes() {
+                let Some(backend) = cluster.services.iter().find(|s| {
+                    s.metadata.name == route.service_name && s.namespace() == ingress.namespace()
+                }) else {
+                    continue;
+                };
+
+                let service_port = match &route.service_port {
+                    Some(IntOrString::Int(number)) => {
+                        backend.spec.ports.iter().find(|p| p.port == *number)
+                    }
+                    Some(IntOrString::String(name)) => backend.port_named(name),
+                    None => backend.spec.ports.first(),
+                };
+
+                let binding_ids: Vec<Uuid> = controller_binding
+                    .into_iter()
+                    .chain(
+                        pods.iter()
+                            .filter(|p| backend.selects(p.pod))
+                            .filter_map(|p| {
+                                let target =
+                                    service_port.and_then(|sp| backend.target_port(sp, p.pod));
+                                p.binding_for_port(target)
+                            }),
+                    )
+                    .collect();
+
+                // A path needs at least two hops to be drawn
+                if binding_ids.len() < 2 {
+                    continue;
+                }
+
+                let host = route.host.as_deref().unwrap_or("*");
+                let path = route.path.as_deref().unwrap_or("/");
+
+                let name = format!("{}{}", host, path);
+                let identity = format!(
+                    "k8s:ingress:{}/{}:{}",
+                    ingress.namespace(),
+                    ingress.metadata.name,
+                    name
+                );
+                let description = format!(
+                    "Ingress {}/{} routes {}{} to service {}",
+                    ingress.namespace(),
+                    ingress.metadata.name,
+                    host,
+                    path,
+                    backend.metadata.name
+                );
+
+                match self
+                    .new_group(
+                        name,
+                        identity,
+                        description,
+                        GroupType::RequestPath,
+                        binding_ids,
+                    )
+                    .await
+                {
+                    Ok(group) => {
+                        if let Ok(created) = self.create_group(&group).await {
+                            let tag_name =
+                                format!("{}{}", NAMESPACE_TAG_PREFIX, ingress.namespace());
+                            if let Err(e) = self
+                                .apply_tag(&tag_name, EntityDiscriminants::Group, vec![created.id])
+                                .await
+                            {
+                                tracing::warn!(error = %e, "Failed to tag ingress group");
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Failed to build ingress group"),
+                }
+            }
+        }
+    }
+
+    /// Find the binding of the ingress controller serving an ingress. The controller is
+    /// identified by the load balancer address it publishes to the ingress status, or by its
+    /// pods' app name matching the ingress class.
+    fn ingress_controller_binding(
+        &self,
+        cluster: &ClusterState,
+        ingress: &Ingress,
+        pods: &[DiscoveredPod<'_>],
+    ) -> Option<Uuid> {
+        let addresses = ingress.status.load_balancer.addresses();
+
+        let controller_service = cluster.services.iter().find(|s| {
+            s.status
+                .load_balancer
+                .addresses()
+                .iter()
+                .any(|a| addresses.contains(a))
+        });
+
+        let controller_pods: Vec<&DiscoveredPod> =
+            match (controller_service, &ingress.spec.ingress_class_name) {
+                (Some(service), _) => pods.iter().filter(|p| service.selects(p.pod)).collect(),
+                (None, Some(class)) => pods
+                    .iter()
+                    .filter(|p| {
+                        p.pod
+                            .metadata
+                            .labels
+                            .get(APP_NAME_LABEL)
+                            .is_some_and(|name| name.contains(class.as_str()))
+                    })
+                    .collect(),
+                (None, None) => Vec::new(),
+            };
+
+        controller_pods.iter().find_map(|p| {
+            p.binding_on_port(443)
+                .or_else(|| p.binding_on_port(80))
+                .or_else(|| p.binding_for_port(None))
+        })
+    }
+
+    /// Tag each pod's services with its namespace
+    async fn tag_namespaces(&self, pods: &[DiscoveredPod<'_>]) {
+        let mut services_by_namespace: HashMap<&str, Vec<Uuid>> = HashMap::new();
+        for pod in pods {
+            services_by_namespace
+                .entry(pod.pod.namespace())
+                .or_default()
+                .extend(pod.services.iter().map(|s| s.id));
+        }
+
+        for (namespace, service_ids) in services_by_namespace {
+            if service_ids.is_empty() {
+                continue;
+            }
+            let tag_name = format!("{}{}", NAMESPACE_TAG_PREFIX, namespace);
+            if let Err(e) = self
+                .apply_tag(&tag_name, EntityDiscriminants::Service, service_ids)
+                .await
+            {
+                tracing::warn!(namespace = %namespace, error = %e, "Failed to tag namespace services");
+            }
+        }
+    }
+
+    async fn new_group(
+        &self,
+        name: String,
+        identity: String,
+        description: String,
+        group_type: GroupType,
+        binding_ids: Vec<Uuid>,
+    ) -> Result<Group, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        Ok(Group::new(GroupBase {
+            name,
+            network_id,
+            description: Some(description),
+            group_type,
+            binding_ids,
+            source: EntitySource::Discovery {
+                metadata: vec![
+                    DiscoveryMetadata::new(self.discovery_type(), daemon_id)
+                        .with_identity(identity),
+                ],
+            },
+            color: Color::Blue,
+            edge_style: EdgeStyle::default(),
+            tags: Vec::new(),
+        }))
+    }
+}
+
+/// A /24 (IPv4) or /64 (IPv6) around an address, for pod networks without a known CIDR
+fn containing_cidr(ip: IpAddr) -> Option<IpCidr> {
+    let len = if ip.is_ipv4() { 24 } else { 64 };
+    IpInet::new(ip, len).ok().map(|inet| inet.network())
+}
+
+/// First usable address of a CIDR, which is where CNI bridges conventionally live
+fn first_host_address(cidr: &IpCidr) -> Option<IpAddr> {
+    match cidr.first_address() {
+        IpAddr::V4(addr) => {
+            let next = u32::from(addr).checked_add(1)?;
+            Some(IpAddr::V4(next.into())).filter(|ip| cidr.contains(ip))
+        }
+        IpAddr::V6(addr) => {
+            let next = u128::from(addr).checked_add(1)?;
+            Some(IpAddr::V6(next.into())).filter(|ip| cidr.contains(ip))
+        }
+    }
+}
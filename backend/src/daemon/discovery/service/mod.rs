@@ -1,4 +1,9 @@
 pub mod base;
 pub mod docker;
+pub mod kubernetes;
 pub mod network;
+pub mod proxmox;
 pub mod self_report;
+pub mod ssh_inventory;
+pub mod traceroute;
+pub mod winrm;
@@ -101,6 +101,10 @@ impl RunsDiscovery for DiscoveryRunner<NetworkScanDiscovery> {
             .await
             .map(|_| ());
 
+        if discovery_result.is_ok() {
+            self.discover_reverse_proxy_routes().await;
+        }
+
         self.finish_discovery(discovery_result, cancel.clone())
             .await?;
 
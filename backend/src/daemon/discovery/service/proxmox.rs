@@ -0,0 +1,422 @@
+use anyhow::{Error, Result, anyhow, bail};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use mac_address::MacAddress;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
+use crate::daemon::utils::proxmox::{ClusterNode, Guest, ProxmoxClient};
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::credentials::r#impl::base::CredentialSecret;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::DiscoveryType;
+use crate::server::hosts::r#impl::api::DiscoveryHostRequest;
+use crate::server::hosts::r#impl::base::{Host, HostBase};
+use crate::server::hosts::r#impl::virtualization::{
+    HostVirtualization, ProxmoxGuestType, ProxmoxVirtualization,
+};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::services::definitions::proxmox::Proxmox;
+use crate::server::services::r#impl::base::{Service, ServiceBase};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::shared::storage::traits::StorableEntity;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::Subnet;
+
+pub struct ProxmoxDiscovery {
+    client: OnceLock<ProxmoxClient>,
+    api_url: String,
+    skip_tls_verify: bool,
+}
+
+impl ProxmoxDiscovery {
+    pub fn new(api_url: String, skip_tls_verify: bool) -> Self {
+        Self {
+            client: OnceLock::new(),
+            api_url,
+            skip_tls_verify,
+        }
+    }
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<ProxmoxDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Proxmox {
+            api_url: self.domain.api_url.clone(),
+            skip_tls_verify: self.domain.skip_tls_verify,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        // Prefer an API token credential attached to the discovery over the daemon's own
+        let credential_token = self
+            .fetch_credentials(&request)
+            .await?
+            .into_iter()
+            .find_map(|c| match c.secret {
+                CredentialSecret::ApiToken { token } => Some(token),
+                _ => None,
+            });
+
+        let token = match credential_token {
+            Some(token) => token,
+            None => self
+                .as_ref()
+                .config_store
+                .get_proxmox_token()
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Proxmox API token not configured. Attach an API token credential to the discovery or set --proxmox-token on the daemon."
+                    )
+                })?,
+        };
+        let client = ProxmoxClient::new(&self.domain.api_url, &token, self.domain.skip_tls_verify)?;
+        self.domain
+            .client
+            .set(client)
+            .map_err(|_| anyhow!("Failed to set Proxmox client"))?;
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.discover_cluster(cancel.clone()).await;
+
+        if let Err(e) = &discovery_result {
+            tracing::warn!(error = %e, "Proxmox discovery failed");
+        }
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<ProxmoxDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<ProxmoxDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// Nodes and guests are attached to the network's existing subnets. Guest bridges are
+    /// ordinary LAN segments, which network discovery or self report will already have created.
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<ProxmoxDiscovery> {
+    fn client(&self) -> Result<&ProxmoxClient, Error> {
+        self.domain
+            .client
+            .get()
+            .ok_or_else(|| anyhow!("Proxmox client unavailable"))
+    }
+
+    async fn discover_cluster(&self, cancel: CancellationToken) -> Result<(), Error> {
+        let client = self.client()?;
+        let subnets = self.discover_create_subnets().await?;
+
+        self.report_discovery_update(DiscoverySessionUpdate::scanning(0))
+            .await?;
+
+        let nodes = client.list_nodes().await?;
+
+        // Proxmox service on each node's host, which the node's guests are virtualized by
+        let mut node_services: HashMap<String, Uuid> = HashMap::new();
+        let mut guests: Vec<Guest> = Vec::new();
+
+        for node in &nodes {
+            if cancel.is_cancelled() {
+                bail!("Proxmox discovery session was cancelled");
+            }
+
+            if !node.online {
+                tracing::warn!(node = %node.name, "Proxmox node is offline, skipping");
+                continue;
+            }
+
+            match self.create_node_host(node, &subnets).await {
+                Ok(Some(service_id)) => {
+                    node_services.insert(node.name.clone(), service_id);
+                }
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!(
+                        node = %node.name,
+                        error = %e,
+                        "Failed to create host for Proxmox node"
+                    );
+                    continue;
+                }
+            }
+
+            match client.list_guests(&node.name).await {
+                Ok(node_guests) => guests.extend(node_guests),
+                Err(e) => {
+                    tracing::warn!(node = %node.name, error = %e, "Failed to list Proxmox guests")
+                }
+            }
+        }
+
+        let total_guests = guests.len();
+        let processed_count = AtomicUsize::new(0);
+        let concurrent_scans = self.as_ref().config_store.get_concurrent_scans().await?;
+
+        let results = stream::iter(guests)
+            .map(|guest| {
+                let node_services = &node_services;
+                let subnets = &subnets;
+                let processed_count = &processed_count;
+
+                async move {
+                    let result = match node_services.get(&guest.node) {
+                        Some(service_id) => {
+                            self.create_guest_host(&guest, *service_id, subnets).await
+                        }
+                        None => Err(anyhow!("No Proxmox service for node {}", guest.node)),
+                    };
+
+                    let done = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
+                    let pct = (done * 100 / total_guests.max(1)) as u8;
+                    let _ = self.report_scanning_progress(pct).await;
+
+                    (guest, result)
+                }
+            })
+            .buffer_unordered(concurrent_scans);
+
+        let mut stream_pin = Box::pin(results);
+        let mut discovered = 0;
+
+        while let Some((guest, result)) = stream_pin.next().await {
+            if cancel.is_cancelled() {
+                bail!("Proxmox discovery session was cancelled");
+            }
+
+            match result {
+                Ok(()) => discovered += 1,
+                Err(e) => tracing::warn!(
+                    vmid = %guest.vmid,
+                    node = %guest.node,
+                    error = %e,
+                    "Proxmox guest processing error"
+                ),
+            }
+        }
+
+        tracing::info!(
+            nodes = %node_services.len(),
+            total_guests = %total_guests,
+            discovered = %discovered,
+            "Proxmox scan complete"
+        );
+
+        Ok(())
+    }
+
+    /// Create a host for a node with a Proxmox service, returning the service's ID
+    async fn create_node_host(
+        &self,
+        node: &ClusterNode,
+        subnets: &[Subnet],
+    ) -> Result<Option<Uuid>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let Some((ip_address, subnet)) = node
+            .ip
+            .and_then(|ip| Some((ip, subnets.iter().find(|s| s.base.cidr.contains(&ip))?)))
+        else {
+            tracing::warn!(
+                node = %node.name,
+                address = ?node.ip,
+                "No subnet found for Proxmox node address, skipping node. Run network discovery on the node's subnet first."
+            );
+            return Ok(None);
+        };
+
+        let interface = Interface::new(InterfaceBase {
+            network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            subnet_id: subnet.id,
+            ip_address,
+            mac_address: None,
+            name: None,
+            position: 0,
+        });
+
+        let host = Host::new(HostBase {
+            name: node.name.clone(),
+            hostname: None,
+            network_id,
+            description: Some("Proxmox VE node".to_string()),
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            virtualization: None,
+            hidden: false,
+            tags: Vec::new(),
+        });
+
+        let proxmox_service = Service::new(ServiceBase {
+            name: ServiceDefinition::name(&Proxmox).to_string(),
+            service_definition: Box::new(Proxmox),
+            bindings: vec![Binding::new_interface_serviceless(interface.id)],
+            host_id: host.id,
+            tags: Vec::new(),
+            network_id,
+            virtualization: None,
+            source: EntitySource::DiscoveryWithMatch {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                details: MatchDetails::new_certain("Node reported by Proxmox VE cluster API"),
+            },
+            position: 0,
+        });
+
+        let host_response = self
+            .create_host(host, vec![interface], vec![], vec![proxmox_service])
+            .await?;
+
+        host_response
+            .services
+            .iter()
+            .find(|s| s.base.service_definition.id() == Proxmox.id())
+            .map(|s| Some(s.id))
+            .ok_or_else(|| anyhow!("Proxmox service was not created for node"))
+    }
+
+    /// Create a host for a VM or container. Guests are matched to existing hosts by their
+    /// config MACs, so stopped guests and guests without the QEMU agent still link up with
+    /// hosts found by network discovery.
+    async fn create_guest_host(
+        &self,
+        guest: &Guest,
+        proxmox_service_id: Uuid,
+        subnets: &[Subnet],
+    ) -> Result<(), Error> {
+        let client = self.client()?;
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let nics = client.guest_nics(guest).await?;
+
+        // Stopped guests and guests without the QEMU agent have no reported addresses
+        let addresses = client.guest_addresses(guest).await.unwrap_or_else(|e| {
+            tracing::debug!(
+                vmid = %guest.vmid,
+                error = %e,
+                "No addresses reported by Proxmox guest"
+            );
+            Vec::new()
+        });
+
+        let mut candidates: Vec<(IpAddr, Option<MacAddress>, Option<String>)> = addresses
+            .into_iter()
+            .map(|a| {
+                let mac_address = a.mac_address.or_else(|| {
+                    nics.iter()
+                        .find(|n| n.name.is_some() && n.name == a.name)
+                        .map(|n| n.mac_address)
+                });
+                (a.ip, mac_address, a.name)
+            })
+            .collect();
+
+        for nic in &nics {
+            if let Some(ip) = nic.static_ip
+                && !candidates.iter().any(|(existing, _, _)| *existing == ip)
+            {
+                candidates.push((ip, Some(nic.mac_address), nic.name.clone()));
+            }
+        }
+
+        let interfaces: Vec<Interface> = candidates
+            .into_iter()
+            .filter_map(|(ip_address, mac_address, name)| {
+                let subnet = subnets.iter().find(|s| s.base.cidr.contains(&ip_address))?;
+                Some(Interface::new(InterfaceBase {
+                    network_id,
+                    host_id: Uuid::nil(),
+                    subnet_id: subnet.id,
+                    ip_address,
+                    mac_address,
+                    name,
+                    position: 0,
+                }))
+            })
+            .collect();
+
+        let kind = match guest.guest_type {
+            ProxmoxGuestType::Qemu => "virtual machine",
+            ProxmoxGuestType::Lxc => "container",
+        };
+
+        let host = Host::new(HostBase {
+            name: guest
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("{} {}", guest.node, guest.vmid)),
+            hostname: None,
+            network_id,
+            description: Some(format!(
+                "Proxmox VE {} {} on {}",
+                kind, guest.vmid, guest.node
+            )),
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            virtualization: Some(HostVirtualization::Proxmox(ProxmoxVirtualization {
+                vm_name: guest.name.clone(),
+                vm_id: Some(guest.vmid.clone()),
+                service_id: proxmox_service_id,
+                node: Some(guest.node.clone()),
+                guest_type: Some(guest.guest_type),
+                status: Some(guest.status),
+            })),
+            hidden: false,
+            tags: Vec::new(),
+        });
+
+        self.create_host_from_request(DiscoveryHostRequest {
+            host,
+            interfaces,
+            ports: Vec::new(),
+            services: Vec::new(),
+            mac_addresses: nics.iter().map(|n| n.mac_address).collect(),
+        })
+        .await?;
+
+        Ok(())
+    }
+}
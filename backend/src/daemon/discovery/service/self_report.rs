@@ -114,47 +114,54 @@ impl RunsDiscovery for DiscoveryRunner<SelfReportDiscovery> {
         let docker_proxy = self.as_ref().config_store.get_docker_proxy().await;
         let docker_proxy_ssl_info = self.as_ref().config_store.get_docker_proxy_ssl_info().await;
 
-        let docker_client = self
+        let container_clients = self
             .as_ref()
             .utils
-            .new_local_docker_client(docker_proxy, docker_proxy_ssl_info)
+            .new_local_container_clients(docker_proxy, docker_proxy_ssl_info)
             .await;
 
-        let (docker_cidrs, has_docker_socket) = if let Ok(docker_client) = docker_client {
-            tracing::debug!("Docker client available, fetching Docker networks");
+        let has_docker_socket = !container_clients.is_empty();
+        let mut docker_cidrs: Vec<IpCidr> = Vec::new();
+
+        if container_clients.is_empty() {
+            tracing::debug!("Docker socket not available - skipping Docker subnet detection");
+        }
+
+        for client in &container_clients {
+            tracing::debug!(
+                client = %client.description(),
+                "Container runtime available, fetching networks"
+            );
             match self
                 .as_ref()
                 .utils
                 .get_subnets_from_docker_networks(
                     daemon_id,
                     network_id,
-                    &docker_client,
+                    client,
                     self.discovery_type(),
                 )
                 .await
             {
                 Ok(docker_subnets) => {
-                    let docker_cidrs: Vec<IpCidr> =
-                        docker_subnets.iter().map(|s| s.base.cidr).collect();
+                    let cidrs: Vec<IpCidr> = docker_subnets.iter().map(|s| s.base.cidr).collect();
                     tracing::debug!(
-                        docker_subnet_count = docker_cidrs.len(),
-                        cidrs = ?docker_cidrs.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+                        docker_subnet_count = cidrs.len(),
+                        cidrs = ?cidrs.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
                         "Docker subnets detected (will be excluded from self-report)"
                     );
-                    (docker_cidrs, true)
+                    docker_cidrs.extend(cidrs);
                 }
                 Err(e) => {
+                    // Still has a container runtime, just couldn't list networks
                     tracing::warn!(
+                        client = %client.description(),
                         error = %e,
                         "Failed to get Docker networks - proceeding without Docker subnet filtering"
                     );
-                    (Vec::new(), true) // Still has Docker socket, just couldn't list networks
                 }
             }
-        } else {
-            tracing::debug!("Docker socket not available - skipping Docker subnet detection");
-            (Vec::new(), false)
-        };
+        }
 
         // Filter out docker bridge subnets, those are handled in docker discovery
         let subnets_to_create: Vec<Subnet> = subnets
@@ -0,0 +1,304 @@
+use anyhow::{Error, Result, anyhow, bail};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
+use crate::daemon::utils::ssh::{HostInventory, SshSession};
+use crate::server::credentials::r#impl::base::CredentialSecret;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::PortType;
+use crate::server::services::r#impl::base::ServiceMatchBaselineParams;
+use crate::server::subnets::r#impl::base::Subnet;
+
+pub struct SshInventoryDiscovery {
+    targets: Vec<IpAddr>,
+    port: u16,
+}
+
+impl SshInventoryDiscovery {
+    pub fn new(targets: Vec<IpAddr>, port: u16) -> Self {
+        Self { targets, port }
+    }
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<SshInventoryDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::SshInventory {
+            targets: self.domain.targets.clone(),
+            port: self.domain.port,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let secrets: Vec<CredentialSecret> = self
+            .fetch_credentials(&request)
+            .await?
+            .into_iter()
+            .map(|c| c.secret)
+            .filter(|s| {
+                matches!(
+                    s,
+                    CredentialSecret::SshKey { .. } | CredentialSecret::UsernamePassword { .. }
+                )
+            })
+            .collect();
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.discover_targets(&secrets, cancel.clone()).await;
+
+        if let Err(e) = &discovery_result {
+            tracing::warn!(error = %e, "SSH inventory discovery failed");
+        }
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<SshInventoryDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<SshInventoryDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// Inventoried hosts are attached to the network's existing subnets, like Proxmox guests
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<SshInventoryDiscovery> {
+    async fn discover_targets(
+        &self,
+        secrets: &[CredentialSecret],
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        if secrets.is_empty() {
+            bail!(
+                "No SSH key or username/password credential is attached to this discovery, or credential storage is not configured on the server"
+            );
+        }
+
+        let subnets = self.discover_create_subnets().await?;
+
+        self.report_discovery_update(DiscoverySessionUpdate::scanning(0))
+            .await?;
+
+        let total_targets = self.domain.targets.len();
+        let processed_count = AtomicUsize::new(0);
+        let concurrent_scans = self.as_ref().config_store.get_concurrent_scans().await?;
+
+        let results = stream::iter(self.domain.targets.clone())
+            .map(|ip| {
+                let subnets = &subnets;
+                let processed_count = &processed_count;
+
+                async move {
+                    let result = self.inventory_target(ip, secrets, subnets).await;
+
+                    let done = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
+                    let pct = (done * 100 / total_targets.max(1)) as u8;
+                    let _ = self.report_scanning_progress(pct).await;
+
+                    (ip, result)
+                }
+            })
+            .buffer_unordered(concurrent_scans);
+
+        let mut stream_pin = Box::pin(results);
+        let mut discovered = 0;
+
+        while let Some((ip, result)) = stream_pin.next().await {
+            if cancel.is_cancelled() {
+                bail!("SSH inventory discovery session was cancelled");
+            }
+
+            match result {
+                Ok(true) => discovered += 1,
+                Ok(false) => {}
+                Err(e) => tracing::warn!(ip = %ip, error = %e, "SSH inventory failed for host"),
+            }
+        }
+
+        tracing::info!(
+            targets = %total_targets,
+            discovered = %discovered,
+            "SSH inventory complete"
+        );
+
+        Ok(())
+    }
+
+    /// Log into a host, collect its inventory and create it. Returns false when the host has
+    /// no address on a known subnet.
+    async fn inventory_target(
+        &self,
+        ip: IpAddr,
+        secrets: &[CredentialSecret],
+        subnets: &[Subnet],
+    ) -> Result<bool, Error> {
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let session = SshSession::connect(ip, self.domain.port, secrets).await?;
+        let inventory = session.collect_inventory().await;
+        session.close().await;
+        let inventory = inventory?;
+
+        let mut interfaces = build_interfaces(&inventory, subnets, network_id);
+
+        // The interface we logged in through comes first so service bindings land on it
+        let Some(primary_position) = interfaces
+            .iter()
+            .position(|i| i.base.ip_address == ip)
+            .or_else(|| (!interfaces.is_empty()).then_some(0))
+        else {
+            tracing::warn!(
+                ip = %ip,
+                "No subnet found for any of the host's addresses, skipping host. Run network discovery on the host's subnet first."
+            );
+            return Ok(false);
+        };
+        let primary = interfaces.remove(primary_position);
+        let subnet = subnets
+            .iter()
+            .find(|s| s.id == primary.base.subnet_id)
+            .ok_or_else(|| anyhow!("Subnet for interface {} not found", primary.id))?;
+
+        // Listening sockets include loopback-only and firewalled services that a network
+        // scan cannot see
+        let mut open_ports: Vec<PortType> = Vec::new();
+        for socket in &inventory.sockets {
+            let port = PortType::new(socket.port, socket.protocol);
+            if !open_ports.contains(&port) {
+                open_ports.push(port);
+            }
+        }
+
+        let local_only = inventory
+            .sockets
+            .iter()
+            .filter(|s| s.is_local_only())
+            .count();
+
+        let Some((mut host, mut host_interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface: &primary,
+                    all_ports: &open_ports,
+                    endpoint_responses: &Vec::new(),
+                    virtualization: &None,
+                },
+                inventory.hostname.clone(),
+                HostNamingFallback::BestService,
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        host.base.description = describe_host(&inventory);
+        host_interfaces.extend(interfaces);
+        for (position, interface) in host_interfaces.iter_mut().enumerate() {
+            interface.base.position = position as i32;
+        }
+
+        let services_count = services.len();
+        self.create_host(host, host_interfaces, ports, services)
+            .await?;
+
+        tracing::info!(
+            ip = %ip,
+            services = services_count,
+            local_only_sockets = local_only,
+            containers = inventory.containers.len(),
+            "Host inventoried"
+        );
+
+        Ok(true)
+    }
+}
+
+/// Interfaces for every address that falls in a known subnet. Loopback and link-local
+/// addresses never do.
+fn build_interfaces(
+    inventory: &HostInventory,
+    subnets: &[Subnet],
+    network_id: Uuid,
+) -> Vec<Interface> {
+    let mut interfaces: Vec<Interface> = Vec::new();
+    let mut seen: HashSet<IpAddr> = HashSet::new();
+
+    for address in &inventory.addresses {
+        if !seen.insert(address.ip) {
+            continue;
+        }
+        let Some(subnet) = subnets.iter().find(|s| s.base.cidr.contains(&address.ip)) else {
+            continue;
+        };
+
+        interfaces.push(Interface::new(InterfaceBase {
+            network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            subnet_id: subnet.id,
+            ip_address: address.ip,
+            mac_address: inventory.mac_addresses.get(&address.name).copied(),
+            name: Some(address.name.clone()),
+            position: 0,
+        }));
+    }
+
+    interfaces
+}
+
+fn describe_host(inventory: &HostInventory) -> Option<String> {
+    let os = inventory.os_release.as_ref().and_then(|r| r.display_name());
+
+    let containers = match inventory.containers.len() {
+        0 => None,
+        n => Some(format!(
+            "{} running container{}: {}",
+            n,
+            if n == 1 { "" } else { "s" },
+            inventory
+                .containers
+                .iter()
+                .map(|c| format!("{} ({})", c.name, c.image))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    };
+
+    match (os, containers) {
+        (Some(os), Some(containers)) => Some(format!("{}. {}", os, containers)),
+        (os, containers) => os.or(containers),
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::{Error, Result, anyhow, bail};
+use async_trait::async_trait;
+use cidr::IpCidr;
+use futures::stream::{self, StreamExt};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::traceroute;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::DiscoveryType;
+use crate::server::shared::storage::traits::StorableEntity;
+use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::subnets::r#impl::types::SubnetType;
+use crate::server::traceroutes::r#impl::base::{Traceroute, TracerouteBase, TracerouteProtocol};
+
+/// Traces share one raw socket each, and most of their time is spent waiting on silent hops
+const MAX_CONCURRENT_TRACES: usize = 8;
+
+pub struct TracerouteDiscovery {
+    external_targets: Vec<IpAddr>,
+    protocol: TracerouteProtocol,
+    max_hops: u8,
+}
+
+impl TracerouteDiscovery {
+    pub fn new(external_targets: Vec<IpAddr>, protocol: TracerouteProtocol, max_hops: u8) -> Self {
+        Self {
+            external_targets,
+            protocol,
+            max_hops,
+        }
+    }
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<TracerouteDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Traceroute {
+            external_targets: self.domain.external_targets.clone(),
+            protocol: self.domain.protocol,
+            max_hops: self.domain.max_hops,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.trace_targets(cancel.clone()).await;
+
+        if let Err(e) = &discovery_result {
+            tracing::warn!(error = %e, "Traceroute discovery failed");
+        }
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<TracerouteDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<TracerouteDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Gateways are traced on the subnets the network already knows about
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<TracerouteDiscovery> {
+    async fn trace_targets(&self, cancel: CancellationToken) -> Result<(), Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let subnets = self.discover_create_subnets().await?;
+        let gateway_ips = self.get_gateway_ips().await.unwrap_or_default();
+
+        let mut targets: Vec<(Option<Uuid>, Ipv4Addr)> = subnets
+            .iter()
+            .filter_map(|s| subnet_gateway(s, &gateway_ips).map(|ip| (Some(s.id), ip)))
+            .collect();
+        for target in &self.domain.external_targets {
+            match target {
+                IpAddr::V4(ip) => targets.push((None, *ip)),
+                IpAddr::V6(_) => {
+                    tracing::warn!(target = %target, "Skipping IPv6 traceroute target")
+                }
+            }
+        }
+
+        if targets.is_empty() {
+            bail!("No IPv4 subnets or external targets to trace");
+        }
+
+        self.report_discovery_update(DiscoverySessionUpdate::scanning(0))
+            .await?;
+
+        let total_targets = targets.len();
+        let processed_count = AtomicUsize::new(0);
+        let concurrent_scans = self
+            .as_ref()
+            .config_store
+            .get_concurrent_scans()
+            .await?
+            .min(MAX_CONCURRENT_TRACES);
+
+        let results = stream::iter(targets)
+            .map(|(subnet_id, ip)| {
+                let processed_count = &processed_count;
+
+                async move {
+                    let result =
+                        traceroute::trace(ip, self.domain.protocol, self.domain.max_hops).await;
+
+                    let done = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
+                    let pct = (done * 100 / total_targets) as u8;
+                    let _ = self.report_scanning_progress(pct).await;
+
+                    (subnet_id, ip, result)
+                }
+            })
+            .buffer_unordered(concurrent_scans.max(1));
+
+        let mut stream_pin = Box::pin(results);
+        let mut traced = 0;
+        let mut last_error = None;
+
+        while let Some((subnet_id, ip, result)) = stream_pin.next().await {
+            if cancel.is_cancelled() {
+                bail!("Traceroute discovery session was cancelled");
+            }
+
+            let trace = match result {
+                Ok(trace) => trace,
+                Err(e) => {
+                    tracing::warn!(ip = %ip, error = %e, "Traceroute failed");
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+
+            let traceroute = Traceroute::new(TracerouteBase {
+                network_id,
+                daemon_id,
+                subnet_id,
+                target: IpAddr::V4(ip),
+                protocol: self.domain.protocol,
+                hops: trace.hops,
+                reached: trace.reached,
+            });
+
+            match self.create_traceroute(&traceroute).await {
+                Ok(_) => traced += 1,
+                Err(e) => tracing::warn!(ip = %ip, error = %e, "Failed to save traceroute"),
+            }
+        }
+
+        // Every trace failing usually means raw sockets aren't available at all
+        if traced == 0
+            && let Some(e) = last_error
+        {
+            return Err(e);
+        }
+
+        tracing::info!(
+            targets = %total_targets,
+            traced = %traced,
+            "Traceroute discovery complete"
+        );
+
+        Ok(())
+    }
+}
+
+/// Address to trace for a subnet: the routing table's gateway on it, otherwise the subnet's
+/// first address, where routers usually sit. Subnets that aren't routed to, like the internet
+/// and Docker bridges, are skipped.
+fn subnet_gateway(subnet: &Subnet, gateway_ips: &[IpAddr]) -> Option<Ipv4Addr> {
+    if matches!(
+        subnet.base.subnet_type,
+        SubnetType::Internet | SubnetType::DockerBridge
+    ) {
+        return None;
+    }
+
+    let IpCidr::V4(cidr) = subnet.base.cidr else {
+        return None;
+    };
+
+    // Point-to-point and single address subnets have no gateway of their own
+    if cidr.network_length() >= 31 {
+        return None;
+    }
+
+    gateway_ips
+        .iter()
+        .find_map(|ip| match ip {
+            IpAddr::V4(ip) if cidr.contains(ip) => Some(*ip),
+            _ => None,
+        })
+        .or_else(|| Some(Ipv4Addr::from(u32::from(cidr.first_address()) + 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests;
+    use cidr::Ipv4Cidr;
+
+    fn subnet(address: Ipv4Addr, length: u8, subnet_type: SubnetType) -> Subnet {
+        let mut subnet = tests::subnet(&Uuid::new_v4());
+        subnet.base.cidr = IpCidr::V4(Ipv4Cidr::new(address, length).unwrap());
+        subnet.base.subnet_type = subnet_type;
+        subnet
+    }
+
+    #[test]
+    fn test_subnet_gateway_prefers_routing_table() {
+        let lan = subnet(Ipv4Addr::new(192, 168, 1, 0), 24, SubnetType::Lan);
+        let gateways = [
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 254)),
+        ];
+
+        assert_eq!(
+            subnet_gateway(&lan, &gateways),
+            Some(Ipv4Addr::new(192, 168, 1, 254))
+        );
+        assert_eq!(
+            subnet_gateway(&lan, &[]),
+            Some(Ipv4Addr::new(192, 168, 1, 1))
+        );
+    }
+
+    #[test]
+    fn test_subnet_gateway_skips_unrouted_subnets() {
+        let internet = subnet(Ipv4Addr::UNSPECIFIED, 0, SubnetType::Internet);
+        let docker = subnet(Ipv4Addr::new(172, 17, 0, 0), 16, SubnetType::DockerBridge);
+        let point_to_point = subnet(Ipv4Addr::new(10, 255, 0, 0), 31, SubnetType::Lan);
+
+        assert_eq!(subnet_gateway(&internet, &[]), None);
+        assert_eq!(subnet_gateway(&docker, &[]), None);
+        assert_eq!(subnet_gateway(&point_to_point, &[]), None);
+    }
+}
@@ -0,0 +1,382 @@
+use anyhow::{Error, Result, anyhow, bail};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
+use crate::daemon::utils::ntlm::NtlmCredentials;
+use crate::daemon::utils::winrm::{WinRmClient, WindowsInventory, WindowsRole};
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::credentials::r#impl::base::CredentialSecret;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::PortType;
+use crate::server::services::definitions::active_directory::ActiveDirectory;
+use crate::server::services::definitions::dhcp_server::DhcpServer;
+use crate::server::services::definitions::dns_server::DnsServer;
+use crate::server::services::r#impl::base::{Service, ServiceBase, ServiceMatchBaselineParams};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::shared::storage::traits::StorableEntity;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::Subnet;
+
+pub struct WinRmDiscovery {
+    targets: Vec<IpAddr>,
+    use_https: bool,
+    skip_tls_verify: bool,
+}
+
+impl WinRmDiscovery {
+    pub fn new(targets: Vec<IpAddr>, use_https: bool, skip_tls_verify: bool) -> Self {
+        Self {
+            targets,
+            use_https,
+            skip_tls_verify,
+        }
+    }
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<WinRmDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::WinRm {
+            targets: self.domain.targets.clone(),
+            use_https: self.domain.use_https,
+            skip_tls_verify: self.domain.skip_tls_verify,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let accounts: Vec<(String, String)> = self
+            .fetch_credentials(&request)
+            .await?
+            .into_iter()
+            .filter_map(|c| match c.secret {
+                CredentialSecret::UsernamePassword { username, password } => {
+                    Some((username, password))
+                }
+                _ => None,
+            })
+            .collect();
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.discover_targets(&accounts, cancel.clone()).await;
+
+        if let Err(e) = &discovery_result {
+            tracing::warn!(error = %e, "WinRM discovery failed");
+        }
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<WinRmDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<WinRmDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// Windows hosts are attached to the network's existing subnets, like Proxmox guests
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<WinRmDiscovery> {
+    async fn discover_targets(
+        &self,
+        accounts: &[(String, String)],
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        if accounts.is_empty() {
+            bail!(
+                "No username/password credential is attached to this discovery, or credential storage is not configured on the server"
+            );
+        }
+
+        let subnets = self.discover_create_subnets().await?;
+
+        self.report_discovery_update(DiscoverySessionUpdate::scanning(0))
+            .await?;
+
+        let total_targets = self.domain.targets.len();
+        let processed_count = AtomicUsize::new(0);
+        let concurrent_scans = self.as_ref().config_store.get_concurrent_scans().await?;
+
+        let results = stream::iter(self.domain.targets.clone())
+            .map(|ip| {
+                let subnets = &subnets;
+                let processed_count = &processed_count;
+
+                async move {
+                    let result = self.inventory_target(ip, accounts, subnets).await;
+
+                    let done = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
+                    let pct = (done * 100 / total_targets.max(1)) as u8;
+                    let _ = self.report_scanning_progress(pct).await;
+
+                    (ip, result)
+                }
+            })
+            .buffer_unordered(concurrent_scans);
+
+        let mut stream_pin = Box::pin(results);
+        let mut discovered = 0;
+
+        while let Some((ip, result)) = stream_pin.next().await {
+            if cancel.is_cancelled() {
+                bail!("WinRM discovery session was cancelled");
+            }
+
+            match result {
+                Ok(true) => discovered += 1,
+                Ok(false) => {}
+                Err(e) => tracing::warn!(ip = %ip, error = %e, "WinRM inventory failed for host"),
+            }
+        }
+
+        tracing::info!(
+            targets = %total_targets,
+            discovered = %discovered,
+            "WinRM inventory complete"
+        );
+
+        Ok(())
+    }
+
+    /// Query a host with the first account it accepts
+    async fn collect_inventory(
+        &self,
+        ip: IpAddr,
+        accounts: &[(String, String)],
+    ) -> Result<WindowsInventory, Error> {
+        let mut last_error = anyhow!("No account was accepted by {}", ip);
+
+        for (username, password) in accounts {
+            let client = WinRmClient::new(
+                ip,
+                self.domain.use_https,
+                self.domain.skip_tls_verify,
+                NtlmCredentials::new(username, password),
+            )?;
+
+            match client.collect_inventory().await {
+                Ok(inventory) => return Ok(inventory),
+                Err(e) => last_error = e,
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Query a host and create it. Returns false when the host has no address on a known
+    /// subnet.
+    async fn inventory_target(
+        &self,
+        ip: IpAddr,
+        accounts: &[(String, String)],
+        subnets: &[Subnet],
+    ) -> Result<bool, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let inventory = self.collect_inventory(ip, accounts).await?;
+
+        let mut interfaces = build_interfaces(&inventory, subnets, network_id);
+
+        // The interface we queried through comes first so service bindings land on it
+        let Some(primary_position) = interfaces
+            .iter()
+            .position(|i| i.base.ip_address == ip)
+            .or_else(|| (!interfaces.is_empty()).then_some(0))
+        else {
+            tracing::warn!(
+                ip = %ip,
+                "No subnet found for any of the host's addresses, skipping host. Run network discovery on the host's subnet first."
+            );
+            return Ok(false);
+        };
+        let primary = interfaces.remove(primary_position);
+        let subnet = subnets
+            .iter()
+            .find(|s| s.id == primary.base.subnet_id)
+            .ok_or_else(|| anyhow!("Subnet for interface {} not found", primary.id))?;
+
+        let mut open_ports: Vec<PortType> = Vec::new();
+        for listener in &inventory.listeners {
+            let port = PortType::new(listener.port, listener.protocol);
+            if !open_ports.contains(&port) {
+                open_ports.push(port);
+            }
+        }
+
+        let Some((mut host, mut host_interfaces, ports, mut services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface: &primary,
+                    all_ports: &open_ports,
+                    endpoint_responses: &Vec::new(),
+                    virtualization: &None,
+                },
+                inventory.hostname.clone(),
+                HostNamingFallback::BestService,
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        // Installed roles are authoritative, so they override port-based guesses
+        for feature in &inventory.features {
+            let Some(definition) = feature.role.and_then(role_definition) else {
+                continue;
+            };
+
+            let source = EntitySource::DiscoveryWithMatch {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                details: MatchDetails::new_certain(&format!(
+                    "Windows Server role installed: {}",
+                    feature.name
+                )),
+            };
+
+            match services
+                .iter_mut()
+                .find(|s| s.base.service_definition.id() == definition.id())
+            {
+                Some(service) => service.base.source = source,
+                None => services.push(Service::new(ServiceBase {
+                    name: definition.name().to_string(),
+                    service_definition: definition,
+                    bindings: vec![Binding::new_interface_serviceless(primary.id)],
+                    host_id: host.id,
+                    tags: Vec::new(),
+                    network_id,
+                    virtualization: None,
+                    source,
+                    position: services.len() as i32,
+                })),
+            }
+        }
+
+        host.base.description = describe_host(&inventory);
+        host_interfaces.extend(interfaces);
+        for (position, interface) in host_interfaces.iter_mut().enumerate() {
+            interface.base.position = position as i32;
+        }
+
+        let services_count = services.len();
+        self.create_host(host, host_interfaces, ports, services)
+            .await?;
+
+        tracing::info!(
+            ip = %ip,
+            services = services_count,
+            roles = inventory.features.iter().filter(|f| f.role.is_some()).count(),
+            "Windows host inventoried"
+        );
+
+        Ok(true)
+    }
+}
+
+/// Service definition a role maps onto. IIS and Hyper-V have no definition of their own and
+/// only show up in the host description.
+fn role_definition(role: WindowsRole) -> Option<Box<dyn ServiceDefinition>> {
+    match role {
+        WindowsRole::ActiveDirectoryDomainServices => Some(Box::new(ActiveDirectory)),
+        WindowsRole::DnsServer => Some(Box::new(DnsServer)),
+        WindowsRole::DhcpServer => Some(Box::new(DhcpServer)),
+        WindowsRole::WebServer | WindowsRole::HyperV => None,
+    }
+}
+
+/// Interfaces for every adapter address that falls in a known subnet
+fn build_interfaces(
+    inventory: &WindowsInventory,
+    subnets: &[Subnet],
+    network_id: Uuid,
+) -> Vec<Interface> {
+    let mut interfaces: Vec<Interface> = Vec::new();
+    let mut seen: HashSet<IpAddr> = HashSet::new();
+
+    for adapter in &inventory.adapters {
+        for ip in &adapter.addresses {
+            if !seen.insert(*ip) {
+                continue;
+            }
+            let Some(subnet) = subnets.iter().find(|s| s.base.cidr.contains(ip)) else {
+                continue;
+            };
+
+            interfaces.push(Interface::new(InterfaceBase {
+                network_id,
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                subnet_id: subnet.id,
+                ip_address: *ip,
+                mac_address: adapter.mac_address,
+                name: adapter.description.clone(),
+                position: 0,
+            }));
+        }
+    }
+
+    interfaces
+}
+
+fn describe_host(inventory: &WindowsInventory) -> Option<String> {
+    let os = inventory.os.as_ref().and_then(|o| o.display_name());
+
+    let roles = match inventory
+        .features
+        .iter()
+        .filter(|f| f.role.is_some())
+        .count()
+    {
+        0 => None,
+        _ => Some(format!(
+            "Roles: {}",
+            inventory
+                .features
+                .iter()
+                .filter(|f| f.role.is_some())
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    };
+
+    match (os, roles) {
+        (Some(os), Some(roles)) => Some(format!("{}. {}", os, roles)),
+        (os, roles) => os.or(roles),
+    }
+}
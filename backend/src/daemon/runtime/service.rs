@@ -65,55 +65,47 @@ impl DaemonRuntimeService {
         }
     }
 
-    /// Check Docker availability and return a detailed description of the connection method.
-    /// Returns (is_available, description) where description explains how Docker is being accessed.
+    /// Check container runtime availability and return a detailed description of the connection method.
+    /// Returns (is_available, description) where description explains how each runtime is being accessed.
     pub async fn check_docker_availability(&self) -> (bool, String) {
         let docker_proxy = self.config.get_docker_proxy().await;
         let docker_proxy_ssl_info = self.config.get_docker_proxy_ssl_info().await;
 
-        // Determine connection method description
-        let connection_method = match &docker_proxy {
-            Ok(Some(proxy_url)) => {
-                if proxy_url.starts_with("https://") {
-                    format!("via SSL proxy at {}", proxy_url)
-                } else {
-                    format!("via HTTP proxy at {}", proxy_url)
-                }
-            }
-            _ => {
-                #[cfg(target_family = "unix")]
-                {
-                    "via local socket (/var/run/docker.sock)".to_string()
-                }
-                #[cfg(target_family = "windows")]
-                {
-                    "via named pipe (//./pipe/docker_engine)".to_string()
-                }
-            }
-        };
+        let clients = self
+            .utils
+            .new_local_container_clients(docker_proxy, docker_proxy_ssl_info)
+            .await;
+
+        if !clients.is_empty() {
+            let descriptions: Vec<String> = clients.iter().map(|c| c.description()).collect();
+            return (true, format!("Available - {}", descriptions.join(", ")));
+        }
 
-        match self
+        // Nothing connected; retry the Docker connection to explain why
+        let docker_proxy = self.config.get_docker_proxy().await;
+        let docker_proxy_ssl_info = self.config.get_docker_proxy_ssl_info().await;
+
+        let error_hint = match self
             .utils
             .new_local_docker_client(docker_proxy, docker_proxy_ssl_info)
             .await
         {
-            Ok(_) => (true, format!("Available {}", connection_method)),
-            Err(e) => {
-                let error_hint = if e.to_string().contains("No such file") {
-                    " (socket not found - is Docker running?)"
-                } else if e.to_string().contains("permission denied") {
-                    " (permission denied - check user is in docker group)"
-                } else if e.to_string().contains("connection refused") {
-                    " (connection refused - is Docker daemon running?)"
-                } else {
-                    ""
-                };
-                (
-                    false,
-                    format!("Not available{} - container discovery disabled", error_hint),
-                )
+            Err(e) if e.to_string().contains("No such file") => {
+                " (socket not found - is Docker or Podman running?)"
             }
-        }
+            Err(e) if e.to_string().contains("permission denied") => {
+                " (permission denied - check user is in docker group)"
+            }
+            Err(e) if e.to_string().contains("connection refused") => {
+                " (connection refused - is Docker daemon running?)"
+            }
+            _ => "",
+        };
+
+        (
+            false,
+            format!("Not available{} - container discovery disabled", error_hint),
+        )
     }
 
     /// Check if an error indicates the API key is no longer valid (rotated/revoked).
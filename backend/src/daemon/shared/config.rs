@@ -69,6 +69,18 @@ pub struct DaemonCli {
     #[arg(long)]
     docker_proxy_ssl_chain: Option<String>,
 
+    /// Path to kubeconfig for Kubernetes discovery. If not set, uses the in-cluster service account when running in a pod, then KUBECONFIG or ~/.kube/config
+    #[arg(long)]
+    kubeconfig: Option<String>,
+
+    /// Nginx Proxy Manager login as email:password, used to read proxy hosts when discovering reverse proxy routes
+    #[arg(long)]
+    npm_credentials: Option<String>,
+
+    /// Proxmox VE API token as user@realm!tokenid=secret, used by Proxmox discovery to read nodes, VMs and containers
+    #[arg(long)]
+    proxmox_token: Option<String>,
+
     /// Select whether the daemon will Pull work from the server or have work Pushed to it. If set to Push, you will need to ensure that network you are deploying the daemon on can be reached by the server by opening/forwarding the port to the daemon, and provide the Daemon URL where the server should try to reach the daemon. If set to Pull, no port opening/forwarding is needed
     #[arg(long)]
     mode: Option<DaemonMode>,
@@ -142,6 +154,12 @@ pub struct AppConfig {
     #[serde(default)]
     docker_proxy_ssl_chain: Option<String>,
     #[serde(default)]
+    pub kubeconfig: Option<String>,
+    #[serde(default)]
+    npm_credentials: Option<String>,
+    #[serde(default)]
+    proxmox_token: Option<String>,
+    #[serde(default)]
     pub use_npcap_arp: bool,
     #[serde(default = "default_arp_retries")]
     pub arp_retries: u32,
@@ -182,6 +200,9 @@ impl Default for AppConfig {
             docker_proxy_ssl_cert: None,
             docker_proxy_ssl_chain: None,
             docker_proxy_ssl_key: None,
+            kubeconfig: None,
+            npm_credentials: None,
+            proxmox_token: None,
             use_npcap_arp: false,
             arp_retries: default_arp_retries(),
             arp_rate_pps: default_arp_rate_pps(),
@@ -264,6 +285,15 @@ impl AppConfig {
         if let Some(docker_proxy_ssl_chain) = cli_args.docker_proxy_ssl_chain {
             figment = figment.merge(("docker_proxy_ssl_chain", docker_proxy_ssl_chain));
         }
+        if let Some(kubeconfig) = cli_args.kubeconfig {
+            figment = figment.merge(("kubeconfig", kubeconfig));
+        }
+        if let Some(npm_credentials) = cli_args.npm_credentials {
+            figment = figment.merge(("npm_credentials", npm_credentials));
+        }
+        if let Some(proxmox_token) = cli_args.proxmox_token {
+            figment = figment.merge(("proxmox_token", proxmox_token));
+        }
         if let Some(mode) = cli_args.mode {
             figment = figment.merge(("mode", mode));
         }
@@ -461,6 +491,25 @@ impl ConfigStore {
         Ok(config.docker_proxy.clone())
     }
 
+    pub async fn get_kubeconfig(&self) -> Result<Option<String>> {
+        let config = self.config.read().await;
+        Ok(config.kubeconfig.clone())
+    }
+
+    /// Nginx Proxy Manager identity and secret
+    pub async fn get_npm_credentials(&self) -> Result<Option<(String, String)>> {
+        let config = self.config.read().await;
+        Ok(config.npm_credentials.as_ref().and_then(|c| {
+            c.split_once(':')
+                .map(|(identity, secret)| (identity.to_string(), secret.to_string()))
+        }))
+    }
+
+    pub async fn get_proxmox_token(&self) -> Result<Option<String>> {
+        let config = self.config.read().await;
+        Ok(config.proxmox_token.clone())
+    }
+
     pub async fn get_docker_proxy_ssl_info(&self) -> Result<Option<(String, String, String)>> {
         let config = self.config.read().await;
 
@@ -1,3 +1,7 @@
+use crate::daemon::utils::containers::{
+    ContainerClient, connect_podman_socket, default_docker_socket, detect_api_runtime,
+    nerdctl_clients, podman_socket_candidates,
+};
 use crate::server::discovery::r#impl::types::DiscoveryType;
 use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
 use crate::server::shared::storage::traits::StorableEntity;
@@ -7,14 +11,13 @@ use crate::server::subnets::r#impl::types::SubnetType;
 use anyhow::Error;
 use anyhow::anyhow;
 use async_trait::async_trait;
-use bollard::query_parameters::ListNetworksOptions;
 use bollard::{API_DEFAULT_VERSION, Docker};
 use cidr::IpCidr;
 use local_ip_address::local_ip;
 use mac_address::MacAddress;
 use net_route::Handle;
 use pnet::ipnetwork::IpNetwork;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -256,15 +259,79 @@ pub trait DaemonUtils {
         }
     }
 
+    /// Connect to every container runtime on this machine: the Docker API (Docker, or Podman's
+    /// compatibility socket) through the configured proxy or local defaults, then rootful and
+    /// rootless Podman sockets and containerd namespaces. A configured proxy disables the
+    /// local fallbacks.
+    async fn new_local_container_clients(
+        &self,
+        docker_proxy: Result<Option<String>, Error>,
+        docker_proxy_ssl_info: Result<Option<(String, String, String)>, Error>,
+    ) -> Vec<ContainerClient> {
+        let proxy = docker_proxy.as_ref().ok().cloned().flatten();
+        let mut clients = Vec::new();
+
+        // Podman is often installed with a docker.sock symlink, so compare resolved paths
+        let mut seen_sockets: HashSet<PathBuf> = HashSet::new();
+
+        if let Ok(docker) = self
+            .new_local_docker_client(docker_proxy, docker_proxy_ssl_info)
+            .await
+        {
+            let runtime = detect_api_runtime(&docker).await;
+            let default_socket = default_docker_socket();
+
+            if proxy.is_none()
+                && let Some(socket) = &default_socket
+            {
+                seen_sockets.insert(socket.canonicalize().unwrap_or(socket.clone()));
+            }
+
+            clients.push(ContainerClient::Api {
+                runtime,
+                docker,
+                endpoint: proxy.clone().unwrap_or_else(|| {
+                    default_socket
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or("local defaults".to_string())
+                }),
+            });
+        }
+
+        if proxy.is_some() {
+            return clients;
+        }
+
+        for socket in podman_socket_candidates() {
+            if !seen_sockets.insert(socket.canonicalize().unwrap_or(socket.clone())) {
+                continue;
+            }
+
+            match connect_podman_socket(&socket).await {
+                Ok(client) => {
+                    tracing::info!(socket = %socket.display(), "Podman client connected successfully");
+                    clients.push(client);
+                }
+                Err(e) => {
+                    tracing::debug!(socket = %socket.display(), error = %e, "Podman socket not usable")
+                }
+            }
+        }
+
+        clients.extend(nerdctl_clients().await);
+
+        clients
+    }
+
     async fn get_subnets_from_docker_networks(
         &self,
         daemon_id: Uuid,
         network_id: Uuid,
-        client: &Docker,
+        client: &ContainerClient,
         discovery_type: DiscoveryType,
     ) -> Result<Vec<Subnet>, Error> {
         let subnets: Vec<Subnet> = client
-            .list_networks(None::<ListNetworksOptions>)
+            .list_networks()
             .await?
             .into_iter()
             .filter_map(|n| {
@@ -0,0 +1,749 @@
+use anyhow::{Error, Result, anyhow};
+use bollard::query_parameters::{
+    InspectContainerOptions, ListContainersOptions, ListNetworksOptions,
+};
+use bollard::secret::{ContainerInspectResponse, ContainerSummary, Network, Port, PortTypeEnum};
+use bollard::{API_DEFAULT_VERSION, Docker};
+use futures::future::try_join_all;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::server::groups::r#impl::types::GroupType;
+use crate::server::services::r#impl::virtualization::ContainerRuntime;
+
+pub const CONTAINER_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Timeout for a single nerdctl invocation. Inspecting many containers can be slow.
+const NERDCTL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Label set by CRI runtimes and `podman kube play` on containers that belong to a pod
+pub const POD_NAME_LABEL: &str = "io.kubernetes.pod.name";
+
+/// Labels set by `docker compose` (and the Podman and nerdctl equivalents) on project containers
+const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
+const COMPOSE_SERVICE_LABEL: &str = "com.docker.compose.service";
+
+/// Labels set by `docker stack deploy` on Swarm task containers
+const STACK_NAMESPACE_LABEL: &str = "com.docker.stack.namespace";
+const SWARM_SERVICE_LABEL: &str = "com.docker.swarm.service.name";
+
+/// containerd namespaces which are already covered by other discovery types:
+/// `moby` holds Docker's containers and `k8s.io` holds Kubernetes pods
+const SKIPPED_CONTAINERD_NAMESPACES: [&str; 2] = ["moby", "k8s.io"];
+
+/// A container runtime the daemon can list containers from. Everything is exposed as Docker
+/// Engine API models so container discovery doesn't need to care which runtime it's talking to.
+pub enum ContainerClient {
+    /// A Docker Engine API socket, served by Docker or by Podman's compatibility API
+    Api {
+        runtime: ContainerRuntime,
+        docker: Docker,
+        endpoint: String,
+    },
+    /// containerd, through the nerdctl CLI which reports Docker-compatible inspect output
+    Nerdctl { namespace: String },
+}
+
+impl ContainerClient {
+    pub fn runtime(&self) -> ContainerRuntime {
+        match self {
+            ContainerClient::Api { runtime, .. } => *runtime,
+            ContainerClient::Nerdctl { .. } => ContainerRuntime::Containerd,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            ContainerClient::Api {
+                runtime, endpoint, ..
+            } => {
+                let runtime: &'static str = runtime.into();
+                format!("{} ({})", runtime, endpoint)
+            }
+            ContainerClient::Nerdctl { namespace } => {
+                format!("Containerd (nerdctl, namespace {})", namespace)
+            }
+        }
+    }
+
+    pub async fn list_networks(&self) -> Result<Vec<Network>, Error> {
+        match self {
+            ContainerClient::Api { docker, .. } => {
+                Ok(docker.list_networks(None::<ListNetworksOptions>).await?)
+            }
+            ContainerClient::Nerdctl { namespace } => {
+                let names =
+                    run_nerdctl(namespace, &["network", "ls", "--format", "{{.Name}}"]).await?;
+                let names: Vec<&str> = names
+                    .lines()
+                    .map(str::trim)
+                    .filter(|n| !n.is_empty())
+                    .collect();
+
+                if names.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                let mut args = vec!["network", "inspect"];
+                args.extend(names);
+
+                Ok(serde_json::from_str(&run_nerdctl(namespace, &args).await?)?)
+            }
+        }
+    }
+
+    /// Running containers, inspected, with their list summaries
+    pub async fn list_containers(
+        &self,
+    ) -> Result<Vec<(ContainerInspectResponse, ContainerSummary)>, Error> {
+        match self {
+            ContainerClient::Api { docker, .. } => {
+                let container_summaries = docker
+                    .list_containers(None::<ListContainersOptions>)
+                    .await?;
+
+                let containers_to_inspect: Vec<_> = container_summaries
+                    .iter()
+                    .filter_map(|c| {
+                        c.id.as_ref()
+                            .map(|id| docker.inspect_container(id, None::<InspectContainerOptions>))
+                    })
+                    .collect();
+
+                let inspected_containers: Vec<ContainerInspectResponse> =
+                    try_join_all(containers_to_inspect).await?;
+
+                Ok(inspected_containers
+                    .into_iter()
+                    .zip(container_summaries)
+                    .collect())
+            }
+            ContainerClient::Nerdctl { namespace } => {
+                let ids = run_nerdctl(namespace, &["ps", "-q", "--no-trunc"]).await?;
+                let ids: Vec<&str> = ids
+                    .lines()
+                    .map(str::trim)
+                    .filter(|i| !i.is_empty())
+                    .collect();
+
+                if ids.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                let mut args = vec!["inspect", "--mode", "dockercompat"];
+                args.extend(ids);
+
+                let inspected: Vec<ContainerInspectResponse> =
+                    serde_json::from_str(&run_nerdctl(namespace, &args).await?)?;
+
+                // nerdctl has no list endpoint with Docker's summary shape, so build the parts
+                // of the summary discovery uses from the inspect output
+                Ok(inspected
+                    .into_iter()
+                    .map(|c| {
+                        let summary = summary_from_inspect(&c);
+                        (c, summary)
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Run a shell command in a container and return its combined stdout and stderr
+    pub async fn exec(
+        &self,
+        container: &str,
+        command: &str,
+        cancel: &CancellationToken,
+    ) -> Result<String, Error> {
+        match self {
+            ContainerClient::Api { docker, .. } => {
+                use futures::StreamExt;
+
+                let exec = docker
+                    .create_exec(
+                        container,
+                        bollard::exec::CreateExecOptions {
+                            cmd: Some(vec!["sh", "-c", command]),
+                            attach_stdout: Some(true),
+                            attach_stderr: Some(true),
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+
+                let mut full_response = String::new();
+
+                if let bollard::exec::StartExecResults::Attached { mut output, .. } =
+                    docker.start_exec(&exec.id, None).await?
+                {
+                    loop {
+                        tokio::select! {
+                            _ = cancel.cancelled() => {
+                                tracing::debug!("Exec cancelled for container {}", container);
+                                break;
+                            }
+                            msg = output.next() => {
+                                match msg {
+                                    Some(Ok(bollard::container::LogOutput::StdOut { message })) => {
+                                        full_response.push_str(&String::from_utf8_lossy(&message));
+                                    }
+                                    Some(Ok(bollard::container::LogOutput::StdErr { message })) => {
+                                        // wget outputs headers to stderr with -S flag
+                                        full_response.push_str(&String::from_utf8_lossy(&message));
+                                    }
+                                    Some(Ok(_)) => {}
+                                    Some(Err(e)) => {
+                                        tracing::warn!("Error reading container exec output: {}", e);
+                                        break;
+                                    }
+                                    None => break,
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Ok(full_response)
+            }
+            ContainerClient::Nerdctl { namespace } => {
+                let args = ["exec", container, "sh", "-c", command];
+                let output = tokio::select! {
+                    _ = cancel.cancelled() => return Ok(String::new()),
+                    output = nerdctl_output(namespace, &args) => output?,
+                };
+
+                Ok(format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                ))
+            }
+        }
+    }
+}
+
+/// Ping a Docker API client, giving up after CONTAINER_CONNECT_TIMEOUT
+pub async fn ping_docker_client(client: &Docker) -> Result<(), Error> {
+    match timeout(CONTAINER_CONNECT_TIMEOUT, client.ping()).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(anyhow!("Docker ping failed: {}", e)),
+        Err(_) => Err(anyhow!(
+            "Docker connection timed out after {:?}",
+            CONTAINER_CONNECT_TIMEOUT
+        )),
+    }
+}
+
+/// Podman serves the Docker API too, so the only way to tell them apart is the version response
+pub async fn detect_api_runtime(client: &Docker) -> ContainerRuntime {
+    match timeout(CONTAINER_CONNECT_TIMEOUT, client.version()).await {
+        Ok(Ok(version)) => {
+            let is_podman = version
+                .components
+                .unwrap_or_default()
+                .iter()
+                .any(|c| c.name.to_lowercase().contains("podman"));
+
+            if is_podman {
+                ContainerRuntime::Podman
+            } else {
+                ContainerRuntime::Docker
+            }
+        }
+        _ => ContainerRuntime::Docker,
+    }
+}
+
+/// Path of the socket bollard's local defaults connect to
+pub fn default_docker_socket() -> Option<PathBuf> {
+    match std::env::var("DOCKER_HOST") {
+        Ok(host) if host.starts_with("unix://") => {
+            Some(PathBuf::from(host.trim_start_matches("unix://")))
+        }
+        Ok(_) => None,
+        Err(_) => Some(PathBuf::from("/var/run/docker.sock")),
+    }
+}
+
+/// Rootful and rootless Podman API sockets. Rootless Podman runs one service per user, so
+/// every user's runtime directory is checked, not just the daemon's own.
+#[cfg(target_family = "unix")]
+pub fn podman_socket_candidates() -> Vec<PathBuf> {
+    let mut candidates = vec![PathBuf::from("/run/podman/podman.sock")];
+
+    if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
+        candidates.push(PathBuf::from(runtime_dir).join("podman/podman.sock"));
+    }
+
+    if let Ok(user_dirs) = std::fs::read_dir("/run/user") {
+        let mut user_sockets: Vec<PathBuf> = user_dirs
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path().join("podman/podman.sock"))
+            .collect();
+        user_sockets.sort();
+        candidates.extend(user_sockets);
+    }
+
+    candidates.retain(|path| path.exists());
+    candidates
+}
+
+#[cfg(target_family = "windows")]
+pub fn podman_socket_candidates() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// Connect to a Podman socket and check it responds
+pub async fn connect_podman_socket(path: &std::path::Path) -> Result<ContainerClient, Error> {
+    let endpoint = path.to_string_lossy().to_string();
+    let docker = Docker::connect_with_socket(&endpoint, 4, API_DEFAULT_VERSION)
+        .map_err(|e| anyhow!("Failed to connect to Podman: {}", e))?;
+
+    ping_docker_client(&docker).await?;
+
+    Ok(ContainerClient::Api {
+        runtime: ContainerRuntime::Podman,
+        docker,
+        endpoint,
+    })
+}
+
+/// One client per containerd namespace, if nerdctl is installed and can reach containerd
+pub async fn nerdctl_clients() -> Vec<ContainerClient> {
+    let namespaces = match timeout(
+        NERDCTL_TIMEOUT,
+        Command::new("nerdctl")
+            .args(["namespace", "ls", "-q"])
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    {
+        Ok(Ok(output)) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).to_string()
+        }
+        Ok(Ok(output)) => {
+            tracing::debug!(
+                stderr = %String::from_utf8_lossy(&output.stderr),
+                "nerdctl could not list containerd namespaces"
+            );
+            return Vec::new();
+        }
+        // Not installed, or not runnable by the daemon's user
+        _ => return Vec::new(),
+    };
+
+    namespaces
+        .lines()
+        .map(str::trim)
+        .filter(|ns| !ns.is_empty() && !SKIPPED_CONTAINERD_NAMESPACES.contains(ns))
+        .map(|ns| ContainerClient::Nerdctl {
+            namespace: ns.to_string(),
+        })
+        .collect()
+}
+
+async fn nerdctl_output(namespace: &str, args: &[&str]) -> Result<std::process::Output, Error> {
+    timeout(
+        NERDCTL_TIMEOUT,
+        Command::new("nerdctl")
+            .arg("--namespace")
+            .arg(namespace)
+            .args(args)
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| anyhow!("nerdctl timed out after {:?}", NERDCTL_TIMEOUT))?
+    .map_err(|e| anyhow!("Failed to run nerdctl: {}", e))
+}
+
+async fn run_nerdctl(namespace: &str, args: &[&str]) -> Result<String, Error> {
+    let output = nerdctl_output(namespace, args).await?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "nerdctl {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Build a list summary from an inspected container. Only the fields used by discovery are set.
+pub fn summary_from_inspect(container: &ContainerInspectResponse) -> ContainerSummary {
+    let ports = container
+        .network_settings
+        .as_ref()
+        .and_then(|n| n.ports.as_ref())
+        .map(|port_map| {
+            port_map
+                .iter()
+                .filter_map(|(key, bindings)| {
+                    let (number, protocol) = key.split_once('/').unwrap_or((key, "tcp"));
+                    let private_port = number.parse::<u16>().ok()?;
+                    let typ = match protocol {
+                        "tcp" => PortTypeEnum::TCP,
+                        "udp" => PortTypeEnum::UDP,
+                        "sctp" => PortTypeEnum::SCTP,
+                        _ => return None,
+                    };
+
+                    let bindings = bindings.clone().unwrap_or_default();
+
+                    if bindings.is_empty() {
+                        return Some(vec![Port {
+                            ip: None,
+                            private_port,
+                            public_port: None,
+                            typ: Some(typ),
+                        }]);
+                    }
+
+                    Some(
+                        bindings
+                            .into_iter()
+                            .map(|b| Port {
+                                ip: b
+                                    .host_ip
+                                    .filter(|ip| !ip.is_empty())
+                                    .or(Some("0.0.0.0".to_string())),
+                                private_port,
+                                public_port: b.host_port.and_then(|p| p.parse().ok()),
+                                typ: Some(typ),
+                            })
+                            .collect(),
+                    )
+                })
+                .flatten()
+                .collect()
+        });
+
+    ContainerSummary {
+        id: container.id.clone(),
+        names: container.name.clone().map(|n| vec![n]),
+        image: container.config.as_ref().and_then(|c| c.image.clone()),
+        labels: container.config.as_ref().and_then(|c| c.labels.clone()),
+        ports,
+        ..Default::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StackKind {
+    Compose,
+    Swarm,
+}
+
+/// The compose project or Swarm stack a container was deployed as part of
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContainerStack {
+    pub kind: StackKind,
+    pub name: String,
+    /// Compose or Swarm service the container runs, without the stack prefix
+    pub service: Option<String>,
+}
+
+impl ContainerStack {
+    pub fn from_labels(labels: &HashMap<String, String>) -> Option<Self> {
+        if let Some(stack) = labels.get(STACK_NAMESPACE_LABEL) {
+            // Swarm service names are "<stack>_<service>"
+            let service = labels.get(SWARM_SERVICE_LABEL).map(|name| {
+                name.strip_prefix(stack.as_str())
+                    .and_then(|s| s.strip_prefix('_'))
+                    .unwrap_or(name)
+                    .to_string()
+            });
+
+            return Some(Self {
+                kind: StackKind::Swarm,
+                name: stack.clone(),
+                service,
+            });
+        }
+
+        labels.get(COMPOSE_PROJECT_LABEL).map(|project| Self {
+            kind: StackKind::Compose,
+            name: project.clone(),
+            service: labels.get(COMPOSE_SERVICE_LABEL).cloned(),
+        })
+    }
+
+    pub fn description(&self) -> String {
+        match self.kind {
+            StackKind::Compose => format!("Docker Compose project {}", self.name),
+            StackKind::Swarm => format!("Swarm stack {}", self.name),
+        }
+    }
+
+    /// Whether two containers belong to the same stack, whichever of its services they run
+    pub fn same_stack(&self, other: &ContainerStack) -> bool {
+        self.kind == other.kind && self.name == other.name
+    }
+
+    /// Tag for the stack's services and group, prefixed like the `k8s:` namespace tags
+    pub fn tag_name(&self) -> String {
+        format!("{}:{}", self.kind_prefix(), self.name)
+    }
+
+    /// Identity of the stack's group. Project names are only unique per host, so two hosts
+    /// running a "blog" project get a group each.
+    pub fn identity(&self, host_id: Uuid) -> String {
+        format!("{}:{}/{}", self.kind_prefix(), host_id, self.name)
+    }
+
+    fn kind_prefix(&self) -> &'static str {
+        match self.kind {
+            StackKind::Compose => "compose",
+            StackKind::Swarm => "swarm",
+        }
+    }
+}
+
+/// A stack container as seen by [`infer_stack_layout`]
+pub struct StackMember<T> {
+    pub item: T,
+    /// Names of the networks the container is attached to
+    pub networks: Vec<String>,
+    /// Whether the container publishes any port on the host
+    pub publishes_ports: bool,
+}
+
+/// Work out how a stack's containers talk to each other from the networks they share and the
+/// ports they publish.
+///
+/// When a single container publishes ports and the shared networks chain every container one
+/// after another (e.g. proxy on `frontend`, app on `frontend` + `backend`, db on `backend`),
+/// traffic follows that chain and the stack is a [`GroupType::RequestPath`] starting at the
+/// published container. Otherwise it's a [`GroupType::HubAndSpoke`] around the container that
+/// publishes ports, falling back to the best connected one. Returned items are ordered for
+/// the group type: path order, or the hub first.
+pub fn infer_stack_layout<T>(members: Vec<StackMember<T>>) -> (GroupType, Vec<T>) {
+    let shares_network =
+        |a: &StackMember<T>, b: &StackMember<T>| a.networks.iter().any(|n| b.networks.contains(n));
+
+    let neighbours: Vec<Vec<usize>> = members
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            members
+                .iter()
+                .enumerate()
+                .filter(|(j, b)| i != *j && shares_network(a, b))
+                .map(|(j, _)| j)
+                .collect()
+        })
+        .collect();
+
+    let entry_points: Vec<usize> = members
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.publishes_ports)
+        .map(|(i, _)| i)
+        .collect();
+
+    let path = match entry_points.as_slice() {
+        [entry] if members.len() > 1 => chain_from(*entry, &neighbours),
+        _ => None,
+    };
+
+    let (group_type, order) = match path {
+        Some(path) => (GroupType::RequestPath, path),
+        None => {
+            let hub = entry_points
+                .iter()
+                .copied()
+                .max_by_key(|i| (neighbours[*i].len(), std::cmp::Reverse(*i)))
+                .or_else(|| {
+                    (0..members.len()).max_by_key(|i| (neighbours[*i].len(), std::cmp::Reverse(*i)))
+                })
+                .unwrap_or_default();
+
+            let order = std::iter::once(hub)
+                .chain((0..members.len()).filter(|i| *i != hub))
+                .collect();
+
+            (GroupType::HubAndSpoke, order)
+        }
+    };
+
+    let mut items: Vec<Option<T>> = members.into_iter().map(|m| Some(m.item)).collect();
+    let ordered = order
+        .into_iter()
+        .filter_map(|i| items.get_mut(i).and_then(Option::take))
+        .collect();
+
+    (group_type, ordered)
+}
+
+/// Walk from `start` while there is exactly one way forward. Returns the visited order if that
+/// visits every node without any node touching more than its two path neighbours.
+fn chain_from(start: usize, neighbours: &[Vec<usize>]) -> Option<Vec<usize>> {
+    if neighbours[start].len() != 1 {
+        return None;
+    }
+
+    let mut path = vec![start];
+    let mut previous = None;
+    let mut current = start;
+
+    loop {
+        let next: Vec<usize> = neighbours[current]
+            .iter()
+            .copied()
+            .filter(|n| Some(*n) != previous)
+            .collect();
+
+        match next.as_slice() {
+            [] => break,
+            [n] if !path.contains(n) && neighbours[*n].len() <= 2 => {
+                path.push(*n);
+                previous = Some(current);
+                current = *n;
+            }
+            _ => return None,
+        }
+    }
+
+    (path.len() == neighbours.len()).then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_from_nerdctl_inspect() {
+        let inspect: Vec<ContainerInspectResponse> = serde_json::from_str(
+            r#"[{
+                "Id": "4f1c2d",
+                "Name": "web",
+                "Config": {"Image": "nginx:latest", "Labels": {"io.kubernetes.pod.name": "web-pod"}},
+                "HostConfig": {"NetworkMode": "bridge"},
+                "NetworkSettings": {
+                    "Ports": {
+                        "80/tcp": [{"HostIp": "0.0.0.0", "HostPort": "8080"}],
+                        "53/udp": null
+                    },
+                    "Networks": {"bridge": {"IPAddress": "10.4.0.2", "MacAddress": "02:42:0a:04:00:02"}}
+                }
+            }]"#,
+        )
+        .unwrap();
+
+        let summary = summary_from_inspect(&inspect[0]);
+
+        assert_eq!(summary.id.as_deref(), Some("4f1c2d"));
+        assert_eq!(
+            summary
+                .labels
+                .unwrap()
+                .get(POD_NAME_LABEL)
+                .map(String::as_str),
+            Some("web-pod")
+        );
+
+        let mut ports = summary.ports.unwrap();
+        ports.sort_by_key(|p| p.private_port);
+
+        assert_eq!(ports.len(), 2);
+        assert_eq!(ports[0].private_port, 53);
+        assert_eq!(ports[0].typ, Some(PortTypeEnum::UDP));
+        assert_eq!(ports[0].public_port, None);
+        assert_eq!(ports[1].private_port, 80);
+        assert_eq!(ports[1].public_port, Some(8080));
+        assert_eq!(ports[1].ip.as_deref(), Some("0.0.0.0"));
+    }
+
+    fn member(
+        name: &'static str,
+        networks: &[&str],
+        publishes_ports: bool,
+    ) -> StackMember<&'static str> {
+        StackMember {
+            item: name,
+            networks: networks.iter().map(|n| n.to_string()).collect(),
+            publishes_ports,
+        }
+    }
+
+    #[test]
+    fn test_stack_from_labels() {
+        let compose = HashMap::from([
+            (COMPOSE_PROJECT_LABEL.to_string(), "blog".to_string()),
+            (COMPOSE_SERVICE_LABEL.to_string(), "db".to_string()),
+        ]);
+        let stack = ContainerStack::from_labels(&compose).unwrap();
+        assert_eq!(stack.kind, StackKind::Compose);
+        assert_eq!(stack.name, "blog");
+        assert_eq!(stack.service.as_deref(), Some("db"));
+
+        let swarm = HashMap::from([
+            (STACK_NAMESPACE_LABEL.to_string(), "blog".to_string()),
+            (SWARM_SERVICE_LABEL.to_string(), "blog_web".to_string()),
+        ]);
+        let stack = ContainerStack::from_labels(&swarm).unwrap();
+        assert_eq!(stack.kind, StackKind::Swarm);
+        assert_eq!(stack.service.as_deref(), Some("web"));
+
+        assert!(ContainerStack::from_labels(&HashMap::new()).is_none());
+    }
+
+    #[test]
+    fn test_stack_tag_and_identity() {
+        let web = ContainerStack::from_labels(&HashMap::from([
+            (COMPOSE_PROJECT_LABEL.to_string(), "blog".to_string()),
+            (COMPOSE_SERVICE_LABEL.to_string(), "web".to_string()),
+        ]))
+        .unwrap();
+        let db = ContainerStack {
+            service: Some("db".to_string()),
+            ..web.clone()
+        };
+        assert!(web.same_stack(&db));
+        assert_eq!(web.tag_name(), "compose:blog");
+
+        let host_a = Uuid::new_v4();
+        let host_b = Uuid::new_v4();
+        assert_eq!(web.identity(host_a), format!("compose:{}/blog", host_a));
+        assert_ne!(web.identity(host_a), web.identity(host_b));
+    }
+
+    #[test]
+    fn test_infer_stack_layout() {
+        // Tiered networks chain proxy -> app -> db
+        let (group_type, order) = infer_stack_layout(vec![
+            member("db", &["backend"], false),
+            member("proxy", &["frontend"], true),
+            member("app", &["frontend", "backend"], false),
+        ]);
+        assert_eq!(group_type, GroupType::RequestPath);
+        assert_eq!(order, vec!["proxy", "app", "db"]);
+
+        // Everything on the default network fans out from the published container
+        let (group_type, order) = infer_stack_layout(vec![
+            member("db", &["blog_default"], false),
+            member("cache", &["blog_default"], false),
+            member("app", &["blog_default"], true),
+        ]);
+        assert_eq!(group_type, GroupType::HubAndSpoke);
+        assert_eq!(order, vec!["app", "db", "cache"]);
+
+        // Nothing published, so the best connected container is the hub
+        let (group_type, order) = infer_stack_layout(vec![
+            member("worker", &["jobs"], false),
+            member("queue", &["jobs", "internal"], false),
+            member("metrics", &["internal"], false),
+        ]);
+        assert_eq!(group_type, GroupType::HubAndSpoke);
+        assert_eq!(order, vec!["queue", "worker", "metrics"]);
+    }
+}
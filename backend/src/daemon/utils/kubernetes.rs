@@ -0,0 +1,957 @@
+//! Minimal read-only client for the Kubernetes API server.
+//!
+//! Only the handful of list endpoints used by Kubernetes discovery are implemented,
+//! along with the subset of each object's fields that discovery needs.
+
+use anyhow::{Error, anyhow, bail};
+use base64ct::{Base64, Encoding};
+use cidr::IpCidr;
+use reqwest::{Certificate, Client, Identity};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::server::ports::r#impl::base::PortType;
+
+const SERVICE_ACCOUNT_DIR: &str = "/var/run/secrets/kubernetes.io/serviceaccount";
+const LIST_PAGE_SIZE: u32 = 500;
+
+pub struct KubernetesClient {
+    client: Client,
+    server: String,
+    token: Option<String>,
+}
+
+impl KubernetesClient {
+    /// Build a client from, in order of precedence: an explicit kubeconfig path, the in-cluster
+    /// service account if the daemon runs in a pod, then $KUBECONFIG or ~/.kube/config
+    pub async fn new(kubeconfig: Option<String>) -> Result<Self, Error> {
+        if let Some(path) = kubeconfig {
+            return Self::from_kubeconfig(Path::new(&path)).await;
+        }
+
+        if std::env::var("KUBERNETES_SERVICE_HOST").is_ok() {
+            return Self::in_cluster().await;
+        }
+
+        let path = std::env::var_os("KUBECONFIG")
+            .and_then(|p| std::env::split_paths(&p).next())
+            .or_else(|| {
+                directories_next::BaseDirs::new().map(|d| d.home_dir().join(".kube").join("config"))
+            })
+            .ok_or_else(|| anyhow!("No kubeconfig found and daemon is not running in a pod"))?;
+
+        Self::from_kubeconfig(&path).await
+    }
+
+    async fn in_cluster() -> Result<Self, Error> {
+        let host = std::env::var("KUBERNETES_SERVICE_HOST")?;
+        let port = std::env::var("KUBERNETES_SERVICE_PORT").unwrap_or_else(|_| "443".to_string());
+
+        // IPv6 service hosts need brackets in the URL
+        let host = if host.contains(':') {
+            format!("[{}]", host)
+        } else {
+            host
+        };
+
+        let account_dir = PathBuf::from(SERVICE_ACCOUNT_DIR);
+        let token = tokio::fs::read_to_string(account_dir.join("token"))
+            .await
+            .map_err(|e| anyhow!("Failed to read service account token: {}", e))?;
+        let ca = tokio::fs::read(account_dir.join("ca.crt"))
+            .await
+            .map_err(|e| anyhow!("Failed to read service account CA: {}", e))?;
+
+        let client = Self::client_builder()
+            .add_root_certificate(Certificate::from_pem(&ca)?)
+            .build()
+            .map_err(|e| anyhow!("Failed to build Kubernetes client: {}", e))?;
+
+        tracing::debug!(host = %host, "Using in-cluster Kubernetes service account");
+
+        Ok(Self {
+            client,
+            server: format!("https://{}:{}", host, port),
+            token: Some(token.trim().to_string()),
+        })
+    }
+
+    async fn from_kubeconfig(path: &Path) -> Result<Self, Error> {
+        let contents = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| anyhow!("Failed to read kubeconfig {}: {}", path.display(), e))?;
+        let kubeconfig = KubeConfig::parse(&contents)?;
+        let (cluster, user) = kubeconfig.current()?;
+
+        // Relative file references are resolved against the kubeconfig's directory
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+
+        let mut builder = Self::client_builder();
+
+        if cluster.insecure_skip_tls_verify {
+            builder = builder.danger_accept_invalid_certs(true);
+        } else if let Some(ca) = read_data_or_file(
+            &cluster.certificate_authority_data,
+            &cluster.certificate_authority,
+            base_dir,
+        )
+        .await?
+        {
+            builder = builder.add_root_certificate(Certificate::from_pem(&ca)?);
+        }
+
+        let mut token = None;
+
+        if let Some(user) = user {
+            if user.exec.is_some() {
+                tracing::warn!(
+                    "Kubeconfig user uses an exec credential plugin, which is not supported. Use a token or client certificate instead."
+                );
+            }
+
+            let cert = read_data_or_file(
+                &user.client_certificate_data,
+                &user.client_certificate,
+                base_dir,
+            )
+            .await?;
+            let key = read_data_or_file(&user.client_key_data, &user.client_key, base_dir).await?;
+
+            if let (Some(mut cert), Some(key)) = (cert, key) {
+                cert.push(b'\n');
+                cert.extend(key);
+                builder = builder.identity(Identity::from_pem(&cert)?);
+            }
+
+            token = match (&user.token, &user.token_file) {
+                (Some(token), _) => Some(token.clone()),
+                (None, Some(file)) => Some(
+                    tokio::fs::read_to_string(base_dir.join(file))
+                        .await?
+                        .trim()
+                        .to_string(),
+                ),
+                _ => None,
+            };
+        }
+
+        let client = builder
+            .build()
+            .map_err(|e| anyhow!("Failed to build Kubernetes client: {}", e))?;
+
+        tracing::debug!(server = %cluster.server, "Using kubeconfig {}", path.display());
+
+        Ok(Self {
+            client,
+            server: cluster.server.trim_end_matches('/').to_string(),
+            token,
+        })
+    }
+
+    fn client_builder() -> reqwest::ClientBuilder {
+        Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+    }
+
+    /// List all objects at a collection path, following continue tokens
+    async fn list<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>, Error> {
+        let mut items = Vec::new();
+        let mut continue_token: Option<String> = None;
+
+        loop {
+            let mut url = format!("{}{}?limit={}", self.server, path, LIST_PAGE_SIZE);
+            if let Some(token) = &continue_token {
+                url.push_str(&format!("&continue={}", urlencoding::encode(token)));
+            }
+
+            let mut request = self.client.get(&url);
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| anyhow!("Kubernetes API request to {} failed: {}", path, e))?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                bail!(
+                    "Kubernetes API request to {} failed with status {}: {}",
+                    path,
+                    status,
+                    body
+                );
+            }
+
+            let list: ObjectList<T> = response.json().await?;
+            items.extend(list.items);
+
+            match list.metadata.continue_token.filter(|c| !c.is_empty()) {
+                Some(token) => continue_token = Some(token),
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    pub async fn list_nodes(&self) -> Result<Vec<Node>, Error> {
+        self.list("/api/v1/nodes").await
+    }
+
+    pub async fn list_pods(&self, namespace: Option<&str>) -> Result<Vec<Pod>, Error> {
+        match namespace {
+            Some(ns) => self.list(&format!("/api/v1/namespaces/{}/pods", ns)).await,
+            None => self.list("/api/v1/pods").await,
+        }
+    }
+
+    pub async fn list_services(&self, namespace: Option<&str>) -> Result<Vec<KubeService>, Error> {
+        match namespace {
+            Some(ns) => {
+                self.list(&format!("/api/v1/namespaces/{}/services", ns))
+                    .await
+            }
+            None => self.list("/api/v1/services").await,
+        }
+    }
+
+    pub async fn list_ingresses(&self, namespace: Option<&str>) -> Result<Vec<Ingress>, Error> {
+        match namespace {
+            Some(ns) => {
+                self.list(&format!(
+                    "/apis/networking.k8s.io/v1/namespaces/{}/ingresses",
+                    ns
+                ))
+                .await
+            }
+            None => self.list("/apis/networking.k8s.io/v1/ingresses").await,
+        }
+    }
+}
+
+async fn read_data_or_file(
+    data: &Option<String>,
+    file: &Option<String>,
+    base_dir: &Path,
+) -> Result<Option<Vec<u8>>, Error> {
+    if let Some(data) = data {
+        let decoded = Base64::decode_vec(data.trim())
+            .map_err(|e| anyhow!("Invalid base64 in kubeconfig: {}", e))?;
+        return Ok(Some(decoded));
+    }
+    if let Some(file) = file {
+        let contents = tokio::fs::read(base_dir.join(file))
+            .await
+            .map_err(|e| anyhow!("Failed to read {}: {}", file, e))?;
+        return Ok(Some(contents));
+    }
+    Ok(None)
+}
+
+/// Map a Kubernetes protocol string to a port type. SCTP ports are not tracked.
+pub fn port_type(protocol: Option<&str>, number: u16) -> Option<PortType> {
+    match protocol.unwrap_or("TCP") {
+        "TCP" => Some(PortType::new_tcp(number)),
+        "UDP" => Some(PortType::new_udp(number)),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Kubeconfig
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct KubeConfig {
+    current_context: Option<String>,
+    #[serde(default)]
+    contexts: Vec<NamedContext>,
+    #[serde(default)]
+    clusters: Vec<NamedCluster>,
+    #[serde(default)]
+    users: Vec<NamedUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedContext {
+    name: String,
+    context: ContextInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextInfo {
+    cluster: String,
+    user: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedCluster {
+    name: String,
+    cluster: ClusterInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ClusterInfo {
+    server: String,
+    certificate_authority: Option<String>,
+    certificate_authority_data: Option<String>,
+    #[serde(default)]
+    insecure_skip_tls_verify: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedUser {
+    name: String,
+    user: UserInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct UserInfo {
+    token: Option<String>,
+    token_file: Option<String>,
+    client_certificate: Option<String>,
+    client_certificate_data: Option<String>,
+    client_key: Option<String>,
+    client_key_data: Option<String>,
+    exec: Option<serde_json::Value>,
+}
+
+impl KubeConfig {
+    fn parse(contents: &str) -> Result<Self, Error> {
+        config::Config::builder()
+            .add_source(config::File::from_str(contents, config::FileFormat::Yaml))
+            .build()
+            .and_then(|c| c.try_deserialize())
+            .map_err(|e| anyhow!("Failed to parse kubeconfig: {}", e))
+    }
+
+    /// Resolve the cluster and user referenced by the current context.
+    /// Falls back to the first context if current-context is unset.
+    fn current(&self) -> Result<(&ClusterInfo, Option<&UserInfo>), Error> {
+        let context = match &self.current_context {
+            Some(name) => self
+                .contexts
+                .iter()
+                .find(|c| &c.name == name)
+                .ok_or_else(|| anyhow!("Kubeconfig context '{}' not found", name))?,
+            None => self
+                .contexts
+                .first()
+                .ok_or_else(|| anyhow!("Kubeconfig has no contexts"))?,
+        };
+
+        let cluster = self
+            .clusters
+            .iter()
+            .find(|c| c.name == context.context.cluster)
+            .map(|c| &c.cluster)
+            .ok_or_else(|| anyhow!("Kubeconfig cluster '{}' not found", context.context.cluster))?;
+
+        let user = context
+            .context
+            .user
+            .as_ref()
+            .and_then(|name| self.users.iter().find(|u| &u.name == name))
+            .map(|u| &u.user);
+
+        Ok((cluster, user))
+    }
+}
+
+// ============================================================================
+// API objects
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct ObjectList<T> {
+    #[serde(default = "Vec::new")]
+    items: Vec<T>,
+    #[serde(default)]
+    metadata: ListMeta,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ListMeta {
+    #[serde(rename = "continue")]
+    continue_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ObjectMeta {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub uid: Option<String>,
+    pub labels: HashMap<String, String>,
+    pub owner_references: Vec<OwnerReference>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct OwnerReference {
+    pub kind: String,
+    pub name: String,
+    pub controller: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Node {
+    pub metadata: ObjectMeta,
+    pub spec: NodeSpec,
+    pub status: NodeStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NodeSpec {
+    #[serde(rename = "podCIDR")]
+    pub pod_cidr: Option<String>,
+    #[serde(rename = "podCIDRs")]
+    pub pod_cidrs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NodeStatus {
+    pub addresses: Vec<NodeAddress>,
+    pub node_info: NodeSystemInfo,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NodeAddress {
+    #[serde(rename = "type")]
+    pub address_type: String,
+    pub address: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NodeSystemInfo {
+    pub os_image: String,
+    pub kubelet_version: String,
+    pub container_runtime_version: String,
+}
+
+impl Node {
+    pub fn internal_ips(&self) -> Vec<IpAddr> {
+        self.status
+            .addresses
+            .iter()
+            .filter(|a| a.address_type == "InternalIP" || a.address_type == "ExternalIP")
+            .filter_map(|a| a.address.parse().ok())
+            .collect()
+    }
+
+    pub fn hostname(&self) -> Option<String> {
+        self.status
+            .addresses
+            .iter()
+            .find(|a| a.address_type == "Hostname")
+            .map(|a| a.address.clone())
+    }
+
+    pub fn pod_cidrs(&self) -> Vec<IpCidr> {
+        let mut cidrs: Vec<IpCidr> = self
+            .spec
+            .pod_cidrs
+            .iter()
+            .filter_map(|c| IpCidr::from_str(c).ok())
+            .collect();
+
+        if let Some(cidr) = self
+            .spec
+            .pod_cidr
+            .as_ref()
+            .and_then(|c| IpCidr::from_str(c).ok())
+            && !cidrs.contains(&cidr)
+        {
+            cidrs.push(cidr);
+        }
+
+        cidrs
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Pod {
+    pub metadata: ObjectMeta,
+    pub spec: PodSpec,
+    pub status: PodStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PodSpec {
+    pub node_name: Option<String>,
+    pub host_network: bool,
+    pub containers: Vec<Container>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Container {
+    pub name: String,
+    pub image: Option<String>,
+    pub ports: Vec<ContainerPort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ContainerPort {
+    pub name: Option<String>,
+    pub container_port: u16,
+    pub protocol: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PodStatus {
+    pub phase: Option<String>,
+    #[serde(rename = "podIP")]
+    pub pod_ip: Option<String>,
+}
+
+impl Pod {
+    pub fn namespace(&self) -> &str {
+        self.metadata.namespace.as_deref().unwrap_or("default")
+    }
+
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.status.pod_ip.as_ref().and_then(|ip| ip.parse().ok())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status.phase.as_deref() == Some("Running")
+    }
+
+    /// Name of the workload that owns this pod. Pods created by a Deployment are owned by a
+    /// ReplicaSet named `<deployment>-<pod-template-hash>`, so the hash is stripped to get a
+    /// name which stays stable across rollouts.
+    pub fn workload_name(&self) -> Option<String> {
+        let owner = self
+            .metadata
+            .owner_references
+            .iter()
+            .find(|o| o.controller == Some(true))
+            .or_else(|| self.metadata.owner_references.first())?;
+
+        if owner.kind == "ReplicaSet"
+            && let Some(hash) = self.metadata.labels.get("pod-template-hash")
+            && let Some(deployment) = owner.name.strip_suffix(&format!("-{}", hash))
+        {
+            return Some(deployment.to_string());
+        }
+
+        Some(owner.name.clone())
+    }
+
+    /// Resolve a service target port, which may be a container port name, to a number
+    pub fn resolve_port(&self, target: &IntOrString) -> Option<u16> {
+        match target {
+            IntOrString::Int(number) => Some(*number),
+            IntOrString::String(name) => self
+                .spec
+                .containers
+                .iter()
+                .flat_map(|c| c.ports.iter())
+                .find(|p| p.name.as_deref() == Some(name.as_str()))
+                .map(|p| p.container_port),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum IntOrString {
+    Int(u16),
+    String(String),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct KubeService {
+    pub metadata: ObjectMeta,
+    pub spec: ServiceSpec,
+    pub status: LoadBalancerStatusWrapper,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServiceSpec {
+    #[serde(rename = "type")]
+    pub service_type: Option<String>,
+    pub selector: Option<HashMap<String, String>>,
+    pub ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ServicePort {
+    pub name: Option<String>,
+    pub protocol: Option<String>,
+    pub port: u16,
+    pub target_port: Option<IntOrString>,
+    pub node_port: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LoadBalancerStatusWrapper {
+    pub load_balancer: LoadBalancerStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LoadBalancerStatus {
+    pub ingress: Vec<LoadBalancerIngress>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LoadBalancerIngress {
+    pub ip: Option<String>,
+    pub hostname: Option<String>,
+}
+
+impl LoadBalancerStatus {
+    pub fn addresses(&self) -> Vec<String> {
+        self.ingress
+            .iter()
+            .filter_map(|i| i.ip.clone().or_else(|| i.hostname.clone()))
+            .collect()
+    }
+}
+
+impl KubeService {
+    pub fn namespace(&self) -> &str {
+        self.metadata.namespace.as_deref().unwrap_or("default")
+    }
+
+    /// Whether this service's selector matches the pod. Services without a selector
+    /// (ExternalName, manually managed endpoints) select nothing.
+    pub fn selects(&self, pod: &Pod) -> bool {
+        match &self.spec.selector {
+            Some(selector) if !selector.is_empty() => {
+                self.namespace() == pod.namespace()
+                    && selector
+                        .iter()
+                        .all(|(k, v)| pod.metadata.labels.get(k) == Some(v))
+            }
+            _ => false,
+        }
+    }
+
+    /// Container port on the pod that a service port forwards to
+    pub fn target_port(&self, port: &ServicePort, pod: &Pod) -> Option<u16> {
+        match &port.target_port {
+            Some(target) => pod.resolve_port(target),
+            None => Some(port.port),
+        }
+    }
+
+    pub fn port_named(&self, name: &str) -> Option<&ServicePort> {
+        self.spec
+            .ports
+            .iter()
+            .find(|p| p.name.as_deref() == Some(name))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Ingress {
+    pub metadata: ObjectMeta,
+    pub spec: IngressSpec,
+    pub status: LoadBalancerStatusWrapper,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IngressSpec {
+    pub ingress_class_name: Option<String>,
+    pub default_backend: Option<IngressBackend>,
+    pub rules: Vec<IngressRule>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IngressRule {
+    pub host: Option<String>,
+    pub http: Option<HttpIngressRuleValue>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpIngressRuleValue {
+    pub paths: Vec<HttpIngressPath>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpIngressPath {
+    pub path: Option<String>,
+    pub backend: IngressBackend,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IngressBackend {
+    pub service: Option<IngressServiceBackend>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IngressServiceBackend {
+    pub name: String,
+    pub port: Option<ServiceBackendPort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServiceBackendPort {
+    pub name: Option<String>,
+    pub number: Option<u16>,
+}
+
+/// A single routing rule of an ingress: public host + path to a backend service port
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngressRoute {
+    pub host: Option<String>,
+    pub path: Option<String>,
+    pub service_name: String,
+    pub service_port: Option<IntOrString>,
+}
+
+impl Ingress {
+    pub fn namespace(&self) -> &str {
+        self.metadata.namespace.as_deref().unwrap_or("default")
+    }
+
+    pub fn routes(&self) -> Vec<IngressRoute> {
+        let to_route = |host: Option<String>, path: Option<String>, backend: &IngressBackend| {
+            backend.service.as_ref().map(|s| IngressRoute {
+                host,
+                path,
+                service_name: s.name.clone(),
+                service_port: s.port.as_ref().and_then(|p| {
+                    p.number
+                        .map(IntOrString::Int)
+                        .or_else(|| p.name.clone().map(IntOrString::String))
+                }),
+            })
+        };
+
+        let mut routes: Vec<IngressRoute> = self
+            .spec
+            .rules
+            .iter()
+            .flat_map(|rule| {
+                rule.http
+                    .iter()
+                    .flat_map(|http| http.paths.iter())
+                    .filter_map(|p| to_route(rule.host.clone(), p.path.clone(), &p.backend))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if let Some(backend) = &self.spec.default_backend
+            && let Some(route) = to_route(None, None, backend)
+        {
+            routes.push(route);
+        }
+
+        routes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KUBECONFIG: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: kind-scanopy
+clusters:
+- name: kind-scanopy
+  cluster:
+    server: https://127.0.0.1:6443
+    certificate-authority-data: LS0tLS1CRUdJTiBDRVJUSUZJQ0FURS0tLS0tCg==
+- name: other
+  cluster:
+    server: https://10.0.0.1:6443
+    insecure-skip-tls-verify: true
+contexts:
+- name: other
+  context:
+    cluster: other
+    user: other
+- name: kind-scanopy
+  context:
+    cluster: kind-scanopy
+    user: kind-scanopy
+users:
+- name: kind-scanopy
+  user:
+    token: abc123
+"#;
+
+    const PODS: &str = r#"{
+  "kind": "PodList",
+  "apiVersion": "v1",
+  "metadata": {"resourceVersion": "1234"},
+  "items": [
+    {
+      "metadata": {
+        "name": "web-5d8f7c9b4-x2x9z",
+        "namespace": "shop",
+        "uid": "2b1c",
+        "labels": {"app": "web", "pod-template-hash": "5d8f7c9b4"},
+        "ownerReferences": [
+          {"apiVersion": "apps/v1", "kind": "ReplicaSet", "name": "web-5d8f7c9b4", "controller": true}
+        ]
+      },
+      "spec": {
+        "nodeName": "kind-worker",
+        "containers": [
+          {"name": "nginx", "image": "nginx:1.27", "ports": [{"name": "http", "containerPort": 80, "protocol": "TCP"}]}
+        ]
+      },
+      "status": {"phase": "Running", "podIP": "10.244.1.5", "hostIP": "172.18.0.3"}
+    },
+    {
+      "metadata": {
+        "name": "db-0",
+        "namespace": "shop",
+        "labels": {"app": "db"},
+        "ownerReferences": [{"kind": "StatefulSet", "name": "db", "controller": true}]
+      },
+      "spec": {"nodeName": "kind-worker", "containers": [{"name": "postgres", "ports": [{"containerPort": 5432}]}]},
+      "status": {"phase": "Pending"}
+    }
+  ]
+}"#;
+
+    const SERVICES: &str = r#"{
+  "kind": "ServiceList",
+  "metadata": {"continue": ""},
+  "items": [
+    {
+      "metadata": {"name": "web", "namespace": "shop"},
+      "spec": {
+        "type": "NodePort",
+        "selector": {"app": "web"},
+        "ports": [{"name": "http", "port": 8080, "targetPort": "http", "nodePort": 30080, "protocol": "TCP"}]
+      },
+      "status": {"loadBalancer": {}}
+    },
+    {
+      "metadata": {"name": "external", "namespace": "shop"},
+      "spec": {"type": "ExternalName", "externalName": "example.com"}
+    }
+  ]
+}"#;
+
+    const INGRESSES: &str = r#"{
+  "items": [
+    {
+      "metadata": {"name": "shop", "namespace": "shop"},
+      "spec": {
+        "ingressClassName": "nginx",
+        "rules": [
+          {"host": "shop.example.com", "http": {"paths": [
+            {"path": "/", "pathType": "Prefix", "backend": {"service": {"name": "web", "port": {"name": "http"}}}}
+          ]}}
+        ]
+      },
+      "status": {"loadBalancer": {"ingress": [{"ip": "172.18.0.200"}]}}
+    }
+  ]
+}"#;
+
+    #[test]
+    fn parses_kubeconfig_current_context() {
+        let kubeconfig = KubeConfig::parse(KUBECONFIG).unwrap();
+        let (cluster, user) = kubeconfig.current().unwrap();
+
+        assert_eq!(cluster.server, "https://127.0.0.1:6443");
+        assert!(!cluster.insecure_skip_tls_verify);
+        assert!(cluster.certificate_authority_data.is_some());
+        assert_eq!(user.unwrap().token.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn parses_pod_list_and_resolves_workload() {
+        let pods: ObjectList<Pod> = serde_json::from_str(PODS).unwrap();
+        assert_eq!(pods.items.len(), 2);
+
+        let web = &pods.items[0];
+        assert!(web.is_running());
+        assert_eq!(web.namespace(), "shop");
+        assert_eq!(web.ip(), Some("10.244.1.5".parse().unwrap()));
+        assert_eq!(web.workload_name().as_deref(), Some("web"));
+        assert_eq!(
+            web.resolve_port(&IntOrString::String("http".to_string())),
+            Some(80)
+        );
+
+        let db = &pods.items[1];
+        assert!(!db.is_running());
+        assert_eq!(db.ip(), None);
+        assert_eq!(db.workload_name().as_deref(), Some("db"));
+    }
+
+    #[test]
+    fn service_selects_pods_by_label_and_namespace() {
+        let pods: ObjectList<Pod> = serde_json::from_str(PODS).unwrap();
+        let services: ObjectList<KubeService> = serde_json::from_str(SERVICES).unwrap();
+        assert_eq!(services.metadata.continue_token.as_deref(), Some(""));
+
+        let web = &services.items[0];
+        assert!(web.selects(&pods.items[0]));
+        assert!(!web.selects(&pods.items[1]));
+
+        let port = &web.spec.ports[0];
+        assert_eq!(web.target_port(port, &pods.items[0]), Some(80));
+        assert_eq!(port.node_port, Some(30080));
+
+        // No selector, selects nothing
+        let external = &services.items[1];
+        assert!(!external.selects(&pods.items[0]));
+    }
+
+    #[test]
+    fn ingress_routes_flatten_rules() {
+        let ingresses: ObjectList<Ingress> = serde_json::from_str(INGRESSES).unwrap();
+        let ingress = &ingresses.items[0];
+
+        assert_eq!(
+            ingress.routes(),
+            vec![IngressRoute {
+                host: Some("shop.example.com".to_string()),
+                path: Some("/".to_string()),
+                service_name: "web".to_string(),
+                service_port: Some(IntOrString::String("http".to_string())),
+            }]
+        );
+        assert_eq!(
+            ingress.status.load_balancer.addresses(),
+            vec!["172.18.0.200"]
+        );
+    }
+}
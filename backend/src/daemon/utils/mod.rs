@@ -1,6 +1,14 @@
 pub mod arp;
 pub mod base;
+pub mod containers;
+pub mod kubernetes;
 pub mod linux;
 pub mod macos;
+pub mod ntlm;
+pub mod proxies;
+pub mod proxmox;
 pub mod scanner;
+pub mod ssh;
+pub mod traceroute;
 pub mod windows;
+pub mod winrm;
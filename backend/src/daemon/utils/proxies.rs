@@ -0,0 +1,596 @@
+use anyhow::{Error, Result, anyhow};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::LazyLock;
+use std::time::Duration;
+use url::Url;
+use uuid::Uuid;
+
+use crate::server::bindings::handlers::BindingTarget;
+use crate::server::bindings::r#impl::base::BindingType;
+use crate::server::hosts::r#impl::api::HostResponse;
+use crate::server::services::definitions::caddy::Caddy;
+use crate::server::services::definitions::haproxy::HAProxy;
+use crate::server::services::definitions::nginx_proxy_manager::NginxProxyManager;
+use crate::server::services::definitions::traefik::Traefik;
+use crate::server::shared::types::metadata::HasId;
+
+const PROXY_API_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Matchers in a Traefik router rule, e.g. Host(`app.example.com`) && PathPrefix(`/api`)
+static TRAEFIK_MATCHER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(Host|Path|PathPrefix)\(([^)]*)\)").unwrap());
+static BACKTICKED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`([^`]*)`").unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseProxyKind {
+    Traefik,
+    Caddy,
+    NginxProxyManager,
+    HAProxy,
+}
+
+impl ReverseProxyKind {
+    pub fn from_service_definition(id: &str) -> Option<Self> {
+        [
+            (Traefik.id(), ReverseProxyKind::Traefik),
+            (Caddy.id(), ReverseProxyKind::Caddy),
+            (NginxProxyManager.id(), ReverseProxyKind::NginxProxyManager),
+            (HAProxy.id(), ReverseProxyKind::HAProxy),
+        ]
+        .into_iter()
+        .find(|(definition_id, _)| *definition_id == id)
+        .map(|(_, kind)| kind)
+    }
+}
+
+/// A matched reverse proxy and where its API or admin endpoint was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReverseProxy {
+    pub kind: ReverseProxyKind,
+    pub name: String,
+    /// Binding the proxy was matched on, which requests enter through
+    pub binding_id: Uuid,
+    pub ip: IpAddr,
+    pub port: u16,
+}
+
+impl ReverseProxy {
+    /// Reverse proxies among a created host's services. The matched binding's port is where
+    /// each definition found the API (Traefik's dashboard, Caddy's admin endpoint, NPM's admin
+    /// UI, HAProxy's stats page).
+    pub fn from_host_response(host: &HostResponse) -> Vec<Self> {
+        host.services
+            .iter()
+            .filter_map(|service| {
+                let kind = ReverseProxyKind::from_service_definition(
+                    service.base.service_definition.id(),
+                )?;
+
+                service.base.bindings.iter().find_map(|binding| {
+                    let BindingType::Port {
+                        port_id,
+                        interface_id,
+                    } = binding.base.binding_type
+                    else {
+                        return None;
+                    };
+
+                    let port = host.ports.iter().find(|p| p.id == port_id)?;
+                    let interface = match interface_id {
+                        Some(id) => host.interfaces.iter().find(|i| i.id == id),
+                        None => host.interfaces.first(),
+                    }?;
+
+                    Some(ReverseProxy {
+                        kind,
+                        name: service.base.name.clone(),
+                        binding_id: binding.id(),
+                        ip: interface.base.ip_address,
+                        port: port.base.port_type.number(),
+                    })
+                })
+            })
+            .collect()
+    }
+
+    fn base_url(&self) -> String {
+        match self.ip {
+            IpAddr::V4(ip) => format!("http://{}:{}", ip, self.port),
+            IpAddr::V6(ip) => format!("http://[{}]:{}", ip, self.port),
+        }
+    }
+
+    /// Read the proxy's routes from its API
+    pub async fn fetch_routes(
+        &self,
+        npm_credentials: Option<&(String, String)>,
+    ) -> Result<Vec<ProxyRoute>, Error> {
+        let client = reqwest::Client::builder()
+            .timeout(PROXY_API_TIMEOUT)
+            .danger_accept_invalid_certs(true)
+            .build()
+            .map_err(|e| anyhow!("Could not build client {}", e))?;
+
+        let base_url = self.base_url();
+
+        match self.kind {
+            ReverseProxyKind::Traefik => {
+                let routers: Vec<TraefikRouter> = client
+                    .get(format!("{}/api/http/routers", base_url))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                let services: Vec<TraefikService> = client
+                    .get(format!("{}/api/http/services", base_url))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(traefik_routes(routers, services))
+            }
+            ReverseProxyKind::Caddy => {
+                let config: Value = client
+                    .get(format!("{}/config/", base_url))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(caddy_routes(&config))
+            }
+            ReverseProxyKind::NginxProxyManager => {
+                let (identity, secret) = npm_credentials
+                    .ok_or_else(|| anyhow!("Nginx Proxy Manager credentials are not configured"))?;
+                let token: NpmToken = client
+                    .post(format!("{}/api/tokens", base_url))
+                    .json(&serde_json::json!({ "identity": identity, "secret": secret }))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                let proxy_hosts: Vec<NpmProxyHost> = client
+                    .get(format!("{}/api/nginx/proxy-hosts", base_url))
+                    .bearer_auth(token.token)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(npm_routes(proxy_hosts))
+            }
+            ReverseProxyKind::HAProxy => {
+                let csv = client
+                    .get(format!("{}/stats;csv", base_url))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?;
+                Ok(haproxy_routes(&csv))
+            }
+        }
+    }
+}
+
+/// A route through a reverse proxy to one or more backends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyRoute {
+    /// Public hostname requests are matched on, if the route matches on one
+    pub hostname: Option<String>,
+    pub path: Option<String>,
+    /// Name of the route (router, backend) in the proxy's config
+    pub name: String,
+    pub backends: Vec<BindingTarget>,
+}
+
+impl ProxyRoute {
+    /// Name for the route's group: the public URL it serves, else the route's own name
+    pub fn display_name(&self) -> String {
+        match (&self.hostname, &self.path) {
+            (Some(hostname), Some(path)) => format!("{}{}", hostname, path),
+            (Some(hostname), None) => hostname.clone(),
+            (None, _) => self.name.clone(),
+        }
+    }
+}
+
+/// Split `host:port`, `[v6]:port` or a URL into a binding target
+fn parse_backend(address: &str, default_port: Option<u16>) -> Option<BindingTarget> {
+    if address.contains("://") {
+        let url = Url::parse(address).ok()?;
+        return Some(BindingTarget {
+            address: url.host_str()?.trim_matches(['[', ']']).to_string(),
+            port: url.port_or_known_default()?,
+        });
+    }
+
+    match address.rsplit_once(':') {
+        Some((host, port)) if !host.is_empty() && !host.ends_with(':') => Some(BindingTarget {
+            address: host.trim_matches(['[', ']']).to_string(),
+            port: port.parse().ok()?,
+        }),
+        _ => Some(BindingTarget {
+            address: address.trim_matches(['[', ']']).to_string(),
+            port: default_port?,
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TraefikRouter {
+    name: String,
+    #[serde(default)]
+    rule: String,
+    service: String,
+    #[serde(default)]
+    provider: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TraefikService {
+    name: String,
+    #[serde(default, rename = "loadBalancer")]
+    load_balancer: Option<TraefikLoadBalancer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TraefikLoadBalancer {
+    #[serde(default)]
+    servers: Vec<TraefikServer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TraefikServer {
+    url: String,
+}
+
+/// One route per router host. Routers reference services without a provider suffix when the
+/// service comes from the same provider as the router.
+fn traefik_routes(routers: Vec<TraefikRouter>, services: Vec<TraefikService>) -> Vec<ProxyRoute> {
+    let backends_by_service: HashMap<String, Vec<BindingTarget>> = services
+        .into_iter()
+        .filter_map(|s| {
+            let servers = s.load_balancer?.servers;
+            let backends = servers
+                .iter()
+                .filter_map(|server| parse_backend(&server.url, None))
+                .collect();
+            Some((s.name, backends))
+        })
+        .collect();
+
+    routers
+        .into_iter()
+        .flat_map(|router| {
+            let service = match (&router.provider, router.service.contains('@')) {
+                (Some(provider), false) => format!("{}@{}", router.service, provider),
+                _ => router.service.clone(),
+            };
+            let Some(backends) = backends_by_service.get(&service).filter(|b| !b.is_empty()) else {
+                return Vec::new();
+            };
+
+            let mut hosts = Vec::new();
+            let mut path = None;
+            for captures in TRAEFIK_MATCHER.captures_iter(&router.rule) {
+                let values = BACKTICKED
+                    .captures_iter(&captures[2])
+                    .map(|c| c[1].to_string());
+                match &captures[1] {
+                    "Host" => hosts.extend(values),
+                    _ => path = path.or(values.into_iter().next()),
+                }
+            }
+
+            let hostnames: Vec<Option<String>> = if hosts.is_empty() {
+                vec![None]
+            } else {
+                hosts.into_iter().map(Some).collect()
+            };
+
+            hostnames
+                .into_iter()
+                .map(|hostname| ProxyRoute {
+                    hostname,
+                    path: path.clone(),
+                    name: router.name.clone(),
+                    backends: backends.clone(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Walk Caddy's JSON config for `reverse_proxy` handlers, carrying host and path matchers
+/// down through subroutes
+fn caddy_routes(config: &Value) -> Vec<ProxyRoute> {
+    fn walk(
+        routes: &[Value],
+        name: &str,
+        hostnames: &[String],
+        path: Option<&str>,
+        out: &mut Vec<ProxyRoute>,
+    ) {
+        for route in routes {
+            let matchers = route["match"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let matched_hosts: Vec<String> = matchers
+                .iter()
+                .filter_map(|m| m["host"].as_array())
+                .flatten()
+                .filter_map(|h| h.as_str().map(str::to_string))
+                .collect();
+            let matched_path = matchers
+                .iter()
+                .find_map(|m| m["path"].as_array()?.first()?.as_str())
+                .map(|p| p.trim_end_matches('*'));
+
+            let hostnames = if matched_hosts.is_empty() {
+                hostnames.to_vec()
+            } else {
+                matched_hosts
+            };
+            let path = matched_path.or(path);
+
+            for handler in route["handle"].as_array().into_iter().flatten() {
+                match handler["handler"].as_str() {
+                    Some("reverse_proxy") => {
+                        let backends: Vec<BindingTarget> = handler["upstreams"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|u| u["dial"].as_str())
+                            // Placeholders are resolved per request
+                            .filter(|dial| !dial.contains('{'))
+                            .filter_map(|dial| parse_backend(dial, None))
+                            .collect();
+                        if backends.is_empty() {
+                            continue;
+                        }
+
+                        let route_hostnames: Vec<Option<String>> = if hostnames.is_empty() {
+                            vec![None]
+                        } else {
+                            hostnames.iter().cloned().map(Some).collect()
+                        };
+                        out.extend(route_hostnames.into_iter().map(|hostname| ProxyRoute {
+                            hostname,
+                            path: path.filter(|p| *p != "/").map(str::to_string),
+                            name: name.to_string(),
+                            backends: backends.clone(),
+                        }));
+                    }
+                    Some("subroute") => {
+                        if let Some(routes) = handler["routes"].as_array() {
+                            walk(routes, name, &hostnames, path, out);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    if let Some(servers) = config["apps"]["http"]["servers"].as_object() {
+        for (name, server) in servers {
+            if let Some(routes) = server["routes"].as_array() {
+                walk(routes, name, &[], None, &mut out);
+            }
+        }
+    }
+    out
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmToken {
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmProxyHost {
+    #[serde(default)]
+    domain_names: Vec<String>,
+    forward_host: String,
+    forward_port: u16,
+    /// NPM has returned this as both 0/1 and a boolean across versions
+    #[serde(default)]
+    enabled: Value,
+    #[serde(default)]
+    locations: Vec<NpmLocation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmLocation {
+    path: String,
+    forward_host: String,
+    forward_port: u16,
+}
+
+fn npm_routes(proxy_hosts: Vec<NpmProxyHost>) -> Vec<ProxyRoute> {
+    proxy_hosts
+        .into_iter()
+        .filter(|h| !matches!(h.enabled, Value::Bool(false)) && h.enabled != 0)
+        .flat_map(|h| {
+            let mut targets = vec![(
+                None,
+                BindingTarget {
+                    address: h.forward_host.clone(),
+                    port: h.forward_port,
+                },
+            )];
+            targets.extend(h.locations.iter().map(|l| {
+                (
+                    Some(l.path.clone()),
+                    BindingTarget {
+                        address: l.forward_host.clone(),
+                        port: l.forward_port,
+                    },
+                )
+            }));
+
+            h.domain_names
+                .iter()
+                .flat_map(|domain| {
+                    targets.iter().map(move |(path, backend)| ProxyRoute {
+                        hostname: Some(domain.clone()),
+                        path: path.clone(),
+                        name: domain.clone(),
+                        backends: vec![backend.clone()],
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// HAProxy stats only know backends and their servers, so there is one route per backend.
+/// Server rows carry their address in the `addr` column.
+fn haproxy_routes(csv: &str) -> Vec<ProxyRoute> {
+    let mut lines = csv.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let columns: Vec<&str> = header.trim_start_matches("# ").split(',').collect();
+    let column = |name: &str| columns.iter().position(|c| *c == name);
+    let (Some(pxname), Some(svname), Some(addr)) =
+        (column("pxname"), column("svname"), column("addr"))
+    else {
+        return Vec::new();
+    };
+
+    let mut routes: Vec<ProxyRoute> = Vec::new();
+    for line in lines {
+        let fields: Vec<&str> = line.split(',').collect();
+        let (Some(backend), Some(server), Some(address)) =
+            (fields.get(pxname), fields.get(svname), fields.get(addr))
+        else {
+            continue;
+        };
+        if matches!(*server, "FRONTEND" | "BACKEND") || address.is_empty() {
+            continue;
+        }
+        let Some(target) = parse_backend(address, None) else {
+            continue;
+        };
+
+        match routes.iter_mut().find(|r| r.name == *backend) {
+            Some(route) => route.backends.push(target),
+            None => routes.push(ProxyRoute {
+                hostname: None,
+                path: None,
+                name: backend.to_string(),
+                backends: vec![target],
+            }),
+        }
+    }
+    routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(address: &str, port: u16) -> BindingTarget {
+        BindingTarget {
+            address: address.to_string(),
+            port,
+        }
+    }
+
+    #[test]
+    fn test_traefik_routes() {
+        let routers: Vec<TraefikRouter> = serde_json::from_str(
+            r#"[
+                {"name": "blog@docker", "provider": "docker", "service": "blog",
+                 "rule": "Host(`blog.example.com`) && PathPrefix(`/api`)"},
+                {"name": "dashboard@internal", "provider": "internal", "service": "api@internal",
+                 "rule": "PathPrefix(`/api`)"}
+            ]"#,
+        )
+        .unwrap();
+        let services: Vec<TraefikService> = serde_json::from_str(
+            r#"[
+                {"name": "blog@docker", "loadBalancer": {"servers": [{"url": "http://172.18.0.3:2368"}]}},
+                {"name": "api@internal"}
+            ]"#,
+        )
+        .unwrap();
+
+        let routes = traefik_routes(routers, services);
+
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].hostname.as_deref(), Some("blog.example.com"));
+        assert_eq!(routes[0].path.as_deref(), Some("/api"));
+        assert_eq!(routes[0].backends, vec![target("172.18.0.3", 2368)]);
+        assert_eq!(routes[0].display_name(), "blog.example.com/api");
+    }
+
+    #[test]
+    fn test_caddy_routes() {
+        let config: Value = serde_json::from_str(
+            r#"{"apps": {"http": {"servers": {"srv0": {"routes": [{
+                "match": [{"host": ["photos.example.com"]}],
+                "handle": [{"handler": "subroute", "routes": [{
+                    "handle": [{"handler": "reverse_proxy", "upstreams": [{"dial": "immich:2283"}]}]
+                }]}]
+            }]}}}}}"#,
+        )
+        .unwrap();
+
+        let routes = caddy_routes(&config);
+
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].hostname.as_deref(), Some("photos.example.com"));
+        assert_eq!(routes[0].path, None);
+        assert_eq!(routes[0].backends, vec![target("immich", 2283)]);
+    }
+
+    #[test]
+    fn test_npm_routes() {
+        let proxy_hosts: Vec<NpmProxyHost> = serde_json::from_str(
+            r#"[
+                {"domain_names": ["git.example.com"], "forward_host": "10.0.0.12", "forward_port": 3000,
+                 "enabled": 1, "locations": [{"path": "/ci", "forward_host": "10.0.0.13", "forward_port": 8000}]},
+                {"domain_names": ["old.example.com"], "forward_host": "10.0.0.14", "forward_port": 80, "enabled": false}
+            ]"#,
+        )
+        .unwrap();
+
+        let routes = npm_routes(proxy_hosts);
+
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0].display_name(), "git.example.com");
+        assert_eq!(routes[0].backends, vec![target("10.0.0.12", 3000)]);
+        assert_eq!(routes[1].display_name(), "git.example.com/ci");
+        assert_eq!(routes[1].backends, vec![target("10.0.0.13", 8000)]);
+    }
+
+    #[test]
+    fn test_haproxy_routes() {
+        let csv = "# pxname,svname,qcur,status,addr\n\
+                   http-in,FRONTEND,,OPEN,\n\
+                   web,web1,0,UP,10.0.0.21:8080\n\
+                   web,web2,0,UP,10.0.0.22:8080\n\
+                   web,BACKEND,0,UP,\n";
+
+        let routes = haproxy_routes(csv);
+
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].display_name(), "web");
+        assert_eq!(
+            routes[0].backends,
+            vec![target("10.0.0.21", 8080), target("10.0.0.22", 8080)]
+        );
+    }
+}
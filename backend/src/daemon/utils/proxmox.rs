@@ -0,0 +1,508 @@
+//! Minimal read-only client for the Proxmox VE API.
+//!
+//! Only the endpoints used by Proxmox discovery are implemented: cluster membership,
+//! guest lists, guest network config, and guest-reported addresses.
+
+use anyhow::{Error, anyhow, bail};
+use mac_address::MacAddress;
+use reqwest::{Client, header};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
+
+use crate::server::hosts::r#impl::virtualization::{ProxmoxGuestStatus, ProxmoxGuestType};
+
+pub struct ProxmoxClient {
+    client: Client,
+    api_url: String,
+}
+
+impl ProxmoxClient {
+    /// Build a client for an API token in the form `user@realm!tokenid=secret`
+    pub fn new(api_url: &str, token: &str, skip_tls_verify: bool) -> Result<Self, Error> {
+        if !token.contains('!') || !token.contains('=') {
+            bail!("Proxmox API token must be in the form user@realm!tokenid=secret");
+        }
+
+        let mut auth = header::HeaderValue::from_str(&format!("PVEAPIToken={}", token))
+            .map_err(|_| anyhow!("Proxmox API token contains invalid characters"))?;
+        auth.set_sensitive(true);
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, auth);
+
+        let client = Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+            .danger_accept_invalid_certs(skip_tls_verify)
+            .default_headers(headers)
+            .build()
+            .map_err(|e| anyhow!("Failed to build Proxmox client: {}", e))?;
+
+        let api_url = api_url
+            .trim_end_matches('/')
+            .trim_end_matches("/api2/json")
+            .to_string();
+
+        Ok(Self { client, api_url })
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        let url = format!("{}/api2/json{}", self.api_url, path);
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Proxmox API request to {} failed: {}", path, e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!(
+                "Proxmox API request to {} failed with status {}: {}",
+                path,
+                status,
+                body
+            );
+        }
+
+        let envelope: Envelope<T> = response.json().await?;
+        Ok(envelope.data)
+    }
+
+    /// Nodes of the cluster. A standalone node reports itself as a single-node cluster.
+    pub async fn list_nodes(&self) -> Result<Vec<ClusterNode>, Error> {
+        let members: Vec<ClusterMember> = self.get("/cluster/status").await?;
+
+        Ok(members
+            .into_iter()
+            .filter(|m| m.member_type == "node")
+            .map(|m| ClusterNode {
+                name: m.name,
+                ip: m.ip.and_then(|ip| ip.parse().ok()),
+                online: m.online.is_none_or(|online| online != 0),
+            })
+            .collect())
+    }
+
+    /// QEMU VMs and LXC containers on a node, including stopped guests. Templates are skipped.
+    pub async fn list_guests(&self, node: &str) -> Result<Vec<Guest>, Error> {
+        let mut guests = Vec::new();
+
+        for guest_type in [ProxmoxGuestType::Qemu, ProxmoxGuestType::Lxc] {
+            let listed: Vec<GuestListItem> = self
+                .get(&format!("/nodes/{}/{}", node, guest_path(guest_type)))
+                .await?;
+
+            guests.extend(
+                listed
+                    .into_iter()
+                    .filter(|g| g.template.is_none_or(|t| t == 0))
+                    .map(|g| Guest {
+                        vmid: g.vmid.to_string(),
+                        status: guest_status(&g.status, g.qmpstatus.as_deref()),
+                        name: g.name,
+                        node: node.to_string(),
+                        guest_type,
+                    }),
+            );
+        }
+
+        Ok(guests)
+    }
+
+    /// NICs declared in a guest's config. Available whether or not the guest is running.
+    pub async fn guest_nics(&self, guest: &Guest) -> Result<Vec<GuestNic>, Error> {
+        let config: HashMap<String, serde_json::Value> = self
+            .get(&format!(
+                "/nodes/{}/{}/{}/config",
+                guest.node,
+                guest_path(guest.guest_type),
+                guest.vmid
+            ))
+            .await?;
+
+        let mut nics: Vec<GuestNic> = config
+            .iter()
+            .filter(|(key, _)| is_net_key(key))
+            .filter_map(|(key, value)| parse_net_config(key, value.as_str()?, guest.guest_type))
+            .collect();
+
+        nics.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Ok(nics)
+    }
+
+    /// Addresses reported from inside a running guest, through the QEMU guest agent or the
+    /// LXC interfaces endpoint. Fails if the agent isn't installed or the guest is stopped.
+    pub async fn guest_addresses(&self, guest: &Guest) -> Result<Vec<GuestAddress>, Error> {
+        match guest.guest_type {
+            ProxmoxGuestType::Qemu => {
+                let response: AgentResponse = self
+                    .get(&format!(
+                        "/nodes/{}/qemu/{}/agent/network-get-interfaces",
+                        guest.node, guest.vmid
+                    ))
+                    .await?;
+                Ok(agent_addresses(response.result))
+            }
+            ProxmoxGuestType::Lxc => {
+                let interfaces: Vec<LxcInterface> = self
+                    .get(&format!(
+                        "/nodes/{}/lxc/{}/interfaces",
+                        guest.node, guest.vmid
+                    ))
+                    .await?;
+                Ok(lxc_addresses(interfaces))
+            }
+        }
+    }
+}
+
+pub struct ClusterNode {
+    pub name: String,
+    pub ip: Option<IpAddr>,
+    pub online: bool,
+}
+
+pub struct Guest {
+    pub vmid: String,
+    pub name: Option<String>,
+    pub node: String,
+    pub guest_type: ProxmoxGuestType,
+    pub status: ProxmoxGuestStatus,
+}
+
+/// A network device from a guest's config (net0, net1...)
+#[derive(Debug, PartialEq, Eq)]
+pub struct GuestNic {
+    /// Config key, e.g. net0
+    pub key: String,
+    pub mac_address: MacAddress,
+    /// Interface name inside the guest, only known for LXC containers
+    pub name: Option<String>,
+    /// Statically configured address, only known for LXC containers
+    pub static_ip: Option<IpAddr>,
+}
+
+/// An address reported from inside a running guest
+#[derive(Debug, PartialEq, Eq)]
+pub struct GuestAddress {
+    pub name: Option<String>,
+    pub mac_address: Option<MacAddress>,
+    pub ip: IpAddr,
+}
+
+fn guest_path(guest_type: ProxmoxGuestType) -> &'static str {
+    match guest_type {
+        ProxmoxGuestType::Qemu => "qemu",
+        ProxmoxGuestType::Lxc => "lxc",
+    }
+}
+
+/// `status` is only running or stopped; QEMU guests also report `qmpstatus`, which
+/// distinguishes paused and suspended VMs
+fn guest_status(status: &str, qmpstatus: Option<&str>) -> ProxmoxGuestStatus {
+    match (status, qmpstatus) {
+        (_, Some("paused" | "suspended" | "prelaunch")) => ProxmoxGuestStatus::Paused,
+        ("running", _) => ProxmoxGuestStatus::Running,
+        _ => ProxmoxGuestStatus::Stopped,
+    }
+}
+
+fn is_net_key(key: &str) -> bool {
+    key.strip_prefix("net")
+        .is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parse a netN config value.
+///
+/// QEMU: `virtio=BC:24:11:2A:6E:01,bridge=vmbr0,firewall=1` (the model key holds the MAC)
+/// LXC: `name=eth0,bridge=vmbr0,hwaddr=BC:24:11:2A:6E:02,ip=192.168.1.20/24,type=veth`
+fn parse_net_config(key: &str, value: &str, guest_type: ProxmoxGuestType) -> Option<GuestNic> {
+    let options: Vec<(&str, &str)> = value
+        .split(',')
+        .filter_map(|option| option.split_once('='))
+        .collect();
+    let option = |name: &str| options.iter().find(|(k, _)| *k == name).map(|(_, v)| *v);
+
+    match guest_type {
+        ProxmoxGuestType::Qemu => {
+            let mac_address = options
+                .iter()
+                .find_map(|(_, v)| MacAddress::from_str(v).ok())?;
+            Some(GuestNic {
+                key: key.to_string(),
+                mac_address,
+                name: None,
+                static_ip: None,
+            })
+        }
+        ProxmoxGuestType::Lxc => Some(GuestNic {
+            key: key.to_string(),
+            mac_address: MacAddress::from_str(option("hwaddr")?).ok()?,
+            name: option("name").map(str::to_string),
+            static_ip: option("ip")
+                .and_then(|ip| ip.split('/').next())
+                .and_then(|ip| ip.parse().ok()),
+        }),
+    }
+}
+
+fn is_reportable(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => !v4.is_loopback() && !v4.is_link_local(),
+        IpAddr::V6(v6) => !v6.is_loopback() && !v6.is_unicast_link_local(),
+    }
+}
+
+fn agent_addresses(interfaces: Vec<AgentInterface>) -> Vec<GuestAddress> {
+    interfaces
+        .into_iter()
+        .flat_map(|iface| {
+            let mac_address = iface
+                .hardware_address
+                .as_deref()
+                .and_then(|mac| MacAddress::from_str(mac).ok())
+                .filter(|mac| mac.bytes() != [0; 6]);
+            iface
+                .ip_addresses
+                .into_iter()
+                .filter_map(|a| a.ip_address.parse::<IpAddr>().ok())
+                .filter(is_reportable)
+                .map(move |ip| GuestAddress {
+                    name: Some(iface.name.clone()),
+                    mac_address,
+                    ip,
+                })
+        })
+        .collect()
+}
+
+fn lxc_addresses(interfaces: Vec<LxcInterface>) -> Vec<GuestAddress> {
+    interfaces
+        .into_iter()
+        .flat_map(|iface| {
+            let mac_address = iface
+                .hwaddr
+                .as_deref()
+                .and_then(|mac| MacAddress::from_str(mac).ok())
+                .filter(|mac| mac.bytes() != [0; 6]);
+            [iface.inet, iface.inet6]
+                .into_iter()
+                .flatten()
+                .filter_map(|cidr| cidr.split('/').next()?.parse::<IpAddr>().ok())
+                .filter(is_reportable)
+                .map(move |ip| GuestAddress {
+                    name: Some(iface.name.clone()),
+                    mac_address,
+                    ip,
+                })
+        })
+        .collect()
+}
+
+// ============================================================================
+// API objects
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClusterMember {
+    #[serde(rename = "type")]
+    member_type: String,
+    name: String,
+    ip: Option<String>,
+    online: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GuestListItem {
+    vmid: VmId,
+    name: Option<String>,
+    status: String,
+    qmpstatus: Option<String>,
+    template: Option<u8>,
+}
+
+/// LXC lists have returned the VM ID as a string on some releases
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum VmId {
+    Number(u64),
+    Text(String),
+}
+
+impl std::fmt::Display for VmId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VmId::Number(n) => write!(f, "{}", n),
+            VmId::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AgentResponse {
+    #[serde(default)]
+    result: Vec<AgentInterface>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct AgentInterface {
+    name: String,
+    hardware_address: Option<String>,
+    #[serde(default)]
+    ip_addresses: Vec<AgentIpAddress>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct AgentIpAddress {
+    ip_address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LxcInterface {
+    name: String,
+    hwaddr: Option<String>,
+    inet: Option<String>,
+    inet6: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_qemu_net_config() {
+        let nic = parse_net_config(
+            "net0",
+            "virtio=BC:24:11:2A:6E:01,bridge=vmbr0,firewall=1",
+            ProxmoxGuestType::Qemu,
+        )
+        .unwrap();
+
+        assert_eq!(nic.key, "net0");
+        assert_eq!(
+            nic.mac_address,
+            MacAddress::from_str("BC:24:11:2A:6E:01").unwrap()
+        );
+        assert_eq!(nic.name, None);
+        assert_eq!(nic.static_ip, None);
+    }
+
+    #[test]
+    fn test_parse_lxc_net_config() {
+        let nic = parse_net_config(
+            "net1",
+            "name=eth0,bridge=vmbr0,hwaddr=BC:24:11:2A:6E:02,ip=192.168.1.20/24,gw=192.168.1.1,type=veth",
+            ProxmoxGuestType::Lxc,
+        )
+        .unwrap();
+
+        assert_eq!(nic.name.as_deref(), Some("eth0"));
+        assert_eq!(nic.static_ip, Some("192.168.1.20".parse().unwrap()));
+
+        let dhcp = parse_net_config(
+            "net0",
+            "name=eth0,bridge=vmbr0,hwaddr=BC:24:11:2A:6E:03,ip=dhcp,type=veth",
+            ProxmoxGuestType::Lxc,
+        )
+        .unwrap();
+        assert_eq!(dhcp.static_ip, None);
+
+        assert!(
+            parse_net_config("net0", "name=eth0,bridge=vmbr0", ProxmoxGuestType::Lxc).is_none()
+        );
+    }
+
+    #[test]
+    fn test_is_net_key() {
+        assert!(is_net_key("net0"));
+        assert!(is_net_key("net12"));
+        assert!(!is_net_key("net"));
+        assert!(!is_net_key("netx"));
+        assert!(!is_net_key("nameserver"));
+    }
+
+    #[test]
+    fn test_guest_status() {
+        assert_eq!(guest_status("running", None), ProxmoxGuestStatus::Running);
+        assert_eq!(
+            guest_status("running", Some("paused")),
+            ProxmoxGuestStatus::Paused
+        );
+        assert_eq!(guest_status("stopped", None), ProxmoxGuestStatus::Stopped);
+    }
+
+    #[test]
+    fn test_guest_list_vmid_formats() {
+        let listed: Vec<GuestListItem> = serde_json::from_str(
+            r#"[
+                {"vmid": 100, "name": "web", "status": "running", "qmpstatus": "running"},
+                {"vmid": "101", "name": "db", "status": "stopped", "template": 0}
+            ]"#,
+        )
+        .unwrap();
+
+        let ids: Vec<String> = listed.iter().map(|g| g.vmid.to_string()).collect();
+        assert_eq!(ids, vec!["100", "101"]);
+    }
+
+    #[test]
+    fn test_agent_addresses_skip_loopback_and_link_local() {
+        let response: AgentResponse = serde_json::from_str(
+            r#"{"result": [
+                {
+                    "name": "lo",
+                    "hardware-address": "00:00:00:00:00:00",
+                    "ip-addresses": [{"ip-address": "127.0.0.1", "ip-address-type": "ipv4", "prefix": 8}]
+                },
+                {
+                    "name": "ens18",
+                    "hardware-address": "bc:24:11:2a:6e:01",
+                    "ip-addresses": [
+                        {"ip-address": "192.168.1.50", "ip-address-type": "ipv4", "prefix": 24},
+                        {"ip-address": "fe80::be24:11ff:fe2a:6e01", "ip-address-type": "ipv6", "prefix": 64}
+                    ]
+                }
+            ]}"#,
+        )
+        .unwrap();
+
+        let addresses = agent_addresses(response.result);
+
+        assert_eq!(
+            addresses,
+            vec![GuestAddress {
+                name: Some("ens18".to_string()),
+                mac_address: Some(MacAddress::from_str("bc:24:11:2a:6e:01").unwrap()),
+                ip: "192.168.1.50".parse().unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lxc_addresses() {
+        let interfaces: Vec<LxcInterface> = serde_json::from_str(
+            r#"[
+                {"name": "lo", "hwaddr": "00:00:00:00:00:00", "inet": "127.0.0.1/8", "inet6": "::1/128"},
+                {"name": "eth0", "hwaddr": "bc:24:11:2a:6e:02", "inet": "192.168.1.20/24"}
+            ]"#,
+        )
+        .unwrap();
+
+        let addresses = lxc_addresses(interfaces);
+
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0].ip, "192.168.1.20".parse::<IpAddr>().unwrap());
+        assert_eq!(addresses[0].name.as_deref(), Some("eth0"));
+    }
+}
@@ -0,0 +1,509 @@
+//! Minimal SSH client for agentless host inventory.
+//!
+//! Only runs a fixed set of read-only commands on the remote host and parses their output:
+//! os-release, hostname, interface addresses, listening sockets and running containers.
+
+use anyhow::{Error, anyhow, bail};
+use mac_address::MacAddress;
+use russh::ChannelMsg;
+use russh::client::{self, Handle};
+use russh::keys::{HashAlg, PrivateKeyWithHashAlg, PublicKey, decode_secret_key};
+use std::{collections::HashMap, net::IpAddr, str::FromStr, sync::Arc, time::Duration};
+
+use crate::server::credentials::r#impl::base::CredentialSecret;
+use crate::server::ports::r#impl::base::TransportProtocol;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Accepts any host key. Inventory targets are picked explicitly by the user, so the
+/// fingerprint is logged rather than checked against a known_hosts file.
+struct InventoryHandler {
+    ip: IpAddr,
+}
+
+impl client::Handler for InventoryHandler {
+    type Error = russh::Error;
+
+    async fn check_server_key(&mut self, key: &PublicKey) -> Result<bool, Self::Error> {
+        tracing::debug!(
+            ip = %self.ip,
+            fingerprint = %key.fingerprint(HashAlg::Sha256),
+            "Accepting SSH host key"
+        );
+        Ok(true)
+    }
+}
+
+pub struct SshSession {
+    handle: Handle<InventoryHandler>,
+}
+
+impl SshSession {
+    /// Connect and authenticate with the first secret the host accepts. Secrets other than
+    /// SSH keys and username/password pairs are ignored.
+    pub async fn connect(
+        ip: IpAddr,
+        port: u16,
+        secrets: &[CredentialSecret],
+    ) -> Result<Self, Error> {
+        let config = Arc::new(client::Config {
+            inactivity_timeout: Some(COMMAND_TIMEOUT),
+            ..Default::default()
+        });
+
+        let mut handle = tokio::time::timeout(
+            CONNECT_TIMEOUT,
+            client::connect(config, (ip, port), InventoryHandler { ip }),
+        )
+        .await
+        .map_err(|_| anyhow!("Timed out connecting to {}:{}", ip, port))?
+        .map_err(|e| anyhow!("Failed to connect to {}:{}: {}", ip, port, e))?;
+
+        for secret in secrets {
+            let authenticated = match secret {
+                CredentialSecret::SshKey {
+                    username,
+                    private_key,
+                    passphrase,
+                } => {
+                    let key = match decode_secret_key(private_key, passphrase.as_deref()) {
+                        Ok(key) => key,
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Failed to decode SSH private key");
+                            continue;
+                        }
+                    };
+                    let hash_alg = handle.best_supported_rsa_hash().await?.flatten();
+                    handle
+                        .authenticate_publickey(
+                            username,
+                            PrivateKeyWithHashAlg::new(Arc::new(key), hash_alg),
+                        )
+                        .await?
+                        .success()
+                }
+                CredentialSecret::UsernamePassword { username, password } => handle
+                    .authenticate_password(username, password)
+                    .await?
+                    .success(),
+                _ => continue,
+            };
+
+            if authenticated {
+                return Ok(Self { handle });
+            }
+        }
+
+        bail!("No credential was accepted by {}:{}", ip, port)
+    }
+
+    /// Run a command, returning its stdout if it exited successfully
+    pub async fn exec(&self, command: &str) -> Result<Option<String>, Error> {
+        let mut channel = self.handle.channel_open_session().await?;
+        channel.exec(true, command).await?;
+
+        let mut stdout = Vec::new();
+        let mut exit_status = None;
+
+        tokio::time::timeout(COMMAND_TIMEOUT, async {
+            while let Some(msg) = channel.wait().await {
+                match msg {
+                    ChannelMsg::Data { ref data } => stdout.extend_from_slice(data),
+                    ChannelMsg::ExitStatus { exit_status: code } => exit_status = Some(code),
+                    _ => {}
+                }
+            }
+        })
+        .await
+        .map_err(|_| anyhow!("Timed out running \"{}\"", command))?;
+
+        match exit_status {
+            Some(0) => Ok(Some(String::from_utf8_lossy(&stdout).into_owned())),
+            _ => Ok(None),
+        }
+    }
+
+    /// Collect everything inventory discovery needs. Missing tools (no `ss`, no container
+    /// runtime) leave the matching fields empty rather than failing the host.
+    pub async fn collect_inventory(&self) -> Result<HostInventory, Error> {
+        let os_release = self
+            .exec("cat /etc/os-release 2>/dev/null || cat /usr/lib/os-release")
+            .await?
+            .map(|s| parse_os_release(&s));
+
+        let hostname = self
+            .exec("hostname 2>/dev/null || cat /etc/hostname")
+            .await?
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
+        let addresses = self
+            .exec("ip -o addr show")
+            .await?
+            .map(|s| parse_ip_addr(&s))
+            .unwrap_or_default();
+
+        let mac_addresses = self
+            .exec("ip -o link show")
+            .await?
+            .map(|s| parse_ip_link(&s))
+            .unwrap_or_default();
+
+        let sockets = self
+            .exec("ss -H -tulpn 2>/dev/null || ss -tulpn")
+            .await?
+            .map(|s| parse_listening_sockets(&s))
+            .unwrap_or_default();
+
+        let containers = self
+            .exec(
+                "docker ps --format '{{.Names}}\t{{.Image}}' 2>/dev/null \
+                 || podman ps --format '{{.Names}}\t{{.Image}}' 2>/dev/null",
+            )
+            .await?
+            .map(|s| parse_container_list(&s))
+            .unwrap_or_default();
+
+        Ok(HostInventory {
+            os_release,
+            hostname,
+            addresses,
+            mac_addresses,
+            sockets,
+            containers,
+        })
+    }
+
+    pub async fn close(self) {
+        let _ = self
+            .handle
+            .disconnect(russh::Disconnect::ByApplication, "", "en")
+            .await;
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct HostInventory {
+    pub os_release: Option<OsRelease>,
+    pub hostname: Option<String>,
+    pub addresses: Vec<InterfaceAddress>,
+    /// MAC address by interface name
+    pub mac_addresses: HashMap<String, MacAddress>,
+    pub sockets: Vec<ListeningSocket>,
+    pub containers: Vec<RunningContainer>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct OsRelease {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub pretty_name: Option<String>,
+    pub version_id: Option<String>,
+}
+
+impl OsRelease {
+    pub fn display_name(&self) -> Option<String> {
+        self.pretty_name
+            .clone()
+            .or_else(|| match (&self.name, &self.version_id) {
+                (Some(name), Some(version)) => Some(format!("{} {}", name, version)),
+                (Some(name), None) => Some(name.clone()),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InterfaceAddress {
+    pub name: String,
+    pub ip: IpAddr,
+    pub prefix: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ListeningSocket {
+    pub protocol: TransportProtocol,
+    /// Bound address, or None when bound to all addresses
+    pub address: Option<IpAddr>,
+    pub port: u16,
+    /// Owning process. Only visible for the login user's own processes unless it is root.
+    pub process: Option<String>,
+}
+
+impl ListeningSocket {
+    /// Only reachable from the host itself, so invisible to a network scan
+    pub fn is_local_only(&self) -> bool {
+        self.address.is_some_and(|a| a.is_loopback())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunningContainer {
+    pub name: String,
+    pub image: String,
+}
+
+fn parse_os_release(output: &str) -> OsRelease {
+    let mut release = OsRelease::default();
+
+    for line in output.lines() {
+        let Some((key, value)) = line.trim().split_once('=') else {
+            continue;
+        };
+        let value = value
+            .trim()
+            .trim_matches('"')
+            .trim_matches('\'')
+            .to_string();
+        if value.is_empty() {
+            continue;
+        }
+
+        match key {
+            "ID" => release.id = Some(value),
+            "NAME" => release.name = Some(value),
+            "PRETTY_NAME" => release.pretty_name = Some(value),
+            "VERSION_ID" => release.version_id = Some(value),
+            _ => {}
+        }
+    }
+
+    release
+}
+
+/// Strip the trailing colon and peer suffix from `ip` interface names, e.g. `eth0@if12:`
+fn interface_name(token: &str) -> &str {
+    let name = token.trim_end_matches(':');
+    name.split_once('@').map(|(n, _)| n).unwrap_or(name)
+}
+
+/// Parse `ip -o addr show`, one address per line:
+/// `2: eth0    inet 192.168.1.10/24 brd 192.168.1.255 scope global eth0 ...`
+fn parse_ip_addr(output: &str) -> Vec<InterfaceAddress> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace().skip(1);
+            let name = interface_name(tokens.next()?);
+            let family = tokens.next()?;
+            if family != "inet" && family != "inet6" {
+                return None;
+            }
+
+            let (ip, prefix) = tokens.next()?.split_once('/')?;
+            Some(InterfaceAddress {
+                name: name.to_string(),
+                ip: ip.parse().ok()?,
+                prefix: prefix.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Parse `ip -o link show` into MAC addresses by interface name
+fn parse_ip_link(output: &str) -> HashMap<String, MacAddress> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let name = interface_name(tokens.get(1)?);
+            let mac = tokens
+                .iter()
+                .position(|t| *t == "link/ether")
+                .and_then(|i| tokens.get(i + 1))
+                .and_then(|m| MacAddress::from_str(m).ok())?;
+            Some((name.to_string(), mac))
+        })
+        .collect()
+}
+
+/// Split an `ss` local address such as `0.0.0.0:22`, `[::1]:631`, `*:68` or
+/// `127.0.0.53%lo:53` into its address and port
+fn parse_socket_address(local: &str) -> Option<(Option<IpAddr>, u16)> {
+    let (address, port) = local.rsplit_once(':')?;
+    let port = port.parse().ok()?;
+
+    let address = address.trim_start_matches('[').trim_end_matches(']');
+    let address = address.split_once('%').map(|(a, _)| a).unwrap_or(address);
+
+    if address == "*" {
+        return Some((None, port));
+    }
+
+    let ip: IpAddr = address.parse().ok()?;
+    Some(((!ip.is_unspecified()).then_some(ip), port))
+}
+
+/// Parse `ss -tulpn` output:
+/// `tcp   LISTEN 0      4096   127.0.0.53%lo:53   0.0.0.0:*   users:(("systemd-resolve",pid=612,fd=14))`
+fn parse_listening_sockets(output: &str) -> Vec<ListeningSocket> {
+    let mut sockets: Vec<ListeningSocket> = Vec::new();
+
+    for line in output.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() < 5 {
+            continue;
+        }
+
+        let protocol = match tokens[0] {
+            "tcp" => TransportProtocol::Tcp,
+            "udp" => TransportProtocol::Udp,
+            // Header line from versions of ss without -H
+            _ => continue,
+        };
+
+        let Some((address, port)) = parse_socket_address(tokens[4]) else {
+            continue;
+        };
+
+        let process = tokens[5..]
+            .iter()
+            .find_map(|t| t.strip_prefix("users:((\""))
+            .and_then(|rest| rest.split_once('"'))
+            .map(|(name, _)| name.to_string());
+
+        let socket = ListeningSocket {
+            protocol,
+            address,
+            port,
+            process,
+        };
+
+        // The same port is usually listed once per address family
+        if !sockets.contains(&socket) {
+            sockets.push(socket);
+        }
+    }
+
+    sockets
+}
+
+/// Parse `docker ps --format '{{.Names}}\t{{.Image}}'`
+fn parse_container_list(output: &str) -> Vec<RunningContainer> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, image) = line.trim().split_once('\t')?;
+            Some(RunningContainer {
+                name: name.to_string(),
+                image: image.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn test_parse_os_release() {
+        let release = parse_os_release(
+            r#"PRETTY_NAME="Ubuntu 24.04.1 LTS"
+NAME="Ubuntu"
+VERSION_ID="24.04"
+ID=ubuntu
+ID_LIKE=debian
+"#,
+        );
+
+        assert_eq!(release.id.as_deref(), Some("ubuntu"));
+        assert_eq!(release.version_id.as_deref(), Some("24.04"));
+        assert_eq!(
+            release.display_name().as_deref(),
+            Some("Ubuntu 24.04.1 LTS")
+        );
+
+        let release = parse_os_release("NAME='Alpine Linux'\nVERSION_ID=3.20.3\n");
+        assert_eq!(
+            release.display_name().as_deref(),
+            Some("Alpine Linux 3.20.3")
+        );
+    }
+
+    #[test]
+    fn test_parse_ip_addr() {
+        let addresses = parse_ip_addr(
+            "1: lo    inet 127.0.0.1/8 scope host lo\\       valid_lft forever preferred_lft forever
+1: lo    inet6 ::1/128 scope host \\       valid_lft forever preferred_lft forever
+2: eth0@if12    inet 172.17.0.2/16 brd 172.17.255.255 scope global eth0\\       valid_lft forever preferred_lft forever
+3: wlan0    inet6 fe80::1c2f:7aff:fe11:2233/64 scope link \\       valid_lft forever preferred_lft forever",
+        );
+
+        assert_eq!(addresses.len(), 4);
+        assert_eq!(
+            addresses[2],
+            InterfaceAddress {
+                name: "eth0".to_string(),
+                ip: IpAddr::V4(Ipv4Addr::new(172, 17, 0, 2)),
+                prefix: 16,
+            }
+        );
+        assert_eq!(addresses[1].ip, IpAddr::V6(Ipv6Addr::LOCALHOST));
+    }
+
+    #[test]
+    fn test_parse_ip_link() {
+        let macs = parse_ip_link(
+            "1: lo: <LOOPBACK,UP,LOWER_UP> mtu 65536 qdisc noqueue state UNKNOWN mode DEFAULT group default qlen 1000\\    link/loopback 00:00:00:00:00:00 brd 00:00:00:00:00:00
+2: eth0@if12: <BROADCAST,MULTICAST,UP,LOWER_UP> mtu 1500 qdisc noqueue state UP mode DEFAULT group default \\    link/ether 02:42:ac:11:00:02 brd ff:ff:ff:ff:ff:ff link-netnsid 0",
+        );
+
+        assert_eq!(macs.len(), 1);
+        assert_eq!(
+            macs.get("eth0"),
+            Some(&MacAddress::from_str("02:42:ac:11:00:02").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_listening_sockets() {
+        let sockets = parse_listening_sockets(
+            r#"Netid State  Recv-Q Send-Q Local Address:Port  Peer Address:Port Process
+udp   UNCONN 0      0      127.0.0.53%lo:53        0.0.0.0:*    users:(("systemd-resolve",pid=612,fd=13))
+tcp   LISTEN 0      4096   127.0.0.53%lo:53        0.0.0.0:*    users:(("systemd-resolve",pid=612,fd=14))
+tcp   LISTEN 0      128          0.0.0.0:22        0.0.0.0:*    users:(("sshd",pid=901,fd=3))
+tcp   LISTEN 0      128             [::]:22           [::]:*    users:(("sshd",pid=901,fd=4))
+tcp   LISTEN 0      244            [::1]:5432         [::]:*
+udp   UNCONN 0      0                  *:68              *:*
+"#,
+        );
+
+        assert_eq!(sockets.len(), 5);
+        assert_eq!(sockets[0].protocol, TransportProtocol::Udp);
+        assert_eq!(sockets[1].process.as_deref(), Some("systemd-resolve"));
+        assert!(sockets[1].is_local_only());
+
+        let ssh = &sockets[2];
+        assert_eq!((ssh.address, ssh.port), (None, 22));
+        assert_eq!(ssh.process.as_deref(), Some("sshd"));
+        assert!(!ssh.is_local_only());
+
+        let postgres = &sockets[3];
+        assert_eq!(postgres.address, Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        assert_eq!(postgres.process, None);
+        assert_eq!((sockets[4].address, sockets[4].port), (None, 68));
+    }
+
+    #[test]
+    fn test_parse_container_list() {
+        let containers =
+            parse_container_list("grafana\tgrafana/grafana:11.2.0\nredis\tredis:7\n\n");
+
+        assert_eq!(
+            containers,
+            vec![
+                RunningContainer {
+                    name: "grafana".to_string(),
+                    image: "grafana/grafana:11.2.0".to_string(),
+                },
+                RunningContainer {
+                    name: "redis".to_string(),
+                    image: "redis:7".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,497 @@
+//! Traceroute over raw sockets. Probes go out with increasing TTLs and each router on the way
+//! answers with ICMP time exceeded once the TTL runs out. Replies for every probe protocol are
+//! read from a raw ICMP socket, so tracing needs root or CAP_NET_RAW. Only IPv4 is traced.
+//!
+//! A routed path can be set up locally with network namespaces: two namespaces joined through
+//! a third with `net.ipv4.ip_forward=1`, and the daemon run in one of them with
+//! `ip netns exec`.
+
+use std::net::Ipv4Addr;
+#[cfg(unix)]
+use std::{
+    io,
+    net::{IpAddr, SocketAddr, UdpSocket},
+    os::fd::AsRawFd,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+#[cfg(unix)]
+use anyhow::anyhow;
+#[cfg(unix)]
+use futures::future::Either;
+#[cfg(unix)]
+use pnet::{
+    packet::{
+        Packet,
+        icmp::{
+            IcmpCode, IcmpPacket, IcmpType, IcmpTypes, checksum,
+            echo_request::MutableEchoRequestPacket,
+        },
+        ip::{IpNextHeaderProtocol, IpNextHeaderProtocols},
+    },
+    transport::{
+        TransportChannelType::Layer4, TransportProtocol::Ipv4, TransportSender, icmp_packet_iter,
+        transport_channel,
+    },
+};
+#[cfg(unix)]
+use tokio::{net::TcpSocket, sync::mpsc, time::Instant};
+
+use crate::server::traceroutes::r#impl::base::{TracerouteHop, TracerouteProtocol};
+
+/// First destination port for UDP probes, as classic traceroute uses
+#[cfg(unix)]
+const UDP_BASE_PORT: u16 = 33434;
+/// Destination port for TCP probes. Firewalls rarely drop traffic to it.
+#[cfg(unix)]
+const TCP_PORT: u16 = 443;
+#[cfg(unix)]
+const PROBE_PAYLOAD_SIZE: usize = 32;
+#[cfg(unix)]
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Probes sent at each TTL before the hop is recorded as silent
+#[cfg(unix)]
+const PROBES_PER_HOP: u8 = 2;
+/// Stop after this many silent hops in a row; the rest of the path is most likely filtered
+#[cfg(unix)]
+const MAX_SILENT_HOPS: usize = 5;
+/// How often the listener thread checks whether it should stop
+#[cfg(unix)]
+const LISTENER_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub struct TraceResult {
+    pub hops: Vec<TracerouteHop>,
+    /// Whether the target itself answered
+    pub reached: bool,
+}
+
+/// What identifies the probe an ICMP message answers
+#[cfg(unix)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProbeKey {
+    Echo { identifier: u16, sequence: u16 },
+    Ports { source: u16, destination: u16 },
+}
+
+#[cfg(unix)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReplyKind {
+    /// A router dropped the probe when its TTL ran out
+    TimeExceeded,
+    /// The target answered an echo request
+    EchoReply,
+    /// The target or a router on the way refused the probe
+    Unreachable,
+    /// The target answered a TCP connection attempt, whether it accepted it or not
+    Handshake,
+}
+
+#[cfg(unix)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IcmpReply {
+    from: Ipv4Addr,
+    key: ProbeKey,
+    kind: ReplyKind,
+}
+
+/// Read an ICMP message and work out which probe it answers. Returns None for messages that
+/// aren't replies to probes.
+#[cfg(unix)]
+fn parse_icmp_reply(from: Ipv4Addr, icmp: &[u8]) -> Option<IcmpReply> {
+    let icmp_type = IcmpType::new(*icmp.first()?);
+
+    if icmp_type == IcmpTypes::EchoReply {
+        return Some(IcmpReply {
+            from,
+            key: ProbeKey::Echo {
+                identifier: read_u16(icmp, 4)?,
+                sequence: read_u16(icmp, 6)?,
+            },
+            kind: ReplyKind::EchoReply,
+        });
+    }
+
+    let kind = if icmp_type == IcmpTypes::TimeExceeded {
+        ReplyKind::TimeExceeded
+    } else if icmp_type == IcmpTypes::DestinationUnreachable {
+        ReplyKind::Unreachable
+    } else {
+        return None;
+    };
+
+    // Errors quote the probe's IP header and at least the first 8 bytes after it
+    let quoted = icmp.get(8..)?;
+    let header_length = usize::from(quoted.first()? & 0x0f) * 4;
+    let protocol = IpNextHeaderProtocol::new(*quoted.get(9)?);
+    let payload = quoted.get(header_length..)?;
+
+    let key = if protocol == IpNextHeaderProtocols::Icmp {
+        if IcmpType::new(*payload.first()?) != IcmpTypes::EchoRequest {
+            return None;
+        }
+        ProbeKey::Echo {
+            identifier: read_u16(payload, 4)?,
+            sequence: read_u16(payload, 6)?,
+        }
+    } else if protocol == IpNextHeaderProtocols::Udp || protocol == IpNextHeaderProtocols::Tcp {
+        ProbeKey::Ports {
+            source: read_u16(payload, 0)?,
+            destination: read_u16(payload, 2)?,
+        }
+    } else {
+        return None;
+    };
+
+    Some(IcmpReply { from, key, kind })
+}
+
+#[cfg(unix)]
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(unix)]
+fn set_tcp_ttl(socket: &TcpSocket, ttl: u8) -> io::Result<()> {
+    let ttl = libc::c_int::from(ttl);
+    let result = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::IPPROTO_IP,
+            libc::IP_TTL,
+            &ttl as *const libc::c_int as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// A raw ICMP socket. Sends echo probes, and reads every ICMP message on a blocking thread
+/// until dropped.
+#[cfg(unix)]
+struct IcmpListener {
+    sender: TransportSender,
+    replies: mpsc::UnboundedReceiver<IcmpReply>,
+    stop: Arc<AtomicBool>,
+}
+
+#[cfg(unix)]
+impl IcmpListener {
+    fn start() -> Result<Self> {
+        let (sender, mut receiver) =
+            transport_channel(4096, Layer4(Ipv4(IpNextHeaderProtocols::Icmp))).map_err(|e| {
+                anyhow!(
+                    "Failed to open a raw ICMP socket, which needs root or CAP_NET_RAW: {}",
+                    e
+                )
+            })?;
+
+        let (reply_tx, replies) = mpsc::unbounded_channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        std::thread::spawn(move || {
+            let mut packets = icmp_packet_iter(&mut receiver);
+            while !thread_stop.load(Ordering::Relaxed) {
+                match packets.next_with_timeout(LISTENER_POLL_INTERVAL) {
+                    Ok(Some((packet, IpAddr::V4(from)))) => {
+                        if let Some(reply) = parse_icmp_reply(from, packet.packet())
+                            && reply_tx.send(reply).is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::debug!(error = %e, "ICMP listener stopped");
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            sender,
+            replies,
+            stop,
+        })
+    }
+
+    /// Send one probe and wait for whatever answers it
+    async fn probe(
+        &mut self,
+        target: Ipv4Addr,
+        protocol: TracerouteProtocol,
+        identifier: u16,
+        ttl: u8,
+        sequence: u16,
+    ) -> Result<Option<(IcmpReply, Duration)>> {
+        let sent = Instant::now();
+        let deadline = sent + PROBE_TIMEOUT;
+
+        let reply = match protocol {
+            TracerouteProtocol::Icmp => {
+                let mut buffer = [0u8; 8 + PROBE_PAYLOAD_SIZE];
+                let mut packet = MutableEchoRequestPacket::new(&mut buffer)
+                    .ok_or_else(|| anyhow!("Echo request buffer is too small"))?;
+                packet.set_icmp_type(IcmpTypes::EchoRequest);
+                packet.set_icmp_code(IcmpCode::new(0));
+                packet.set_identifier(identifier);
+                packet.set_sequence_number(sequence);
+                let icmp = IcmpPacket::new(packet.packet())
+                    .ok_or_else(|| anyhow!("Echo request buffer is too small"))?;
+                let icmp_checksum = checksum(&icmp);
+                packet.set_checksum(icmp_checksum);
+
+                self.sender.set_ttl(ttl)?;
+                self.sender.send_to(packet, IpAddr::V4(target))?;
+
+                self.wait_for(
+                    ProbeKey::Echo {
+                        identifier,
+                        sequence,
+                    },
+                    deadline,
+                )
+                .await
+            }
+            TracerouteProtocol::Udp => {
+                let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+                socket.set_ttl(u32::from(ttl))?;
+                let destination = UDP_BASE_PORT + sequence;
+                socket.send_to(&[0u8; PROBE_PAYLOAD_SIZE], (target, destination))?;
+                let source = socket.local_addr()?.port();
+
+                self.wait_for(
+                    ProbeKey::Ports {
+                        source,
+                        destination,
+                    },
+                    deadline,
+                )
+                .await
+            }
+            TracerouteProtocol::Tcp => {
+                let socket = TcpSocket::new_v4()?;
+                set_tcp_ttl(&socket, ttl)?;
+                socket.bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))?;
+                let key = ProbeKey::Ports {
+                    source: socket.local_addr()?.port(),
+                    destination: TCP_PORT,
+                };
+                let handshake = IcmpReply {
+                    from: target,
+                    key,
+                    kind: ReplyKind::Handshake,
+                };
+
+                let connect = socket.connect(SocketAddr::from((target, TCP_PORT)));
+                tokio::pin!(connect);
+
+                let first = tokio::select! {
+                    result = &mut connect => Either::Left(result),
+                    reply = self.wait_for(key, deadline) => Either::Right(reply),
+                };
+
+                match first {
+                    Either::Left(Ok(_)) => Some(handshake),
+                    Either::Left(Err(e)) if e.kind() == io::ErrorKind::ConnectionRefused => {
+                        Some(handshake)
+                    }
+                    // The router that refused the connection also sends an ICMP error
+                    Either::Left(Err(_)) => self.wait_for(key, deadline).await,
+                    Either::Right(reply) => reply,
+                }
+            }
+        };
+
+        Ok(reply.map(|reply| (reply, sent.elapsed())))
+    }
+
+    async fn wait_for(&mut self, key: ProbeKey, deadline: Instant) -> Option<IcmpReply> {
+        loop {
+            match tokio::time::timeout_at(deadline, self.replies.recv()).await {
+                Ok(Some(reply)) if reply.key == key => return Some(reply),
+                // Late replies to earlier probes, or another program's ICMP traffic
+                Ok(Some(_)) => continue,
+                Ok(None) | Err(_) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for IcmpListener {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Trace the path to `target` one TTL at a time, until the target answers, something refuses
+/// the probe, or `max_hops` is reached
+#[cfg(unix)]
+pub async fn trace(
+    target: Ipv4Addr,
+    protocol: TracerouteProtocol,
+    max_hops: u8,
+) -> Result<TraceResult> {
+    let mut listener = IcmpListener::start()?;
+    let identifier = fastrand::u16(..);
+    let mut hops = Vec::new();
+    let mut silent_hops = 0;
+
+    for ttl in 1..=max_hops {
+        let mut reply = None;
+        for attempt in 0..PROBES_PER_HOP {
+            let sequence = u16::from(ttl) * u16::from(PROBES_PER_HOP) + u16::from(attempt);
+            reply = listener
+                .probe(target, protocol, identifier, ttl, sequence)
+                .await?;
+            if reply.is_some() {
+                break;
+            }
+        }
+
+        let Some((reply, rtt)) = reply else {
+            hops.push(TracerouteHop {
+                ttl,
+                ip: None,
+                rtt_us: None,
+            });
+            silent_hops += 1;
+            if silent_hops >= MAX_SILENT_HOPS {
+                break;
+            }
+            continue;
+        };
+
+        silent_hops = 0;
+        hops.push(TracerouteHop {
+            ttl,
+            ip: Some(IpAddr::V4(reply.from)),
+            rtt_us: Some(u32::try_from(rtt.as_micros()).unwrap_or(u32::MAX)),
+        });
+
+        if reply.kind != ReplyKind::TimeExceeded {
+            return Ok(TraceResult {
+                hops,
+                reached: reply.from == target,
+            });
+        }
+    }
+
+    Ok(TraceResult {
+        hops,
+        reached: false,
+    })
+}
+
+// Stub for platforms without raw socket support
+#[cfg(not(unix))]
+pub async fn trace(
+    _target: Ipv4Addr,
+    _protocol: TracerouteProtocol,
+    _max_hops: u8,
+) -> Result<TraceResult> {
+    Err(anyhow::anyhow!(
+        "Traceroute is only available on Linux and macOS"
+    ))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    /// IPv4 header quoting a probe, followed by the first 8 bytes of its payload
+    fn quoted_probe(protocol: u8, payload: [u8; 8]) -> Vec<u8> {
+        let mut header = vec![0x45, 0, 0, 60, 0, 0, 0, 0, 1, protocol, 0, 0];
+        header.extend_from_slice(&[192, 168, 1, 10, 10, 20, 0, 1]);
+        header.extend_from_slice(&payload);
+        header
+    }
+
+    #[test]
+    fn test_parse_time_exceeded_for_udp_probe() {
+        let source = 51000u16.to_be_bytes();
+        let destination = (UDP_BASE_PORT + 3).to_be_bytes();
+        let mut icmp = vec![11, 0, 0, 0, 0, 0, 0, 0];
+        icmp.extend(quoted_probe(
+            17,
+            [
+                source[0],
+                source[1],
+                destination[0],
+                destination[1],
+                0,
+                40,
+                0,
+                0,
+            ],
+        ));
+
+        let reply = parse_icmp_reply(Ipv4Addr::new(10, 0, 0, 1), &icmp).unwrap();
+
+        assert_eq!(reply.from, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(reply.kind, ReplyKind::TimeExceeded);
+        assert_eq!(
+            reply.key,
+            ProbeKey::Ports {
+                source: 51000,
+                destination: UDP_BASE_PORT + 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_echo_reply_and_quoted_echo_request() {
+        let echo_reply = [0, 0, 0, 0, 0x12, 0x34, 0, 7];
+        let reply = parse_icmp_reply(Ipv4Addr::new(10, 20, 0, 1), &echo_reply).unwrap();
+        assert_eq!(reply.kind, ReplyKind::EchoReply);
+        assert_eq!(
+            reply.key,
+            ProbeKey::Echo {
+                identifier: 0x1234,
+                sequence: 7
+            }
+        );
+
+        let mut unreachable = vec![3, 1, 0, 0, 0, 0, 0, 0];
+        unreachable.extend(quoted_probe(1, [8, 0, 0, 0, 0x12, 0x34, 0, 7]));
+        let reply = parse_icmp_reply(Ipv4Addr::new(10, 0, 0, 1), &unreachable).unwrap();
+        assert_eq!(reply.kind, ReplyKind::Unreachable);
+        assert_eq!(
+            reply.key,
+            ProbeKey::Echo {
+                identifier: 0x1234,
+                sequence: 7
+            }
+        );
+    }
+
+    #[test]
+    fn test_ignores_unrelated_icmp() {
+        // Someone else pinging the daemon's host
+        let echo_request = [8, 0, 0, 0, 0x12, 0x34, 0, 1];
+        assert_eq!(
+            parse_icmp_reply(Ipv4Addr::new(10, 0, 0, 5), &echo_request),
+            None
+        );
+
+        // Truncated error message
+        assert_eq!(
+            parse_icmp_reply(Ipv4Addr::new(10, 0, 0, 1), &[11, 0, 0, 0, 0, 0, 0, 0, 0x45]),
+            None
+        );
+    }
+}
@@ -0,0 +1,701 @@
+//! Minimal WS-Management client for querying WMI on Windows hosts.
+//!
+//! Only WQL enumeration is implemented, authenticated with NTLMv2. See [`super::ntlm`] for why
+//! WinRM has to be reached over HTTPS unless the host allows unencrypted traffic.
+
+use anyhow::{Error, anyhow, bail};
+use base64ct::{Base64, Encoding};
+use mac_address::MacAddress;
+use reqwest::{Client, StatusCode, header};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    time::Duration,
+};
+use uuid::Uuid;
+
+use crate::daemon::utils::ntlm::{
+    NtlmCredentials, authenticate_message, filetime_now, negotiate_message, parse_challenge_message,
+};
+use crate::server::ports::r#impl::base::TransportProtocol;
+
+const NS_ENUMERATION: &str = "http://schemas.xmlsoap.org/ws/2004/09/enumeration";
+const WMI_RESOURCE_PREFIX: &str = "http://schemas.microsoft.com/wbem/wsman/1/wmi";
+const WQL_DIALECT: &str = "http://schemas.microsoft.com/wbem/wsman/1/WQL";
+
+/// First port of the dynamic range. UDP endpoints above it are client sockets, not services.
+const DYNAMIC_PORT_START: u16 = 49152;
+
+pub struct WinRmClient {
+    client: Client,
+    endpoint: String,
+    credentials: NtlmCredentials,
+}
+
+impl WinRmClient {
+    pub fn new(
+        ip: IpAddr,
+        use_https: bool,
+        skip_tls_verify: bool,
+        credentials: NtlmCredentials,
+    ) -> Result<Self, Error> {
+        let (scheme, port) = if use_https {
+            ("https", 5986)
+        } else {
+            ("http", 5985)
+        };
+
+        Self::with_endpoint(
+            format!("{}://{}/wsman", scheme, SocketAddr::new(ip, port)),
+            skip_tls_verify,
+            credentials,
+        )
+    }
+
+    fn with_endpoint(
+        endpoint: String,
+        skip_tls_verify: bool,
+        credentials: NtlmCredentials,
+    ) -> Result<Self, Error> {
+        // NTLM authenticates the connection, so the handshake and the request that follows it
+        // have to go over the same one
+        let client = Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(60))
+            .pool_max_idle_per_host(1)
+            .danger_accept_invalid_certs(skip_tls_verify)
+            .build()
+            .map_err(|e| anyhow!("Failed to build WinRM client: {}", e))?;
+
+        Ok(Self {
+            client,
+            endpoint,
+            credentials,
+        })
+    }
+
+    /// Send a SOAP envelope, authenticating the connection first
+    async fn send(&self, envelope: String) -> Result<String, Error> {
+        let negotiate = self
+            .client
+            .post(&self.endpoint)
+            .header(
+                header::AUTHORIZATION,
+                format!("Negotiate {}", Base64::encode_string(&negotiate_message())),
+            )
+            .header(header::CONTENT_LENGTH, 0)
+            .send()
+            .await
+            .map_err(|e| anyhow!("WinRM request to {} failed: {}", self.endpoint, e))?;
+
+        if negotiate.status() != StatusCode::UNAUTHORIZED {
+            bail!(
+                "WinRM at {} did not offer NTLM authentication (HTTP {})",
+                self.endpoint,
+                negotiate.status()
+            );
+        }
+
+        let challenge = negotiate
+            .headers()
+            .get_all(header::WWW_AUTHENTICATE)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .find_map(|v| {
+                v.strip_prefix("Negotiate ")
+                    .or_else(|| v.strip_prefix("NTLM "))
+            })
+            .and_then(|token| Base64::decode_vec(token.trim()).ok())
+            .ok_or_else(|| anyhow!("WinRM at {} sent no NTLM challenge", self.endpoint))?;
+        let challenge = parse_challenge_message(&challenge)?;
+
+        // Drain the body so the connection goes back to the pool for the next request
+        let _ = negotiate.bytes().await;
+
+        let authenticate = authenticate_message(
+            &self.credentials,
+            &challenge,
+            rand::random(),
+            filetime_now(),
+        );
+
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .header(
+                header::AUTHORIZATION,
+                format!("Negotiate {}", Base64::encode_string(&authenticate)),
+            )
+            .header(header::CONTENT_TYPE, "application/soap+xml;charset=UTF-8")
+            .body(envelope)
+            .send()
+            .await
+            .map_err(|e| anyhow!("WinRM request to {} failed: {}", self.endpoint, e))?;
+
+        let status = response.status();
+        let body = response.text().await?;
+
+        match status {
+            s if s.is_success() => Ok(body),
+            StatusCode::UNAUTHORIZED => bail!("WinRM at {} rejected the credential", self.endpoint),
+            _ => match parse_fault(&body) {
+                Some(reason) => bail!("WinRM fault from {}: {}", self.endpoint, reason),
+                None => bail!("WinRM at {} returned HTTP {}", self.endpoint, status),
+            },
+        }
+    }
+
+    /// Run a WQL query against a WMI namespace such as `root/cimv2`
+    pub async fn query(&self, namespace: &str, wql: &str) -> Result<Vec<WmiObject>, Error> {
+        let resource_uri = format!("{}/{}/*", WMI_RESOURCE_PREFIX, namespace);
+
+        let response = self
+            .send(enumerate_envelope(&self.endpoint, &resource_uri, wql))
+            .await?;
+        let (mut objects, mut context) = parse_enumeration(&response)?;
+
+        while let Some(ctx) = context {
+            let response = self
+                .send(pull_envelope(&self.endpoint, &resource_uri, &ctx))
+                .await?;
+            let (more, next) = parse_enumeration(&response)?;
+            objects.extend(more);
+            context = next;
+        }
+
+        Ok(objects)
+    }
+
+    /// Collect everything WinRM discovery needs. Only the OS query is required; the rest
+    /// are missing on some editions (no server features on client Windows) or for
+    /// non-administrators, and leave the matching fields empty.
+    pub async fn collect_inventory(&self) -> Result<WindowsInventory, Error> {
+        let os = self
+            .query(
+                "root/cimv2",
+                "SELECT Caption, Version, BuildNumber, CSName, ProductType FROM Win32_OperatingSystem",
+            )
+            .await?
+            .first()
+            .map(WindowsOs::from_wmi);
+
+        let computer = self
+            .optional_query(
+                "root/cimv2",
+                "SELECT DNSHostName, Domain, PartOfDomain FROM Win32_ComputerSystem",
+            )
+            .await;
+
+        let hostname = computer
+            .first()
+            .and_then(|c| {
+                let name = c.get("DNSHostName")?;
+                match (c.get("PartOfDomain"), c.get("Domain")) {
+                    (Some("true"), Some(domain)) => Some(format!("{}.{}", name, domain)),
+                    _ => Some(name.to_string()),
+                }
+            })
+            .or_else(|| os.as_ref().and_then(|o| o.computer_name.clone()));
+
+        let features = self
+            .optional_query("root/cimv2", "SELECT ID, Name FROM Win32_ServerFeature")
+            .await
+            .iter()
+            .filter_map(ServerFeature::from_wmi)
+            .collect();
+
+        let adapters = self
+            .optional_query(
+                "root/cimv2",
+                "SELECT Description, MACAddress, IPAddress FROM Win32_NetworkAdapterConfiguration WHERE IPEnabled = TRUE",
+            )
+            .await
+            .iter()
+            .map(NetworkAdapter::from_wmi)
+            .collect();
+
+        let mut listeners: Vec<ListeningEndpoint> = Vec::new();
+        for (protocol, wql) in [
+            (
+                TransportProtocol::Tcp,
+                "SELECT LocalAddress, LocalPort FROM MSFT_NetTCPConnection WHERE State = 2",
+            ),
+            (
+                TransportProtocol::Udp,
+                "SELECT LocalAddress, LocalPort FROM MSFT_NetUDPEndpoint",
+            ),
+        ] {
+            for endpoint in self
+                .optional_query("root/StandardCimv2", wql)
+                .await
+                .iter()
+                .filter_map(|o| ListeningEndpoint::from_wmi(o, protocol))
+            {
+                if !listeners.contains(&endpoint) {
+                    listeners.push(endpoint);
+                }
+            }
+        }
+
+        Ok(WindowsInventory {
+            os,
+            hostname,
+            features,
+            adapters,
+            listeners,
+        })
+    }
+
+    async fn optional_query(&self, namespace: &str, wql: &str) -> Vec<WmiObject> {
+        self.query(namespace, wql).await.unwrap_or_else(|e| {
+            tracing::debug!(endpoint = %self.endpoint, wql, error = %e, "WMI query failed");
+            Vec::new()
+        })
+    }
+}
+
+/// A WMI instance. Array properties are repeated elements, so every property maps to a list.
+#[derive(Debug, Default)]
+pub struct WmiObject(HashMap<String, Vec<String>>);
+
+impl WmiObject {
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.0
+            .get(property)
+            .and_then(|v| v.first())
+            .map(|s| s.as_str())
+    }
+
+    pub fn get_all(&self, property: &str) -> &[String] {
+        self.0.get(property).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WindowsInventory {
+    pub os: Option<WindowsOs>,
+    pub hostname: Option<String>,
+    pub features: Vec<ServerFeature>,
+    pub adapters: Vec<NetworkAdapter>,
+    pub listeners: Vec<ListeningEndpoint>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WindowsOs {
+    /// Edition, e.g. "Microsoft Windows Server 2022 Standard"
+    pub caption: Option<String>,
+    pub version: Option<String>,
+    pub build_number: Option<String>,
+    pub computer_name: Option<String>,
+    /// 1 for workstations, 2 for domain controllers, 3 for other servers
+    pub product_type: Option<u32>,
+}
+
+impl WindowsOs {
+    fn from_wmi(object: &WmiObject) -> Self {
+        Self {
+            caption: object.get("Caption").map(|s| s.trim().to_string()),
+            version: object.get("Version").map(String::from),
+            build_number: object.get("BuildNumber").map(String::from),
+            computer_name: object.get("CSName").map(String::from),
+            product_type: object.get("ProductType").and_then(|s| s.parse().ok()),
+        }
+    }
+
+    pub fn display_name(&self) -> Option<String> {
+        match (&self.caption, &self.build_number) {
+            (Some(caption), Some(build)) => Some(format!("{} (build {})", caption, build)),
+            (Some(caption), None) => Some(caption.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Roles that map onto service definitions, by their `Win32_ServerFeature` ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowsRole {
+    WebServer,
+    ActiveDirectoryDomainServices,
+    DhcpServer,
+    DnsServer,
+    HyperV,
+}
+
+impl WindowsRole {
+    fn from_feature_id(id: u32) -> Option<Self> {
+        match id {
+            2 => Some(Self::WebServer),
+            10 => Some(Self::ActiveDirectoryDomainServices),
+            12 => Some(Self::DhcpServer),
+            13 => Some(Self::DnsServer),
+            20 => Some(Self::HyperV),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ServerFeature {
+    pub id: u32,
+    pub name: String,
+    pub role: Option<WindowsRole>,
+}
+
+impl ServerFeature {
+    fn from_wmi(object: &WmiObject) -> Option<Self> {
+        let id = object.get("ID")?.parse().ok()?;
+        Some(Self {
+            id,
+            name: object.get("Name")?.to_string(),
+            role: WindowsRole::from_feature_id(id),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NetworkAdapter {
+    pub description: Option<String>,
+    pub mac_address: Option<MacAddress>,
+    pub addresses: Vec<IpAddr>,
+}
+
+impl NetworkAdapter {
+    fn from_wmi(object: &WmiObject) -> Self {
+        Self {
+            description: object.get("Description").map(String::from),
+            mac_address: object
+                .get("MACAddress")
+                .and_then(|m| MacAddress::from_str(m).ok()),
+            addresses: object
+                .get_all("IPAddress")
+                .iter()
+                .filter_map(|a| a.parse().ok())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ListeningEndpoint {
+    pub protocol: TransportProtocol,
+    /// Bound address, or None when bound to all addresses
+    pub address: Option<IpAddr>,
+    pub port: u16,
+}
+
+impl ListeningEndpoint {
+    fn from_wmi(object: &WmiObject, protocol: TransportProtocol) -> Option<Self> {
+        let port: u16 = object.get("LocalPort")?.parse().ok()?;
+        if protocol == TransportProtocol::Udp && port >= DYNAMIC_PORT_START {
+            return None;
+        }
+
+        let address: IpAddr = object.get("LocalAddress")?.parse().ok()?;
+        Some(Self {
+            protocol,
+            address: (!address.is_unspecified()).then_some(address),
+            port,
+        })
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn envelope(endpoint: &str, resource_uri: &str, action: &str, body: &str) -> String {
+    format!(
+        r#"<s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope" xmlns:a="http://schemas.xmlsoap.org/ws/2004/08/addressing" xmlns:n="{enumeration}" xmlns:w="http://schemas.dmtf.org/wbem/wsman/1/wsman.xsd"><s:Header><a:To>{to}</a:To><w:ResourceURI s:mustUnderstand="true">{resource_uri}</w:ResourceURI><a:ReplyTo><a:Address s:mustUnderstand="true">http://schemas.xmlsoap.org/ws/2004/08/addressing/role/anonymous</a:Address></a:ReplyTo><a:Action s:mustUnderstand="true">{enumeration}/{action}</a:Action><w:MaxEnvelopeSize s:mustUnderstand="true">512000</w:MaxEnvelopeSize><a:MessageID>uuid:{message_id}</a:MessageID><w:OperationTimeout>PT30S</w:OperationTimeout></s:Header><s:Body>{body}</s:Body></s:Envelope>"#,
+        enumeration = NS_ENUMERATION,
+        to = escape_xml(endpoint),
+        resource_uri = escape_xml(resource_uri),
+        action = action,
+        message_id = Uuid::new_v4(),
+        body = body,
+    )
+}
+
+/// Enumerate with the first batch of items returned in the response itself
+fn enumerate_envelope(endpoint: &str, resource_uri: &str, wql: &str) -> String {
+    let body = format!(
+        r#"<n:Enumerate><w:OptimizeEnumeration/><w:MaxElements>100</w:MaxElements><w:Filter Dialect="{}">{}</w:Filter></n:Enumerate>"#,
+        WQL_DIALECT,
+        escape_xml(wql)
+    );
+    envelope(endpoint, resource_uri, "Enumerate", &body)
+}
+
+fn pull_envelope(endpoint: &str, resource_uri: &str, context: &str) -> String {
+    let body = format!(
+        "<n:Pull><n:EnumerationContext>{}</n:EnumerationContext><n:MaxElements>100</n:MaxElements></n:Pull>",
+        escape_xml(context)
+    );
+    envelope(endpoint, resource_uri, "Pull", &body)
+}
+
+/// Parse an EnumerateResponse or PullResponse into its items and, if the enumeration isn't
+/// finished, the context to pull the next batch with
+fn parse_enumeration(xml: &str) -> Result<(Vec<WmiObject>, Option<String>), Error> {
+    let doc =
+        roxmltree::Document::parse(xml).map_err(|e| anyhow!("Invalid WinRM response: {}", e))?;
+
+    let objects = doc
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "Items")
+        .flat_map(|items| items.children().filter(|n| n.is_element()))
+        .map(|instance| {
+            let mut properties: HashMap<String, Vec<String>> = HashMap::new();
+            for property in instance.children().filter(|n| n.is_element()) {
+                let nil = property
+                    .attributes()
+                    .any(|a| a.name() == "nil" && a.value() == "true");
+                if nil {
+                    continue;
+                }
+                properties
+                    .entry(property.tag_name().name().to_string())
+                    .or_default()
+                    .push(property.text().unwrap_or_default().to_string());
+            }
+            WmiObject(properties)
+        })
+        .collect();
+
+    let finished = doc
+        .descendants()
+        .any(|n| n.is_element() && n.tag_name().name() == "EndOfSequence");
+
+    let context = doc
+        .descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "EnumerationContext")
+        .and_then(|n| n.text())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !finished && !s.is_empty());
+
+    Ok((objects, context))
+}
+
+fn parse_fault(xml: &str) -> Option<String> {
+    let doc = roxmltree::Document::parse(xml).ok()?;
+    doc.descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "Reason")
+        .and_then(|reason| reason.descendants().find(|n| n.is_text()))
+        .and_then(|n| n.text())
+        .map(|s| s.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Shaped like Windows Server 2022 responses to optimized WQL enumeration
+    const OS_RESPONSE: &str = r#"<s:Envelope xml:lang="en-US" xmlns:s="http://www.w3.org/2003/05/soap-envelope" xmlns:a="http://schemas.xmlsoap.org/ws/2004/08/addressing" xmlns:n="http://schemas.xmlsoap.org/ws/2004/09/enumeration" xmlns:w="http://schemas.dmtf.org/wbem/wsman/1/wsman.xsd" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><s:Header><a:Action>http://schemas.xmlsoap.org/ws/2004/09/enumeration/EnumerateResponse</a:Action><a:MessageID>uuid:0E1C1E5C-6D5B-4C8B-8A1F-3C2B8E0D4A11</a:MessageID><a:To>http://schemas.xmlsoap.org/ws/2004/08/addressing/role/anonymous</a:To></s:Header><s:Body><n:EnumerateResponse><n:EnumerationContext></n:EnumerationContext><w:Items><w:XmlFragment><BuildNumber>20348</BuildNumber><Caption>Microsoft Windows Server 2022 Standard </Caption><CSName>DC01</CSName><ProductType>2</ProductType><Version>10.0.20348</Version></w:XmlFragment></w:Items><w:EndOfSequence/></n:EnumerateResponse></s:Body></s:Envelope>"#;
+
+    const ADAPTER_RESPONSE: &str = r#"<s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope" xmlns:n="http://schemas.xmlsoap.org/ws/2004/09/enumeration" xmlns:w="http://schemas.dmtf.org/wbem/wsman/1/wsman.xsd" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><s:Body><n:PullResponse><n:EnumerationContext>uuid:6A3F0C1E-2B44-4E0A-9C7D-1B2E3F4A5B6C</n:EnumerationContext><n:Items><w:XmlFragment><Description>Intel(R) 82574L Gigabit Network Connection</Description><IPAddress>10.0.0.10</IPAddress><IPAddress>fe80::5d1b:9c2e:11aa:42f0</IPAddress><MACAddress>00:15:5D:01:0A:02</MACAddress></w:XmlFragment><w:XmlFragment><Description>Hyper-V Virtual Ethernet Adapter</Description><IPAddress xsi:nil="true"/><MACAddress>00:15:5D:01:0A:03</MACAddress></w:XmlFragment></n:Items></n:PullResponse></s:Body></s:Envelope>"#;
+
+    const FAULT_RESPONSE: &str = r#"<s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"><s:Body><s:Fault><s:Code><s:Value>s:Sender</s:Value></s:Code><s:Reason><s:Text xml:lang="en-US">The WS-Management service cannot process the request. The WMI service returned an 'invalid class' error. </s:Text></s:Reason></s:Fault></s:Body></s:Envelope>"#;
+
+    /// Minimal WinRM stand-in: answers the negotiate message with a challenge, then serves
+    /// `body` to the authenticated request. Accepts a single connection, so a client that
+    /// doesn't keep the handshake on one connection hangs and times out.
+    fn spawn_stub(body: &'static str) -> SocketAddr {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            for expected_type in [1u8, 3u8] {
+                let mut request = Vec::new();
+                let mut chunk = [0u8; 4096];
+                let headers = loop {
+                    let n = stream.read(&mut chunk).unwrap();
+                    assert!(n > 0, "Connection closed mid-handshake");
+                    request.extend_from_slice(&chunk[..n]);
+
+                    let text = String::from_utf8_lossy(&request).into_owned();
+                    let Some(end) = text.find("\r\n\r\n") else {
+                        continue;
+                    };
+                    let content_length: usize = header(&text[..end], "content-length")
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + content_length {
+                        break text[..end].to_string();
+                    }
+                };
+
+                let token = header(&headers, "authorization")
+                    .and_then(|v| v.strip_prefix("Negotiate "))
+                    .and_then(|t| Base64::decode_vec(t).ok())
+                    .expect("Request has no Negotiate token");
+                assert_eq!(token[8], expected_type);
+
+                let response = if expected_type == 1 {
+                    let mut challenge = b"NTLMSSP\0".to_vec();
+                    challenge.extend(2u32.to_le_bytes());
+                    challenge.extend([0u8; 8]);
+                    challenge.extend(0xe28a_8233u32.to_le_bytes());
+                    challenge.extend([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+                    challenge.extend([0u8; 8]);
+                    challenge.extend([0, 0, 0, 0]);
+                    challenge.extend(48u32.to_le_bytes());
+                    format!(
+                        "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Negotiate {}\r\nContent-Length: 0\r\n\r\n",
+                        Base64::encode_string(&challenge)
+                    )
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/soap+xml;charset=UTF-8\r\nContent-Length: {}\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        addr
+    }
+
+    fn header<'a>(headers: &'a str, name: &str) -> Option<&'a str> {
+        headers.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_query_against_stub() {
+        let addr = spawn_stub(OS_RESPONSE);
+        let client = WinRmClient::with_endpoint(
+            format!("http://{}/wsman", addr),
+            false,
+            NtlmCredentials::new("CORP\\svc-scan", "secret"),
+        )
+        .unwrap();
+
+        let objects = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.query("root/cimv2", "SELECT Caption FROM Win32_OperatingSystem"),
+        )
+        .await
+        .expect("WinRM handshake did not stay on one connection")
+        .unwrap();
+
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].get("CSName"), Some("DC01"));
+    }
+
+    #[test]
+    fn test_parse_os_enumeration() {
+        let (objects, context) = parse_enumeration(OS_RESPONSE).unwrap();
+
+        assert_eq!(context, None);
+        assert_eq!(objects.len(), 1);
+
+        let os = WindowsOs::from_wmi(&objects[0]);
+        assert_eq!(
+            os.display_name().as_deref(),
+            Some("Microsoft Windows Server 2022 Standard (build 20348)")
+        );
+        assert_eq!(os.computer_name.as_deref(), Some("DC01"));
+        assert_eq!(os.product_type, Some(2));
+    }
+
+    #[test]
+    fn test_parse_pull_with_arrays_and_nil() {
+        let (objects, context) = parse_enumeration(ADAPTER_RESPONSE).unwrap();
+
+        assert_eq!(
+            context.as_deref(),
+            Some("uuid:6A3F0C1E-2B44-4E0A-9C7D-1B2E3F4A5B6C")
+        );
+
+        let adapters: Vec<NetworkAdapter> = objects.iter().map(NetworkAdapter::from_wmi).collect();
+        assert_eq!(adapters.len(), 2);
+        assert_eq!(adapters[0].addresses.len(), 2);
+        assert_eq!(
+            adapters[0].mac_address,
+            Some(MacAddress::from_str("00:15:5D:01:0A:02").unwrap())
+        );
+        assert!(adapters[1].addresses.is_empty());
+    }
+
+    #[test]
+    fn test_parse_fault() {
+        assert_eq!(
+            parse_fault(FAULT_RESPONSE).as_deref(),
+            Some(
+                "The WS-Management service cannot process the request. The WMI service returned an 'invalid class' error."
+            )
+        );
+        assert_eq!(parse_fault(OS_RESPONSE), None);
+    }
+
+    #[test]
+    fn test_server_features_and_listeners() {
+        let feature = |id: &str, name: &str| {
+            WmiObject(HashMap::from([
+                ("ID".to_string(), vec![id.to_string()]),
+                ("Name".to_string(), vec![name.to_string()]),
+            ]))
+        };
+
+        let features: Vec<ServerFeature> = [
+            feature("10", "Active Directory Domain Services"),
+            feature("13", "DNS Server"),
+            feature("41", ".NET Framework 4.8 Features"),
+        ]
+        .iter()
+        .filter_map(ServerFeature::from_wmi)
+        .collect();
+
+        assert_eq!(
+            features.iter().map(|f| f.role).collect::<Vec<_>>(),
+            vec![
+                Some(WindowsRole::ActiveDirectoryDomainServices),
+                Some(WindowsRole::DnsServer),
+                None
+            ]
+        );
+
+        let endpoint = |address: &str, port: &str| {
+            WmiObject(HashMap::from([
+                ("LocalAddress".to_string(), vec![address.to_string()]),
+                ("LocalPort".to_string(), vec![port.to_string()]),
+            ]))
+        };
+
+        let ldap = ListeningEndpoint::from_wmi(&endpoint("0.0.0.0", "389"), TransportProtocol::Tcp)
+            .unwrap();
+        assert_eq!((ldap.address, ldap.port), (None, 389));
+
+        let dns = ListeningEndpoint::from_wmi(&endpoint("10.0.0.10", "53"), TransportProtocol::Udp)
+            .unwrap();
+        assert_eq!(dns.address, Some("10.0.0.10".parse().unwrap()));
+
+        assert_eq!(
+            ListeningEndpoint::from_wmi(&endpoint("0.0.0.0", "58213"), TransportProtocol::Udp),
+            None
+        );
+    }
+
+    #[test]
+    fn test_enumerate_envelope_escapes_wql() {
+        let envelope = enumerate_envelope(
+            "https://10.0.0.10:5986/wsman",
+            "http://schemas.microsoft.com/wbem/wsman/1/wmi/root/cimv2/*",
+            "SELECT Name FROM Win32_Service WHERE State = \"Running\"",
+        );
+
+        assert!(envelope.contains("WHERE State = &quot;Running&quot;"));
+        assert!(roxmltree::Document::parse(&envelope).is_ok());
+    }
+}
@@ -2,12 +2,22 @@ use crate::server::{
     auth::{
         r#impl::{
             api::{
-                DaemonSetupRequest, DaemonSetupResponse, ForgotPasswordRequest, LoginRequest,
-                OidcAuthorizeParams, OidcCallbackParams, RegisterRequest, ResetPasswordRequest,
-                SetupRequest, SetupResponse, UpdateEmailPasswordRequest,
+                ConfirmTotpRequest, DaemonSetupRequest, DaemonSetupResponse,
+                FinishPasskeyRegistrationRequest, ForgotPasswordRequest, LoginRequest,
+                LoginResponse, MfaEnrollmentResponse, MfaStatusResponse, MfaVerifyRequest,
+                OidcAuthorizeParams, OidcCallbackParams, PasskeyChallengeResponse,
+                RecoveryCodesResponse, RegisterRequest, ResetPasswordRequest,
+                RevokeSessionsResponse, SetupRequest, SetupResponse, TotpEnrollmentResponse,
+                UpdateEmailPasswordRequest, UserSessionResponse,
             },
-            base::{LoginRegisterParams, PendingDaemonSetup, PendingNetworkSetup, PendingSetup},
+            base::{
+                LoginRegisterParams, PendingDaemonSetup, PendingMfaLogin, PendingNetworkSetup,
+                PendingSetup,
+            },
+            ldap::LdapLoginRequest,
             oidc::{OidcFlow, OidcPendingAuth, OidcProviderMetadata, OidcRegisterParams},
+            passkeys::{PasskeyAuthentication, PasskeyRegistration},
+            sessions::SESSION_RECORD_KEY,
         },
         middleware::{
             auth::AuthenticatedEntity,
@@ -51,6 +61,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(register))
         .routes(routes!(login))
+        .routes(routes!(ldap_login))
         .routes(routes!(logout))
         .routes(routes!(get_current_user))
         // Note: /keys routes are handled separately via OpenApiRouter in factory.rs
@@ -63,6 +74,18 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         .routes(routes!(unlink_oidc_account))
         .routes(routes!(forgot_password))
         .routes(routes!(reset_password))
+        .routes(routes!(get_mfa_status))
+        .routes(routes!(begin_totp_enrollment))
+        .routes(routes!(confirm_totp_enrollment))
+        .routes(routes!(begin_passkey_registration))
+        .routes(routes!(finish_passkey_registration))
+        .routes(routes!(delete_mfa_credential))
+        .routes(routes!(regenerate_recovery_codes))
+        .routes(routes!(begin_passkey_authentication))
+        .routes(routes!(verify_mfa))
+        .routes(routes!(list_sessions))
+        .routes(routes!(revoke_session))
+        .routes(routes!(revoke_other_sessions))
 }
 
 #[utoipa::path(
@@ -154,7 +177,7 @@ async fn register(
                 org_id,
                 permissions,
                 ip,
-                user_agent,
+                user_agent: user_agent.clone(),
                 network_ids,
             },
             pending_setup.clone(),
@@ -162,10 +185,7 @@ async fn register(
         )
         .await?;
 
-    session
-        .insert("user_id", user.id)
-        .await
-        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+    sign_in(&state, &session, &user, ip, user_agent).await?;
 
     // If this is a new org and setup was provided, create network/topology/daemon
     if is_new_org && let Some(setup) = pending_setup {
@@ -470,7 +490,7 @@ async fn apply_pending_setup(
     tags = ["auth", "internal"],
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = ApiResponse<User>),
+        (status = 200, description = "Login successful, or second factor required", body = ApiResponse<LoginResponse>),
         (status = 401, description = "Invalid credentials", body = ApiErrorResponse),
         (status = 403, description = "Login forbidden", body = ApiErrorResponse),
     )
@@ -482,21 +502,23 @@ async fn login(
     user_agent: Option<TypedHeader<UserAgent>>,
     session: Session,
     Json(request): Json<LoginRequest>,
-) -> ApiResult<Json<ApiResponse<User>>> {
+) -> ApiResult<Json<ApiResponse<LoginResponse>>> {
     let user_agent = user_agent.map(|u| u.to_string());
 
     let user = state
         .services
         .auth_service
-        .login(request, ip, user_agent)
+        .login(request, ip, user_agent.clone())
         .await?;
 
-    // Check if user is trying to log into demo account on non-demo and visa versa
-    if let Some(organization) = state
+    let organization = state
         .services
         .organization_service
         .get_by_id(&user.base.organization_id)
-        .await?
+        .await?;
+
+    // Check if user is trying to log into demo account on non-demo and visa versa
+    if let Some(organization) = &organization
         && let Some(plan) = organization.base.plan
     {
         if plan.is_demo() && host != DEMO_HOST {
@@ -516,18 +538,133 @@ async fn login(
         ));
     }
 
+    let require_mfa = organization.is_some_and(|o| o.base.require_mfa);
+    let response =
+        start_password_session(&state, &session, user, require_mfa, ip, user_agent).await?;
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/ldap/login",
+    tags = ["auth", "internal"],
+    request_body = LdapLoginRequest,
+    responses(
+        (status = 200, description = "Login successful, or second factor required", body = ApiResponse<LoginResponse>),
+        (status = 401, description = "Invalid credentials", body = ApiErrorResponse),
+        (status = 403, description = "Login forbidden", body = ApiErrorResponse),
+    )
+)]
+async fn ldap_login(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    Host(host): Host,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    session: Session,
+    Json(request): Json<LdapLoginRequest>,
+) -> ApiResult<Json<ApiResponse<LoginResponse>>> {
+    let ldap_service = state
+        .services
+        .ldap_service
+        .as_ref()
+        .ok_or_else(|| ApiError::bad_request("LDAP is not configured"))?;
+
+    // Directory accounts never belong to the demo organization
+    if host == DEMO_HOST {
+        return Err(ApiError::forbidden(
+            "You can only log in to the demo account on this instance.",
+        ));
+    }
+
+    let user_agent = user_agent.map(|u| u.to_string());
+    let user = ldap_service
+        .login(request, ip, user_agent.clone())
+        .await
+        .map_err(|e| ApiError::unauthorized(e.to_string()))?;
+
+    let require_mfa = state
+        .services
+        .organization_service
+        .get_by_id(&user.base.organization_id)
+        .await?
+        .is_some_and(|o| o.base.require_mfa);
+    let response =
+        start_password_session(&state, &session, user, require_mfa, ip, user_agent).await?;
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// Issue a session after a successful password check, or hold it back until the
+/// user completes (or enrols) a second factor.
+async fn start_password_session(
+    state: &AppState,
+    session: &Session,
+    user: User,
+    require_mfa: bool,
+    ip: IpAddr,
+    user_agent: Option<String>,
+) -> ApiResult<LoginResponse> {
+    let methods = state.services.mfa_service.login_methods(&user.id).await?;
+    let enrollment_required = methods.is_empty() && require_mfa;
+
     // Cycle session ID to prevent session fixation attacks
     session
         .cycle_id()
         .await
         .map_err(|e| ApiError::internal_error(&format!("Failed to cycle session: {}", e)))?;
 
+    if methods.is_empty() && !enrollment_required {
+        sign_in(state, session, &user, ip, user_agent).await?;
+
+        return Ok(LoginResponse::Authenticated { user });
+    }
+
+    let _ = session.remove::<Uuid>("user_id").await;
+    session
+        .insert(
+            "pending_mfa",
+            PendingMfaLogin {
+                user_id: user.id,
+                enrollment_required,
+                expires_at: Utc::now() + chrono::Duration::minutes(PENDING_MFA_MINUTES),
+            },
+        )
+        .await
+        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+
+    if enrollment_required {
+        Ok(LoginResponse::MfaEnrollmentRequired)
+    } else {
+        Ok(LoginResponse::MfaRequired { methods })
+    }
+}
+
+/// Record the sign-in and attach it and the user to the session
+async fn sign_in(
+    state: &AppState,
+    session: &Session,
+    user: &User,
+    ip: IpAddr,
+    user_agent: Option<String>,
+) -> ApiResult<()> {
+    let record = state
+        .services
+        .session_service
+        .create(user.id, ip, user_agent)
+        .await?;
+
+    session
+        .insert(SESSION_RECORD_KEY, record.id)
+        .await
+        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+
     session
         .insert("user_id", user.id)
         .await
         .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
 
-    Ok(Json(ApiResponse::success(user)))
+    Ok(())
 }
 
 #[utoipa::path(
@@ -544,6 +681,14 @@ async fn logout(
     user_agent: Option<TypedHeader<UserAgent>>,
     session: Session,
 ) -> ApiResult<Json<ApiResponse<()>>> {
+    if let Ok(Some(session_record_id)) = session.get::<Uuid>(SESSION_RECORD_KEY).await {
+        state
+            .services
+            .session_service
+            .remove(&session_record_id)
+            .await?;
+    }
+
     if let Ok(Some(user_id)) = session.get::<Uuid>("user_id").await {
         let user_agent = user_agent.map(|u| u.to_string());
 
@@ -617,6 +762,8 @@ async fn update_password_auth(
         .ok_or_else(|| ApiError::unauthorized("Not authenticated".to_string()))?;
 
     let user_agent = user_agent.map(|u| u.to_string());
+    let password_changed = request.password.is_some();
+    let authentication = auth.into_entity();
 
     let user = state
         .services
@@ -626,11 +773,21 @@ async fn update_password_auth(
             request.password,
             request.email,
             ip,
-            user_agent,
-            auth.into_entity(),
+            user_agent.clone(),
+            authentication.clone(),
         )
         .await?;
 
+    // A new password signs out every other session
+    if password_changed {
+        let current = session.get::<Uuid>(SESSION_RECORD_KEY).await.ok().flatten();
+        state
+            .services
+            .session_service
+            .revoke_all(&user_id, current.as_ref(), ip, user_agent, authentication)
+            .await?;
+    }
+
     Ok(Json(ApiResponse::success(user)))
 }
 
@@ -671,7 +828,7 @@ async fn forgot_password(
     tags = ["auth", "internal"],
     request_body = ResetPasswordRequest,
     responses(
-        (status = 200, description = "Password reset successful", body = ApiResponse<User>),
+        (status = 200, description = "Password reset successful, or second factor required", body = ApiResponse<LoginResponse>),
         (status = 400, description = "Invalid or expired token", body = ApiErrorResponse),
     )
 )]
@@ -681,21 +838,33 @@ async fn reset_password(
     user_agent: Option<TypedHeader<UserAgent>>,
     session: Session,
     Json(request): Json<ResetPasswordRequest>,
-) -> ApiResult<Json<ApiResponse<User>>> {
+) -> ApiResult<Json<ApiResponse<LoginResponse>>> {
     let user_agent = user_agent.map(|u| u.to_string());
 
     let user = state
         .services
         .auth_service
-        .complete_password_reset(&request.token, &request.password, ip, user_agent)
+        .complete_password_reset(&request.token, &request.password, ip, user_agent.clone())
         .await?;
 
-    session
-        .insert("user_id", user.id)
-        .await
-        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+    // Whoever knew the old password is signed out everywhere
+    state
+        .services
+        .session_service
+        .revoke_all(&user.id, None, ip, user_agent.clone(), user.clone().into())
+        .await?;
 
-    Ok(Json(ApiResponse::success(user)))
+    // A reset link only proves access to the mailbox, so MFA still applies
+    let require_mfa = state
+        .services
+        .organization_service
+        .get_by_id(&user.base.organization_id)
+        .await?
+        .is_some_and(|o| o.base.require_mfa);
+    let response =
+        start_password_session(&state, &session, user, require_mfa, ip, user_agent).await?;
+
+    Ok(Json(ApiResponse::success(response)))
 }
 
 async fn list_oidc_providers(
@@ -1058,7 +1227,7 @@ async fn handle_login_flow(
 
     // Login user
     match oidc_service
-        .login(slug, code, pending_auth, ip, user_agent)
+        .login(slug, code, pending_auth, ip, user_agent.clone())
         .await
     {
         Ok(user) => {
@@ -1109,12 +1278,12 @@ async fn handle_login_flow(
             }
 
             // Save user_id to session
-            if let Err(e) = session.insert("user_id", user.id).await {
-                tracing::error!("Failed to save session: {}", e);
+            if let Err(e) = sign_in(&state, &session, &user, ip, user_agent.clone()).await {
+                tracing::error!("Failed to save session: {}", e.message);
                 return Err(Redirect::to(&format!(
                     "{}?error={}",
                     return_url,
-                    urlencoding::encode(&format!("Failed to create session: {}", e))
+                    urlencoding::encode(&format!("Failed to create session: {}", e.message))
                 )));
             }
 
@@ -1195,7 +1364,7 @@ async fn handle_register_flow(
                 org_id,
                 permissions,
                 ip,
-                user_agent,
+                user_agent: user_agent.clone(),
                 network_ids,
             },
             OidcRegisterParams {
@@ -1221,12 +1390,12 @@ async fn handle_register_flow(
             }
 
             // Save user_id to session
-            if let Err(e) = session.insert("user_id", user.id).await {
-                tracing::error!("Failed to save session: {}", e);
+            if let Err(e) = sign_in(&state, &session, &user, ip, user_agent.clone()).await {
+                tracing::error!("Failed to save session: {}", e.message);
                 return Err(Redirect::to(&format!(
                     "{}?error={}",
                     return_url,
-                    urlencoding::encode(&format!("Failed to create session: {}", e))
+                    urlencoding::encode(&format!("Failed to create session: {}", e.message))
                 )));
             }
 
@@ -1309,9 +1478,543 @@ async fn unlink_oidc_account(
 
     // Unlink OIDC account
     let updated_user = oidc_service
-        .unlink_from_user(&slug, &user_id, ip, user_agent)
+        .unlink_from_user(&slug, &user_id, ip, user_agent.clone())
         .await
         .map_err(|e| ApiError::internal_error(&format!("Failed to unlink OIDC: {}", e)))?;
 
+    // Sessions signed in through the provider shouldn't outlive the link
+    let current = session.get::<Uuid>(SESSION_RECORD_KEY).await.ok().flatten();
+    state
+        .services
+        .session_service
+        .revoke_all(
+            &user_id,
+            current.as_ref(),
+            ip,
+            user_agent,
+            updated_user.clone().into(),
+        )
+        .await?;
+
     Ok(Json(ApiResponse::success(updated_user)))
 }
+
+/// How long a password login may wait on its second factor
+const PENDING_MFA_MINUTES: i64 = 10;
+
+/// Resolve the user for an MFA request: either fully signed in, or mid-login
+/// with a pending second factor.
+async fn mfa_session_user(
+    state: &AppState,
+    session: &Session,
+) -> ApiResult<(User, Option<PendingMfaLogin>)> {
+    let (user_id, pending) = if let Ok(Some(user_id)) = session.get::<Uuid>("user_id").await {
+        (user_id, None)
+    } else {
+        let pending: PendingMfaLogin = session
+            .get("pending_mfa")
+            .await
+            .ok()
+            .flatten()
+            .filter(|p: &PendingMfaLogin| p.expires_at > Utc::now())
+            .ok_or_else(|| ApiError::unauthorized("Not authenticated".to_string()))?;
+
+        (pending.user_id, Some(pending))
+    };
+
+    let user = state
+        .services
+        .user_service
+        .get_by_id(&user_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("User not found".to_string()))?;
+
+    Ok((user, pending))
+}
+
+/// Enrolment is open to signed-in users, and to logins held back because the
+/// organization requires MFA.
+async fn mfa_enrollment_user(
+    state: &AppState,
+    session: &Session,
+) -> ApiResult<(User, Option<PendingMfaLogin>)> {
+    let (user, pending) = mfa_session_user(state, session).await?;
+
+    if pending.as_ref().is_some_and(|p| !p.enrollment_required) {
+        return Err(ApiError::unauthorized(
+            "Complete multi-factor verification first".to_string(),
+        ));
+    }
+
+    // Demo accounts are shared, so nobody may lock them behind a second factor
+    if let Some(organization) = state
+        .services
+        .organization_service
+        .get_by_id(&user.base.organization_id)
+        .await?
+        && organization.base.plan.is_some_and(|p| p.is_demo())
+    {
+        return Err(ApiError::forbidden("This action is disabled in demo mode"));
+    }
+
+    Ok((user, pending))
+}
+
+/// Swap a pending MFA login for a full session
+async fn complete_mfa_login(
+    state: &AppState,
+    session: &Session,
+    user: &User,
+    ip: IpAddr,
+    user_agent: Option<String>,
+) -> ApiResult<()> {
+    session
+        .cycle_id()
+        .await
+        .map_err(|e| ApiError::internal_error(&format!("Failed to cycle session: {}", e)))?;
+
+    let _ = session.remove::<PendingMfaLogin>("pending_mfa").await;
+
+    sign_in(state, session, user, ip, user_agent).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/mfa",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "MFA status for the current user", body = ApiResponse<MfaStatusResponse>),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn get_mfa_status(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+    _auth: Authorized<IsUser>,
+) -> ApiResult<Json<ApiResponse<MfaStatusResponse>>> {
+    let (user, _) = mfa_session_user(&state, &session).await?;
+
+    let status = state.services.mfa_service.status(&user).await?;
+
+    Ok(Json(ApiResponse::success(status)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/totp",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "TOTP secret generated", body = ApiResponse<TotpEnrollmentResponse>),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn begin_totp_enrollment(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+) -> ApiResult<Json<ApiResponse<TotpEnrollmentResponse>>> {
+    let (user, _) = mfa_enrollment_user(&state, &session).await?;
+
+    let totp = state.services.mfa_service.begin_totp_enrollment(&user)?;
+    let secret = totp.get_secret_base32();
+
+    session
+        .insert("mfa_totp_enrollment", secret.clone())
+        .await
+        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+
+    Ok(Json(ApiResponse::success(TotpEnrollmentResponse {
+        secret,
+        otpauth_url: totp.get_url(),
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/totp/confirm",
+    tags = ["auth", "internal"],
+    request_body = ConfirmTotpRequest,
+    responses(
+        (status = 200, description = "Authenticator app enabled", body = ApiResponse<MfaEnrollmentResponse>),
+        (status = 400, description = "Invalid code", body = ApiErrorResponse),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn confirm_totp_enrollment(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    session: Session,
+    Json(request): Json<ConfirmTotpRequest>,
+) -> ApiResult<Json<ApiResponse<MfaEnrollmentResponse>>> {
+    let (user, pending) = mfa_enrollment_user(&state, &session).await?;
+
+    let secret: String = session
+        .get("mfa_totp_enrollment")
+        .await
+        .ok()
+        .flatten()
+        .ok_or_else(|| ApiError::bad_request("No authenticator setup in progress"))?;
+
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    let recovery_codes = state
+        .services
+        .mfa_service
+        .confirm_totp_enrollment(
+            &user,
+            &secret,
+            &request.code,
+            request.name,
+            ip,
+            user_agent.clone(),
+        )
+        .await
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    let _ = session.remove::<String>("mfa_totp_enrollment").await;
+
+    if pending.is_some() {
+        complete_mfa_login(&state, &session, &user, ip, user_agent).await?;
+    }
+
+    Ok(Json(ApiResponse::success(MfaEnrollmentResponse {
+        recovery_codes,
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/passkeys",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "Passkey registration options", body = ApiResponse<PasskeyChallengeResponse>),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn begin_passkey_registration(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+) -> ApiResult<Json<ApiResponse<PasskeyChallengeResponse>>> {
+    let (user, _) = mfa_enrollment_user(&state, &session).await?;
+
+    let (options, registration) = state
+        .services
+        .mfa_service
+        .begin_passkey_registration(&user)
+        .await
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    session
+        .insert("mfa_passkey_registration", registration)
+        .await
+        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+
+    Ok(Json(ApiResponse::success(PasskeyChallengeResponse {
+        options: serde_json::to_value(options)
+            .map_err(|e| ApiError::internal_error(&e.to_string()))?,
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/passkeys/confirm",
+    tags = ["auth", "internal"],
+    request_body = FinishPasskeyRegistrationRequest,
+    responses(
+        (status = 200, description = "Passkey registered", body = ApiResponse<MfaEnrollmentResponse>),
+        (status = 400, description = "Registration failed", body = ApiErrorResponse),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn finish_passkey_registration(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    session: Session,
+    Json(request): Json<FinishPasskeyRegistrationRequest>,
+) -> ApiResult<Json<ApiResponse<MfaEnrollmentResponse>>> {
+    let (user, pending) = mfa_enrollment_user(&state, &session).await?;
+
+    // Challenge state is single use
+    let registration: PasskeyRegistration = session
+        .remove("mfa_passkey_registration")
+        .await
+        .ok()
+        .flatten()
+        .ok_or_else(|| ApiError::bad_request("No passkey registration in progress"))?;
+
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    let recovery_codes = state
+        .services
+        .mfa_service
+        .finish_passkey_registration(
+            &user,
+            request.name,
+            &request.credential,
+            &registration,
+            ip,
+            user_agent.clone(),
+        )
+        .await
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    if pending.is_some() {
+        complete_mfa_login(&state, &session, &user, ip, user_agent).await?;
+    }
+
+    Ok(Json(ApiResponse::success(MfaEnrollmentResponse {
+        recovery_codes,
+    })))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/mfa/credentials/{id}",
+    tags = ["auth", "internal"],
+    params(("id" = Uuid, Path, description = "Credential ID")),
+    responses(
+        (status = 200, description = "Credential removed", body = EmptyApiResponse),
+        (status = 400, description = "Credential required by organization policy", body = ApiErrorResponse),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn delete_mfa_credential(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    session: Session,
+    _auth: Authorized<IsUser>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    let (user, _) = mfa_session_user(&state, &session).await?;
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    state
+        .services
+        .mfa_service
+        .remove_credential(&user, &id, ip, user_agent)
+        .await
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/recovery-codes",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "New recovery codes; previous codes are invalidated", body = ApiResponse<RecoveryCodesResponse>),
+        (status = 400, description = "MFA not enabled", body = ApiErrorResponse),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn regenerate_recovery_codes(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    session: Session,
+    _auth: Authorized<IsUser>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+) -> ApiResult<Json<ApiResponse<RecoveryCodesResponse>>> {
+    let (user, _) = mfa_session_user(&state, &session).await?;
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    let codes = state
+        .services
+        .mfa_service
+        .regenerate_recovery_codes(&user, ip, user_agent)
+        .await
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    Ok(Json(ApiResponse::success(RecoveryCodesResponse { codes })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/verify/passkey",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "Passkey authentication options", body = ApiResponse<PasskeyChallengeResponse>),
+        (status = 401, description = "No login awaiting verification", body = ApiErrorResponse),
+    )
+)]
+async fn begin_passkey_authentication(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+) -> ApiResult<Json<ApiResponse<PasskeyChallengeResponse>>> {
+    let (user, pending) = mfa_session_user(&state, &session).await?;
+
+    if pending.is_none() {
+        return Err(ApiError::bad_request("No login awaiting verification"));
+    }
+
+    let (options, authentication) = state
+        .services
+        .mfa_service
+        .begin_passkey_authentication(&user.id)
+        .await
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    session
+        .insert("mfa_passkey_authentication", authentication)
+        .await
+        .map_err(|e| ApiError::internal_error(&format!("Failed to save session: {}", e)))?;
+
+    Ok(Json(ApiResponse::success(PasskeyChallengeResponse {
+        options: serde_json::to_value(options)
+            .map_err(|e| ApiError::internal_error(&e.to_string()))?,
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/mfa/verify",
+    tags = ["auth", "internal"],
+    request_body = MfaVerifyRequest,
+    responses(
+        (status = 200, description = "Second factor accepted, session issued", body = ApiResponse<User>),
+        (status = 401, description = "Verification failed", body = ApiErrorResponse),
+    )
+)]
+async fn verify_mfa(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    session: Session,
+    Json(request): Json<MfaVerifyRequest>,
+) -> ApiResult<Json<ApiResponse<User>>> {
+    let (user, pending) = mfa_session_user(&state, &session).await?;
+
+    if pending.is_none_or(|p| p.enrollment_required) {
+        return Err(ApiError::bad_request("No login awaiting verification"));
+    }
+
+    // Challenge state is single use
+    let passkey_state: Option<PasskeyAuthentication> = session
+        .remove("mfa_passkey_authentication")
+        .await
+        .ok()
+        .flatten();
+
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    state
+        .services
+        .mfa_service
+        .verify(&user, &request, passkey_state, ip, user_agent.clone())
+        .await
+        .map_err(|e| ApiError::unauthorized(e.to_string()))?;
+
+    complete_mfa_login(&state, &session, &user, ip, user_agent).await?;
+
+    Ok(Json(ApiResponse::success(user)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/sessions",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "Signed-in sessions for the current user", body = ApiResponse<Vec<UserSessionResponse>>),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn list_sessions(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+    auth: Authorized<IsUser>,
+) -> ApiResult<Json<ApiResponse<Vec<UserSessionResponse>>>> {
+    let user_id = auth.require_user_id()?;
+    let current = session.get::<Uuid>(SESSION_RECORD_KEY).await.ok().flatten();
+
+    let sessions = state
+        .services
+        .session_service
+        .list_for_user(&user_id)
+        .await?
+        .into_iter()
+        .map(|s| UserSessionResponse::new(s, current.as_ref()))
+        .collect();
+
+    Ok(Json(ApiResponse::success(sessions)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/sessions/{id}",
+    tags = ["auth", "internal"],
+    params(("id" = Uuid, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session revoked", body = EmptyApiResponse),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+        (status = 404, description = "Session not found", body = ApiErrorResponse),
+    )
+)]
+async fn revoke_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    session: Session,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    auth: Authorized<IsUser>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    let user_id = auth.require_user_id()?;
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    state
+        .services
+        .session_service
+        .revoke(&user_id, &id, ip, user_agent, auth.into_entity())
+        .await
+        .map_err(|e| ApiError::not_found(e.to_string()))?;
+
+    // Revoking the current session is a sign-out
+    if session.get::<Uuid>(SESSION_RECORD_KEY).await.ok().flatten() == Some(id) {
+        session
+            .delete()
+            .await
+            .map_err(|e| ApiError::internal_error(&format!("Failed to delete session: {}", e)))?;
+    }
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+#[utoipa::path(
+    post,
+    path = "/sessions/revoke-others",
+    tags = ["auth", "internal"],
+    responses(
+        (status = 200, description = "All other sessions revoked", body = ApiResponse<RevokeSessionsResponse>),
+        (status = 401, description = "Not authenticated", body = ApiErrorResponse),
+    )
+)]
+async fn revoke_other_sessions(
+    State(state): State<Arc<AppState>>,
+    session: Session,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    auth: Authorized<IsUser>,
+) -> ApiResult<Json<ApiResponse<RevokeSessionsResponse>>> {
+    let user_id = auth.require_user_id()?;
+    let user_agent = user_agent.map(|u| u.to_string());
+    let current = session.get::<Uuid>(SESSION_RECORD_KEY).await.ok().flatten();
+
+    let revoked = state
+        .services
+        .session_service
+        .revoke_all(
+            &user_id,
+            current.as_ref(),
+            ip,
+            user_agent,
+            auth.into_entity(),
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(RevokeSessionsResponse {
+        revoked,
+    })))
+}
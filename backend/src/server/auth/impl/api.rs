@@ -1,8 +1,15 @@
+use crate::server::{
+    auth::r#impl::{mfa::MfaCredentialType, sessions::UserSession},
+    users::r#impl::base::User,
+};
+use chrono::{DateTime, Utc};
 use email_address::EmailAddress;
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
+use webauthn_rs_proto::{PublicKeyCredential, RegisterPublicKeyCredential};
 
 /// Login request from client
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
@@ -113,3 +120,122 @@ pub struct DaemonSetupRequest {
 pub struct DaemonSetupResponse {
     pub api_key: Option<String>,
 }
+
+/// Result of a password login: either a signed-in user, or a pending second-factor step
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LoginResponse {
+    /// Session issued
+    Authenticated { user: User },
+    /// Password accepted; complete one of `methods` via `/auth/mfa/verify`
+    MfaRequired { methods: Vec<MfaCredentialType> },
+    /// Password accepted; organization requires MFA and the user must enrol a factor first
+    MfaEnrollmentRequired,
+}
+
+/// Second-factor verification during login
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum MfaVerifyRequest {
+    Totp {
+        code: String,
+    },
+    RecoveryCode {
+        code: String,
+    },
+    Passkey {
+        #[schema(value_type = Object)]
+        credential: PublicKeyCredential,
+    },
+}
+
+/// An enrolled second factor, as shown in account settings
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MfaCredentialSummary {
+    pub id: Uuid,
+    pub credential_type: MfaCredentialType,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// MFA state for the current user
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MfaStatusResponse {
+    pub enabled: bool,
+    /// Whether the user's organization requires MFA
+    pub required: bool,
+    pub passkeys_available: bool,
+    pub credentials: Vec<MfaCredentialSummary>,
+    pub recovery_codes_remaining: usize,
+}
+
+/// TOTP secret to load into an authenticator app; not active until confirmed
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TotpEnrollmentResponse {
+    pub secret: String,
+    pub otpauth_url: String,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ConfirmTotpRequest {
+    pub code: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct FinishPasskeyRegistrationRequest {
+    pub name: Option<String>,
+    #[schema(value_type = Object)]
+    pub credential: RegisterPublicKeyCredential,
+}
+
+/// WebAuthn options to pass to `navigator.credentials.create()` or `.get()`
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PasskeyChallengeResponse {
+    #[schema(value_type = Object)]
+    pub options: serde_json::Value,
+}
+
+/// Returned after enrolling a factor. Recovery codes are only generated with the first factor.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MfaEnrollmentResponse {
+    pub recovery_codes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    pub codes: Vec<String>,
+}
+
+/// A signed-in session, as shown to its user or an admin
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserSessionResponse {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_activity_at: DateTime<Utc>,
+    #[schema(value_type = String)]
+    pub ip_address: IpAddr,
+    pub user_agent: Option<String>,
+    /// Whether this is the session making the request
+    pub is_current: bool,
+}
+
+impl UserSessionResponse {
+    pub fn new(session: UserSession, current: Option<&Uuid>) -> Self {
+        Self {
+            is_current: current == Some(&session.id),
+            id: session.id,
+            created_at: session.created_at,
+            last_activity_at: session.base.last_activity_at,
+            ip_address: session.base.ip_address,
+            user_agent: session.base.user_agent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevokeSessionsResponse {
+    /// Number of sessions signed out
+    pub revoked: usize,
+}
@@ -47,3 +47,12 @@ pub struct PendingDaemonSetup {
     pub network_id: Uuid,
     pub api_key_raw: Option<String>, // None if install_later
 }
+
+/// Password login waiting on a second factor (stored in session instead of `user_id`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingMfaLogin {
+    pub user_id: Uuid,
+    /// The organization requires MFA and the user has no factor enrolled yet
+    pub enrollment_required: bool,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,315 @@
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::server::users::r#impl::permissions::UserOrgPermissions;
+
+fn default_name() -> String {
+    "LDAP".to_string()
+}
+
+fn default_user_filter() -> String {
+    "(&(objectClass=person)(|(uid={username})(sAMAccountName={username})(mail={username})))"
+        .to_string()
+}
+
+fn default_email_attribute() -> String {
+    "mail".to_string()
+}
+
+fn default_group_attribute() -> String {
+    "memberOf".to_string()
+}
+
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+/// LDAP / Active Directory bind authentication, loaded from `ldap.toml`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LdapConfig {
+    /// Display name shown on the login page
+    #[serde(default = "default_name")]
+    pub name: String,
+    /// Server URL - `ldap://host:389`, or `ldaps://host:636` for implicit TLS
+    pub url: String,
+    /// Upgrade `ldap://` connections with StartTLS
+    #[serde(default)]
+    pub starttls: bool,
+    /// Skip certificate verification. Only for testing against self-signed servers.
+    #[serde(default)]
+    pub tls_skip_verify: bool,
+    /// Service account used to search for users. Anonymous search if unset.
+    pub bind_dn: Option<String>,
+    pub bind_password: Option<String>,
+    /// Where to search for users
+    pub user_base_dn: String,
+    /// Search filter; `{username}` is replaced with the escaped login name
+    #[serde(default = "default_user_filter")]
+    pub user_filter: String,
+    /// Attribute holding the user's email address
+    #[serde(default = "default_email_attribute")]
+    pub email_attribute: String,
+    /// Attribute listing the user's groups as DNs
+    #[serde(default = "default_group_attribute")]
+    pub group_attribute: String,
+    /// Organization users are created in on first login
+    pub organization_id: Uuid,
+    /// Group to role mappings - the highest matching role wins. Groups match by
+    /// full DN or by CN.
+    #[serde(default)]
+    pub roles: Vec<LdapRoleMapping>,
+    /// Role for users matching no role mapping. If unset and role mappings exist,
+    /// users without a matching group are refused.
+    pub default_permissions: Option<UserOrgPermissions>,
+    /// Group to network mappings - users get the union of all matching networks
+    #[serde(default)]
+    pub networks: Vec<LdapNetworkMapping>,
+    /// Only allow logins from members of at least one of these groups
+    #[serde(default)]
+    pub allowed_groups: Vec<String>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LdapRoleMapping {
+    pub group: String,
+    pub permissions: UserOrgPermissions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LdapNetworkMapping {
+    pub group: String,
+    pub network_ids: Vec<Uuid>,
+}
+
+/// Directory entry of a user who bound successfully
+#[derive(Debug, Clone)]
+pub struct LdapUserInfo {
+    pub dn: String,
+    pub email: Option<String>,
+    pub groups: Vec<String>,
+}
+
+/// Access resolved from a user's groups. `None` means the directory doesn't
+/// manage that part of the user's access and the stored value is kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LdapResolvedAccess {
+    pub permissions: Option<UserOrgPermissions>,
+    pub network_ids: Option<Vec<Uuid>>,
+}
+
+/// LDAP login details shown on the login page
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LdapProviderMetadata {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct LdapLoginRequest {
+    /// Directory username, e.g. `jdoe` or `jdoe@corp.example.com`
+    #[validate(length(min = 1, max = 256))]
+    pub username: String,
+    #[validate(length(min = 1))]
+    pub password: String,
+}
+
+/// Escape a value for use in an LDAP search filter (RFC 4515)
+pub fn escape_filter_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '*' => escaped.push_str("\\2a"),
+            '(' => escaped.push_str("\\28"),
+            ')' => escaped.push_str("\\29"),
+            '\\' => escaped.push_str("\\5c"),
+            '\0' => escaped.push_str("\\00"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// CN of a DN (`cn=Admins,ou=Groups,dc=example,dc=com` -> `Admins`)
+fn common_name(dn: &str) -> Option<&str> {
+    let first = dn.split(',').next()?.trim();
+    let (attribute, value) = first.split_once('=')?;
+    attribute
+        .trim()
+        .eq_ignore_ascii_case("cn")
+        .then(|| value.trim())
+}
+
+/// Whether a configured group (DN or CN) matches one of the user's group DNs
+fn group_matches(configured: &str, user_groups: &[String]) -> bool {
+    user_groups.iter().any(|dn| {
+        dn.eq_ignore_ascii_case(configured)
+            || common_name(dn).is_some_and(|cn| cn.eq_ignore_ascii_case(configured))
+    })
+}
+
+impl LdapConfig {
+    pub fn metadata(&self) -> LdapProviderMetadata {
+        LdapProviderMetadata {
+            name: self.name.clone(),
+        }
+    }
+
+    pub fn user_filter_for(&self, username: &str) -> String {
+        self.user_filter
+            .replace("{username}", &escape_filter_value(username))
+    }
+
+    /// Reject users outside the allowed groups
+    pub fn check_allowed(&self, user_info: &LdapUserInfo) -> Result<()> {
+        if !self.allowed_groups.is_empty()
+            && !self
+                .allowed_groups
+                .iter()
+                .any(|group| group_matches(group, &user_info.groups))
+        {
+            return Err(anyhow!(
+                "You are not a member of a group allowed to sign in to Scanopy"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Resolve role and network access from the user's groups
+    pub fn resolve(&self, groups: &[String]) -> Result<LdapResolvedAccess> {
+        let permissions = if self.roles.is_empty() {
+            None
+        } else {
+            let mapped = self
+                .roles
+                .iter()
+                .filter(|mapping| group_matches(&mapping.group, groups))
+                .map(|mapping| mapping.permissions)
+                .max()
+                .or(self.default_permissions);
+
+            Some(mapped.ok_or_else(|| anyhow!("Your directory account is not assigned a role"))?)
+        };
+
+        let network_ids = if self.networks.is_empty() {
+            None
+        } else {
+            let mut network_ids: Vec<Uuid> = Vec::new();
+            for mapping in self
+                .networks
+                .iter()
+                .filter(|mapping| group_matches(&mapping.group, groups))
+            {
+                for network_id in &mapping.network_ids {
+                    if !network_ids.contains(network_id) {
+                        network_ids.push(*network_id);
+                    }
+                }
+            }
+            Some(network_ids)
+        };
+
+        Ok(LdapResolvedAccess {
+            permissions,
+            network_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> LdapConfig {
+        serde_json::from_value(serde_json::json!({
+            "url": "ldap://localhost:389",
+            "user_base_dn": "ou=People,dc=example,dc=com",
+            "organization_id": Uuid::nil(),
+        }))
+        .unwrap()
+    }
+
+    fn user_info(groups: &[&str]) -> LdapUserInfo {
+        LdapUserInfo {
+            dn: "uid=jdoe,ou=People,dc=example,dc=com".to_string(),
+            email: Some("jdoe@example.com".to_string()),
+            groups: groups.iter().map(|g| g.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_user_filter_escapes_username() {
+        assert_eq!(escape_filter_value("jdoe"), "jdoe");
+        assert_eq!(escape_filter_value("*)(uid=*"), "\\2a\\29\\28uid=\\2a");
+        assert_eq!(escape_filter_value("a\\b"), "a\\5cb");
+
+        assert_eq!(
+            config().user_filter_for("j*"),
+            "(&(objectClass=person)(|(uid=j\\2a)(sAMAccountName=j\\2a)(mail=j\\2a)))"
+        );
+    }
+
+    #[test]
+    fn test_resolve_matches_groups_by_dn_or_cn() {
+        let network = Uuid::new_v4();
+        let mut config = config();
+        config.roles = vec![
+            LdapRoleMapping {
+                group: "Scanopy Admins".to_string(),
+                permissions: UserOrgPermissions::Admin,
+            },
+            LdapRoleMapping {
+                group: "cn=staff,ou=Groups,dc=example,dc=com".to_string(),
+                permissions: UserOrgPermissions::Member,
+            },
+        ];
+        config.networks = vec![LdapNetworkMapping {
+            group: "staff".to_string(),
+            network_ids: vec![network],
+        }];
+
+        let groups = user_info(&[
+            "CN=Staff,OU=Groups,DC=example,DC=com",
+            "cn=scanopy admins,ou=Groups,dc=example,dc=com",
+        ])
+        .groups;
+        let access = config.resolve(&groups).unwrap();
+        assert_eq!(access.permissions, Some(UserOrgPermissions::Admin));
+        assert_eq!(access.network_ids, Some(vec![network]));
+
+        // No matching role and no default refuses the login
+        assert!(config.resolve(&[]).is_err());
+
+        config.default_permissions = Some(UserOrgPermissions::Viewer);
+        let access = config.resolve(&[]).unwrap();
+        assert_eq!(access.permissions, Some(UserOrgPermissions::Viewer));
+        assert_eq!(access.network_ids, Some(vec![]));
+    }
+
+    #[test]
+    fn test_resolve_without_mappings_keeps_existing_access() {
+        let access = config().resolve(&["cn=anything".to_string()]).unwrap();
+        assert_eq!(access, LdapResolvedAccess::default());
+    }
+
+    #[test]
+    fn test_check_allowed() {
+        let mut config = config();
+        assert!(config.check_allowed(&user_info(&[])).is_ok());
+
+        config.allowed_groups = vec!["scanopy".to_string()];
+        assert!(
+            config
+                .check_allowed(&user_info(&["cn=Scanopy,ou=Groups,dc=example,dc=com"]))
+                .is_ok()
+        );
+        assert!(
+            config
+                .check_allowed(&user_info(&["cn=staff,ou=Groups,dc=example,dc=com"]))
+                .is_err()
+        );
+    }
+}
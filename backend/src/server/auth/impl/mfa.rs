@@ -0,0 +1,248 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use std::{fmt::Display, str::FromStr};
+use strum_macros::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::shared::{
+    entities::EntityDiscriminants,
+    storage::{
+        filter::EntityFilter,
+        generic::GenericPostgresStorage,
+        traits::{SqlValue, StorableEntity, Storage},
+    },
+};
+
+/// Kind of second factor stored in a credential record
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    ToSchema,
+)]
+pub enum MfaCredentialType {
+    #[default]
+    Totp,
+    Passkey,
+    RecoveryCode,
+}
+
+/// The base data for a UserMfaCredential record
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct UserMfaCredentialBase {
+    pub user_id: Uuid,
+    pub credential_type: MfaCredentialType,
+    pub name: String,
+    /// Base32 TOTP secret, serialized passkey, or SHA-256 hash of a recovery code
+    pub data: String,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl UserMfaCredentialBase {
+    pub fn new(
+        user_id: Uuid,
+        credential_type: MfaCredentialType,
+        name: String,
+        data: String,
+    ) -> Self {
+        Self {
+            user_id,
+            credential_type,
+            name,
+            data,
+            last_used_at: None,
+        }
+    }
+}
+
+/// A second factor enrolled by a user
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct UserMfaCredential {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub base: UserMfaCredentialBase,
+}
+
+impl UserMfaCredential {
+    pub fn new(base: UserMfaCredentialBase) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+
+    pub fn credential_type(&self) -> MfaCredentialType {
+        self.base.credential_type
+    }
+}
+
+impl Display for UserMfaCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UserMfaCredential(user={}, type={})",
+            self.base.user_id, self.base.credential_type
+        )
+    }
+}
+
+impl StorableEntity for UserMfaCredential {
+    type BaseData = UserMfaCredentialBase;
+
+    fn table_name() -> &'static str {
+        "user_mfa_credentials"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        UserMfaCredential::new(base)
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.last_used_at.unwrap_or(self.created_at)
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, _time: DateTime<Utc>) {
+        // No updated_at column; last_used_at is set explicitly
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::UserMfaCredential
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>)> {
+        Ok((
+            vec![
+                "id",
+                "user_id",
+                "credential_type",
+                "name",
+                "data",
+                "created_at",
+                "last_used_at",
+            ],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.user_id),
+                SqlValue::String(self.base.credential_type.to_string()),
+                SqlValue::String(self.base.name.clone()),
+                SqlValue::String(self.base.data.clone()),
+                SqlValue::Timestamp(self.created_at),
+                SqlValue::OptionTimestamp(self.base.last_used_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self> {
+        let credential_type: String = row.get("credential_type");
+
+        Ok(UserMfaCredential {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: UserMfaCredentialBase {
+                user_id: row.get("user_id"),
+                credential_type: MfaCredentialType::from_str(&credential_type).map_err(|e| {
+                    anyhow::anyhow!("Invalid MFA credential type '{}': {}", credential_type, e)
+                })?,
+                name: row.get("name"),
+                data: row.get("data"),
+                last_used_at: row.get("last_used_at"),
+            },
+        })
+    }
+}
+
+/// Storage operations for the user_mfa_credentials table.
+pub struct UserMfaCredentialStorage {
+    storage: GenericPostgresStorage<UserMfaCredential>,
+}
+
+impl UserMfaCredentialStorage {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            storage: GenericPostgresStorage::new(pool),
+        }
+    }
+
+    /// Get all credentials for a user, including recovery codes
+    pub async fn get_for_user(&self, user_id: &Uuid) -> Result<Vec<UserMfaCredential>> {
+        let filter = EntityFilter::unfiltered().user_id(user_id);
+        self.storage.get_all(filter).await
+    }
+
+    pub async fn get_by_id(&self, id: &Uuid) -> Result<Option<UserMfaCredential>> {
+        self.storage.get_by_id(id).await
+    }
+
+    pub async fn create(&self, credential: &UserMfaCredential) -> Result<UserMfaCredential> {
+        self.storage.create(credential).await
+    }
+
+    pub async fn update(&self, credential: &mut UserMfaCredential) -> Result<UserMfaCredential> {
+        self.storage.update(credential).await
+    }
+
+    pub async fn delete(&self, id: &Uuid) -> Result<()> {
+        self.storage.delete(id).await
+    }
+
+    /// Delete all credentials of one type for a user
+    pub async fn delete_for_user_by_type(
+        &self,
+        user_id: &Uuid,
+        credential_type: MfaCredentialType,
+    ) -> Result<()> {
+        let ids: Vec<Uuid> = self
+            .get_for_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|c| c.credential_type() == credential_type)
+            .map(|c| c.id)
+            .collect();
+
+        if !ids.is_empty() {
+            self.storage.delete_many(&ids).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,7 @@
 pub mod api;
 pub mod base;
+pub mod ldap;
+pub mod mfa;
 pub mod oidc;
+pub mod passkeys;
+pub mod sessions;
@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use base64ct::{Base64UrlUnpadded, Encoding};
 use chrono::{DateTime, Utc};
 use openidconnect::{
     AuthenticationFlow, AuthorizationCode, ClientId, ClientSecret, CsrfToken, IssuerUrl, Nonce,
@@ -8,8 +9,9 @@ use openidconnect::{
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
-use crate::server::config::DeploymentType;
+use crate::server::{config::DeploymentType, users::r#impl::permissions::UserOrgPermissions};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OidcPendingAuth {
@@ -40,7 +42,10 @@ pub enum OidcFlow {
 pub struct OidcUserInfo {
     pub subject: String,
     pub email: Option<String>,
+    pub email_verified: Option<bool>,
     pub name: Option<String>,
+    /// Values of the provider's groups claim, empty if the claim is absent
+    pub groups: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -51,6 +56,168 @@ pub struct OidcProviderConfig {
     pub issuer_url: String,
     pub client_id: String,
     pub client_secret: String,
+    #[serde(default)]
+    pub claim_mapping: OidcClaimMapping,
+}
+
+/// Maps a provider's claims onto Scanopy access. Role and network mappings are
+/// re-applied on every login so changes made in the IdP take effect at next sign-in.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct OidcClaimMapping {
+    /// ID token claim holding the user's groups. Dotted paths reach nested
+    /// claims (e.g. `realm_access.roles`). Defaults to `groups`.
+    pub groups_claim: Option<String>,
+    /// Group to role mappings - the highest matching role wins
+    #[serde(default)]
+    pub roles: Vec<OidcRoleMapping>,
+    /// Role for users matching no role mapping. If unset and role mappings
+    /// exist, users without a matching group are refused.
+    pub default_permissions: Option<UserOrgPermissions>,
+    /// Group to network mappings - users get the union of all matching networks
+    #[serde(default)]
+    pub networks: Vec<OidcNetworkMapping>,
+    /// Only allow logins from these email domains
+    #[serde(default)]
+    pub allowed_email_domains: Vec<String>,
+    /// Only allow logins from members of at least one of these groups
+    #[serde(default)]
+    pub allowed_groups: Vec<String>,
+    /// Create unknown users in this organization on first login
+    pub jit_organization_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OidcRoleMapping {
+    pub group: String,
+    pub permissions: UserOrgPermissions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OidcNetworkMapping {
+    pub group: String,
+    pub network_ids: Vec<Uuid>,
+}
+
+/// Access resolved from a user's claims. `None` means the provider doesn't
+/// manage that part of the user's access and the stored value is kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OidcResolvedAccess {
+    pub permissions: Option<UserOrgPermissions>,
+    pub network_ids: Option<Vec<Uuid>>,
+}
+
+impl OidcClaimMapping {
+    pub fn groups_claim(&self) -> &str {
+        self.groups_claim.as_deref().unwrap_or("groups")
+    }
+
+    /// Reject users outside the allowed email domains or groups
+    pub fn check_allowed(&self, user_info: &OidcUserInfo) -> Result<()> {
+        if !self.allowed_email_domains.is_empty() {
+            let domain = user_info
+                .email
+                .as_deref()
+                .and_then(|email| email.rsplit_once('@'))
+                .map(|(_, domain)| domain.to_lowercase());
+
+            let allowed = domain.is_some_and(|domain| {
+                self.allowed_email_domains.iter().any(|allowed| {
+                    allowed
+                        .trim_start_matches('@')
+                        .eq_ignore_ascii_case(&domain)
+                })
+            });
+
+            if !allowed {
+                return Err(anyhow!(
+                    "Your email domain is not allowed to sign in with this provider"
+                ));
+            }
+        }
+
+        if !self.allowed_groups.is_empty()
+            && !user_info
+                .groups
+                .iter()
+                .any(|group| self.allowed_groups.contains(group))
+        {
+            return Err(anyhow!(
+                "You are not a member of a group allowed to sign in with this provider"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Resolve role and network access from the user's groups
+    pub fn resolve(&self, groups: &[String]) -> Result<OidcResolvedAccess> {
+        let permissions =
+            if self.roles.is_empty() {
+                None
+            } else {
+                let mapped = self
+                    .roles
+                    .iter()
+                    .filter(|mapping| groups.contains(&mapping.group))
+                    .map(|mapping| mapping.permissions)
+                    .max()
+                    .or(self.default_permissions);
+
+                Some(mapped.ok_or_else(|| {
+                    anyhow!("Your account is not assigned a role for this provider")
+                })?)
+            };
+
+        let network_ids = if self.networks.is_empty() {
+            None
+        } else {
+            let mut network_ids: Vec<Uuid> = Vec::new();
+            for mapping in self
+                .networks
+                .iter()
+                .filter(|mapping| groups.contains(&mapping.group))
+            {
+                for network_id in &mapping.network_ids {
+                    if !network_ids.contains(network_id) {
+                        network_ids.push(*network_id);
+                    }
+                }
+            }
+            Some(network_ids)
+        };
+
+        Ok(OidcResolvedAccess {
+            permissions,
+            network_ids,
+        })
+    }
+}
+
+/// Read a string or string-array claim from a JSON claim set, following dotted paths
+fn extract_groups(claims: &serde_json::Value, path: &str) -> Vec<String> {
+    let value = path
+        .split('.')
+        .try_fold(claims, |value, segment| value.get(segment));
+
+    match value {
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        Some(serde_json::Value::String(value)) => vec![value.clone()],
+        _ => vec![],
+    }
+}
+
+/// Decode the payload of an ID token. Only call after the token has been verified.
+fn decode_id_token_payload(id_token: &str) -> Result<serde_json::Value> {
+    let payload = id_token
+        .split('.')
+        .nth(1)
+        .ok_or_else(|| anyhow!("Malformed ID token"))?;
+    let bytes = Base64UrlUnpadded::decode_vec(payload.trim_end_matches('='))
+        .map_err(|e| anyhow!("Invalid ID token encoding: {}", e))?;
+    Ok(serde_json::from_slice(&bytes)?)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -75,6 +242,7 @@ pub struct OidcProvider {
     pub slug: String,
     pub name: String,
     pub logo: Option<String>,
+    pub claim_mapping: OidcClaimMapping,
     issuer_url: String,
     client_id: String,
     client_secret: String,
@@ -82,22 +250,15 @@ pub struct OidcProvider {
 }
 
 impl OidcProvider {
-    pub fn new(
-        slug: String,
-        name: String,
-        logo: Option<String>,
-        issuer_url: String,
-        client_id: String,
-        client_secret: String,
-        redirect_url: String,
-    ) -> Self {
+    pub fn new(config: OidcProviderConfig, redirect_url: String) -> Self {
         Self {
-            slug,
-            name,
-            logo,
-            issuer_url,
-            client_id,
-            client_secret,
+            slug: config.slug,
+            name: config.name,
+            logo: config.logo,
+            claim_mapping: config.claim_mapping,
+            issuer_url: config.issuer_url,
+            client_id: config.client_id,
+            client_secret: config.client_secret,
             redirect_url,
         }
     }
@@ -183,12 +344,155 @@ impl OidcProvider {
 
         let claims = id_token.claims(&client.id_token_verifier(), &nonce)?;
 
+        // Group claims are provider-specific, so read them from the raw (verified) payload
+        let groups = extract_groups(
+            &decode_id_token_payload(&id_token.to_string())?,
+            self.claim_mapping.groups_claim(),
+        );
+
         Ok(OidcUserInfo {
             subject: claims.subject().to_string(),
             email: claims.email().map(|e| e.to_string()),
+            email_verified: claims.email_verified(),
             name: claims
                 .name()
                 .and_then(|n| n.get(None).map(|s| s.to_string())),
+            groups,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn user_info(email: Option<&str>, group_names: &[&str]) -> OidcUserInfo {
+        OidcUserInfo {
+            subject: "subject".to_string(),
+            email: email.map(str::to_string),
+            email_verified: Some(true),
+            name: None,
+            groups: groups(group_names),
+        }
+    }
+
+    #[test]
+    fn test_resolve_picks_highest_role_and_unions_networks() {
+        let office = Uuid::new_v4();
+        let lab = Uuid::new_v4();
+        let mapping = OidcClaimMapping {
+            roles: vec![
+                OidcRoleMapping {
+                    group: "viewers".to_string(),
+                    permissions: UserOrgPermissions::Viewer,
+                },
+                OidcRoleMapping {
+                    group: "admins".to_string(),
+                    permissions: UserOrgPermissions::Admin,
+                },
+            ],
+            networks: vec![
+                OidcNetworkMapping {
+                    group: "viewers".to_string(),
+                    network_ids: vec![office],
+                },
+                OidcNetworkMapping {
+                    group: "admins".to_string(),
+                    network_ids: vec![office, lab],
+                },
+            ],
+            ..Default::default()
+        };
+
+        let access = mapping.resolve(&groups(&["viewers", "admins"])).unwrap();
+        assert_eq!(access.permissions, Some(UserOrgPermissions::Admin));
+        assert_eq!(access.network_ids, Some(vec![office, lab]));
+
+        // Removing the user from every group revokes network access
+        let access = mapping.resolve(&[]);
+        assert!(access.is_err());
+    }
+
+    #[test]
+    fn test_resolve_without_mappings_keeps_existing_access() {
+        let access = OidcClaimMapping::default()
+            .resolve(&groups(&["anything"]))
+            .unwrap();
+        assert_eq!(access, OidcResolvedAccess::default());
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_default_role() {
+        let mapping = OidcClaimMapping {
+            roles: vec![OidcRoleMapping {
+                group: "admins".to_string(),
+                permissions: UserOrgPermissions::Admin,
+            }],
+            default_permissions: Some(UserOrgPermissions::Viewer),
+            ..Default::default()
+        };
+
+        let access = mapping.resolve(&groups(&["staff"])).unwrap();
+        assert_eq!(access.permissions, Some(UserOrgPermissions::Viewer));
+        assert_eq!(access.network_ids, None);
+    }
+
+    #[test]
+    fn test_check_allowed() {
+        let mapping = OidcClaimMapping {
+            allowed_email_domains: vec!["Example.com".to_string()],
+            allowed_groups: vec!["scanopy".to_string()],
+            ..Default::default()
+        };
+
+        assert!(
+            mapping
+                .check_allowed(&user_info(Some("a@example.com"), &["scanopy"]))
+                .is_ok()
+        );
+        assert!(
+            mapping
+                .check_allowed(&user_info(Some("a@other.com"), &["scanopy"]))
+                .is_err()
+        );
+        assert!(
+            mapping
+                .check_allowed(&user_info(Some("a@example.com"), &["staff"]))
+                .is_err()
+        );
+        assert!(
+            mapping
+                .check_allowed(&user_info(None, &["scanopy"]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_extract_groups_follows_nested_claims() {
+        let claims = serde_json::json!({
+            "groups": ["a", "b"],
+            "realm_access": { "roles": ["admin"] },
+            "department": "ops"
+        });
+
+        assert_eq!(extract_groups(&claims, "groups"), groups(&["a", "b"]));
+        assert_eq!(
+            extract_groups(&claims, "realm_access.roles"),
+            groups(&["admin"])
+        );
+        assert_eq!(extract_groups(&claims, "department"), groups(&["ops"]));
+        assert!(extract_groups(&claims, "missing.claim").is_empty());
+    }
+
+    #[test]
+    fn test_decode_id_token_payload() {
+        let payload = Base64UrlUnpadded::encode_string(br#"{"groups":["x"]}"#);
+        let token = format!("header.{}.signature", payload);
+        let claims = decode_id_token_payload(&token).unwrap();
+        assert_eq!(extract_groups(&claims, "groups"), groups(&["x"]));
+    }
+}
@@ -0,0 +1,758 @@
+use anyhow::{Result, anyhow, bail, ensure};
+use base64urlsafedata::Base64UrlSafeData;
+use ciborium::Value;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use url::Url;
+use uuid::Uuid;
+use webauthn_rs_proto::{
+    AllowCredentials, AttestationConveyancePreference, AuthenticatorSelectionCriteria,
+    COSEAlgorithm, CollectedClientData, CreationChallengeResponse, PubKeyCredParams,
+    PublicKeyCredential, PublicKeyCredentialCreationOptions, PublicKeyCredentialDescriptor,
+    PublicKeyCredentialRequestOptions, RegisterPublicKeyCredential, RelyingParty,
+    RequestChallengeResponse, ResidentKeyRequirement, User, UserVerificationPolicy,
+};
+
+/// How long the browser waits for the user to complete a ceremony
+const CEREMONY_TIMEOUT_MS: u32 = 5 * 60 * 1000;
+const CREDENTIAL_TYPE: &str = "public-key";
+
+/// Authenticator data flags
+const USER_PRESENT: u8 = 0x01;
+const USER_VERIFIED: u8 = 0x04;
+const ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+/// A registered passkey, stored as the data of its MFA credential
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Passkey {
+    pub credential_id: Base64UrlSafeData,
+    pub public_key: PasskeyPublicKey,
+    /// Signature counter; stays 0 for authenticators that don't keep one
+    pub counter: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "alg", rename_all = "snake_case")]
+pub enum PasskeyPublicKey {
+    /// ECDSA on P-256 with SHA-256
+    Es256 {
+        x: Base64UrlSafeData,
+        y: Base64UrlSafeData,
+    },
+    /// Ed25519
+    EdDsa { x: Base64UrlSafeData },
+}
+
+/// Registration challenge, kept in the session until the browser answers it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyRegistration {
+    challenge: Base64UrlSafeData,
+}
+
+/// Authentication challenge and the passkeys allowed to answer it, kept in the session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasskeyAuthentication {
+    challenge: Base64UrlSafeData,
+    credential_ids: Vec<Base64UrlSafeData>,
+}
+
+/// Runs WebAuthn registration and authentication for passkeys used as a second factor.
+///
+/// Verification is done here rather than with webauthn-rs, which links OpenSSL and would break
+/// musl builds. Every ceremony requires user verification.
+///
+/// Attestation is not verified. Registration asks for none, and the format and statement the
+/// authenticator returns are ignored, so nothing is known about what made a passkey; it is
+/// trusted because a signed-in user registered it.
+///
+/// Only ES256 and EdDSA keys are accepted. RS256 would need the `rsa` crate, whose
+/// signature code is affected by RUSTSEC-2023-0071, so authenticators that only support
+/// RS256 can't be registered.
+pub struct Webauthn {
+    rp_id: String,
+    rp_name: String,
+    origin: Url,
+}
+
+impl Webauthn {
+    /// Passkeys are bound to the host of the public URL
+    pub fn new(public_url: &str, rp_name: &str) -> Result<Self> {
+        let origin = Url::parse(public_url)?;
+        let rp_id = origin
+            .host_str()
+            .ok_or_else(|| anyhow!("Public URL has no host"))?
+            .to_string();
+
+        Ok(Self {
+            rp_id,
+            rp_name: rp_name.to_string(),
+            origin,
+        })
+    }
+
+    pub fn start_passkey_registration(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        exclude: Vec<Base64UrlSafeData>,
+    ) -> (CreationChallengeResponse, PasskeyRegistration) {
+        let challenge = new_challenge();
+
+        let options = CreationChallengeResponse {
+            public_key: PublicKeyCredentialCreationOptions {
+                rp: RelyingParty {
+                    name: self.rp_name.clone(),
+                    id: self.rp_id.clone(),
+                },
+                user: User {
+                    id: user_id.as_bytes().to_vec().into(),
+                    name: name.to_string(),
+                    display_name: name.to_string(),
+                },
+                challenge: challenge.clone(),
+                pub_key_cred_params: [COSEAlgorithm::ES256, COSEAlgorithm::EDDSA]
+                    .into_iter()
+                    .map(|alg| PubKeyCredParams {
+                        type_: CREDENTIAL_TYPE.to_string(),
+                        alg: alg as i64,
+                    })
+                    .collect(),
+                timeout: Some(CEREMONY_TIMEOUT_MS),
+                exclude_credentials: Some(
+                    exclude
+                        .into_iter()
+                        .map(|id| PublicKeyCredentialDescriptor {
+                            type_: CREDENTIAL_TYPE.to_string(),
+                            id,
+                            transports: None,
+                        })
+                        .collect(),
+                ),
+                authenticator_selection: Some(AuthenticatorSelectionCriteria {
+                    authenticator_attachment: None,
+                    resident_key: Some(ResidentKeyRequirement::Discouraged),
+                    require_resident_key: false,
+                    user_verification: UserVerificationPolicy::Required,
+                }),
+                hints: None,
+                attestation: Some(AttestationConveyancePreference::None),
+                attestation_formats: None,
+                extensions: None,
+            },
+        };
+
+        (options, PasskeyRegistration { challenge })
+    }
+
+    pub fn finish_passkey_registration(
+        &self,
+        credential: &RegisterPublicKeyCredential,
+        state: &PasskeyRegistration,
+    ) -> Result<Passkey> {
+        self.check_client_data(
+            &credential.response.client_data_json,
+            "webauthn.create",
+            &state.challenge,
+        )?;
+
+        // Only the authenticator data is read; `fmt` and `attStmt` are deliberately ignored
+        let attestation: Value =
+            ciborium::from_reader(credential.response.attestation_object.as_slice())
+                .map_err(|_| anyhow!("Attestation object is not valid CBOR"))?;
+        let auth_data = map_get(&attestation, &Value::from("authData"))
+            .and_then(Value::as_bytes)
+            .ok_or_else(|| anyhow!("Attestation object has no authenticator data"))?;
+
+        let auth_data = AuthenticatorData::parse(auth_data)?;
+        self.check_authenticator_data(&auth_data)?;
+
+        let (credential_id, public_key) = auth_data
+            .attested_credential
+            .ok_or_else(|| anyhow!("Authenticator did not return a credential"))?;
+        ensure!(
+            credential_id == credential.raw_id.as_slice(),
+            "Credential ID does not match the authenticator data"
+        );
+
+        Ok(Passkey {
+            credential_id: credential_id.into(),
+            public_key,
+            counter: auth_data.counter,
+        })
+    }
+
+    pub fn start_passkey_authentication(
+        &self,
+        passkeys: &[Passkey],
+    ) -> (RequestChallengeResponse, PasskeyAuthentication) {
+        let challenge = new_challenge();
+        let credential_ids: Vec<Base64UrlSafeData> =
+            passkeys.iter().map(|p| p.credential_id.clone()).collect();
+
+        let options = RequestChallengeResponse {
+            public_key: PublicKeyCredentialRequestOptions {
+                challenge: challenge.clone(),
+                timeout: Some(CEREMONY_TIMEOUT_MS),
+                rp_id: self.rp_id.clone(),
+                allow_credentials: credential_ids
+                    .iter()
+                    .map(|id| AllowCredentials {
+                        type_: CREDENTIAL_TYPE.to_string(),
+                        id: id.clone(),
+                        transports: None,
+                    })
+                    .collect(),
+                user_verification: UserVerificationPolicy::Required,
+                hints: None,
+                extensions: None,
+            },
+            mediation: None,
+        };
+
+        (
+            options,
+            PasskeyAuthentication {
+                challenge,
+                credential_ids,
+            },
+        )
+    }
+
+    /// Check an assertion made with `passkey`. Returns the signature counter to store.
+    pub fn finish_passkey_authentication(
+        &self,
+        credential: &PublicKeyCredential,
+        state: &PasskeyAuthentication,
+        passkey: &Passkey,
+    ) -> Result<u32> {
+        ensure!(
+            credential.raw_id == passkey.credential_id
+                && state.credential_ids.contains(&passkey.credential_id),
+            "Passkey was not offered for this login"
+        );
+
+        let client_data_json = &credential.response.client_data_json;
+        self.check_client_data(client_data_json, "webauthn.get", &state.challenge)?;
+
+        let raw_auth_data = credential.response.authenticator_data.as_slice();
+        let auth_data = AuthenticatorData::parse(raw_auth_data)?;
+        self.check_authenticator_data(&auth_data)?;
+
+        // A counter that doesn't move forward suggests the authenticator was cloned
+        if (auth_data.counter != 0 || passkey.counter != 0) && auth_data.counter <= passkey.counter
+        {
+            bail!("Signature counter went backwards");
+        }
+
+        let mut signed = raw_auth_data.to_vec();
+        signed.extend_from_slice(&Sha256::digest(client_data_json.as_slice()));
+        passkey
+            .public_key
+            .verify(&signed, &credential.response.signature)?;
+
+        Ok(auth_data.counter)
+    }
+
+    fn check_client_data(
+        &self,
+        client_data_json: &[u8],
+        ceremony: &str,
+        challenge: &Base64UrlSafeData,
+    ) -> Result<()> {
+        let client_data: CollectedClientData = serde_json::from_slice(client_data_json)
+            .map_err(|_| anyhow!("Client data is not valid"))?;
+
+        ensure!(client_data.type_ == ceremony, "Wrong WebAuthn ceremony");
+        ensure!(
+            client_data.challenge == *challenge,
+            "Challenge does not match"
+        );
+        ensure!(
+            client_data.origin.origin() == self.origin.origin()
+                && client_data.cross_origin != Some(true),
+            "Passkey was used from another site"
+        );
+
+        Ok(())
+    }
+
+    fn check_authenticator_data(&self, auth_data: &AuthenticatorData) -> Result<()> {
+        ensure!(
+            auth_data.rp_id_hash == &Sha256::digest(self.rp_id.as_bytes())[..],
+            "Passkey belongs to another site"
+        );
+        ensure!(
+            auth_data.flags & USER_PRESENT != 0 && auth_data.flags & USER_VERIFIED != 0,
+            "Authenticator did not verify the user"
+        );
+
+        Ok(())
+    }
+}
+
+impl PasskeyPublicKey {
+    /// Read a COSE_Key from a credential's attested data
+    fn from_cose(key: &Value) -> Result<Self> {
+        let field = |label: i64| map_get(key, &Value::from(label));
+        let bytes = |label: i64| {
+            field(label)
+                .and_then(Value::as_bytes)
+                .map(|b| Base64UrlSafeData::from(b.clone()))
+                .ok_or_else(|| anyhow!("Public key is missing a parameter"))
+        };
+        let integer = |label: i64| {
+            field(label)
+                .and_then(Value::as_integer)
+                .map(i128::from)
+                .ok_or_else(|| anyhow!("Public key is missing a parameter"))
+        };
+
+        const ES256: i128 = COSEAlgorithm::ES256 as i128;
+        const EDDSA: i128 = COSEAlgorithm::EDDSA as i128;
+
+        // Key type (1), algorithm (3) and curve (-1) as registered with IANA for COSE
+        match (integer(1)?, integer(3)?) {
+            (2, ES256) if integer(-1)? == 1 => Ok(Self::Es256 {
+                x: bytes(-2)?,
+                y: bytes(-3)?,
+            }),
+            (1, EDDSA) if integer(-1)? == 6 => Ok(Self::EdDsa { x: bytes(-2)? }),
+            (_, alg) => bail!("Unsupported passkey algorithm {}", alg),
+        }
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<()> {
+        let invalid = |_| anyhow!("Passkey signature is not valid");
+
+        match self {
+            Self::Es256 { x, y } => {
+                use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+
+                let point = [&[0x04], x.as_slice(), y.as_slice()].concat();
+                let key = VerifyingKey::from_sec1_bytes(&point)
+                    .map_err(|_| anyhow!("Stored passkey is malformed"))?;
+                let signature = Signature::from_der(signature).map_err(invalid)?;
+                key.verify(message, &signature).map_err(invalid)
+            }
+            Self::EdDsa { x } => {
+                use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+                let key: [u8; 32] = x
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| anyhow!("Stored passkey is malformed"))?;
+                let key = VerifyingKey::from_bytes(&key)
+                    .map_err(|_| anyhow!("Stored passkey is malformed"))?;
+                let signature = Signature::from_slice(signature).map_err(invalid)?;
+                key.verify(message, &signature).map_err(invalid)
+            }
+        }
+    }
+}
+
+/// The fields of WebAuthn authenticator data that are checked
+struct AuthenticatorData<'a> {
+    rp_id_hash: &'a [u8],
+    flags: u8,
+    counter: u32,
+    attested_credential: Option<(&'a [u8], PasskeyPublicKey)>,
+}
+
+impl<'a> AuthenticatorData<'a> {
+    /// Layout: RP ID hash (32), flags (1), counter (4), then when flagged an AAGUID (16),
+    /// credential ID length (2), credential ID and the COSE public key
+    fn parse(data: &'a [u8]) -> Result<Self> {
+        let truncated = || anyhow!("Authenticator data is truncated");
+
+        let (rp_id_hash, rest) = data.split_at_checked(32).ok_or_else(truncated)?;
+        let (&flags, rest) = rest.split_first().ok_or_else(truncated)?;
+        let (counter, rest) = rest.split_first_chunk::<4>().ok_or_else(truncated)?;
+
+        let attested_credential = if flags & ATTESTED_CREDENTIAL_DATA != 0 {
+            let (_aaguid, rest) = rest.split_at_checked(16).ok_or_else(truncated)?;
+            let (id_len, rest) = rest.split_first_chunk::<2>().ok_or_else(truncated)?;
+            let (credential_id, mut rest) = rest
+                .split_at_checked(u16::from_be_bytes(*id_len) as usize)
+                .ok_or_else(truncated)?;
+
+            // Extensions may follow the key, so only read the one CBOR item
+            let key: Value = ciborium::from_reader(&mut rest)
+                .map_err(|_| anyhow!("Credential public key is not valid CBOR"))?;
+
+            Some((credential_id, PasskeyPublicKey::from_cose(&key)?))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            counter: u32::from_be_bytes(*counter),
+            attested_credential,
+        })
+    }
+}
+
+fn new_challenge() -> Base64UrlSafeData {
+    rand::random::<[u8; 32]>().to_vec().into()
+}
+
+fn map_get<'v>(map: &'v Value, key: &Value) -> Option<&'v Value> {
+    map.as_map()?.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{DerSignature, SigningKey, signature::Signer};
+
+    const PUBLIC_URL: &str = "https://scanopy.example.com/app";
+    const ORIGIN: &str = "https://scanopy.example.com";
+
+    /// A software authenticator holding one P-256 passkey
+    struct Authenticator {
+        key: SigningKey,
+        credential_id: Vec<u8>,
+        counter: u32,
+        flags: u8,
+    }
+
+    impl Authenticator {
+        fn new() -> Self {
+            Self {
+                key: SigningKey::from_slice(&[7; 32]).unwrap(),
+                credential_id: vec![1, 2, 3, 4],
+                counter: 0,
+                flags: USER_PRESENT | USER_VERIFIED,
+            }
+        }
+
+        fn auth_data(&self, rp_id: &str, attested: bool) -> Vec<u8> {
+            let mut data = Sha256::digest(rp_id.as_bytes()).to_vec();
+            data.push(
+                self.flags
+                    | if attested {
+                        ATTESTED_CREDENTIAL_DATA
+                    } else {
+                        0
+                    },
+            );
+            data.extend_from_slice(&self.counter.to_be_bytes());
+
+            if attested {
+                let point = self.key.verifying_key().to_encoded_point(false);
+                let cose = Value::Map(vec![
+                    (Value::from(1), Value::from(2)),
+                    (Value::from(3), Value::from(-7)),
+                    (Value::from(-1), Value::from(1)),
+                    (Value::from(-2), Value::from(point.x().unwrap().to_vec())),
+                    (Value::from(-3), Value::from(point.y().unwrap().to_vec())),
+                ]);
+
+                data.extend_from_slice(&[0; 16]);
+                data.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+                data.extend_from_slice(&self.credential_id);
+                ciborium::into_writer(&cose, &mut data).unwrap();
+            }
+
+            data
+        }
+
+        fn client_data(ceremony: &str, challenge: &Base64UrlSafeData, origin: &str) -> Vec<u8> {
+            serde_json::to_vec(&serde_json::json!({
+                "type": ceremony,
+                "challenge": challenge,
+                "origin": origin,
+            }))
+            .unwrap()
+        }
+
+        fn register(
+            &self,
+            options: &CreationChallengeResponse,
+            origin: &str,
+        ) -> RegisterPublicKeyCredential {
+            let public_key = &options.public_key;
+            let attestation = Value::Map(vec![
+                (Value::from("fmt"), Value::from("none")),
+                (Value::from("attStmt"), Value::Map(vec![])),
+                (
+                    Value::from("authData"),
+                    Value::from(self.auth_data(&public_key.rp.id, true)),
+                ),
+            ]);
+            let mut attestation_object = Vec::new();
+            ciborium::into_writer(&attestation, &mut attestation_object).unwrap();
+
+            serde_json::from_value(serde_json::json!({
+                "id": Base64UrlSafeData::from(self.credential_id.clone()),
+                "rawId": Base64UrlSafeData::from(self.credential_id.clone()),
+                "type": "public-key",
+                "response": {
+                    "attestationObject": Base64UrlSafeData::from(attestation_object),
+                    "clientDataJSON": Base64UrlSafeData::from(Self::client_data(
+                        "webauthn.create",
+                        &public_key.challenge,
+                        origin,
+                    )),
+                },
+            }))
+            .unwrap()
+        }
+
+        fn sign_in(
+            &mut self,
+            options: &RequestChallengeResponse,
+            origin: &str,
+        ) -> PublicKeyCredential {
+            self.counter += 1;
+
+            let auth_data = self.auth_data(&options.public_key.rp_id, false);
+            let client_data =
+                Self::client_data("webauthn.get", &options.public_key.challenge, origin);
+
+            let mut signed = auth_data.clone();
+            signed.extend_from_slice(&Sha256::digest(&client_data));
+            let signature: DerSignature = self.key.sign(&signed);
+
+            serde_json::from_value(serde_json::json!({
+                "id": Base64UrlSafeData::from(self.credential_id.clone()),
+                "rawId": Base64UrlSafeData::from(self.credential_id.clone()),
+                "type": "public-key",
+                "response": {
+                    "authenticatorData": Base64UrlSafeData::from(auth_data),
+                    "clientDataJSON": Base64UrlSafeData::from(client_data),
+                    "signature": Base64UrlSafeData::from(signature.as_bytes().to_vec()),
+                },
+            }))
+            .unwrap()
+        }
+    }
+
+    fn webauthn() -> Webauthn {
+        Webauthn::new(PUBLIC_URL, "Scanopy").unwrap()
+    }
+
+    fn registered(webauthn: &Webauthn, authenticator: &Authenticator) -> Passkey {
+        let (options, state) =
+            webauthn.start_passkey_registration(Uuid::new_v4(), "user@example.com", vec![]);
+        webauthn
+            .finish_passkey_registration(&authenticator.register(&options, ORIGIN), &state)
+            .unwrap()
+    }
+
+    fn registration_error(
+        webauthn: &Webauthn,
+        credential_for: impl Fn(&CreationChallengeResponse) -> RegisterPublicKeyCredential,
+    ) -> String {
+        let (options, state) =
+            webauthn.start_passkey_registration(Uuid::new_v4(), "user@example.com", vec![]);
+        webauthn
+            .finish_passkey_registration(&credential_for(&options), &state)
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn test_register_and_sign_in() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+
+        let passkey = registered(&webauthn, &authenticator);
+        assert_eq!(passkey.credential_id.as_slice(), &[1, 2, 3, 4]);
+        assert!(matches!(passkey.public_key, PasskeyPublicKey::Es256 { .. }));
+
+        let (options, state) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+        assert_eq!(options.public_key.rp_id, "scanopy.example.com");
+
+        let assertion = authenticator.sign_in(&options, ORIGIN);
+        assert_eq!(
+            webauthn
+                .finish_passkey_authentication(&assertion, &state, &passkey)
+                .unwrap(),
+            1
+        );
+
+        // Stored passkeys survive a round trip through the credential's data
+        let stored: Passkey =
+            serde_json::from_str(&serde_json::to_string(&passkey).unwrap()).unwrap();
+        assert_eq!(stored, passkey);
+    }
+
+    #[test]
+    fn test_sign_in_rejects_another_origin() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        let passkey = registered(&webauthn, &authenticator);
+        let (options, state) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+
+        let assertion = authenticator.sign_in(&options, "https://evil.example.com");
+        let error = webauthn
+            .finish_passkey_authentication(&assertion, &state, &passkey)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Passkey was used from another site");
+    }
+
+    #[test]
+    fn test_sign_in_rejects_another_rp_id() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        let passkey = registered(&webauthn, &authenticator);
+        let (mut options, state) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+
+        // Signed for a different relying party, so the rpIdHash is wrong
+        options.public_key.rp_id = "evil.example.com".to_string();
+        let assertion = authenticator.sign_in(&options, ORIGIN);
+        let error = webauthn
+            .finish_passkey_authentication(&assertion, &state, &passkey)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Passkey belongs to another site");
+    }
+
+    #[test]
+    fn test_sign_in_requires_user_verification() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        let passkey = registered(&webauthn, &authenticator);
+        let (options, state) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+
+        authenticator.flags = USER_PRESENT;
+        let assertion = authenticator.sign_in(&options, ORIGIN);
+        let error = webauthn
+            .finish_passkey_authentication(&assertion, &state, &passkey)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Authenticator did not verify the user");
+    }
+
+    #[test]
+    fn test_sign_in_rejects_counter_regression() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        let mut passkey = registered(&webauthn, &authenticator);
+        let (options, state) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+
+        passkey.counter = 5;
+        authenticator.counter = 2;
+        let assertion = authenticator.sign_in(&options, ORIGIN);
+        let error = webauthn
+            .finish_passkey_authentication(&assertion, &state, &passkey)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Signature counter went backwards");
+
+        // A replayed counter is rejected too
+        authenticator.counter = 4;
+        let assertion = authenticator.sign_in(&options, ORIGIN);
+        assert!(
+            webauthn
+                .finish_passkey_authentication(&assertion, &state, &passkey)
+                .is_err()
+        );
+
+        authenticator.counter = 5;
+        let assertion = authenticator.sign_in(&options, ORIGIN);
+        assert_eq!(
+            webauthn
+                .finish_passkey_authentication(&assertion, &state, &passkey)
+                .unwrap(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_sign_in_rejects_another_challenge() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        let passkey = registered(&webauthn, &authenticator);
+        let (_, state) = webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+        let (other_options, _) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+
+        let assertion = authenticator.sign_in(&other_options, ORIGIN);
+        let error = webauthn
+            .finish_passkey_authentication(&assertion, &state, &passkey)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Challenge does not match");
+    }
+
+    #[test]
+    fn test_sign_in_rejects_tampered_signature() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        let passkey = registered(&webauthn, &authenticator);
+        let (options, state) =
+            webauthn.start_passkey_authentication(std::slice::from_ref(&passkey));
+
+        let mut assertion = authenticator.sign_in(&options, ORIGIN);
+        let last = assertion.response.signature.len() - 1;
+        assertion.response.signature[last] ^= 1;
+        let error = webauthn
+            .finish_passkey_authentication(&assertion, &state, &passkey)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Passkey signature is not valid");
+    }
+
+    #[test]
+    fn test_registration_rejects_another_challenge() {
+        let webauthn = webauthn();
+        let authenticator = Authenticator::new();
+
+        let error = registration_error(&webauthn, |_| {
+            let (other_options, _) =
+                webauthn.start_passkey_registration(Uuid::new_v4(), "user@example.com", vec![]);
+            authenticator.register(&other_options, ORIGIN)
+        });
+        assert_eq!(error, "Challenge does not match");
+    }
+
+    #[test]
+    fn test_registration_rejects_another_origin() {
+        let webauthn = webauthn();
+        let authenticator = Authenticator::new();
+
+        let error = registration_error(&webauthn, |options| {
+            authenticator.register(options, "https://evil.example.com")
+        });
+        assert_eq!(error, "Passkey was used from another site");
+    }
+
+    #[test]
+    fn test_registration_rejects_another_rp_id() {
+        let webauthn = webauthn();
+        let authenticator = Authenticator::new();
+
+        let error = registration_error(&webauthn, |options| {
+            let mut options = options.clone();
+            options.public_key.rp.id = "evil.example.com".to_string();
+            authenticator.register(&options, ORIGIN)
+        });
+        assert_eq!(error, "Passkey belongs to another site");
+    }
+
+    #[test]
+    fn test_registration_requires_user_verification() {
+        let webauthn = webauthn();
+        let mut authenticator = Authenticator::new();
+        authenticator.flags = USER_PRESENT;
+
+        let error =
+            registration_error(&webauthn, |options| authenticator.register(options, ORIGIN));
+        assert_eq!(error, "Authenticator did not verify the user");
+    }
+
+    #[test]
+    fn test_rs256_keys_are_not_accepted() {
+        let rs256 = Value::Map(vec![
+            (Value::from(1), Value::from(3)),
+            (Value::from(3), Value::from(-257)),
+            (Value::from(-1), Value::from(vec![0xc5; 256])),
+            (Value::from(-2), Value::from(vec![0x01, 0x00, 0x01])),
+        ]);
+        let error = PasskeyPublicKey::from_cose(&rs256).unwrap_err();
+        assert_eq!(error.to_string(), "Unsupported passkey algorithm -257");
+    }
+}
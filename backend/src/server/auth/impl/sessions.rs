@@ -0,0 +1,234 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use ipnetwork::IpNetwork;
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use std::{
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr},
+};
+use uuid::Uuid;
+
+use crate::server::shared::{
+    entities::EntityDiscriminants,
+    storage::{
+        filter::EntityFilter,
+        generic::GenericPostgresStorage,
+        traits::{SqlValue, StorableEntity, Storage},
+    },
+};
+
+/// Key in the session cookie data holding the id of the session's record
+pub const SESSION_RECORD_KEY: &str = "user_session_id";
+
+/// The base data for a UserSession record
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UserSessionBase {
+    pub user_id: Uuid,
+    /// Address the session was signed in from
+    pub ip_address: IpAddr,
+    pub user_agent: Option<String>,
+    pub last_activity_at: DateTime<Utc>,
+}
+
+impl Default for UserSessionBase {
+    fn default() -> Self {
+        Self {
+            user_id: Uuid::nil(),
+            ip_address: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            user_agent: None,
+            last_activity_at: Utc::now(),
+        }
+    }
+}
+
+impl UserSessionBase {
+    pub fn new(user_id: Uuid, ip_address: IpAddr, user_agent: Option<String>) -> Self {
+        Self {
+            user_id,
+            ip_address,
+            user_agent,
+            last_activity_at: Utc::now(),
+        }
+    }
+}
+
+/// A signed-in browser session
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct UserSession {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub base: UserSessionBase,
+}
+
+impl UserSession {
+    pub fn new(base: UserSessionBase) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+}
+
+impl Display for UserSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UserSession(id={}, user={}, ip={})",
+            self.id, self.base.user_id, self.base.ip_address
+        )
+    }
+}
+
+impl StorableEntity for UserSession {
+    type BaseData = UserSessionBase;
+
+    fn table_name() -> &'static str {
+        "user_sessions"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        UserSession::new(base)
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.last_activity_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, _time: DateTime<Utc>) {
+        // No updated_at column; last_activity_at is set explicitly
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::UserSession
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>)> {
+        Ok((
+            vec![
+                "id",
+                "user_id",
+                "ip_address",
+                "user_agent",
+                "created_at",
+                "last_activity_at",
+            ],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.user_id),
+                SqlValue::IpAddr(self.base.ip_address),
+                SqlValue::OptionalString(self.base.user_agent.clone()),
+                SqlValue::Timestamp(self.created_at),
+                SqlValue::Timestamp(self.base.last_activity_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self> {
+        let ip_network: IpNetwork = row
+            .try_get("ip_address")
+            .map_err(|e| anyhow::anyhow!("Failed to read ip_address: {}", e))?;
+
+        Ok(UserSession {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: UserSessionBase {
+                user_id: row.get("user_id"),
+                ip_address: ip_network.ip(),
+                user_agent: row.get("user_agent"),
+                last_activity_at: row.get("last_activity_at"),
+            },
+        })
+    }
+}
+
+/// Storage operations for the user_sessions table.
+pub struct UserSessionStorage {
+    storage: GenericPostgresStorage<UserSession>,
+}
+
+impl UserSessionStorage {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            storage: GenericPostgresStorage::new(pool),
+        }
+    }
+
+    /// Get a user's sessions, most recently active first
+    pub async fn get_for_user(&self, user_id: &Uuid) -> Result<Vec<UserSession>> {
+        let filter = EntityFilter::unfiltered().user_id(user_id);
+        self.storage
+            .get_all_ordered(filter, "last_activity_at DESC")
+            .await
+    }
+
+    pub async fn get_by_id(&self, id: &Uuid) -> Result<Option<UserSession>> {
+        self.storage.get_by_id(id).await
+    }
+
+    pub async fn create(&self, session: &UserSession) -> Result<UserSession> {
+        self.storage.create(session).await
+    }
+
+    pub async fn update(&self, session: &mut UserSession) -> Result<UserSession> {
+        self.storage.update(session).await
+    }
+
+    pub async fn delete(&self, id: &Uuid) -> Result<()> {
+        self.storage.delete(id).await
+    }
+
+    pub async fn delete_many(&self, ids: &[Uuid]) -> Result<usize> {
+        self.storage.delete_many(ids).await
+    }
+
+    /// Delete sessions idle since `idle_before`, or signed in before `created_before`
+    pub async fn delete_expired(
+        &self,
+        idle_before: DateTime<Utc>,
+        created_before: Option<DateTime<Utc>>,
+    ) -> Result<usize> {
+        let mut deleted = self
+            .storage
+            .delete_by_filter(EntityFilter::unfiltered().last_activity_before(idle_before))
+            .await?;
+
+        if let Some(created_before) = created_before {
+            deleted += self
+                .storage
+                .delete_by_filter(EntityFilter::unfiltered().created_before(created_before))
+                .await?;
+        }
+
+        Ok(deleted)
+    }
+}
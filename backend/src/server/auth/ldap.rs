@@ -0,0 +1,303 @@
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use email_address::EmailAddress;
+use ldap3::{LdapConnAsync, LdapConnSettings, Scope, SearchEntry};
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::server::{
+    auth::{
+        r#impl::{
+            base::ProvisionUserParams,
+            ldap::{LdapConfig, LdapLoginRequest, LdapProviderMetadata, LdapUserInfo},
+        },
+        middleware::auth::AuthenticatedEntity,
+        service::AuthService,
+    },
+    shared::{
+        events::{
+            bus::EventBus,
+            types::{AuthEvent, AuthOperation},
+        },
+        services::traits::CrudService,
+        storage::filter::EntityFilter,
+    },
+    users::{r#impl::base::User, service::UserService},
+};
+
+pub struct LdapService {
+    config: LdapConfig,
+    auth_service: Arc<AuthService>,
+    user_service: Arc<UserService>,
+    login_attempts: Arc<RwLock<HashMap<String, (u32, Instant)>>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl LdapService {
+    const MAX_LOGIN_ATTEMPTS: u32 = 5;
+    const LOCKOUT_DURATION_SECS: u64 = 15 * 60; // 15 minutes
+
+    pub fn new(
+        config: LdapConfig,
+        auth_service: Arc<AuthService>,
+        user_service: Arc<UserService>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            config,
+            auth_service,
+            user_service,
+            login_attempts: Arc::new(RwLock::new(HashMap::new())),
+            event_bus,
+        }
+    }
+
+    pub fn metadata(&self) -> LdapProviderMetadata {
+        self.config.metadata()
+    }
+
+    /// Login with directory credentials. Unknown users are created in the
+    /// configured organization on first login.
+    pub async fn login(
+        &self,
+        request: LdapLoginRequest,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<User> {
+        request
+            .validate()
+            .map_err(|e| anyhow!("Validation failed: {}", e))?;
+
+        let username = request.username.trim().to_lowercase();
+        self.check_login_lockout(&username).await?;
+
+        match self.try_login(&request, ip, user_agent.clone()).await {
+            Ok(user) => {
+                self.login_attempts.write().await.remove(&username);
+
+                let authentication: AuthenticatedEntity = user.clone().into();
+                self.event_bus
+                    .publish_auth(AuthEvent {
+                        id: Uuid::new_v4(),
+                        user_id: Some(user.id),
+                        organization_id: Some(user.base.organization_id),
+                        timestamp: Utc::now(),
+                        operation: AuthOperation::LoginSuccess,
+                        ip_address: ip,
+                        user_agent,
+                        metadata: serde_json::json!({
+                            "method": "ldap",
+                        }),
+
+                        authentication,
+                    })
+                    .await?;
+
+                Ok(user)
+            }
+            Err(e) => {
+                self.event_bus
+                    .publish_auth(AuthEvent {
+                        id: Uuid::new_v4(),
+                        user_id: None,
+                        organization_id: Some(self.config.organization_id),
+                        timestamp: Utc::now(),
+                        operation: AuthOperation::LoginFailed,
+                        ip_address: ip,
+                        user_agent,
+                        metadata: serde_json::json!({
+                            "method": "ldap",
+                            "username": username
+                        }),
+                        authentication: AuthenticatedEntity::Anonymous,
+                    })
+                    .await?;
+
+                let mut attempts = self.login_attempts.write().await;
+                let entry = attempts.entry(username).or_insert((0, Instant::now()));
+                entry.0 += 1;
+                entry.1 = Instant::now();
+                Err(e)
+            }
+        }
+    }
+
+    /// Check if a username is locked out due to too many login attempts
+    async fn check_login_lockout(&self, username: &str) -> Result<()> {
+        let attempts = self.login_attempts.read().await;
+        if let Some((count, last_attempt)) = attempts.get(username)
+            && *count >= Self::MAX_LOGIN_ATTEMPTS
+        {
+            let elapsed = last_attempt.elapsed().as_secs();
+            if elapsed < Self::LOCKOUT_DURATION_SECS {
+                let remaining = (Self::LOCKOUT_DURATION_SECS - elapsed) / 60;
+                return Err(anyhow!(
+                    "Too many failed login attempts. Try again in {} minutes.",
+                    remaining + 1
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn try_login(
+        &self,
+        request: &LdapLoginRequest,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<User> {
+        let timeout = Duration::from_secs(self.config.timeout_secs);
+        let user_info = tokio::time::timeout(
+            timeout * 3,
+            self.authenticate(request.username.trim(), &request.password),
+        )
+        .await
+        .map_err(|_| anyhow!("Timed out contacting the directory server"))??;
+
+        // Enforce restrictions and resolve mapped access before touching the account,
+        // so users removed from directory groups are refused here
+        self.config.check_allowed(&user_info)?;
+        let access = self.config.resolve(&user_info.groups)?;
+
+        let email = user_info
+            .email
+            .as_deref()
+            .and_then(|email| EmailAddress::from_str(email).ok())
+            .ok_or_else(|| anyhow!("Your directory account has no valid email address"))?;
+
+        let existing_user = self
+            .user_service
+            .get_one(EntityFilter::unfiltered().email(&email))
+            .await?;
+
+        match existing_user {
+            Some(user) if user.base.organization_id != self.config.organization_id => Err(anyhow!(
+                "An account with this email address already exists in another organization"
+            )),
+            Some(user) if user.is_deactivated() => {
+                Err(anyhow!("This account has been deactivated"))
+            }
+            Some(user) => {
+                self.user_service
+                    .apply_external_access(user, access.permissions, access.network_ids, "LDAP")
+                    .await
+            }
+            None => {
+                let user = self
+                    .auth_service
+                    .provision_user(
+                        ProvisionUserParams {
+                            email,
+                            password_hash: None,
+                            oidc_subject: None,
+                            oidc_provider: None,
+                            org_id: Some(self.config.organization_id),
+                            permissions: access.permissions.or(self.config.default_permissions),
+                            network_ids: access.network_ids.unwrap_or_default(),
+                            terms_accepted_at: None,
+                            billing_enabled: false,
+                        },
+                        None,
+                    )
+                    .await?;
+
+                let authentication: AuthenticatedEntity = user.clone().into();
+                self.event_bus
+                    .publish_auth(AuthEvent {
+                        id: Uuid::new_v4(),
+                        user_id: Some(user.id),
+                        organization_id: Some(user.base.organization_id),
+                        timestamp: Utc::now(),
+                        operation: AuthOperation::Register,
+                        ip_address: ip,
+                        user_agent,
+                        metadata: serde_json::json!({
+                            "method": "ldap",
+                            "dn": user_info.dn
+                        }),
+
+                        authentication,
+                    })
+                    .await?;
+
+                Ok(user)
+            }
+        }
+    }
+
+    /// Find the user's entry and bind as them to verify the password
+    async fn authenticate(&self, username: &str, password: &str) -> Result<LdapUserInfo> {
+        // An empty password is an unauthenticated bind, which most servers accept
+        if password.is_empty() {
+            return Err(anyhow!("Invalid username or password"));
+        }
+
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(Duration::from_secs(self.config.timeout_secs))
+            .set_starttls(self.config.starttls)
+            .set_no_tls_verify(self.config.tls_skip_verify);
+
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.config.url)
+            .await
+            .map_err(|e| anyhow!("Failed to connect to directory server: {}", e))?;
+        ldap3::drive!(conn);
+
+        if let Some(bind_dn) = &self.config.bind_dn {
+            ldap.simple_bind(bind_dn, self.config.bind_password.as_deref().unwrap_or(""))
+                .await?
+                .success()
+                .map_err(|e| anyhow!("Directory service account bind failed: {}", e))?;
+        }
+
+        let (entries, _) = ldap
+            .search(
+                &self.config.user_base_dn,
+                Scope::Subtree,
+                &self.config.user_filter_for(username),
+                vec![
+                    self.config.email_attribute.as_str(),
+                    self.config.group_attribute.as_str(),
+                ],
+            )
+            .await?
+            .success()
+            .map_err(|e| anyhow!("Directory user search failed: {}", e))?;
+
+        // Refuse ambiguous filters rather than guessing which entry to bind as
+        let entry = match entries.as_slice() {
+            [entry] => SearchEntry::construct(entry.clone()),
+            _ => {
+                let _ = ldap.unbind().await;
+                return Err(anyhow!("Invalid username or password"));
+            }
+        };
+
+        let bind = ldap.simple_bind(&entry.dn, password).await?.success();
+        let _ = ldap.unbind().await;
+        bind.map_err(|_| anyhow!("Invalid username or password"))?;
+
+        // Servers return attribute names in their own case
+        let attribute = |name: &str| -> Vec<String> {
+            entry
+                .attrs
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, values)| values.clone())
+                .unwrap_or_default()
+        };
+
+        Ok(LdapUserInfo {
+            email: attribute(&self.config.email_attribute).into_iter().next(),
+            groups: attribute(&self.config.group_attribute),
+            dn: entry.dn,
+        })
+    }
+}
@@ -0,0 +1,723 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, net::IpAddr, sync::Arc, time::Instant};
+use tokio::sync::RwLock;
+use totp_rs::{Algorithm, Secret, TOTP};
+use uuid::Uuid;
+use webauthn_rs_proto::{
+    CreationChallengeResponse, RegisterPublicKeyCredential, RequestChallengeResponse,
+};
+
+use crate::server::{
+    auth::{
+        r#impl::{
+            api::{MfaCredentialSummary, MfaStatusResponse, MfaVerifyRequest},
+            mfa::{
+                MfaCredentialType, UserMfaCredential, UserMfaCredentialBase,
+                UserMfaCredentialStorage,
+            },
+            passkeys::{Passkey, PasskeyAuthentication, PasskeyRegistration, Webauthn},
+        },
+        middleware::auth::AuthenticatedEntity,
+    },
+    organizations::service::OrganizationService,
+    shared::{
+        events::{
+            bus::EventBus,
+            types::{AuthEvent, AuthOperation},
+        },
+        services::traits::CrudService,
+    },
+    users::r#impl::base::User,
+};
+
+const MFA_ISSUER: &str = "Scanopy";
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP: u64 = 30;
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+pub struct MfaService {
+    storage: Arc<UserMfaCredentialStorage>,
+    organization_service: Arc<OrganizationService>,
+    event_bus: Arc<EventBus>,
+    webauthn: Option<Webauthn>,
+    verify_attempts: Arc<RwLock<HashMap<Uuid, (u32, Instant)>>>,
+}
+
+impl MfaService {
+    const MAX_VERIFY_ATTEMPTS: u32 = 5;
+    const LOCKOUT_DURATION_SECS: u64 = 15 * 60; // 15 minutes
+
+    pub fn new(
+        storage: Arc<UserMfaCredentialStorage>,
+        organization_service: Arc<OrganizationService>,
+        event_bus: Arc<EventBus>,
+        public_url: &str,
+    ) -> Self {
+        // Passkeys are bound to the host users reach Scanopy on
+        let webauthn = Webauthn::new(public_url, MFA_ISSUER)
+            .inspect_err(|e| {
+                tracing::warn!(
+                    "Passkeys unavailable, could not configure WebAuthn for {}: {}",
+                    public_url,
+                    e
+                )
+            })
+            .ok();
+
+        Self {
+            storage,
+            organization_service,
+            event_bus,
+            webauthn,
+            verify_attempts: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Second factors the user can complete at login. Empty if MFA is not enabled.
+    pub async fn login_methods(&self, user_id: &Uuid) -> Result<Vec<MfaCredentialType>> {
+        let credentials = self.storage.get_for_user(user_id).await?;
+
+        if !has_second_factor(&credentials) {
+            return Ok(vec![]);
+        }
+
+        let mut methods = vec![];
+        for credential_type in [
+            MfaCredentialType::Totp,
+            MfaCredentialType::Passkey,
+            MfaCredentialType::RecoveryCode,
+        ] {
+            if credential_type == MfaCredentialType::Passkey && self.webauthn.is_none() {
+                continue;
+            }
+            if credentials
+                .iter()
+                .any(|c| c.credential_type() == credential_type)
+            {
+                methods.push(credential_type);
+            }
+        }
+
+        Ok(methods)
+    }
+
+    pub async fn status(&self, user: &User) -> Result<MfaStatusResponse> {
+        let credentials = self.storage.get_for_user(&user.id).await?;
+
+        Ok(MfaStatusResponse {
+            enabled: has_second_factor(&credentials),
+            required: self.org_requires_mfa(&user.base.organization_id).await?,
+            passkeys_available: self.webauthn.is_some(),
+            recovery_codes_remaining: credentials
+                .iter()
+                .filter(|c| c.credential_type() == MfaCredentialType::RecoveryCode)
+                .count(),
+            credentials: credentials
+                .into_iter()
+                .filter(|c| c.credential_type() != MfaCredentialType::RecoveryCode)
+                .map(|c| MfaCredentialSummary {
+                    id: c.id,
+                    credential_type: c.base.credential_type,
+                    name: c.base.name,
+                    created_at: c.created_at,
+                    last_used_at: c.base.last_used_at,
+                })
+                .collect(),
+        })
+    }
+
+    async fn org_requires_mfa(&self, organization_id: &Uuid) -> Result<bool> {
+        Ok(self
+            .organization_service
+            .get_by_id(organization_id)
+            .await?
+            .map(|o| o.base.require_mfa)
+            .unwrap_or(false))
+    }
+
+    /// Generate a TOTP secret for the user to scan. Nothing is stored until the
+    /// user proves their authenticator works with `confirm_totp_enrollment`.
+    pub fn begin_totp_enrollment(&self, user: &User) -> Result<TOTP> {
+        let secret = Secret::generate_secret()
+            .to_bytes()
+            .map_err(|e| anyhow!("Failed to generate TOTP secret: {:?}", e))?;
+
+        build_totp(secret, user)
+    }
+
+    pub async fn confirm_totp_enrollment(
+        &self,
+        user: &User,
+        secret: &str,
+        code: &str,
+        name: Option<String>,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<Option<Vec<String>>> {
+        let totp = totp_from_base32(secret, user)?;
+
+        let Some(step) = matched_totp_step(&totp, code, Utc::now().timestamp() as u64) else {
+            return Err(anyhow!(
+                "Invalid code. Check your authenticator app and try again."
+            ));
+        };
+
+        let mut base = UserMfaCredentialBase::new(
+            user.id,
+            MfaCredentialType::Totp,
+            name.unwrap_or_else(|| "Authenticator app".to_string()),
+            secret.to_string(),
+        );
+        base.last_used_at = step_time(step);
+
+        self.enroll(user, UserMfaCredential::new(base), ip, user_agent)
+            .await
+    }
+
+    pub async fn begin_passkey_registration(
+        &self,
+        user: &User,
+    ) -> Result<(CreationChallengeResponse, PasskeyRegistration)> {
+        let webauthn = self.webauthn()?;
+
+        let existing = self
+            .passkeys_for_user(&user.id)
+            .await?
+            .into_iter()
+            .map(|(_, passkey)| passkey.credential_id)
+            .collect();
+
+        Ok(webauthn.start_passkey_registration(user.id, user.base.email.as_str(), existing))
+    }
+
+    pub async fn finish_passkey_registration(
+        &self,
+        user: &User,
+        name: Option<String>,
+        credential: &RegisterPublicKeyCredential,
+        state: &PasskeyRegistration,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<Option<Vec<String>>> {
+        let passkey = self
+            .webauthn()?
+            .finish_passkey_registration(credential, state)
+            .map_err(|e| anyhow!("Passkey registration failed: {}", e))?;
+
+        let base = UserMfaCredentialBase::new(
+            user.id,
+            MfaCredentialType::Passkey,
+            name.unwrap_or_else(|| "Passkey".to_string()),
+            serde_json::to_string(&passkey)?,
+        );
+
+        self.enroll(user, UserMfaCredential::new(base), ip, user_agent)
+            .await
+    }
+
+    /// Store a confirmed factor. Recovery codes are generated alongside the first one.
+    async fn enroll(
+        &self,
+        user: &User,
+        credential: UserMfaCredential,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<Option<Vec<String>>> {
+        let first_factor = !has_second_factor(&self.storage.get_for_user(&user.id).await?);
+
+        self.storage.create(&credential).await?;
+
+        let recovery_codes = if first_factor {
+            Some(self.replace_recovery_codes(&user.id).await?)
+        } else {
+            None
+        };
+
+        self.publish(
+            user,
+            AuthOperation::MfaEnrolled,
+            ip,
+            user_agent,
+            serde_json::json!({
+                "method": credential.credential_type(),
+                "name": credential.base.name,
+            }),
+        )
+        .await?;
+
+        Ok(recovery_codes)
+    }
+
+    pub async fn begin_passkey_authentication(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<(RequestChallengeResponse, PasskeyAuthentication)> {
+        let passkeys: Vec<Passkey> = self
+            .passkeys_for_user(user_id)
+            .await?
+            .into_iter()
+            .map(|(_, passkey)| passkey)
+            .collect();
+
+        if passkeys.is_empty() {
+            return Err(anyhow!("No passkeys registered"));
+        }
+
+        Ok(self.webauthn()?.start_passkey_authentication(&passkeys))
+    }
+
+    /// Check a second factor submitted during login
+    pub async fn verify(
+        &self,
+        user: &User,
+        request: &MfaVerifyRequest,
+        passkey_state: Option<PasskeyAuthentication>,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<()> {
+        let method = match request {
+            MfaVerifyRequest::Totp { .. } => MfaCredentialType::Totp,
+            MfaVerifyRequest::RecoveryCode { .. } => MfaCredentialType::RecoveryCode,
+            MfaVerifyRequest::Passkey { .. } => MfaCredentialType::Passkey,
+        };
+
+        self.check_verify_lockout(&user.id).await?;
+
+        match self.try_verify(user, request, passkey_state).await {
+            Ok(()) => {
+                self.verify_attempts.write().await.remove(&user.id);
+
+                self.publish(
+                    user,
+                    AuthOperation::MfaVerified,
+                    ip,
+                    user_agent,
+                    serde_json::json!({ "method": method }),
+                )
+                .await?;
+
+                Ok(())
+            }
+            Err(e) => {
+                self.publish(
+                    user,
+                    AuthOperation::MfaFailed,
+                    ip,
+                    user_agent,
+                    serde_json::json!({
+                        "method": method,
+                        "reason": e.to_string(),
+                    }),
+                )
+                .await?;
+
+                let mut attempts = self.verify_attempts.write().await;
+                let entry = attempts.entry(user.id).or_insert((0, Instant::now()));
+                entry.0 += 1;
+                entry.1 = Instant::now();
+                Err(e)
+            }
+        }
+    }
+
+    async fn try_verify(
+        &self,
+        user: &User,
+        request: &MfaVerifyRequest,
+        passkey_state: Option<PasskeyAuthentication>,
+    ) -> Result<()> {
+        let credentials = self.storage.get_for_user(&user.id).await?;
+
+        match request {
+            MfaVerifyRequest::Totp { code } => {
+                let now = Utc::now().timestamp() as u64;
+
+                for mut credential in credentials
+                    .into_iter()
+                    .filter(|c| c.credential_type() == MfaCredentialType::Totp)
+                {
+                    let totp = totp_from_base32(&credential.base.data, user)?;
+
+                    if let Some(step) = matched_totp_step(&totp, code, now) {
+                        // Reject a code from a window that was already used
+                        if is_replayed_step(step, credential.base.last_used_at) {
+                            return Err(anyhow!("Code has already been used"));
+                        }
+
+                        credential.base.last_used_at = step_time(step);
+                        self.storage.update(&mut credential).await?;
+                        return Ok(());
+                    }
+                }
+
+                Err(anyhow!("Invalid authentication code"))
+            }
+            MfaVerifyRequest::RecoveryCode { code } => {
+                let hash = hash_recovery_code(code);
+
+                let credential = credentials
+                    .into_iter()
+                    .find(|c| {
+                        c.credential_type() == MfaCredentialType::RecoveryCode
+                            && c.base.data == hash
+                    })
+                    .ok_or_else(|| anyhow!("Invalid recovery code"))?;
+
+                // Recovery codes are single use
+                self.storage.delete(&credential.id).await
+            }
+            MfaVerifyRequest::Passkey { credential } => {
+                let state =
+                    passkey_state.ok_or_else(|| anyhow!("No passkey challenge in progress"))?;
+
+                let (mut record, mut passkey) = self
+                    .passkeys_for_user(&user.id)
+                    .await?
+                    .into_iter()
+                    .find(|(_, passkey)| passkey.credential_id == credential.raw_id)
+                    .ok_or_else(|| anyhow!("Passkey is not registered to this account"))?;
+
+                // Persist the authenticator's signature counter
+                passkey.counter = self
+                    .webauthn()?
+                    .finish_passkey_authentication(credential, &state, &passkey)
+                    .map_err(|e| anyhow!("Passkey verification failed: {}", e))?;
+                record.base.data = serde_json::to_string(&passkey)?;
+                record.base.last_used_at = Some(Utc::now());
+                self.storage.update(&mut record).await?;
+                Ok(())
+            }
+        }
+    }
+
+    pub async fn remove_credential(
+        &self,
+        user: &User,
+        credential_id: &Uuid,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<()> {
+        let credentials = self.storage.get_for_user(&user.id).await?;
+
+        let credential = credentials
+            .iter()
+            .find(|c| {
+                c.id == *credential_id && c.credential_type() != MfaCredentialType::RecoveryCode
+            })
+            .ok_or_else(|| anyhow!("Credential not found"))?;
+
+        let remaining_factors = credentials
+            .iter()
+            .filter(|c| {
+                c.id != credential.id && c.credential_type() != MfaCredentialType::RecoveryCode
+            })
+            .count();
+
+        if remaining_factors == 0 {
+            if self.org_requires_mfa(&user.base.organization_id).await? {
+                return Err(anyhow!(
+                    "Your organization requires multi-factor authentication. Add another factor before removing this one."
+                ));
+            }
+
+            // Recovery codes are meaningless without a factor to recover
+            self.storage
+                .delete_for_user_by_type(&user.id, MfaCredentialType::RecoveryCode)
+                .await?;
+        }
+
+        self.storage.delete(&credential.id).await?;
+
+        self.publish(
+            user,
+            AuthOperation::MfaRemoved,
+            ip,
+            user_agent,
+            serde_json::json!({
+                "method": credential.credential_type(),
+                "name": credential.base.name,
+            }),
+        )
+        .await
+    }
+
+    pub async fn regenerate_recovery_codes(
+        &self,
+        user: &User,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<Vec<String>> {
+        if !has_second_factor(&self.storage.get_for_user(&user.id).await?) {
+            return Err(anyhow!(
+                "Enable an authenticator app or passkey before generating recovery codes"
+            ));
+        }
+
+        let codes = self.replace_recovery_codes(&user.id).await?;
+
+        self.publish(
+            user,
+            AuthOperation::MfaEnrolled,
+            ip,
+            user_agent,
+            serde_json::json!({
+                "method": MfaCredentialType::RecoveryCode,
+                "regenerated": true,
+            }),
+        )
+        .await?;
+
+        Ok(codes)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: &Uuid) -> Result<Vec<String>> {
+        self.storage
+            .delete_for_user_by_type(user_id, MfaCredentialType::RecoveryCode)
+            .await?;
+
+        let codes = generate_recovery_codes();
+        for code in &codes {
+            let credential = UserMfaCredential::new(UserMfaCredentialBase::new(
+                *user_id,
+                MfaCredentialType::RecoveryCode,
+                String::new(),
+                hash_recovery_code(code),
+            ));
+            self.storage.create(&credential).await?;
+        }
+
+        Ok(codes)
+    }
+
+    async fn passkeys_for_user(&self, user_id: &Uuid) -> Result<Vec<(UserMfaCredential, Passkey)>> {
+        self.storage
+            .get_for_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|c| c.credential_type() == MfaCredentialType::Passkey)
+            .map(|c| {
+                let passkey: Passkey = serde_json::from_str(&c.base.data)
+                    .map_err(|e| anyhow!("Failed to deserialize passkey: {}", e))?;
+                Ok((c, passkey))
+            })
+            .collect()
+    }
+
+    fn webauthn(&self) -> Result<&Webauthn> {
+        self.webauthn
+            .as_ref()
+            .ok_or_else(|| anyhow!("Passkeys are not available on this server"))
+    }
+
+    async fn check_verify_lockout(&self, user_id: &Uuid) -> Result<()> {
+        let attempts = self.verify_attempts.read().await;
+        if let Some((count, last_attempt)) = attempts.get(user_id)
+            && *count >= Self::MAX_VERIFY_ATTEMPTS
+        {
+            let elapsed = last_attempt.elapsed().as_secs();
+            if elapsed < Self::LOCKOUT_DURATION_SECS {
+                let remaining = (Self::LOCKOUT_DURATION_SECS - elapsed) / 60;
+                return Err(anyhow!(
+                    "Too many failed verification attempts. Try again in {} minutes.",
+                    remaining + 1
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Cleanup old verification attempts (called periodically from background task)
+    pub async fn cleanup_old_verify_attempts(&self) {
+        let mut attempts = self.verify_attempts.write().await;
+
+        attempts.retain(|_, (_, last_attempt)| {
+            last_attempt.elapsed().as_secs() < Self::LOCKOUT_DURATION_SECS
+        });
+    }
+
+    async fn publish(
+        &self,
+        user: &User,
+        operation: AuthOperation,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        metadata: serde_json::Value,
+    ) -> Result<()> {
+        let authentication: AuthenticatedEntity = user.clone().into();
+
+        self.event_bus
+            .publish_auth(AuthEvent {
+                id: Uuid::new_v4(),
+                user_id: Some(user.id),
+                organization_id: Some(user.base.organization_id),
+                timestamp: Utc::now(),
+                operation,
+                ip_address: ip,
+                user_agent,
+                metadata,
+                authentication,
+            })
+            .await
+    }
+}
+
+fn build_totp(secret: Vec<u8>, user: &User) -> Result<TOTP> {
+    TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        0,
+        TOTP_STEP,
+        secret,
+        Some(MFA_ISSUER.to_string()),
+        user.base.email.to_string(),
+    )
+    .map_err(|e| anyhow!("Invalid TOTP configuration: {}", e))
+}
+
+fn totp_from_base32(secret: &str, user: &User) -> Result<TOTP> {
+    let bytes = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| anyhow!("Invalid TOTP secret: {:?}", e))?;
+
+    build_totp(bytes, user)
+}
+
+/// Whether any credential is an actual second factor (recovery codes don't count)
+fn has_second_factor(credentials: &[UserMfaCredential]) -> bool {
+    credentials
+        .iter()
+        .any(|c| c.credential_type() != MfaCredentialType::RecoveryCode)
+}
+
+/// Find the time step a code belongs to, allowing one step of clock drift either way
+fn matched_totp_step(totp: &TOTP, code: &str, now: u64) -> Option<u64> {
+    let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+    let current = now / TOTP_STEP;
+
+    [current.saturating_sub(1), current, current + 1]
+        .into_iter()
+        .find(|step| totp.check(&code, step * TOTP_STEP))
+}
+
+/// `last_used_at` stores the start of the last accepted step, so a code is only
+/// accepted once and never from an earlier window
+fn is_replayed_step(step: u64, last_used_at: Option<DateTime<Utc>>) -> bool {
+    last_used_at.is_some_and(|t| step <= t.timestamp() as u64 / TOTP_STEP)
+}
+
+fn step_time(step: u64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp((step * TOTP_STEP) as i64, 0)
+}
+
+/// Generate single-use recovery codes in `xxxxx-xxxxx` form
+fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = rand::rng();
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let chars: String = (0..10)
+                .map(|_| {
+                    RECOVERY_CODE_ALPHABET[rng.random_range(0..RECOVERY_CODE_ALPHABET.len())]
+                        as char
+                })
+                .collect();
+            format!("{}-{}", &chars[..5], &chars[5..])
+        })
+        .collect()
+}
+
+/// Hash a recovery code using SHA-256, ignoring case, spaces and dashes
+fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    let mut hasher = Sha256::new();
+    hasher.update(normalized.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_totp() -> TOTP {
+        TOTP::new(
+            Algorithm::SHA1,
+            TOTP_DIGITS,
+            0,
+            TOTP_STEP,
+            b"12345678901234567890".to_vec(),
+            Some(MFA_ISSUER.to_string()),
+            "user@example.com".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_totp_step_allows_one_step_drift() {
+        let totp = test_totp();
+        let now = 1_700_000_000;
+        let current = now / TOTP_STEP;
+
+        let previous = totp.generate((current - 1) * TOTP_STEP);
+        assert_eq!(matched_totp_step(&totp, &previous, now), Some(current - 1));
+
+        let next = totp.generate((current + 1) * TOTP_STEP);
+        assert_eq!(matched_totp_step(&totp, &next, now), Some(current + 1));
+
+        let stale = totp.generate((current - 2) * TOTP_STEP);
+        assert_eq!(matched_totp_step(&totp, &stale, now), None);
+    }
+
+    #[test]
+    fn test_totp_step_ignores_whitespace() {
+        let totp = test_totp();
+        let now = 1_700_000_000;
+        let code = totp.generate(now);
+        let spaced = format!("{} {}", &code[..3], &code[3..]);
+
+        assert_eq!(
+            matched_totp_step(&totp, &spaced, now),
+            Some(now / TOTP_STEP)
+        );
+    }
+
+    #[test]
+    fn test_replayed_step_rejected() {
+        let step = 1_700_000_000 / TOTP_STEP;
+
+        assert!(!is_replayed_step(step, None));
+        assert!(is_replayed_step(step, step_time(step)));
+        assert!(is_replayed_step(step - 1, step_time(step)));
+        assert!(!is_replayed_step(step + 1, step_time(step)));
+    }
+
+    #[test]
+    fn test_recovery_codes_are_unique_and_formatted() {
+        let codes = generate_recovery_codes();
+
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        for code in &codes {
+            assert_eq!(code.len(), 11);
+            assert_eq!(&code[5..6], "-");
+        }
+
+        let unique: std::collections::HashSet<_> = codes.iter().collect();
+        assert_eq!(unique.len(), codes.len());
+    }
+
+    #[test]
+    fn test_recovery_code_hash_normalizes_input() {
+        let hash = hash_recovery_code("abcde-fghjk");
+
+        assert_eq!(hash, hash_recovery_code("ABCDE FGHJK"));
+        assert_eq!(hash, hash_recovery_code("abcdefghjk"));
+        assert_ne!(hash, hash_recovery_code("abcde-fghjm"));
+    }
+}
@@ -2,6 +2,7 @@ use std::{fmt::Display, net::IpAddr};
 
 use crate::daemon::runtime::service::{INVALID_API_KEY_ERROR, REGISTERED_INVALID_KEY_ERROR};
 use crate::server::{
+    auth::r#impl::sessions::SESSION_RECORD_KEY,
     config::AppState,
     shared::{
         api_key_common::{ApiKeyCommon, ApiKeyType, check_key_validity, hash_api_key},
@@ -10,6 +11,7 @@ use crate::server::{
         storage::filter::EntityFilter,
         types::api::ApiError,
     },
+    user_api_keys::r#impl::scopes::ApiKeyScope,
     users::r#impl::{base::User, permissions::UserOrgPermissions},
 };
 use axum::{
@@ -83,6 +85,12 @@ pub enum AuthenticatedEntity {
         organization_id: Uuid,
         permissions: UserOrgPermissions,
         network_ids: Vec<Uuid>,
+        /// `resource:action` grants; empty means the permission level applies everywhere
+        #[serde(default)]
+        scopes: Vec<ApiKeyScope>,
+        /// Per-key requests per minute, checked by the rate limiter
+        #[serde(default)]
+        rate_limit_per_minute: Option<u32>,
     },
     System,
     Anonymous,
@@ -308,6 +316,8 @@ impl AuthenticatedEntity {
                         let user_id = user_api_key.base.user_id;
                         let organization_id = user_api_key.base.organization_id;
                         let permissions = user_api_key.base.permissions;
+                        let scopes = user_api_key.base.scopes.clone();
+                        let rate_limit_per_minute = user_api_key.base.rate_limit_per_minute;
                         let service = app_state.services.user_api_key_service.clone();
 
                         // Check validity using shared trait
@@ -339,6 +349,21 @@ impl AuthenticatedEntity {
                             return Err(AuthError(e));
                         }
 
+                        if !user_api_key.allows_ip(&ip) {
+                            publish_api_key_auth_failed(
+                                app_state,
+                                ip,
+                                user_agent.clone(),
+                                key_type,
+                                "ip_not_allowed",
+                                key_prefix,
+                            )
+                            .await;
+                            return Err(AuthError(ApiError::forbidden(
+                                "API key is not allowed from this IP address",
+                            )));
+                        }
+
                         let organization_has_api_access = app_state
                             .services
                             .organization_service
@@ -381,6 +406,8 @@ impl AuthenticatedEntity {
                             organization_id,
                             permissions,
                             network_ids,
+                            scopes,
+                            rate_limit_per_minute,
                         });
                     }
 
@@ -397,6 +424,18 @@ impl AuthenticatedEntity {
                         "Invalid API key".to_string(),
                     )));
                 }
+                ApiKeyType::Scim => {
+                    // SCIM tokens are only accepted by the SCIM endpoints' own extractor
+                    return Err(AuthError(ApiError::unauthorized(
+                        "SCIM tokens can only be used with the SCIM API".to_string(),
+                    )));
+                }
+                ApiKeyType::Share => {
+                    // Share tokens only open the share they were created for
+                    return Err(AuthError(ApiError::unauthorized(
+                        "Share tokens can only be used to view shares".to_string(),
+                    )));
+                }
                 ApiKeyType::Daemon => {
                     // Daemon API key authentication - requires X-Daemon-ID header
                     let daemon_id = parts
@@ -521,6 +560,44 @@ impl AuthenticatedEntity {
             .map_err(|_| AuthError(ApiError::unauthorized("User not found".to_string())))?
             .ok_or_else(|| AuthError(ApiError::unauthorized("User not found".to_string())))?;
 
+        if user.is_deactivated() {
+            return Err(AuthError(ApiError::unauthorized(
+                "This account has been deactivated".to_string(),
+            )));
+        }
+
+        // Every signed-in session has a record; revoked or expired ones are signed out
+        let session_record = match session.get::<Uuid>(SESSION_RECORD_KEY).await.ok().flatten() {
+            Some(session_record_id) => app_state
+                .services
+                .session_service
+                .validate(&session_record_id, &user, ip, user_agent)
+                .await
+                .map_err(|_| AuthError(ApiError::internal_error("Failed to load session")))?,
+            // Sessions signed in before records existed get one on their next request, rather
+            // than everyone being signed out on upgrade
+            None => {
+                let record = app_state
+                    .services
+                    .session_service
+                    .create(user.id, ip, user_agent)
+                    .await
+                    .map_err(|_| AuthError(ApiError::internal_error("Failed to save session")))?;
+                session
+                    .insert(SESSION_RECORD_KEY, record.id)
+                    .await
+                    .map_err(|_| AuthError(ApiError::internal_error("Failed to save session")))?;
+                Some(record)
+            }
+        };
+
+        if session_record.is_none() {
+            let _ = session.flush().await;
+            return Err(AuthError(ApiError::unauthorized(
+                "Session expired".to_string(),
+            )));
+        }
+
         let network_ids: Vec<Uuid> = if matches!(
             user.base.permissions,
             UserOrgPermissions::Owner | UserOrgPermissions::Admin
@@ -576,6 +653,8 @@ async fn publish_api_key_auth_failed(
     let key_type_str = match key_type {
         ApiKeyType::User => "user",
         ApiKeyType::Daemon => "daemon",
+        ApiKeyType::Scim => "scim",
+        ApiKeyType::Share => "share",
     };
 
     let metadata = serde_json::json!({
@@ -613,6 +692,10 @@ pub struct AuthenticatedApiKey {
     pub organization_id: Uuid,
     pub permissions: UserOrgPermissions,
     pub network_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub scopes: Vec<ApiKeyScope>,
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<u32>,
 }
 
 impl From<AuthenticatedApiKey> for AuthenticatedEntity {
@@ -623,6 +706,8 @@ impl From<AuthenticatedApiKey> for AuthenticatedEntity {
             organization_id: value.organization_id,
             permissions: value.permissions,
             network_ids: value.network_ids,
+            scopes: value.scopes,
+            rate_limit_per_minute: value.rate_limit_per_minute,
         }
     }
 }
@@ -643,12 +728,16 @@ where
                 organization_id,
                 permissions,
                 network_ids,
+                scopes,
+                rate_limit_per_minute,
             } => Ok(AuthenticatedApiKey {
                 api_key_id,
                 user_id,
                 organization_id,
                 permissions,
                 network_ids,
+                scopes,
+                rate_limit_per_minute,
             }),
             _ => Err(AuthError(ApiError::unauthorized(
                 "API key authentication required".to_string(),
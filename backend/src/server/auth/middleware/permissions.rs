@@ -9,12 +9,19 @@
 //! - Permission levels: `Viewer`, `Member`, `Admin`, `Owner` - check User/ApiKey permission levels
 //! - Auth type requirements: `IsDaemon`, `IsUser`, `IsApiKey` - check authentication type
 //! - Combinators: `Or<A, B>` - compose requirements
+//! - API key scopes: `Scope<R, A>` - check a scoped user API key grants action `A` on resource `R`
+//!
+//! Scoped API keys are rejected by any endpoint whose requirement declares no scope,
+//! so new endpoints stay closed to them until they opt in.
 use std::marker::PhantomData;
 
 use crate::server::auth::middleware::auth::AuthError;
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
 use crate::server::{
-    config::AppState, shared::types::api::ApiError, users::r#impl::permissions::UserOrgPermissions,
+    config::AppState,
+    shared::types::api::ApiError,
+    user_api_keys::r#impl::scopes::{ApiKeyScope, ScopeAction, ScopedResource, scopes_allow},
+    users::r#impl::permissions::UserOrgPermissions,
 };
 use axum::{extract::FromRequestParts, http::request::Parts};
 use uuid::Uuid;
@@ -35,6 +42,11 @@ pub trait PermissionRequirement: Send + Sync + 'static {
 
     /// Human-readable description of this requirement for error messages.
     fn description() -> &'static str;
+
+    /// The API key scope this requirement checks, if any.
+    fn scope() -> Option<ApiKeyScope> {
+        None
+    }
 }
 
 // ============================================================================
@@ -256,6 +268,10 @@ where
     fn description() -> &'static str {
         "Insufficient permissions"
     }
+
+    fn scope() -> Option<ApiKeyScope> {
+        A::scope().or_else(B::scope)
+    }
 }
 
 /// Requires both A and B to pass.
@@ -277,6 +293,87 @@ where
     fn description() -> &'static str {
         "Insufficient permissions"
     }
+
+    fn scope() -> Option<ApiKeyScope> {
+        A::scope().or_else(B::scope)
+    }
+}
+
+// ============================================================================
+// API Key Scopes
+// ============================================================================
+
+/// An action a scope can grant, used as the second parameter of `Scope<R, A>`.
+pub trait ScopedAction: Send + Sync + 'static {
+    const SCOPE_ACTION: ScopeAction;
+}
+
+/// Marker for `<resource>:read`
+pub struct Read;
+/// Marker for `<resource>:write`
+pub struct Write;
+/// Marker for `<resource>:delete`
+pub struct Delete;
+/// Marker for `<resource>:trigger`
+pub struct Trigger;
+
+impl ScopedAction for Read {
+    const SCOPE_ACTION: ScopeAction = ScopeAction::Read;
+}
+
+impl ScopedAction for Write {
+    const SCOPE_ACTION: ScopeAction = ScopeAction::Write;
+}
+
+impl ScopedAction for Delete {
+    const SCOPE_ACTION: ScopeAction = ScopeAction::Delete;
+}
+
+impl ScopedAction for Trigger {
+    const SCOPE_ACTION: ScopeAction = ScopeAction::Trigger;
+}
+
+/// Requires a scoped user API key to grant action `A` on resource `R`.
+///
+/// Combine with a permission level, e.g. `And<Member, Scope<Host, Write>>`.
+///
+/// Passes for: ApiKey whose scopes include the action (or that has no scopes), User, Daemon, System
+/// Fails for: ApiKey with scopes that don't include the action
+pub struct Scope<R, A>(PhantomData<(R, A)>);
+
+impl<R: ScopedResource, A: ScopedAction> Scope<R, A> {
+    /// The scope as documented in the OpenAPI spec, e.g. `hosts:read`
+    pub fn name() -> String {
+        ApiKeyScope::new(R::SCOPE_RESOURCE, A::SCOPE_ACTION).to_string()
+    }
+}
+
+impl<R, A> PermissionRequirement for Scope<R, A>
+where
+    R: ScopedResource + Send + Sync + 'static,
+    A: ScopedAction,
+{
+    fn check(entity: &AuthenticatedEntity) -> Result<(), ApiError> {
+        match entity {
+            AuthenticatedEntity::ApiKey { scopes, .. }
+                if !scopes_allow(scopes, R::SCOPE_RESOURCE, A::SCOPE_ACTION) =>
+            {
+                Err(ApiError::forbidden(&format!(
+                    "API key is missing the '{}' scope",
+                    ApiKeyScope::new(R::SCOPE_RESOURCE, A::SCOPE_ACTION)
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn description() -> &'static str {
+        "API key scope required"
+    }
+
+    fn scope() -> Option<ApiKeyScope> {
+        Some(ApiKeyScope::new(R::SCOPE_RESOURCE, A::SCOPE_ACTION))
+    }
 }
 
 // ============================================================================
@@ -365,6 +462,16 @@ where
         // Check the permission requirement
         P::check(&entity).map_err(AuthError)?;
 
+        // Scoped API keys only reach endpoints that declare the scope they need
+        if let AuthenticatedEntity::ApiKey { scopes, .. } = &entity
+            && !scopes.is_empty()
+            && P::scope().is_none()
+        {
+            return Err(AuthError(ApiError::forbidden(
+                "This endpoint is not available to scoped API keys",
+            )));
+        }
+
         Ok(Authorized {
             entity,
             _marker: PhantomData,
@@ -1,8 +1,14 @@
 use crate::server::config::AppState;
 #[cfg(not(feature = "generate-fixtures"))]
-use crate::server::{auth::middleware::auth::AuthenticatedEntity, shared::types::api::ApiError};
+use crate::server::{
+    auth::middleware::{auth::AuthenticatedEntity, cache::CachedOrganization},
+    shared::{
+        events::types::{AuthEvent, AuthOperation},
+        types::api::ApiError,
+    },
+};
 #[cfg(not(feature = "generate-fixtures"))]
-use axum::{extract::FromRequestParts, response::IntoResponse};
+use axum::{extract::FromRequestParts, http::request::Parts, response::IntoResponse};
 use axum::{
     extract::{Request, State},
     middleware::Next,
@@ -10,22 +16,147 @@ use axum::{
 };
 use axum_client_ip::ClientIp;
 #[cfg(not(feature = "generate-fixtures"))]
+use chrono::Utc;
+#[cfg(not(feature = "generate-fixtures"))]
 use governor::{
     Quota, RateLimiter,
     clock::{Clock, DefaultClock},
     state::keyed::DashMapStateStore,
 };
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
 #[cfg(not(feature = "generate-fixtures"))]
-use std::{net::IpAddr, num::NonZeroU32, sync::OnceLock, time::Duration};
+use sqlx::PgPool;
+use std::{collections::HashMap, sync::Arc};
+#[cfg(not(feature = "generate-fixtures"))]
+use std::{
+    fmt::Display,
+    net::IpAddr,
+    num::NonZeroU32,
+    sync::{Mutex, Once, OnceLock, RwLock},
+    time::{Duration, Instant},
+};
 #[cfg(not(feature = "generate-fixtures"))]
 use uuid::Uuid;
 
+/// A per-minute request budget
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RateLimitQuota {
+    /// Sustained requests per minute. 0 disables the limit.
+    pub per_minute: u32,
+    /// Requests allowed in a burst before the per-minute rate applies. Defaults
+    /// to `per_minute`. Only used by the in-memory store.
+    #[serde(default)]
+    pub burst: Option<u32>,
+}
+
+impl RateLimitQuota {
+    pub const fn new(per_minute: u32, burst: u32) -> Self {
+        Self {
+            per_minute,
+            burst: Some(burst),
+        }
+    }
+
+    pub const fn per_minute(per_minute: u32) -> Self {
+        Self {
+            per_minute,
+            burst: None,
+        }
+    }
+}
+
+/// Where rate limit counters are kept
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitStore {
+    /// Process-local token buckets. Each replica enforces its own budget.
+    #[default]
+    Memory,
+    /// Fixed one-minute windows in Postgres, shared by every replica
+    Postgres,
+}
+
+/// Overrides applied to organizations on a given billing plan
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlanRateLimits {
+    pub user: Option<RateLimitQuota>,
+    pub organization: Option<RateLimitQuota>,
+}
+
+/// Rate limiting settings, loaded from the `[rate_limits]` table of `rate_limits.toml`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    pub store: RateLimitStore,
+    /// Budget for each authenticated user, shared with that user's API keys
+    pub user: RateLimitQuota,
+    /// Budget for each client IP making unauthenticated requests
+    pub anonymous: RateLimitQuota,
+    /// Budget shared by every user and API key of an organization. Unlimited if unset.
+    pub organization: Option<RateLimitQuota>,
+    /// Overrides keyed by plan type, e.g. `Pro` or `Enterprise`
+    pub plans: HashMap<String, PlanRateLimits>,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            store: RateLimitStore::Memory,
+            user: RateLimitQuota::new(300, 150),
+            anonymous: RateLimitQuota::new(20, 5),
+            organization: None,
+            plans: HashMap::new(),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    pub fn user_quota(&self, plan: Option<&str>) -> RateLimitQuota {
+        plan.and_then(|p| self.plans.get(p))
+            .and_then(|p| p.user)
+            .unwrap_or(self.user)
+    }
+
+    pub fn organization_quota(&self, plan: Option<&str>) -> Option<RateLimitQuota> {
+        plan.and_then(|p| self.plans.get(p))
+            .and_then(|p| p.organization)
+            .or(self.organization)
+    }
+}
+
 #[cfg(not(feature = "generate-fixtures"))]
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub enum RateLimitKey {
     User(Uuid),
     Ip(IpAddr),
+    ApiKey(Uuid),
+    Organization(Uuid),
+}
+
+#[cfg(not(feature = "generate-fixtures"))]
+impl Display for RateLimitKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitKey::User(id) => write!(f, "user:{}", id),
+            RateLimitKey::Ip(ip) => write!(f, "ip:{}", ip),
+            RateLimitKey::ApiKey(id) => write!(f, "api_key:{}", id),
+            RateLimitKey::Organization(id) => write!(f, "organization:{}", id),
+        }
+    }
+}
+
+/// Which budget a request was checked against, for rejection metrics and audit events
+#[cfg(not(feature = "generate-fixtures"))]
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+enum RateLimitScope {
+    Anonymous,
+    User,
+    ApiKey,
+    Organization,
 }
 
 #[cfg(not(feature = "generate-fixtures"))]
@@ -33,45 +164,72 @@ type KeyedRateLimiter =
     Arc<RateLimiter<RateLimitKey, DashMapStateStore<RateLimitKey>, DefaultClock>>;
 
 #[cfg(not(feature = "generate-fixtures"))]
+#[derive(Default)]
 struct RateLimiters {
-    user: KeyedRateLimiter,
-    anonymous: KeyedRateLimiter,
+    /// One keyed limiter per distinct quota
+    limiters: RwLock<HashMap<RateLimitQuota, KeyedRateLimiter>>,
+    /// When each key last produced an audit event, so a client stuck over its
+    /// limit logs one event per minute rather than one per request
+    last_audited: Mutex<HashMap<RateLimitKey, Instant>>,
+    /// Rejections since the last cleanup tick
+    rejections: Mutex<HashMap<RateLimitScope, u64>>,
 }
 
 #[cfg(not(feature = "generate-fixtures"))]
-static RATE_LIMITERS: OnceLock<RateLimiters> = OnceLock::new();
+static RATE_LIMITERS: OnceLock<Arc<RateLimiters>> = OnceLock::new();
+
+#[cfg(not(feature = "generate-fixtures"))]
+static SHARED_CLEANUP: Once = Once::new();
+
+#[cfg(not(feature = "generate-fixtures"))]
+const AUDIT_INTERVAL: Duration = Duration::from_secs(60);
 
 #[cfg(not(feature = "generate-fixtures"))]
-fn get_limiters() -> &'static RateLimiters {
+fn get_limiters() -> &'static Arc<RateLimiters> {
     RATE_LIMITERS.get_or_init(|| {
-        let limiters = RateLimiters {
-            // Authenticated users and API keys: 300 requests per minute with burst of 150
-            user: Arc::new(RateLimiter::keyed(
-                Quota::per_minute(NonZeroU32::new(300).unwrap())
-                    .allow_burst(NonZeroU32::new(150).unwrap()),
-            )),
-            // Anonymous/unauthenticated: 20 requests per minute with burst of 5
-            anonymous: Arc::new(RateLimiter::keyed(
-                Quota::per_minute(NonZeroU32::new(20).unwrap())
-                    .allow_burst(NonZeroU32::new(5).unwrap()),
-            )),
-        };
+        let limiters = Arc::new(RateLimiters::default());
 
         // Spawn cleanup task
-        let user_limiter = Arc::clone(&limiters.user);
-        let anonymous_limiter = Arc::clone(&limiters.anonymous);
+        let cleanup = Arc::clone(&limiters);
 
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(60));
             loop {
                 interval.tick().await;
-                user_limiter.retain_recent();
-                anonymous_limiter.retain_recent();
-                tracing::debug!(
-                    "Rate limiter cleanup: user keys={}, anonymous keys={}",
-                    user_limiter.len(),
-                    anonymous_limiter.len()
-                );
+
+                let tracked_keys: usize = cleanup
+                    .limiters
+                    .read()
+                    .map(|limiters| {
+                        limiters
+                            .values()
+                            .map(|limiter| {
+                                limiter.retain_recent();
+                                limiter.len()
+                            })
+                            .sum()
+                    })
+                    .unwrap_or_default();
+
+                if let Ok(mut last_audited) = cleanup.last_audited.lock() {
+                    last_audited.retain(|_, at| at.elapsed() < AUDIT_INTERVAL);
+                }
+
+                let rejections = cleanup
+                    .rejections
+                    .lock()
+                    .map(|mut r| std::mem::take(&mut *r))
+                    .unwrap_or_default();
+
+                if !rejections.is_empty() {
+                    tracing::info!(
+                        rejected = rejections.values().sum::<u64>(),
+                        rejected_by_scope = ?rejections,
+                        "Rate limit rejections in the last minute"
+                    );
+                }
+
+                tracing::debug!("Rate limiter cleanup: tracked keys={}", tracked_keys);
             }
         });
 
@@ -117,17 +275,56 @@ impl RateLimitInfo {
 
         response
     }
+
+    /// Keep whichever budget is closer to running out, so headers reflect the
+    /// limit the client will hit first
+    fn tightest(current: Option<Self>, next: Self) -> Self {
+        match current {
+            Some(current) if current.remaining <= next.remaining => current,
+            _ => next,
+        }
+    }
 }
 
 #[cfg(not(feature = "generate-fixtures"))]
-fn check_user(user_id: Uuid) -> Result<RateLimitInfo, RateLimitInfo> {
+fn limiter_for(quota: RateLimitQuota, per_minute: NonZeroU32) -> KeyedRateLimiter {
     let limiters = get_limiters();
-    let key = RateLimitKey::User(user_id);
 
-    match limiters.user.check_key(&key) {
+    if let Some(limiter) = limiters
+        .limiters
+        .read()
+        .ok()
+        .and_then(|l| l.get(&quota).cloned())
+    {
+        return limiter;
+    }
+
+    let mut governor_quota = Quota::per_minute(per_minute);
+    if let Some(burst) = quota.burst.and_then(NonZeroU32::new) {
+        governor_quota = governor_quota.allow_burst(burst);
+    }
+
+    let mut keyed = limiters
+        .limiters
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    Arc::clone(
+        keyed
+            .entry(quota)
+            .or_insert_with(|| Arc::new(RateLimiter::keyed(governor_quota))),
+    )
+}
+
+#[cfg(not(feature = "generate-fixtures"))]
+fn check_memory(
+    key: &RateLimitKey,
+    quota: RateLimitQuota,
+    per_minute: NonZeroU32,
+) -> Result<RateLimitInfo, RateLimitInfo> {
+    match limiter_for(quota, per_minute).check_key(key) {
         Ok(_) => Ok(RateLimitInfo {
-            limit: 100,
-            remaining: 99,
+            limit: per_minute.get(),
+            remaining: per_minute.get() - 1,
             reset_in_secs: 60,
         }),
         Err(not_until) => {
@@ -135,7 +332,7 @@ fn check_user(user_id: Uuid) -> Result<RateLimitInfo, RateLimitInfo> {
                 .wait_time_from(DefaultClock::default().now())
                 .as_secs();
             Err(RateLimitInfo {
-                limit: 100,
+                limit: per_minute.get(),
                 remaining: 0,
                 reset_in_secs: wait_time,
             })
@@ -143,28 +340,199 @@ fn check_user(user_id: Uuid) -> Result<RateLimitInfo, RateLimitInfo> {
     }
 }
 
+/// Count the request in the current one-minute window shared by all replicas
 #[cfg(not(feature = "generate-fixtures"))]
-fn check_anonymous(ip: IpAddr) -> Result<RateLimitInfo, RateLimitInfo> {
+async fn check_postgres(
+    pool: &PgPool,
+    key: &RateLimitKey,
+    per_minute: NonZeroU32,
+) -> Result<Result<RateLimitInfo, RateLimitInfo>, sqlx::Error> {
+    SHARED_CLEANUP.call_once(|| {
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                if let Err(e) = sqlx::query(
+                    "DELETE FROM rate_limit_buckets WHERE window_start < now() - interval '2 minutes'",
+                )
+                .execute(&pool)
+                .await
+                {
+                    tracing::warn!(error = %e, "Failed to clean up rate limit buckets");
+                }
+            }
+        });
+    });
+
+    let (count, reset_in_secs): (i32, i64) = sqlx::query_as(
+        "INSERT INTO rate_limit_buckets (key, window_start, count)
+         VALUES ($1, date_trunc('minute', now()), 1)
+         ON CONFLICT (key, window_start)
+         DO UPDATE SET count = rate_limit_buckets.count + 1
+         RETURNING count,
+            CEIL(EXTRACT(EPOCH FROM (window_start + interval '1 minute' - now())))::BIGINT",
+    )
+    .bind(key.to_string())
+    .fetch_one(pool)
+    .await?;
+
+    let limit = per_minute.get();
+    let count = u32::try_from(count).unwrap_or(u32::MAX);
+    let info = RateLimitInfo {
+        limit,
+        remaining: limit.saturating_sub(count),
+        reset_in_secs: u64::try_from(reset_in_secs).unwrap_or_default(),
+    };
+
+    Ok(if count > limit { Err(info) } else { Ok(info) })
+}
+
+#[cfg(not(feature = "generate-fixtures"))]
+async fn check(
+    state: &AppState,
+    key: &RateLimitKey,
+    quota: RateLimitQuota,
+) -> Result<Option<RateLimitInfo>, RateLimitInfo> {
+    let Some(per_minute) = NonZeroU32::new(quota.per_minute) else {
+        return Ok(None);
+    };
+
+    match state.config.rate_limits.store {
+        RateLimitStore::Memory => check_memory(key, quota, per_minute).map(Some),
+        RateLimitStore::Postgres => {
+            match check_postgres(&state.storage.pool, key, per_minute).await {
+                Ok(result) => result.map(Some),
+                Err(e) => {
+                    // Don't take the API down with the database; fall back to this replica's budget
+                    tracing::warn!(error = %e, "Shared rate limit check failed, using in-memory limiter");
+                    check_memory(key, quota, per_minute).map(Some)
+                }
+            }
+        }
+    }
+}
+
+/// Budgets a request is counted against, broadest first. Each check spends a token, so a
+/// request an organization or owner budget rejects never spends one from the caller's own.
+#[cfg(not(feature = "generate-fixtures"))]
+fn budgets(
+    config: &RateLimitConfig,
+    entity: Option<&AuthenticatedEntity>,
+    ip: IpAddr,
+    plan: Option<&str>,
+) -> Vec<(RateLimitScope, RateLimitKey, RateLimitQuota)> {
+    let mut budgets = Vec::new();
+
+    if let Some(organization_id) = entity.and_then(|e| e.organization_id())
+        && let Some(quota) = config.organization_quota(plan)
+    {
+        budgets.push((
+            RateLimitScope::Organization,
+            RateLimitKey::Organization(organization_id),
+            quota,
+        ));
+    }
+
+    match entity {
+        Some(AuthenticatedEntity::User { user_id, .. }) => budgets.push((
+            RateLimitScope::User,
+            RateLimitKey::User(*user_id),
+            config.user_quota(plan),
+        )),
+        Some(AuthenticatedEntity::ApiKey {
+            user_id,
+            api_key_id,
+            rate_limit_per_minute,
+            ..
+        }) => {
+            // Keys with their own limit still count against their owner's limit
+            budgets.push((
+                RateLimitScope::User,
+                RateLimitKey::User(*user_id),
+                config.user_quota(plan),
+            ));
+            if let Some(limit) = rate_limit_per_minute {
+                budgets.push((
+                    RateLimitScope::ApiKey,
+                    RateLimitKey::ApiKey(*api_key_id),
+                    RateLimitQuota::per_minute(*limit),
+                ));
+            }
+        }
+        _ => budgets.push((
+            RateLimitScope::Anonymous,
+            RateLimitKey::Ip(ip),
+            config.anonymous,
+        )),
+    }
+
+    budgets
+}
+
+/// Count a rejection and publish a throttled `rate_limited` audit event
+#[cfg(not(feature = "generate-fixtures"))]
+async fn record_rejection(
+    state: &AppState,
+    parts: &Parts,
+    entity: Option<&AuthenticatedEntity>,
+    ip: IpAddr,
+    scope: RateLimitScope,
+    key: &RateLimitKey,
+    info: &RateLimitInfo,
+) {
     let limiters = get_limiters();
-    let key = RateLimitKey::Ip(ip);
 
-    match limiters.anonymous.check_key(&key) {
-        Ok(_) => Ok(RateLimitInfo {
-            limit: 20,
-            remaining: 19,
-            reset_in_secs: 60,
+    if let Ok(mut rejections) = limiters.rejections.lock() {
+        *rejections.entry(scope).or_default() += 1;
+    }
+
+    let should_audit = limiters
+        .last_audited
+        .lock()
+        .map(|mut last_audited| match last_audited.get(key) {
+            Some(at) if at.elapsed() < AUDIT_INTERVAL => false,
+            _ => {
+                last_audited.insert(key.clone(), Instant::now());
+                true
+            }
+        })
+        .unwrap_or(false);
+
+    if !should_audit {
+        return;
+    }
+
+    let authentication = entity.cloned().unwrap_or(AuthenticatedEntity::Anonymous);
+    let user_agent = parts
+        .headers
+        .get(axum::http::header::USER_AGENT)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let event = AuthEvent::new(
+        Uuid::new_v4(),
+        authentication.user_id(),
+        authentication.organization_id(),
+        AuthOperation::RateLimited,
+        Utc::now(),
+        ip,
+        user_agent,
+        serde_json::json!({
+            "scope": scope,
+            "limit": info.limit,
+            "path": parts.uri.path(),
+            "retry_after_secs": info.reset_in_secs,
         }),
-        Err(not_until) => {
-            let wait_time = not_until
-                .wait_time_from(DefaultClock::default().now())
-                .as_secs();
-            Err(RateLimitInfo {
-                limit: 20,
-                remaining: 0,
-                reset_in_secs: wait_time,
-            })
+        authentication,
+    );
+
+    let event_bus = state.services.event_bus.clone();
+    tokio::spawn(async move {
+        if let Err(e) = event_bus.publish_auth(event).await {
+            tracing::warn!(error = %e, "Failed to publish rate limited event");
         }
-    }
+    });
 }
 
 pub async fn rate_limit_middleware(
@@ -181,12 +549,13 @@ pub async fn rate_limit_middleware(
 
     #[cfg(not(feature = "generate-fixtures"))]
     {
+        let config = &state.config.rate_limits;
         let path = request.uri().path();
 
         let exempt_paths = ["/api/billing/webhooks/", "/api/config", "/api/metadata"];
 
         // Exempt static file serving, billing webhooks, config and metadata
-        if !path.starts_with("/api/") || exempt_paths.contains(&path) {
+        if !config.enabled || !path.starts_with("/api/") || exempt_paths.contains(&path) {
             return Ok(next.run(request).await);
         }
 
@@ -207,20 +576,131 @@ pub async fn rate_limit_middleware(
             return Ok(next.run(request).await);
         }
 
-        let check_result = match entity {
-            Some(AuthenticatedEntity::User { user_id, .. }) => check_user(user_id),
-            Some(AuthenticatedEntity::ApiKey { user_id, .. }) => check_user(user_id),
-            _ => check_anonymous(ip),
+        let organization_id = entity.as_ref().and_then(|e| e.organization_id());
+
+        // Only look up the plan when there are plan overrides to apply
+        let plan: Option<&'static str> = match organization_id {
+            Some(organization_id) if !config.plans.is_empty() => {
+                CachedOrganization::get_or_load(&mut parts, &state, &organization_id)
+                    .await
+                    .ok()
+                    .and_then(|o| o.base.plan)
+                    .map(|p| p.into())
+            }
+            _ => None,
         };
 
-        match check_result {
-            Ok(info) => {
-                let request = Request::from_parts(parts, body);
-                let mut response = next.run(request).await;
-                info.apply_headers(&mut response);
-                Ok(response)
+        let checks = budgets(config, entity.as_ref(), ip, plan);
+
+        let mut tightest = None;
+        for (scope, key, quota) in checks {
+            match check(&state, &key, quota).await {
+                Ok(Some(info)) => tightest = Some(RateLimitInfo::tightest(tightest, info)),
+                Ok(None) => {}
+                Err(info) => {
+                    record_rejection(&state, &parts, entity.as_ref(), ip, scope, &key, &info).await;
+                    return Err(info.to_error_response());
+                }
             }
-            Err(info) => Err(info.to_error_response()),
         }
+
+        let request = Request::from_parts(parts, body);
+        let mut response = next.run(request).await;
+        if let Some(info) = tightest {
+            info.apply_headers(&mut response);
+        }
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "generate-fixtures"))]
+    use crate::server::users::r#impl::permissions::UserOrgPermissions;
+
+    #[test]
+    fn test_plan_overrides() {
+        let config: RateLimitConfig = serde_json::from_value(serde_json::json!({
+            "organization": { "per_minute": 1000 },
+            "plans": {
+                "Enterprise": {
+                    "user": { "per_minute": 1200, "burst": 600 },
+                    "organization": { "per_minute": 10000 }
+                },
+                "Starter": { "user": { "per_minute": 60 } }
+            }
+        }))
+        .unwrap();
+
+        // Unset fields keep their defaults
+        assert!(config.enabled);
+        assert_eq!(config.store, RateLimitStore::Memory);
+        assert_eq!(config.anonymous, RateLimitQuota::new(20, 5));
+
+        assert_eq!(config.user_quota(None), RateLimitQuota::new(300, 150));
+        assert_eq!(
+            config.user_quota(Some("Enterprise")),
+            RateLimitQuota::new(1200, 600)
+        );
+        assert_eq!(
+            config.user_quota(Some("Starter")),
+            RateLimitQuota::per_minute(60)
+        );
+        assert_eq!(
+            config.user_quota(Some("Pro")),
+            RateLimitQuota::new(300, 150)
+        );
+
+        assert_eq!(
+            config.organization_quota(Some("Enterprise")),
+            Some(RateLimitQuota::per_minute(10000))
+        );
+        // Plans without an organization override fall back to the global one
+        assert_eq!(
+            config.organization_quota(Some("Starter")),
+            Some(RateLimitQuota::per_minute(1000))
+        );
+        assert_eq!(RateLimitConfig::default().organization_quota(None), None);
+    }
+
+    #[cfg(not(feature = "generate-fixtures"))]
+    #[test]
+    fn test_broadest_budget_is_checked_first() {
+        let config = RateLimitConfig {
+            organization: Some(RateLimitQuota::per_minute(1000)),
+            ..Default::default()
+        };
+        let ip = IpAddr::from([192, 168, 1, 10]);
+        let (user_id, api_key_id, organization_id) =
+            (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let api_key = AuthenticatedEntity::ApiKey {
+            api_key_id,
+            user_id,
+            organization_id,
+            permissions: UserOrgPermissions::Member,
+            network_ids: vec![],
+            scopes: vec![],
+            rate_limit_per_minute: Some(10),
+        };
+        let keys: Vec<RateLimitKey> = budgets(&config, Some(&api_key), ip, None)
+            .into_iter()
+            .map(|(_, key, _)| key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                RateLimitKey::Organization(organization_id),
+                RateLimitKey::User(user_id),
+                RateLimitKey::ApiKey(api_key_id),
+            ]
+        );
+
+        let keys: Vec<RateLimitKey> = budgets(&config, None, ip, None)
+            .into_iter()
+            .map(|(_, key, _)| key)
+            .collect();
+        assert_eq!(keys, vec![RateLimitKey::Ip(ip)]);
     }
 }
@@ -1,5 +1,8 @@
 pub mod handlers;
 pub mod r#impl;
+pub mod ldap;
+pub mod mfa;
 pub mod middleware;
 pub mod oidc;
 pub mod service;
+pub mod sessions;
@@ -11,7 +11,7 @@ use crate::server::{
             base::{LoginRegisterParams, PendingSetup, ProvisionUserParams},
             oidc::{
                 OidcPendingAuth, OidcProvider, OidcProviderConfig, OidcProviderMetadata,
-                OidcRegisterParams,
+                OidcRegisterParams, OidcResolvedAccess, OidcUserInfo,
             },
         },
         middleware::auth::AuthenticatedEntity,
@@ -24,6 +24,7 @@ use crate::server::{
             types::{AuthEvent, AuthOperation},
         },
         services::traits::CrudService,
+        storage::filter::EntityFilter,
     },
     users::{r#impl::base::User, service::UserService},
 };
@@ -53,17 +54,10 @@ impl OidcService {
                 config.slug
             );
 
-            let provider = OidcProvider::new(
-                config.slug.clone(),
-                config.name.clone(),
-                config.logo.clone(),
-                config.issuer_url.clone(),
-                config.client_id.clone(),
-                config.client_secret.clone(),
-                redirect_url,
-            );
+            let slug = config.slug.clone();
+            let provider = OidcProvider::new(config, redirect_url);
 
-            providers.insert(config.slug.clone(), Arc::new(provider));
+            providers.insert(slug, Arc::new(provider));
         }
 
         Self {
@@ -123,6 +117,7 @@ impl OidcService {
 
         // Exchange code for user info using provider
         let user_info = provider.exchange_code(code, &pending_auth).await?;
+        provider.claim_mapping.check_allowed(&user_info)?;
 
         // Check if user already exists with this OIDC account
         if let Some(_existing_user) = self
@@ -196,7 +191,8 @@ impl OidcService {
         Ok(user)
     }
 
-    /// Login existing user via OIDC (fails if account doesn't exist)
+    /// Login existing user via OIDC. Unknown users are only accepted when the
+    /// provider provisions just-in-time into a configured organization.
     pub async fn login(
         &self,
         provider_slug: &str,
@@ -212,19 +208,155 @@ impl OidcService {
         // Exchange code for user info using provider
         let user_info = provider.exchange_code(code, &pending_auth).await?;
 
-        // Check if user exists with this OIDC account
-        let user = self
+        // Enforce restrictions and resolve mapped access before touching the account,
+        // so users removed from the IdP groups are refused here
+        provider.claim_mapping.check_allowed(&user_info)?;
+        let access = provider.claim_mapping.resolve(&user_info.groups)?;
+
+        // Check if user exists with this OIDC account, or was provisioned ahead of time
+        let existing_user = match self
             .user_service
             .get_user_by_oidc(&user_info.subject)
             .await?
+        {
+            Some(user) => Some(user),
+            None => self.claim_provisioned_account(provider, &user_info).await?,
+        };
+
+        let user = match existing_user {
+            Some(user) if user.is_deactivated() => {
+                return Err(anyhow!("This account has been deactivated"));
+            }
+            Some(user) => {
+                self.user_service
+                    .apply_external_access(user, access.permissions, access.network_ids, "OIDC")
+                    .await?
+            }
+            None => {
+                let org_id = provider.claim_mapping.jit_organization_id.ok_or_else(|| {
+                    anyhow!(
+                        "No account found with this {} login. Please register first.",
+                        provider.name
+                    )
+                })?;
+
+                self.provision_jit_user(provider, org_id, user_info, access, ip, user_agent.clone())
+                    .await?
+            }
+        };
+
+        // Publish event
+        let authentication: AuthenticatedEntity = user.clone().into();
+        self.event_bus
+            .publish_auth(AuthEvent {
+                id: Uuid::new_v4(),
+                user_id: Some(user.id),
+                organization_id: Some(user.base.organization_id),
+                timestamp: Utc::now(),
+                operation: AuthOperation::LoginSuccess,
+                ip_address: ip,
+                user_agent,
+                metadata: serde_json::json!({
+                    "method": "oidc",
+                    "provider": provider.slug,
+                    "provider_name": provider.name
+                }),
+
+                authentication,
+            })
+            .await?;
+
+        Ok(user)
+    }
+
+    /// Link a first OIDC sign-in to an account provisioned ahead of time (e.g. via
+    /// SCIM), which has neither a password nor an OIDC identity yet
+    async fn claim_provisioned_account(
+        &self,
+        provider: &OidcProvider,
+        user_info: &OidcUserInfo,
+    ) -> Result<Option<User>> {
+        if user_info.email_verified == Some(false) {
+            return Ok(None);
+        }
+
+        let Some(email) = user_info
+            .email
+            .as_deref()
+            .and_then(|email| EmailAddress::from_str(email).ok())
+        else {
+            return Ok(None);
+        };
+
+        let Some(mut user) = self
+            .user_service
+            .get_one(EntityFilter::unfiltered().email(&email))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if user.base.password_hash.is_some() || user.base.oidc_subject.is_some() {
+            return Ok(None);
+        }
+
+        if user.is_deactivated() {
+            return Err(anyhow!("This account has been deactivated"));
+        }
+
+        user.base.oidc_provider = Some(provider.slug.clone());
+        user.base.oidc_subject = Some(user_info.subject.clone());
+        user.base.oidc_linked_at = Some(Utc::now());
+
+        let user = self
+            .user_service
+            .update(&mut user, AuthenticatedEntity::System)
+            .await?;
+
+        Ok(Some(user))
+    }
+
+    /// Create an account on first login for providers configured with a JIT organization
+    async fn provision_jit_user(
+        &self,
+        provider: &OidcProvider,
+        org_id: Uuid,
+        user_info: OidcUserInfo,
+        access: OidcResolvedAccess,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<User> {
+        let email = user_info
+            .email
+            .as_deref()
+            .and_then(|email| EmailAddress::from_str(email).ok())
             .ok_or_else(|| {
                 anyhow!(
-                    "No account found with this {} login. Please register first.",
+                    "{} did not provide a valid email address for your account",
                     provider.name
                 )
             })?;
 
-        // Publish event
+        let user = self
+            .auth_service
+            .provision_user(
+                ProvisionUserParams {
+                    email,
+                    password_hash: None,
+                    oidc_subject: Some(user_info.subject),
+                    oidc_provider: Some(provider.slug.clone()),
+                    org_id: Some(org_id),
+                    permissions: access
+                        .permissions
+                        .or(provider.claim_mapping.default_permissions),
+                    network_ids: access.network_ids.unwrap_or_default(),
+                    terms_accepted_at: None,
+                    billing_enabled: false,
+                },
+                None,
+            )
+            .await?;
+
         let authentication: AuthenticatedEntity = user.clone().into();
         self.event_bus
             .publish_auth(AuthEvent {
@@ -232,13 +364,14 @@ impl OidcService {
                 user_id: Some(user.id),
                 organization_id: Some(user.base.organization_id),
                 timestamp: Utc::now(),
-                operation: AuthOperation::LoginSuccess,
+                operation: AuthOperation::Register,
                 ip_address: ip,
                 user_agent,
                 metadata: serde_json::json!({
                     "method": "oidc",
                     "provider": provider.slug,
-                    "provider_name": provider.name
+                    "provider_name": provider.name,
+                    "jit": true
                 }),
 
                 authentication,
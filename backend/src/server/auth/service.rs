@@ -202,6 +202,7 @@ impl AuthService {
                         plan,
                         plan_status: None,
                         onboarding,
+                        require_mfa: false,
                     }),
                     AuthenticatedEntity::System,
                 )
@@ -385,6 +386,10 @@ impl AuthService {
         // Verify password
         verify_password(&request.password, password_hash)?;
 
+        if user.is_deactivated() {
+            return Err(anyhow!("This account has been deactivated"));
+        }
+
         Ok(user.clone())
     }
 
@@ -508,6 +513,10 @@ impl AuthService {
             .await?
             .ok_or_else(|| anyhow!("User not found"))?;
 
+        if user.is_deactivated() {
+            return Err(anyhow!("This account has been deactivated"));
+        }
+
         let authentication: AuthenticatedEntity = user.clone().into();
         self.event_bus
             .publish_auth(AuthEvent {
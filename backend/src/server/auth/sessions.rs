@@ -0,0 +1,302 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Utc};
+use std::{net::IpAddr, sync::Arc};
+use uuid::Uuid;
+
+use crate::server::{
+    auth::{
+        r#impl::sessions::{UserSession, UserSessionBase, UserSessionStorage},
+        middleware::auth::AuthenticatedEntity,
+    },
+    shared::events::{
+        bus::EventBus,
+        types::{AuthEvent, AuthOperation},
+    },
+    users::r#impl::base::User,
+};
+
+pub struct SessionService {
+    storage: Arc<UserSessionStorage>,
+    event_bus: Arc<EventBus>,
+    idle_timeout: Duration,
+    max_lifetime: Option<Duration>,
+}
+
+impl SessionService {
+    /// Last activity is written back at most this often, to avoid a write per request
+    const ACTIVITY_WRITE_INTERVAL_SECS: i64 = 60;
+
+    pub fn new(
+        storage: Arc<UserSessionStorage>,
+        event_bus: Arc<EventBus>,
+        idle_timeout_minutes: u64,
+        max_lifetime_hours: Option<u64>,
+    ) -> Self {
+        let minutes = |m: u64| Duration::minutes(m.min(u32::MAX.into()) as i64);
+
+        Self {
+            storage,
+            event_bus,
+            idle_timeout: minutes(idle_timeout_minutes),
+            max_lifetime: max_lifetime_hours.map(|h| minutes(h.saturating_mul(60))),
+        }
+    }
+
+    /// Record a new sign-in
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<UserSession> {
+        let session = UserSession::new(UserSessionBase::new(user_id, ip, user_agent));
+        self.storage.create(&session).await
+    }
+
+    /// Check the session record behind a request and refresh its last activity.
+    /// Returns `None` if the session was revoked, belongs to someone else, or
+    /// has outlived the configured lifetimes.
+    pub async fn validate(
+        &self,
+        id: &Uuid,
+        user: &User,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<Option<UserSession>> {
+        let Some(mut session) = self.storage.get_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        if session.base.user_id != user.id {
+            return Ok(None);
+        }
+
+        let now = Utc::now();
+
+        if self.is_expired(&session, now) {
+            self.storage.delete(&session.id).await?;
+
+            let authentication: AuthenticatedEntity = user.clone().into();
+            self.publish(
+                AuthOperation::SessionExpired,
+                user.id,
+                ip,
+                user_agent,
+                serde_json::json!({ "session_id": session.id }),
+                authentication,
+            )
+            .await;
+
+            return Ok(None);
+        }
+
+        if now - session.base.last_activity_at
+            > Duration::seconds(Self::ACTIVITY_WRITE_INTERVAL_SECS)
+        {
+            session.base.last_activity_at = now;
+            self.storage.update(&mut session).await?;
+        }
+
+        Ok(Some(session))
+    }
+
+    fn is_expired(&self, session: &UserSession, now: DateTime<Utc>) -> bool {
+        session_expired(session, now, self.idle_timeout, self.max_lifetime)
+    }
+
+    /// Forget a session that has been signed out
+    pub async fn remove(&self, id: &Uuid) -> Result<()> {
+        self.storage.delete(id).await
+    }
+
+    /// A user's sessions, most recently active first
+    pub async fn list_for_user(&self, user_id: &Uuid) -> Result<Vec<UserSession>> {
+        let now = Utc::now();
+        Ok(self
+            .storage
+            .get_for_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|s| !self.is_expired(s, now))
+            .collect())
+    }
+
+    /// Revoke one of a user's sessions
+    pub async fn revoke(
+        &self,
+        user_id: &Uuid,
+        session_id: &Uuid,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        authentication: AuthenticatedEntity,
+    ) -> Result<()> {
+        let session = self
+            .storage
+            .get_by_id(session_id)
+            .await?
+            .filter(|s| s.base.user_id == *user_id)
+            .ok_or_else(|| anyhow!("Session not found"))?;
+
+        self.storage.delete(&session.id).await?;
+
+        self.publish(
+            AuthOperation::SessionRevoked,
+            *user_id,
+            ip,
+            user_agent,
+            serde_json::json!({ "session_ids": [session.id] }),
+            authentication,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Revoke all of a user's sessions, optionally keeping the one making the request.
+    /// Returns how many sessions were revoked.
+    pub async fn revoke_all(
+        &self,
+        user_id: &Uuid,
+        except: Option<&Uuid>,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        authentication: AuthenticatedEntity,
+    ) -> Result<usize> {
+        let ids: Vec<Uuid> = self
+            .storage
+            .get_for_user(user_id)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .filter(|id| Some(id) != except)
+            .collect();
+
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let revoked = self.storage.delete_many(&ids).await?;
+
+        self.publish(
+            AuthOperation::SessionRevoked,
+            *user_id,
+            ip,
+            user_agent,
+            serde_json::json!({ "session_ids": ids }),
+            authentication,
+        )
+        .await;
+
+        Ok(revoked)
+    }
+
+    /// Drop records of sessions past their lifetime (called periodically from background task)
+    pub async fn cleanup_expired(&self) {
+        let now = Utc::now();
+        match self
+            .storage
+            .delete_expired(
+                now - self.idle_timeout,
+                self.max_lifetime.map(|max| now - max),
+            )
+            .await
+        {
+            Ok(0) => {}
+            Ok(deleted) => tracing::debug!("Removed {} expired user sessions", deleted),
+            Err(e) => tracing::warn!(error = %e, "Failed to remove expired user sessions"),
+        }
+    }
+
+    async fn publish(
+        &self,
+        operation: AuthOperation,
+        user_id: Uuid,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        metadata: serde_json::Value,
+        authentication: AuthenticatedEntity,
+    ) {
+        let event = AuthEvent::new(
+            Uuid::new_v4(),
+            Some(user_id),
+            authentication.organization_id(),
+            operation,
+            Utc::now(),
+            ip,
+            user_agent,
+            metadata,
+            authentication,
+        );
+
+        if let Err(e) = self.event_bus.publish_auth(event).await {
+            tracing::warn!(error = %e, "Failed to publish session event");
+        }
+    }
+}
+
+/// Whether a session has been idle too long or has passed its absolute lifetime
+fn session_expired(
+    session: &UserSession,
+    now: DateTime<Utc>,
+    idle_timeout: Duration,
+    max_lifetime: Option<Duration>,
+) -> bool {
+    now - session.base.last_activity_at > idle_timeout
+        || max_lifetime.is_some_and(|max| now - session.created_at > max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_at(created_at: DateTime<Utc>, last_activity_at: DateTime<Utc>) -> UserSession {
+        let mut session = UserSession::new(UserSessionBase::new(
+            Uuid::new_v4(),
+            "127.0.0.1".parse().unwrap(),
+            None,
+        ));
+        session.created_at = created_at;
+        session.base.last_activity_at = last_activity_at;
+        session
+    }
+
+    #[test]
+    fn test_session_lifetimes() {
+        let now = Utc::now();
+        let idle = Duration::minutes(60);
+
+        assert!(!session_expired(
+            &session_at(now - Duration::days(30), now),
+            now,
+            idle,
+            None
+        ));
+        assert!(!session_expired(
+            &session_at(now, now - Duration::minutes(59)),
+            now,
+            idle,
+            None
+        ));
+        assert!(session_expired(
+            &session_at(now, now - Duration::minutes(61)),
+            now,
+            idle,
+            None
+        ));
+
+        let max = Some(Duration::hours(24));
+        assert!(!session_expired(
+            &session_at(now - Duration::hours(23), now),
+            now,
+            idle,
+            max
+        ));
+        // Activity doesn't extend a session past its absolute lifetime
+        assert!(session_expired(
+            &session_at(now - Duration::hours(25), now),
+            now,
+            idle,
+            max
+        ));
+    }
+}
@@ -1,18 +1,28 @@
 use axum::Json;
 use axum::extract::{Path, State};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 use std::sync::Arc;
+use utoipa::ToSchema;
 use utoipa_axum::{router::OpenApiRouter, routes};
 use uuid::Uuid;
 
-use crate::server::auth::middleware::permissions::{Authorized, Member};
+use crate::server::auth::middleware::permissions::{
+    And, Authorized, IsDaemon, Member, Scope, Write,
+};
 use crate::server::bindings::r#impl::base::{Binding, BindingType};
 use crate::server::bindings::service::BindingService;
 use crate::server::config::AppState;
+use crate::server::hosts::r#impl::base::Host;
+use crate::server::interfaces::r#impl::base::Interface;
+use crate::server::ports::r#impl::base::Port;
+use crate::server::services::r#impl::base::Service;
 use crate::server::shared::handlers::query::BindingQuery;
 use crate::server::shared::handlers::traits::{CrudHandlers, create_handler, update_handler};
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::storage::filter::EntityFilter;
 use crate::server::shared::types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult};
+use crate::server::user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource};
 impl CrudHandlers for Binding {
     type Service = BindingService;
     type FilterQuery = BindingQuery;
@@ -22,6 +32,10 @@ impl CrudHandlers for Binding {
     }
 }
 
+impl ScopedResource for Binding {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Bindings;
+}
+
 mod generated {
     use super::*;
     crate::crud_get_all_handler!(Binding, "bindings", "binding");
@@ -147,11 +161,12 @@ async fn validate_no_binding_type_conflict(
         (status = 400, description = "Referenced port or interface does not exist", body = ApiErrorResponse),
         (status = 409, description = "Conflict with existing binding type", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Binding, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_binding(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Binding, Write>>>,
     Json(binding): Json<Binding>,
 ) -> ApiResult<Json<ApiResponse<Binding>>> {
     validate_no_binding_type_conflict(&state, &binding, None).await?;
@@ -189,7 +204,12 @@ async fn create_binding(
         }
     }
 
-    create_handler::<Binding>(State(state), auth, Json(binding)).await
+    create_handler::<Binding>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Json(binding),
+    )
+    .await
 }
 
 /// Update a binding
@@ -211,16 +231,23 @@ async fn create_binding(
         (status = 400, description = "Referenced port or interface does not exist", body = ApiErrorResponse),
         (status = 409, description = "Conflict with existing binding type", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Binding, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_binding(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Binding, Write>>>,
     path: Path<Uuid>,
     Json(binding): Json<Binding>,
 ) -> ApiResult<Json<ApiResponse<Binding>>> {
     validate_no_binding_type_conflict(&state, &binding, Some(*path)).await?;
-    update_handler::<Binding>(State(state), auth, path, Json(binding)).await
+    update_handler::<Binding>(
+        State(state),
+        auth.into_permission::<Member>(),
+        path,
+        Json(binding),
+    )
+    .await
 }
 
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
@@ -232,4 +259,173 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
             generated::delete
         ))
         .routes(routes!(generated::bulk_delete))
+        .routes(routes!(resolve_bindings_discovery))
+}
+
+/// A backend referenced by something a daemon discovered, such as the upstream of a reverse
+/// proxy route
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize, ToSchema)]
+pub struct BindingTarget {
+    /// IP address, hostname or container name
+    pub address: String,
+    pub port: u16,
+}
+
+/// Request body for resolving daemon-observed backends to bindings
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct DiscoveryResolveBindingsRequest {
+    pub targets: Vec<BindingTarget>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct DiscoveryResolveBindingsResponse {
+    /// One entry per requested target, in order; `null` where no binding matched
+    pub binding_ids: Vec<Option<Uuid>>,
+}
+
+/// Internal endpoint for daemon discovery
+///
+/// Resolves backends a daemon has seen referenced to the bindings of the services listening
+/// on them, on the daemon's network.
+///
+/// Tagged as "internal" - included in OpenAPI spec for client generation
+/// but hidden from public documentation.
+#[utoipa::path(
+    post,
+    path = "/discovery/resolve",
+    tags = ["bindings", "internal"],
+    request_body = DiscoveryResolveBindingsRequest,
+    responses(
+        (status = 200, description = "Targets resolved", body = ApiResponse<DiscoveryResolveBindingsResponse>),
+        (status = 403, description = "Daemon has no network assignment", body = ApiErrorResponse),
+    ),
+    security(("daemon_api_key" = []))
+)]
+async fn resolve_bindings_discovery(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<IsDaemon>,
+    Json(request): Json<DiscoveryResolveBindingsRequest>,
+) -> ApiResult<Json<ApiResponse<DiscoveryResolveBindingsResponse>>> {
+    let network_id = auth
+        .network_ids()
+        .first()
+        .copied()
+        .ok_or_else(|| ApiError::forbidden("Daemon has no network assignment"))?;
+
+    let network_filter = EntityFilter::unfiltered().network_ids(&[network_id]);
+
+    let hosts = state
+        .services
+        .host_service
+        .get_all(network_filter.clone())
+        .await?;
+    let interfaces = state
+        .services
+        .interface_service
+        .get_all(network_filter.clone())
+        .await?;
+    let ports = state
+        .services
+        .port_service
+        .get_all(network_filter.clone())
+        .await?;
+    let services = state
+        .services
+        .service_service
+        .get_all(network_filter)
+        .await?;
+
+    let binding_ids = request
+        .targets
+        .iter()
+        .map(|target| resolve_binding_target(target, &hosts, &interfaces, &ports, &services))
+        .collect();
+
+    Ok(Json(ApiResponse::success(
+        DiscoveryResolveBindingsResponse { binding_ids },
+    )))
+}
+
+/// Find the binding of a service listening on a target's port, on the interface with the
+/// target's IP address, or on the host or container the target names
+fn resolve_binding_target(
+    target: &BindingTarget,
+    hosts: &[Host],
+    interfaces: &[Interface],
+    ports: &[Port],
+    services: &[Service],
+) -> Option<Uuid> {
+    let address = target.address.trim_end_matches('.').to_lowercase();
+
+    // (host, interface) pairs the target can be reached on; `None` means any interface
+    let locations: Vec<(Uuid, Option<Uuid>)> = match address.parse::<IpAddr>() {
+        Ok(ip) => interfaces
+            .iter()
+            .filter(|i| i.base.ip_address == ip)
+            .map(|i| (i.base.host_id, Some(i.id)))
+            .collect(),
+        Err(_) => hosts
+            .iter()
+            .filter(|h| {
+                h.base.name.to_lowercase() == address
+                    || h.base.hostname.as_ref().is_some_and(|hostname| {
+                        let hostname = hostname.to_lowercase();
+                        hostname == address || hostname.split('.').next() == Some(address.as_str())
+                    })
+            })
+            .map(|h| (h.id, None))
+            .collect(),
+    };
+
+    let port_ids: Vec<Uuid> = ports
+        .iter()
+        .filter(|p| p.base.port_type.number() == target.port)
+        .filter(|p| {
+            locations
+                .iter()
+                .any(|(host_id, _)| *host_id == p.base.host_id)
+        })
+        .map(|p| p.id)
+        .collect();
+
+    let on_location = |service: &Service, binding: &Binding| match binding.base.binding_type {
+        BindingType::Port {
+            port_id,
+            interface_id,
+        } if port_ids.contains(&port_id) => locations.iter().any(|(host_id, location)| {
+            *host_id == service.base.host_id
+                && (location.is_none() || interface_id.is_none() || *location == interface_id)
+        }),
+        _ => false,
+    };
+
+    // Containers are usually addressed by name on their compose network
+    let is_named_container = |service: &Service| {
+        service
+            .base
+            .virtualization
+            .as_ref()
+            .and_then(|v| v.container_name())
+            .is_some_and(|name| name.trim_start_matches('/').to_lowercase() == address)
+    };
+
+    let container_binding = services
+        .iter()
+        .filter(|s| is_named_container(s))
+        .find_map(|s| {
+            s.base.bindings.iter().find(|b| match b.base.binding_type {
+                BindingType::Port { port_id, .. } => ports
+                    .iter()
+                    .any(|p| p.id == port_id && p.base.port_type.number() == target.port),
+                _ => false,
+            })
+        });
+
+    container_binding
+        .or_else(|| {
+            services
+                .iter()
+                .find_map(|s| s.base.bindings.iter().find(|b| on_location(s, b)))
+        })
+        .map(|b| b.id)
 }
@@ -1,4 +1,6 @@
+use crate::server::auth::r#impl::ldap::{LdapConfig, LdapProviderMetadata};
 use crate::server::auth::r#impl::oidc::OidcProviderMetadata;
+use crate::server::auth::middleware::rate_limit::RateLimitConfig;
 use crate::server::shared::types::api::ApiResponse;
 use crate::server::{
     auth::r#impl::oidc::OidcProviderConfig, shared::services::factory::ServiceFactory,
@@ -108,6 +110,10 @@ pub struct ServerConfig {
     pub public_url: String,
     pub integrated_daemon_url: Option<String>,
     pub use_secure_session_cookies: bool,
+    /// Sign sessions out after this long without a request
+    pub session_idle_timeout_minutes: u64,
+    /// Sign sessions out this long after login regardless of activity. Unlimited if unset.
+    pub session_max_lifetime_hours: Option<u64>,
     pub disable_registration: bool,
     pub client_ip_source: Option<String>,
     pub smtp_username: Option<String>,
@@ -116,6 +122,18 @@ pub struct ServerConfig {
     pub smtp_email: Option<String>,
     #[serde(default)]
     pub oidc_providers: Option<Vec<OidcProviderConfig>>,
+    #[serde(default)]
+    pub ldap: Option<LdapConfig>,
+    #[serde(default)]
+    pub rate_limits: RateLimitConfig,
+    /// 64 hex characters (32 bytes) used to encrypt discovery credentials at rest.
+    /// Credentials can't be created until this is set.
+    pub credential_key: Option<String>,
+    /// Cron expression (with seconds) for snapshotting every topology, e.g. "0 0 2 * * *".
+    /// Scheduled snapshots are off if unset; snapshots after discovery happen regardless.
+    pub topology_snapshot_schedule: Option<String>,
+    /// Automatic snapshots kept per topology. Manual snapshots are never pruned.
+    pub topology_snapshot_retention: usize,
 
     // Used in SaaS deployment
     pub plunk_key: Option<String>,
@@ -143,6 +161,7 @@ pub struct PublicConfigResponse {
     pub server_port: u16,
     pub disable_registration: bool,
     pub oidc_providers: Vec<OidcProviderMetadata>,
+    pub ldap: Option<LdapProviderMetadata>,
     pub billing_enabled: bool,
     pub has_integrated_daemon: bool,
     pub has_email_service: bool,
@@ -164,6 +183,8 @@ impl Default for ServerConfig {
             public_url: "http://localhost:60072".to_string(),
             web_external_path: None,
             use_secure_session_cookies: false,
+            session_idle_timeout_minutes: 7 * 24 * 60,
+            session_max_lifetime_hours: None,
             integrated_daemon_url: None,
             disable_registration: false,
             stripe_key: None,
@@ -177,6 +198,11 @@ impl Default for ServerConfig {
             plunk_key: None,
             client_ip_source: None,
             oidc_providers: None,
+            ldap: None,
+            rate_limits: RateLimitConfig::default(),
+            credential_key: None,
+            topology_snapshot_schedule: None,
+            topology_snapshot_retention: 30,
             posthog_key: None,
             enforce_billing_for_testing: false,
         }
@@ -188,6 +214,8 @@ impl ServerConfig {
         // Standard configuration layering: Defaults → Env → CLI (highest priority)
         let mut figment = Figment::from(Serialized::defaults(ServerConfig::default()))
             .merge(Toml::file("../oidc.toml"))
+            .merge(Toml::file("../ldap.toml"))
+            .merge(Toml::file("../rate_limits.toml"))
             .merge(Env::prefixed("NETVISOR_"))
             .merge(Env::prefixed("SCANOPY_"));
 
@@ -281,8 +309,12 @@ pub struct AppState {
 
 impl AppState {
     pub async fn new(config: ServerConfig) -> Result<Arc<Self>, Error> {
-        let storage =
-            StorageFactory::new(&config.database_url(), config.use_secure_session_cookies).await?;
+        let storage = StorageFactory::new(
+            &config.database_url(),
+            config.use_secure_session_cookies,
+            config.session_idle_timeout_minutes,
+        )
+        .await?;
         let services = ServiceFactory::new(&storage, Some(config.clone())).await?;
 
         Ok(Arc::new(Self {
@@ -327,6 +359,8 @@ pub async fn get_public_config(State(state): State<Arc<AppState>>) -> impl IntoR
         .map(|o| o.as_ref().list_providers())
         .unwrap_or_default();
 
+    let ldap = state.services.ldap_service.as_ref().map(|l| l.metadata());
+
     let deployment_type = get_deployment_type(state.clone());
 
     (
@@ -335,6 +369,7 @@ pub async fn get_public_config(State(state): State<Arc<AppState>>) -> impl IntoR
             server_port: state.config.server_port,
             disable_registration: state.config.disable_registration,
             oidc_providers,
+            ldap,
             billing_enabled: state.config.stripe_secret.is_some(),
             has_integrated_daemon: state.config.integrated_daemon_url.is_some(),
             has_email_service: (state.config.smtp_password.is_some()
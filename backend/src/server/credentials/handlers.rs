@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::permissions::{And, Authorized, Member, Scope, Write},
+    config::AppState,
+    credentials::r#impl::{api::CreateUpdateCredentialRequest, base::Credential},
+    shared::{
+        handlers::traits::{CrudHandlers, create_handler, update_handler},
+        services::traits::CrudService,
+        types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult},
+    },
+};
+
+// Generated handlers for generic CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_get_all_handler!(Credential, "credentials", "credential");
+    crate::crud_get_by_id_handler!(Credential, "credentials", "credential");
+    crate::crud_delete_handler!(Credential, "credentials", "credential");
+    crate::crud_bulk_delete_handler!(Credential, "credentials");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(generated::get_all, create_credential))
+        .routes(routes!(
+            generated::get_by_id,
+            update_credential,
+            generated::delete
+        ))
+        .routes(routes!(generated::bulk_delete))
+}
+
+/// Create a new credential
+///
+/// The secret is encrypted at rest and is never returned by the API.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "credentials",
+    request_body = CreateUpdateCredentialRequest,
+    responses(
+        (status = 200, description = "Credential created", body = ApiResponse<Credential>),
+        (status = 400, description = "Missing secret or credential storage not configured", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Credential, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_credential(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<Credential, Write>>>,
+    Json(CreateUpdateCredentialRequest {
+        mut credential,
+        secret,
+    }): Json<CreateUpdateCredentialRequest>,
+) -> ApiResult<Json<ApiResponse<Credential>>> {
+    let secret = secret
+        .ok_or_else(|| ApiError::bad_request("A secret is required to create a credential"))?;
+
+    state
+        .services
+        .credential_service
+        .seal(&mut credential, &secret)?;
+
+    create_handler::<Credential>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Json(credential),
+    )
+    .await
+}
+
+/// Update a credential
+///
+/// Omit `secret` to keep the stored secret.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "credentials",
+    params(("id" = Uuid, Path, description = "Credential ID")),
+    request_body = CreateUpdateCredentialRequest,
+    responses(
+        (status = 200, description = "Credential updated", body = ApiResponse<Credential>),
+        (status = 404, description = "Credential not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Credential, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_credential(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<Credential, Write>>>,
+    Path(id): Path<Uuid>,
+    Json(CreateUpdateCredentialRequest {
+        mut credential,
+        secret,
+    }): Json<CreateUpdateCredentialRequest>,
+) -> ApiResult<Json<ApiResponse<Credential>>> {
+    let service = Credential::get_service(&state);
+
+    let existing = service
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Credential '{}' not found", id)))?;
+
+    match &secret {
+        Some(secret) => service.seal(&mut credential, secret)?,
+        None => service.carry_over(&mut credential, &existing)?,
+    }
+
+    update_handler::<Credential>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(credential),
+    )
+    .await
+}
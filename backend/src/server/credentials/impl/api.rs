@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::base::{Credential, CredentialSecret};
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateUpdateCredentialRequest {
+    pub credential: Credential,
+    /// Required when creating. When updating, omit to keep the stored secret.
+    pub secret: Option<CredentialSecret>,
+}
+
+/// A decrypted credential, handed to a daemon at the start of a discovery session
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DiscoveryCredential {
+    pub id: Uuid,
+    pub name: String,
+    pub secret: CredentialSecret,
+}
@@ -0,0 +1,214 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::server::shared::{
+    entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
+    storage::traits::{SqlValue, StorableEntity},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Secret material for a credential. Only ever accepted from clients and sent to
+/// daemons at the start of a discovery session; it is never returned by the API.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, EnumDiscriminants, ToSchema)]
+#[strum_discriminants(
+    name(CredentialType),
+    derive(
+        Display,
+        EnumString,
+        EnumIter,
+        IntoStaticStr,
+        Hash,
+        Serialize,
+        Deserialize,
+        ToSchema,
+        Default
+    )
+)]
+#[serde(tag = "type")]
+pub enum CredentialSecret {
+    /// SNMP v1/v2c community string
+    #[schema(title = "SnmpCommunity")]
+    #[strum_discriminants(default)]
+    SnmpCommunity { community: String },
+    /// Bearer token for an HTTP API, such as a Proxmox VE API token
+    #[schema(title = "ApiToken")]
+    ApiToken { token: String },
+    #[schema(title = "SshKey")]
+    SshKey {
+        username: String,
+        /// PEM or OpenSSH encoded private key
+        private_key: String,
+        #[serde(default)]
+        passphrase: Option<String>,
+    },
+    #[schema(title = "UsernamePassword")]
+    UsernamePassword { username: String, password: String },
+}
+
+impl std::fmt::Debug for CredentialSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print secret material, even in debug logs
+        write!(
+            f,
+            "CredentialSecret::{}([redacted])",
+            CredentialType::from(self)
+        )
+    }
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
+)]
+pub struct CredentialBase {
+    pub network_id: Uuid,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[schema(required)]
+    pub description: Option<String>,
+    /// Set from the secret when the credential is created
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub credential_type: CredentialType,
+    /// Encrypted secret - never sent to client, never accept from client
+    #[serde(skip)]
+    pub encrypted_secret: String,
+}
+
+/// A secret used by discovery, such as an SNMP community or API token, encrypted at rest
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
+)]
+pub struct Credential {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: CredentialBase,
+}
+
+impl Display for Credential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Credential {} ({})", self.id, self.base.name)
+    }
+}
+
+impl ChangeTriggersTopologyStaleness<Credential> for Credential {
+    fn triggers_staleness(&self, _other: Option<Credential>) -> bool {
+        false
+    }
+}
+
+impl StorableEntity for Credential {
+    type BaseData = CredentialBase;
+
+    fn table_name() -> &'static str {
+        "credentials"
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::Credential
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        Ok((
+            vec![
+                "id",
+                "network_id",
+                "name",
+                "description",
+                "credential_type",
+                "encrypted_secret",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.network_id),
+                SqlValue::String(self.base.name.clone()),
+                SqlValue::OptionalString(self.base.description.clone()),
+                SqlValue::String(self.base.credential_type.to_string()),
+                SqlValue::String(self.base.encrypted_secret.clone()),
+                SqlValue::Timestamp(self.created_at),
+                SqlValue::Timestamp(self.updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let credential_type: String = row.get("credential_type");
+        let credential_type = CredentialType::from_str(&credential_type)
+            .map_err(|_| anyhow::anyhow!("Unknown credential type '{}'", credential_type))?;
+
+        Ok(Credential {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: CredentialBase {
+                network_id: row.get("network_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                credential_type,
+                encrypted_secret: row.get("encrypted_secret"),
+            },
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, KeyInit, Payload},
+};
+use anyhow::{Result, anyhow, bail};
+use uuid::Uuid;
+
+use crate::server::credentials::r#impl::base::CredentialSecret;
+
+/// Prefix on stored ciphertexts, so the format or key can change later without
+/// guessing how an entry was sealed
+const FORMAT_PREFIX: &str = "v1:";
+const NONCE_LEN: usize = 12;
+
+/// Encrypts credential secrets at rest with AES-256-GCM.
+///
+/// Each entry is bound to its network as associated data, so a ciphertext copied
+/// onto a credential in another network fails to decrypt.
+pub struct CredentialCipher {
+    cipher: Aes256Gcm,
+}
+
+impl CredentialCipher {
+    /// Build from a 32-byte key, hex encoded (64 characters)
+    pub fn from_hex_key(key: &str) -> Result<Self> {
+        let key =
+            hex::decode(key.trim()).map_err(|_| anyhow!("Credential key must be hex encoded"))?;
+
+        let key: [u8; 32] = match key.try_into() {
+            Ok(key) => key,
+            Err(key) => bail!(
+                "Credential key must be 32 bytes (64 hex characters), got {} bytes",
+                key.len()
+            ),
+        };
+
+        Ok(Self {
+            cipher: Aes256Gcm::new(&Key::<Aes256Gcm>::from(key)),
+        })
+    }
+
+    pub fn encrypt(&self, secret: &CredentialSecret, network_id: &Uuid) -> Result<String> {
+        let plaintext = serde_json::to_vec(secret)?;
+        let nonce_bytes: [u8; NONCE_LEN] = rand::random();
+
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &Nonce::from(nonce_bytes),
+                Payload {
+                    msg: &plaintext,
+                    aad: network_id.as_bytes(),
+                },
+            )
+            .map_err(|_| anyhow!("Failed to encrypt credential"))?;
+
+        let mut sealed = nonce_bytes.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+
+        Ok(format!("{}{}", FORMAT_PREFIX, hex::encode(sealed)))
+    }
+
+    pub fn decrypt(&self, sealed: &str, network_id: &Uuid) -> Result<CredentialSecret> {
+        let sealed = sealed
+            .strip_prefix(FORMAT_PREFIX)
+            .and_then(|s| hex::decode(s).ok())
+            .filter(|s| s.len() > NONCE_LEN)
+            .ok_or_else(|| anyhow!("Stored credential is malformed"))?;
+
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let nonce: [u8; NONCE_LEN] = nonce.try_into()?;
+
+        let plaintext = self
+            .cipher
+            .decrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: network_id.as_bytes(),
+                },
+            )
+            .map_err(|_| {
+                anyhow!(
+                    "Failed to decrypt credential; the server's credential key may have changed"
+                )
+            })?;
+
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn secret() -> CredentialSecret {
+        CredentialSecret::ApiToken {
+            token: "root@pam!scanopy=secret".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cipher = CredentialCipher::from_hex_key(KEY).unwrap();
+        let network_id = Uuid::new_v4();
+
+        let sealed = cipher.encrypt(&secret(), &network_id).unwrap();
+        assert!(sealed.starts_with(FORMAT_PREFIX));
+        assert!(!sealed.contains("secret"));
+
+        assert_eq!(cipher.decrypt(&sealed, &network_id).unwrap(), secret());
+
+        // Fresh nonce per entry
+        assert_ne!(sealed, cipher.encrypt(&secret(), &network_id).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_wrong_network_key_or_tampering() {
+        let cipher = CredentialCipher::from_hex_key(KEY).unwrap();
+        let network_id = Uuid::new_v4();
+        let sealed = cipher.encrypt(&secret(), &network_id).unwrap();
+
+        assert!(cipher.decrypt(&sealed, &Uuid::new_v4()).is_err());
+
+        let other = CredentialCipher::from_hex_key(&"ab".repeat(32)).unwrap();
+        assert!(other.decrypt(&sealed, &network_id).is_err());
+
+        let mut tampered = sealed.clone();
+        let last = tampered.pop().unwrap();
+        tampered.push(if last == '0' { '1' } else { '0' });
+        assert!(cipher.decrypt(&tampered, &network_id).is_err());
+
+        assert!(cipher.decrypt("not sealed", &network_id).is_err());
+    }
+
+    #[test]
+    fn test_key_validation() {
+        assert!(CredentialCipher::from_hex_key("abcd").is_err());
+        assert!(CredentialCipher::from_hex_key(&"zz".repeat(32)).is_err());
+        assert!(CredentialCipher::from_hex_key(&format!(" {} ", KEY)).is_ok());
+    }
+}
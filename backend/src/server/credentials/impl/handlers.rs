@@ -0,0 +1,19 @@
+use crate::server::{
+    config::AppState,
+    credentials::{r#impl::base::Credential, service::CredentialService},
+    shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
+};
+
+impl CrudHandlers for Credential {
+    type Service = CredentialService;
+    type FilterQuery = NetworkFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.credential_service
+    }
+}
+
+impl ScopedResource for Credential {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Credentials;
+}
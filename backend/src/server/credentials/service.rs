@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    credentials::r#impl::{
+        api::DiscoveryCredential,
+        base::{Credential, CredentialSecret, CredentialType},
+        cipher::CredentialCipher,
+    },
+    shared::{
+        events::{
+            bus::EventBus,
+            types::{EntityEvent, EntityOperation},
+        },
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::EntityFilter, generic::GenericPostgresStorage},
+        types::api::ValidationError,
+    },
+};
+
+pub struct CredentialService {
+    storage: Arc<GenericPostgresStorage<Credential>>,
+    event_bus: Arc<EventBus>,
+    /// None when the server has no credential key configured
+    cipher: Option<CredentialCipher>,
+}
+
+impl EventBusService<Credential> for CredentialService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &Credential) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &Credential) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<Credential> for CredentialService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<Credential>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::shared::services::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl CredentialService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<Credential>>,
+        event_bus: Arc<EventBus>,
+        cipher: Option<CredentialCipher>,
+    ) -> Self {
+        Self {
+            storage,
+            event_bus,
+            cipher,
+        }
+    }
+
+    fn cipher(&self) -> Result<&CredentialCipher> {
+        self.cipher.as_ref().ok_or_else(|| {
+            ValidationError::new(
+                "Credential storage is not configured. Set SCANOPY_CREDENTIAL_KEY on the server.",
+            )
+            .into()
+        })
+    }
+
+    /// Encrypt a new secret onto a credential
+    pub fn seal(&self, credential: &mut Credential, secret: &CredentialSecret) -> Result<()> {
+        credential.base.credential_type = CredentialType::from(secret);
+        credential.base.encrypted_secret = self
+            .cipher()?
+            .encrypt(secret, &credential.base.network_id)?;
+        Ok(())
+    }
+
+    /// Carry the stored secret over to an updated credential. Secrets are bound to
+    /// their network, so a credential moving network is re-encrypted.
+    pub fn carry_over(&self, credential: &mut Credential, existing: &Credential) -> Result<()> {
+        credential.base.credential_type = existing.base.credential_type;
+
+        if credential.base.network_id == existing.base.network_id {
+            credential.base.encrypted_secret = existing.base.encrypted_secret.clone();
+            return Ok(());
+        }
+
+        let secret = self
+            .cipher()?
+            .decrypt(&existing.base.encrypted_secret, &existing.base.network_id)?;
+        self.seal(credential, &secret)
+    }
+
+    /// Decrypt credentials for a discovery session. Every credential handed out is
+    /// recorded on the event bus; any that were deleted or belong to another network
+    /// are skipped.
+    pub async fn open_for_session(
+        &self,
+        ids: &[Uuid],
+        network_id: Uuid,
+        session_id: Uuid,
+        authentication: AuthenticatedEntity,
+    ) -> Result<Vec<DiscoveryCredential>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let cipher = self.cipher()?;
+        let credentials = self
+            .get_all(
+                EntityFilter::unfiltered()
+                    .entity_ids(ids)
+                    .network_ids(&[network_id]),
+            )
+            .await?;
+
+        if credentials.len() < ids.len() {
+            tracing::warn!(
+                session_id = %session_id,
+                requested = ids.len(),
+                found = credentials.len(),
+                "Some discovery credentials no longer exist and were skipped"
+            );
+        }
+
+        let mut opened = Vec::with_capacity(credentials.len());
+
+        for credential in credentials {
+            let secret = cipher.decrypt(&credential.base.encrypted_secret, &network_id)?;
+
+            self.event_bus
+                .publish_entity(EntityEvent {
+                    id: Uuid::new_v4(),
+                    entity_id: credential.id,
+                    network_id: Some(network_id),
+                    organization_id: None,
+                    entity_type: credential.clone().into(),
+                    operation: EntityOperation::SecretAccessed,
+                    timestamp: Utc::now(),
+                    metadata: serde_json::json!({
+                        "session_id": session_id
+                    }),
+                    authentication: authentication.clone(),
+                })
+                .await?;
+
+            opened.push(DiscoveryCredential {
+                id: credential.id,
+                name: credential.base.name,
+                secret,
+            });
+        }
+
+        Ok(opened)
+    }
+
+    /// Check that credentials referenced by a discovery exist on its network
+    pub async fn validate_references(&self, ids: &[Uuid], network_id: Uuid) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let found = self
+            .get_all(
+                EntityFilter::unfiltered()
+                    .entity_ids(ids)
+                    .network_ids(&[network_id]),
+            )
+            .await?;
+
+        if let Some(missing) = ids.iter().find(|id| !found.iter().any(|c| c.id == **id)) {
+            return Err(ValidationError::new(format!(
+                "Credential {} doesn't exist on network {}",
+                missing, network_id
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+}
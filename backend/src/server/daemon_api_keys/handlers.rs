@@ -1,7 +1,7 @@
 use crate::server::{
     auth::middleware::{
         features::{BlockedInDemoMode, RequireFeature},
-        permissions::{Authorized, Member},
+        permissions::{And, Authorized, Member, Scope, Write},
     },
     config::AppState,
     daemon_api_keys::r#impl::{api::DaemonApiKeyResponse, base::DaemonApiKey},
@@ -54,11 +54,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 403, description = "Insufficient permissions (member+ required)", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<DaemonApiKey, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn create_daemon_api_key(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<DaemonApiKey, Write>>>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     Json(mut api_key): Json<DaemonApiKey>,
 ) -> ApiResult<Json<ApiResponse<DaemonApiKeyResponse>>> {
@@ -131,11 +132,12 @@ pub async fn create_daemon_api_key(
         (status = 200, description = "Daemon API key updated", body = ApiResponse<DaemonApiKey>),
         (status = 404, description = "Daemon API key not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<DaemonApiKey, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn update_daemon_api_key(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<DaemonApiKey, Write>>>,
     Path(id): Path<Uuid>,
     Json(mut request): Json<DaemonApiKey>,
 ) -> ApiResult<Json<ApiResponse<DaemonApiKey>>> {
@@ -154,7 +156,13 @@ pub async fn update_daemon_api_key(
     request.preserve_immutable_fields(&existing);
 
     // Delegate to generic handler
-    update_handler::<DaemonApiKey>(State(state), auth, Path(id), Json(request)).await
+    update_handler::<DaemonApiKey>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(request),
+    )
+    .await
 }
 
 /// Rotate a daemon API key
@@ -167,11 +175,12 @@ pub async fn update_daemon_api_key(
         (status = 200, description = "Daemon API key rotated, returns new key", body = ApiResponse<String>),
         (status = 404, description = "Daemon API key not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<DaemonApiKey, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn rotate_key_handler(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<DaemonApiKey, Write>>>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     ClientIp(ip): ClientIp,
     user_agent: Option<TypedHeader<UserAgent>>,
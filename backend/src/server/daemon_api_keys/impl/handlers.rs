@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     daemon_api_keys::{r#impl::base::DaemonApiKey, service::DaemonApiKeyService},
     shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for DaemonApiKey {
@@ -12,3 +13,7 @@ impl CrudHandlers for DaemonApiKey {
         &state.services.daemon_api_key_service
     }
 }
+
+impl ScopedResource for DaemonApiKey {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::DaemonApiKeys;
+}
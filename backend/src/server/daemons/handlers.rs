@@ -1,4 +1,6 @@
-use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Viewer};
+use crate::server::auth::middleware::permissions::{
+    And, Authorized, IsDaemon, Read, Scope, Viewer,
+};
 use crate::server::billing::types::base::BillingPlan;
 use crate::server::daemons::r#impl::api::DaemonHeartbeatPayload;
 use crate::server::shared::entities::EntityDiscriminants;
@@ -82,11 +84,12 @@ pub fn create_internal_router() -> OpenApiRouter<Arc<AppState>> {
     responses(
         (status = 200, description = "List of daemons", body = PaginatedApiResponse<DaemonResponse>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Daemon, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_all(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Daemon, Read>>>,
     query: Query<NetworkFilterQuery>,
 ) -> ApiResult<Json<PaginatedApiResponse<DaemonResponse>>> {
     let network_ids = auth.network_ids();
@@ -144,11 +147,12 @@ async fn get_all(
         (status = 404, description = "Daemon not found", body = ApiErrorResponse),
         (status = 403, description = "Access denied", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Daemon, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_by_id(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Daemon, Read>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<DaemonResponse>>> {
     let network_ids = auth.network_ids();
@@ -389,6 +393,7 @@ async fn register_daemon(
                 daemon_id: request.daemon_id,
                 network_id: request.network_id,
                 tags: Vec::new(),
+                credential_ids: Vec::new(),
             }),
             AuthenticatedEntity::System,
         )
@@ -417,6 +422,7 @@ async fn register_daemon(
                     daemon_id: request.daemon_id,
                     network_id: request.network_id,
                     tags: Vec::new(),
+                    credential_ids: Vec::new(),
                 }),
                 AuthenticatedEntity::System,
             )
@@ -445,6 +451,7 @@ async fn register_daemon(
                 daemon_id: request.daemon_id,
                 network_id: request.network_id,
                 tags: Vec::new(),
+                credential_ids: Vec::new(),
             }),
             AuthenticatedEntity::System,
         )
@@ -71,6 +71,10 @@ pub struct DaemonRegistrationResponse {
 pub struct DaemonDiscoveryRequest {
     pub session_id: Uuid,
     pub discovery_type: DiscoveryType,
+    /// Credentials attached to the discovery. The daemon fetches the secrets from the
+    /// server at session start; they are never included in this request.
+    #[serde(default)]
+    pub credential_ids: Vec<Uuid>,
 }
 
 impl From<DiscoveryUpdatePayload> for DaemonDiscoveryRequest {
@@ -78,6 +82,7 @@ impl From<DiscoveryUpdatePayload> for DaemonDiscoveryRequest {
         Self {
             session_id: payload.session_id,
             discovery_type: payload.discovery_type,
+            credential_ids: payload.credential_ids,
         }
     }
 }
@@ -100,6 +105,9 @@ pub struct DiscoveryUpdatePayload {
     pub error: Option<String>,
     pub started_at: Option<DateTime<Utc>>,
     pub finished_at: Option<DateTime<Utc>>,
+    /// Credentials the daemon should fetch when it starts this session
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub credential_ids: Vec<Uuid>,
 }
 
 impl DiscoveryUpdatePayload {
@@ -119,6 +127,7 @@ impl DiscoveryUpdatePayload {
             error: None,
             started_at: None,
             finished_at: None,
+            credential_ids: Vec::new(),
         }
     }
 
@@ -137,6 +146,7 @@ impl DiscoveryUpdatePayload {
             error: update.error,
             started_at: info.started_at,
             finished_at: update.finished_at,
+            credential_ids: Vec::new(),
         }
     }
 }
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     daemons::{r#impl::base::Daemon, service::DaemonService},
     shared::handlers::{query::HostChildQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Daemon {
@@ -12,3 +13,7 @@ impl CrudHandlers for Daemon {
         &state.services.daemon_service
     }
 }
+
+impl ScopedResource for Daemon {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Daemons;
+}
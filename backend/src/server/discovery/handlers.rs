@@ -1,6 +1,9 @@
 use crate::server::{
-    auth::middleware::permissions::{Authorized, IsDaemon, Member, Viewer},
+    auth::middleware::permissions::{
+        And, Authorized, IsDaemon, Member, Read, Scope, Trigger, Viewer, Write,
+    },
     config::AppState,
+    credentials::r#impl::api::DiscoveryCredential,
     daemons::r#impl::api::DiscoveryUpdatePayload,
     discovery::r#impl::{
         base::Discovery,
@@ -50,6 +53,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         .routes(routes!(cancel_discovery))
         // Internal daemon endpoints
         .routes(routes!(receive_discovery_update))
+        .routes(routes!(get_session_credentials))
         // SSE endpoint (internal - not well-supported by OpenAPI)
         .route("/stream", get(discovery_stream))
 }
@@ -64,12 +68,14 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Discovery created successfully", body = ApiResponse<Discovery>),
         (status = 400, description = "Invalid subnet network", body = ApiErrorResponse),
         (status = 400, description = "Can't create historical discovery", body = ApiErrorResponse),
+        (status = 400, description = "Credential not on the discovery's network", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Discovery, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn create_discovery(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Discovery, Write>>>,
     Json(discovery): Json<Discovery>,
 ) -> ApiResult<Json<ApiResponse<Discovery>>> {
     if let RunType::Historical { .. } = discovery.base.run_type {
@@ -93,11 +99,56 @@ pub async fn create_discovery(
                 }
             }
         }
-        DiscoveryType::Docker { .. } | DiscoveryType::SelfReport { .. } => (),
+        DiscoveryType::SshInventory { targets, .. } => {
+            if targets.is_empty() {
+                return Err(ApiError::bad_request(
+                    "SSH inventory discovery needs at least one target address.",
+                ));
+            }
+            if discovery.base.credential_ids.is_empty() {
+                return Err(ApiError::bad_request(
+                    "SSH inventory discovery needs an SSH key or username/password credential.",
+                ));
+            }
+        }
+        DiscoveryType::WinRm { targets, .. } => {
+            if targets.is_empty() {
+                return Err(ApiError::bad_request(
+                    "WinRM discovery needs at least one target address.",
+                ));
+            }
+            if discovery.base.credential_ids.is_empty() {
+                return Err(ApiError::bad_request(
+                    "WinRM discovery needs a username/password credential.",
+                ));
+            }
+        }
+        DiscoveryType::Traceroute { max_hops, .. } => {
+            if *max_hops == 0 {
+                return Err(ApiError::bad_request(
+                    "Traceroute discovery needs a max hop count of at least 1.",
+                ));
+            }
+        }
+        DiscoveryType::Docker { .. }
+        | DiscoveryType::Kubernetes { .. }
+        | DiscoveryType::Proxmox { .. }
+        | DiscoveryType::SelfReport { .. } => (),
     }
 
+    state
+        .services
+        .credential_service
+        .validate_references(&discovery.base.credential_ids, discovery.base.network_id)
+        .await?;
+
     // Delegate to generic handler (handles validation, auth checks, creation)
-    create_handler::<Discovery>(State(state), auth, Json(discovery)).await
+    create_handler::<Discovery>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Json(discovery),
+    )
+    .await
 }
 
 /// Update discovery
@@ -111,12 +162,14 @@ pub async fn create_discovery(
         (status = 200, description = "Discovery updated successfully", body = ApiResponse<Discovery>),
         (status = 400, description = "Invalid subnet network", body = ApiErrorResponse),
         (status = 400, description = "Can't update historical discovery", body = ApiErrorResponse),
+        (status = 400, description = "Credential not on the discovery's network", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Discovery, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn update_discovery(
     state: State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Discovery, Write>>>,
     id: Path<Uuid>,
     discovery: Json<Discovery>,
 ) -> ApiResult<Json<ApiResponse<Discovery>>> {
@@ -126,7 +179,13 @@ pub async fn update_discovery(
         ));
     }
 
-    update_handler::<Discovery>(state, auth, id, discovery).await
+    state
+        .services
+        .credential_service
+        .validate_references(&discovery.base.credential_ids, discovery.base.network_id)
+        .await?;
+
+    update_handler::<Discovery>(state, auth.into_permission::<Member>(), id, discovery).await
 }
 
 /// Receive discovery progress update from daemon
@@ -176,6 +235,64 @@ async fn receive_discovery_update(
     Ok(Json(ApiResponse::success(())))
 }
 
+/// Fetch credentials for a discovery session
+///
+/// Internal endpoint for daemons to collect the decrypted credentials attached to a
+/// discovery when the session starts. Credentials can only be fetched once per session.
+#[utoipa::path(
+    post,
+    path = "/{session_id}/credentials",
+    tags = ["discovery", "internal"],
+    params(("session_id" = Uuid, Path, description = "Discovery session ID")),
+    responses(
+        (status = 200, description = "Session credentials", body = ApiResponse<Vec<DiscoveryCredential>>),
+        (status = 403, description = "Session belongs to another daemon", body = ApiErrorResponse),
+        (status = 404, description = "Session not found", body = ApiErrorResponse),
+    ),
+    security(("daemon_api_key" = []))
+)]
+async fn get_session_credentials(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<IsDaemon>,
+    Path(session_id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<Vec<DiscoveryCredential>>>> {
+    // IsDaemon guarantees exactly one network_id and a daemon_id
+    let daemon_network_id = auth.network_ids()[0];
+    let daemon_id = auth.daemon_id().expect("IsDaemon ensures daemon_id exists");
+
+    let session = state
+        .services
+        .discovery_service
+        .get_session(&session_id)
+        .await
+        .ok_or_else(|| ApiError::not_found(format!("Session '{}' not found", session_id)))?;
+
+    if session.daemon_id != daemon_id || session.network_id != daemon_network_id {
+        return Err(ApiError::forbidden(
+            "Cannot fetch credentials for another daemon's session",
+        ));
+    }
+
+    let credential_ids = state
+        .services
+        .discovery_service
+        .take_session_credentials(&session_id)
+        .await;
+
+    let credentials = state
+        .services
+        .credential_service
+        .open_for_session(
+            &credential_ids,
+            daemon_network_id,
+            session_id,
+            auth.into_entity(),
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(credentials)))
+}
+
 /// Start a discovery session
 #[utoipa::path(
     post,
@@ -186,11 +303,12 @@ async fn receive_discovery_update(
         (status = 200, description = "Discovery session started", body = ApiResponse<DiscoveryUpdatePayload>),
         (status = 404, description = "Discovery not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Discovery, Trigger>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn start_session(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Discovery, Trigger>>>,
     Json(discovery_id): Json<Uuid>,
 ) -> ApiResult<Json<ApiResponse<DiscoveryUpdatePayload>>> {
     let network_ids = auth.network_ids();
@@ -240,7 +358,7 @@ async fn start_session(
 
 async fn discovery_stream(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Discovery, Read>>>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
     let mut rx = state.services.discovery_service.subscribe();
     let allowed_networks = auth.network_ids();
@@ -275,11 +393,12 @@ async fn discovery_stream(
     responses(
         (status = 200, description = "List of active discovery sessions", body = ApiResponse<Vec<DiscoveryUpdatePayload>>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Discovery, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_active_sessions(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Discovery, Read>>>,
 ) -> ApiResult<Json<ApiResponse<Vec<DiscoveryUpdatePayload>>>> {
     let network_ids = auth.network_ids();
     let sessions = state
@@ -300,11 +419,12 @@ async fn get_active_sessions(
     responses(
         (status = 200, description = "Discovery session cancelled", body = EmptyApiResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Discovery, Trigger>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn cancel_discovery(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Discovery, Trigger>>>,
     Path(session_id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     // Get session and validate user has access to this session's network
@@ -23,6 +23,10 @@ pub struct DiscoveryBase {
     #[serde(default)]
     #[schema(required)]
     pub tags: Vec<Uuid>,
+    /// Credentials handed to the daemon when a session of this discovery starts
+    #[serde(default)]
+    #[schema(required)]
+    pub credential_ids: Vec<Uuid>,
 }
 
 #[derive(
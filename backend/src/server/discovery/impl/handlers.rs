@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     discovery::{r#impl::base::Discovery, service::DiscoveryService},
     shared::handlers::{query::DiscoveryQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Discovery {
@@ -12,3 +13,7 @@ impl CrudHandlers for Discovery {
         &state.services.discovery_service
     }
 }
+
+impl ScopedResource for Discovery {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Discovery;
+}
@@ -93,6 +93,7 @@ impl StorableEntity for Discovery {
                     daemon_id,
                     network_id,
                     tags: _, // Stored in entity_tags junction table
+                    credential_ids,
                 },
         } = self.clone();
 
@@ -106,6 +107,7 @@ impl StorableEntity for Discovery {
                 "daemon_id",
                 "run_type",
                 "discovery_type",
+                "credential_ids",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -116,6 +118,7 @@ impl StorableEntity for Discovery {
                 SqlValue::Uuid(daemon_id),
                 SqlValue::RunType(run_type),
                 SqlValue::DiscoveryType(discovery_type),
+                SqlValue::UuidArray(credential_ids),
             ],
         ))
     }
@@ -139,6 +142,7 @@ impl StorableEntity for Discovery {
                 run_type,
                 discovery_type,
                 tags: Vec::new(), // Hydrated from entity_tags junction table
+                credential_ids: row.get("credential_ids"),
             },
         })
     }
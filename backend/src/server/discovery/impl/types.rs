@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt::Display;
+use std::net::IpAddr;
 use strum::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -13,6 +14,7 @@ use crate::server::{
         Color, Icon,
         metadata::{EntityMetadataProvider, HasId, TypeMetadataProvider},
     },
+    traceroutes::r#impl::base::TracerouteProtocol,
 };
 
 #[derive(
@@ -51,6 +53,71 @@ pub enum DiscoveryType {
         #[schema(required)]
         host_naming_fallback: HostNamingFallback,
     },
+    #[schema(title = "Kubernetes")]
+    Kubernetes {
+        /// Namespaces to discover. Discovers all namespaces if not set.
+        #[serde(default)]
+        #[schema(required)]
+        namespaces: Option<Vec<String>>,
+        #[serde(default)]
+        #[schema(required)]
+        host_naming_fallback: HostNamingFallback,
+    },
+    #[schema(title = "Proxmox")]
+    Proxmox {
+        /// Base URL of the Proxmox VE API, e.g. https://pve.example.com:8006
+        api_url: String,
+        /// Accept self-signed certificates, which Proxmox VE uses out of the box
+        #[serde(default)]
+        #[schema(required)]
+        skip_tls_verify: bool,
+    },
+    #[schema(title = "SshInventory")]
+    SshInventory {
+        /// Addresses of the hosts to log into. Keys or passwords come from the discovery's
+        /// SSH key and username/password credentials, tried in order.
+        #[schema(value_type = Vec<String>)]
+        targets: Vec<IpAddr>,
+        #[serde(default = "default_ssh_port")]
+        #[schema(required)]
+        port: u16,
+    },
+    #[schema(title = "WinRm")]
+    WinRm {
+        /// Addresses of the Windows hosts to query. Accounts come from the discovery's
+        /// username/password credentials, as `DOMAIN\user` or `user@domain`, tried in order.
+        #[schema(value_type = Vec<String>)]
+        targets: Vec<IpAddr>,
+        /// Connect over HTTPS on 5986 rather than HTTP on 5985. Plain HTTP only works on hosts
+        /// that allow unencrypted WinRM traffic.
+        #[serde(default)]
+        #[schema(required)]
+        use_https: bool,
+        #[serde(default)]
+        #[schema(required)]
+        skip_tls_verify: bool,
+    },
+    #[schema(title = "Traceroute")]
+    Traceroute {
+        /// Addresses outside the network to trace, on top of each subnet's gateway
+        #[serde(default)]
+        #[schema(value_type = Vec<String>, required)]
+        external_targets: Vec<IpAddr>,
+        #[serde(default)]
+        #[schema(required)]
+        protocol: TracerouteProtocol,
+        #[serde(default = "default_max_hops")]
+        #[schema(required)]
+        max_hops: u8,
+    },
+}
+
+fn default_ssh_port() -> u16 {
+    22
+}
+
+fn default_max_hops() -> u8 {
+    30
 }
 
 impl Default for DiscoveryType {
@@ -67,6 +134,11 @@ impl Display for DiscoveryType {
             DiscoveryType::SelfReport { .. } => write!(f, "Self Report"),
             DiscoveryType::Network { .. } => write!(f, "Network Discovery"),
             DiscoveryType::Docker { .. } => write!(f, "Docker Discovery"),
+            DiscoveryType::Kubernetes { .. } => write!(f, "Kubernetes Discovery"),
+            DiscoveryType::Proxmox { .. } => write!(f, "Proxmox Discovery"),
+            DiscoveryType::SshInventory { .. } => write!(f, "SSH Inventory"),
+            DiscoveryType::WinRm { .. } => write!(f, "WinRM Inventory"),
+            DiscoveryType::Traceroute { .. } => write!(f, "Traceroute"),
         }
     }
 }
@@ -133,6 +205,21 @@ impl TypeMetadataProvider for DiscoveryType {
             DiscoveryType::Docker { .. } => {
                 "Discover Docker containers and their configurations on the daemon's host"
             }
+            DiscoveryType::Kubernetes { .. } => {
+                "Discover Kubernetes nodes, pods, services and ingresses from the cluster API"
+            }
+            DiscoveryType::Proxmox { .. } => {
+                "Discover Proxmox VE nodes, virtual machines and containers from the cluster API"
+            }
+            DiscoveryType::SshInventory { .. } => {
+                "Log into hosts over SSH to inventory interfaces, listening sockets and containers"
+            }
+            DiscoveryType::WinRm { .. } => {
+                "Query Windows hosts over WinRM for their edition, server roles, adapters and listening ports"
+            }
+            DiscoveryType::Traceroute { .. } => {
+                "Trace the routed path to each subnet's gateway and to external targets"
+            }
             DiscoveryType::Network { .. } => {
                 "Scan network subnets to discover hosts, open ports, and running services"
             }
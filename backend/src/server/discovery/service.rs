@@ -36,6 +36,7 @@ pub struct DiscoveryService {
     daemon_sessions: RwLock<HashMap<Uuid, Vec<Uuid>>>,       // daemon_id -> session_id mapping
     daemon_pull_cancellations: RwLock<HashMap<Uuid, (bool, Uuid)>>, // daemon_id -> (boolean, session_id) mapping for pull mode cancellations of current session on daemon
     session_last_updated: RwLock<HashMap<Uuid, chrono::DateTime<Utc>>>,
+    session_credentials: RwLock<HashMap<Uuid, Vec<Uuid>>>, // session_id -> credential ids not yet fetched by the daemon
     update_tx: broadcast::Sender<DiscoveryUpdatePayload>,
     scheduler: Option<Arc<RwLock<JobScheduler>>>,
     event_bus: Arc<EventBus>,
@@ -83,6 +84,7 @@ impl DiscoveryService {
             daemon_sessions: RwLock::new(HashMap::new()),
             daemon_pull_cancellations: RwLock::new(HashMap::new()),
             session_last_updated: RwLock::new(HashMap::new()),
+            session_credentials: RwLock::new(HashMap::new()),
             update_tx: tx,
             scheduler: Some(Arc::new(RwLock::new(scheduler))),
             event_bus,
@@ -100,6 +102,15 @@ impl DiscoveryService {
         self.sessions.read().await.get(session_id).cloned()
     }
 
+    /// Credential ids for a session, handed out once when the daemon starts it
+    pub async fn take_session_credentials(&self, session_id: &Uuid) -> Vec<Uuid> {
+        self.session_credentials
+            .write()
+            .await
+            .remove(session_id)
+            .unwrap_or_default()
+    }
+
     /// Get session state
     pub async fn get_all_sessions(&self, network_ids: &[Uuid]) -> Vec<DiscoveryUpdatePayload> {
         let all_sessions = self.sessions.read().await;
@@ -467,13 +478,21 @@ impl DiscoveryService {
     ) -> Result<DiscoveryUpdatePayload, anyhow::Error> {
         let session_id = Uuid::new_v4();
 
-        let session_payload = DiscoveryUpdatePayload::new(
+        let mut session_payload = DiscoveryUpdatePayload::new(
             session_id,
             discovery.base.daemon_id,
             discovery.base.network_id,
             discovery.base.discovery_type.clone(),
         );
 
+        if !discovery.base.credential_ids.is_empty() {
+            session_payload.credential_ids = discovery.base.credential_ids.clone();
+            self.session_credentials
+                .write()
+                .await
+                .insert(session_id, discovery.base.credential_ids.clone());
+        }
+
         // Add to session map
         self.sessions
             .write()
@@ -515,6 +534,7 @@ impl DiscoveryService {
                     DaemonDiscoveryRequest {
                         discovery_type: discovery.base.discovery_type,
                         session_id,
+                        credential_ids: discovery.base.credential_ids,
                     },
                     authentication,
                 )
@@ -589,6 +609,7 @@ impl DiscoveryService {
                     network_id: session.network_id,
                     name: session.discovery_type.to_string(),
                     tags: Vec::new(),
+                    credential_ids: Vec::new(),
                     discovery_type: session.discovery_type.clone(),
                     run_type: RunType::Historical {
                         results: session.clone(),
@@ -639,7 +660,7 @@ impl DiscoveryService {
                     .and_then(|next_session_id| sessions.get_mut(next_session_id))
                     .map(|next_session| {
                         next_session.phase = DiscoveryPhase::Pending;
-                        (next_session.discovery_type.clone(), next_session.session_id)
+                        DaemonDiscoveryRequest::from(next_session.clone())
                     })
             } else {
                 None
@@ -647,6 +668,10 @@ impl DiscoveryService {
 
             // Remove the completed session
             sessions.remove(&update.session_id);
+            self.session_credentials
+                .write()
+                .await
+                .remove(&update.session_id);
 
             // Drop the sessions lock before sending the request
             drop(sessions);
@@ -660,20 +685,13 @@ impl DiscoveryService {
                 .map(|d| d.base.mode == DaemonMode::Push)
                 .unwrap_or(false);
 
-            if let Some((discovery_type, session_id)) = next_session_info
+            if let Some(request) = next_session_info
                 && daemon_is_push
             {
                 tracing::debug!("Starting next session");
 
                 self.daemon_service
-                    .send_discovery_request(
-                        &daemon_id,
-                        DaemonDiscoveryRequest {
-                            discovery_type,
-                            session_id,
-                        },
-                        AuthenticatedEntity::System,
-                    )
+                    .send_discovery_request(&daemon_id, request, AuthenticatedEntity::System)
                     .await?;
             }
         }
@@ -707,6 +725,7 @@ impl DiscoveryService {
 
                 // Remove from sessions map
                 sessions.remove(&session_id);
+                self.session_credentials.write().await.remove(&session_id);
 
                 // Remove from daemon queue
                 if let Some(queue) = daemon_sessions.get_mut(&daemon_id) {
@@ -727,6 +746,7 @@ impl DiscoveryService {
                     started_at: session.started_at,
                     finished_at: Some(Utc::now()),
                     discovery_type: session.discovery_type,
+                    credential_ids: Vec::new(),
                 };
                 let _ = self.update_tx.send(cancelled_update);
 
@@ -769,6 +789,8 @@ impl DiscoveryService {
                                     let mut daemon_sessions = self.daemon_sessions.write().await;
 
                                     if let Some(session) = sessions.remove(&session_id) {
+                                        self.session_credentials.write().await.remove(&session_id);
+
                                         // Remove from daemon queue
                                         if let Some(queue) = daemon_sessions.get_mut(&daemon_id) {
                                             queue.retain(|id| *id != session_id);
@@ -785,6 +807,7 @@ impl DiscoveryService {
                                             started_at: session.started_at,
                                             finished_at: Some(Utc::now()),
                                             discovery_type: session.discovery_type.clone(),
+                                            credential_ids: Vec::new(),
                                         };
                                         let _ = self.update_tx.send(cancelled_update.clone());
 
@@ -797,6 +820,7 @@ impl DiscoveryService {
                                                 daemon_id: session.daemon_id,
                                                 network_id: session.network_id,
                                                 tags: Vec::new(),
+                                                credential_ids: Vec::new(),
                                                 name: "Discovery Run (Cancellation Failed)".to_string(),
                                                 discovery_type: session.discovery_type.clone(),
                                                 run_type: RunType::Historical {
@@ -863,6 +887,7 @@ impl DiscoveryService {
         let mut sessions = self.sessions.write().await;
         let mut daemon_sessions = self.daemon_sessions.write().await;
         let mut daemon_pull_cancellations = self.daemon_pull_cancellations.write().await;
+        let mut session_credentials = self.session_credentials.write().await;
 
         let mut to_remove = Vec::new();
         for (session_id, session) in sessions.iter() {
@@ -876,6 +901,7 @@ impl DiscoveryService {
         for session_id in to_remove {
             if let Some(session) = sessions.remove(&session_id) {
                 daemon_pull_cancellations.remove(&session.daemon_id);
+                session_credentials.remove(&session_id);
 
                 if let Some(daemon_sessions) = daemon_sessions.get_mut(&session.daemon_id) {
                     daemon_sessions.retain(|s| *s != session.session_id);
@@ -1003,6 +1029,7 @@ impl DiscoveryService {
         let mut last_updated = self.session_last_updated.write().await;
         let mut daemon_sessions = self.daemon_sessions.write().await;
         let mut daemon_pull_cancellations = self.daemon_pull_cancellations.write().await;
+        let mut session_credentials = self.session_credentials.write().await;
 
         let mut stalled_count = 0;
 
@@ -1032,6 +1059,7 @@ impl DiscoveryService {
 
                 // Remove from last_updated tracking
                 last_updated.remove(&session_id);
+                session_credentials.remove(&session_id);
 
                 // Broadcast the failed state update
                 let _ = self.update_tx.send(session.clone());
@@ -1057,6 +1085,7 @@ impl DiscoveryService {
                         daemon_id: session.daemon_id,
                         network_id: session.network_id,
                         tags: Vec::new(),
+                        credential_ids: Vec::new(),
                         name: "Discovery Run (Stalled)".to_string(),
                         discovery_type: session.discovery_type.clone(),
                         run_type: RunType::Historical { results: session },
@@ -2,7 +2,9 @@ use axum::Json;
 use axum::extract::{Path, State};
 use uuid::Uuid;
 
-use crate::server::auth::middleware::permissions::{Authorized, Member};
+use crate::server::auth::middleware::permissions::{
+    And, Authorized, IsDaemon, Member, Scope, Write,
+};
 use crate::server::config::AppState;
 use crate::server::groups::r#impl::base::Group;
 use crate::server::shared::handlers::traits::{create_handler, update_handler};
@@ -30,6 +32,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
             generated::delete
         ))
         .routes(routes!(generated::bulk_delete))
+        .routes(routes!(create_group_discovery))
 }
 
 /// Create a new group
@@ -42,11 +45,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Group created successfully", body = ApiResponse<Group>),
         (status = 400, description = "Invalid request", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Group, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_group(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Group, Write>>>,
     Json(group): Json<Group>,
 ) -> ApiResult<Json<ApiResponse<Group>>> {
     // Custom validation: Check for service bindings on different networks
@@ -68,7 +72,114 @@ async fn create_group(
     }
 
     // Delegate to generic handler (handles validation, auth checks, creation)
-    create_handler::<Group>(State(state), auth, Json(group)).await
+    create_handler::<Group>(State(state), auth.into_permission::<Member>(), Json(group)).await
+}
+
+/// Internal endpoint for daemon discovery
+///
+/// Used by daemons to report groups inferred from orchestrator metadata. A group on the
+/// daemon's network that was itself created by discovery is updated in place, so repeated
+/// runs don't produce duplicates. Groups are matched by their discovery identity when the
+/// daemon reports one, since names such as ingress paths can repeat across namespaces.
+/// Discovered groups from before identities were reported are matched by name.
+///
+/// Tagged as "internal" - included in OpenAPI spec for client generation
+/// but hidden from public documentation.
+#[utoipa::path(
+    post,
+    path = "/discovery",
+    tags = ["groups", "internal"],
+    request_body = Group,
+    responses(
+        (status = 200, description = "Group discovered/updated successfully", body = ApiResponse<Group>),
+        (status = 403, description = "Daemon cannot create groups on other networks", body = ApiErrorResponse),
+    ),
+    security(("daemon_api_key" = []))
+)]
+async fn create_group_discovery(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<IsDaemon>,
+    Json(group): Json<Group>,
+) -> ApiResult<Json<ApiResponse<Group>>> {
+    let daemon_network_id = auth
+        .network_ids()
+        .first()
+        .copied()
+        .ok_or_else(|| ApiError::forbidden("Daemon has no network assignment"))?;
+
+    if group.base.network_id != daemon_network_id {
+        return Err(ApiError::forbidden(
+            "Daemon cannot create groups on networks it's not assigned to",
+        ));
+    }
+
+    if !group.base.source.is_from_discovery() {
+        return Err(ApiError::bad_request(
+            "Groups reported by a daemon must have a discovery source",
+        ));
+    }
+
+    // Bindings must belong to the daemon's network
+    if !group.base.binding_ids.is_empty() {
+        let binding_filter = EntityFilter::unfiltered()
+            .entity_ids(&group.base.binding_ids)
+            .network_ids(&[daemon_network_id]);
+        let bindings = state
+            .services
+            .binding_service
+            .get_all(binding_filter)
+            .await?;
+        if bindings.len() != group.base.binding_ids.len() {
+            return Err(ApiError::bad_request(
+                "Group references bindings which are not on the daemon's network",
+            ));
+        }
+    }
+
+    let service = &state.services.group_service;
+
+    let existing = match group.base.source.discovery_identity() {
+        Some(identity) => {
+            let identity_filter = EntityFilter::unfiltered()
+                .network_ids(&[daemon_network_id])
+                .discovery_identity(identity);
+
+            match service.get_one(identity_filter).await? {
+                Some(existing) => Some(existing),
+                // Fall back to a same-named group that no discovery has claimed yet
+                None => {
+                    let name_filter = EntityFilter::unfiltered()
+                        .network_ids(&[daemon_network_id])
+                        .name(group.base.name.clone());
+                    service.get_all(name_filter).await?.into_iter().find(|g| {
+                        !g.base.source.is_from_discovery()
+                            || g.base.source.discovery_identity().is_none()
+                    })
+                }
+            }
+        }
+        None => {
+            let existing_filter = EntityFilter::unfiltered()
+                .network_ids(&[daemon_network_id])
+                .name(group.base.name.clone());
+            service.get_one(existing_filter).await?
+        }
+    };
+
+    let result = match existing {
+        Some(mut existing) if existing.base.source.is_from_discovery() => {
+            existing.base.binding_ids = group.base.binding_ids;
+            existing.base.group_type = group.base.group_type;
+            existing.base.description = group.base.description;
+            existing.base.source = group.base.source;
+            service.update(&mut existing, auth.into_entity()).await?
+        }
+        // Never overwrite a group a user created by hand
+        Some(existing) => existing,
+        None => service.create(group, auth.into_entity()).await?,
+    };
+
+    Ok(Json(ApiResponse::success(result)))
 }
 
 /// Update a group
@@ -83,11 +194,12 @@ async fn create_group(
         (status = 400, description = "Invalid request", body = ApiErrorResponse),
         (status = 404, description = "Group not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Group, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_group(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Group, Write>>>,
     path: Path<Uuid>,
     Json(group): Json<Group>,
 ) -> ApiResult<Json<ApiResponse<Group>>> {
@@ -110,5 +222,11 @@ async fn update_group(
     }
 
     // Delegate to generic handler (handles validation, auth checks, update)
-    update_handler::<Group>(State(state), auth, path, Json(group)).await
+    update_handler::<Group>(
+        State(state),
+        auth.into_permission::<Member>(),
+        path,
+        Json(group),
+    )
+    .await
 }
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     groups::{r#impl::base::Group, service::GroupService},
     shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Group {
@@ -12,3 +13,7 @@ impl CrudHandlers for Group {
         &state.services.group_service
     }
 }
+
+impl ScopedResource for Group {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Groups;
+}
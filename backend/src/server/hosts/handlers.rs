@@ -1,5 +1,7 @@
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
-use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Or, Viewer};
+use crate::server::auth::middleware::permissions::{
+    And, Authorized, Delete, IsDaemon, Member, Or, Read, Scope, Viewer, Write,
+};
 use crate::server::shared::entities::EntityDiscriminants;
 use crate::server::shared::extractors::Query;
 use crate::server::shared::handlers::query::FilterQueryExtractor;
@@ -26,6 +28,9 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 use uuid::Uuid;
 use validator::Validate;
 
+/// Members (API keys need `hosts:write`) or a daemon
+type HostWriter = Or<And<Member, Scope<Host, Write>>, IsDaemon>;
+
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(get_all_hosts, create_host))
@@ -48,11 +53,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     responses(
         (status = 200, description = "List of hosts with their children", body = PaginatedApiResponse<HostResponse>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Host, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_all_hosts(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Host, Read>>>,
     Query(query): Query<crate::server::shared::handlers::query::NetworkFilterQuery>,
 ) -> ApiResult<Json<PaginatedApiResponse<HostResponse>>> {
     let network_ids = auth.network_ids();
@@ -97,11 +103,12 @@ async fn get_all_hosts(
         (status = 200, description = "Host found", body = ApiResponse<HostResponse>),
         (status = 404, description = "Host not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Host, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_host_by_id(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Host, Read>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<HostResponse>>> {
     let network_ids = auth.network_ids();
@@ -152,11 +159,12 @@ async fn get_host_by_id(
         (status = 400, description = "Validation error: network not found, subnet mismatch, or invalid tags", body = ApiErrorResponse),
         (status = 401, description = "No access to the specified network", body = ApiErrorResponse),
     ),
+    extensions(("x-required-scope" = json!(Scope::<Host, Write>::name()))),
     security( ("user_api_key" = []),("session" = []), ("daemon_api_key" = []))
 )]
 async fn create_host(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Or<Member, IsDaemon>>,
+    auth: Authorized<HostWriter>,
     Json(request): Json<HostCreateRequestBody>,
 ) -> ApiResult<Json<ApiResponse<HostCreateResponse>>> {
     let network_ids = auth.network_ids();
@@ -231,6 +239,7 @@ async fn create_host(
                 interfaces,
                 ports,
                 services,
+                ..
             } = discovery_request;
 
             let host_response = host_service
@@ -272,11 +281,12 @@ async fn create_host(
         (status = 400, description = "Validation error: invalid tags", body = ApiErrorResponse),
         (status = 404, description = "Host not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Host, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_host(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Host, Write>>>,
     Path(id): Path<Uuid>,
     Json(mut request): Json<UpdateHostRequest>,
 ) -> ApiResult<Json<ApiResponse<HostResponse>>> {
@@ -351,10 +361,11 @@ async fn create_host_discovery(
     let host_service = &state.services.host_service;
 
     let DiscoveryHostRequest {
-        host,
+        mut host,
         interfaces,
         ports,
         services,
+        mac_addresses,
     } = request;
 
     // Get daemon network_id from entity
@@ -370,6 +381,19 @@ async fn create_host_discovery(
         ));
     }
 
+    // Hypervisors report guests that may have no addresses yet (powered off, no guest
+    // agent), so match them by identity before falling back to interface matching
+    if let Some(existing_host) = host_service
+        .find_matching_host_by_identity(
+            &host.base.network_id,
+            host.base.virtualization.as_ref(),
+            &mac_addresses,
+        )
+        .await?
+    {
+        host.id = existing_host.id;
+    }
+
     let host_response = host_service
         .discover_host(host, interfaces, ports, services, auth.into_entity())
         .await?;
@@ -409,11 +433,12 @@ async fn create_host_discovery(
         (status = 404, description = "One or both hosts not found", body = ApiErrorResponse),
         (status = 400, description = "Validation error: same host, has daemon, or different networks", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Host, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn consolidate_hosts(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Host, Write>>>,
     Path((destination_host_id, other_host_id)): Path<(Uuid, Uuid)>,
 ) -> ApiResult<Json<ApiResponse<HostResponse>>> {
     let network_ids = auth.network_ids();
@@ -496,11 +521,12 @@ async fn consolidate_hosts(
         (status = 404, description = "Host not found", body = ApiErrorResponse),
         (status = 409, description = "Host has associated daemon", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Host, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn delete_host(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Host, Delete>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     // Pre-validation: Can't delete a host with an associated daemon
@@ -518,7 +544,7 @@ pub async fn delete_host(
     }
 
     // Delegate to generic handler (handles auth checks, deletion)
-    delete_handler::<Host>(State(state), auth, Path(id)).await
+    delete_handler::<Host>(State(state), auth.into_permission::<Member>(), Path(id)).await
 }
 
 /// Bulk delete hosts
@@ -534,11 +560,12 @@ pub async fn delete_host(
         (status = 200, description = "Hosts deleted successfully", body = ApiResponse<BulkDeleteResponse>),
         (status = 409, description = "One or more hosts has an associated daemon - delete daemons first", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Host, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn bulk_delete_hosts(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Host, Delete>>>,
     Json(ids): Json<Vec<Uuid>>,
 ) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
     let daemon_service = &state.services.daemon_service;
@@ -551,5 +578,10 @@ pub async fn bulk_delete_hosts(
         ));
     }
 
-    bulk_delete_handler::<Host>(axum::extract::State(state), auth, axum::extract::Json(ids)).await
+    bulk_delete_handler::<Host>(
+        axum::extract::State(state),
+        auth.into_permission::<Member>(),
+        axum::extract::Json(ids),
+    )
+    .await
 }
@@ -52,6 +52,12 @@ pub struct DiscoveryHostRequest {
     pub interfaces: Vec<Interface>,
     pub ports: Vec<Port>,
     pub services: Vec<Service>,
+    /// MAC addresses known to belong to the host that have no IP address to build an
+    /// interface from, e.g. NICs of a powered-off VM read from its hypervisor config.
+    /// Only used to match the host against existing hosts.
+    #[serde(default)]
+    #[schema(value_type = Vec<String>, required)]
+    pub mac_addresses: Vec<MacAddress>,
 }
 
 // =============================================================================
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     hosts::{r#impl::base::Host, service::HostService},
     shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Host {
@@ -12,3 +13,7 @@ impl CrudHandlers for Host {
         &state.services.host_service
     }
 }
+
+impl ScopedResource for Host {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Hosts;
+}
@@ -339,6 +339,7 @@ impl LegacyHostWithServicesRequest {
             interfaces,
             ports,
             services,
+            mac_addresses: Vec::new(),
         }
     }
 }
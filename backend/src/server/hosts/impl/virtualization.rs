@@ -26,6 +26,49 @@ pub struct ProxmoxVirtualization {
     pub vm_name: Option<String>,
     pub vm_id: Option<String>,
     pub service_id: Uuid,
+    /// Cluster node the guest was last seen on
+    #[serde(default)]
+    #[schema(required)]
+    pub node: Option<String>,
+    #[serde(default)]
+    #[schema(required)]
+    pub guest_type: Option<ProxmoxGuestType>,
+    /// Power state reported by the Proxmox VE API at the last discovery
+    #[serde(default)]
+    #[schema(required)]
+    pub status: Option<ProxmoxGuestStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum ProxmoxGuestType {
+    /// QEMU/KVM virtual machine
+    Qemu,
+    /// LXC container
+    Lxc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum ProxmoxGuestStatus {
+    Running,
+    Stopped,
+    Paused,
+}
+
+impl HostVirtualization {
+    /// Whether two virtualization records describe the same guest on the same hypervisor.
+    /// VM IDs are only unique within a cluster, so the managing service or the guest name
+    /// must also agree - a guest migrated between nodes keeps its name but may be picked
+    /// up by a different node's service.
+    pub fn is_same_guest(&self, other: &HostVirtualization) -> bool {
+        match (self, other) {
+            (HostVirtualization::Proxmox(a), HostVirtualization::Proxmox(b)) => {
+                a.vm_id.is_some()
+                    && a.vm_id == b.vm_id
+                    && (a.service_id == b.service_id
+                        || (a.vm_name.is_some() && a.vm_name == b.vm_name))
+            }
+        }
+    }
 }
 
 impl HasId for HostVirtualization {
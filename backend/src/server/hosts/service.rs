@@ -8,6 +8,7 @@ use crate::server::{
             PortInput, ServiceInput, UpdateHostRequest,
         },
         base::{Host, HostBase},
+        virtualization::HostVirtualization,
     },
     interfaces::{r#impl::base::Interface, service::InterfaceService},
     ports::{r#impl::base::Port, service::PortService},
@@ -37,6 +38,7 @@ use crate::server::{
 use anyhow::{Error, Result, anyhow};
 use async_trait::async_trait;
 use chrono::Utc;
+use mac_address::MacAddress;
 use std::{collections::HashMap, sync::Arc};
 use strum::IntoDiscriminant;
 use tokio::sync::Mutex;
@@ -1137,6 +1139,51 @@ impl HostService {
         Ok(None)
     }
 
+    /// Find an existing host that is the same machine as reported by a hypervisor: the same
+    /// guest on the same virtualization service, or a host with an interface on one of the
+    /// given MAC addresses.
+    pub async fn find_matching_host_by_identity(
+        &self,
+        network_id: &Uuid,
+        virtualization: Option<&HostVirtualization>,
+        mac_addresses: &[MacAddress],
+    ) -> Result<Option<Host>> {
+        if virtualization.is_none() && mac_addresses.is_empty() {
+            return Ok(None);
+        }
+
+        let filter = EntityFilter::unfiltered().network_ids(&[*network_id]);
+        let all_hosts = self.get_all(filter).await?;
+
+        if let Some(virtualization) = virtualization
+            && let Some(host) = all_hosts.iter().find(|h| {
+                h.base
+                    .virtualization
+                    .as_ref()
+                    .is_some_and(|v| v.is_same_guest(virtualization))
+            })
+        {
+            return Ok(Some(host.clone()));
+        }
+
+        if mac_addresses.is_empty() {
+            return Ok(None);
+        }
+
+        let host_ids: Vec<Uuid> = all_hosts.iter().map(|h| h.id).collect();
+        let interfaces_by_host = self.interface_service.get_for_hosts(&host_ids).await?;
+
+        Ok(all_hosts.into_iter().find(|host| {
+            interfaces_by_host.get(&host.id).is_some_and(|interfaces| {
+                interfaces.iter().any(|i| {
+                    i.base
+                        .mac_address
+                        .is_some_and(|mac| mac_addresses.contains(&mac))
+                })
+            })
+        }))
+    }
+
     async fn get_host_lock(&self, host_id: &Uuid) -> Arc<Mutex<()>> {
         let mut locks = self.host_locks.lock().await;
         locks
@@ -1167,6 +1214,14 @@ impl HostService {
             existing_host.base.hostname = new_host_data.base.hostname;
         }
 
+        // Hypervisor discovery is authoritative for a guest's placement and power state
+        if new_host_data.base.virtualization.is_some()
+            && existing_host.base.virtualization != new_host_data.base.virtualization
+        {
+            has_updates = true;
+            existing_host.base.virtualization = new_host_data.base.virtualization;
+        }
+
         // Merge entity source metadata
         existing_host.base.source = match (existing_host.base.source, new_host_data.base.source) {
             (
@@ -1,4 +1,4 @@
-use crate::server::auth::middleware::permissions::{Authorized, Member};
+use crate::server::auth::middleware::permissions::{And, Authorized, Delete, Member, Scope, Write};
 use crate::server::config::AppState;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::shared::handlers::traits::{BulkDeleteResponse, create_handler, update_handler};
@@ -90,11 +90,12 @@ async fn validate_interface_consistency(
         (status = 200, description = "Interface created successfully", body = ApiResponse<Interface>),
         (status = 400, description = "Network mismatch or invalid request", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Interface, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_interface(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Interface, Write>>>,
     Json(mut interface): Json<Interface>,
 ) -> ApiResult<Json<ApiResponse<Interface>>> {
     validate_interface_consistency(&state, &interface).await?;
@@ -108,7 +109,12 @@ async fn create_interface(
         .map_err(|e| ApiError::internal_error(&e.to_string()))?;
     interface.base.position = next_position;
 
-    create_handler::<Interface>(State(state), auth, Json(interface)).await
+    create_handler::<Interface>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Json(interface),
+    )
+    .await
 }
 
 /// Update an interface
@@ -124,11 +130,12 @@ async fn create_interface(
         (status = 400, description = "Network mismatch or invalid request", body = ApiErrorResponse),
         (status = 404, description = "Interface not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Interface, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_interface(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Interface, Write>>>,
     path: Path<Uuid>,
     Json(interface): Json<Interface>,
 ) -> ApiResult<Json<ApiResponse<Interface>>> {
@@ -141,7 +148,13 @@ async fn update_interface(
         .validate_position_for_update(&path, &interface.base.host_id, interface.base.position)
         .await?;
 
-    update_handler::<Interface>(State(state), auth, path, Json(interface)).await
+    update_handler::<Interface>(
+        State(state),
+        auth.into_permission::<Member>(),
+        path,
+        Json(interface),
+    )
+    .await
 }
 
 /// Delete an interface
@@ -155,11 +168,12 @@ async fn update_interface(
         (status = 200, description = "Interface deleted successfully", body = EmptyApiResponse),
         (status = 404, description = "Interface not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Interface, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn delete_interface(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Interface, Delete>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     let network_ids = auth.network_ids();
@@ -212,11 +226,12 @@ async fn delete_interface(
         (status = 200, description = "Interfaces deleted successfully", body = ApiResponse<BulkDeleteResponse>),
         (status = 400, description = "No IDs provided", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Interface, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn bulk_delete_interfaces(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Interface, Delete>>>,
     Json(ids): Json<Vec<Uuid>>,
 ) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
     if ids.is_empty() {
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     interfaces::{r#impl::base::Interface, service::InterfaceService},
     shared::handlers::{query::InterfaceQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Interface {
@@ -12,3 +13,7 @@ impl CrudHandlers for Interface {
         &state.services.interface_service
     }
 }
+
+impl ScopedResource for Interface {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Interfaces;
+}
@@ -2,7 +2,9 @@ use crate::server::auth::middleware::auth::AuthenticatedEntity;
 use crate::server::auth::middleware::features::{
     BlockedInDemoMode, InviteUsersFeature, RequireFeature,
 };
-use crate::server::auth::middleware::permissions::{Admin, Authorized};
+use crate::server::auth::middleware::permissions::{
+    Admin, And, Authorized, Delete, Read, Scope, Write,
+};
 use crate::server::config::AppState;
 use crate::server::invites::r#impl::base::Invite;
 use crate::server::organizations::r#impl::api::CreateInviteRequest;
@@ -41,11 +43,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Invite created", body = ApiResponse<Invite>),
         (status = 403, description = "Cannot create invite with higher permissions", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Invite, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_invite(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Invite, Write>>>,
     RequireFeature { plan, .. }: RequireFeature<InviteUsersFeature>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     Json(request): Json<CreateInviteRequest>,
@@ -174,11 +177,12 @@ async fn create_invite(
         (status = 400, description = "Invalid or expired invite", body = ApiErrorResponse),
         (status = 403, description = "Access denied", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Invite, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_invite(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Invite, Read>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<Invite>>> {
     let organization_id = auth
@@ -210,11 +214,12 @@ async fn get_invite(
     responses(
         (status = 200, description = "List of active invites", body = ApiResponse<Vec<Invite>>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Invite, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_invites(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Invite, Read>>>,
 ) -> ApiResult<Json<ApiResponse<Vec<Invite>>>> {
     let organization_id = auth
         .organization_id()
@@ -258,11 +263,12 @@ async fn get_invites(
         (status = 400, description = "Invalid invite", body = ApiErrorResponse),
         (status = 403, description = "Cannot revoke this invite", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Invite, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn revoke_invite(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Invite, Delete>>>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
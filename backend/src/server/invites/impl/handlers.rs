@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     invites::{r#impl::base::Invite, service::InviteService},
     shared::handlers::{query::NoFilterQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Invite {
@@ -12,3 +13,7 @@ impl CrudHandlers for Invite {
         &state.services.invite_service
     }
 }
+
+impl ScopedResource for Invite {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Invites;
+}
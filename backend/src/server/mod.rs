@@ -2,6 +2,7 @@ pub mod auth;
 pub mod billing;
 pub mod bindings;
 pub mod config;
+pub mod credentials;
 pub mod daemon_api_keys;
 pub mod daemons;
 pub mod discovery;
@@ -13,15 +14,19 @@ pub mod hosts;
 pub mod interfaces;
 pub mod invites;
 pub mod logging;
+pub mod network_links;
 pub mod networks;
 pub mod openapi;
 pub mod organizations;
 pub mod ports;
+pub mod scim;
 pub mod services;
 pub mod shared;
 pub mod shares;
+pub mod snapshots;
 pub mod subnets;
 pub mod tags;
 pub mod topology;
+pub mod traceroutes;
 pub mod user_api_keys;
 pub mod users;
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::permissions::{And, Authorized, Member, Scope, Write},
+    config::AppState,
+    network_links::r#impl::base::NetworkLink,
+    shared::{
+        handlers::traits::{CrudHandlers, create_handler, update_handler},
+        services::traits::CrudService,
+        types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult},
+    },
+};
+
+// Generated handlers for generic CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_get_all_handler!(NetworkLink, "network_links", "network_link");
+    crate::crud_get_by_id_handler!(NetworkLink, "network_links", "network_link");
+    crate::crud_delete_handler!(NetworkLink, "network_links", "network_link");
+    crate::crud_bulk_delete_handler!(NetworkLink, "network_links");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(generated::get_all, create_network_link))
+        .routes(routes!(
+            generated::get_by_id,
+            update_network_link,
+            generated::delete
+        ))
+        .routes(routes!(generated::bulk_delete))
+}
+
+/// The generic handlers check access to `network_id`; the other end needs checking too
+fn validate_target_network(link: &NetworkLink, network_ids: &[Uuid]) -> Result<(), ApiError> {
+    if link.base.target_network_id == link.base.network_id {
+        return Err(ApiError::bad_request(
+            "A network link must connect two different networks",
+        ));
+    }
+
+    if !network_ids.contains(&link.base.target_network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to the target network",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Create a network link
+///
+/// Connects two networks in the organization topology. You need access to both networks.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "network_links",
+    request_body = NetworkLink,
+    responses(
+        (status = 200, description = "Network link created", body = ApiResponse<NetworkLink>),
+        (status = 400, description = "Both ends are the same network", body = ApiErrorResponse),
+        (status = 403, description = "No access to one of the networks", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<NetworkLink, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_network_link(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<NetworkLink, Write>>>,
+    Json(link): Json<NetworkLink>,
+) -> ApiResult<Json<ApiResponse<NetworkLink>>> {
+    validate_target_network(&link, &auth.network_ids())?;
+
+    create_handler::<NetworkLink>(State(state), auth.into_permission::<Member>(), Json(link)).await
+}
+
+/// Update a network link
+///
+/// You need access to both networks the link connects.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "network_links",
+    params(("id" = Uuid, Path, description = "NetworkLink ID")),
+    request_body = NetworkLink,
+    responses(
+        (status = 200, description = "Network link updated", body = ApiResponse<NetworkLink>),
+        (status = 400, description = "Both ends are the same network", body = ApiErrorResponse),
+        (status = 403, description = "No access to one of the networks", body = ApiErrorResponse),
+        (status = 404, description = "Network link not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<NetworkLink, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_network_link(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<NetworkLink, Write>>>,
+    Path(id): Path<Uuid>,
+    Json(link): Json<NetworkLink>,
+) -> ApiResult<Json<ApiResponse<NetworkLink>>> {
+    let network_ids = auth.network_ids();
+
+    let existing = NetworkLink::get_service(&state)
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("NetworkLink '{}' not found", id)))?;
+
+    validate_target_network(&existing, &network_ids)?;
+    validate_target_network(&link, &network_ids)?;
+
+    update_handler::<NetworkLink>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(link),
+    )
+    .await
+}
@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use crate::server::shared::{
+    entities::ChangeTriggersTopologyStaleness, types::api::deserialize_empty_string_as_none,
+};
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// A connection between two networks that discovery can't see, such as a site-to-site link
+/// or a peering arrangement. Drawn between the two networks in the organization topology.
+#[derive(
+    Debug, Clone, Validate, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema,
+)]
+pub struct NetworkLinkBase {
+    /// Network the link starts from
+    pub network_id: Uuid,
+    /// Network the link goes to. Must be a different network in the same organization.
+    pub target_network_id: Uuid,
+    #[validate(length(max = 100, message = "Link label must be at most 100 characters"))]
+    #[serde(default, deserialize_with = "deserialize_empty_string_as_none")]
+    pub label: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_empty_string_as_none")]
+    pub description: Option<String>,
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema, Validate,
+)]
+pub struct NetworkLink {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: NetworkLinkBase,
+}
+
+// Links only appear in the organization topology, which is built on request
+impl ChangeTriggersTopologyStaleness<NetworkLink> for NetworkLink {
+    fn triggers_staleness(&self, _other: Option<NetworkLink>) -> bool {
+        false
+    }
+}
+
+impl Display for NetworkLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "NetworkLink {}: {} -> {}",
+            self.id, self.base.network_id, self.base.target_network_id
+        )
+    }
+}
@@ -0,0 +1,20 @@
+use crate::server::{
+    config::AppState,
+    network_links::{r#impl::base::NetworkLink, service::NetworkLinkService},
+    shared::handlers::{query::NetworkLinksQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
+};
+
+impl CrudHandlers for NetworkLink {
+    type Service = NetworkLinkService;
+    type FilterQuery = NetworkLinksQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.network_link_service
+    }
+}
+
+// Links are part of how networks are described, so they share the networks scope
+impl ScopedResource for NetworkLink {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Networks;
+}
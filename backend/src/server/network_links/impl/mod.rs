@@ -0,0 +1,3 @@
+pub mod base;
+pub mod handlers;
+pub mod storage;
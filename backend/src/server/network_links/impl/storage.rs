@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    network_links::r#impl::base::{NetworkLink, NetworkLinkBase},
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{SqlValue, StorableEntity},
+    },
+};
+
+impl StorableEntity for NetworkLink {
+    type BaseData = NetworkLinkBase;
+
+    fn table_name() -> &'static str {
+        "network_links"
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::NetworkLink
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    network_id,
+                    target_network_id,
+                    label,
+                    description,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "network_id",
+                "target_network_id",
+                "label",
+                "description",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(network_id),
+                SqlValue::Uuid(target_network_id),
+                SqlValue::OptionalString(label),
+                SqlValue::OptionalString(description),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        Ok(NetworkLink {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: NetworkLinkBase {
+                network_id: row.get("network_id"),
+                target_network_id: row.get("target_network_id"),
+                label: row.get("label"),
+                description: row.get("description"),
+            },
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::server::{
+    network_links::r#impl::base::NetworkLink,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::generic::GenericPostgresStorage,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct NetworkLinkService {
+    storage: Arc<GenericPostgresStorage<NetworkLink>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<NetworkLink> for NetworkLinkService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &NetworkLink) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+    fn get_organization_id(&self, _entity: &NetworkLink) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<NetworkLink> for NetworkLinkService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<NetworkLink>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::shared::services::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl NetworkLinkService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<NetworkLink>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+}
@@ -5,7 +5,7 @@ use crate::server::shared::handlers::traits::{
 use crate::server::{
     auth::middleware::{
         features::{CreateNetworkFeature, RequireFeature},
-        permissions::{Admin, Authorized, Member},
+        permissions::{Admin, And, Authorized, Delete, Member, Scope, Write},
     },
     shared::types::api::{ApiErrorResponse, EmptyApiResponse},
 };
@@ -46,11 +46,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     responses(
         (status = 200, description = "Network created", body = ApiResponse<Network>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Network, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_network(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Network, Write>>>,
     RequireFeature { .. }: RequireFeature<CreateNetworkFeature>,
     Json(network): Json<Network>,
 ) -> ApiResult<Json<ApiResponse<Network>>> {
@@ -84,11 +85,12 @@ async fn create_network(
         (status = 404, description = "Network not found", body = ApiErrorResponse),
         (status = 403, description = "User not admin", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Network, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_network(
     state: State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Network, Write>>>,
     path: Path<Uuid>,
     json: Json<Network>,
 ) -> ApiResult<Json<ApiResponse<Network>>> {
@@ -106,11 +108,12 @@ async fn update_network(
         (status = 404, description = "Network not found", body = ApiErrorResponse),
         (status = 403, description = "User not admin", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Network, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn delete_network(
     state: State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Network, Delete>>>,
     path: Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     delete_handler::<Network>(state, auth.into_permission::<Member>(), path).await
@@ -126,11 +129,12 @@ async fn delete_network(
         (status = 200, description = "Networks deleted successfully", body = ApiResponse<BulkDeleteResponse>),
         (status = 403, description = "User not admin", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Network, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn bulk_delete_networks(
     state: State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Network, Delete>>>,
     json: Json<Vec<Uuid>>,
 ) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
     bulk_delete_handler::<Network>(state, auth.into_permission::<Member>(), json).await
@@ -7,6 +7,7 @@ use crate::server::{
         entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
         handlers::{query::NoFilterQuery, traits::CrudHandlers},
     },
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -74,6 +75,10 @@ impl CrudHandlers for Network {
     }
 }
 
+impl ScopedResource for Network {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Networks;
+}
+
 impl ChangeTriggersTopologyStaleness<Network> for Network {
     fn triggers_staleness(&self, _other: Option<Network>) -> bool {
         false
@@ -141,6 +141,7 @@ Resources are scoped to your **organization** and **network(s)**:
         (name = "api_keys", description = "API keys for daemon authentication. Create and manage keys that allow daemons to communicate with the server."),
         (name = "auth", description = "Authentication and session management. Handle user login, logout, and session state."),
         (name = "config", description = "Server configuration. Public configuration settings for client applications."),
+        (name = "credentials", description = "Discovery credentials. Store SNMP communities, API tokens, SSH keys and passwords, encrypted at rest, and attach them to discoveries."),
         (name = "daemon_api_keys", description = "Daemon API keys for scanner authentication. Create and manage keys that allow daemons to authenticate with the server and submit discovery results."),
         (name = "discoveries", description = "Network discovery operations. Trigger and monitor scans that detect hosts, services, and network topology."),
         (name = "github", description = "GitHub integration endpoints."),
@@ -159,13 +160,17 @@ Resources are scoped to your **organization** and **network(s)**:
         (name = "internal", description = "Internal endpoints for system operations. Not part of the public API."),
         (name = "invites", description = "Organization invitations. Invite users to join your organization."),
         (name = "metadata", description = "Entity metadata registry. Schema information for all entity types in the system."),
+        (name = "network_links", description = "Links between networks. Connects networks in the organization topology where discovery can't see the connection."),
         (name = "networks", description = "Network containers. Top-level organizational unit that contains subnets, hosts, and other entities."),
         (name = "organizations", description = "Manage organization settings."),
+        (name = "scim", description = "SCIM provisioning. Manage the token identity providers use to provision users and groups, and map provisioned groups onto roles and network access."),
         (name = "services", description = "Services running on hosts. Detected or manually added services like databases, web servers, etc."),
         (name = "shares", description = "Shared network views. Create read-only shareable links to your network topology."),
+        (name = "snapshots", description = "Topology snapshots. Capture a topology at a point in time and compare snapshots to see what changed."),
         (name = "subnets", description = "IP subnets within networks. Define address ranges and organize hosts by subnet."),
         (name = "system", description = "System information endpoints. Version and compatibility checking."),
         (name = "tags", description = "Custom tags for categorization. Apply labels to entities for filtering and organization."),
+        (name = "traceroutes", description = "Paths measured by traceroute. Daemons trace the route to each subnet's gateway and to external targets during traceroute discovery."),
         (name = "user_api_keys", description = "User API keys for programmatic access. Create and manage personal API keys with scoped permissions for automation and integrations."),
         (name = "users", description = "User account management. Manage user profiles and permissions within organizations."),
     )
@@ -4,6 +4,7 @@ use crate::server::auth::middleware::permissions::{Authorized, IsUser, Member, O
 use crate::server::auth::service::hash_password;
 use crate::server::billing::types::base::BillingPlan;
 use crate::server::config::AppState;
+use crate::server::organizations::r#impl::api::UpdateMfaPolicyRequest;
 use crate::server::organizations::r#impl::base::Organization;
 use crate::server::shared::handlers::traits::{CrudHandlers, update_handler};
 use crate::server::shared::services::traits::CrudService;
@@ -26,6 +27,7 @@ use uuid::Uuid;
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(get_organization, update_org_name))
+        .routes(routes!(update_mfa_policy))
         .routes(routes!(reset))
         .routes(routes!(populate_demo_data))
 }
@@ -97,6 +99,45 @@ pub async fn update_org_name(
     .await
 }
 
+/// Require a second factor for all members' password logins
+#[utoipa::path(
+    put,
+    path = "/{id}/mfa-policy",
+    tag = "organizations",
+    params(("id" = Uuid, Path, description = "Organization ID")),
+    request_body = UpdateMfaPolicyRequest,
+    responses(
+        (status = 200, description = "MFA policy updated", body = ApiResponse<Organization>),
+        (status = 403, description = "Only owners can change the MFA policy", body = ApiErrorResponse),
+        (status = 404, description = "Organization not found", body = ApiErrorResponse),
+    ),
+     security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn update_mfa_policy(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Owner>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateMfaPolicyRequest>,
+) -> ApiResult<Json<ApiResponse<Organization>>> {
+    let mut org = state
+        .services
+        .organization_service
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| anyhow!("Could not find org"))?;
+
+    org.base.require_mfa = request.require_mfa;
+
+    update_handler::<Organization>(
+        axum::extract::State(state),
+        auth.into_permission::<Member>(),
+        axum::extract::Path(id),
+        axum::extract::Json(org),
+    )
+    .await
+}
+
 /// Reset all organization data (delete all entities except organization and owner user)
 #[utoipa::path(
     post,
@@ -12,3 +12,8 @@ pub struct CreateInviteRequest {
     #[schema(value_type = Option<String>)]
     pub send_to: Option<EmailAddress>,
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateMfaPolicyRequest {
+    pub require_mfa: bool,
+}
@@ -27,6 +27,10 @@ pub struct OrganizationBase {
     pub plan_status: Option<String>,
     #[schema(read_only, required)]
     pub onboarding: Vec<TelemetryOperation>,
+    /// Require members to complete a second factor when logging in with a password
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub require_mfa: bool,
 }
 
 #[derive(
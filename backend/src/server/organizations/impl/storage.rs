@@ -92,6 +92,7 @@ impl StorableEntity for Organization {
                     plan,
                     plan_status,
                     onboarding,
+                    require_mfa,
                 },
         } = self.clone();
 
@@ -105,6 +106,7 @@ impl StorableEntity for Organization {
                 "plan",
                 "plan_status",
                 "onboarding",
+                "require_mfa",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -115,6 +117,7 @@ impl StorableEntity for Organization {
                 SqlValue::OptionBillingPlan(plan),
                 SqlValue::OptionalString(plan_status),
                 SqlValue::TelemetryOperation(onboarding),
+                SqlValue::Bool(require_mfa),
             ],
         ))
     }
@@ -139,6 +142,7 @@ impl StorableEntity for Organization {
                 plan,
                 plan_status: row.get("plan_status"),
                 onboarding,
+                require_mfa: row.get("require_mfa"),
             },
         })
     }
@@ -4,13 +4,14 @@ use std::sync::Arc;
 use utoipa_axum::{router::OpenApiRouter, routes};
 use uuid::Uuid;
 
-use crate::server::auth::middleware::permissions::{Authorized, Member};
+use crate::server::auth::middleware::permissions::{And, Authorized, Member, Scope, Write};
 use crate::server::config::AppState;
 use crate::server::ports::{r#impl::base::Port, service::PortService};
 use crate::server::shared::handlers::query::HostChildQuery;
 use crate::server::shared::handlers::traits::{CrudHandlers, create_handler, update_handler};
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult};
+use crate::server::user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource};
 
 impl CrudHandlers for Port {
     type Service = PortService;
@@ -21,6 +22,10 @@ impl CrudHandlers for Port {
     }
 }
 
+impl ScopedResource for Port {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Ports;
+}
+
 mod generated {
     use super::*;
     crate::crud_get_all_handler!(Port, "ports", "port");
@@ -68,15 +73,16 @@ async fn validate_port_network_consistency(state: &AppState, port: &Port) -> Res
         (status = 200, description = "Port created successfully", body = ApiResponse<Port>),
         (status = 400, description = "Network mismatch or duplicate port", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Port, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_port(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Port, Write>>>,
     Json(port): Json<Port>,
 ) -> ApiResult<Json<ApiResponse<Port>>> {
     validate_port_network_consistency(&state, &port).await?;
-    create_handler::<Port>(State(state), auth, Json(port)).await
+    create_handler::<Port>(State(state), auth.into_permission::<Member>(), Json(port)).await
 }
 
 /// Update a port
@@ -91,14 +97,21 @@ async fn create_port(
         (status = 400, description = "Network mismatch or invalid request", body = ApiErrorResponse),
         (status = 404, description = "Port not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Port, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_port(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Port, Write>>>,
     path: Path<Uuid>,
     Json(port): Json<Port>,
 ) -> ApiResult<Json<ApiResponse<Port>>> {
     validate_port_network_consistency(&state, &port).await?;
-    update_handler::<Port>(State(state), auth, path, Json(port)).await
+    update_handler::<Port>(
+        State(state),
+        auth.into_permission::<Member>(),
+        path,
+        Json(port),
+    )
+    .await
 }
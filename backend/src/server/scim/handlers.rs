@@ -0,0 +1,396 @@
+use crate::server::auth::middleware::features::{BlockedInDemoMode, RequireFeature};
+use crate::server::auth::middleware::permissions::{Authorized, Owner};
+use crate::server::config::AppState;
+use crate::server::scim::r#impl::api::{
+    SERVICE_PROVIDER_CONFIG_SCHEMA, ScimError, ScimGroupResource, ScimJson, ScimListQuery,
+    ScimPatchRequest, ScimSettingsResponse, ScimTokenResponse, ScimUser,
+    UpdateScimGroupMappingRequest,
+};
+use crate::server::scim::r#impl::base::ScimGroup;
+use crate::server::shared::api_key_common::ApiKeyType;
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, ApiResponse, ApiResult, EmptyApiResponse,
+};
+use axum::extract::{FromRequestParts, Path, Query, State};
+use axum::http::{StatusCode, request::Parts};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use std::sync::Arc;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+// ============================================================================
+// Management API (/api/v1/scim)
+// ============================================================================
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_scim_settings))
+        .routes(routes!(rotate_scim_token, revoke_scim_token))
+        .routes(routes!(update_scim_group_mapping))
+}
+
+/// Get SCIM provisioning settings
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "scim",
+    responses(
+        (status = 200, description = "SCIM settings", body = ApiResponse<ScimSettingsResponse>),
+        (status = 403, description = "Only owners can manage SCIM provisioning", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn get_scim_settings(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Owner>,
+) -> ApiResult<Json<ApiResponse<ScimSettingsResponse>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let service = &state.services.scim_service;
+    let token = service.get_token(&organization_id).await?;
+    let groups = service.get_groups(&organization_id).await?;
+
+    Ok(Json(ApiResponse::success(ScimSettingsResponse {
+        enabled: token.is_some(),
+        base_url: service.base_url(),
+        token_created_at: token.as_ref().map(|t| t.created_at),
+        token_last_used: token.and_then(|t| t.base.last_used),
+        groups,
+    })))
+}
+
+/// Generate a SCIM token, replacing any existing one
+///
+/// The token is only returned once.
+#[utoipa::path(
+    post,
+    path = "/token",
+    tag = "scim",
+    responses(
+        (status = 200, description = "Token generated", body = ApiResponse<ScimTokenResponse>),
+        (status = 403, description = "Only owners can manage SCIM provisioning", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn rotate_scim_token(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Owner>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+) -> ApiResult<Json<ApiResponse<ScimTokenResponse>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let service = &state.services.scim_service;
+    let token = service.rotate_token(&organization_id).await?;
+
+    Ok(Json(ApiResponse::success(ScimTokenResponse {
+        token,
+        base_url: service.base_url(),
+    })))
+}
+
+/// Revoke the SCIM token, disabling provisioning
+#[utoipa::path(
+    delete,
+    path = "/token",
+    tag = "scim",
+    responses(
+        (status = 200, description = "Token revoked", body = EmptyApiResponse),
+        (status = 403, description = "Only owners can manage SCIM provisioning", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn revoke_scim_token(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Owner>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    state
+        .services
+        .scim_service
+        .revoke_token(&organization_id)
+        .await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// Map a SCIM group onto a role and network access
+///
+/// Members of mapped groups get the highest role across their groups and the union
+/// of their networks.
+#[utoipa::path(
+    put,
+    path = "/groups/{id}",
+    tag = "scim",
+    params(("id" = Uuid, Path, description = "SCIM group ID")),
+    request_body = UpdateScimGroupMappingRequest,
+    responses(
+        (status = 200, description = "Group mapping updated", body = ApiResponse<ScimGroup>),
+        (status = 403, description = "Only owners can manage SCIM provisioning", body = ApiErrorResponse),
+        (status = 404, description = "Group not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn update_scim_group_mapping(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Owner>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateScimGroupMappingRequest>,
+) -> ApiResult<Json<ApiResponse<ScimGroup>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let group = state
+        .services
+        .scim_service
+        .update_group_mapping(&organization_id, &id, request)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("SCIM group '{}' not found", id)))?;
+
+    Ok(Json(ApiResponse::success(group)))
+}
+
+// ============================================================================
+// SCIM 2.0 protocol (/api/scim/v2)
+// ============================================================================
+
+/// Organization authenticated by a SCIM bearer token
+pub struct ScimOrganization(pub Uuid);
+
+impl<S> FromRequestParts<S> for ScimOrganization
+where
+    S: Send + Sync + AsRef<AppState>,
+{
+    type Rejection = ScimError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .filter(|token| ApiKeyType::from_key(token).0 == ApiKeyType::Scim)
+            .ok_or_else(|| ScimError::unauthorized("A SCIM bearer token is required"))?;
+
+        state
+            .as_ref()
+            .services
+            .scim_service
+            .authenticate(token)
+            .await?
+            .map(ScimOrganization)
+            .ok_or_else(|| ScimError::unauthorized("Invalid SCIM token"))
+    }
+}
+
+/// Routes implementing the SCIM 2.0 protocol. These speak SCIM's own JSON format
+/// rather than the Scanopy API envelope, so they aren't part of the OpenAPI spec.
+pub fn create_protocol_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/ServiceProviderConfig", get(service_provider_config))
+        .route("/Users", get(list_users).post(create_user))
+        .route(
+            "/Users/{id}",
+            get(get_user)
+                .put(replace_user)
+                .patch(patch_user)
+                .delete(delete_user),
+        )
+        .route("/Groups", get(list_groups).post(create_group))
+        .route(
+            "/Groups/{id}",
+            get(get_group)
+                .put(replace_group)
+                .patch(patch_group)
+                .delete(delete_group),
+        )
+}
+
+async fn service_provider_config() -> impl IntoResponse {
+    ScimJson(serde_json::json!({
+        "schemas": [SERVICE_PROVIDER_CONFIG_SCHEMA],
+        "patch": { "supported": true },
+        "bulk": { "supported": false, "maxOperations": 0, "maxPayloadSize": 0 },
+        "filter": { "supported": true, "maxResults": 1000 },
+        "changePassword": { "supported": false },
+        "sort": { "supported": false },
+        "etag": { "supported": false },
+        "authenticationSchemes": [{
+            "type": "oauthbearertoken",
+            "name": "Bearer Token",
+            "description": "SCIM token generated in organization settings",
+            "primary": true
+        }]
+    }))
+}
+
+async fn list_users(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Query(query): Query<ScimListQuery>,
+) -> Result<impl IntoResponse, ScimError> {
+    let users = state
+        .services
+        .scim_service
+        .list_users(&organization_id, &query)
+        .await?;
+    Ok(ScimJson(users))
+}
+
+async fn get_user(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ScimError> {
+    let user = state
+        .services
+        .scim_service
+        .get_user(&organization_id, &id)
+        .await?;
+    Ok(ScimJson(user))
+}
+
+async fn create_user(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    ScimJson(request): ScimJson<ScimUser>,
+) -> Result<impl IntoResponse, ScimError> {
+    let user = state
+        .services
+        .scim_service
+        .create_user(&organization_id, request)
+        .await?;
+    Ok((StatusCode::CREATED, ScimJson(user)))
+}
+
+async fn replace_user(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+    ScimJson(request): ScimJson<ScimUser>,
+) -> Result<impl IntoResponse, ScimError> {
+    let user = state
+        .services
+        .scim_service
+        .replace_user(&organization_id, &id, request)
+        .await?;
+    Ok(ScimJson(user))
+}
+
+async fn patch_user(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+    ScimJson(request): ScimJson<ScimPatchRequest>,
+) -> Result<impl IntoResponse, ScimError> {
+    let user = state
+        .services
+        .scim_service
+        .patch_user(&organization_id, &id, &request.operations)
+        .await?;
+    Ok(ScimJson(user))
+}
+
+async fn delete_user(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ScimError> {
+    state
+        .services
+        .scim_service
+        .delete_user(&organization_id, &id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn list_groups(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Query(query): Query<ScimListQuery>,
+) -> Result<impl IntoResponse, ScimError> {
+    let groups = state
+        .services
+        .scim_service
+        .list_groups(&organization_id, &query)
+        .await?;
+    Ok(ScimJson(groups))
+}
+
+async fn get_group(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ScimError> {
+    let group = state
+        .services
+        .scim_service
+        .get_group(&organization_id, &id)
+        .await?;
+    Ok(ScimJson(group))
+}
+
+async fn create_group(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    ScimJson(request): ScimJson<ScimGroupResource>,
+) -> Result<impl IntoResponse, ScimError> {
+    let group = state
+        .services
+        .scim_service
+        .create_group(&organization_id, request)
+        .await?;
+    Ok((StatusCode::CREATED, ScimJson(group)))
+}
+
+async fn replace_group(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+    ScimJson(request): ScimJson<ScimGroupResource>,
+) -> Result<impl IntoResponse, ScimError> {
+    let group = state
+        .services
+        .scim_service
+        .replace_group(&organization_id, &id, request)
+        .await?;
+    Ok(ScimJson(group))
+}
+
+async fn patch_group(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+    ScimJson(request): ScimJson<ScimPatchRequest>,
+) -> Result<impl IntoResponse, ScimError> {
+    let group = state
+        .services
+        .scim_service
+        .patch_group(&organization_id, &id, &request.operations)
+        .await?;
+    Ok(ScimJson(group))
+}
+
+async fn delete_group(
+    State(state): State<Arc<AppState>>,
+    ScimOrganization(organization_id): ScimOrganization,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ScimError> {
+    state
+        .services
+        .scim_service
+        .delete_group(&organization_id, &id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
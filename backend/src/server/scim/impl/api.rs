@@ -0,0 +1,299 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Request, rejection::JsonRejection},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    scim::r#impl::base::ScimGroup, users::r#impl::permissions::UserOrgPermissions,
+};
+
+pub const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+pub const SERVICE_PROVIDER_CONFIG_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+
+const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+// ============================================================================
+// SCIM protocol resources (RFC 7643 / RFC 7644)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: String,
+    pub created: DateTime<Utc>,
+    pub last_modified: DateTime<Utc>,
+    pub location: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimEmail {
+    pub value: String,
+    #[serde(default)]
+    pub primary: bool,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+/// Reference to a user (in a group's members) or a group (in a user's groups)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimReference {
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+    #[serde(rename = "$ref", default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUser {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    pub user_name: String,
+    #[serde(default)]
+    pub emails: Vec<ScimEmail>,
+    #[serde(default = "default_active")]
+    pub active: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<ScimReference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ScimMeta>,
+}
+
+impl ScimUser {
+    /// The address to use as the Scanopy login: the primary email, else the first
+    /// email, else userName (which IdPs usually set to the email or UPN)
+    pub fn email(&self) -> &str {
+        self.emails
+            .iter()
+            .find(|e| e.primary)
+            .or_else(|| self.emails.first())
+            .map(|e| e.value.as_str())
+            .unwrap_or(&self.user_name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResource {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    pub display_name: String,
+    #[serde(default)]
+    pub members: Vec<ScimReference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ScimMeta>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T> {
+    pub schemas: Vec<String>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+impl<T> ScimListResponse<T> {
+    /// Page through already-filtered resources. SCIM's startIndex is 1-based.
+    pub fn paginate(resources: Vec<T>, query: &ScimListQuery) -> Self {
+        let total_results = resources.len();
+        let start_index = query.start_index.unwrap_or(1).max(1);
+        let count = query.count.unwrap_or(100).min(1000);
+
+        let resources: Vec<T> = resources
+            .into_iter()
+            .skip(start_index - 1)
+            .take(count)
+            .collect();
+
+        Self {
+            schemas: vec![LIST_RESPONSE_SCHEMA.to_string()],
+            total_results,
+            start_index,
+            items_per_page: resources.len(),
+            resources,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    pub filter: Option<String>,
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimPatchRequest {
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimPatchOperation {
+    /// add, remove or replace. Some IdPs capitalize it.
+    pub op: String,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+// ============================================================================
+// Errors and content type
+// ============================================================================
+
+/// Error in the SCIM error response format (RFC 7644 section 3.12)
+#[derive(Debug, Clone)]
+pub struct ScimError {
+    pub status: StatusCode,
+    pub scim_type: Option<&'static str>,
+    pub detail: String,
+}
+
+impl ScimError {
+    pub fn bad_request(scim_type: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            scim_type: Some(scim_type),
+            detail: detail.into(),
+        }
+    }
+
+    pub fn unauthorized(detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            scim_type: None,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn not_found(detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            scim_type: None,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn conflict(detail: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::CONFLICT,
+            scim_type: Some("uniqueness"),
+            detail: detail.into(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ScimError {
+    fn from(err: anyhow::Error) -> Self {
+        tracing::error!("SCIM internal error: {}", err);
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            scim_type: None,
+            detail: "Internal server error".to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ScimError {
+    fn into_response(self) -> Response {
+        let mut body = serde_json::json!({
+            "schemas": [ERROR_SCHEMA],
+            "status": self.status.as_u16().to_string(),
+            "detail": self.detail,
+        });
+        if let Some(scim_type) = self.scim_type {
+            body["scimType"] = serde_json::Value::String(scim_type.to_string());
+        }
+
+        (self.status, ScimJson(body)).into_response()
+    }
+}
+
+/// JSON body served and accepted as `application/scim+json`
+pub struct ScimJson<T>(pub T);
+
+impl<T: Serialize> IntoResponse for ScimJson<T> {
+    fn into_response(self) -> Response {
+        let mut response = Json(self.0).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(SCIM_CONTENT_TYPE),
+        );
+        response
+    }
+}
+
+impl<S, T> FromRequest<S> for ScimJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ScimError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(ScimJson(value)),
+            Err(rejection) => Err(ScimError::bad_request(
+                "invalidSyntax",
+                rejection.body_text(),
+            )),
+        }
+    }
+}
+
+// ============================================================================
+// Scanopy management API
+// ============================================================================
+
+/// SCIM provisioning status for the current organization
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScimSettingsResponse {
+    /// Whether a SCIM token exists
+    pub enabled: bool,
+    /// SCIM base URL to configure in the identity provider
+    pub base_url: String,
+    pub token_created_at: Option<DateTime<Utc>>,
+    pub token_last_used: Option<DateTime<Utc>>,
+    pub groups: Vec<ScimGroup>,
+}
+
+/// Newly generated SCIM token - only shown once
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScimTokenResponse {
+    pub token: String,
+    pub base_url: String,
+}
+
+/// Role and network access granted to members of a SCIM group
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateScimGroupMappingRequest {
+    pub permissions: Option<UserOrgPermissions>,
+    #[serde(default)]
+    pub network_ids: Vec<Uuid>,
+}
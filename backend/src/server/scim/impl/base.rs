@@ -0,0 +1,327 @@
+use std::fmt::Display;
+
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{SqlValue, StorableEntity},
+    },
+    users::r#impl::permissions::UserOrgPermissions,
+};
+
+/// The base data for a ScimToken record
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct ScimTokenBase {
+    pub organization_id: Uuid,
+    /// SHA-256 hash of the bearer token
+    pub key: String,
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+/// Organization-scoped bearer token authenticating SCIM provisioning requests
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct ScimToken {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub base: ScimTokenBase,
+}
+
+impl Display for ScimToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ScimToken(org={})", self.base.organization_id)
+    }
+}
+
+impl StorableEntity for ScimToken {
+    type BaseData = ScimTokenBase;
+
+    fn table_name() -> &'static str {
+        "scim_tokens"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.last_used.unwrap_or(self.created_at)
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, _time: DateTime<Utc>) {
+        // No updated_at column; last_used is set explicitly
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::ScimToken
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>)> {
+        Ok((
+            vec!["id", "organization_id", "key", "created_at", "last_used"],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.organization_id),
+                SqlValue::String(self.base.key.clone()),
+                SqlValue::Timestamp(self.created_at),
+                SqlValue::OptionTimestamp(self.base.last_used),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self> {
+        Ok(ScimToken {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: ScimTokenBase {
+                organization_id: row.get("organization_id"),
+                key: row.get("key"),
+                last_used: row.get("last_used"),
+            },
+        })
+    }
+}
+
+/// The base data for a ScimGroup record
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash, ToSchema)]
+pub struct ScimGroupBase {
+    pub organization_id: Uuid,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    /// Role granted to members. The highest role across a user's groups applies.
+    pub permissions: Option<UserOrgPermissions>,
+    /// Networks members get access to
+    #[serde(default)]
+    pub network_ids: Vec<Uuid>,
+    /// Users in this group, as pushed by the identity provider
+    #[serde(default)]
+    pub member_ids: Vec<Uuid>,
+}
+
+/// A group pushed by the identity provider over SCIM. Owners map each group onto
+/// a role and network access, which is applied to its members.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash, ToSchema)]
+pub struct ScimGroup {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub base: ScimGroupBase,
+}
+
+impl Display for ScimGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.base.display_name, self.id)
+    }
+}
+
+impl StorableEntity for ScimGroup {
+    type BaseData = ScimGroupBase;
+
+    fn table_name() -> &'static str {
+        "scim_groups"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::ScimGroup
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>)> {
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "display_name",
+                "external_id",
+                "permissions",
+                "network_ids",
+                "member_ids",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.organization_id),
+                SqlValue::String(self.base.display_name.clone()),
+                SqlValue::OptionalString(self.base.external_id.clone()),
+                SqlValue::OptionalString(self.base.permissions.map(|p| p.as_str().to_string())),
+                SqlValue::UuidArray(self.base.network_ids.clone()),
+                SqlValue::UuidArray(self.base.member_ids.clone()),
+                SqlValue::Timestamp(self.created_at),
+                SqlValue::Timestamp(self.updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self> {
+        let permissions = row
+            .get::<Option<String>, _>("permissions")
+            .map(|p| {
+                p.parse::<UserOrgPermissions>()
+                    .map_err(|_| Error::msg(format!("Failed to parse permissions '{}'", p)))
+            })
+            .transpose()?;
+
+        Ok(ScimGroup {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: ScimGroupBase {
+                organization_id: row.get("organization_id"),
+                display_name: row.get("display_name"),
+                external_id: row.get("external_id"),
+                permissions,
+                network_ids: row.get("network_ids"),
+                member_ids: row.get("member_ids"),
+            },
+        })
+    }
+}
+
+/// Access a user gets from the SCIM groups they belong to: the highest mapped role
+/// (None if no group maps a role) and the union of mapped networks.
+pub fn resolve_group_access<'a>(
+    groups: impl IntoIterator<Item = &'a ScimGroupBase>,
+) -> (Option<UserOrgPermissions>, Vec<Uuid>) {
+    let mut permissions: Option<UserOrgPermissions> = None;
+    let mut network_ids: Vec<Uuid> = Vec::new();
+
+    for group in groups {
+        if let Some(group_permissions) = group.permissions {
+            permissions = permissions.max(Some(group_permissions));
+        }
+
+        for network_id in &group.network_ids {
+            if !network_ids.contains(network_id) {
+                network_ids.push(*network_id);
+            }
+        }
+    }
+
+    (permissions, network_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(permissions: Option<UserOrgPermissions>, network_ids: Vec<Uuid>) -> ScimGroupBase {
+        ScimGroupBase {
+            permissions,
+            network_ids,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_group_access_takes_highest_role_and_unions_networks() {
+        let office = Uuid::new_v4();
+        let lab = Uuid::new_v4();
+        let groups = [
+            group(Some(UserOrgPermissions::Member), vec![office]),
+            group(Some(UserOrgPermissions::Admin), vec![]),
+            group(None, vec![office, lab]),
+        ];
+
+        let (permissions, network_ids) = resolve_group_access(&groups);
+        assert_eq!(permissions, Some(UserOrgPermissions::Admin));
+        assert_eq!(network_ids, vec![office, lab]);
+    }
+
+    #[test]
+    fn test_resolve_group_access_without_groups() {
+        let (permissions, network_ids) = resolve_group_access(std::iter::empty());
+        assert_eq!(permissions, None);
+        assert!(network_ids.is_empty());
+    }
+}
@@ -0,0 +1,414 @@
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::server::scim::r#impl::api::{ScimError, ScimPatchOperation};
+
+/// A parsed `attribute eq "value"` filter. This is the only filter form identity
+/// providers send when provisioning, so it's the only one supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScimFilter {
+    /// Attribute name, lowercased since SCIM attribute names are case-insensitive
+    pub attribute: String,
+    pub value: String,
+}
+
+impl ScimFilter {
+    pub fn parse(filter: &str) -> Result<Self, ScimError> {
+        let invalid = || {
+            ScimError::bad_request(
+                "invalidFilter",
+                format!(
+                    "Unsupported filter '{}': only 'attribute eq \"value\"' is supported",
+                    filter
+                ),
+            )
+        };
+
+        let mut parts = filter.trim().splitn(3, char::is_whitespace);
+        let attribute = parts.next().filter(|a| !a.is_empty()).ok_or_else(invalid)?;
+        let operator = parts.next().ok_or_else(invalid)?;
+        let value = parts.next().map(str::trim).ok_or_else(invalid)?;
+
+        if !operator.eq_ignore_ascii_case("eq") {
+            return Err(invalid());
+        }
+
+        let value = match value.strip_prefix('"') {
+            Some(quoted) => quoted.strip_suffix('"').ok_or_else(invalid)?,
+            None if value.contains(char::is_whitespace) => return Err(invalid()),
+            None => value,
+        };
+
+        Ok(Self {
+            attribute: attribute.to_ascii_lowercase(),
+            value: value.replace("\\\"", "\""),
+        })
+    }
+}
+
+fn invalid_value(detail: impl Into<String>) -> ScimError {
+    ScimError::bad_request("invalidValue", detail)
+}
+
+fn invalid_path(path: &str) -> ScimError {
+    ScimError::bad_request("invalidPath", format!("Unsupported path '{}'", path))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PatchOp {
+    Add,
+    Remove,
+    Replace,
+}
+
+impl PatchOp {
+    fn parse(op: &str) -> Result<Self, ScimError> {
+        match op.to_ascii_lowercase().as_str() {
+            "add" => Ok(Self::Add),
+            "remove" => Ok(Self::Remove),
+            "replace" => Ok(Self::Replace),
+            _ => Err(invalid_value(format!("Unsupported patch op '{}'", op))),
+        }
+    }
+}
+
+/// Booleans arrive as JSON booleans or, from some IdPs (Azure AD), as "True"/"False"
+fn parse_bool(value: &Value) -> Result<bool, ScimError> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        Value::String(s) if s.eq_ignore_ascii_case("true") => Ok(true),
+        Value::String(s) if s.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(invalid_value("Expected a boolean")),
+    }
+}
+
+fn parse_string(value: &Value) -> Result<String, ScimError> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid_value("Expected a string"))
+}
+
+/// Email from an `emails` value: either a list of email objects (primary first) or
+/// a bare string when the path already selected `.value`
+fn parse_email(value: &Value) -> Result<String, ScimError> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Array(emails) => emails
+            .iter()
+            .find(|e| e.get("primary").and_then(Value::as_bool).unwrap_or(false))
+            .or_else(|| emails.first())
+            .and_then(|e| e.get("value"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| invalid_value("Expected at least one email")),
+        _ => Err(invalid_value("Expected a list of emails")),
+    }
+}
+
+/// User attributes that can be changed over SCIM PATCH
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserPatchState {
+    pub email: String,
+    pub active: bool,
+}
+
+impl UserPatchState {
+    fn set(&mut self, attribute: &str, value: &Value) -> Result<(), ScimError> {
+        let attribute = attribute.to_ascii_lowercase();
+        match attribute.as_str() {
+            "active" => self.active = parse_bool(value)?,
+            "username" => self.email = parse_string(value)?,
+            _ if attribute == "emails" || attribute.starts_with("emails[") => {
+                self.email = parse_email(value)?
+            }
+            // Names, titles and other profile attributes aren't stored
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Apply PATCH operations. Unknown attributes are ignored so IdPs that push
+    /// full profiles don't fail provisioning.
+    pub fn apply(&mut self, operations: &[ScimPatchOperation]) -> Result<(), ScimError> {
+        for operation in operations {
+            let op = PatchOp::parse(&operation.op)?;
+            if op == PatchOp::Remove {
+                // Email and active status can't be unset
+                continue;
+            }
+
+            let value = operation
+                .value
+                .as_ref()
+                .ok_or_else(|| invalid_value("Patch operation requires a value"))?;
+
+            match operation.path.as_deref() {
+                Some(path) => self.set(path, value)?,
+                None => {
+                    let attributes = value
+                        .as_object()
+                        .ok_or_else(|| invalid_value("Patch without a path requires an object"))?;
+                    for (attribute, value) in attributes {
+                        self.set(attribute, value)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_member_ids(value: &Value) -> Result<Vec<Uuid>, ScimError> {
+    let members = match value {
+        Value::Array(members) => members.as_slice(),
+        Value::Object(_) => std::slice::from_ref(value),
+        _ => return Err(invalid_value("Expected a list of members")),
+    };
+
+    members
+        .iter()
+        .map(|member| {
+            member
+                .get("value")
+                .and_then(Value::as_str)
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .ok_or_else(|| invalid_value("Member value must be a user id"))
+        })
+        .collect()
+}
+
+/// Group attributes that can be changed over SCIM PATCH
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupPatchState {
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub member_ids: Vec<Uuid>,
+}
+
+impl GroupPatchState {
+    fn add_members(&mut self, ids: Vec<Uuid>) {
+        for id in ids {
+            if !self.member_ids.contains(&id) {
+                self.member_ids.push(id);
+            }
+        }
+    }
+
+    fn set(&mut self, op: PatchOp, attribute: &str, value: &Value) -> Result<(), ScimError> {
+        match attribute.to_ascii_lowercase().as_str() {
+            "displayname" => self.display_name = parse_string(value)?,
+            "externalid" => self.external_id = Some(parse_string(value)?),
+            "members" => {
+                let ids = parse_member_ids(value)?;
+                if op == PatchOp::Replace {
+                    self.member_ids.clear();
+                }
+                self.add_members(ids);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn remove(&mut self, path: &str, value: Option<&Value>) -> Result<(), ScimError> {
+        // members[value eq "<id>"]
+        if let Some(filter) = path
+            .strip_prefix("members[")
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            let filter = ScimFilter::parse(filter)?;
+            if filter.attribute != "value" {
+                return Err(invalid_path(path));
+            }
+            let id = Uuid::parse_str(&filter.value)
+                .map_err(|_| invalid_value("Member value must be a user id"))?;
+            self.member_ids.retain(|m| *m != id);
+            return Ok(());
+        }
+
+        match path.to_ascii_lowercase().as_str() {
+            "members" => match value {
+                Some(value) => {
+                    let ids = parse_member_ids(value)?;
+                    self.member_ids.retain(|m| !ids.contains(m));
+                }
+                None => self.member_ids.clear(),
+            },
+            "externalid" => self.external_id = None,
+            _ => return Err(invalid_path(path)),
+        }
+        Ok(())
+    }
+
+    pub fn apply(&mut self, operations: &[ScimPatchOperation]) -> Result<(), ScimError> {
+        for operation in operations {
+            let op = PatchOp::parse(&operation.op)?;
+
+            if op == PatchOp::Remove {
+                let path = operation
+                    .path
+                    .as_deref()
+                    .ok_or_else(|| ScimError::bad_request("noTarget", "Remove requires a path"))?;
+                self.remove(path, operation.value.as_ref())?;
+                continue;
+            }
+
+            let value = operation
+                .value
+                .as_ref()
+                .ok_or_else(|| invalid_value("Patch operation requires a value"))?;
+
+            match operation.path.as_deref() {
+                Some(path) => self.set(op, path, value)?,
+                None => {
+                    let attributes = value
+                        .as_object()
+                        .ok_or_else(|| invalid_value("Patch without a path requires an object"))?;
+                    for (attribute, value) in attributes {
+                        self.set(op, attribute, value)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn op(op: &str, path: Option<&str>, value: Option<Value>) -> ScimPatchOperation {
+        ScimPatchOperation {
+            op: op.to_string(),
+            path: path.map(str::to_string),
+            value,
+        }
+    }
+
+    #[test]
+    fn parses_eq_filters() {
+        let filter = ScimFilter::parse("userName eq \"alice@example.com\"").unwrap();
+        assert_eq!(filter.attribute, "username");
+        assert_eq!(filter.value, "alice@example.com");
+
+        let filter = ScimFilter::parse("displayName EQ \"Network Admins\"").unwrap();
+        assert_eq!(filter.attribute, "displayname");
+        assert_eq!(filter.value, "Network Admins");
+
+        assert!(ScimFilter::parse("userName co \"alice\"").is_err());
+        assert!(ScimFilter::parse("userName eq \"a\" and active eq true").is_err());
+        assert!(ScimFilter::parse("userName").is_err());
+    }
+
+    #[test]
+    fn user_patch_handles_azure_style_operations() {
+        let mut state = UserPatchState {
+            email: "old@example.com".to_string(),
+            active: true,
+        };
+
+        state
+            .apply(&[
+                op("Replace", Some("active"), Some(json!("False"))),
+                op(
+                    "replace",
+                    Some("emails[type eq \"work\"].value"),
+                    Some(json!("new@example.com")),
+                ),
+                op("replace", Some("name.givenName"), Some(json!("Alice"))),
+            ])
+            .unwrap();
+
+        assert!(!state.active);
+        assert_eq!(state.email, "new@example.com");
+    }
+
+    #[test]
+    fn user_patch_without_path_sets_attributes() {
+        let mut state = UserPatchState {
+            email: "a@example.com".to_string(),
+            active: false,
+        };
+
+        state
+            .apply(&[op(
+                "replace",
+                None,
+                Some(json!({ "active": true, "userName": "b@example.com" })),
+            )])
+            .unwrap();
+
+        assert!(state.active);
+        assert_eq!(state.email, "b@example.com");
+    }
+
+    #[test]
+    fn group_patch_adds_and_removes_members() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut state = GroupPatchState {
+            display_name: "Admins".to_string(),
+            external_id: None,
+            member_ids: vec![a],
+        };
+
+        state
+            .apply(&[
+                op(
+                    "add",
+                    Some("members"),
+                    Some(json!([{ "value": a.to_string() }, { "value": b.to_string() }])),
+                ),
+                op(
+                    "remove",
+                    Some(&format!("members[value eq \"{}\"]", a)),
+                    None,
+                ),
+                op(
+                    "add",
+                    None,
+                    Some(json!({ "members": [{ "value": c.to_string() }] })),
+                ),
+            ])
+            .unwrap();
+
+        assert_eq!(state.member_ids, vec![b, c]);
+
+        state
+            .apply(&[op(
+                "replace",
+                None,
+                Some(
+                    json!({ "displayName": "Operators", "members": [{ "value": a.to_string() }] }),
+                ),
+            )])
+            .unwrap();
+
+        assert_eq!(state.display_name, "Operators");
+        assert_eq!(state.member_ids, vec![a]);
+
+        state.apply(&[op("remove", Some("members"), None)]).unwrap();
+        assert!(state.member_ids.is_empty());
+    }
+
+    #[test]
+    fn group_patch_rejects_invalid_member_ids() {
+        let mut state = GroupPatchState {
+            display_name: "Admins".to_string(),
+            external_id: None,
+            member_ids: vec![],
+        };
+
+        assert!(
+            state
+                .apply(&[op(
+                    "add",
+                    Some("members"),
+                    Some(json!([{ "value": "not-a-uuid" }]))
+                )])
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,707 @@
+use anyhow::Result;
+use chrono::Utc;
+use email_address::EmailAddress;
+use std::{str::FromStr, sync::Arc};
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    scim::r#impl::{
+        api::{
+            GROUP_SCHEMA, ScimEmail, ScimError, ScimGroupResource, ScimListQuery, ScimListResponse,
+            ScimMeta, ScimPatchOperation, ScimReference, ScimUser, USER_SCHEMA,
+            UpdateScimGroupMappingRequest,
+        },
+        base::{ScimGroup, ScimGroupBase, ScimToken, ScimTokenBase, resolve_group_access},
+        filter::{GroupPatchState, ScimFilter, UserPatchState},
+    },
+    shared::{
+        api_key_common::{ApiKeyType, generate_api_key_for_storage, hash_api_key},
+        services::traits::CrudService,
+        storage::{
+            filter::EntityFilter,
+            generic::GenericPostgresStorage,
+            traits::{StorableEntity, Storage},
+        },
+    },
+    user_api_keys::service::UserApiKeyService,
+    users::{
+        r#impl::{
+            base::{User, UserBase},
+            permissions::UserOrgPermissions,
+        },
+        service::UserService,
+    },
+};
+
+pub struct ScimService {
+    token_storage: Arc<GenericPostgresStorage<ScimToken>>,
+    group_storage: Arc<GenericPostgresStorage<ScimGroup>>,
+    user_service: Arc<UserService>,
+    user_api_key_service: Arc<UserApiKeyService>,
+    public_url: String,
+}
+
+impl ScimService {
+    pub fn new(
+        token_storage: Arc<GenericPostgresStorage<ScimToken>>,
+        group_storage: Arc<GenericPostgresStorage<ScimGroup>>,
+        user_service: Arc<UserService>,
+        user_api_key_service: Arc<UserApiKeyService>,
+        public_url: &str,
+    ) -> Self {
+        Self {
+            token_storage,
+            group_storage,
+            user_service,
+            user_api_key_service,
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// SCIM base URL to configure in the identity provider
+    pub fn base_url(&self) -> String {
+        format!("{}/api/scim/v2", self.public_url)
+    }
+
+    // ========================================================================
+    // Tokens
+    // ========================================================================
+
+    pub async fn get_token(&self, organization_id: &Uuid) -> Result<Option<ScimToken>> {
+        self.token_storage
+            .get_one(EntityFilter::unfiltered().organization_id(organization_id))
+            .await
+    }
+
+    /// Resolve a bearer token to the organization it provisions
+    pub async fn authenticate(&self, raw_token: &str) -> Result<Option<Uuid>> {
+        let filter = EntityFilter::unfiltered().api_key(hash_api_key(raw_token));
+        let Some(mut token) = self.token_storage.get_one(filter).await? else {
+            return Ok(None);
+        };
+
+        let organization_id = token.base.organization_id;
+        token.base.last_used = Some(Utc::now());
+        self.token_storage.update(&mut token).await?;
+
+        Ok(Some(organization_id))
+    }
+
+    /// Replace the organization's token, returning the plaintext value once
+    pub async fn rotate_token(&self, organization_id: &Uuid) -> Result<String> {
+        self.revoke_token(organization_id).await?;
+
+        let (plaintext, hashed) = generate_api_key_for_storage(ApiKeyType::Scim);
+        self.token_storage
+            .create(&ScimToken::new(ScimTokenBase {
+                organization_id: *organization_id,
+                key: hashed,
+                last_used: None,
+            }))
+            .await?;
+
+        Ok(plaintext)
+    }
+
+    pub async fn revoke_token(&self, organization_id: &Uuid) -> Result<()> {
+        self.token_storage
+            .delete_by_filter(EntityFilter::unfiltered().organization_id(organization_id))
+            .await?;
+        Ok(())
+    }
+
+    // ========================================================================
+    // Users
+    // ========================================================================
+
+    async fn get_org_user(&self, organization_id: &Uuid, id: &str) -> Result<User, ScimError> {
+        let not_found = || ScimError::not_found(format!("User {} not found", id));
+        let id = Uuid::parse_str(id).map_err(|_| not_found())?;
+
+        self.user_service
+            .get_by_id(&id)
+            .await?
+            .filter(|user| user.base.organization_id == *organization_id)
+            .ok_or_else(not_found)
+    }
+
+    async fn is_last_owner(&self, user: &User) -> Result<bool> {
+        Ok(user.base.permissions == UserOrgPermissions::Owner
+            && self
+                .user_service
+                .get_organization_owners(&user.base.organization_id)
+                .await?
+                .iter()
+                .filter(|owner| !owner.is_deactivated())
+                .count()
+                <= 1)
+    }
+
+    fn to_scim_user(&self, user: &User, groups: &[ScimGroup]) -> ScimUser {
+        let location = format!("{}/Users/{}", self.base_url(), user.id);
+
+        ScimUser {
+            schemas: vec![USER_SCHEMA.to_string()],
+            id: Some(user.id.to_string()),
+            external_id: None,
+            user_name: user.base.email.to_string(),
+            emails: vec![ScimEmail {
+                value: user.base.email.to_string(),
+                primary: true,
+                kind: Some("work".to_string()),
+            }],
+            active: !user.is_deactivated(),
+            groups: groups
+                .iter()
+                .filter(|group| group.base.member_ids.contains(&user.id))
+                .map(|group| ScimReference {
+                    value: group.id.to_string(),
+                    display: Some(group.base.display_name.clone()),
+                    location: Some(format!("{}/Groups/{}", self.base_url(), group.id)),
+                })
+                .collect(),
+            meta: Some(ScimMeta {
+                resource_type: "User".to_string(),
+                created: user.created_at,
+                last_modified: user.updated_at,
+                location,
+            }),
+        }
+    }
+
+    pub async fn list_users(
+        &self,
+        organization_id: &Uuid,
+        query: &ScimListQuery,
+    ) -> Result<ScimListResponse<ScimUser>, ScimError> {
+        let filter = query.filter.as_deref().map(ScimFilter::parse).transpose()?;
+
+        let users = self
+            .user_service
+            .get_all(EntityFilter::unfiltered().organization_id(organization_id))
+            .await?;
+        let groups = self.get_groups(organization_id).await?;
+
+        let users: Vec<ScimUser> = users
+            .iter()
+            .filter(|user| match &filter {
+                None => true,
+                Some(filter) => match filter.attribute.as_str() {
+                    "username" | "emails" | "emails.value" => {
+                        user.base.email.as_str().eq_ignore_ascii_case(&filter.value)
+                    }
+                    "id" => user.id.to_string() == filter.value,
+                    // External ids aren't stored, so nothing matches
+                    _ => false,
+                },
+            })
+            .map(|user| self.to_scim_user(user, &groups))
+            .collect();
+
+        Ok(ScimListResponse::paginate(users, query))
+    }
+
+    pub async fn get_user(&self, organization_id: &Uuid, id: &str) -> Result<ScimUser, ScimError> {
+        let user = self.get_org_user(organization_id, id).await?;
+        let groups = self.get_groups(organization_id).await?;
+        Ok(self.to_scim_user(&user, &groups))
+    }
+
+    fn parse_email(email: &str) -> Result<EmailAddress, ScimError> {
+        EmailAddress::from_str(email).map_err(|_| {
+            ScimError::bad_request("invalidValue", format!("'{}' is not a valid email", email))
+        })
+    }
+
+    async fn ensure_email_available(&self, email: &EmailAddress) -> Result<(), ScimError> {
+        let existing = self
+            .user_service
+            .get_one(EntityFilter::unfiltered().email(email))
+            .await?;
+        if existing.is_some() {
+            return Err(ScimError::conflict(format!(
+                "A user with email {} already exists",
+                email
+            )));
+        }
+        Ok(())
+    }
+
+    /// Create an account the user can sign into with the organization's OIDC provider.
+    /// Provisioned users start as Viewers; group mappings raise their access.
+    pub async fn create_user(
+        &self,
+        organization_id: &Uuid,
+        request: ScimUser,
+    ) -> Result<ScimUser, ScimError> {
+        let email = Self::parse_email(request.email())?;
+        self.ensure_email_available(&email).await?;
+
+        let user = self
+            .user_service
+            .create(
+                User::new(UserBase {
+                    email,
+                    permissions: UserOrgPermissions::Viewer,
+                    organization_id: *organization_id,
+                    password_hash: None,
+                    oidc_linked_at: None,
+                    oidc_provider: None,
+                    oidc_subject: None,
+                    network_ids: vec![],
+                    terms_accepted_at: None,
+                    deactivated_at: (!request.active).then(Utc::now),
+                }),
+                AuthenticatedEntity::System,
+            )
+            .await?;
+
+        let groups = self.get_groups(organization_id).await?;
+        Ok(self.to_scim_user(&user, &groups))
+    }
+
+    pub async fn replace_user(
+        &self,
+        organization_id: &Uuid,
+        id: &str,
+        request: ScimUser,
+    ) -> Result<ScimUser, ScimError> {
+        let user = self.get_org_user(organization_id, id).await?;
+        let state = UserPatchState {
+            email: request.email().to_string(),
+            active: request.active,
+        };
+        self.write_user(organization_id, user, state).await
+    }
+
+    pub async fn patch_user(
+        &self,
+        organization_id: &Uuid,
+        id: &str,
+        operations: &[ScimPatchOperation],
+    ) -> Result<ScimUser, ScimError> {
+        let user = self.get_org_user(organization_id, id).await?;
+        let mut state = UserPatchState {
+            email: user.base.email.to_string(),
+            active: !user.is_deactivated(),
+        };
+        state.apply(operations)?;
+        self.write_user(organization_id, user, state).await
+    }
+
+    async fn write_user(
+        &self,
+        organization_id: &Uuid,
+        mut user: User,
+        state: UserPatchState,
+    ) -> Result<ScimUser, ScimError> {
+        let email = Self::parse_email(&state.email)?;
+        if !email
+            .as_str()
+            .eq_ignore_ascii_case(user.base.email.as_str())
+        {
+            self.ensure_email_available(&email).await?;
+        }
+        user.base.email = email;
+
+        if state.active == user.is_deactivated() {
+            if state.active {
+                user.base.deactivated_at = None;
+            } else {
+                if self.is_last_owner(&user).await? {
+                    return Err(ScimError::bad_request(
+                        "mutability",
+                        "Can't deactivate the only owner in an organization",
+                    ));
+                }
+                user.base.deactivated_at = Some(Utc::now());
+                self.disable_api_keys(&user.id).await?;
+            }
+        }
+
+        user.updated_at = Utc::now();
+        let user = self
+            .user_service
+            .update(&mut user, AuthenticatedEntity::System)
+            .await?;
+
+        let groups = self.get_groups(organization_id).await?;
+        Ok(self.to_scim_user(&user, &groups))
+    }
+
+    /// Deactivated users keep their keys so reactivation is lossless, but the keys
+    /// stop working until re-enabled.
+    async fn disable_api_keys(&self, user_id: &Uuid) -> Result<()> {
+        for mut key in self.user_api_key_service.get_for_user(user_id).await? {
+            if key.base.is_enabled {
+                key.base.is_enabled = false;
+                self.user_api_key_service
+                    .update(&mut key, AuthenticatedEntity::System)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn delete_user(&self, organization_id: &Uuid, id: &str) -> Result<(), ScimError> {
+        let user = self.get_org_user(organization_id, id).await?;
+
+        if self.is_last_owner(&user).await? {
+            return Err(ScimError::bad_request(
+                "mutability",
+                "Can't delete the only owner in an organization",
+            ));
+        }
+
+        for mut group in self.get_groups(organization_id).await? {
+            if group.base.member_ids.contains(&user.id) {
+                group.base.member_ids.retain(|member| *member != user.id);
+                self.group_storage.update(&mut group).await?;
+            }
+        }
+
+        self.user_service
+            .delete(&user.id, AuthenticatedEntity::System)
+            .await?;
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Groups
+    // ========================================================================
+
+    pub async fn get_groups(&self, organization_id: &Uuid) -> Result<Vec<ScimGroup>> {
+        self.group_storage
+            .get_all(EntityFilter::unfiltered().organization_id(organization_id))
+            .await
+    }
+
+    async fn get_org_group(
+        &self,
+        organization_id: &Uuid,
+        id: &str,
+    ) -> Result<ScimGroup, ScimError> {
+        let not_found = || ScimError::not_found(format!("Group {} not found", id));
+        let id = Uuid::parse_str(id).map_err(|_| not_found())?;
+
+        self.group_storage
+            .get_by_id(&id)
+            .await?
+            .filter(|group| group.base.organization_id == *organization_id)
+            .ok_or_else(not_found)
+    }
+
+    async fn to_scim_group(&self, group: &ScimGroup) -> Result<ScimGroupResource> {
+        let members = if group.base.member_ids.is_empty() {
+            vec![]
+        } else {
+            self.user_service
+                .get_all(EntityFilter::unfiltered().entity_ids(&group.base.member_ids))
+                .await?
+        };
+
+        Ok(ScimGroupResource {
+            schemas: vec![GROUP_SCHEMA.to_string()],
+            id: Some(group.id.to_string()),
+            external_id: group.base.external_id.clone(),
+            display_name: group.base.display_name.clone(),
+            members: members
+                .iter()
+                .map(|user| ScimReference {
+                    value: user.id.to_string(),
+                    display: Some(user.base.email.to_string()),
+                    location: Some(format!("{}/Users/{}", self.base_url(), user.id)),
+                })
+                .collect(),
+            meta: Some(ScimMeta {
+                resource_type: "Group".to_string(),
+                created: group.created_at,
+                last_modified: group.updated_at,
+                location: format!("{}/Groups/{}", self.base_url(), group.id),
+            }),
+        })
+    }
+
+    pub async fn list_groups(
+        &self,
+        organization_id: &Uuid,
+        query: &ScimListQuery,
+    ) -> Result<ScimListResponse<ScimGroupResource>, ScimError> {
+        let filter = query.filter.as_deref().map(ScimFilter::parse).transpose()?;
+
+        let mut resources = Vec::new();
+        for group in self.get_groups(organization_id).await? {
+            let matches = match &filter {
+                None => true,
+                Some(filter) => match filter.attribute.as_str() {
+                    "displayname" => group.base.display_name.eq_ignore_ascii_case(&filter.value),
+                    "externalid" => group.base.external_id.as_deref() == Some(&filter.value),
+                    "id" => group.id.to_string() == filter.value,
+                    _ => false,
+                },
+            };
+            if matches {
+                resources.push(self.to_scim_group(&group).await?);
+            }
+        }
+
+        Ok(ScimListResponse::paginate(resources, query))
+    }
+
+    pub async fn get_group(
+        &self,
+        organization_id: &Uuid,
+        id: &str,
+    ) -> Result<ScimGroupResource, ScimError> {
+        let group = self.get_org_group(organization_id, id).await?;
+        Ok(self.to_scim_group(&group).await?)
+    }
+
+    /// Members must be users of the same organization
+    async fn validate_members(
+        &self,
+        organization_id: &Uuid,
+        member_ids: &[Uuid],
+    ) -> Result<(), ScimError> {
+        if member_ids.is_empty() {
+            return Ok(());
+        }
+
+        let users = self
+            .user_service
+            .get_all(
+                EntityFilter::unfiltered()
+                    .organization_id(organization_id)
+                    .entity_ids(member_ids),
+            )
+            .await?;
+
+        if let Some(unknown) = member_ids
+            .iter()
+            .find(|id| !users.iter().any(|user| user.id == **id))
+        {
+            return Err(ScimError::bad_request(
+                "invalidValue",
+                format!("User {} not found", unknown),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn ensure_display_name_available(
+        &self,
+        organization_id: &Uuid,
+        display_name: &str,
+        except: Option<Uuid>,
+    ) -> Result<(), ScimError> {
+        let taken = self.get_groups(organization_id).await?.iter().any(|group| {
+            Some(group.id) != except && group.base.display_name.eq_ignore_ascii_case(display_name)
+        });
+        if taken {
+            return Err(ScimError::conflict(format!(
+                "A group named {} already exists",
+                display_name
+            )));
+        }
+        Ok(())
+    }
+
+    fn member_ids(members: &[ScimReference]) -> Result<Vec<Uuid>, ScimError> {
+        let mut ids: Vec<Uuid> = Vec::new();
+        for member in members {
+            let id = Uuid::parse_str(&member.value).map_err(|_| {
+                ScimError::bad_request("invalidValue", "Member value must be a user id")
+            })?;
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    pub async fn create_group(
+        &self,
+        organization_id: &Uuid,
+        request: ScimGroupResource,
+    ) -> Result<ScimGroupResource, ScimError> {
+        self.ensure_display_name_available(organization_id, &request.display_name, None)
+            .await?;
+
+        let member_ids = Self::member_ids(&request.members)?;
+        self.validate_members(organization_id, &member_ids).await?;
+
+        let group = self
+            .group_storage
+            .create(&ScimGroup::new(ScimGroupBase {
+                organization_id: *organization_id,
+                display_name: request.display_name,
+                external_id: request.external_id,
+                permissions: None,
+                network_ids: vec![],
+                member_ids,
+            }))
+            .await?;
+
+        Ok(self.to_scim_group(&group).await?)
+    }
+
+    pub async fn replace_group(
+        &self,
+        organization_id: &Uuid,
+        id: &str,
+        request: ScimGroupResource,
+    ) -> Result<ScimGroupResource, ScimError> {
+        let group = self.get_org_group(organization_id, id).await?;
+        let state = GroupPatchState {
+            display_name: request.display_name,
+            external_id: request.external_id,
+            member_ids: Self::member_ids(&request.members)?,
+        };
+        self.write_group(organization_id, group, state).await
+    }
+
+    pub async fn patch_group(
+        &self,
+        organization_id: &Uuid,
+        id: &str,
+        operations: &[ScimPatchOperation],
+    ) -> Result<ScimGroupResource, ScimError> {
+        let group = self.get_org_group(organization_id, id).await?;
+        let mut state = GroupPatchState {
+            display_name: group.base.display_name.clone(),
+            external_id: group.base.external_id.clone(),
+            member_ids: group.base.member_ids.clone(),
+        };
+        state.apply(operations)?;
+        self.write_group(organization_id, group, state).await
+    }
+
+    async fn write_group(
+        &self,
+        organization_id: &Uuid,
+        mut group: ScimGroup,
+        state: GroupPatchState,
+    ) -> Result<ScimGroupResource, ScimError> {
+        if !state
+            .display_name
+            .eq_ignore_ascii_case(&group.base.display_name)
+        {
+            self.ensure_display_name_available(
+                organization_id,
+                &state.display_name,
+                Some(group.id),
+            )
+            .await?;
+        }
+        self.validate_members(organization_id, &state.member_ids)
+            .await?;
+
+        // Both removed and added members may need their access changed
+        let mut affected = group.base.member_ids.clone();
+        affected.extend(state.member_ids.iter().copied());
+
+        group.base.display_name = state.display_name;
+        group.base.external_id = state.external_id;
+        group.base.member_ids = state.member_ids;
+        let group = self.group_storage.update(&mut group).await?;
+
+        self.recompute_user_access(organization_id, &affected)
+            .await?;
+
+        Ok(self.to_scim_group(&group).await?)
+    }
+
+    pub async fn delete_group(&self, organization_id: &Uuid, id: &str) -> Result<(), ScimError> {
+        let group = self.get_org_group(organization_id, id).await?;
+        self.group_storage.delete(&group.id).await?;
+        self.recompute_user_access(organization_id, &group.base.member_ids)
+            .await?;
+        Ok(())
+    }
+
+    /// Set the role and networks an owner mapped onto a group, then apply them to
+    /// its members
+    pub async fn update_group_mapping(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+        request: UpdateScimGroupMappingRequest,
+    ) -> Result<Option<ScimGroup>> {
+        let Some(mut group) = self
+            .group_storage
+            .get_by_id(id)
+            .await?
+            .filter(|group| group.base.organization_id == *organization_id)
+        else {
+            return Ok(None);
+        };
+
+        group.base.permissions = request.permissions;
+        group.base.network_ids = request.network_ids;
+        let group = self.group_storage.update(&mut group).await?;
+
+        self.recompute_user_access(organization_id, &group.base.member_ids)
+            .await?;
+
+        Ok(Some(group))
+    }
+
+    /// Sync users' role and network access with their group memberships. Only runs
+    /// once an owner has mapped at least one group, so organizations that just push
+    /// groups for reference keep managing access by hand.
+    async fn recompute_user_access(&self, organization_id: &Uuid, user_ids: &[Uuid]) -> Result<()> {
+        let groups = self.get_groups(organization_id).await?;
+        let has_mapping = groups
+            .iter()
+            .any(|group| group.base.permissions.is_some() || !group.base.network_ids.is_empty());
+        if !has_mapping || user_ids.is_empty() {
+            return Ok(());
+        }
+
+        let users = self
+            .user_service
+            .get_all(
+                EntityFilter::unfiltered()
+                    .organization_id(organization_id)
+                    .entity_ids(user_ids),
+            )
+            .await?;
+
+        for mut user in users {
+            let (permissions, network_ids) = resolve_group_access(
+                groups
+                    .iter()
+                    .filter(|group| group.base.member_ids.contains(&user.id))
+                    .map(|group| &group.base),
+            );
+            let permissions = permissions.unwrap_or(UserOrgPermissions::Viewer);
+
+            self.user_service
+                .set_network_ids(&user.id, &network_ids)
+                .await?;
+
+            if permissions == user.base.permissions {
+                continue;
+            }
+
+            if self.is_last_owner(&user).await? {
+                tracing::warn!(
+                    user_id = %user.id,
+                    mapped = %permissions,
+                    "Not applying SCIM group mapping to the organization's only owner"
+                );
+                continue;
+            }
+
+            user.base.permissions = permissions;
+            user.updated_at = Utc::now();
+            self.user_service
+                .update(&mut user, AuthenticatedEntity::System)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
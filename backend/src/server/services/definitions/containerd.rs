@@ -0,0 +1,29 @@
+use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::categories::ServiceCategory;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::Pattern;
+
+#[derive(Default, Clone, Eq, PartialEq, Hash)]
+pub struct Containerd;
+
+impl ServiceDefinition for Containerd {
+    fn name(&self) -> &'static str {
+        "containerd"
+    }
+    fn description(&self) -> &'static str {
+        "Industry-standard container runtime"
+    }
+    fn category(&self) -> ServiceCategory {
+        ServiceCategory::Virtualization
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        Pattern::None
+    }
+
+    fn logo_url(&self) -> &'static str {
+        "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/containerd.svg"
+    }
+}
+
+inventory::submit!(ServiceDefinitionFactory::new(create_service::<Containerd>));
@@ -0,0 +1,62 @@
+use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::base::DiscoverySessionServiceMatchParams;
+use crate::server::services::r#impl::categories::ServiceCategory;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::{MatchConfidence, Pattern};
+use crate::server::services::r#impl::virtualization::ServiceVirtualization;
+
+#[derive(Default, Clone, Eq, PartialEq, Hash)]
+pub struct KubernetesPod;
+
+impl ServiceDefinition for KubernetesPod {
+    fn name(&self) -> &'static str {
+        "Kubernetes Pod"
+    }
+    fn description(&self) -> &'static str {
+        "A generic container running in a Kubernetes pod"
+    }
+    fn category(&self) -> ServiceCategory {
+        ServiceCategory::Virtualization
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        Pattern::Custom(
+            |p: &DiscoverySessionServiceMatchParams| {
+                // If there's a matched service with the key of the container, the container was already detected as a non-generic service
+                let c_key = match p.baseline_params.virtualization {
+                    Some(ServiceVirtualization::Kubernetes(kv)) => match kv.container_key() {
+                        Some(key) => key,
+                        None => return false,
+                    },
+                    _ => return false, // Not running in a pod
+                };
+
+                p.service_params
+                    .matched_services
+                    .iter()
+                    .all(|s| match &s.base.virtualization {
+                        Some(ServiceVirtualization::Kubernetes(kv)) => {
+                            kv.container_key().as_ref() != Some(&c_key)
+                        }
+                        _ => true,
+                    })
+            },
+            |_| Vec::new(),
+            "No other services with this pod container have been matched",
+            "A service for this pod container has already been matched",
+            MatchConfidence::Low,
+        )
+    }
+
+    fn is_generic(&self) -> bool {
+        true
+    }
+
+    fn logo_url(&self) -> &'static str {
+        "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/kubernetes.svg"
+    }
+}
+
+inventory::submit!(ServiceDefinitionFactory::new(
+    create_service::<KubernetesPod>
+));
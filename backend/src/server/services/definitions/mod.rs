@@ -159,12 +159,15 @@ pub mod zigbee2mqtt;
 pub mod zwave_js;
 
 // Virtualization
+pub mod containerd;
 pub mod docker_container;
 pub mod docker_daemon;
 pub mod docker_swarm;
 pub mod kubernetes;
+pub mod kubernetes_pod;
 pub mod nomad;
 pub mod openshift;
+pub mod podman;
 pub mod portainer;
 pub mod proxmox;
 pub mod rancher;
@@ -0,0 +1,29 @@
+use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::categories::ServiceCategory;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::Pattern;
+
+#[derive(Default, Clone, Eq, PartialEq, Hash)]
+pub struct Podman;
+
+impl ServiceDefinition for Podman {
+    fn name(&self) -> &'static str {
+        "Podman"
+    }
+    fn description(&self) -> &'static str {
+        "Daemonless container engine"
+    }
+    fn category(&self) -> ServiceCategory {
+        ServiceCategory::Virtualization
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        Pattern::None
+    }
+
+    fn logo_url(&self) -> &'static str {
+        "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/podman.svg"
+    }
+}
+
+inventory::submit!(ServiceDefinitionFactory::new(create_service::<Podman>));
@@ -1,4 +1,4 @@
-use crate::server::auth::middleware::permissions::{Authorized, Member};
+use crate::server::auth::middleware::permissions::{And, Authorized, Member, Scope, Write};
 use crate::server::shared::handlers::traits::update_handler;
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult};
@@ -58,11 +58,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Service created successfully", body = ApiResponse<Service>),
         (status = 400, description = "Validation error: host network mismatch, cross-host binding, or binding conflict", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Service, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn create_service(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Service, Write>>>,
     Json(request): Json<CreateServiceRequest>,
 ) -> ApiResult<Json<ApiResponse<Service>>> {
     // Validate user has access to the network
@@ -119,11 +120,12 @@ pub async fn create_service(
         (status = 400, description = "Validation error: host network mismatch, cross-host binding, or binding conflict", body = ApiErrorResponse),
         (status = 404, description = "Service not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Service, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn update_service(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Service, Write>>>,
     Path(id): Path<Uuid>,
     Json(service): Json<Service>,
 ) -> ApiResult<Json<ApiResponse<Service>>> {
@@ -142,5 +144,11 @@ pub async fn update_service(
     }
 
     // Delegate to generic handler (handles validation, auth checks, update)
-    update_handler::<Service>(State(state), auth, Path(id), Json(service)).await
+    update_handler::<Service>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(service),
+    )
+    .await
 }
@@ -7,9 +7,7 @@ use crate::server::services::r#impl::definitions::ServiceDefinitionExt;
 use crate::server::services::r#impl::definitions::{DefaultServiceDefinition, ServiceDefinition};
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
 use crate::server::services::r#impl::patterns::{MatchConfidence, MatchReason};
-use crate::server::services::r#impl::virtualization::{
-    DockerVirtualization, ServiceVirtualization,
-};
+use crate::server::services::r#impl::virtualization::ServiceVirtualization;
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::position::Positioned;
 use crate::server::shared::storage::traits::StorableEntity;
@@ -159,15 +157,8 @@ impl PartialEq for Service {
         // All possible permutations of generic services on the same host:
 
         // Extract virtualization info
-        let self_docker = self.base.virtualization.as_ref().map(|v| {
-            let ServiceVirtualization::Docker(dv) = v;
-            dv
-        });
-
-        let other_docker = other.base.virtualization.as_ref().map(|v| {
-            let ServiceVirtualization::Docker(dv) = v;
-            dv
-        });
+        let self_virtualization = self.base.virtualization.as_ref();
+        let other_virtualization = other.base.virtualization.as_ref();
 
         // Extract port IDs from bindings
         let self_port_ids: std::collections::HashSet<_> = self
@@ -188,37 +179,45 @@ impl PartialEq for Service {
             && !other_port_ids.is_empty()
             && !self_port_ids.is_disjoint(&other_port_ids);
 
-        match (self_docker, other_docker) {
+        match (self_virtualization, other_virtualization) {
             // ========================================
             // CASE 1: Both containerized
             // ========================================
-            (Some(self_dv), Some(other_dv)) => {
-                // CASE 1A: Both have container IDs
+            (Some(self_v), Some(other_v)) => {
+                // CASE 1A: Different container runtimes
+                // Match Method: Different services
+                // Example: Docker container and Kubernetes pod on the same node
+                if std::mem::discriminant(self_v) != std::mem::discriminant(other_v) {
+                    return false;
+                }
+
+                let self_cid = self_v.container_id();
+                let other_cid = other_v.container_id();
+
+                // CASE 1B: Both have container IDs
                 // Match Method: Container ID equality
                 // Example: PostgreSQL container discovered via docker scan vs network scan
-                if let (Some(self_cid), Some(other_cid)) =
-                    (&self_dv.container_id, &other_dv.container_id)
-                {
+                if let (Some(self_cid), Some(other_cid)) = (&self_cid, &other_cid) {
                     return self_cid == other_cid;
                 }
 
-                // CASE 1B: Only one has container ID
+                // CASE 1C: Only one has container ID
                 // Match Method: Different services
                 // Example: Shouldn't happen in practice, but treat as different
-                if self_dv.container_id.is_some() || other_dv.container_id.is_some() {
+                if self_cid.is_some() || other_cid.is_some() {
                     return false;
                 }
 
-                // CASE 1C: Neither has container ID, but both have container names
+                // CASE 1D: Neither has container ID, but both have container names
                 // Match Method: Container name equality
                 // Example: Edge case where container_id wasn't captured
                 if let (Some(self_cname), Some(other_cname)) =
-                    (&self_dv.container_name, &other_dv.container_name)
+                    (self_v.container_name(), other_v.container_name())
                 {
                     return self_cname == other_cname;
                 }
 
-                // CASE 1D: Neither has container ID or name, check ports
+                // CASE 1E: Neither has container ID or name, check ports
                 // Match Method: Port binding overlap
                 // Example: Malformed container data, fall back to port matching
                 has_shared_ports
@@ -391,11 +390,7 @@ impl Service {
             let mut name = service_definition.name().to_string();
 
             if ServiceDefinitionExt::is_generic(&service_definition) {
-                if let Some(ServiceVirtualization::Docker(DockerVirtualization {
-                    container_name: Some(c_name),
-                    ..
-                })) = virtualization
-                {
+                if let Some(c_name) = virtualization.as_ref().and_then(|v| v.container_name()) {
                     name = c_name.clone()
                 }
 
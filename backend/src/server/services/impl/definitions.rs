@@ -1,5 +1,8 @@
 use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::definitions::containerd::Containerd;
 use crate::server::services::definitions::docker_daemon::Docker;
+use crate::server::services::definitions::kubernetes::Kubernetes;
+use crate::server::services::definitions::podman::Podman;
 use crate::server::services::definitions::proxmox::Proxmox;
 use crate::server::services::r#impl::categories::ServiceCategory;
 use crate::server::services::r#impl::patterns::Pattern;
@@ -136,7 +139,13 @@ impl ServiceDefinitionExt for Box<dyn ServiceDefinition> {
         let id = self.id();
         match id {
             _ if id == Proxmox.id() => Some("vms"),
-            _ if id == Docker.id() => Some("containers"),
+            _ if id == Docker.id()
+                || id == Podman.id()
+                || id == Containerd.id()
+                || id == Kubernetes.id() =>
+            {
+                Some("containers")
+            }
             _ => None,
         }
     }
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     services::{r#impl::base::Service, service::ServiceService},
     shared::handlers::{query::HostChildQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Service {
@@ -12,3 +13,7 @@ impl CrudHandlers for Service {
         &state.services.service_service
     }
 }
+
+impl ScopedResource for Service {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Services;
+}
@@ -30,6 +30,8 @@ use crate::server::shared::{
 pub enum ServiceVirtualization {
     #[schema(title = "Docker")]
     Docker(DockerVirtualization),
+    #[schema(title = "Kubernetes")]
+    Kubernetes(KubernetesVirtualization),
 }
 
 #[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
@@ -37,6 +39,83 @@ pub struct DockerVirtualization {
     pub container_name: Option<String>,
     pub container_id: Option<String>,
     pub service_id: Uuid,
+    /// Runtime the container was discovered through. Podman and containerd containers are
+    /// discovered with the same Docker-compatible models as Docker containers.
+    #[serde(default)]
+    #[schema(required)]
+    pub runtime: ContainerRuntime,
+    /// Pod the container shares its network namespace with, if any
+    #[serde(default)]
+    #[schema(required)]
+    pub pod_name: Option<String>,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    IntoStaticStr,
+    ToSchema,
+)]
+pub enum ContainerRuntime {
+    #[default]
+    Docker,
+    Podman,
+    Containerd,
+}
+
+#[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct KubernetesVirtualization {
+    pub namespace: String,
+    pub pod_name: Option<String>,
+    /// Name of the controller that owns the pod (Deployment, StatefulSet, DaemonSet...)
+    /// Pods are replaced on every rollout, so this is what identifies a workload across runs
+    pub workload_name: Option<String>,
+    pub container_name: Option<String>,
+    pub node_name: Option<String>,
+    pub service_id: Uuid,
+}
+
+impl KubernetesVirtualization {
+    /// Stable identity for a container: namespace, owning workload (or pod if unowned) and container
+    pub fn container_key(&self) -> Option<String> {
+        let owner = self.workload_name.as_ref().or(self.pod_name.as_ref())?;
+        Some(match &self.container_name {
+            Some(container_name) => format!("{}/{}/{}", self.namespace, owner, container_name),
+            None => format!("{}/{}", self.namespace, owner),
+        })
+    }
+}
+
+impl ServiceVirtualization {
+    /// Runtime-specific identifier for the container backing this service
+    pub fn container_id(&self) -> Option<String> {
+        match self {
+            ServiceVirtualization::Docker(dv) => dv.container_id.clone(),
+            ServiceVirtualization::Kubernetes(kv) => kv.container_key(),
+        }
+    }
+
+    pub fn container_name(&self) -> Option<&String> {
+        match self {
+            ServiceVirtualization::Docker(dv) => dv.container_name.as_ref(),
+            ServiceVirtualization::Kubernetes(kv) => kv.container_name.as_ref(),
+        }
+    }
+
+    /// ID of the service (Docker daemon, Kubernetes node) that runs this container
+    pub fn service_id(&self) -> Uuid {
+        match self {
+            ServiceVirtualization::Docker(dv) => dv.service_id,
+            ServiceVirtualization::Kubernetes(kv) => kv.service_id,
+        }
+    }
 }
 
 impl HasId for ServiceVirtualization {
@@ -56,10 +135,16 @@ impl EntityMetadataProvider for ServiceVirtualization {
 
 impl TypeMetadataProvider for ServiceVirtualization {
     fn name(&self) -> &'static str {
-        "Docker"
+        match self {
+            ServiceVirtualization::Docker(..) => "Docker",
+            ServiceVirtualization::Kubernetes(..) => "Kubernetes",
+        }
     }
 
     fn description(&self) -> &'static str {
-        "A service running in a docker container"
+        match self {
+            ServiceVirtualization::Docker(..) => "A service running in a docker container",
+            ServiceVirtualization::Kubernetes(..) => "A service running in a Kubernetes pod",
+        }
     }
 }
@@ -29,6 +29,10 @@ use crate::server::{
 pub enum ApiKeyType {
     Daemon,
     User,
+    /// Organization-scoped SCIM provisioning token
+    Scim,
+    /// Named token granting access to a single share
+    Share,
 }
 
 impl ApiKeyType {
@@ -37,6 +41,8 @@ impl ApiKeyType {
         match self {
             ApiKeyType::Daemon => "scp_d_",
             ApiKeyType::User => "scp_u_",
+            ApiKeyType::Scim => "scp_s_",
+            ApiKeyType::Share => "scp_t_",
         }
     }
 
@@ -47,6 +53,10 @@ impl ApiKeyType {
             (ApiKeyType::User, true)
         } else if key.starts_with("scp_d_") {
             (ApiKeyType::Daemon, true)
+        } else if key.starts_with("scp_s_") {
+            (ApiKeyType::Scim, true)
+        } else if key.starts_with("scp_t_") {
+            (ApiKeyType::Share, true)
         } else {
             // Legacy key without prefix - assume daemon
             (ApiKeyType::Daemon, false)
@@ -1,13 +1,21 @@
+use crate::server::auth::r#impl::mfa::UserMfaCredential;
+use crate::server::auth::r#impl::sessions::UserSession;
 use crate::server::bindings::r#impl::base::Binding;
+use crate::server::credentials::r#impl::base::Credential;
 use crate::server::group_bindings::GroupBinding;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::invites::r#impl::base::Invite;
+use crate::server::network_links::r#impl::base::NetworkLink;
 use crate::server::ports::r#impl::base::Port;
+use crate::server::scim::r#impl::base::{ScimGroup, ScimToken};
 use crate::server::services::r#impl::base::Service;
 use crate::server::shared::storage::entity_tags::EntityTag;
 use crate::server::shares::r#impl::base::Share;
+use crate::server::shares::r#impl::{tokens::ShareToken, views::ShareView};
+use crate::server::snapshots::r#impl::base::TopologySnapshot;
 use crate::server::subnets::r#impl::base::Subnet;
 use crate::server::topology::types::base::Topology;
+use crate::server::traceroutes::r#impl::base::Traceroute;
 use crate::server::user_api_keys::r#impl::network_access::UserApiKeyNetworkAccess;
 use crate::server::users::r#impl::network_access::UserNetworkAccess;
 use crate::server::{groups::r#impl::base::Group, tags::r#impl::base::Tag};
@@ -62,13 +70,16 @@ pub enum Entity {
     Organization(Organization),
     Invite(Invite),
     Share(Share),
+    Credential(Credential),
     Network(Network),
+    NetworkLink(NetworkLink),
     DaemonApiKey(DaemonApiKey),
     UserApiKey(UserApiKey),
     User(User),
     Tag(Tag),
 
     Discovery(Discovery),
+    Traceroute(Traceroute),
     Daemon(Daemon),
 
     Host(Host),
@@ -80,12 +91,19 @@ pub enum Entity {
     Subnet(Subnet),
     Group(Group),
     Topology(Box<Topology>),
+    TopologySnapshot(Box<TopologySnapshot>),
 
     // Junction table entities, not used outside of making sure entity_type() method for StorableEntity has a return value
     GroupBinding(GroupBinding),
     EntityTag(EntityTag),
     UserApiKeyNetworkAccess(UserApiKeyNetworkAccess),
     UserNetworkAccess(UserNetworkAccess),
+    UserMfaCredential(UserMfaCredential),
+    UserSession(UserSession),
+    ScimToken(ScimToken),
+    ScimGroup(ScimGroup),
+    ShareToken(ShareToken),
+    ShareView(ShareView),
     #[default]
     #[strum_discriminants(default)]
     Unknown,
@@ -102,13 +120,16 @@ impl EntityMetadataProvider for EntityDiscriminants {
         match self {
             EntityDiscriminants::Organization => Color::Blue,
             EntityDiscriminants::Network => Color::Gray,
+            EntityDiscriminants::NetworkLink => Color::Gray,
             EntityDiscriminants::Daemon => Color::Green,
             EntityDiscriminants::Discovery => Color::Green,
+            EntityDiscriminants::Traceroute => Color::Emerald,
             EntityDiscriminants::DaemonApiKey => Color::Yellow,
             EntityDiscriminants::UserApiKey => Color::Yellow,
             EntityDiscriminants::User => Color::Blue,
             EntityDiscriminants::Invite => Color::Green,
             EntityDiscriminants::Share => Color::Teal,
+            EntityDiscriminants::Credential => Color::Yellow,
             EntityDiscriminants::Tag => Color::Yellow,
 
             EntityDiscriminants::Host => Color::Blue,
@@ -120,12 +141,19 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Subnet => Color::Orange,
             EntityDiscriminants::Group => Color::Rose,
             EntityDiscriminants::Topology => Color::Pink,
+            EntityDiscriminants::TopologySnapshot => Color::Pink,
 
             // Junction
             EntityDiscriminants::EntityTag => Color::Gray,
             EntityDiscriminants::GroupBinding => Color::Gray,
             EntityDiscriminants::UserApiKeyNetworkAccess => Color::Gray,
             EntityDiscriminants::UserNetworkAccess => Color::Gray,
+            EntityDiscriminants::UserMfaCredential => Color::Gray,
+            EntityDiscriminants::UserSession => Color::Gray,
+            EntityDiscriminants::ScimToken => Color::Gray,
+            EntityDiscriminants::ScimGroup => Color::Gray,
+            EntityDiscriminants::ShareToken => Color::Gray,
+            EntityDiscriminants::ShareView => Color::Gray,
 
             // Misc
             EntityDiscriminants::Unknown => Color::Gray,
@@ -136,14 +164,17 @@ impl EntityMetadataProvider for EntityDiscriminants {
         match self {
             EntityDiscriminants::Organization => Icon::Building,
             EntityDiscriminants::Network => Icon::Globe,
+            EntityDiscriminants::NetworkLink => Icon::Cable,
             EntityDiscriminants::User => Icon::User,
             EntityDiscriminants::Tag => Icon::Tag,
             EntityDiscriminants::Invite => Icon::UserPlus,
             EntityDiscriminants::Share => Icon::Share2,
+            EntityDiscriminants::Credential => Icon::KeyRound,
             EntityDiscriminants::DaemonApiKey => Icon::Key,
             EntityDiscriminants::UserApiKey => Icon::Key,
             EntityDiscriminants::Daemon => Icon::SatelliteDish,
             EntityDiscriminants::Discovery => Icon::Radar,
+            EntityDiscriminants::Traceroute => Icon::Route,
             EntityDiscriminants::Host => Icon::Server,
             EntityDiscriminants::Service => Icon::Layers,
             EntityDiscriminants::Interface => Icon::Binary,
@@ -152,11 +183,18 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Subnet => Icon::Network,
             EntityDiscriminants::Group => Icon::Group,
             EntityDiscriminants::Topology => Icon::ChartBarStacked,
+            EntityDiscriminants::TopologySnapshot => Icon::History,
 
             EntityDiscriminants::EntityTag => Icon::Tag,
             EntityDiscriminants::GroupBinding => Icon::Link,
             EntityDiscriminants::UserApiKeyNetworkAccess => Icon::User,
             EntityDiscriminants::UserNetworkAccess => Icon::User,
+            EntityDiscriminants::UserMfaCredential => Icon::User,
+            EntityDiscriminants::UserSession => Icon::User,
+            EntityDiscriminants::ScimToken => Icon::Key,
+            EntityDiscriminants::ScimGroup => Icon::Users,
+            EntityDiscriminants::ShareToken => Icon::Key,
+            EntityDiscriminants::ShareView => Icon::Eye,
 
             EntityDiscriminants::Unknown => Icon::CircleQuestionMark,
         }
@@ -181,12 +219,24 @@ impl From<Share> for Entity {
     }
 }
 
+impl From<Credential> for Entity {
+    fn from(value: Credential) -> Self {
+        Self::Credential(value)
+    }
+}
+
 impl From<Network> for Entity {
     fn from(value: Network) -> Self {
         Self::Network(value)
     }
 }
 
+impl From<NetworkLink> for Entity {
+    fn from(value: NetworkLink) -> Self {
+        Self::NetworkLink(value)
+    }
+}
+
 impl From<DaemonApiKey> for Entity {
     fn from(value: DaemonApiKey) -> Self {
         Self::DaemonApiKey(value)
@@ -211,6 +261,12 @@ impl From<Discovery> for Entity {
     }
 }
 
+impl From<Traceroute> for Entity {
+    fn from(value: Traceroute) -> Self {
+        Self::Traceroute(value)
+    }
+}
+
 impl From<Daemon> for Entity {
     fn from(value: Daemon) -> Self {
         Self::Daemon(value)
@@ -265,6 +321,12 @@ impl From<Topology> for Entity {
     }
 }
 
+impl From<TopologySnapshot> for Entity {
+    fn from(value: TopologySnapshot) -> Self {
+        Self::TopologySnapshot(Box::new(value))
+    }
+}
+
 impl From<Tag> for Entity {
     fn from(value: Tag) -> Self {
         Self::Tag(value)
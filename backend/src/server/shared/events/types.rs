@@ -242,19 +242,36 @@ pub enum AuthOperation {
     PasswordChanged,
     EmailVerified,
     SessionExpired,
+    SessionRevoked,
     OidcLinked,
     OidcUnlinked,
     LoggedOut,
+    MfaEnrolled,
+    MfaRemoved,
+    MfaVerified,
+    MfaFailed,
 
     // Api Key Auth
     RotateKey,
     ApiKeyAuthFailed,
+
+    // Request was rejected by the rate limiter
+    RateLimited,
+
+    // Share Access Tokens
+    ShareTokenCreated,
+    ShareTokenRevoked,
+    ShareTokenUsed,
+    ShareTokenRejected,
 }
 
 impl AuthOperation {
     fn log_level(&self) -> EventLogLevel {
         match self {
-            AuthOperation::LoginFailed | AuthOperation::ApiKeyAuthFailed => EventLogLevel::Error,
+            AuthOperation::LoginFailed
+            | AuthOperation::ApiKeyAuthFailed
+            | AuthOperation::MfaFailed => EventLogLevel::Error,
+            AuthOperation::RateLimited | AuthOperation::ShareTokenRejected => EventLogLevel::Warn,
             _ => EventLogLevel::Info,
         }
     }
@@ -337,6 +354,8 @@ pub enum EntityOperation {
     Deleted,
     DiscoveryStarted,
     DiscoveryCancelled,
+    /// A stored secret was decrypted and handed out, e.g. to a daemon for discovery
+    SecretAccessed,
 }
 
 impl EntityOperation {
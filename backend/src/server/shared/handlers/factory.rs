@@ -8,14 +8,17 @@ use crate::server::shared::types::metadata::{__path_get_metadata_registry, get_m
 use crate::server::{
     auth::handlers as auth_handlers, billing::handlers as billing_handlers,
     bindings::handlers as binding_handlers, config::AppState,
+    credentials::handlers as credential_handlers,
     daemon_api_keys::handlers as daemon_api_key_handlers, daemons::handlers as daemon_handlers,
     discovery::handlers as discovery_handlers, groups::handlers as group_handlers,
     hosts::handlers as host_handlers, interfaces::handlers as interface_handlers,
-    invites::handlers as invite_handlers, networks::handlers as network_handlers,
-    organizations::handlers as organization_handlers, ports::handlers as port_handlers,
+    invites::handlers as invite_handlers, network_links::handlers as network_link_handlers,
+    networks::handlers as network_handlers, organizations::handlers as organization_handlers,
+    ports::handlers as port_handlers, scim::handlers as scim_handlers,
     services::handlers as service_handlers, shares::handlers as share_handlers,
-    subnets::handlers as subnet_handlers, tags::handlers as tag_handlers,
-    topology::handlers as topology_handlers, user_api_keys::handlers as user_api_key_handlers,
+    snapshots::handlers as snapshot_handlers, subnets::handlers as subnet_handlers,
+    tags::handlers as tag_handlers, topology::handlers as topology_handlers,
+    traceroutes::handlers as traceroute_handlers, user_api_keys::handlers as user_api_key_handlers,
     users::handlers as user_handlers,
 };
 use axum::Json;
@@ -80,7 +83,12 @@ fn create_billed_openapi_routes() -> OpenApiRouter<Arc<AppState>> {
             organization_handlers::create_router(),
         )
         .nest("/api/v1/invites", invite_handlers::create_router())
+        .nest("/api/v1/scim", scim_handlers::create_router())
         .nest("/api/v1/tags", tag_handlers::create_router())
+        .nest(
+            "/api/v1/network-links",
+            network_link_handlers::create_router(),
+        )
         .nest("/api/v1/ports", port_handlers::create_router())
         .nest("/api/v1/bindings", binding_handlers::create_router())
         // API key routes (versioned)
@@ -91,6 +99,9 @@ fn create_billed_openapi_routes() -> OpenApiRouter<Arc<AppState>> {
         )
         // Topology endpoints (tagged as internal - hidden from public docs)
         .nest("/api/v1/topology", topology_handlers::create_router())
+        // Point-in-time copies of topologies
+        .nest("/api/v1/snapshots", snapshot_handlers::create_router())
+        .nest("/api/v1/traceroutes", traceroute_handlers::create_router())
 }
 
 /// Creates the OpenApiRouter with exempt routes (not subject to billing middleware).
@@ -99,6 +110,7 @@ fn create_exempt_openapi_routes() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .nest("/api/billing", billing_handlers::create_router())
         .nest("/api/v1/shares", share_handlers::create_router())
+        .nest("/api/v1/credentials", credential_handlers::create_router())
         .nest("/api/auth", auth_handlers::create_router())
         .nest("/api/daemons", daemon_handlers::create_internal_router())
         .routes(utoipa_axum::routes!(get_version))
@@ -158,10 +170,16 @@ pub fn create_router(state: Arc<AppState>) -> (Router<Arc<AppState>>, OpenApi) {
         HeaderValue::from_static("max-age=3600, must-revalidate"),
     ));
 
+    // SCIM 2.0 protocol routes authenticate with SCIM tokens and use SCIM's own
+    // response format, so they are not documented in OpenAPI
+    let scim_router: Router<Arc<AppState>> =
+        Router::new().nest("/api/scim/v2", scim_handlers::create_protocol_router());
+
     let router = Router::new()
         .merge(billed_router)
         .merge(exempt_router)
         .merge(legacy_entity_router)
+        .merge(scim_router)
         .merge(cacheable_routes)
         .merge(create_docs_router(openapi.clone()))
         // Fixture capture middleware (no-op unless capture-fixtures feature is enabled)
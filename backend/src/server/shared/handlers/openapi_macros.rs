@@ -31,6 +31,10 @@
 //!     .routes(routes!(generated::bulk_delete))
 //! ```
 //!
+//! Each handler requires the matching API key scope (`<resource>:read`, `:write` or
+//! `:delete`), so the entity must implement `ScopedResource`. The scope is documented
+//! in the spec as the `x-required-scope` operation extension.
+//!
 //! **Note:** These macros use `crate::` paths for utoipa body types instead of `$crate::`
 //! because utoipa's proc macro cannot resolve `$crate::` tokens. This means these macros
 //! can only be used within this crate, not from external crates.
@@ -50,16 +54,17 @@ macro_rules! crud_get_by_id_handler {
                 (status = 200, description = concat!(stringify!($entity), " found"), body = $crate::server::shared::types::api::ApiResponse<$entity>),
                 (status = 404, description = concat!(stringify!($entity), " not found"), body = $crate::server::shared::types::api::ApiErrorResponse),
             ),
+             extensions(("x-required-scope" = json!($crate::server::auth::middleware::permissions::Scope::<$entity, $crate::server::auth::middleware::permissions::Read>::name()))),
              security(("user_api_key" = []), ("session" = []))
         )]
         pub async fn get_by_id(
             state: axum::extract::State<std::sync::Arc<$crate::server::config::AppState>>,
-            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::Viewer>,
+            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::And<$crate::server::auth::middleware::permissions::Viewer, $crate::server::auth::middleware::permissions::Scope<$entity, $crate::server::auth::middleware::permissions::Read>>>,
             path: axum::extract::Path<uuid::Uuid>,
         ) -> $crate::server::shared::types::api::ApiResult<
             axum::response::Json<$crate::server::shared::types::api::ApiResponse<$entity>>,
         > {
-            $crate::server::shared::handlers::traits::get_by_id_handler::<$entity>(state, auth, path)
+            $crate::server::shared::handlers::traits::get_by_id_handler::<$entity>(state, auth.into_permission::<$crate::server::auth::middleware::permissions::Viewer>(), path)
                 .await
         }
     };
@@ -80,16 +85,17 @@ macro_rules! crud_create_handler {
                 (status = 200, description = concat!(stringify!($entity), " created"), body = $crate::server::shared::types::api::ApiResponse<$entity>),
                 (status = 400, description = "Invalid request", body = $crate::server::shared::types::api::ApiErrorResponse),
             ),
+             extensions(("x-required-scope" = json!($crate::server::auth::middleware::permissions::Scope::<$entity, $crate::server::auth::middleware::permissions::Write>::name()))),
              security(("user_api_key" = []), ("session" = []))
         )]
         pub async fn create(
             state: axum::extract::State<std::sync::Arc<$crate::server::config::AppState>>,
-            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::Member>,
+            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::And<$crate::server::auth::middleware::permissions::Member, $crate::server::auth::middleware::permissions::Scope<$entity, $crate::server::auth::middleware::permissions::Write>>>,
             body: axum::response::Json<$entity>,
         ) -> $crate::server::shared::types::api::ApiResult<
             axum::response::Json<$crate::server::shared::types::api::ApiResponse<$entity>>,
         > {
-            $crate::server::shared::handlers::traits::create_handler::<$entity>(state, auth, body)
+            $crate::server::shared::handlers::traits::create_handler::<$entity>(state, auth.into_permission::<$crate::server::auth::middleware::permissions::Member>(), body)
                 .await
         }
     };
@@ -111,18 +117,22 @@ macro_rules! crud_update_handler {
                 (status = 200, description = concat!(stringify!($entity), " updated"), body = $crate::server::shared::types::api::ApiResponse<$entity>),
                 (status = 404, description = concat!(stringify!($entity), " not found"), body = $crate::server::shared::types::api::ApiErrorResponse),
             ),
+             extensions(("x-required-scope" = json!($crate::server::auth::middleware::permissions::Scope::<$entity, $crate::server::auth::middleware::permissions::Write>::name()))),
              security(("user_api_key" = []), ("session" = []))
         )]
         pub async fn update(
             state: axum::extract::State<std::sync::Arc<$crate::server::config::AppState>>,
-            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::Member>,
+            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::And<$crate::server::auth::middleware::permissions::Member, $crate::server::auth::middleware::permissions::Scope<$entity, $crate::server::auth::middleware::permissions::Write>>>,
             path: axum::extract::Path<uuid::Uuid>,
             body: axum::response::Json<$entity>,
         ) -> $crate::server::shared::types::api::ApiResult<
             axum::response::Json<$crate::server::shared::types::api::ApiResponse<$entity>>,
         > {
             $crate::server::shared::handlers::traits::update_handler::<$entity>(
-                state, auth, path, body,
+                state,
+                auth.into_permission::<$crate::server::auth::middleware::permissions::Member>(),
+                path,
+                body,
             )
             .await
         }
@@ -144,16 +154,17 @@ macro_rules! crud_delete_handler {
                 (status = 200, description = concat!(stringify!($entity), " deleted"), body = $crate::server::shared::types::api::EmptyApiResponse),
                 (status = 404, description = concat!(stringify!($entity), " not found"), body = $crate::server::shared::types::api::ApiErrorResponse),
             ),
+             extensions(("x-required-scope" = json!($crate::server::auth::middleware::permissions::Scope::<$entity, $crate::server::auth::middleware::permissions::Delete>::name()))),
              security(("user_api_key" = []), ("session" = []))
         )]
         pub async fn delete(
             state: axum::extract::State<std::sync::Arc<$crate::server::config::AppState>>,
-            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::Member>,
+            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::And<$crate::server::auth::middleware::permissions::Member, $crate::server::auth::middleware::permissions::Scope<$entity, $crate::server::auth::middleware::permissions::Delete>>>,
             path: axum::extract::Path<uuid::Uuid>,
         ) -> $crate::server::shared::types::api::ApiResult<
             axum::response::Json<$crate::server::shared::types::api::ApiResponse<()>>,
         > {
-            $crate::server::shared::handlers::traits::delete_handler::<$entity>(state, auth, path)
+            $crate::server::shared::handlers::traits::delete_handler::<$entity>(state, auth.into_permission::<$crate::server::auth::middleware::permissions::Member>(), path)
                 .await
         }
     };
@@ -173,11 +184,12 @@ macro_rules! crud_bulk_delete_handler {
             responses(
                 (status = 200, description = concat!(stringify!($entity), "s deleted"), body = $crate::server::shared::types::api::ApiResponse<$crate::server::shared::handlers::traits::BulkDeleteResponse>),
             ),
+             extensions(("x-required-scope" = json!($crate::server::auth::middleware::permissions::Scope::<$entity, $crate::server::auth::middleware::permissions::Delete>::name()))),
              security(("user_api_key" = []), ("session" = []))
         )]
         pub async fn bulk_delete(
             state: axum::extract::State<std::sync::Arc<$crate::server::config::AppState>>,
-            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::Member>,
+            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::And<$crate::server::auth::middleware::permissions::Member, $crate::server::auth::middleware::permissions::Scope<$entity, $crate::server::auth::middleware::permissions::Delete>>>,
             body: axum::response::Json<Vec<uuid::Uuid>>,
         ) -> $crate::server::shared::types::api::ApiResult<
             axum::response::Json<
@@ -187,7 +199,9 @@ macro_rules! crud_bulk_delete_handler {
             >,
         > {
             $crate::server::shared::handlers::traits::bulk_delete_handler::<$entity>(
-                state, auth, body,
+                state,
+                auth.into_permission::<$crate::server::auth::middleware::permissions::Member>(),
+                body,
             )
             .await
         }
@@ -223,16 +237,17 @@ macro_rules! crud_get_all_handler {
                 // Use inline() to force utoipa 5.0 to generate unique schema for each generic instantiation
                 (status = 200, description = concat!("List of ", $tag), body = inline(__PaginatedResponse)),
             ),
+             extensions(("x-required-scope" = json!($crate::server::auth::middleware::permissions::Scope::<$entity, $crate::server::auth::middleware::permissions::Read>::name()))),
              security(("user_api_key" = []), ("session" = []))
         )]
         pub async fn get_all(
             state: axum::extract::State<std::sync::Arc<$crate::server::config::AppState>>,
-            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::Viewer>,
+            auth: $crate::server::auth::middleware::permissions::Authorized<$crate::server::auth::middleware::permissions::And<$crate::server::auth::middleware::permissions::Viewer, $crate::server::auth::middleware::permissions::Scope<$entity, $crate::server::auth::middleware::permissions::Read>>>,
             query: $crate::server::shared::extractors::Query<__GetAllFilterQuery>,
         ) -> $crate::server::shared::types::api::ApiResult<
             axum::response::Json<__PaginatedResponse>,
         > {
-            $crate::server::shared::handlers::traits::get_all_handler::<$entity>(state, auth, query)
+            $crate::server::shared::handlers::traits::get_all_handler::<$entity>(state, auth.into_permission::<$crate::server::auth::middleware::permissions::Viewer>(), query)
                 .await
         }
     };
@@ -412,3 +412,84 @@ impl FilterQueryExtractor for SharesQuery {
         }
     }
 }
+
+/// Query for filtering topology snapshots by network_id or topology_id
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct SnapshotsQuery {
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by topology ID
+    pub topology_id: Option<Uuid>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl FilterQueryExtractor for SnapshotsQuery {
+    fn apply_to_filter(
+        &self,
+        filter: EntityFilter,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> EntityFilter {
+        let mut filter = match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]),
+            None => filter.network_ids(user_network_ids),
+        };
+        filter = match self.topology_id {
+            Some(id) => filter.topology_id(&id),
+            None => filter,
+        };
+
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+/// Query for filtering network links. Only links with both ends on networks the user can
+/// access are returned.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct NetworkLinksQuery {
+    /// Filter by the network a link starts from
+    pub network_id: Option<Uuid>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl FilterQueryExtractor for NetworkLinksQuery {
+    fn apply_to_filter(
+        &self,
+        filter: EntityFilter,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> EntityFilter {
+        let filter = match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]),
+            None => filter.network_ids(user_network_ids),
+        };
+
+        filter.uuid_columns("target_network_id", user_network_ids)
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
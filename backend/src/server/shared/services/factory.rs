@@ -1,8 +1,16 @@
 use crate::server::{
-    auth::{oidc::OidcService, service::AuthService},
+    auth::{
+        r#impl::{mfa::UserMfaCredentialStorage, sessions::UserSessionStorage},
+        ldap::LdapService,
+        mfa::MfaService,
+        oidc::OidcService,
+        service::AuthService,
+        sessions::SessionService,
+    },
     billing::service::{BillingService, BillingServiceParams},
     bindings::service::BindingService,
     config::ServerConfig,
+    credentials::{r#impl::cipher::CredentialCipher, service::CredentialService},
     daemon_api_keys::service::DaemonApiKeyService,
     daemons::service::DaemonService,
     discovery::service::DiscoveryService,
@@ -13,9 +21,11 @@ use crate::server::{
     interfaces::service::InterfaceService,
     invites::service::InviteService,
     logging::service::LoggingService,
+    network_links::service::NetworkLinkService,
     networks::service::NetworkService,
     organizations::service::OrganizationService,
     ports::service::PortService,
+    scim::service::ScimService,
     services::service::ServiceService,
     shared::{
         events::bus::EventBus,
@@ -23,9 +33,11 @@ use crate::server::{
         storage::{entity_tags::EntityTagStorage, factory::StorageFactory},
     },
     shares::service::ShareService,
+    snapshots::service::SnapshotService,
     subnets::service::SubnetService,
     tags::service::TagService,
     topology::service::main::TopologyService,
+    traceroutes::service::TracerouteService,
     user_api_keys::{
         r#impl::network_access::UserApiKeyNetworkAccessStorage, service::UserApiKeyService,
     },
@@ -37,13 +49,18 @@ use std::sync::Arc;
 pub struct ServiceFactory {
     pub user_service: Arc<UserService>,
     pub auth_service: Arc<AuthService>,
+    pub mfa_service: Arc<MfaService>,
+    pub session_service: Arc<SessionService>,
     pub network_service: Arc<NetworkService>,
+    pub network_link_service: Arc<NetworkLinkService>,
     pub host_service: Arc<HostService>,
     pub interface_service: Arc<InterfaceService>,
     pub group_service: Arc<GroupService>,
     pub subnet_service: Arc<SubnetService>,
     pub daemon_service: Arc<DaemonService>,
     pub topology_service: Arc<TopologyService>,
+    pub snapshot_service: Arc<SnapshotService>,
+    pub traceroute_service: Arc<TracerouteService>,
     pub service_service: Arc<ServiceService>,
     pub discovery_service: Arc<DiscoveryService>,
     pub daemon_api_key_service: Arc<DaemonApiKeyService>,
@@ -51,7 +68,9 @@ pub struct ServiceFactory {
     pub organization_service: Arc<OrganizationService>,
     pub invite_service: Arc<InviteService>,
     pub share_service: Arc<ShareService>,
+    pub credential_service: Arc<CredentialService>,
     pub oidc_service: Option<Arc<OidcService>>,
+    pub ldap_service: Option<Arc<LdapService>>,
     pub billing_service: Option<Arc<BillingService>>,
     pub email_service: Option<Arc<EmailService>>,
     pub event_bus: Arc<EventBus>,
@@ -60,6 +79,7 @@ pub struct ServiceFactory {
     pub entity_tag_service: Arc<EntityTagService>,
     pub port_service: Arc<PortService>,
     pub binding_service: Arc<BindingService>,
+    pub scim_service: Arc<ScimService>,
 }
 
 impl ServiceFactory {
@@ -111,7 +131,23 @@ impl ServiceFactory {
             event_bus.clone(),
         ));
 
-        let share_service = Arc::new(ShareService::new(storage.shares.clone(), event_bus.clone()));
+        let share_service = Arc::new(ShareService::new(
+            storage.shares.clone(),
+            storage.share_tokens.clone(),
+            storage.share_views.clone(),
+            event_bus.clone(),
+        ));
+
+        let credential_cipher = config
+            .as_ref()
+            .and_then(|c| c.credential_key.as_deref())
+            .map(CredentialCipher::from_hex_key)
+            .transpose()?;
+        let credential_service = Arc::new(CredentialService::new(
+            storage.credentials.clone(),
+            event_bus.clone(),
+            credential_cipher,
+        ));
 
         let port_service = Arc::new(PortService::new(storage.ports.clone(), event_bus.clone()));
 
@@ -162,6 +198,11 @@ impl ServiceFactory {
         // ServiceService needs HostService for circular reference
         let _ = service_service.set_host_service(host_service.clone());
 
+        let traceroute_service = Arc::new(TracerouteService::new(
+            storage.traceroutes.clone(),
+            event_bus.clone(),
+        ));
+
         let topology_service = Arc::new(TopologyService::new(
             host_service.clone(),
             interface_service.clone(),
@@ -170,10 +211,24 @@ impl ServiceFactory {
             service_service.clone(),
             port_service.clone(),
             binding_service.clone(),
+            traceroute_service.clone(),
             storage.topologies.clone(),
             event_bus.clone(),
         ));
 
+        let snapshot_service = Arc::new(SnapshotService::new(
+            storage.topology_snapshots.clone(),
+            topology_service.clone(),
+            event_bus.clone(),
+            config
+                .as_ref()
+                .and_then(|c| c.topology_snapshot_schedule.clone()),
+            config
+                .as_ref()
+                .map(|c| c.topology_snapshot_retention)
+                .unwrap_or(30),
+        ));
+
         let network_service = Arc::new(NetworkService::new(
             storage.networks.clone(),
             subnet_service.clone(),
@@ -181,6 +236,11 @@ impl ServiceFactory {
             entity_tag_service.clone(),
         ));
 
+        let network_link_service = Arc::new(NetworkLinkService::new(
+            storage.network_links.clone(),
+            event_bus.clone(),
+        ));
+
         let user_network_access_storage =
             Arc::new(UserNetworkAccessStorage::new(storage.pool.clone()));
         let user_service = Arc::new(UserService::new(
@@ -239,6 +299,47 @@ impl ServiceFactory {
             event_bus.clone(),
         ));
 
+        let public_url = config
+            .as_ref()
+            .map(|c| c.public_url.clone())
+            .unwrap_or_else(|| "http://localhost:60072".to_string());
+
+        let mfa_credential_storage = Arc::new(UserMfaCredentialStorage::new(storage.pool.clone()));
+        let mfa_service = Arc::new(MfaService::new(
+            mfa_credential_storage,
+            organization_service.clone(),
+            event_bus.clone(),
+            &public_url,
+        ));
+
+        let scim_service = Arc::new(ScimService::new(
+            storage.scim_tokens.clone(),
+            storage.scim_groups.clone(),
+            user_service.clone(),
+            user_api_key_service.clone(),
+            &public_url,
+        ));
+
+        let ldap_service = config.as_ref().and_then(|c| c.ldap.clone()).map(|ldap| {
+            Arc::new(LdapService::new(
+                ldap,
+                auth_service.clone(),
+                user_service.clone(),
+                event_bus.clone(),
+            ))
+        });
+
+        let session_storage = Arc::new(UserSessionStorage::new(storage.pool.clone()));
+        let session_service = Arc::new(SessionService::new(
+            session_storage,
+            event_bus.clone(),
+            config
+                .as_ref()
+                .map(|c| c.session_idle_timeout_minutes)
+                .unwrap_or(7 * 24 * 60),
+            config.as_ref().and_then(|c| c.session_max_lifetime_hours),
+        ));
+
         let oidc_service = config.and_then(|c| {
             if let Some(oidc_providers) = c.oidc_providers {
                 return Some(Arc::new(OidcService::new(
@@ -256,6 +357,9 @@ impl ServiceFactory {
         event_bus
             .register_subscriber(topology_service.clone())
             .await;
+        event_bus
+            .register_subscriber(snapshot_service.clone())
+            .await;
 
         event_bus.register_subscriber(logging_service.clone()).await;
         event_bus
@@ -273,13 +377,18 @@ impl ServiceFactory {
         Ok(Self {
             user_service,
             auth_service,
+            mfa_service,
+            session_service,
             network_service,
+            network_link_service,
             host_service,
             interface_service,
             group_service,
             subnet_service,
             daemon_service,
             topology_service,
+            snapshot_service,
+            traceroute_service,
             service_service,
             discovery_service,
             daemon_api_key_service,
@@ -287,7 +396,9 @@ impl ServiceFactory {
             organization_service,
             invite_service,
             share_service,
+            credential_service,
             oidc_service,
+            ldap_service,
             billing_service,
             email_service,
             event_bus,
@@ -296,6 +407,7 @@ impl ServiceFactory {
             entity_tag_service,
             port_service,
             binding_service,
+            scim_service,
         })
     }
 }
@@ -5,14 +5,30 @@ use tower_sessions::{Expiry, SessionManagerLayer};
 use tower_sessions_sqlx_store::PostgresStore;
 
 use crate::server::{
-    bindings::r#impl::base::Binding, daemon_api_keys::r#impl::base::DaemonApiKey,
-    daemons::r#impl::base::Daemon, discovery::r#impl::base::Discovery, groups::r#impl::base::Group,
-    hosts::r#impl::base::Host, interfaces::r#impl::base::Interface, invites::r#impl::base::Invite,
-    networks::r#impl::Network, organizations::r#impl::base::Organization,
-    ports::r#impl::base::Port, services::r#impl::base::Service,
-    shared::storage::generic::GenericPostgresStorage, shares::r#impl::base::Share,
-    subnets::r#impl::base::Subnet, tags::r#impl::base::Tag, topology::types::base::Topology,
-    user_api_keys::r#impl::base::UserApiKey, users::r#impl::base::User,
+    bindings::r#impl::base::Binding,
+    credentials::r#impl::base::Credential,
+    daemon_api_keys::r#impl::base::DaemonApiKey,
+    daemons::r#impl::base::Daemon,
+    discovery::r#impl::base::Discovery,
+    groups::r#impl::base::Group,
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    invites::r#impl::base::Invite,
+    network_links::r#impl::base::NetworkLink,
+    networks::r#impl::Network,
+    organizations::r#impl::base::Organization,
+    ports::r#impl::base::Port,
+    scim::r#impl::base::{ScimGroup, ScimToken},
+    services::r#impl::base::Service,
+    shared::storage::generic::GenericPostgresStorage,
+    shares::r#impl::{base::Share, tokens::ShareToken, views::ShareView},
+    snapshots::r#impl::base::TopologySnapshot,
+    subnets::r#impl::base::Subnet,
+    tags::r#impl::base::Tag,
+    topology::types::base::Topology,
+    traceroutes::r#impl::base::Traceroute,
+    user_api_keys::r#impl::base::UserApiKey,
+    users::r#impl::base::User,
 };
 
 pub struct StorageFactory {
@@ -22,6 +38,7 @@ pub struct StorageFactory {
     pub user_api_keys: Arc<GenericPostgresStorage<UserApiKey>>,
     pub users: Arc<GenericPostgresStorage<User>>,
     pub networks: Arc<GenericPostgresStorage<Network>>,
+    pub network_links: Arc<GenericPostgresStorage<NetworkLink>>,
     pub hosts: Arc<GenericPostgresStorage<Host>>,
     pub interfaces: Arc<GenericPostgresStorage<Interface>>,
     pub groups: Arc<GenericPostgresStorage<Group>>,
@@ -31,23 +48,33 @@ pub struct StorageFactory {
     pub organizations: Arc<GenericPostgresStorage<Organization>>,
     pub invites: Arc<GenericPostgresStorage<Invite>>,
     pub shares: Arc<GenericPostgresStorage<Share>>,
+    pub share_tokens: Arc<GenericPostgresStorage<ShareToken>>,
+    pub share_views: Arc<GenericPostgresStorage<ShareView>>,
+    pub credentials: Arc<GenericPostgresStorage<Credential>>,
     pub discovery: Arc<GenericPostgresStorage<Discovery>>,
     pub topologies: Arc<GenericPostgresStorage<Topology>>,
+    pub topology_snapshots: Arc<GenericPostgresStorage<TopologySnapshot>>,
+    pub traceroutes: Arc<GenericPostgresStorage<Traceroute>>,
     pub tags: Arc<GenericPostgresStorage<Tag>>,
     pub ports: Arc<GenericPostgresStorage<Port>>,
     pub bindings: Arc<GenericPostgresStorage<Binding>>,
+    pub scim_tokens: Arc<GenericPostgresStorage<ScimToken>>,
+    pub scim_groups: Arc<GenericPostgresStorage<ScimGroup>>,
 }
 
 pub async fn create_session_store(
     db_pool: Pool<Postgres>,
     use_secure: bool,
+    idle_timeout_minutes: u64,
 ) -> Result<SessionManagerLayer<PostgresStore>> {
     let session_store = PostgresStore::new(db_pool.clone());
 
     session_store.migrate().await?;
 
     Ok(SessionManagerLayer::new(session_store)
-        .with_expiry(Expiry::OnInactivity(time::Duration::days(7)))
+        .with_expiry(Expiry::OnInactivity(time::Duration::minutes(
+            idle_timeout_minutes.min(u32::MAX.into()) as i64,
+        )))
         .with_name("session_id")
         .with_secure(use_secure)
         .with_http_only(true)
@@ -55,12 +82,21 @@ pub async fn create_session_store(
 }
 
 impl StorageFactory {
-    pub async fn new(database_url: &str, use_secure_session_cookies: bool) -> Result<Self> {
+    pub async fn new(
+        database_url: &str,
+        use_secure_session_cookies: bool,
+        session_idle_timeout_minutes: u64,
+    ) -> Result<Self> {
         let pool = PgPool::connect(database_url).await?;
 
         sqlx::migrate!("./migrations").run(&pool).await?;
 
-        let sessions = create_session_store(pool.clone(), use_secure_session_cookies).await?;
+        let sessions = create_session_store(
+            pool.clone(),
+            use_secure_session_cookies,
+            session_idle_timeout_minutes,
+        )
+        .await?;
 
         Ok(Self {
             pool: pool.clone(),
@@ -69,10 +105,14 @@ impl StorageFactory {
             organizations: Arc::new(GenericPostgresStorage::new(pool.clone())),
             invites: Arc::new(GenericPostgresStorage::new(pool.clone())),
             shares: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            share_tokens: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            share_views: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            credentials: Arc::new(GenericPostgresStorage::new(pool.clone())),
             daemon_api_keys: Arc::new(GenericPostgresStorage::new(pool.clone())),
             user_api_keys: Arc::new(GenericPostgresStorage::new(pool.clone())),
             users: Arc::new(GenericPostgresStorage::new(pool.clone())),
             networks: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            network_links: Arc::new(GenericPostgresStorage::new(pool.clone())),
             hosts: Arc::new(GenericPostgresStorage::new(pool.clone())),
             interfaces: Arc::new(GenericPostgresStorage::new(pool.clone())),
             groups: Arc::new(GenericPostgresStorage::new(pool.clone())),
@@ -80,9 +120,13 @@ impl StorageFactory {
             subnets: Arc::new(GenericPostgresStorage::new(pool.clone())),
             services: Arc::new(GenericPostgresStorage::new(pool.clone())),
             topologies: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            topology_snapshots: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            traceroutes: Arc::new(GenericPostgresStorage::new(pool.clone())),
             tags: Arc::new(GenericPostgresStorage::new(pool.clone())),
             ports: Arc::new(GenericPostgresStorage::new(pool.clone())),
             bindings: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            scim_tokens: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            scim_groups: Arc::new(GenericPostgresStorage::new(pool.clone())),
         })
     }
 }
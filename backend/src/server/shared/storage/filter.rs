@@ -175,6 +175,16 @@ impl EntityFilter {
         self
     }
 
+    /// Entities whose discovery metadata carries this identity
+    pub fn discovery_identity(mut self, identity: &str) -> Self {
+        self.conditions.push(format!(
+            "source->'metadata' @> jsonb_build_array(jsonb_build_object('identity', ${}::text))",
+            self.values.len() + 1
+        ));
+        self.values.push(SqlValue::String(identity.to_string()));
+        self
+    }
+
     pub fn group_id(mut self, id: &Uuid) -> Self {
         self.conditions
             .push(format!("group_id = ${}", self.values.len() + 1));
@@ -279,6 +289,13 @@ impl EntityFilter {
         self
     }
 
+    pub fn share_id(mut self, share_id: &Uuid) -> Self {
+        self.conditions
+            .push(format!("share_id = ${}", self.values.len() + 1));
+        self.values.push(SqlValue::Uuid(*share_id));
+        self
+    }
+
     pub fn user_permissions(mut self, permissions: &UserOrgPermissions) -> Self {
         self.conditions
             .push(format!("permissions = ${}", self.values.len() + 1));
@@ -293,6 +310,20 @@ impl EntityFilter {
         self
     }
 
+    pub fn created_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.conditions
+            .push(format!("created_at < ${}", self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
+    pub fn last_activity_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.conditions
+            .push(format!("last_activity_at < ${}", self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
     /// Generic UUID filter for any column name.
     /// Used by generic child entity handlers to filter by parent_column dynamically.
     pub fn uuid_column(mut self, column: &str, id: &Uuid) -> Self {
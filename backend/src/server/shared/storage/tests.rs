@@ -1,5 +1,6 @@
 use crate::server::{
     bindings::r#impl::base::Binding,
+    credentials::r#impl::base::Credential,
     daemon_api_keys::r#impl::base::DaemonApiKey,
     daemons::r#impl::base::Daemon,
     discovery::r#impl::base::Discovery,
@@ -8,15 +9,18 @@ use crate::server::{
     hosts::r#impl::base::Host,
     interfaces::r#impl::base::Interface,
     invites::r#impl::base::Invite,
+    network_links::r#impl::base::NetworkLink,
     networks::r#impl::Network,
     organizations::r#impl::base::Organization,
     ports::r#impl::base::Port,
     services::r#impl::base::Service,
     shared::storage::{entity_tags::EntityTag, traits::StorableEntity},
-    shares::r#impl::base::Share,
+    shares::r#impl::{base::Share, tokens::ShareToken, views::ShareView},
+    snapshots::r#impl::base::TopologySnapshot,
     subnets::r#impl::base::Subnet,
     tags::r#impl::base::Tag,
     topology::types::base::Topology,
+    traceroutes::r#impl::base::Traceroute,
     user_api_keys::r#impl::base::UserApiKey,
     users::r#impl::base::User,
 };
@@ -123,6 +127,30 @@ fn get_entity_deserializers() -> HashMap<&'static str, DeserializeFn> {
         }),
     );
 
+    map.insert(
+        TopologySnapshot::table_name(),
+        Box::new(|row| {
+            TopologySnapshot::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        Traceroute::table_name(),
+        Box::new(|row| {
+            Traceroute::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        NetworkLink::table_name(),
+        Box::new(|row| {
+            NetworkLink::from_row(row)?;
+            Ok(())
+        }),
+    );
+
     map.insert(
         Tag::table_name(),
         Box::new(|row| {
@@ -147,6 +175,30 @@ fn get_entity_deserializers() -> HashMap<&'static str, DeserializeFn> {
         }),
     );
 
+    map.insert(
+        ShareToken::table_name(),
+        Box::new(|row| {
+            ShareToken::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        ShareView::table_name(),
+        Box::new(|row| {
+            ShareView::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        Credential::table_name(),
+        Box::new(|row| {
+            Credential::from_row(row)?;
+            Ok(())
+        }),
+    );
+
     map.insert(
         Interface::table_name(),
         Box::new(|row| {
@@ -136,6 +136,7 @@ pub enum SqlValue {
     String(String),
     OptionalString(Option<String>),
     I32(i32),
+    OptionalI32(Option<i32>),
     U16(u16),
     Bool(bool),
     Email(EmailAddress),
@@ -35,3 +35,29 @@ pub enum Color {
     #[serde(other)]
     Yellow,
 }
+
+impl Color {
+    /// RGB of the 400 shade the UI uses for icons and strokes
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Color::Pink => (244, 114, 182),
+            Color::Rose => (251, 113, 133),
+            Color::Red => (248, 113, 113),
+            Color::Orange => (251, 146, 60),
+            Color::Yellow => (250, 204, 21),
+            Color::Green => (74, 222, 128),
+            Color::Emerald => (52, 211, 153),
+            Color::Teal => (45, 212, 191),
+            Color::Cyan => (34, 211, 238),
+            Color::Blue => (96, 165, 250),
+            Color::Indigo => (129, 140, 248),
+            Color::Purple => (196, 181, 253),
+            Color::Gray => (156, 163, 175),
+        }
+    }
+
+    pub fn hex(&self) -> String {
+        let (r, g, b) = self.rgb();
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
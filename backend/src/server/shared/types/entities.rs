@@ -38,6 +38,17 @@ impl EntitySource {
             EntitySource::Discovery { .. } | EntitySource::DiscoveryWithMatch { .. }
         )
     }
+
+    /// Stable identity reported by discovery, if any
+    pub fn discovery_identity(&self) -> Option<&str> {
+        match self {
+            EntitySource::Discovery { metadata }
+            | EntitySource::DiscoveryWithMatch { metadata, .. } => {
+                metadata.iter().find_map(|m| m.identity.as_deref())
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
@@ -46,6 +57,10 @@ pub struct DiscoveryMetadata {
     pub discovery_type: DiscoveryType,
     pub daemon_id: Uuid,
     pub date: DateTime<Utc>,
+    /// What was discovered, for entities whose names aren't unique on a network, such as
+    /// "k8s:ingress:default/web:example.com/api". Used to find the entity on later runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<String>,
 }
 
 impl DiscoveryMetadata {
@@ -54,8 +69,14 @@ impl DiscoveryMetadata {
             discovery_type,
             daemon_id,
             date: Utc::now(),
+            identity: None,
         }
     }
+
+    pub fn with_identity(mut self, identity: String) -> Self {
+        self.identity = Some(identity);
+        self
+    }
 }
 
 impl Default for DiscoveryMetadata {
@@ -67,6 +88,7 @@ impl Default for DiscoveryMetadata {
             },
             daemon_id: Uuid::new_v4(),
             date: Utc::now(),
+            identity: None,
         }
     }
 }
@@ -293,6 +293,7 @@ pub fn user() -> User {
             oidc_linked_at: None,
             network_ids: vec![ids::NETWORK],
             terms_accepted_at: Some(example_timestamp()),
+            deactivated_at: None,
         },
     }
 }
@@ -309,6 +310,7 @@ pub fn organization() -> Organization {
             plan: None,
             plan_status: None,
             onboarding: vec![],
+            require_mfa: false,
         },
     }
 }
@@ -331,6 +333,7 @@ pub fn discovery() -> Discovery {
                 last_run: Some(example_timestamp()),
             },
             tags: vec![],
+            credential_ids: vec![],
         },
     }
 }
@@ -1,11 +1,18 @@
-use std::sync::Arc;
+use std::{convert::Infallible, net::IpAddr, sync::Arc};
 
 use axum::{
     Json,
     extract::{Path, Query, State},
     http::{HeaderMap, header},
-    response::{IntoResponse, Response},
+    response::{
+        IntoResponse, Response, Sse,
+        sse::{Event, KeepAlive},
+    },
+    routing::{get, post},
 };
+use axum_client_ip::ClientIp;
+use axum_extra::{TypedHeader, headers::UserAgent};
+use futures::{Stream, stream};
 use serde::Deserialize;
 use utoipa::ToSchema;
 use utoipa_axum::{router::OpenApiRouter, routes};
@@ -13,11 +20,12 @@ use uuid::Uuid;
 
 use crate::server::{
     auth::{
-        middleware::permissions::{Authorized, Member},
+        middleware::permissions::{And, Authorized, Member, Read, Scope, Write},
         service::hash_password,
     },
-    billing::types::base::BillingPlan,
+    billing::types::base::BillingPlanFeatures,
     config::AppState,
+    organizations::r#impl::base::Organization,
     shared::{
         handlers::traits::{CrudHandlers, create_handler, update_handler},
         services::traits::CrudService,
@@ -25,8 +33,18 @@ use crate::server::{
         types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult},
     },
     shares::r#impl::{
-        api::{CreateUpdateShareRequest, PublicShareMetadata, ShareWithTopology},
+        api::{
+            CreateShareTokenRequest, CreateUpdateShareRequest, PublicShareMetadata,
+            ShareTokenResponse, ShareWithTopology,
+        },
         base::Share,
+        tokens::ShareToken,
+        views::{ShareViewBase, ShareViewStats},
+    },
+    topology::{
+        handlers::rendered_topology_response,
+        service::render::render_blocking,
+        types::render::{RenderParams, RenderQuery},
     },
 };
 
@@ -61,14 +79,17 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
             generated::delete
         ))
         .routes(routes!(generated::bulk_delete))
+        .routes(routes!(list_share_tokens, create_share_token))
+        .routes(routes!(revoke_share_token))
+        .routes(routes!(get_share_view_stats))
         // Public routes (no auth required)
         .routes(routes!(get_public_share_metadata))
         .routes(routes!(verify_share_password))
+        .routes(routes!(get_share_image))
         // Public topology route (complex response handling - use regular route for now)
-        .route(
-            "/public/{id}/topology",
-            axum::routing::post(get_share_topology),
-        )
+        .route("/public/{id}/topology", post(get_share_topology))
+        // SSE endpoint (not well-supported by OpenAPI)
+        .route("/public/{id}/stream", get(share_topology_stream))
 }
 
 // ============================================================================
@@ -85,11 +106,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Share created", body = ApiResponse<Share>),
         (status = 400, description = "Invalid request", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Share, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_share(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Share, Write>>>,
     Json(CreateUpdateShareRequest {
         mut share,
         password,
@@ -107,7 +129,7 @@ async fn create_share(
         .user_id()
         .ok_or_else(|| ApiError::forbidden("User context required"))?;
 
-    create_handler::<Share>(State(state), auth, Json(share)).await
+    create_handler::<Share>(State(state), auth.into_permission::<Member>(), Json(share)).await
 }
 
 /// Update a share
@@ -121,11 +143,12 @@ async fn create_share(
         (status = 200, description = "Share updated", body = ApiResponse<Share>),
         (status = 404, description = "Share not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Share, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_share(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Share, Write>>>,
     Path(id): Path<Uuid>,
     Json(CreateUpdateShareRequest {
         mut share,
@@ -160,15 +183,179 @@ async fn update_share(
     }
 
     // Delegate to generic handler
-    update_handler::<Share>(State(state), auth, Path(id), Json(share)).await
+    update_handler::<Share>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(share),
+    )
+    .await
+}
+
+/// Fetch a share, checking the caller has access to its network
+async fn get_managed_share(
+    state: &AppState,
+    id: &Uuid,
+    network_ids: &[Uuid],
+) -> Result<Share, ApiError> {
+    let share = Share::get_service(state)
+        .get_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Share '{}' not found", id)))?;
+
+    if !network_ids.contains(&share.base.network_id) {
+        return Err(ApiError::forbidden("You don't have access to this share"));
+    }
+
+    Ok(share)
+}
+
+/// List a share's access tokens
+#[utoipa::path(
+    get,
+    path = "/{id}/tokens",
+    tag = "shares",
+    params(("id" = Uuid, Path, description = "Share ID")),
+    responses(
+        (status = 200, description = "Share tokens", body = ApiResponse<Vec<ShareToken>>),
+        (status = 404, description = "Share not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Share, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn list_share_tokens(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<Share, Read>>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<Vec<ShareToken>>>> {
+    let share = get_managed_share(&state, &id, &auth.network_ids()).await?;
+
+    let tokens = state.services.share_service.get_tokens(&share.id).await?;
+
+    Ok(Json(ApiResponse::success(tokens)))
+}
+
+/// Create an access token for a share
+///
+/// The token can be sent in place of the share's password, and views made with it are
+/// attributed to it. The plaintext key is only returned once.
+#[utoipa::path(
+    post,
+    path = "/{id}/tokens",
+    tag = "shares",
+    params(("id" = Uuid, Path, description = "Share ID")),
+    request_body = CreateShareTokenRequest,
+    responses(
+        (status = 200, description = "Share token created", body = ApiResponse<ShareTokenResponse>),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 404, description = "Share not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Share, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_share_token(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    auth: Authorized<And<Member, Scope<Share, Write>>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateShareTokenRequest>,
+) -> ApiResult<Json<ApiResponse<ShareTokenResponse>>> {
+    let name = request.name.trim().to_string();
+    if name.is_empty() || name.len() > 100 {
+        return Err(ApiError::bad_request(
+            "Token name must be between 1 and 100 characters",
+        ));
+    }
+
+    let share = get_managed_share(&state, &id, &auth.network_ids()).await?;
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    let (token, key) = state
+        .services
+        .share_service
+        .create_token(&share, name, ip, user_agent, auth.into_entity())
+        .await?;
+
+    Ok(Json(ApiResponse::success(ShareTokenResponse {
+        token,
+        key,
+    })))
+}
+
+/// Revoke a share access token
+#[utoipa::path(
+    post,
+    path = "/{id}/tokens/{token_id}/revoke",
+    tag = "shares",
+    params(
+        ("id" = Uuid, Path, description = "Share ID"),
+        ("token_id" = Uuid, Path, description = "Share token ID")
+    ),
+    responses(
+        (status = 200, description = "Share token revoked", body = ApiResponse<ShareToken>),
+        (status = 404, description = "Share or token not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Share, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn revoke_share_token(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    auth: Authorized<And<Member, Scope<Share, Write>>>,
+    Path((id, token_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<Json<ApiResponse<ShareToken>>> {
+    let share = get_managed_share(&state, &id, &auth.network_ids()).await?;
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    let token = state
+        .services
+        .share_service
+        .revoke_token(&share, &token_id, ip, user_agent, auth.into_entity())
+        .await
+        .map_err(|e| ApiError::not_found(e.to_string()))?;
+
+    Ok(Json(ApiResponse::success(token)))
+}
+
+/// Get view statistics for a share
+///
+/// Covers views from the last 90 days. Views are only recorded on plans with share analytics.
+#[utoipa::path(
+    get,
+    path = "/{id}/views",
+    tag = "shares",
+    params(("id" = Uuid, Path, description = "Share ID")),
+    responses(
+        (status = 200, description = "Share view statistics", body = ApiResponse<ShareViewStats>),
+        (status = 404, description = "Share not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Share, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_share_view_stats(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<Share, Read>>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<ShareViewStats>>> {
+    let share = get_managed_share(&state, &id, &auth.network_ids()).await?;
+
+    let stats = state
+        .services
+        .share_service
+        .get_view_stats(&share.id)
+        .await?;
+
+    Ok(Json(ApiResponse::success(stats)))
 }
 
 // ============================================================================
 // Public Routes (No Authentication Required)
 // ============================================================================
 
-/// Helper to get the organization's plan for a share
-async fn get_share_org_plan(state: &AppState, share: &Share) -> Result<BillingPlan, ApiError> {
+/// Helper to get the organization a share belongs to
+async fn get_share_org(state: &AppState, share: &Share) -> Result<Organization, ApiError> {
     // Get network to find organization
     let network = state
         .services
@@ -179,16 +366,118 @@ async fn get_share_org_plan(state: &AppState, share: &Share) -> Result<BillingPl
         .map_err(|e| ApiError::internal_error(&e.to_string()))?
         .ok_or_else(|| ApiError::not_found("Network not found".to_string()))?;
 
-    // Get organization to find plan
-    let org = state
+    state
         .services
         .organization_service
         .get_by_id(&network.base.organization_id)
         .await
         .map_err(|e| ApiError::internal_error(&e.to_string()))?
-        .ok_or_else(|| ApiError::not_found("Organization not found".to_string()))?;
+        .ok_or_else(|| ApiError::not_found("Organization not found".to_string()))
+}
+
+/// Check an embed request against the organization's plan and the share's allowed domains
+fn check_embed_access(
+    state: &AppState,
+    share: &Share,
+    features: &BillingPlanFeatures,
+    embed: bool,
+    req_headers: &HeaderMap,
+) -> Result<(), ApiError> {
+    // If requesting embed mode, check if org has embeds feature
+    if embed && !features.embeds {
+        return Err(ApiError::payment_required(
+            "Embed access requires a plan with embeds feature",
+        ));
+    }
+
+    // Validate allowed_domains only for embed requests
+    if embed && share.has_domain_restrictions() {
+        let referer = req_headers
+            .get(header::REFERER)
+            .and_then(|v| v.to_str().ok());
+
+        if !state
+            .services
+            .share_service
+            .validate_allowed_domains(share, referer)
+        {
+            return Err(ApiError::forbidden("Domain not allowed"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the password or access token sent for a share, if it needs one. Returns the token
+/// the share was opened with.
+async fn check_share_secret(
+    state: &AppState,
+    share: &Share,
+    organization_id: Uuid,
+    secret: Option<&str>,
+    ip: IpAddr,
+    req_headers: &HeaderMap,
+) -> Result<Option<ShareToken>, ApiError> {
+    let share_service = &state.services.share_service;
+
+    if !share_service
+        .requires_secret(share)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+    {
+        return Ok(None);
+    }
+
+    let Some(secret) = secret else {
+        return Err(ApiError::unauthorized("Password required".to_string()));
+    };
 
-    Ok(org.base.plan.unwrap_or_default())
+    let user_agent = req_headers
+        .get(header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string());
+
+    share_service
+        .verify_share_secret(share, secret, organization_id, ip, user_agent)
+        .await
+        .map_err(|_| ApiError::unauthorized("Invalid password".to_string()))
+}
+
+/// Metadata shown to viewers. Shares with active tokens ask for a secret like a password.
+async fn get_public_metadata(
+    state: &AppState,
+    share: &Share,
+) -> Result<PublicShareMetadata, ApiError> {
+    let mut metadata = PublicShareMetadata::from(share);
+    metadata.requires_password = state
+        .services
+        .share_service
+        .requires_secret(share)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?;
+
+    Ok(metadata)
+}
+
+/// Record a view in the background, if the organization's plan includes share views
+fn record_share_view(
+    state: &AppState,
+    share: &Share,
+    features: &BillingPlanFeatures,
+    token: Option<&ShareToken>,
+    embed: bool,
+    ip: IpAddr,
+    req_headers: &HeaderMap,
+) {
+    if !features.share_views {
+        return;
+    }
+
+    let view = ShareViewBase::from_request(share.id, token.map(|t| t.id), embed, ip, req_headers);
+    let share_service = state.services.share_service.clone();
+    tokio::spawn(async move {
+        share_service.record_view(view).await;
+    });
 }
 
 /// Get share metadata
@@ -222,12 +511,12 @@ async fn get_public_share_metadata(
         ));
     }
 
-    Ok(Json(ApiResponse::success(PublicShareMetadata::from(
-        &share,
-    ))))
+    Ok(Json(ApiResponse::success(
+        get_public_metadata(&state, &share).await?,
+    )))
 }
 
-/// Verify password for a password-protected share (returns success/failure only)
+/// Verify the password or an access token for a protected share (returns success/failure only)
 #[utoipa::path(
     post,
     path = "/public/{id}/verify",
@@ -235,14 +524,16 @@ async fn get_public_share_metadata(
     params(("id" = Uuid, Path, description = "Share ID")),
     request_body = String,
     responses(
-        (status = 200, description = "Password verified", body = ApiResponse<bool>),
-        (status = 401, description = "Invalid password", body = ApiErrorResponse),
+        (status = 200, description = "Password or token verified", body = ApiResponse<bool>),
+        (status = 401, description = "Invalid password or token", body = ApiErrorResponse),
         (status = 404, description = "Share not found", body = ApiErrorResponse),
     )
 )]
 async fn verify_share_password(
     State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
     Path(id): Path<Uuid>,
+    req_headers: HeaderMap,
     Json(password): Json<String>,
 ) -> ApiResult<Json<ApiResponse<bool>>> {
     let share = state
@@ -259,16 +550,19 @@ async fn verify_share_password(
         ));
     }
 
-    if !share.requires_password() {
+    if !state
+        .services
+        .share_service
+        .requires_secret(&share)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+    {
         return Err(ApiError::bad_request("Share does not require a password"));
     }
 
-    // Verify password - returns error if invalid
-    state
-        .services
-        .share_service
-        .verify_share_password(&share, &password)
-        .map_err(|_| ApiError::unauthorized("Invalid password".to_string()))?;
+    // Verify password or token - returns error if invalid
+    let org = get_share_org(&state, &share).await?;
+    check_share_secret(&state, &share, org.id, Some(&password), ip, &req_headers).await?;
 
     Ok(Json(ApiResponse::success(true)))
 }
@@ -276,6 +570,7 @@ async fn verify_share_password(
 /// Get topology data for a public share
 async fn get_share_topology(
     State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
     Path(id): Path<Uuid>,
     Query(query): Query<ShareQuery>,
     req_headers: HeaderMap,
@@ -293,50 +588,25 @@ async fn get_share_topology(
         return Err(ApiError::not_found("Share disabled or expired".to_string()));
     }
 
-    // Get org's plan to check embed feature
-    let plan = get_share_org_plan(&state, &share).await?;
-    let has_embeds_feature = plan.features().embeds;
+    let org = get_share_org(&state, &share).await?;
+    let features = org.base.plan.unwrap_or_default().features();
+    let has_embeds_feature = features.embeds;
 
-    // If requesting embed mode, check if org has embeds feature
-    if query.embed && !has_embeds_feature {
-        return Err(ApiError::payment_required(
-            "Embed access requires a plan with embeds feature",
-        ));
-    }
+    check_embed_access(&state, &share, &features, query.embed, &req_headers)?;
 
-    // Handle password-protected shares
-    if share.requires_password() {
-        match &body.password {
-            Some(password) => {
-                state
-                    .services
-                    .share_service
-                    .verify_share_password(&share, password)
-                    .map_err(|_| ApiError::unauthorized("Invalid password".to_string()))?;
-            }
-            None => {
-                return Err(ApiError::unauthorized("Password required".to_string()));
-            }
-        }
-    }
-
-    // Validate allowed_domains only for embed requests
-    if query.embed && share.has_domain_restrictions() {
-        let referer = req_headers
-            .get(header::REFERER)
-            .and_then(|v| v.to_str().ok());
-
-        if !state
-            .services
-            .share_service
-            .validate_allowed_domains(&share, referer)
-        {
-            return Err(ApiError::forbidden("Domain not allowed"));
-        }
-    }
+    // Handle shares protected by a password or access tokens
+    let token = check_share_secret(
+        &state,
+        &share,
+        org.id,
+        body.password.as_deref(),
+        ip,
+        &req_headers,
+    )
+    .await?;
 
     // Get topology data
-    let topology = state
+    let mut topology = state
         .services
         .topology_service
         .storage()
@@ -345,8 +615,20 @@ async fn get_share_topology(
         .map_err(|e| ApiError::internal_error(&e.to_string()))?
         .ok_or_else(|| ApiError::not_found("Topology not found".to_string()))?;
 
+    share.base.redaction.apply(&mut topology);
+
+    record_share_view(
+        &state,
+        &share,
+        &features,
+        token.as_ref(),
+        query.embed,
+        ip,
+        &req_headers,
+    );
+
     let response_data = ShareWithTopology {
-        share: PublicShareMetadata::from(&share),
+        share: get_public_metadata(&state, &share).await?,
         topology: serde_json::to_value(&topology)
             .map_err(|e| ApiError::internal_error(&e.to_string()))?,
     };
@@ -389,3 +671,188 @@ async fn get_share_topology(
 
     Ok(response)
 }
+
+/// Stream live updates to a public share
+///
+/// Sends the share's topology, redacted, each time it is refreshed or goes stale. Not available
+/// for shares behind a password or tokens, since EventSource has no way to send them.
+async fn share_topology_stream(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ShareQuery>,
+    req_headers: HeaderMap,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    let share = state
+        .services
+        .share_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+        .ok_or_else(|| ApiError::not_found("Share not found".to_string()))?;
+
+    if !share.is_valid() {
+        return Err(ApiError::not_found("Share disabled or expired".to_string()));
+    }
+
+    if !share.base.options.live_updates {
+        return Err(ApiError::forbidden(
+            "Live updates are disabled for this share",
+        ));
+    }
+
+    if state
+        .services
+        .share_service
+        .requires_secret(&share)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+    {
+        return Err(ApiError::unauthorized(
+            "Password-protected shares can't be streamed".to_string(),
+        ));
+    }
+
+    let org = get_share_org(&state, &share).await?;
+    let features = org.base.plan.unwrap_or_default().features();
+    check_embed_access(&state, &share, &features, query.embed, &req_headers)?;
+
+    let topology_id = share.base.topology_id;
+    let rx = state
+        .services
+        .topology_service
+        .subscribe_staleness_changes();
+
+    let stream = stream::unfold(rx, move |mut rx| {
+        let state = state.clone();
+        async move {
+            loop {
+                match rx.recv().await {
+                    Ok(mut topology) => {
+                        if topology.id != topology_id {
+                            continue;
+                        }
+
+                        // The share may have been disabled or its redaction changed since the
+                        // stream was opened
+                        let share = state
+                            .services
+                            .share_service
+                            .get_by_id(&id)
+                            .await
+                            .ok()
+                            .flatten()?;
+                        if !share.is_valid()
+                            || !share.base.options.live_updates
+                            || state
+                                .services
+                                .share_service
+                                .requires_secret(&share)
+                                .await
+                                .unwrap_or(true)
+                        {
+                            return None;
+                        }
+
+                        share.base.redaction.apply(&mut topology);
+                        let json = serde_json::to_string(&topology).ok()?;
+                        return Some((Ok(Event::default().data(json)), rx));
+                    }
+                    Err(_) => return None,
+                }
+            }
+        }
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Get a rendered image of a public share
+///
+/// Static image URL for reports, wikis and emails. Not available for password-protected
+/// shares or shares with export disabled.
+#[utoipa::path(
+    get,
+    path = "/public/{id}/image",
+    tag = "shares",
+    params(("id" = Uuid, Path, description = "Share ID"), RenderQuery),
+    responses(
+        (status = 200, description = "Rendered topology", content(
+            (String = "image/svg+xml"),
+            (Vec<u8> = "image/png"),
+            (Vec<u8> = "application/pdf")
+        )),
+        (status = 400, description = "Invalid render options", body = ApiErrorResponse),
+        (status = 401, description = "Share is password protected", body = ApiErrorResponse),
+        (status = 403, description = "Export disabled for this share", body = ApiErrorResponse),
+        (status = 404, description = "Share not found", body = ApiErrorResponse),
+    )
+)]
+async fn get_share_image(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    Path(id): Path<Uuid>,
+    Query(query): Query<RenderQuery>,
+    req_headers: HeaderMap,
+) -> ApiResult<Response> {
+    let share = state
+        .services
+        .share_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+        .ok_or_else(|| ApiError::not_found("Share not found".to_string()))?;
+
+    if !share.is_valid() {
+        return Err(ApiError::not_found("Share disabled or expired".to_string()));
+    }
+
+    // A static URL has nowhere to send a password or token
+    if state
+        .services
+        .share_service
+        .requires_secret(&share)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+    {
+        return Err(ApiError::unauthorized(
+            "Password-protected shares can't be served as images".to_string(),
+        ));
+    }
+
+    if !share.base.options.show_export_button {
+        return Err(ApiError::forbidden("Export is disabled for this share"));
+    }
+
+    let mut topology = state
+        .services
+        .topology_service
+        .storage()
+        .get_by_id(&share.base.topology_id)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+        .ok_or_else(|| ApiError::not_found("Topology not found".to_string()))?;
+
+    share.base.redaction.apply(&mut topology);
+
+    let org = get_share_org(&state, &share).await?;
+    let features = org.base.plan.unwrap_or_default().features();
+    record_share_view(&state, &share, &features, None, false, ip, &req_headers);
+
+    let params = RenderParams::new(
+        query.format,
+        topology.base.options.clone(),
+        query.scale,
+        query.page_size,
+    )?;
+
+    let name = share.base.name.clone();
+    let bytes = render_blocking(topology, params).await?;
+
+    let mut response = rendered_topology_response(bytes, query.format, &name);
+    response.headers_mut().insert(
+        header::CACHE_CONTROL,
+        "public, max-age=300".parse().unwrap(),
+    );
+
+    Ok(response)
+}
@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use super::base::{Share, ShareOptions};
+use super::{
+    base::{Share, ShareOptions},
+    tokens::ShareToken,
+};
 
 #[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateUpdateShareRequest {
@@ -36,3 +39,15 @@ pub struct ShareWithTopology {
     pub share: PublicShareMetadata,
     pub topology: serde_json::Value,
 }
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateShareTokenRequest {
+    pub name: String,
+}
+
+/// A newly created share token. The plaintext key is only returned here.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ShareTokenResponse {
+    pub token: ShareToken,
+    pub key: String,
+}
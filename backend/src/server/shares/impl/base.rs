@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
 use crate::server::shared::{
@@ -21,6 +22,10 @@ pub struct ShareOptions {
     pub show_zoom_controls: bool,
     #[schema(required)]
     pub show_export_button: bool,
+    /// Push topology refreshes and staleness to viewers as they happen
+    #[serde(default)]
+    #[schema(required)]
+    pub live_updates: bool,
 }
 
 impl Default for ShareOptions {
@@ -29,10 +34,29 @@ impl Default for ShareOptions {
             show_inspect_panel: true,
             show_zoom_controls: true,
             show_export_button: true,
+            live_updates: false,
         }
     }
 }
 
+/// What a share leaves out of the topology it serves. Applied on the server, so hidden data
+/// never reaches viewers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema)]
+#[serde(default)]
+pub struct ShareRedaction {
+    /// Replace IP addresses and subnet ranges with unspecified addresses
+    #[schema(required)]
+    pub hide_ip_addresses: bool,
+    #[schema(required)]
+    pub hide_mac_addresses: bool,
+    /// Leave out hosts with any of these tags, along with their interfaces and services
+    #[schema(required)]
+    pub hidden_host_tags: Vec<Uuid>,
+    /// Names to show instead of the real ones, by host, subnet, service or group ID
+    #[schema(required)]
+    pub aliases: BTreeMap<Uuid, String>,
+}
+
 #[derive(
     Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
 )]
@@ -50,6 +74,9 @@ pub struct ShareBase {
     #[schema(required)]
     pub allowed_domains: Option<Vec<String>>,
     pub options: ShareOptions,
+    #[serde(default)]
+    #[schema(required)]
+    pub redaction: ShareRedaction,
 }
 
 #[derive(
@@ -180,6 +207,7 @@ impl StorableEntity for Share {
                 "password_hash",
                 "allowed_domains",
                 "options",
+                "redaction",
                 "created_at",
                 "updated_at",
             ],
@@ -194,6 +222,7 @@ impl StorableEntity for Share {
                 SqlValue::OptionalString(self.base.password_hash.clone()),
                 SqlValue::OptionalStringArray(self.base.allowed_domains.clone()),
                 SqlValue::JsonValue(serde_json::to_value(&self.base.options)?),
+                SqlValue::JsonValue(serde_json::to_value(&self.base.redaction)?),
                 SqlValue::Timestamp(self.created_at),
                 SqlValue::Timestamp(self.updated_at),
             ],
@@ -203,6 +232,8 @@ impl StorableEntity for Share {
     fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
         let options_value: serde_json::Value = row.get("options");
         let options: ShareOptions = serde_json::from_value(options_value)?;
+        let redaction_value: serde_json::Value = row.get("redaction");
+        let redaction: ShareRedaction = serde_json::from_value(redaction_value)?;
 
         Ok(Share {
             id: row.get("id"),
@@ -218,6 +249,7 @@ impl StorableEntity for Share {
                 password_hash: row.get("password_hash"),
                 allowed_domains: row.get("allowed_domains"),
                 options,
+                redaction,
             },
         })
     }
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     shared::handlers::{query::SharesQuery, traits::CrudHandlers},
     shares::{r#impl::base::Share, service::ShareService},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Share {
@@ -12,3 +13,7 @@ impl CrudHandlers for Share {
         &state.services.share_service
     }
 }
+
+impl ScopedResource for Share {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Shares;
+}
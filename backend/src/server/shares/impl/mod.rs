@@ -1,3 +1,6 @@
 pub mod api;
 pub mod base;
 pub mod handlers;
+pub mod redaction;
+pub mod tokens;
+pub mod views;
@@ -0,0 +1,465 @@
+use std::{
+    collections::HashSet,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use cidr::IpCidr;
+use uuid::Uuid;
+
+use crate::server::{
+    services::r#impl::patterns::MatchReason,
+    shared::types::entities::EntitySource,
+    shares::r#impl::base::ShareRedaction,
+    topology::types::{base::Topology, edges::EdgeType, nodes::NodeType},
+};
+
+/// Stands in for addresses found in names and labels
+const HIDDEN_ADDRESS: &str = "[hidden]";
+
+impl ShareRedaction {
+    pub fn is_empty(&self) -> bool {
+        !self.hide_ip_addresses
+            && !self.hide_mac_addresses
+            && self.hidden_host_tags.is_empty()
+            && self.aliases.is_empty()
+    }
+
+    /// Strip the topology down to what this share allows viewers to see
+    pub fn apply(&self, topology: &mut Topology) {
+        if !self.hidden_host_tags.is_empty() {
+            self.remove_tagged_hosts(topology);
+        }
+
+        if self.hide_mac_addresses {
+            for interface in &mut topology.base.interfaces {
+                interface.base.mac_address = None;
+            }
+        }
+
+        if self.hide_ip_addresses {
+            Self::hide_ip_addresses(topology);
+        }
+
+        // Last, so an alias is shown as written even when it looks like an address
+        if !self.aliases.is_empty() {
+            self.apply_aliases(topology);
+        }
+    }
+
+    fn remove_tagged_hosts(&self, topology: &mut Topology) {
+        let base = &mut topology.base;
+
+        let host_ids: HashSet<Uuid> = base
+            .hosts
+            .iter()
+            .filter(|h| {
+                h.base
+                    .tags
+                    .iter()
+                    .any(|t| self.hidden_host_tags.contains(t))
+            })
+            .map(|h| h.id)
+            .collect();
+
+        if host_ids.is_empty() {
+            return;
+        }
+
+        let service_ids: HashSet<Uuid> = base
+            .services
+            .iter()
+            .filter(|s| host_ids.contains(&s.base.host_id))
+            .map(|s| s.id)
+            .collect();
+        let binding_ids: HashSet<Uuid> = base
+            .bindings
+            .iter()
+            .filter(|b| service_ids.contains(&b.base.service_id))
+            .map(|b| b.id)
+            .collect();
+
+        base.hosts.retain(|h| !host_ids.contains(&h.id));
+        base.interfaces
+            .retain(|i| !host_ids.contains(&i.base.host_id));
+        base.services.retain(|s| !service_ids.contains(&s.id));
+        base.ports.retain(|p| !host_ids.contains(&p.base.host_id));
+        base.bindings.retain(|b| !binding_ids.contains(&b.id));
+        for group in &mut base.groups {
+            group
+                .base
+                .binding_ids
+                .retain(|id| !binding_ids.contains(id));
+        }
+
+        let removed_nodes: HashSet<Uuid> = base
+            .nodes
+            .iter()
+            .filter(|n| match n.node_type {
+                NodeType::InterfaceNode { host_id, .. }
+                | NodeType::HostNode { host_id }
+                | NodeType::ServiceNode { host_id, .. }
+                | NodeType::NetworkHostNode { host_id, .. } => host_ids.contains(&host_id),
+                NodeType::StubNode { binding_id } => binding_ids.contains(&binding_id),
+                NodeType::SubnetNode { .. }
+                | NodeType::NetworkNode { .. }
+                | NodeType::NetworkSubnetNode { .. } => false,
+            })
+            .map(|n| n.id)
+            .collect();
+
+        base.nodes.retain(|n| !removed_nodes.contains(&n.id));
+        base.edges.retain(|e| {
+            let references_removed = match e.edge_type {
+                EdgeType::Interface { host_id } => host_ids.contains(&host_id),
+                EdgeType::HostVirtualization { vm_service_id } => {
+                    service_ids.contains(&vm_service_id)
+                }
+                EdgeType::ServiceVirtualization {
+                    host_id,
+                    containerizing_service_id,
+                } => {
+                    host_ids.contains(&host_id) || service_ids.contains(&containerizing_service_id)
+                }
+                EdgeType::RequestPath {
+                    source_binding_id,
+                    target_binding_id,
+                    ..
+                }
+                | EdgeType::HubAndSpoke {
+                    source_binding_id,
+                    target_binding_id,
+                    ..
+                } => {
+                    binding_ids.contains(&source_binding_id)
+                        || binding_ids.contains(&target_binding_id)
+                }
+                EdgeType::SharedHost {
+                    source_host_id,
+                    target_host_id,
+                } => host_ids.contains(&source_host_id) || host_ids.contains(&target_host_id),
+                EdgeType::SharedSubnet { .. }
+                | EdgeType::NetworkLink { .. }
+                | EdgeType::Route { .. } => false,
+            };
+
+            !references_removed
+                && !removed_nodes.contains(&e.source)
+                && !removed_nodes.contains(&e.target)
+        });
+    }
+
+    fn hide_ip_addresses(topology: &mut Topology) {
+        let base = &mut topology.base;
+
+        for interface in &mut base.interfaces {
+            interface.base.ip_address = unspecified(interface.base.ip_address);
+            interface.base.name = interface.base.name.as_deref().map(redact_addresses);
+        }
+
+        for subnet in &mut base.subnets {
+            subnet.base.cidr = hidden_cidr(subnet.base.cidr);
+            subnet.base.name = redact_addresses(&subnet.base.name);
+            subnet.base.description = subnet.base.description.as_deref().map(redact_addresses);
+            redact_source(&mut subnet.base.source);
+        }
+
+        for host in &mut base.hosts {
+            host.base.name = redact_addresses(&host.base.name);
+            host.base.hostname = host.base.hostname.as_deref().map(redact_addresses);
+            host.base.description = host.base.description.as_deref().map(redact_addresses);
+            redact_source(&mut host.base.source);
+        }
+
+        for service in &mut base.services {
+            service.base.name = redact_addresses(&service.base.name);
+            redact_source(&mut service.base.source);
+        }
+
+        for group in &mut base.groups {
+            group.base.name = redact_addresses(&group.base.name);
+            group.base.description = group.base.description.as_deref().map(redact_addresses);
+            redact_source(&mut group.base.source);
+        }
+
+        // Consolidated subnets list their ranges in the header
+        for node in &mut base.nodes {
+            node.header = node.header.as_deref().map(redact_addresses);
+        }
+
+        for edge in &mut base.edges {
+            edge.label = edge.label.as_deref().map(redact_addresses);
+        }
+    }
+
+    fn apply_aliases(&self, topology: &mut Topology) {
+        let base = &mut topology.base;
+
+        for host in &mut base.hosts {
+            if let Some(alias) = self.aliases.get(&host.id) {
+                host.base.name = alias.clone();
+                // The hostname would give the real name away
+                host.base.hostname = None;
+            }
+        }
+
+        for subnet in &mut base.subnets {
+            if let Some(alias) = self.aliases.get(&subnet.id) {
+                subnet.base.name = alias.clone();
+            }
+        }
+
+        for service in &mut base.services {
+            if let Some(alias) = self.aliases.get(&service.id) {
+                service.base.name = alias.clone();
+            }
+        }
+
+        for group in &mut base.groups {
+            if let Some(alias) = self.aliases.get(&group.id) {
+                group.base.name = alias.clone();
+            }
+        }
+
+        // Nodes that stand for a host are headed with its name
+        for node in &mut base.nodes {
+            if let NodeType::ServiceNode { host_id, .. } | NodeType::NetworkHostNode { host_id, .. } =
+                node.node_type
+                && let Some(alias) = self.aliases.get(&host_id)
+                && node.header.is_some()
+            {
+                node.header = Some(alias.clone());
+            }
+        }
+    }
+}
+
+fn unspecified(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+    }
+}
+
+/// Keeps the prefix length, so viewers can still tell a /24 from a /16, and leaves the
+/// internet's 0.0.0.0/0 as it is
+fn hidden_cidr(cidr: IpCidr) -> IpCidr {
+    IpCidr::new(unspecified(cidr.first_address()), cidr.network_length()).unwrap_or(cidr)
+}
+
+/// Discovery metadata carries scan targets and match reasons can quote addresses
+fn redact_source(source: &mut EntitySource) {
+    match source {
+        EntitySource::Discovery { metadata } => metadata.clear(),
+        EntitySource::DiscoveryWithMatch { metadata, details } => {
+            metadata.clear();
+            redact_match_reason(&mut details.reason);
+        }
+        EntitySource::Manual | EntitySource::System | EntitySource::Unknown => {}
+    }
+}
+
+fn redact_match_reason(reason: &mut MatchReason) {
+    match reason {
+        MatchReason::Reason(text) => *text = redact_addresses(text),
+        MatchReason::Container(text, reasons) => {
+            *text = redact_addresses(text);
+            reasons.iter_mut().for_each(redact_match_reason);
+        }
+    }
+}
+
+/// Replace every IP address or CIDR range in free text, e.g. hosts named after their address
+fn redact_addresses(text: &str) -> String {
+    let is_address_char = |c: char| c.is_ascii_hexdigit() || matches!(c, '.' | ':' | '/');
+    let is_address =
+        |token: &str| IpAddr::from_str(token).is_ok() || IpCidr::from_str(token).is_ok();
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut token_start = None;
+
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        if i < text.len() && is_address_char(c) {
+            token_start.get_or_insert(i);
+            continue;
+        }
+
+        if let Some(start) = token_start.take() {
+            let token = &text[start..i];
+            // Sentence punctuation isn't part of the address
+            let trimmed = token.trim_end_matches(['.', ':']);
+            if is_address(trimmed) {
+                redacted.push_str(HIDDEN_ADDRESS);
+                redacted.push_str(&token[trimmed.len()..]);
+            } else {
+                redacted.push_str(token);
+            }
+        }
+
+        if i < text.len() {
+            redacted.push(c);
+        }
+    }
+
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::server::{
+        bindings::r#impl::base::Binding,
+        interfaces::r#impl::base::Interface,
+        shared::storage::traits::StorableEntity,
+        topology::types::{
+            base::TopologyBase,
+            edges::{Edge, EdgeHandle},
+            layout::{Ixy, Uxy},
+            nodes::Node,
+        },
+    };
+    use crate::tests;
+
+    fn node(id: Uuid, node_type: NodeType) -> Node {
+        Node {
+            node_type,
+            id,
+            position: Ixy { x: 0, y: 0 },
+            size: Uxy { x: 100, y: 100 },
+            header: None,
+        }
+    }
+
+    #[test]
+    fn test_redact_addresses_in_text() {
+        assert_eq!(redact_addresses("192.168.1.20"), "[hidden]");
+        assert_eq!(
+            redact_addresses("Docker Bridge: (172.17.0.0/16, 172.18.0.0/16)"),
+            "Docker Bridge: ([hidden], [hidden])"
+        );
+        assert_eq!(
+            redact_addresses("Port 53 open on fe80::1."),
+            "Port 53 open on [hidden]."
+        );
+        assert_eq!(redact_addresses("cafe dns-01"), "cafe dns-01");
+    }
+
+    #[test]
+    fn test_hidden_host_tags_remove_host_and_graph() {
+        let network_id = Uuid::new_v4();
+        let secret_tag = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+
+        let mut hidden = tests::host(&network_id);
+        hidden.base.tags = vec![secret_tag];
+        let shown = tests::host(&network_id);
+
+        let mut hidden_interface = tests::interface(&network_id, &subnet.id);
+        hidden_interface.base.host_id = hidden.id;
+        let mut shown_interface = tests::interface(&network_id, &subnet.id);
+        shown_interface.base.host_id = shown.id;
+
+        let hidden_service = tests::service(&network_id, &hidden.id);
+        let hidden_binding =
+            Binding::new_interface(hidden_service.id, network_id, hidden_interface.id);
+        let shown_service = tests::service(&network_id, &shown.id);
+        let shown_binding =
+            Binding::new_interface(shown_service.id, network_id, shown_interface.id);
+
+        let mut group = tests::group(&network_id);
+        group.base.binding_ids = vec![hidden_binding.id, shown_binding.id];
+
+        let interface_node = |interface: &Interface| {
+            node(
+                interface.id,
+                NodeType::InterfaceNode {
+                    subnet_id: subnet.id,
+                    host_id: interface.base.host_id,
+                    interface_id: Some(interface.id),
+                    is_infra: false,
+                },
+            )
+        };
+
+        let mut topology = Topology::new(TopologyBase::new("Shared".to_string(), network_id));
+        topology.base.hosts = vec![hidden.clone(), shown.clone()];
+        topology.base.interfaces = vec![hidden_interface.clone(), shown_interface.clone()];
+        topology.base.services = vec![hidden_service, shown_service];
+        topology.base.bindings = vec![hidden_binding, shown_binding];
+        topology.base.subnets = vec![subnet.clone()];
+        topology.base.groups = vec![group.clone()];
+        topology.base.nodes = vec![
+            node(subnet.id, NodeType::SubnetNode { infra_width: 0 }),
+            interface_node(&hidden_interface),
+            interface_node(&shown_interface),
+        ];
+        topology.base.edges = vec![Edge {
+            id: Uuid::new_v4(),
+            source: shown_interface.id,
+            target: hidden_interface.id,
+            edge_type: EdgeType::RequestPath {
+                group_id: group.id,
+                source_binding_id: shown_binding.id,
+                target_binding_id: hidden_binding.id,
+            },
+            label: None,
+            source_handle: EdgeHandle::Top,
+            target_handle: EdgeHandle::Top,
+            is_multi_hop: false,
+        }];
+
+        ShareRedaction {
+            hidden_host_tags: vec![secret_tag],
+            ..Default::default()
+        }
+        .apply(&mut topology);
+
+        assert_eq!(topology.base.hosts, vec![shown]);
+        assert_eq!(topology.base.interfaces.len(), 1);
+        assert_eq!(topology.base.services.len(), 1);
+        assert_eq!(topology.base.bindings, vec![shown_binding]);
+        assert_eq!(
+            topology.base.groups[0].base.binding_ids,
+            vec![shown_binding.id]
+        );
+        assert_eq!(topology.base.nodes.len(), 2);
+        assert!(topology.base.edges.is_empty());
+    }
+
+    #[test]
+    fn test_hide_addresses_and_apply_aliases() {
+        let network_id = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+
+        let mut named_by_ip = tests::host(&network_id);
+        named_by_ip.base.name = "192.168.1.100".to_string();
+        named_by_ip.base.hostname = None;
+        let aliased = tests::host(&network_id);
+
+        let mut interface = tests::interface(&network_id, &subnet.id);
+        interface.base.host_id = named_by_ip.id;
+
+        let mut topology = Topology::new(TopologyBase::new("Shared".to_string(), network_id));
+        topology.base.hosts = vec![named_by_ip, aliased.clone()];
+        topology.base.interfaces = vec![interface];
+        topology.base.subnets = vec![subnet];
+
+        ShareRedaction {
+            hide_ip_addresses: true,
+            hide_mac_addresses: true,
+            aliases: BTreeMap::from([(aliased.id, "Core Switch".to_string())]),
+            ..Default::default()
+        }
+        .apply(&mut topology);
+
+        let interface = &topology.base.interfaces[0];
+        assert_eq!(interface.base.ip_address, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(interface.base.mac_address, None);
+        assert_eq!(topology.base.subnets[0].base.cidr.to_string(), "0.0.0.0/24");
+        assert_eq!(topology.base.hosts[0].base.name, "[hidden]");
+        assert_eq!(topology.base.hosts[1].base.name, "Core Switch");
+        assert_eq!(topology.base.hosts[1].base.hostname, None);
+    }
+}
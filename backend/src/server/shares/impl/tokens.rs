@@ -0,0 +1,154 @@
+use std::fmt::Display;
+
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::shared::{
+    entities::EntityDiscriminants,
+    storage::traits::{SqlValue, StorableEntity},
+    types::api::serialize_sensitive_info,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash, ToSchema)]
+pub struct ShareTokenBase {
+    pub share_id: Uuid,
+    pub name: String,
+    /// SHA-256 hash of the token
+    #[serde(default)]
+    #[serde(serialize_with = "serialize_sensitive_info")]
+    #[schema(read_only, required)]
+    pub key: String,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub last_used: Option<DateTime<Utc>>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// A named token granting access to a share in place of its password. Each one can be
+/// revoked on its own, and views made with it are attributed to it.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash, ToSchema)]
+pub struct ShareToken {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub base: ShareTokenBase,
+}
+
+impl ShareToken {
+    pub fn is_revoked(&self) -> bool {
+        self.base.revoked_at.is_some()
+    }
+}
+
+impl Display for ShareToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ShareToken {} ({}, share={})",
+            self.id, self.base.name, self.base.share_id
+        )
+    }
+}
+
+impl StorableEntity for ShareToken {
+    type BaseData = ShareTokenBase;
+
+    fn table_name() -> &'static str {
+        "share_tokens"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.last_used.unwrap_or(self.created_at)
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, _time: DateTime<Utc>) {
+        // No updated_at column; last_used and revoked_at are set explicitly
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::ShareToken
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>)> {
+        Ok((
+            vec![
+                "id",
+                "share_id",
+                "name",
+                "key",
+                "created_at",
+                "last_used",
+                "revoked_at",
+            ],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.share_id),
+                SqlValue::String(self.base.name.clone()),
+                SqlValue::String(self.base.key.clone()),
+                SqlValue::Timestamp(self.created_at),
+                SqlValue::OptionTimestamp(self.base.last_used),
+                SqlValue::OptionTimestamp(self.base.revoked_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        Ok(ShareToken {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: ShareTokenBase {
+                share_id: row.get("share_id"),
+                name: row.get("name"),
+                key: row.get("key"),
+                last_used: row.get("last_used"),
+                revoked_at: row.get("revoked_at"),
+            },
+        })
+    }
+}
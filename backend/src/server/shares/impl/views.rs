@@ -0,0 +1,405 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use anyhow::{Error, Result};
+use axum::http::{HeaderMap, header};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, postgres::PgRow};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{SqlValue, StorableEntity},
+    },
+    shares::r#impl::tokens::ShareToken,
+};
+
+/// Headers CDNs and proxies put the viewer's country in, as an ISO 3166 code
+const COUNTRY_HEADERS: [&str; 2] = ["cf-ipcountry", "cloudfront-viewer-country"];
+
+/// How many referrers, countries and tokens the stats list
+const TOP_COUNT: usize = 10;
+
+/// How many of the latest views the stats include
+const RECENT_COUNT: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash, ToSchema)]
+pub struct ShareViewBase {
+    pub share_id: Uuid,
+    /// Token the share was opened with, if any
+    pub token_id: Option<Uuid>,
+    /// Domain of the page the share was opened or embedded from
+    pub referrer_domain: Option<String>,
+    /// The viewer's network rather than their address: a /24 for IPv4, a /48 for IPv6
+    pub ip_bucket: String,
+    /// Country reported by a CDN in front of the server, if there is one
+    pub country: Option<String>,
+    pub embed: bool,
+}
+
+impl ShareViewBase {
+    pub fn from_request(
+        share_id: Uuid,
+        token_id: Option<Uuid>,
+        embed: bool,
+        ip: IpAddr,
+        headers: &HeaderMap,
+    ) -> Self {
+        let referrer_domain = headers
+            .get(header::REFERER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|referer| url::Url::parse(referer).ok())
+            .and_then(|url| url.host_str().map(|host| host.to_lowercase()));
+
+        // Cloudflare reports XX for unknown and T1 for Tor
+        let country = COUNTRY_HEADERS
+            .iter()
+            .find_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+            .map(|code| code.trim().to_uppercase())
+            .filter(|code| code.len() == 2 && code != "XX" && code != "T1");
+
+        Self {
+            share_id,
+            token_id,
+            referrer_domain,
+            ip_bucket: ip_bucket(ip),
+            country,
+            embed,
+        }
+    }
+}
+
+/// A single load of a share's topology or image
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash, ToSchema)]
+pub struct ShareView {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub base: ShareViewBase,
+}
+
+impl Display for ShareView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ShareView {} (share={}, from={})",
+            self.id, self.base.share_id, self.base.ip_bucket
+        )
+    }
+}
+
+impl StorableEntity for ShareView {
+    type BaseData = ShareViewBase;
+
+    fn table_name() -> &'static str {
+        "share_views"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, _time: DateTime<Utc>) {
+        // Views are never updated
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::ShareView
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>)> {
+        Ok((
+            vec![
+                "id",
+                "share_id",
+                "token_id",
+                "referrer_domain",
+                "ip_bucket",
+                "country",
+                "embed",
+                "created_at",
+            ],
+            vec![
+                SqlValue::Uuid(self.id),
+                SqlValue::Uuid(self.base.share_id),
+                SqlValue::OptionalUuid(self.base.token_id),
+                SqlValue::OptionalString(self.base.referrer_domain.clone()),
+                SqlValue::String(self.base.ip_bucket.clone()),
+                SqlValue::OptionalString(self.base.country.clone()),
+                SqlValue::Bool(self.base.embed),
+                SqlValue::Timestamp(self.created_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, Error> {
+        Ok(ShareView {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: ShareViewBase {
+                share_id: row.get("share_id"),
+                token_id: row.get("token_id"),
+                referrer_domain: row.get("referrer_domain"),
+                ip_bucket: row.get("ip_bucket"),
+                country: row.get("country"),
+                embed: row.get("embed"),
+            },
+        })
+    }
+}
+
+/// Number of views for one referrer, country or token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct ShareViewCount {
+    pub key: String,
+    pub count: usize,
+}
+
+/// Aggregate view counters for a share
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct ShareViewStats {
+    pub total_views: usize,
+    /// Distinct viewer networks, as a rough count of visitors
+    pub unique_visitors: usize,
+    pub embed_views: usize,
+    /// Views with no referrer, such as links opened directly
+    pub direct_views: usize,
+    pub last_viewed_at: Option<DateTime<Utc>>,
+    pub referrers: Vec<ShareViewCount>,
+    pub countries: Vec<ShareViewCount>,
+    /// Views per access token, by token name
+    pub tokens: Vec<ShareViewCount>,
+    /// Latest views, newest first
+    pub recent: Vec<ShareView>,
+}
+
+impl ShareViewStats {
+    pub fn from_views(mut views: Vec<ShareView>, tokens: &[ShareToken]) -> Self {
+        views.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let token_names: HashMap<Uuid, &str> = tokens
+            .iter()
+            .map(|t| (t.id, t.base.name.as_str()))
+            .collect();
+
+        let referrers = top_counts(views.iter().filter_map(|v| v.base.referrer_domain.clone()));
+        let countries = top_counts(views.iter().filter_map(|v| v.base.country.clone()));
+        let token_counts = top_counts(views.iter().filter_map(|v| {
+            v.base.token_id.map(|id| {
+                token_names
+                    .get(&id)
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| id.to_string())
+            })
+        }));
+
+        Self {
+            total_views: views.len(),
+            unique_visitors: views
+                .iter()
+                .map(|v| v.base.ip_bucket.as_str())
+                .collect::<HashSet<_>>()
+                .len(),
+            embed_views: views.iter().filter(|v| v.base.embed).count(),
+            direct_views: views
+                .iter()
+                .filter(|v| v.base.referrer_domain.is_none())
+                .count(),
+            last_viewed_at: views.first().map(|v| v.created_at),
+            referrers,
+            countries,
+            tokens: token_counts,
+            recent: views.into_iter().take(RECENT_COUNT).collect(),
+        }
+    }
+}
+
+fn top_counts(keys: impl Iterator<Item = String>) -> Vec<ShareViewCount> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for key in keys {
+        *counts.entry(key).or_default() += 1;
+    }
+
+    let mut counts: Vec<ShareViewCount> = counts
+        .into_iter()
+        .map(|(key, count)| ShareViewCount { key, count })
+        .collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+    counts.truncate(TOP_COUNT);
+    counts
+}
+
+/// The network an address belongs to, coarse enough that it doesn't identify a single viewer
+fn ip_bucket(ip: IpAddr) -> String {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => {
+            let network = Ipv4Addr::from(u32::from(ip) & 0xFFFF_FF00);
+            format!("{}/24", network)
+        }
+        IpAddr::V6(ip) => {
+            let network = Ipv6Addr::from(u128::from(ip) & !((1u128 << 80) - 1));
+            format!("{}/48", network)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::shares::r#impl::tokens::ShareTokenBase;
+    use axum::http::HeaderValue;
+    use chrono::Duration;
+
+    fn view(referrer: Option<&str>, ip_bucket: &str, minutes_ago: i64) -> ShareView {
+        ShareView {
+            id: Uuid::new_v4(),
+            created_at: Utc::now() - Duration::minutes(minutes_ago),
+            base: ShareViewBase {
+                referrer_domain: referrer.map(|r| r.to_string()),
+                ip_bucket: ip_bucket.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_ip_bucket_drops_host_bits() {
+        assert_eq!(ip_bucket("203.0.113.77".parse().unwrap()), "203.0.113.0/24");
+        assert_eq!(
+            ip_bucket("2001:db8:1234:5678::1".parse().unwrap()),
+            "2001:db8:1234::/48"
+        );
+        assert_eq!(
+            ip_bucket("::ffff:198.51.100.9".parse().unwrap()),
+            "198.51.100.0/24"
+        );
+    }
+
+    #[test]
+    fn test_view_from_request_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::REFERER,
+            HeaderValue::from_static("https://Wiki.Example.com/network?page=2"),
+        );
+        headers.insert("cf-ipcountry", HeaderValue::from_static("de"));
+
+        let base = ShareViewBase::from_request(
+            Uuid::new_v4(),
+            None,
+            true,
+            "192.0.2.10".parse().unwrap(),
+            &headers,
+        );
+
+        assert_eq!(base.referrer_domain.as_deref(), Some("wiki.example.com"));
+        assert_eq!(base.country.as_deref(), Some("DE"));
+        assert_eq!(base.ip_bucket, "192.0.2.0/24");
+
+        headers.insert("cf-ipcountry", HeaderValue::from_static("XX"));
+        headers.remove(header::REFERER);
+        let base = ShareViewBase::from_request(
+            Uuid::new_v4(),
+            None,
+            false,
+            "192.0.2.10".parse().unwrap(),
+            &headers,
+        );
+
+        assert_eq!(base.referrer_domain, None);
+        assert_eq!(base.country, None);
+    }
+
+    #[test]
+    fn test_stats_from_views() {
+        let token = ShareToken::new(ShareTokenBase {
+            name: "Partner".to_string(),
+            ..Default::default()
+        });
+
+        let mut tokened = view(Some("wiki.example.com"), "10.0.0.0/24", 1);
+        tokened.base.token_id = Some(token.id);
+        tokened.base.embed = true;
+
+        let views = vec![
+            view(None, "10.0.0.0/24", 30),
+            tokened,
+            view(Some("wiki.example.com"), "10.0.1.0/24", 10),
+            view(Some("docs.example.com"), "10.0.1.0/24", 20),
+        ];
+
+        let stats = ShareViewStats::from_views(views, &[token]);
+
+        assert_eq!(stats.total_views, 4);
+        assert_eq!(stats.unique_visitors, 2);
+        assert_eq!(stats.embed_views, 1);
+        assert_eq!(stats.direct_views, 1);
+        assert_eq!(
+            stats.referrers,
+            vec![
+                ShareViewCount {
+                    key: "wiki.example.com".to_string(),
+                    count: 2
+                },
+                ShareViewCount {
+                    key: "docs.example.com".to_string(),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            stats.tokens,
+            vec![ShareViewCount {
+                key: "Partner".to_string(),
+                count: 1
+            }]
+        );
+        // Newest first
+        assert!(stats.recent[0].base.embed);
+        assert_eq!(stats.last_viewed_at, Some(stats.recent[0].created_at));
+    }
+}
@@ -1,19 +1,38 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
+use anyhow::{Result, anyhow, bail};
+use chrono::{Duration, Utc};
 use uuid::Uuid;
 
 use crate::server::{
-    auth::service::verify_password,
+    auth::{middleware::auth::AuthenticatedEntity, service::verify_password},
     shared::{
-        events::bus::EventBus,
+        api_key_common::{ApiKeyType, generate_api_key_for_storage, hash_api_key},
+        events::{
+            bus::EventBus,
+            types::{AuthEvent, AuthOperation},
+        },
         services::traits::{CrudService, EventBusService},
-        storage::generic::GenericPostgresStorage,
+        storage::{
+            filter::EntityFilter,
+            generic::GenericPostgresStorage,
+            traits::{StorableEntity, Storage},
+        },
+    },
+    shares::r#impl::{
+        base::Share,
+        tokens::{ShareToken, ShareTokenBase},
+        views::{ShareView, ShareViewBase, ShareViewStats},
     },
-    shares::r#impl::base::Share,
 };
 
+/// Views older than this are deleted
+const SHARE_VIEW_RETENTION_DAYS: i64 = 90;
+
 pub struct ShareService {
     storage: Arc<GenericPostgresStorage<Share>>,
+    token_storage: Arc<GenericPostgresStorage<ShareToken>>,
+    view_storage: Arc<GenericPostgresStorage<ShareView>>,
     event_bus: Arc<EventBus>,
 }
 
@@ -44,8 +63,87 @@ impl CrudService<Share> for ShareService {
 }
 
 impl ShareService {
-    pub fn new(storage: Arc<GenericPostgresStorage<Share>>, event_bus: Arc<EventBus>) -> Self {
-        Self { storage, event_bus }
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<Share>>,
+        token_storage: Arc<GenericPostgresStorage<ShareToken>>,
+        view_storage: Arc<GenericPostgresStorage<ShareView>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            storage,
+            token_storage,
+            view_storage,
+            event_bus,
+        }
+    }
+
+    /// Check if viewers need a password or token. A share with active tokens is closed to
+    /// anyone without one, even when it has no password.
+    pub async fn requires_secret(&self, share: &Share) -> Result<bool> {
+        if share.requires_password() {
+            return Ok(true);
+        }
+
+        Ok(self
+            .get_tokens(&share.id)
+            .await?
+            .iter()
+            .any(|t| !t.is_revoked()))
+    }
+
+    /// Verify a password or access token for a share, returning the token if one was used.
+    /// Token use, accepted or rejected, is recorded in the audit trail.
+    pub async fn verify_share_secret(
+        &self,
+        share: &Share,
+        secret: &str,
+        organization_id: Uuid,
+        ip: IpAddr,
+        user_agent: Option<String>,
+    ) -> Result<Option<ShareToken>> {
+        if ApiKeyType::from_key(secret).0 != ApiKeyType::Share {
+            self.verify_share_password(share, secret)?;
+            return Ok(None);
+        }
+
+        let filter = EntityFilter::unfiltered()
+            .share_id(&share.id)
+            .api_key(hash_api_key(secret));
+        let token = self.token_storage.get_one(filter).await?;
+
+        // Revoked tokens are still audited as the token that was rejected
+        let mut token = match token {
+            Some(token) if !token.is_revoked() => token,
+            rejected => {
+                self.publish_token_event(
+                    AuthOperation::ShareTokenRejected,
+                    share,
+                    rejected.as_ref(),
+                    Some(organization_id),
+                    ip,
+                    user_agent,
+                    AuthenticatedEntity::Anonymous,
+                )
+                .await;
+                bail!("Invalid share token");
+            }
+        };
+
+        token.base.last_used = Some(Utc::now());
+        let token = self.token_storage.update(&mut token).await?;
+
+        self.publish_token_event(
+            AuthOperation::ShareTokenUsed,
+            share,
+            Some(&token),
+            Some(organization_id),
+            ip,
+            user_agent,
+            AuthenticatedEntity::Anonymous,
+        )
+        .await;
+
+        Ok(Some(token))
     }
 
     /// Verify password for a password-protected share
@@ -100,4 +198,152 @@ impl ShareService {
             }
         })
     }
+
+    // ========================================================================
+    // Tokens
+    // ========================================================================
+
+    pub async fn get_tokens(&self, share_id: &Uuid) -> Result<Vec<ShareToken>> {
+        self.token_storage
+            .get_all(EntityFilter::unfiltered().share_id(share_id))
+            .await
+    }
+
+    /// Create a named token for a share, returning the plaintext value once
+    pub async fn create_token(
+        &self,
+        share: &Share,
+        name: String,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        authentication: AuthenticatedEntity,
+    ) -> Result<(ShareToken, String)> {
+        let (plaintext, hashed) = generate_api_key_for_storage(ApiKeyType::Share);
+        let token = self
+            .token_storage
+            .create(&ShareToken::new(ShareTokenBase {
+                share_id: share.id,
+                name,
+                key: hashed,
+                last_used: None,
+                revoked_at: None,
+            }))
+            .await?;
+
+        self.publish_token_event(
+            AuthOperation::ShareTokenCreated,
+            share,
+            Some(&token),
+            authentication.organization_id(),
+            ip,
+            user_agent,
+            authentication,
+        )
+        .await;
+
+        Ok((token, plaintext))
+    }
+
+    /// Revoke a single token. Revoked tokens are kept so past views stay attributed to them.
+    pub async fn revoke_token(
+        &self,
+        share: &Share,
+        token_id: &Uuid,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        authentication: AuthenticatedEntity,
+    ) -> Result<ShareToken> {
+        let mut token = self
+            .token_storage
+            .get_by_id(token_id)
+            .await?
+            .filter(|t| t.base.share_id == share.id)
+            .ok_or_else(|| anyhow!("Share token '{}' not found", token_id))?;
+
+        if token.is_revoked() {
+            return Ok(token);
+        }
+
+        token.base.revoked_at = Some(Utc::now());
+        let token = self.token_storage.update(&mut token).await?;
+
+        self.publish_token_event(
+            AuthOperation::ShareTokenRevoked,
+            share,
+            Some(&token),
+            authentication.organization_id(),
+            ip,
+            user_agent,
+            authentication,
+        )
+        .await;
+
+        Ok(token)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn publish_token_event(
+        &self,
+        operation: AuthOperation,
+        share: &Share,
+        token: Option<&ShareToken>,
+        organization_id: Option<Uuid>,
+        ip: IpAddr,
+        user_agent: Option<String>,
+        authentication: AuthenticatedEntity,
+    ) {
+        let event = AuthEvent::new(
+            Uuid::new_v4(),
+            authentication.user_id(),
+            organization_id,
+            operation,
+            Utc::now(),
+            ip,
+            user_agent,
+            serde_json::json!({
+                "share_id": share.id,
+                "network_id": share.base.network_id,
+                "token_id": token.map(|t| t.id),
+                "token_name": token.map(|t| t.base.name.clone()),
+            }),
+            authentication,
+        );
+
+        if let Err(e) = self.event_bus.publish_auth(event).await {
+            tracing::warn!(error = %e, "Failed to publish share token event");
+        }
+    }
+
+    // ========================================================================
+    // Views
+    // ========================================================================
+
+    /// Record a view. Failures are logged rather than returned, so they never block viewers.
+    pub async fn record_view(&self, view: ShareViewBase) {
+        if let Err(e) = self.view_storage.create(&ShareView::new(view)).await {
+            tracing::warn!(error = %e, "Failed to record share view");
+        }
+    }
+
+    pub async fn get_view_stats(&self, share_id: &Uuid) -> Result<ShareViewStats> {
+        let views = self
+            .view_storage
+            .get_all(EntityFilter::unfiltered().share_id(share_id))
+            .await?;
+        let tokens = self.get_tokens(share_id).await?;
+
+        Ok(ShareViewStats::from_views(views, &tokens))
+    }
+
+    /// Delete views past the retention period
+    pub async fn cleanup_old_views(&self) {
+        let cutoff = Utc::now() - Duration::days(SHARE_VIEW_RETENTION_DAYS);
+        let filter = EntityFilter::unfiltered().created_before(cutoff);
+
+        match self.view_storage.delete_by_filter(filter).await {
+            Ok(0) => {}
+            Ok(count) => tracing::debug!("Cleaned up {} old share views.", count),
+            Err(e) => tracing::error!("Failed to delete old share views: {}", e),
+        }
+    }
 }
@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::Response,
+};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::permissions::{And, Authorized, Member, Read, Scope, Viewer, Write},
+    config::AppState,
+    shared::{
+        extractors::Query,
+        handlers::traits::CrudHandlers,
+        services::traits::CrudService,
+        types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult},
+    },
+    snapshots::r#impl::{
+        api::{CreateSnapshotRequest, SnapshotDiffQuery},
+        base::{SnapshotTrigger, TopologySnapshot},
+        diff::{TopologyDiff, diff},
+    },
+    topology::{
+        handlers::{exported_topology_response, rendered_topology_response},
+        service::{export::export, render::render_blocking},
+        types::{
+            base::Topology, export::ExportTopologyRequest, render::RenderParams,
+            render::RenderTopologyRequest,
+        },
+    },
+};
+
+// Generated handlers for generic CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_get_all_handler!(TopologySnapshot, "snapshots", "snapshot");
+    crate::crud_get_by_id_handler!(TopologySnapshot, "snapshots", "snapshot");
+    crate::crud_delete_handler!(TopologySnapshot, "snapshots", "snapshot");
+    crate::crud_bulk_delete_handler!(TopologySnapshot, "snapshots");
+}
+
+/// Snapshots are immutable, so there is no update route
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(generated::get_all, create_snapshot))
+        .routes(routes!(generated::get_by_id, generated::delete))
+        .routes(routes!(generated::bulk_delete))
+        .routes(routes!(diff_snapshots))
+        .routes(routes!(render_snapshot))
+        .routes(routes!(export_snapshot))
+}
+
+/// Take a snapshot of a topology
+///
+/// Copies the topology's graph and entities as currently stored. Manual snapshots are
+/// kept until deleted.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "snapshots",
+    request_body = CreateSnapshotRequest,
+    responses(
+        (status = 200, description = "Snapshot created", body = ApiResponse<TopologySnapshot>),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Topology not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<TopologySnapshot, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_snapshot(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Member, Scope<TopologySnapshot, Write>>>,
+    Json(request): Json<CreateSnapshotRequest>,
+) -> ApiResult<Json<ApiResponse<TopologySnapshot>>> {
+    let network_ids = auth.network_ids();
+
+    let topology = Topology::get_service(&state)
+        .get_by_id(&request.topology_id)
+        .await?
+        .ok_or_else(|| {
+            ApiError::not_found(format!("Could not find topology {}", request.topology_id))
+        })?;
+
+    // Validate user has access to this topology's network
+    if !network_ids.contains(&topology.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this topology",
+        ));
+    }
+
+    let created = TopologySnapshot::get_service(&state)
+        .snapshot_topology(
+            &topology,
+            request.name,
+            SnapshotTrigger::Manual,
+            auth.into_entity(),
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(created)))
+}
+
+/// Compare two snapshots
+///
+/// Returns the nodes and edges added, removed and changed between two snapshots of the same
+/// topology, with the hosts, interfaces, subnets, services and groups they draw.
+#[utoipa::path(
+    get,
+    path = "/diff",
+    tag = "snapshots",
+    params(SnapshotDiffQuery),
+    responses(
+        (status = 200, description = "Snapshot diff", body = ApiResponse<TopologyDiff>),
+        (status = 400, description = "Snapshots belong to different topologies", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Snapshot not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<TopologySnapshot, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn diff_snapshots(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Viewer, Scope<TopologySnapshot, Read>>>,
+    Query(query): Query<SnapshotDiffQuery>,
+) -> ApiResult<Json<ApiResponse<TopologyDiff>>> {
+    let network_ids = auth.network_ids();
+
+    let from = get_snapshot(&state, &query.from, &network_ids).await?;
+    let to = get_snapshot(&state, &query.to, &network_ids).await?;
+
+    if from.base.topology_id != to.base.topology_id {
+        return Err(ApiError::bad_request(
+            "Snapshots must belong to the same topology",
+        ));
+    }
+
+    let (older, newer) = if from.created_at <= to.created_at {
+        (&from, &to)
+    } else {
+        (&to, &from)
+    };
+
+    Ok(Json(ApiResponse::success(diff(older, newer))))
+}
+
+/// Render a snapshot
+///
+/// Draws the snapshot as SVG, PNG or PDF, like rendering a topology. Options in the request
+/// override the ones saved with the snapshot.
+#[utoipa::path(
+    post,
+    path = "/{id}/render",
+    tag = "snapshots",
+    params(("id" = Uuid, Path, description = "Snapshot ID")),
+    request_body = RenderTopologyRequest,
+    responses(
+        (status = 200, description = "Rendered snapshot", content(
+            (String = "image/svg+xml"),
+            (Vec<u8> = "image/png"),
+            (Vec<u8> = "application/pdf")
+        )),
+        (status = 400, description = "Invalid render options", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Snapshot not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<TopologySnapshot, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn render_snapshot(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Viewer, Scope<TopologySnapshot, Read>>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<RenderTopologyRequest>,
+) -> ApiResult<Response> {
+    let snapshot = get_snapshot(&state, &id, &auth.network_ids()).await?;
+    let topology = snapshot.to_topology();
+
+    let params = RenderParams::new(
+        request.format,
+        request
+            .options
+            .unwrap_or_else(|| topology.base.options.clone()),
+        request.scale,
+        request.page_size,
+    )?;
+
+    let bytes = render_blocking(topology, params).await?;
+
+    Ok(rendered_topology_response(
+        bytes,
+        request.format,
+        &snapshot.base.name,
+    ))
+}
+
+/// Export a snapshot
+///
+/// Converts the snapshot to Graphviz DOT, draw.io, Mermaid or GraphML, like exporting a
+/// topology.
+#[utoipa::path(
+    post,
+    path = "/{id}/export",
+    tag = "snapshots",
+    params(("id" = Uuid, Path, description = "Snapshot ID")),
+    request_body = ExportTopologyRequest,
+    responses(
+        (status = 200, description = "Exported snapshot", content(
+            (String = "text/vnd.graphviz"),
+            (String = "application/vnd.jgraph.mxfile"),
+            (String = "text/vnd.mermaid"),
+            (String = "application/graphml+xml")
+        )),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Snapshot not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<TopologySnapshot, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_snapshot(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Viewer, Scope<TopologySnapshot, Read>>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ExportTopologyRequest>,
+) -> ApiResult<Response> {
+    let snapshot = get_snapshot(&state, &id, &auth.network_ids()).await?;
+    let topology = snapshot.to_topology();
+
+    let options = request
+        .options
+        .unwrap_or_else(|| topology.base.options.clone());
+    let exported = export(&topology, request.format, &options);
+
+    Ok(exported_topology_response(
+        exported,
+        request.format,
+        &snapshot.base.name,
+    ))
+}
+
+/// Fetch a snapshot the caller has network access to
+async fn get_snapshot(
+    state: &AppState,
+    id: &Uuid,
+    network_ids: &[Uuid],
+) -> Result<TopologySnapshot, ApiError> {
+    let snapshot = TopologySnapshot::get_service(state)
+        .get_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Could not find snapshot {}", id)))?;
+
+    if !network_ids.contains(&snapshot.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this snapshot",
+        ));
+    }
+
+    Ok(snapshot)
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Take a snapshot of a topology as it's currently stored
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateSnapshotRequest {
+    pub topology_id: Uuid,
+    /// Defaults to the topology name and the current time
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Snapshots of the same topology to compare, in either order. The older one is the
+/// baseline.
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct SnapshotDiffQuery {
+    /// Snapshot ID
+    pub from: Uuid,
+    /// Snapshot ID
+    pub to: Uuid,
+}
@@ -0,0 +1,415 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::server::{
+    bindings::r#impl::base::Binding,
+    groups::r#impl::base::Group,
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    ports::r#impl::base::Port,
+    services::r#impl::base::Service,
+    shared::{
+        entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
+        storage::traits::{SqlValue, StorableEntity},
+    },
+    subnets::r#impl::base::Subnet,
+    topology::types::{
+        base::{Topology, TopologyBase, TopologyOptions},
+        edges::Edge,
+        nodes::Node,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// What caused a snapshot to be taken. Only automatic snapshots are pruned by retention.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Display,
+    EnumString,
+    ToSchema,
+)]
+pub enum SnapshotTrigger {
+    #[default]
+    Manual,
+    Discovery,
+    Schedule,
+}
+
+impl SnapshotTrigger {
+    pub fn is_automatic(&self) -> bool {
+        !matches!(self, SnapshotTrigger::Manual)
+    }
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
+)]
+pub struct TopologySnapshotBase {
+    pub topology_id: Uuid,
+    pub network_id: Uuid,
+    #[validate(length(min = 0, max = 100))]
+    pub name: String,
+    pub trigger: SnapshotTrigger,
+    /// User who took a manual snapshot
+    #[schema(required)]
+    pub created_by: Option<Uuid>,
+    pub options: TopologyOptions,
+
+    // Graph
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+
+    // Entities
+    pub hosts: Vec<Host>,
+    pub interfaces: Vec<Interface>,
+    pub ports: Vec<Port>,
+    pub bindings: Vec<Binding>,
+    pub subnets: Vec<Subnet>,
+    pub services: Vec<Service>,
+    pub groups: Vec<Group>,
+
+    /// Entities deleted since the topology's graph was last rebuilt. Their nodes and edges
+    /// are still in the graph.
+    pub removed_hosts: Vec<Uuid>,
+    pub removed_interfaces: Vec<Uuid>,
+    pub removed_subnets: Vec<Uuid>,
+    pub removed_services: Vec<Uuid>,
+    pub removed_groups: Vec<Uuid>,
+    pub removed_ports: Vec<Uuid>,
+    pub removed_bindings: Vec<Uuid>,
+}
+
+impl TopologySnapshotBase {
+    /// Copy a topology's graph, entities and removal tracking as they are now
+    pub fn capture(
+        topology: &Topology,
+        name: String,
+        trigger: SnapshotTrigger,
+        created_by: Option<Uuid>,
+    ) -> Self {
+        let base = topology.base.clone();
+
+        Self {
+            topology_id: topology.id,
+            network_id: base.network_id,
+            name,
+            trigger,
+            created_by,
+            options: base.options,
+            nodes: base.nodes,
+            edges: base.edges,
+            hosts: base.hosts,
+            interfaces: base.interfaces,
+            ports: base.ports,
+            bindings: base.bindings,
+            subnets: base.subnets,
+            services: base.services,
+            groups: base.groups,
+            removed_hosts: base.removed_hosts,
+            removed_interfaces: base.removed_interfaces,
+            removed_subnets: base.removed_subnets,
+            removed_services: base.removed_services,
+            removed_groups: base.removed_groups,
+            removed_ports: base.removed_ports,
+            removed_bindings: base.removed_bindings,
+        }
+    }
+}
+
+/// Immutable copy of a topology at a point in time
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
+)]
+pub struct TopologySnapshot {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: TopologySnapshotBase,
+}
+
+impl TopologySnapshot {
+    /// The snapshot as a locked topology, for rendering, exporting and diffing with the
+    /// same code as live topologies
+    pub fn to_topology(&self) -> Topology {
+        let base = self.base.clone();
+
+        Topology {
+            id: base.topology_id,
+            created_at: self.created_at,
+            updated_at: self.created_at,
+            base: TopologyBase {
+                name: base.name,
+                options: base.options,
+                network_id: base.network_id,
+                tags: vec![],
+                parent_id: None,
+                nodes: base.nodes,
+                edges: base.edges,
+                hosts: base.hosts,
+                interfaces: base.interfaces,
+                ports: base.ports,
+                bindings: base.bindings,
+                subnets: base.subnets,
+                services: base.services,
+                groups: base.groups,
+                is_stale: false,
+                last_refreshed: self.created_at,
+                is_locked: true,
+                locked_at: Some(self.created_at),
+                locked_by: base.created_by,
+                removed_hosts: base.removed_hosts,
+                removed_interfaces: base.removed_interfaces,
+                removed_subnets: base.removed_subnets,
+                removed_services: base.removed_services,
+                removed_groups: base.removed_groups,
+                removed_ports: base.removed_ports,
+                removed_bindings: base.removed_bindings,
+            },
+        }
+    }
+}
+
+impl Display for TopologySnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TopologySnapshot {} ({})", self.id, self.base.name)
+    }
+}
+
+impl ChangeTriggersTopologyStaleness<TopologySnapshot> for TopologySnapshot {
+    fn triggers_staleness(&self, _other: Option<TopologySnapshot>) -> bool {
+        false
+    }
+}
+
+impl StorableEntity for TopologySnapshot {
+    type BaseData = TopologySnapshotBase;
+
+    fn table_name() -> &'static str {
+        "topology_snapshots"
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::TopologySnapshot
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    topology_id,
+                    network_id,
+                    name,
+                    trigger,
+                    created_by,
+                    options,
+                    nodes,
+                    edges,
+                    hosts,
+                    interfaces,
+                    ports,
+                    bindings,
+                    subnets,
+                    services,
+                    groups,
+                    removed_hosts,
+                    removed_interfaces,
+                    removed_subnets,
+                    removed_services,
+                    removed_groups,
+                    removed_ports,
+                    removed_bindings,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "created_at",
+                "updated_at",
+                "topology_id",
+                "network_id",
+                "name",
+                "trigger",
+                "created_by",
+                "options",
+                "nodes",
+                "edges",
+                "hosts",
+                "interfaces",
+                "ports",
+                "bindings",
+                "subnets",
+                "services",
+                "groups",
+                "removed_hosts",
+                "removed_interfaces",
+                "removed_subnets",
+                "removed_services",
+                "removed_groups",
+                "removed_ports",
+                "removed_bindings",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+                SqlValue::Uuid(topology_id),
+                SqlValue::Uuid(network_id),
+                SqlValue::String(name),
+                SqlValue::String(trigger.to_string()),
+                SqlValue::OptionalUuid(created_by),
+                SqlValue::TopologyOptions(options),
+                SqlValue::Nodes(nodes),
+                SqlValue::Edges(edges),
+                SqlValue::Hosts(hosts),
+                SqlValue::Interfaces(interfaces),
+                SqlValue::Ports(ports),
+                SqlValue::Bindings(bindings),
+                SqlValue::Subnets(subnets),
+                SqlValue::Services(services),
+                SqlValue::Groups(groups),
+                SqlValue::UuidArray(removed_hosts),
+                SqlValue::UuidArray(removed_interfaces),
+                SqlValue::UuidArray(removed_subnets),
+                SqlValue::UuidArray(removed_services),
+                SqlValue::UuidArray(removed_groups),
+                SqlValue::UuidArray(removed_ports),
+                SqlValue::UuidArray(removed_bindings),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let trigger: String = row.get("trigger");
+        let trigger = SnapshotTrigger::from_str(&trigger)
+            .map_err(|_| anyhow::anyhow!("Unknown snapshot trigger '{}'", trigger))?;
+
+        let options: TopologyOptions =
+            serde_json::from_value(row.get::<serde_json::Value, _>("options"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize options: {}", e))?;
+        let nodes: Vec<Node> = serde_json::from_value(row.get::<serde_json::Value, _>("nodes"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize nodes: {}", e))?;
+        let edges: Vec<Edge> = serde_json::from_value(row.get::<serde_json::Value, _>("edges"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize edges: {}", e))?;
+
+        let hosts: Vec<Host> = serde_json::from_value(row.get::<serde_json::Value, _>("hosts"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize hosts: {}", e))?;
+        let interfaces: Vec<Interface> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("interfaces"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize interfaces: {}", e))?;
+        let ports: Vec<Port> = serde_json::from_value(row.get::<serde_json::Value, _>("ports"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize ports: {}", e))?;
+        let bindings: Vec<Binding> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("bindings"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize bindings: {}", e))?;
+        let subnets: Vec<Subnet> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("subnets"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize subnets: {}", e))?;
+        let services: Vec<Service> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("services"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize services: {}", e))?;
+        let groups: Vec<Group> = serde_json::from_value(row.get::<serde_json::Value, _>("groups"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize groups: {}", e))?;
+
+        Ok(TopologySnapshot {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: TopologySnapshotBase {
+                topology_id: row.get("topology_id"),
+                network_id: row.get("network_id"),
+                name: row.get("name"),
+                trigger,
+                created_by: row.get("created_by"),
+                options,
+                nodes,
+                edges,
+                hosts,
+                interfaces,
+                ports,
+                bindings,
+                subnets,
+                services,
+                groups,
+                removed_hosts: row.get("removed_hosts"),
+                removed_interfaces: row.get("removed_interfaces"),
+                removed_subnets: row.get("removed_subnets"),
+                removed_services: row.get("removed_services"),
+                removed_groups: row.get("removed_groups"),
+                removed_ports: row.get("removed_ports"),
+                removed_bindings: row.get("removed_bindings"),
+            },
+        })
+    }
+}
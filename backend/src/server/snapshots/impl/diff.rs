@@ -0,0 +1,641 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    groups::r#impl::base::Group,
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    services::r#impl::base::Service,
+    shared::types::metadata::TypeMetadataProvider,
+    snapshots::r#impl::base::TopologySnapshot,
+    subnets::r#impl::base::Subnet,
+    topology::{
+        service::view::TopologyView,
+        types::{
+            base::Topology,
+            edges::{Edge, EdgeType},
+            nodes::{Node, NodeType},
+        },
+    },
+};
+
+/// An attribute of a node or edge that differs between the two snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct FieldChange {
+    /// Attribute name, such as `position`, `host.name` or `services`
+    pub field: String,
+    #[schema(required)]
+    pub before: Option<String>,
+    #[schema(required)]
+    pub after: Option<String>,
+}
+
+/// A node with the entities it draws. Removed nodes carry the older snapshot's entities,
+/// added and changed nodes the newer snapshot's.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NodeDiff {
+    pub node: Node,
+    #[schema(required)]
+    pub host: Option<Host>,
+    #[schema(required)]
+    pub interface: Option<Interface>,
+    #[schema(required)]
+    pub subnet: Option<Subnet>,
+    /// Services shown on the node
+    pub services: Vec<Service>,
+    /// Empty for added and removed nodes
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EdgeDiff {
+    pub edge: Edge,
+    /// Group a request path or hub-and-spoke edge belongs to
+    #[schema(required)]
+    pub group: Option<Group>,
+    /// Empty for added and removed edges
+    pub changes: Vec<FieldChange>,
+}
+
+/// Changes between two snapshots of the same topology. Nodes are matched by id and edges by
+/// endpoints and type, since edge ids are regenerated on every rebuild. A node or edge whose
+/// entity is listed as removed in the newer snapshot counts as removed even if the graph
+/// hadn't been rebuilt yet.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct TopologyDiff {
+    pub from_snapshot_id: Uuid,
+    pub from_created_at: DateTime<Utc>,
+    pub to_snapshot_id: Uuid,
+    pub to_created_at: DateTime<Utc>,
+    pub added_nodes: Vec<NodeDiff>,
+    pub removed_nodes: Vec<NodeDiff>,
+    pub changed_nodes: Vec<NodeDiff>,
+    pub added_edges: Vec<EdgeDiff>,
+    pub removed_edges: Vec<EdgeDiff>,
+    pub changed_edges: Vec<EdgeDiff>,
+}
+
+/// Compare an older snapshot with a newer one
+pub fn diff(from: &TopologySnapshot, to: &TopologySnapshot) -> TopologyDiff {
+    let before_topology = from.to_topology();
+    let after_topology = to.to_topology();
+    let before = TopologyView::new(&before_topology, &before_topology.base.options);
+    let after = TopologyView::new(&after_topology, &after_topology.base.options);
+
+    let before_nodes: HashMap<Uuid, &Node> =
+        live_nodes(&before_topology).map(|n| (n.id, n)).collect();
+    let after_nodes: HashMap<Uuid, &Node> =
+        live_nodes(&after_topology).map(|n| (n.id, n)).collect();
+
+    let mut added_nodes = Vec::new();
+    let mut removed_nodes = Vec::new();
+    let mut changed_nodes = Vec::new();
+
+    for node in &before_topology.base.nodes {
+        if before_nodes.contains_key(&node.id) && !after_nodes.contains_key(&node.id) {
+            removed_nodes.push(node_diff(&before, node, vec![]));
+        }
+    }
+
+    for node in &after_topology.base.nodes {
+        if !after_nodes.contains_key(&node.id) {
+            continue;
+        }
+        match before_nodes.get(&node.id) {
+            None => added_nodes.push(node_diff(&after, node, vec![])),
+            Some(previous) => {
+                let changes =
+                    field_changes(node_fields(&before, previous), node_fields(&after, node));
+                if !changes.is_empty() {
+                    changed_nodes.push(node_diff(&after, node, changes));
+                }
+            }
+        }
+    }
+
+    let before_edges: HashMap<EdgeKey, &Edge> = live_edges(&before_topology)
+        .map(|e| (edge_key(e), e))
+        .collect();
+    let after_edges: HashMap<EdgeKey, &Edge> = live_edges(&after_topology)
+        .map(|e| (edge_key(e), e))
+        .collect();
+
+    let mut added_edges = Vec::new();
+    let mut removed_edges = Vec::new();
+    let mut changed_edges = Vec::new();
+
+    for edge in live_edges(&before_topology) {
+        if !after_edges.contains_key(&edge_key(edge)) {
+            removed_edges.push(edge_diff(&before, edge, vec![]));
+        }
+    }
+
+    for edge in live_edges(&after_topology) {
+        match before_edges.get(&edge_key(edge)) {
+            None => added_edges.push(edge_diff(&after, edge, vec![])),
+            Some(previous) => {
+                let changes =
+                    field_changes(edge_fields(&before, previous), edge_fields(&after, edge));
+                if !changes.is_empty() {
+                    changed_edges.push(edge_diff(&after, edge, changes));
+                }
+            }
+        }
+    }
+
+    TopologyDiff {
+        from_snapshot_id: from.id,
+        from_created_at: from.created_at,
+        to_snapshot_id: to.id,
+        to_created_at: to.created_at,
+        added_nodes,
+        removed_nodes,
+        changed_nodes,
+        added_edges,
+        removed_edges,
+        changed_edges,
+    }
+}
+
+type EdgeKey = (Uuid, Uuid, EdgeType);
+
+fn edge_key(edge: &Edge) -> EdgeKey {
+    (edge.source, edge.target, edge.edge_type.clone())
+}
+
+/// Nodes whose subnet, host, interface or service hasn't been deleted since the last rebuild
+fn live_nodes(topology: &Topology) -> impl Iterator<Item = &Node> {
+    let base = &topology.base;
+
+    base.nodes.iter().filter(|node| match node.node_type {
+        NodeType::SubnetNode { .. } => !base.removed_subnets.contains(&node.id),
+        NodeType::InterfaceNode {
+            host_id,
+            interface_id,
+            ..
+        } => {
+            !base.removed_hosts.contains(&host_id)
+                && !interface_id.is_some_and(|id| base.removed_interfaces.contains(&id))
+        }
+        NodeType::HostNode { host_id } => !base.removed_hosts.contains(&host_id),
+        NodeType::ServiceNode {
+            service_id,
+            host_id,
+            ..
+        } => !base.removed_hosts.contains(&host_id) && !base.removed_services.contains(&service_id),
+        NodeType::StubNode { binding_id } => !base.removed_bindings.contains(&binding_id),
+        NodeType::NetworkNode { .. } => true,
+        NodeType::NetworkSubnetNode { subnet_id, .. } => !base.removed_subnets.contains(&subnet_id),
+        NodeType::NetworkHostNode { host_id, .. } => !base.removed_hosts.contains(&host_id),
+    })
+}
+
+/// Edges between live nodes whose group hasn't been deleted since the last rebuild
+fn live_edges(topology: &Topology) -> impl Iterator<Item = &Edge> {
+    let live: HashSet<Uuid> = live_nodes(topology).map(|n| n.id).collect();
+    let removed_groups = &topology.base.removed_groups;
+
+    topology.base.edges.iter().filter(move |edge| {
+        let group_removed = match &edge.edge_type {
+            EdgeType::RequestPath { group_id, .. } | EdgeType::HubAndSpoke { group_id, .. } => {
+                removed_groups.contains(group_id)
+            }
+            _ => false,
+        };
+
+        !group_removed && live.contains(&edge.source) && live.contains(&edge.target)
+    })
+}
+
+fn node_diff(view: &TopologyView, node: &Node, changes: Vec<FieldChange>) -> NodeDiff {
+    let (host, interface, subnet, services) = match node.node_type {
+        NodeType::SubnetNode { .. } => (None, None, view.subnets.get(&node.id), vec![]),
+        NodeType::NetworkSubnetNode { subnet_id, .. } => {
+            (None, None, view.subnets.get(&subnet_id), vec![])
+        }
+        NodeType::InterfaceNode {
+            subnet_id,
+            host_id,
+            interface_id,
+            ..
+        } => (
+            view.hosts.get(&host_id),
+            interface_id.and_then(|id| view.interfaces.get(&id)),
+            view.subnets.get(&subnet_id),
+            view.services_for_node(host_id, interface_id),
+        ),
+        NodeType::HostNode { host_id } | NodeType::NetworkHostNode { host_id, .. } => {
+            (view.hosts.get(&host_id), None, None, vec![])
+        }
+        NodeType::ServiceNode {
+            service_id,
+            host_id,
+            ..
+        } => (
+            view.hosts.get(&host_id),
+            None,
+            None,
+            view.services
+                .get(&service_id)
+                .copied()
+                .into_iter()
+                .collect(),
+        ),
+        NodeType::StubNode { .. } | NodeType::NetworkNode { .. } => (None, None, None, vec![]),
+    };
+
+    NodeDiff {
+        node: node.clone(),
+        host: host.map(|h| (*h).clone()),
+        interface: interface.map(|i| (*i).clone()),
+        subnet: subnet.map(|s| (*s).clone()),
+        services: services.into_iter().cloned().collect(),
+        changes,
+    }
+}
+
+fn edge_diff(view: &TopologyView, edge: &Edge, changes: Vec<FieldChange>) -> EdgeDiff {
+    EdgeDiff {
+        edge: edge.clone(),
+        group: view.edge_group(edge).cloned(),
+        changes,
+    }
+}
+
+type Fields = Vec<(&'static str, Option<String>)>;
+
+/// Attributes worth reporting for a node: its layout, the entity fields shown on it and the
+/// services it lists
+fn node_fields(view: &TopologyView, node: &Node) -> Fields {
+    let mut fields: Fields = vec![
+        ("header", node.header.clone()),
+        (
+            "position",
+            Some(format!("{},{}", node.position.x, node.position.y)),
+        ),
+        ("size", Some(format!("{}x{}", node.size.x, node.size.y))),
+    ];
+
+    match node.node_type {
+        // Subnet nodes in either topology share their subnet's id
+        NodeType::SubnetNode { .. } | NodeType::NetworkSubnetNode { .. } => {
+            let subnet = view.subnets.get(&node.id);
+            fields.extend([
+                ("subnet.name", subnet.map(|s| s.base.name.clone())),
+                ("subnet.cidr", subnet.map(|s| s.base.cidr.to_string())),
+                (
+                    "subnet.subnet_type",
+                    subnet.map(|s| s.base.subnet_type.name().to_string()),
+                ),
+                (
+                    "subnet.description",
+                    subnet.and_then(|s| s.base.description.clone()),
+                ),
+            ]);
+        }
+        NodeType::InterfaceNode {
+            host_id,
+            interface_id,
+            ..
+        } => {
+            let host = view.hosts.get(&host_id);
+            let interface = interface_id.and_then(|id| view.interfaces.get(&id));
+
+            let mut services: Vec<String> = view
+                .services_for_node(host_id, interface_id)
+                .into_iter()
+                .map(|service| {
+                    let ports = view.ports_for_service(service, interface_id);
+                    if ports.is_empty() {
+                        service.base.name.clone()
+                    } else {
+                        format!("{} ({})", service.base.name, ports.join(", "))
+                    }
+                })
+                .collect();
+            services.sort();
+
+            fields.extend([
+                ("host.name", host.map(|h| h.base.name.clone())),
+                ("host.hostname", host.and_then(|h| h.base.hostname.clone())),
+                (
+                    "host.description",
+                    host.and_then(|h| h.base.description.clone()),
+                ),
+                (
+                    "interface.name",
+                    interface.and_then(|i| i.base.name.clone()),
+                ),
+                (
+                    "interface.ip_address",
+                    interface.map(|i| i.base.ip_address.to_string()),
+                ),
+                (
+                    "interface.mac_address",
+                    interface.and_then(|i| i.base.mac_address.map(|m| m.to_string())),
+                ),
+                (
+                    "services",
+                    (!services.is_empty()).then(|| services.join("; ")),
+                ),
+            ]);
+        }
+        NodeType::HostNode { host_id } | NodeType::NetworkHostNode { host_id, .. } => {
+            let host = view.hosts.get(&host_id);
+            fields.extend([
+                ("host.name", host.map(|h| h.base.name.clone())),
+                ("host.hostname", host.and_then(|h| h.base.hostname.clone())),
+                (
+                    "host.description",
+                    host.and_then(|h| h.base.description.clone()),
+                ),
+            ]);
+        }
+        NodeType::ServiceNode {
+            service_id,
+            host_id,
+            ..
+        } => {
+            let host = view.hosts.get(&host_id);
+            let service = view.services.get(&service_id);
+            fields.extend([
+                ("host.name", host.map(|h| h.base.name.clone())),
+                ("service.name", service.map(|s| s.base.name.clone())),
+                (
+                    "service.service_definition",
+                    service.map(|s| s.base.service_definition.name().to_string()),
+                ),
+            ]);
+        }
+        NodeType::StubNode { .. } | NodeType::NetworkNode { .. } => {}
+    }
+
+    fields
+}
+
+fn edge_fields(view: &TopologyView, edge: &Edge) -> Fields {
+    vec![
+        ("label", edge.label.clone()),
+        (
+            "group.name",
+            view.edge_group(edge).map(|g| g.base.name.clone()),
+        ),
+        (
+            "handles",
+            Some(format!(
+                "{:?} -> {:?}",
+                edge.source_handle, edge.target_handle
+            )),
+        ),
+        ("is_multi_hop", Some(edge.is_multi_hop.to_string())),
+    ]
+}
+
+fn field_changes(before: Fields, after: Fields) -> Vec<FieldChange> {
+    let before: HashMap<&str, Option<String>> = before.into_iter().collect();
+
+    after
+        .into_iter()
+        .filter_map(|(field, after)| {
+            let before = before.get(field).cloned().flatten();
+            (before != after).then(|| FieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        bindings::r#impl::base::{Binding, BindingType},
+        shared::storage::traits::StorableEntity,
+        snapshots::r#impl::base::{SnapshotTrigger, TopologySnapshotBase},
+        topology::types::{
+            edges::EdgeHandle,
+            layout::{Ixy, Uxy},
+        },
+    };
+    use crate::tests;
+
+    /// One subnet with two hosts joined by a request path group
+    fn sample_topology() -> Topology {
+        let network_id = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+        let group = tests::group(&network_id);
+
+        let mut topology = Topology::default();
+        topology.base.nodes.push(Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: subnet.id,
+            position: Ixy { x: 0, y: 0 },
+            size: Uxy { x: 600, y: 300 },
+            header: None,
+        });
+
+        for name in ["web", "db"] {
+            let mut host = tests::host(&network_id);
+            host.base.name = name.to_string();
+            let mut interface = tests::interface(&network_id, &subnet.id);
+            interface.base.host_id = host.id;
+
+            let mut service = tests::service(&network_id, &host.id);
+            service.base.name = format!("{} service", name);
+            service.base.bindings = vec![Binding::new_serviceless(BindingType::Interface {
+                interface_id: interface.id,
+            })];
+
+            topology.base.nodes.push(Node {
+                node_type: NodeType::InterfaceNode {
+                    subnet_id: subnet.id,
+                    host_id: host.id,
+                    interface_id: Some(interface.id),
+                    is_infra: false,
+                },
+                id: interface.id,
+                position: Ixy { x: 25, y: 50 },
+                size: Uxy { x: 250, y: 100 },
+                header: None,
+            });
+
+            topology.base.hosts.push(host);
+            topology.base.interfaces.push(interface);
+            topology.base.services.push(service);
+        }
+
+        topology.base.edges.push(Edge {
+            id: Uuid::new_v4(),
+            source: topology.base.nodes[1].id,
+            target: topology.base.nodes[2].id,
+            edge_type: EdgeType::RequestPath {
+                group_id: group.id,
+                source_binding_id: Uuid::new_v4(),
+                target_binding_id: Uuid::new_v4(),
+            },
+            label: None,
+            source_handle: EdgeHandle::Right,
+            target_handle: EdgeHandle::Left,
+            is_multi_hop: false,
+        });
+
+        topology.base.subnets.push(subnet);
+        topology.base.groups.push(group);
+        topology
+    }
+
+    fn snapshot(topology: &Topology) -> TopologySnapshot {
+        TopologySnapshot::new(TopologySnapshotBase::capture(
+            topology,
+            "test".to_string(),
+            SnapshotTrigger::Manual,
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_no_changes() {
+        let topology = sample_topology();
+        let mut rebuilt = topology.clone();
+        // Rebuilds assign new edge ids
+        rebuilt.base.edges[0].id = Uuid::new_v4();
+
+        let diff = diff(&snapshot(&topology), &snapshot(&rebuilt));
+        assert!(diff.added_nodes.is_empty());
+        assert!(diff.removed_nodes.is_empty());
+        assert!(diff.changed_nodes.is_empty());
+        assert!(diff.added_edges.is_empty());
+        assert!(diff.removed_edges.is_empty());
+        assert!(diff.changed_edges.is_empty());
+    }
+
+    #[test]
+    fn test_node_and_edge_changes() {
+        let before = sample_topology();
+        let mut after = before.clone();
+
+        // db host and its node go away, taking the group edge with them
+        let db_node = after.base.nodes.remove(2);
+        after.base.edges.clear();
+
+        // web host is renamed and its node moves
+        after.base.hosts[0].base.name = "web-01".to_string();
+        after.base.nodes[1].position = Ixy { x: 325, y: 50 };
+
+        // A new host appears
+        let network_id = after.base.subnets[0].base.network_id;
+        let subnet_id = after.base.subnets[0].id;
+        let host = tests::host(&network_id);
+        let mut interface = tests::interface(&network_id, &subnet_id);
+        interface.base.host_id = host.id;
+        after.base.nodes.push(Node {
+            node_type: NodeType::InterfaceNode {
+                subnet_id,
+                host_id: host.id,
+                interface_id: Some(interface.id),
+                is_infra: false,
+            },
+            id: interface.id,
+            position: Ixy { x: 25, y: 175 },
+            size: Uxy { x: 250, y: 100 },
+            header: None,
+        });
+        after.base.hosts.push(host);
+        after.base.interfaces.push(interface);
+
+        let diff = diff(&snapshot(&before), &snapshot(&after));
+
+        assert_eq!(diff.removed_nodes.len(), 1);
+        assert_eq!(diff.removed_nodes[0].node.id, db_node.id);
+        // Removed nodes keep the older snapshot's entities
+        assert_eq!(
+            diff.removed_nodes[0]
+                .host
+                .as_ref()
+                .map(|h| h.base.name.as_str()),
+            Some("db")
+        );
+        assert_eq!(diff.removed_nodes[0].services[0].base.name, "db service");
+
+        assert_eq!(diff.added_nodes.len(), 1);
+        assert_eq!(
+            diff.added_nodes[0]
+                .host
+                .as_ref()
+                .map(|h| h.base.name.as_str()),
+            Some("Test Host")
+        );
+
+        assert_eq!(diff.changed_nodes.len(), 1);
+        let fields: Vec<_> = diff.changed_nodes[0]
+            .changes
+            .iter()
+            .map(|c| (c.field.as_str(), c.before.as_deref(), c.after.as_deref()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("position", Some("25,50"), Some("325,50")),
+                ("host.name", Some("web"), Some("web-01")),
+            ]
+        );
+
+        assert_eq!(diff.removed_edges.len(), 1);
+        assert_eq!(
+            diff.removed_edges[0]
+                .group
+                .as_ref()
+                .map(|g| g.base.name.as_str()),
+            Some("Test Group")
+        );
+        assert!(diff.added_edges.is_empty());
+    }
+
+    #[test]
+    fn test_entities_removed_before_rebuild_count_as_removed() {
+        let before = sample_topology();
+        let mut after = before.clone();
+
+        // The graph still has the db node and group edge, but the host and group were deleted
+        let db_host_id = after.base.hosts.remove(1).id;
+        after.base.removed_hosts.push(db_host_id);
+        after.base.removed_groups.push(after.base.groups[0].id);
+
+        let diff = diff(&snapshot(&before), &snapshot(&after));
+        assert_eq!(diff.removed_nodes.len(), 1);
+        assert_eq!(
+            diff.removed_nodes[0].host.as_ref().map(|h| h.id),
+            Some(db_host_id)
+        );
+        assert_eq!(diff.removed_edges.len(), 1);
+        assert!(diff.changed_nodes.is_empty());
+        assert!(diff.added_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_edge_changes_match_by_endpoints_and_type() {
+        let before = sample_topology();
+        let mut after = before.clone();
+        after.base.edges[0].id = Uuid::new_v4();
+        after.base.edges[0].source_handle = EdgeHandle::Bottom;
+        after.base.groups[0].base.name = "Web tier".to_string();
+
+        let diff = diff(&snapshot(&before), &snapshot(&after));
+        assert!(diff.added_edges.is_empty());
+        assert!(diff.removed_edges.is_empty());
+        assert_eq!(diff.changed_edges.len(), 1);
+
+        let fields: Vec<_> = diff.changed_edges[0]
+            .changes
+            .iter()
+            .map(|c| c.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["group.name", "handles"]);
+    }
+}
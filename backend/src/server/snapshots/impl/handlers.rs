@@ -0,0 +1,20 @@
+use crate::server::{
+    config::AppState,
+    shared::handlers::{query::SnapshotsQuery, traits::CrudHandlers},
+    snapshots::{r#impl::base::TopologySnapshot, service::SnapshotService},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
+};
+
+impl CrudHandlers for TopologySnapshot {
+    type Service = SnapshotService;
+    type FilterQuery = SnapshotsQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.snapshot_service
+    }
+}
+
+// Snapshots are part of a topology's history, so they share its scope
+impl ScopedResource for TopologySnapshot {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Topology;
+}
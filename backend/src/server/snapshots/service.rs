@@ -0,0 +1,268 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use tokio::sync::RwLock;
+use tokio_cron_scheduler::{Job, JobScheduler};
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::{
+            filter::EntityFilter,
+            generic::GenericPostgresStorage,
+            traits::{StorableEntity, Storage},
+        },
+    },
+    snapshots::r#impl::base::{SnapshotTrigger, TopologySnapshot, TopologySnapshotBase},
+    topology::{
+        service::main::{BuildGraphParams, TopologyService},
+        types::base::{SetEntitiesParams, Topology},
+    },
+};
+
+pub struct SnapshotService {
+    storage: Arc<GenericPostgresStorage<TopologySnapshot>>,
+    topology_service: Arc<TopologyService>,
+    event_bus: Arc<EventBus>,
+    /// Cron expression for scheduled snapshots of every topology. None disables them.
+    schedule: Option<String>,
+    /// Automatic snapshots kept per topology. Manual snapshots are never pruned.
+    retention: usize,
+    scheduler: RwLock<Option<JobScheduler>>,
+}
+
+impl EventBusService<TopologySnapshot> for SnapshotService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &TopologySnapshot) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &TopologySnapshot) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<TopologySnapshot> for SnapshotService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<TopologySnapshot>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::shared::services::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl SnapshotService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<TopologySnapshot>>,
+        topology_service: Arc<TopologyService>,
+        event_bus: Arc<EventBus>,
+        schedule: Option<String>,
+        retention: usize,
+    ) -> Self {
+        Self {
+            storage,
+            topology_service,
+            event_bus,
+            schedule,
+            retention,
+            scheduler: RwLock::new(None),
+        }
+    }
+
+    /// Snapshot a topology exactly as it's stored
+    pub async fn snapshot_topology(
+        &self,
+        topology: &Topology,
+        name: Option<String>,
+        trigger: SnapshotTrigger,
+        authentication: AuthenticatedEntity,
+    ) -> Result<TopologySnapshot> {
+        let name = name
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| default_name(topology));
+
+        let created = self
+            .create(
+                TopologySnapshot::new(TopologySnapshotBase::capture(
+                    topology,
+                    name,
+                    trigger,
+                    authentication.user_id(),
+                )),
+                authentication,
+            )
+            .await?;
+
+        if trigger.is_automatic() {
+            self.prune(topology.id).await?;
+        }
+
+        Ok(created)
+    }
+
+    /// Snapshot every topology in a network with its graph rebuilt from current entities.
+    /// The topologies themselves are left as they are. Locked topologies are captured as
+    /// stored, since their layout is pinned on purpose.
+    pub async fn snapshot_network(&self, network_id: Uuid, trigger: SnapshotTrigger) -> Result<()> {
+        let filter = EntityFilter::unfiltered().network_ids(&[network_id]);
+        let topologies = self.topology_service.get_all(filter).await?;
+
+        for topology in topologies {
+            let topology = self.current_state(topology).await?;
+            self.snapshot_topology(&topology, None, trigger, AuthenticatedEntity::System)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn current_state(&self, mut topology: Topology) -> Result<Topology> {
+        if topology.base.is_locked {
+            return Ok(topology);
+        }
+
+        let service = &self.topology_service;
+        let network_id = topology.base.network_id;
+
+        let (hosts, interfaces, subnets, groups, ports, bindings) = service
+            .get_entity_data(network_id, &topology.base.options)
+            .await?;
+        let services = service
+            .get_service_data(network_id, &topology.base.options, &hosts)
+            .await?;
+        let traceroutes = service.get_traceroute_data(network_id).await?;
+
+        let (nodes, edges) = TopologyService::build_graph(BuildGraphParams {
+            options: &topology.base.options,
+            hosts: &hosts,
+            interfaces: &interfaces,
+            subnets: &subnets,
+            services: &services,
+            groups: &groups,
+            ports: &ports,
+            bindings: &bindings,
+            traceroutes: &traceroutes,
+            old_nodes: &topology.base.nodes,
+            old_edges: &topology.base.edges,
+        });
+
+        topology.set_entities(SetEntitiesParams {
+            hosts,
+            services,
+            interfaces,
+            subnets,
+            groups,
+            ports,
+            bindings,
+        });
+        topology.set_graph(nodes, edges);
+        topology.clear_stale();
+
+        Ok(topology)
+    }
+
+    /// Delete automatic snapshots of a topology beyond the retention limit, oldest first
+    async fn prune(&self, topology_id: Uuid) -> Result<()> {
+        let filter = EntityFilter::unfiltered().topology_id(&topology_id);
+        let snapshots = self
+            .storage
+            .get_all_ordered(filter, "created_at DESC")
+            .await?;
+
+        let expired: Vec<Uuid> = snapshots
+            .iter()
+            .filter(|s| s.base.trigger.is_automatic())
+            .skip(self.retention)
+            .map(|s| s.id)
+            .collect();
+
+        if !expired.is_empty() {
+            let deleted = self
+                .delete_many(&expired, AuthenticatedEntity::System)
+                .await?;
+            tracing::debug!(
+                topology_id = %topology_id,
+                deleted,
+                "Pruned automatic topology snapshots"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Start scheduled snapshots if a schedule is configured
+    pub async fn start_scheduler(self: &Arc<Self>) -> Result<()> {
+        let Some(schedule) = self.schedule.clone() else {
+            return Ok(());
+        };
+
+        let scheduler = JobScheduler::new().await?;
+        let service = Arc::clone(self);
+
+        let job = Job::new_async(schedule.as_str(), move |_uuid, _lock| {
+            let service = service.clone();
+
+            Box::pin(async move {
+                if let Err(e) = service.snapshot_all().await {
+                    tracing::error!("Scheduled topology snapshots failed: {}", e);
+                }
+            })
+        })?;
+
+        scheduler.add(job).await?;
+        scheduler.start().await?;
+        *self.scheduler.write().await = Some(scheduler);
+
+        tracing::info!(
+            "Topology snapshot scheduler started with cron: {}",
+            schedule
+        );
+
+        Ok(())
+    }
+
+    async fn snapshot_all(&self) -> Result<()> {
+        let topologies = self
+            .topology_service
+            .get_all(EntityFilter::unfiltered())
+            .await?;
+
+        let mut network_ids: Vec<Uuid> = topologies.iter().map(|t| t.base.network_id).collect();
+        network_ids.sort();
+        network_ids.dedup();
+
+        for network_id in network_ids {
+            // One network failing shouldn't stop the others from being captured
+            if let Err(e) = self
+                .snapshot_network(network_id, SnapshotTrigger::Schedule)
+                .await
+            {
+                tracing::warn!(
+                    network_id = %network_id,
+                    "Failed to snapshot topologies: {}",
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn default_name(topology: &Topology) -> String {
+    format!(
+        "{} {}",
+        topology.base.name,
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    )
+}
@@ -0,0 +1,71 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Error;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    daemon::discovery::types::base::DiscoveryPhase,
+    server::{
+        discovery::r#impl::types::RunType,
+        shared::{
+            entities::{Entity, EntityDiscriminants},
+            events::{
+                bus::{EventFilter, EventSubscriber},
+                types::{EntityOperation, Event},
+            },
+        },
+        snapshots::{r#impl::base::SnapshotTrigger, service::SnapshotService},
+    },
+};
+
+/// Snapshots a network's topologies after each discovery session that completes
+#[async_trait]
+impl EventSubscriber for SnapshotService {
+    fn event_filter(&self) -> EventFilter {
+        EventFilter::entity_only(HashMap::from([(
+            EntityDiscriminants::Discovery,
+            Some(vec![EntityOperation::Created]),
+        )]))
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), Error> {
+        // Sessions finishing together on one network only need one snapshot
+        let mut network_ids: HashSet<Uuid> = HashSet::new();
+
+        for event in events {
+            // Historical records are created when a session reaches a terminal phase
+            if let Event::Entity(entity_event) = event
+                && let Entity::Discovery(discovery) = entity_event.entity_type
+                && let RunType::Historical { results } = discovery.base.run_type
+                && results.phase == DiscoveryPhase::Complete
+            {
+                network_ids.insert(results.network_id);
+            }
+        }
+
+        for network_id in network_ids {
+            if let Err(e) = self
+                .snapshot_network(network_id, SnapshotTrigger::Discovery)
+                .await
+            {
+                tracing::warn!(
+                    network_id = %network_id,
+                    "Failed to snapshot topologies after discovery: {}",
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn debounce_window_ms(&self) -> u64 {
+        // Leave time for the last entity updates of the session to land
+        5000
+    }
+
+    fn name(&self) -> &str {
+        "topology_snapshots"
+    }
+}
@@ -1,5 +1,7 @@
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
-use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Or, Viewer};
+use crate::server::auth::middleware::permissions::{
+    And, Authorized, IsDaemon, Member, Or, Read, Scope, Viewer, Write,
+};
 use crate::server::shared::extractors::Query;
 use crate::server::shared::handlers::query::{FilterQueryExtractor, NetworkFilterQuery};
 use crate::server::shared::handlers::traits::{CrudHandlers, update_handler};
@@ -23,6 +25,12 @@ mod generated {
     crate::crud_bulk_delete_handler!(Subnet, "subnets");
 }
 
+/// Viewers (API keys need `subnets:read`) or a daemon
+type SubnetReader = Or<And<Viewer, Scope<Subnet, Read>>, IsDaemon>;
+
+/// Members (API keys need `subnets:write`) or a daemon
+type SubnetWriter = Or<And<Member, Scope<Subnet, Write>>, IsDaemon>;
+
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(get_all_subnets, create_subnet))
@@ -48,11 +56,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     responses(
         (status = 200, description = "List of subnets", body = PaginatedApiResponse<Subnet>),
     ),
+    extensions(("x-required-scope" = json!(Scope::<Subnet, Read>::name()))),
     security( ("user_api_key" = []),("session" = []), ("daemon_api_key" = []))
 )]
 async fn get_all_subnets(
     state: State<Arc<AppState>>,
-    auth: Authorized<Or<Viewer, IsDaemon>>,
+    auth: Authorized<SubnetReader>,
     query: Query<NetworkFilterQuery>,
 ) -> ApiResult<Json<PaginatedApiResponse<Subnet>>> {
     let network_ids = auth.network_ids();
@@ -117,11 +126,12 @@ async fn get_all_subnets(
         (status = 200, description = "Subnet created successfully", body = ApiResponse<Subnet>),
         (status = 400, description = "Invalid request", body = ApiErrorResponse),
     ),
+    extensions(("x-required-scope" = json!(Scope::<Subnet, Write>::name()))),
     security( ("user_api_key" = []),("session" = []), ("daemon_api_key" = []))
 )]
 async fn create_subnet(
     state: State<Arc<AppState>>,
-    auth: Authorized<Or<Member, IsDaemon>>,
+    auth: Authorized<SubnetWriter>,
     ApiJson(request): ApiJson<Subnet>,
 ) -> ApiResult<Json<ApiResponse<Subnet>>> {
     let network_ids = auth.network_ids();
@@ -199,11 +209,12 @@ async fn create_subnet(
         (status = 400, description = "CIDR change would orphan existing interfaces", body = ApiErrorResponse),
         (status = 404, description = "Subnet not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Subnet, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_subnet(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Subnet, Write>>>,
     Path(id): Path<Uuid>,
     ApiJson(subnet): ApiJson<Subnet>,
 ) -> ApiResult<Json<ApiResponse<Subnet>>> {
@@ -239,5 +250,11 @@ async fn update_subnet(
     }
 
     // Delegate to generic handler
-    update_handler::<Subnet>(State(state), auth, Path(id), Json(subnet)).await
+    update_handler::<Subnet>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(subnet),
+    )
+    .await
 }
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
     subnets::{r#impl::base::Subnet, service::SubnetService},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Subnet {
@@ -12,3 +13,7 @@ impl CrudHandlers for Subnet {
         &state.services.subnet_service
     }
 }
+
+impl ScopedResource for Subnet {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Subnets;
+}
@@ -1,10 +1,14 @@
-use crate::server::auth::middleware::permissions::{Admin, Authorized, Member};
+use crate::server::auth::middleware::permissions::{
+    Admin, And, Authorized, IsDaemon, Member, Scope, Write,
+};
 use crate::server::shared::entities::EntityDiscriminants;
 use crate::server::shared::handlers::traits::create_handler;
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::storage::filter::EntityFilter;
+use crate::server::shared::storage::traits::StorableEntity;
+use crate::server::shared::types::Color;
 use crate::server::shared::types::api::{ApiError, ApiErrorResponse};
-use crate::server::tags::r#impl::base::Tag;
+use crate::server::tags::r#impl::base::{Tag, TagBase};
 use crate::server::{
     config::AppState,
     shared::types::api::{ApiResponse, ApiResult, EmptyApiResponse},
@@ -39,6 +43,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         .routes(routes!(bulk_add_tag))
         .routes(routes!(bulk_remove_tag))
         .routes(routes!(set_entity_tags))
+        .routes(routes!(apply_discovery_tag))
 }
 
 /// Create a new tag
@@ -59,11 +64,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 400, description = "Validation error: name empty or too long", body = ApiErrorResponse),
         (status = 409, description = "Tag name already exists in this organization", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Tag, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn create_tag(
     state: State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<Tag, Write>>>,
     Json(tag): Json<Tag>,
 ) -> ApiResult<Json<ApiResponse<Tag>>> {
     let organization_id = auth
@@ -131,11 +137,12 @@ pub struct SetTagsRequest {
         (status = 400, description = "Invalid entity type or tag", body = ApiErrorResponse),
         (status = 404, description = "Tag not found", body = ApiErrorResponse),
     ),
+    extensions(("x-required-scope" = json!(Scope::<Tag, Write>::name()))),
     security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn bulk_add_tag(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Tag, Write>>>,
     Json(request): Json<BulkTagRequest>,
 ) -> ApiResult<Json<ApiResponse<BulkTagResponse>>> {
     let organization_id = auth
@@ -175,11 +182,12 @@ pub async fn bulk_add_tag(
         (status = 200, description = "Tag removed successfully", body = ApiResponse<BulkTagResponse>),
         (status = 400, description = "Invalid entity type", body = ApiErrorResponse),
     ),
+    extensions(("x-required-scope" = json!(Scope::<Tag, Write>::name()))),
     security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn bulk_remove_tag(
     State(state): State<Arc<AppState>>,
-    _auth: Authorized<Member>,
+    _auth: Authorized<And<Member, Scope<Tag, Write>>>,
     Json(request): Json<BulkTagRequest>,
 ) -> ApiResult<Json<ApiResponse<BulkTagResponse>>> {
     let affected_count = state
@@ -211,11 +219,12 @@ pub async fn bulk_remove_tag(
         (status = 400, description = "Invalid entity type or tag", body = ApiErrorResponse),
         (status = 404, description = "Tag not found", body = ApiErrorResponse),
     ),
+    extensions(("x-required-scope" = json!(Scope::<Tag, Write>::name()))),
     security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn set_entity_tags(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Tag, Write>>>,
     Json(request): Json<SetTagsRequest>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     let organization_id = auth
@@ -235,3 +244,134 @@ pub async fn set_entity_tags(
 
     Ok(Json(ApiResponse::success(())))
 }
+
+/// Request body for daemon-applied tags
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct DiscoveryTagRequest {
+    /// Tag name; the tag is created in the daemon's organization if it doesn't exist
+    pub name: String,
+    /// Color used if the tag needs to be created
+    #[serde(default)]
+    pub color: Option<Color>,
+    /// The entity type (Host, Service, Subnet or Group)
+    pub entity_type: EntityDiscriminants,
+    /// The IDs of entities to tag. All must be on the daemon's network.
+    pub entity_ids: Vec<Uuid>,
+}
+
+/// Internal endpoint for daemon discovery
+///
+/// Used by daemons to tag discovered entities with values derived from orchestrator
+/// metadata (namespaces, stacks, etc). The tag is looked up by name in the daemon's
+/// organization and created if missing.
+///
+/// Tagged as "internal" - included in OpenAPI spec for client generation
+/// but hidden from public documentation.
+#[utoipa::path(
+    post,
+    path = "/discovery",
+    tags = ["tags", "internal"],
+    request_body = DiscoveryTagRequest,
+    responses(
+        (status = 200, description = "Tag applied successfully", body = ApiResponse<Tag>),
+        (status = 400, description = "Invalid entity type or entities", body = ApiErrorResponse),
+        (status = 403, description = "Entities are not on the daemon's network", body = ApiErrorResponse),
+    ),
+    security(("daemon_api_key" = []))
+)]
+pub async fn apply_discovery_tag(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<IsDaemon>,
+    Json(request): Json<DiscoveryTagRequest>,
+) -> ApiResult<Json<ApiResponse<Tag>>> {
+    let daemon_network_id = auth
+        .network_ids()
+        .first()
+        .copied()
+        .ok_or_else(|| ApiError::forbidden("Daemon has no network assignment"))?;
+
+    let organization_id = state
+        .services
+        .network_service
+        .get_by_id(&daemon_network_id)
+        .await?
+        .map(|n| n.base.organization_id)
+        .ok_or_else(|| ApiError::forbidden("Daemon network not found"))?;
+
+    // Daemons can only tag entities on their own network
+    let entity_filter = EntityFilter::unfiltered()
+        .entity_ids(&request.entity_ids)
+        .network_ids(&[daemon_network_id]);
+    let found = match request.entity_type {
+        EntityDiscriminants::Host => state
+            .services
+            .host_service
+            .get_all(entity_filter)
+            .await?
+            .len(),
+        EntityDiscriminants::Service => state
+            .services
+            .service_service
+            .get_all(entity_filter)
+            .await?
+            .len(),
+        EntityDiscriminants::Subnet => state
+            .services
+            .subnet_service
+            .get_all(entity_filter)
+            .await?
+            .len(),
+        EntityDiscriminants::Group => state
+            .services
+            .group_service
+            .get_all(entity_filter)
+            .await?
+            .len(),
+        other => {
+            return Err(ApiError::bad_request(&format!(
+                "Daemons can't tag entities of type {}",
+                other
+            )));
+        }
+    };
+
+    if found != request.entity_ids.len() {
+        return Err(ApiError::forbidden(
+            "Daemon cannot tag entities on networks it's not assigned to",
+        ));
+    }
+
+    let name_filter = EntityFilter::unfiltered()
+        .organization_id(&organization_id)
+        .name(request.name.clone());
+
+    let tag = match state.services.tag_service.get_one(name_filter).await? {
+        Some(tag) => tag,
+        None => {
+            let tag = Tag::new(TagBase {
+                name: request.name,
+                description: None,
+                color: request.color.unwrap_or(Color::Yellow),
+                organization_id,
+            });
+            state
+                .services
+                .tag_service
+                .create(tag, auth.into_entity())
+                .await?
+        }
+    };
+
+    state
+        .services
+        .entity_tag_service
+        .bulk_add_tag(
+            &request.entity_ids,
+            request.entity_type,
+            tag.id,
+            organization_id,
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(tag)))
+}
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     shared::handlers::{query::NoFilterQuery, traits::CrudHandlers},
     tags::{r#impl::base::Tag, service::TagService},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Tag {
@@ -12,3 +13,7 @@ impl CrudHandlers for Tag {
         &state.services.tag_service
     }
 }
+
+impl ScopedResource for Tag {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Tags;
+}
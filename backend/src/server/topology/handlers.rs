@@ -1,6 +1,8 @@
 use crate::server::shared::extractors::Query;
 use crate::server::{
-    auth::middleware::permissions::{Authorized, IsUser, Member, Viewer},
+    auth::middleware::permissions::{
+        And, Authorized, IsUser, Member, Read, Scope, Trigger, Viewer, Write,
+    },
     config::AppState,
     shared::{
         events::types::{TelemetryEvent, TelemetryOperation},
@@ -16,14 +18,23 @@ use crate::server::{
         },
     },
     topology::{
-        service::main::BuildGraphParams,
-        types::base::{SetEntitiesParams, Topology},
+        service::{
+            export::export,
+            main::{BuildGraphParams, TopologyService},
+            render::render_blocking,
+        },
+        types::{
+            base::{SetEntitiesParams, Topology},
+            export::{ExportFormat, ExportTopologyRequest},
+            render::{RenderFormat, RenderParams, RenderTopologyRequest},
+        },
     },
 };
 use axum::{
     extract::{Path, State},
+    http::header,
     response::{
-        Json, Sse,
+        IntoResponse, Json, Response, Sse,
         sse::{Event, KeepAlive},
     },
     routing::get,
@@ -45,6 +56,7 @@ mod generated {
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(get_all_topologies, create_topology))
+        .routes(routes!(get_organization_topology))
         .routes(routes!(
             generated::get_by_id,
             update_topology,
@@ -54,6 +66,8 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         .routes(routes!(rebuild))
         .routes(routes!(lock))
         .routes(routes!(unlock))
+        .routes(routes!(render_topology))
+        .routes(routes!(export_topology))
         // SSE endpoint (not well-supported by OpenAPI)
         .route("/stream", get(staleness_stream))
 }
@@ -67,15 +81,16 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Topology updated", body = ApiResponse<Topology>),
         (status = 404, description = "Topology not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn update_topology(
     state: State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Topology, Write>>>,
     id: Path<Uuid>,
     topology: Json<Topology>,
 ) -> ApiResult<Json<ApiResponse<Topology>>> {
-    update_handler::<Topology>(state, auth, id, topology).await
+    update_handler::<Topology>(state, auth.into_permission::<Member>(), id, topology).await
 }
 
 /// Get all topologies
@@ -87,11 +102,12 @@ async fn update_topology(
     responses(
         (status = 200, description = "List of topologies", body = PaginatedApiResponse<Topology>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn get_all_topologies(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<And<Viewer, Scope<Topology, Read>>>,
     query: Query<NetworkFilterQuery>,
 ) -> ApiResult<Json<PaginatedApiResponse<Topology>>> {
     let network_ids = auth.network_ids();
@@ -122,6 +138,65 @@ async fn get_all_topologies(
     )))
 }
 
+/// Get the organization topology
+///
+/// Every network you have access to as a container, connected through shared remote and VPN
+/// subnets, hosts found in more than one network, and network links. Built on each request
+/// and never stored.
+#[utoipa::path(
+    get,
+    path = "/organization",
+    tags = ["topology", "internal"],
+    responses(
+        (status = 200, description = "Organization topology", body = ApiResponse<Topology>),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_organization_topology(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Viewer, Scope<Topology, Read>>>,
+) -> ApiResult<Json<ApiResponse<Topology>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let organization = state
+        .services
+        .organization_service
+        .get_by_id(&organization_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Organization not found".to_string()))?;
+
+    let networks = state
+        .services
+        .network_service
+        .get_all(
+            EntityFilter::unfiltered()
+                .organization_id(&organization_id)
+                .entity_ids(&network_ids),
+        )
+        .await?;
+
+    // Both ends of a link have to be visible to the user
+    let links = state
+        .services
+        .network_link_service
+        .get_all(
+            EntityFilter::unfiltered()
+                .network_ids(&network_ids)
+                .uuid_columns("target_network_id", &network_ids),
+        )
+        .await?;
+
+    let topology = Topology::get_service(&state)
+        .build_organization_topology(organization.base.name, &networks, &links)
+        .await?;
+
+    Ok(Json(ApiResponse::success(topology)))
+}
+
 /// Create topology
 #[utoipa::path(
     post,
@@ -132,11 +207,12 @@ async fn get_all_topologies(
         (status = 200, description = "Topology created", body = ApiResponse<Topology>),
         (status = 400, description = "Validation failed", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn create_topology(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Topology, Write>>>,
     Json(mut topology): Json<Topology>,
 ) -> ApiResult<Json<ApiResponse<Topology>>> {
     let user_id = auth.user_id();
@@ -169,14 +245,19 @@ async fn create_topology(
 
     let service = Topology::get_service(&state);
 
-    let (hosts, interfaces, subnets, groups, ports, bindings) =
-        service.get_entity_data(topology.base.network_id).await?;
+    let (hosts, interfaces, subnets, groups, ports, bindings) = service
+        .get_entity_data(topology.base.network_id, &topology.base.options)
+        .await?;
 
     let services = service
-        .get_service_data(topology.base.network_id, &topology.base.options)
+        .get_service_data(topology.base.network_id, &topology.base.options, &hosts)
         .await?;
 
-    let (nodes, edges) = service.build_graph(BuildGraphParams {
+    let traceroutes = service
+        .get_traceroute_data(topology.base.network_id)
+        .await?;
+
+    let (nodes, edges) = TopologyService::build_graph(BuildGraphParams {
         options: &topology.base.options,
         hosts: &hosts,
         interfaces: &interfaces,
@@ -185,6 +266,7 @@ async fn create_topology(
         groups: &groups,
         ports: &ports,
         bindings: &bindings,
+        traceroutes: &traceroutes,
         old_edges: &[],
         old_nodes: &[],
     });
@@ -238,11 +320,12 @@ async fn create_topology(
         (status = 200, description = "Topology refreshed", body = EmptyApiResponse),
         (status = 403, description = "Access denied", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Trigger>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn refresh(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Topology, Trigger>>>,
     Json(mut topology): Json<Topology>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     let network_ids = auth.network_ids();
@@ -256,11 +339,12 @@ async fn refresh(
 
     let service = Topology::get_service(&state);
 
-    let (hosts, interfaces, subnets, groups, ports, bindings) =
-        service.get_entity_data(topology.base.network_id).await?;
+    let (hosts, interfaces, subnets, groups, ports, bindings) = service
+        .get_entity_data(topology.base.network_id, &topology.base.options)
+        .await?;
 
     let services = service
-        .get_service_data(topology.base.network_id, &topology.base.options)
+        .get_service_data(topology.base.network_id, &topology.base.options, &hosts)
         .await?;
 
     topology.set_entities(SetEntitiesParams {
@@ -291,11 +375,12 @@ async fn refresh(
         (status = 200, description = "Topology rebuilt", body = EmptyApiResponse),
         (status = 403, description = "Access denied", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Trigger>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn rebuild(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Topology, Trigger>>>,
     Json(mut topology): Json<Topology>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     let network_ids = auth.network_ids();
@@ -309,14 +394,19 @@ async fn rebuild(
 
     let service = Topology::get_service(&state);
 
-    let (hosts, interfaces, subnets, groups, ports, bindings) =
-        service.get_entity_data(topology.base.network_id).await?;
+    let (hosts, interfaces, subnets, groups, ports, bindings) = service
+        .get_entity_data(topology.base.network_id, &topology.base.options)
+        .await?;
 
     let services = service
-        .get_service_data(topology.base.network_id, &topology.base.options)
+        .get_service_data(topology.base.network_id, &topology.base.options, &hosts)
+        .await?;
+
+    let traceroutes = service
+        .get_traceroute_data(topology.base.network_id)
         .await?;
 
-    let (nodes, edges) = service.build_graph(BuildGraphParams {
+    let (nodes, edges) = TopologyService::build_graph(BuildGraphParams {
         options: &topology.base.options,
         hosts: &hosts,
         interfaces: &interfaces,
@@ -325,6 +415,7 @@ async fn rebuild(
         groups: &groups,
         ports: &ports,
         bindings: &bindings,
+        traceroutes: &traceroutes,
         old_nodes: &topology.base.nodes,
         old_edges: &topology.base.edges,
     });
@@ -391,11 +482,12 @@ async fn rebuild(
         (status = 403, description = "Access denied", body = ApiErrorResponse),
         (status = 404, description = "Topology not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn lock(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Topology, Write>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<Topology>>> {
     let service = Topology::get_service(&state);
@@ -436,11 +528,12 @@ async fn lock(
         (status = 403, description = "Access denied", body = ApiErrorResponse),
         (status = 404, description = "Topology not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn unlock(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<And<Member, Scope<Topology, Write>>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<Topology>>> {
     let service = Topology::get_service(&state);
@@ -467,6 +560,185 @@ async fn unlock(
     }
 }
 
+/// Render a topology
+///
+/// Draws the stored nodes and edges as SVG, or rasterizes them to PNG or a paginated PDF.
+/// Options in the request override the ones saved with the topology.
+#[utoipa::path(
+    post,
+    path = "/{id}/render",
+    tags = ["topology"],
+    params(("id" = Uuid, Path, description = "Topology ID")),
+    request_body = RenderTopologyRequest,
+    responses(
+        (status = 200, description = "Rendered topology", content(
+            (String = "image/svg+xml"),
+            (Vec<u8> = "image/png"),
+            (Vec<u8> = "application/pdf")
+        )),
+        (status = 400, description = "Invalid render options", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Topology not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn render_topology(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Viewer, Scope<Topology, Read>>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<RenderTopologyRequest>,
+) -> ApiResult<Response> {
+    let service = Topology::get_service(&state);
+    let network_ids = auth.network_ids();
+
+    let topology = service
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Could not find topology {}", id)))?;
+
+    // Validate user has access to this topology's network
+    if !network_ids.contains(&topology.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this topology",
+        ));
+    }
+
+    let params = RenderParams::new(
+        request.format,
+        request
+            .options
+            .unwrap_or_else(|| topology.base.options.clone()),
+        request.scale,
+        request.page_size,
+    )?;
+
+    let name = topology.base.name.clone();
+    let bytes = render_blocking(topology, params).await?;
+
+    Ok(rendered_topology_response(bytes, request.format, &name))
+}
+
+/// Export a topology
+///
+/// Converts the stored nodes and edges to Graphviz DOT, draw.io, Mermaid or GraphML for
+/// editing in other tools. draw.io and GraphML keep the computed layout. Options in the
+/// request override the ones saved with the topology.
+#[utoipa::path(
+    post,
+    path = "/{id}/export",
+    tags = ["topology"],
+    params(("id" = Uuid, Path, description = "Topology ID")),
+    request_body = ExportTopologyRequest,
+    responses(
+        (status = 200, description = "Exported topology", content(
+            (String = "text/vnd.graphviz"),
+            (String = "application/vnd.jgraph.mxfile"),
+            (String = "text/vnd.mermaid"),
+            (String = "application/graphml+xml")
+        )),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Topology not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<Topology, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_topology(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Viewer, Scope<Topology, Read>>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ExportTopologyRequest>,
+) -> ApiResult<Response> {
+    let service = Topology::get_service(&state);
+    let network_ids = auth.network_ids();
+
+    let topology = service
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Could not find topology {}", id)))?;
+
+    // Validate user has access to this topology's network
+    if !network_ids.contains(&topology.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this topology",
+        ));
+    }
+
+    let options = request
+        .options
+        .unwrap_or_else(|| topology.base.options.clone());
+    let exported = export(&topology, request.format, &options);
+
+    Ok(exported_topology_response(
+        exported,
+        request.format,
+        &topology.base.name,
+    ))
+}
+
+/// Response carrying a rendered topology, named after the topology for downloads
+pub fn rendered_topology_response(bytes: Vec<u8>, format: RenderFormat, name: &str) -> Response {
+    topology_file_response(
+        bytes,
+        format.content_type(),
+        format.extension(),
+        name,
+        false,
+    )
+}
+
+/// Response carrying an exported topology, downloaded as a file named after the topology
+pub fn exported_topology_response(text: String, format: ExportFormat, name: &str) -> Response {
+    topology_file_response(
+        text.into_bytes(),
+        format.content_type(),
+        format.extension(),
+        name,
+        true,
+    )
+}
+
+/// File response named after the topology. Attachments are downloaded rather than shown.
+fn topology_file_response(
+    bytes: Vec<u8>,
+    content_type: &str,
+    extension: &str,
+    name: &str,
+    attachment: bool,
+) -> Response {
+    let stem: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let stem = match stem.trim_matches('-') {
+        "" => "topology",
+        stem => stem,
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "{}; filename=\"{}.{}\"",
+                    if attachment { "attachment" } else { "inline" },
+                    stem,
+                    extension
+                ),
+            ),
+        ],
+        bytes,
+    )
+        .into_response()
+}
+
 async fn staleness_stream(
     State(state): State<Arc<AppState>>,
     auth: Authorized<IsUser>,
@@ -7,7 +7,7 @@ use crate::server::{
     interfaces::r#impl::base::Interface,
     ports::r#impl::base::Port,
     services::r#impl::{
-        base::Service, definitions::ServiceDefinitionExt, virtualization::ServiceVirtualization,
+        base::Service, categories::ServiceCategory, definitions::ServiceDefinitionExt,
     },
     subnets::r#impl::base::Subnet,
     topology::types::{
@@ -116,6 +116,12 @@ impl<'a> TopologyContext<'a> {
             .collect()
     }
 
+    pub fn get_service_by_binding_id(&self, binding_id: Uuid) -> Option<&'a Service> {
+        self.services
+            .iter()
+            .find(|s| s.get_binding(binding_id).is_some())
+    }
+
     pub fn get_subnet_from_interface_id(&self, interface_id: Uuid) -> Option<&'a Subnet> {
         let interface = self.interfaces.iter().find(|i| i.id == interface_id)?;
         self.get_subnet_by_id(interface.base.subnet_id)
@@ -145,13 +151,12 @@ impl<'a> TopologyContext<'a> {
 
     pub fn get_service_is_containerized_by(&self, service_id: &Uuid) -> Option<&Service> {
         if let Some(service) = self.get_service_by_id(*service_id)
-            && let Some(ServiceVirtualization::Docker(docker_virtualization)) =
-                &service.base.virtualization
+            && let Some(virtualization) = &service.base.virtualization
         {
             return self
                 .services
                 .iter()
-                .find(|s| s.id == docker_virtualization.service_id);
+                .find(|s| s.id == virtualization.service_id());
         }
         None
     }
@@ -160,9 +165,15 @@ impl<'a> TopologyContext<'a> {
         nodes
             .iter()
             .find(|n| n.id == node_id)
-            .map(|node| match node.node_type {
-                NodeType::InterfaceNode { subnet_id, .. } => subnet_id,
-                NodeType::SubnetNode { .. } => node.id,
+            .and_then(|node| match node.node_type {
+                NodeType::InterfaceNode { subnet_id, .. } => Some(subnet_id),
+                NodeType::SubnetNode { .. } => Some(node.id),
+                NodeType::HostNode { .. }
+                | NodeType::ServiceNode { .. }
+                | NodeType::StubNode { .. }
+                | NodeType::NetworkNode { .. }
+                | NodeType::NetworkSubnetNode { .. }
+                | NodeType::NetworkHostNode { .. } => None,
             })
     }
 
@@ -191,6 +202,18 @@ impl<'a> TopologyContext<'a> {
             .collect()
     }
 
+    /// Whether a host routes between subnets: it runs a gateway, core network or network
+    /// security service
+    pub fn host_is_router(&self, host_id: Uuid) -> bool {
+        self.get_services_for_host(host_id).iter().any(|s| {
+            s.base.service_definition.is_gateway()
+                || matches!(
+                    s.base.service_definition.category(),
+                    ServiceCategory::NetworkCore | ServiceCategory::NetworkSecurity
+                )
+        })
+    }
+
     pub fn is_interface_infra(&self, interface_id: Uuid) -> bool {
         if let Some(subnet) = self.get_subnet_from_interface_id(interface_id) {
             let infra_interfaces = self.get_interfaces_with_infra_service(subnet);
@@ -7,7 +7,6 @@ use uuid::Uuid;
 use crate::server::{
     groups::r#impl::{base::Group, types::GroupType},
     hosts::r#impl::virtualization::HostVirtualization,
-    services::r#impl::virtualization::ServiceVirtualization,
     subnets::r#impl::types::{SubnetType, SubnetTypeDiscriminants},
     topology::{
         service::context::TopologyContext,
@@ -26,39 +25,60 @@ impl EdgeBuilder {
         ctx.groups
             .iter()
             .flat_map(|group| {
-                let binding_ids = &group.base.binding_ids;
-                match &group.base.group_type {
-                    GroupType::RequestPath => binding_ids
-                        .windows(2)
-                        .filter_map(|window| {
-                            EdgeBuilder::edge_from_service_bindings(
-                                ctx, window[0], window[1], group,
-                            )
-                        })
-                        .collect::<Vec<Edge>>(),
-                    GroupType::HubAndSpoke => {
-                        let mut binding_ids = binding_ids.clone();
-                        binding_ids.reverse();
-                        if let Some(hub_binding_id) = binding_ids.pop() {
-                            return binding_ids
-                                .iter()
-                                .filter_map(|spoke_binding| {
-                                    EdgeBuilder::edge_from_service_bindings(
-                                        ctx,
-                                        hub_binding_id,
-                                        *spoke_binding,
-                                        group,
-                                    )
-                                })
-                                .collect::<Vec<Edge>>();
-                        }
-                        Vec::new()
-                    }
-                }
+                EdgeBuilder::group_binding_pairs(group)
+                    .into_iter()
+                    .filter_map(|(source_binding_id, target_binding_id)| {
+                        EdgeBuilder::edge_from_service_bindings(
+                            ctx,
+                            source_binding_id,
+                            target_binding_id,
+                            group,
+                        )
+                    })
+                    .collect::<Vec<Edge>>()
             })
             .collect()
     }
 
+    /// Source and target binding of each edge a group draws: consecutive bindings for a
+    /// request path, the first binding to each of the others for hub and spoke
+    pub fn group_binding_pairs(group: &Group) -> Vec<(Uuid, Uuid)> {
+        let binding_ids = &group.base.binding_ids;
+        match &group.base.group_type {
+            GroupType::RequestPath => binding_ids
+                .windows(2)
+                .map(|window| (window[0], window[1]))
+                .collect(),
+            GroupType::HubAndSpoke => match binding_ids.split_first() {
+                Some((hub_binding_id, spoke_binding_ids)) => spoke_binding_ids
+                    .iter()
+                    .map(|spoke_binding_id| (*hub_binding_id, *spoke_binding_id))
+                    .collect(),
+                None => Vec::new(),
+            },
+        }
+    }
+
+    /// Edge type for an edge drawn by a group
+    pub fn group_edge_type(
+        group: &Group,
+        source_binding_id: Uuid,
+        target_binding_id: Uuid,
+    ) -> EdgeType {
+        match group.base.group_type {
+            GroupType::HubAndSpoke => EdgeType::HubAndSpoke {
+                source_binding_id,
+                target_binding_id,
+                group_id: group.id,
+            },
+            GroupType::RequestPath => EdgeType::RequestPath {
+                source_binding_id,
+                target_binding_id,
+                group_id: group.id,
+            },
+        }
+    }
+
     // Create edges to connect a host that virtualizes containers via docker to the docker bridge subnets
     pub fn create_containerized_service_edges(
         ctx: &TopologyContext,
@@ -71,11 +91,9 @@ impl EdgeBuilder {
             HashMap::new();
 
         ctx.services.iter().for_each(|s| {
-            if let Some(ServiceVirtualization::Docker(docker_virtualization)) =
-                &s.base.virtualization
-            {
+            if let Some(virtualization) = &s.base.virtualization {
                 let entry = docker_service_to_containerized_service_ids
-                    .entry(docker_virtualization.service_id)
+                    .entry(virtualization.service_id())
                     .or_default();
                 if !entry.contains(&s.id) {
                     entry.push(s.id);
@@ -489,18 +507,11 @@ impl EdgeBuilder {
                 id: Uuid::new_v4(),
                 source: source_interface,
                 target: target_interface,
-                edge_type: match group.base.group_type {
-                    GroupType::HubAndSpoke => EdgeType::HubAndSpoke {
-                        source_binding_id,
-                        target_binding_id,
-                        group_id: group.id,
-                    },
-                    GroupType::RequestPath => EdgeType::RequestPath {
-                        source_binding_id,
-                        target_binding_id,
-                        group_id: group.id,
-                    },
-                },
+                edge_type: EdgeBuilder::group_edge_type(
+                    group,
+                    source_binding_id,
+                    target_binding_id,
+                ),
                 label,
                 source_handle,
                 target_handle,
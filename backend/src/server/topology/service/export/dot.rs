@@ -0,0 +1,150 @@
+use crate::server::{
+    shared::types::metadata::EntityMetadataProvider,
+    topology::service::{
+        export::{clusters, edge_kind, edge_label, node_lines},
+        view::TopologyView,
+    },
+};
+
+/// Graphviz digraph with one `cluster_` subgraph per subnet. Graphviz lays the graph out
+/// itself, so positions are not carried over.
+pub fn export(view: &TopologyView) -> String {
+    let (clusters, top_level) = clusters(view);
+
+    let mut out = format!("digraph {} {{\n", quote(&view.topology.base.name));
+    out.push_str("    graph [rankdir=TB, compound=true, fontname=\"Helvetica\"];\n");
+    out.push_str(
+        "    node [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\", fontname=\"Helvetica\"];\n",
+    );
+    out.push_str("    edge [penwidth=2, fontname=\"Helvetica\", fontsize=10];\n");
+
+    for cluster in &clusters {
+        let color = view
+            .subnets
+            .get(&cluster.node.id)
+            .map(|s| s.base.subnet_type.color().hex())
+            .unwrap_or_else(|| "#9ca3af".to_string());
+
+        out.push_str(&format!(
+            "\n    subgraph {} {{\n",
+            quote(&format!("cluster_{}", cluster.node.id))
+        ));
+        out.push_str(&format!("        label={};\n", quote(&cluster.label)));
+        out.push_str(&format!(
+            "        style=\"rounded\";\n        color={};\n",
+            quote(&color)
+        ));
+        for node in &cluster.children {
+            out.push_str(&format!(
+                "        {} [label={}];\n",
+                quote(&node.id.to_string()),
+                label(&node_lines(view, node))
+            ));
+        }
+        out.push_str("    }\n");
+    }
+
+    if !top_level.is_empty() {
+        out.push('\n');
+    }
+    for node in &top_level {
+        out.push_str(&format!(
+            "    {} [label={}];\n",
+            quote(&node.id.to_string()),
+            label(&node_lines(view, node))
+        ));
+    }
+
+    let edges = view.visible_edges();
+    if !edges.is_empty() {
+        out.push('\n');
+    }
+    for edge in edges {
+        let appearance = view.edge_appearance(edge);
+
+        let mut attributes = vec![
+            format!("color={}", quote(&appearance.color.hex())),
+            format!("class={}", quote(edge_kind(edge))),
+        ];
+        if let Some(text) = edge_label(view, edge) {
+            attributes.push(format!("label={}", quote(&text)));
+            attributes.push(format!("fontcolor={}", quote(&appearance.color.hex())));
+        }
+        if appearance.is_dashed {
+            attributes.push("style=dashed".to_string());
+        }
+        if !appearance.has_end_marker {
+            attributes.push("dir=none".to_string());
+        }
+
+        out.push_str(&format!(
+            "    {} -> {} [{}];\n",
+            quote(&edge.source.to_string()),
+            quote(&edge.target.to_string()),
+            attributes.join(", ")
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Double-quoted DOT ID
+fn quote(text: &str) -> String {
+    format!("\"{}\"", escape(text))
+}
+
+/// Multi-line label with centered lines, like the UI
+fn label(lines: &[String]) -> String {
+    format!(
+        "\"{}\"",
+        lines
+            .iter()
+            .map(|l| escape(l))
+            .collect::<Vec<_>>()
+            .join("\\n")
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::service::export::tests::sample_topology;
+
+    #[test]
+    fn test_dot_clusters_and_edge_styles() {
+        let topology = sample_topology();
+        let view = TopologyView::new(&topology, &topology.base.options);
+        let dot = export(&view);
+        let subnet_id = topology.base.subnets[0].id;
+
+        assert!(dot.starts_with("digraph \"Home <Lab>\" {\n"));
+        assert!(dot.contains(&format!("subgraph \"cluster_{}\" {{", subnet_id)));
+        assert!(dot.contains("label=\"Test Subnet: 192.168.1.0/24\";"));
+        assert!(
+            dot.contains("[label=\"web & proxy\\nweb & proxy service\\neth0: 192.168.1.100\"]")
+        );
+        assert!(dot.contains("label=\"Web \\\"frontend\\\"\""));
+        assert!(dot.contains("class=\"Interface\""));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_dot_escape() {
+        assert_eq!(quote("a \"b\" \\ c\nd"), "\"a \\\"b\\\" \\\\ c\\nd\"");
+    }
+}
@@ -0,0 +1,270 @@
+use crate::server::{
+    shared::{concepts::Concept, types::metadata::EntityMetadataProvider},
+    topology::{
+        service::{
+            export::{clusters, edge_label, node_lines},
+            view::{Rect, TopologyView, escape_xml},
+        },
+        types::{
+            edges::{EdgeHandle, EdgeStyle},
+            nodes::{Node, NodeType},
+        },
+    },
+};
+
+/// Parent of every top-level cell; draw.io expects cells "0" and "1" to exist
+const ROOT_LAYER: &str = "1";
+
+/// draw.io (diagrams.net) file. Subnets are containers and interface nodes their children,
+/// at the positions computed by the planner and optimizer, so the diagram opens looking like
+/// it does in Scanopy and stays editable.
+pub fn export(view: &TopologyView) -> String {
+    let (clusters, top_level) = clusters(view);
+
+    let mut cells = String::new();
+
+    for cluster in &clusters {
+        let Some(rect) = view.bounds.get(&cluster.node.id) else {
+            continue;
+        };
+        let stroke = view
+            .subnets
+            .get(&cluster.node.id)
+            .map(|s| s.base.subnet_type.color().hex())
+            .unwrap_or_else(|| "#9ca3af".to_string());
+
+        let style = format!(
+            "rounded=1;arcSize=3;absoluteArcSize=1;whiteSpace=wrap;html=1;container=1;collapsible=0;\
+             fillColor=#f9fafb;strokeColor={};verticalLabelPosition=top;verticalAlign=bottom;\
+             labelPosition=center;align=left;fontStyle=1;",
+            stroke
+        );
+        cells.push_str(&vertex(
+            &cluster.node.id.to_string(),
+            &escape_xml(&cluster.label),
+            &style,
+            ROOT_LAYER,
+            *rect,
+        ));
+
+        for node in &cluster.children {
+            // Children are placed relative to their container, which is how nodes are stored
+            let relative = Rect {
+                x: node.position.x as f64,
+                y: node.position.y as f64,
+                width: node.size.x as f64,
+                height: node.size.y as f64,
+            };
+            cells.push_str(&interface_vertex(
+                view,
+                node,
+                &cluster.node.id.to_string(),
+                relative,
+            ));
+        }
+    }
+
+    for node in &top_level {
+        if let Some(rect) = view.bounds.get(&node.id) {
+            cells.push_str(&interface_vertex(view, node, ROOT_LAYER, *rect));
+        }
+    }
+
+    for edge in view.visible_edges() {
+        let appearance = view.edge_appearance(edge);
+        let (exit_x, exit_y) = handle_point(edge.source_handle);
+        let (entry_x, entry_y) = handle_point(edge.target_handle);
+
+        let mut style = String::from(match appearance.style {
+            EdgeStyle::Straight => "edgeStyle=none;",
+            EdgeStyle::SmoothStep => "edgeStyle=orthogonalEdgeStyle;rounded=1;",
+            EdgeStyle::Step => "edgeStyle=orthogonalEdgeStyle;rounded=0;",
+            EdgeStyle::Bezier | EdgeStyle::SimpleBezier => {
+                "edgeStyle=orthogonalEdgeStyle;curved=1;rounded=0;"
+            }
+        });
+        style.push_str(&format!(
+            "html=1;strokeWidth=2;strokeColor={color};fontColor={color};\
+             exitX={};exitY={};exitDx=0;exitDy=0;entryX={};entryY={};entryDx=0;entryDy=0;",
+            exit_x,
+            exit_y,
+            entry_x,
+            entry_y,
+            color = appearance.color.hex()
+        ));
+        if appearance.is_dashed {
+            style.push_str("dashed=1;");
+        }
+        style.push_str(if appearance.has_end_marker {
+            "endArrow=block;endFill=1;"
+        } else {
+            "endArrow=none;"
+        });
+
+        cells.push_str(&format!(
+            "        <mxCell id=\"{}\" value=\"{}\" style=\"{}\" edge=\"1\" parent=\"{}\" source=\"{}\" target=\"{}\">\n\
+             \x20         <mxGeometry relative=\"1\" as=\"geometry\"/>\n\
+             \x20       </mxCell>\n",
+            edge.id,
+            escape_xml(&edge_label(view, edge).unwrap_or_default()),
+            style,
+            ROOT_LAYER,
+            edge.source,
+            edge.target
+        ));
+    }
+
+    format!(
+        "<mxfile host=\"Scanopy\">\n\
+         \x20 <diagram id=\"{}\" name=\"{}\">\n\
+         \x20   <mxGraphModel grid=\"1\" gridSize=\"10\" guides=\"1\" arrows=\"1\" connect=\"1\" page=\"0\">\n\
+         \x20     <root>\n\
+         \x20       <mxCell id=\"0\"/>\n\
+         \x20       <mxCell id=\"{}\" parent=\"0\"/>\n\
+         {}\
+         \x20     </root>\n\
+         \x20   </mxGraphModel>\n\
+         \x20 </diagram>\n\
+         </mxfile>\n",
+        view.topology.id,
+        escape_xml(&view.topology.base.name),
+        ROOT_LAYER,
+        cells
+    )
+}
+
+fn interface_vertex(view: &TopologyView, node: &Node, parent: &str, rect: Rect) -> String {
+    let is_virtualized = match node.node_type {
+        NodeType::InterfaceNode { host_id, .. } => view
+            .hosts
+            .get(&host_id)
+            .is_some_and(|h| h.base.virtualization.is_some()),
+        NodeType::HostNode { host_id } | NodeType::NetworkHostNode { host_id, .. } => view
+            .hosts
+            .get(&host_id)
+            .is_some_and(|h| h.base.virtualization.is_some()),
+        NodeType::ServiceNode { service_id, .. } => view
+            .services
+            .get(&service_id)
+            .is_some_and(|s| s.base.virtualization.is_some()),
+        NodeType::SubnetNode { .. }
+        | NodeType::StubNode { .. }
+        | NodeType::NetworkNode { .. }
+        | NodeType::NetworkSubnetNode { .. } => false,
+    };
+    let stroke = if is_virtualized {
+        Concept::Virtualization.color().hex()
+    } else {
+        "#6b7280".to_string()
+    };
+
+    // The label is HTML, so each line is escaped once for HTML and the whole value again
+    // for the XML attribute
+    let lines = node_lines(view, node);
+    let html = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = escape_xml(line);
+            if index == 0 {
+                format!("<b>{}</b>", line)
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("<br>");
+
+    vertex(
+        &node.id.to_string(),
+        &escape_xml(&html),
+        &format!(
+            "rounded=1;arcSize=8;absoluteArcSize=1;whiteSpace=wrap;html=1;fillColor=#ffffff;strokeColor={};",
+            stroke
+        ),
+        parent,
+        rect,
+    )
+}
+
+fn vertex(id: &str, value: &str, style: &str, parent: &str, rect: Rect) -> String {
+    format!(
+        "        <mxCell id=\"{}\" value=\"{}\" style=\"{}\" vertex=\"1\" parent=\"{}\">\n\
+         \x20         <mxGeometry x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" as=\"geometry\"/>\n\
+         \x20       </mxCell>\n",
+        id, value, style, parent, rect.x, rect.y, rect.width, rect.height
+    )
+}
+
+/// Connection point as a fraction of the node's width and height
+fn handle_point(handle: EdgeHandle) -> (f64, f64) {
+    match handle {
+        EdgeHandle::Top => (0.5, 0.0),
+        EdgeHandle::Bottom => (0.5, 1.0),
+        EdgeHandle::Left => (0.0, 0.5),
+        EdgeHandle::Right => (1.0, 0.5),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::service::export::tests::sample_topology;
+
+    #[test]
+    fn test_drawio_keeps_layout_positions() {
+        let topology = sample_topology();
+        let view = TopologyView::new(&topology, &topology.base.options);
+        let drawio = export(&view);
+
+        let document = roxmltree::Document::parse(&drawio).unwrap();
+        let cell = |id: String| {
+            document
+                .descendants()
+                .find(|n| n.has_tag_name("mxCell") && n.attribute("id") == Some(id.as_str()))
+                .unwrap()
+        };
+        fn geometry<'a>(node: roxmltree::Node<'a, '_>) -> (Option<&'a str>, Option<&'a str>) {
+            let g = node
+                .children()
+                .find(|n| n.has_tag_name("mxGeometry"))
+                .unwrap();
+            (g.attribute("x"), g.attribute("y"))
+        }
+
+        let subnet = cell(topology.base.subnets[0].id.to_string());
+        assert_eq!(subnet.attribute("parent"), Some(ROOT_LAYER));
+        assert!(subnet.attribute("style").unwrap().contains("container=1;"));
+        assert_eq!(geometry(subnet), (Some("100"), Some("200")));
+
+        // Interface nodes keep their position relative to the subnet container
+        let child = cell(topology.base.nodes[1].id.to_string());
+        assert_eq!(
+            child.attribute("parent"),
+            Some(topology.base.subnets[0].id.to_string().as_str())
+        );
+        assert_eq!(geometry(child), (Some("25"), Some("50")));
+        assert_eq!(
+            child.attribute("value"),
+            Some("<b>web &amp; proxy</b><br>web &amp; proxy service<br>eth0: 192.168.1.100")
+        );
+
+        // Nodes outside any subnet node are placed absolutely
+        let orphan = cell(topology.base.nodes[3].id.to_string());
+        assert_eq!(orphan.attribute("parent"), Some(ROOT_LAYER));
+        assert_eq!(geometry(orphan), (Some("800"), Some("200")));
+
+        let request_path = cell(topology.base.edges[0].id.to_string());
+        let style = request_path.attribute("style").unwrap();
+        assert_eq!(request_path.attribute("value"), Some("Web \"frontend\""));
+        assert!(style.contains("curved=1;"));
+        assert!(style.contains("exitX=1;exitY=0.5;"));
+        assert!(style.contains("endArrow=block;"));
+
+        let interface = cell(topology.base.edges[1].id.to_string());
+        let style = interface.attribute("style").unwrap();
+        assert!(style.contains("rounded=1;"));
+        assert!(style.contains("dashed=1;"));
+        assert!(style.contains("endArrow=none;"));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::server::topology::{
+    service::{
+        export::{clusters, edge_group_id, edge_kind, edge_label, node_lines},
+        view::{Rect, TopologyView, escape_xml},
+    },
+    types::nodes::Node,
+};
+
+/// Attribute keys declared up front, as GraphML requires: (id, domain, name, type)
+const KEYS: [(&str, &str, &str, &str); 10] = [
+    ("label", "all", "label", "string"),
+    ("kind", "all", "kind", "string"),
+    ("x", "node", "x", "double"),
+    ("y", "node", "y", "double"),
+    ("width", "node", "width", "double"),
+    ("height", "node", "height", "double"),
+    ("color", "edge", "color", "string"),
+    ("dashed", "edge", "dashed", "boolean"),
+    ("group", "edge", "group_id", "string"),
+    ("multi_hop", "edge", "multi_hop", "boolean"),
+];
+
+/// GraphML with subnets as nested graphs. Node geometry is absolute, in the same pixel space
+/// as the UI, so tools that read x/y data can keep the layout.
+pub fn export(view: &TopologyView) -> String {
+    let (clusters, top_level) = clusters(view);
+
+    let mut out = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    out.push('\n');
+    out.push_str(r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#);
+    out.push('\n');
+    for (id, domain, name, kind) in KEYS {
+        out.push_str(&format!(
+            "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>\n",
+            id, domain, name, kind
+        ));
+    }
+    out.push_str(&format!(
+        "  <graph id=\"{}\" edgedefault=\"directed\">\n",
+        view.topology.id
+    ));
+    out.push_str(&format!(
+        "    {}\n",
+        data("label", &view.topology.base.name)
+    ));
+
+    for cluster in &clusters {
+        out.push_str(&format!("    <node id=\"{}\">\n", cluster.node.id));
+        out.push_str(&format!("      {}\n", data("label", &cluster.label)));
+        out.push_str(&format!("      {}\n", data("kind", "subnet")));
+        out.push_str(&geometry(view, cluster.node, "      "));
+        out.push_str(&format!(
+            "      <graph id=\"{}:\" edgedefault=\"directed\">\n",
+            cluster.node.id
+        ));
+        for node in &cluster.children {
+            out.push_str(&interface_node(view, node, "        "));
+        }
+        out.push_str("      </graph>\n");
+        out.push_str("    </node>\n");
+    }
+
+    for node in &top_level {
+        out.push_str(&interface_node(view, node, "    "));
+    }
+
+    for edge in view.visible_edges() {
+        let appearance = view.edge_appearance(edge);
+
+        out.push_str(&format!(
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\" directed=\"{}\">\n",
+            edge.id, edge.source, edge.target, appearance.has_end_marker
+        ));
+        if let Some(label) = edge_label(view, edge) {
+            out.push_str(&format!("      {}\n", data("label", &label)));
+        }
+        out.push_str(&format!("      {}\n", data("kind", edge_kind(edge))));
+        out.push_str(&format!(
+            "      {}\n",
+            data("color", &appearance.color.hex())
+        ));
+        out.push_str(&format!(
+            "      {}\n",
+            data("dashed", &appearance.is_dashed.to_string())
+        ));
+        if let Some(group_id) = edge_group_id(view, edge) {
+            out.push_str(&format!("      {}\n", data("group", &group_id.to_string())));
+        }
+        out.push_str(&format!(
+            "      {}\n",
+            data("multi_hop", &edge.is_multi_hop.to_string())
+        ));
+        out.push_str("    </edge>\n");
+    }
+
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn interface_node(view: &TopologyView, node: &Node, indent: &str) -> String {
+    let mut out = format!("{}<node id=\"{}\">\n", indent, node.id);
+    out.push_str(&format!(
+        "{}  {}\n",
+        indent,
+        data("label", &node_lines(view, node).join("\n"))
+    ));
+    out.push_str(&format!("{}  {}\n", indent, data("kind", "interface")));
+    out.push_str(&geometry(view, node, &format!("{}  ", indent)));
+    out.push_str(&format!("{}</node>\n", indent));
+    out
+}
+
+fn geometry(view: &TopologyView, node: &Node, indent: &str) -> String {
+    let Some(Rect {
+        x,
+        y,
+        width,
+        height,
+    }) = view.bounds.get(&node.id).copied()
+    else {
+        return String::new();
+    };
+
+    [("x", x), ("y", y), ("width", width), ("height", height)]
+        .iter()
+        .map(|(key, value)| format!("{}{}\n", indent, data(key, &value.to_string())))
+        .collect()
+}
+
+fn data(key: &str, value: &str) -> String {
+    format!("<data key=\"{}\">{}</data>", key, escape_xml(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::service::export::tests::sample_topology;
+
+    #[test]
+    fn test_graphml_is_well_formed_and_nested() {
+        let topology = sample_topology();
+        let view = TopologyView::new(&topology, &topology.base.options);
+        let graphml = export(&view);
+
+        let document = roxmltree::Document::parse(&graphml).unwrap();
+        let root = document.root_element();
+        assert_eq!(root.tag_name().name(), "graphml");
+        assert_eq!(
+            root.children().filter(|n| n.has_tag_name("key")).count(),
+            KEYS.len()
+        );
+
+        let subnet_id = topology.base.subnets[0].id.to_string();
+        let subnet = document
+            .descendants()
+            .find(|n| n.has_tag_name("node") && n.attribute("id") == Some(subnet_id.as_str()))
+            .unwrap();
+        let children: Vec<_> = subnet
+            .children()
+            .filter(|n| n.has_tag_name("graph"))
+            .flat_map(|g| g.children().filter(|n| n.has_tag_name("node")))
+            .collect();
+        assert_eq!(children.len(), 2);
+
+        // Interface geometry is absolute
+        let x = children[0]
+            .children()
+            .find(|n| n.attribute("key") == Some("x"))
+            .and_then(|n| n.text());
+        assert_eq!(x, Some("125"));
+
+        let edges: Vec<_> = document
+            .descendants()
+            .filter(|n| n.has_tag_name("edge"))
+            .collect();
+        assert_eq!(edges.len(), 2);
+        assert_eq!(edges[0].attribute("directed"), Some("true"));
+        assert_eq!(edges[1].attribute("directed"), Some("false"));
+    }
+}
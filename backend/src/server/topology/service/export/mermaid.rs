@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::types::metadata::EntityMetadataProvider,
+    topology::service::{
+        export::{clusters, edge_label, node_lines},
+        view::TopologyView,
+    },
+};
+
+/// Mermaid flowchart for Markdown docs. Subnets become subgraphs; Mermaid lays the chart out
+/// itself. Node ids are short sequential ids since UUIDs make the source unreadable.
+pub fn export(view: &TopologyView) -> String {
+    let (clusters, top_level) = clusters(view);
+    let mut ids: HashMap<Uuid, String> = HashMap::new();
+
+    let mut out = format!(
+        "---\ntitle: \"{}\"\n---\nflowchart TB\n",
+        view.topology
+            .base
+            .name
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+    );
+    let mut styles = String::new();
+
+    for (index, cluster) in clusters.iter().enumerate() {
+        let subgraph_id = format!("s{}", index);
+        out.push_str(&format!(
+            "    subgraph {}[\"{}\"]\n",
+            subgraph_id,
+            escape(&cluster.label)
+        ));
+        for node in &cluster.children {
+            let id = format!("n{}", ids.len());
+            out.push_str(&format!(
+                "        {}[\"{}\"]\n",
+                id,
+                node_label(&node_lines(view, node))
+            ));
+            ids.insert(node.id, id);
+        }
+        out.push_str("    end\n");
+
+        if let Some(subnet) = view.subnets.get(&cluster.node.id) {
+            styles.push_str(&format!(
+                "    style {} stroke:{}\n",
+                subgraph_id,
+                subnet.base.subnet_type.color().hex()
+            ));
+        }
+    }
+
+    for node in &top_level {
+        let id = format!("n{}", ids.len());
+        out.push_str(&format!(
+            "    {}[\"{}\"]\n",
+            id,
+            node_label(&node_lines(view, node))
+        ));
+        ids.insert(node.id, id);
+    }
+
+    let mut link_index = 0;
+    for edge in view.visible_edges() {
+        let (Some(source), Some(target)) = (ids.get(&edge.source), ids.get(&edge.target)) else {
+            continue;
+        };
+        let appearance = view.edge_appearance(edge);
+
+        let arrow = match (appearance.is_dashed, appearance.has_end_marker) {
+            (true, true) => "-.->",
+            (true, false) => "-.-",
+            (false, true) => "-->",
+            (false, false) => "---",
+        };
+        let text = edge_label(view, edge)
+            .map(|l| format!("|\"{}\"|", escape(&l)))
+            .unwrap_or_default();
+
+        out.push_str(&format!("    {} {}{} {}\n", source, arrow, text, target));
+        styles.push_str(&format!(
+            "    linkStyle {} stroke:{},stroke-width:2px\n",
+            link_index,
+            appearance.color.hex()
+        ));
+        link_index += 1;
+    }
+
+    out.push_str(&styles);
+    out
+}
+
+fn node_label(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|l| escape(l))
+        .collect::<Vec<_>>()
+        .join("<br/>")
+}
+
+/// Quoted Mermaid labels take entity codes for characters that would end the label or be
+/// read as markup
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '#' => escaped.push_str("#35;"),
+            '"' => escaped.push_str("#quot;"),
+            '<' => escaped.push_str("#lt;"),
+            '>' => escaped.push_str("#gt;"),
+            '\n' => escaped.push_str("<br/>"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::service::export::tests::sample_topology;
+
+    #[test]
+    fn test_mermaid_subgraphs_and_links() {
+        let topology = sample_topology();
+        let view = TopologyView::new(&topology, &topology.base.options);
+        let mermaid = export(&view);
+
+        assert!(mermaid.starts_with("---\ntitle: \"Home <Lab>\"\n---\nflowchart TB\n"));
+        assert!(mermaid.contains("    subgraph s0[\"Test Subnet: 192.168.1.0/24\"]\n"));
+        assert!(mermaid.contains(
+            "        n0[\"web & proxy<br/>web & proxy service<br/>eth0: 192.168.1.100\"]\n"
+        ));
+        assert!(mermaid.contains("    n2[\"VM: hypervisor<br/>Test Host\"]\n"));
+        assert!(mermaid.contains("    n0 -->|\"Web #quot;frontend#quot;\"| n1\n"));
+        assert!(mermaid.contains("    n0 -.- n2\n"));
+        assert!(mermaid.contains("linkStyle 0 stroke:"));
+        assert!(mermaid.contains("linkStyle 1 stroke:"));
+    }
+
+    #[test]
+    fn test_mermaid_escape() {
+        assert_eq!(escape("#1 <a> \"b\""), "#35;1 #lt;a#gt; #quot;b#quot;");
+    }
+}
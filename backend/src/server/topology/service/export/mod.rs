@@ -0,0 +1,326 @@
+use uuid::Uuid;
+
+use crate::server::{
+    shared::types::metadata::HasId,
+    topology::{
+        service::view::TopologyView,
+        types::{
+            base::{Topology, TopologyOptions},
+            edges::Edge,
+            export::ExportFormat,
+            nodes::{Node, NodeType, STUB_LABEL},
+        },
+    },
+};
+
+pub mod dot;
+pub mod drawio;
+pub mod graphml;
+pub mod mermaid;
+
+/// Export the stored graph of a topology. Subnets become clusters or containers, interface
+/// nodes become nodes inside them, and edges keep the color and style they have in the UI.
+pub fn export(topology: &Topology, format: ExportFormat, options: &TopologyOptions) -> String {
+    let view = TopologyView::new(topology, options);
+
+    match format {
+        ExportFormat::Dot => dot::export(&view),
+        ExportFormat::Drawio => drawio::export(&view),
+        ExportFormat::Mermaid => mermaid::export(&view),
+        ExportFormat::GraphMl => graphml::export(&view),
+    }
+}
+
+/// A node drawn as a container (a subnet, or a host in the host focus layout) and the nodes
+/// placed in it
+struct Cluster<'a> {
+    node: &'a Node,
+    label: String,
+    children: Vec<&'a Node>,
+}
+
+/// Group nodes under the node they are placed in. Everything else, including nodes whose
+/// parent is missing, is returned separately so exporters can place it at the top level.
+fn clusters<'a>(view: &TopologyView<'a>) -> (Vec<Cluster<'a>>, Vec<&'a Node>) {
+    let nodes = &view.topology.base.nodes;
+
+    let mut clusters: Vec<Cluster<'a>> = nodes
+        .iter()
+        .filter(|node| view.has_children(node))
+        .map(|node| Cluster {
+            node,
+            label: node_lines(view, node)
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| "Subnet".to_string()),
+            children: Vec::new(),
+        })
+        .collect();
+    let mut top_level = Vec::new();
+
+    for node in nodes {
+        if clusters.iter().any(|c| c.node.id == node.id) {
+            continue;
+        }
+        match node
+            .parent_id()
+            .and_then(|parent_id| clusters.iter_mut().find(|c| c.node.id == parent_id))
+        {
+            Some(cluster) => cluster.children.push(node),
+            None => top_level.push(node),
+        }
+    }
+
+    (clusters, top_level)
+}
+
+/// Text shown on a node, one entry per line. Interface nodes show the layout header, the
+/// host, its services with their ports, then the interface address.
+fn node_lines(view: &TopologyView, node: &Node) -> Vec<String> {
+    let mut lines = Vec::new();
+    lines.extend(node.header.clone());
+
+    match node.node_type {
+        NodeType::SubnetNode { .. } => return view.subnet_label(node).into_iter().collect(),
+        NodeType::InterfaceNode {
+            host_id,
+            interface_id,
+            subnet_id,
+            ..
+        } => {
+            if let Some(host) = view.hosts.get(&host_id) {
+                lines.push(host.base.name.clone());
+            }
+
+            for service in view.services_for_node(host_id, interface_id) {
+                let ports = view.ports_for_service(service, interface_id);
+                if ports.is_empty() {
+                    lines.push(service.base.name.clone());
+                } else {
+                    lines.push(format!("{} ({})", service.base.name, ports.join(", ")));
+                }
+            }
+
+            lines.extend(view.interface_label(subnet_id, interface_id));
+        }
+        NodeType::HostNode { host_id } => {
+            if let Some(host) = view.hosts.get(&host_id) {
+                lines.push(host.base.name.clone());
+                lines.extend(host.base.hostname.clone());
+            }
+        }
+        NodeType::ServiceNode { service_id, .. } => {
+            if let Some(service) = view.services.get(&service_id) {
+                lines.push(service.base.name.clone());
+            }
+        }
+        NodeType::StubNode { .. } => lines.push(STUB_LABEL.to_string()),
+        // Named by their header
+        NodeType::NetworkNode { .. } => {}
+        NodeType::NetworkSubnetNode { subnet_id, .. } => {
+            if let Some(subnet) = view.subnets.get(&subnet_id) {
+                lines.push(subnet.base.cidr.to_string());
+            }
+        }
+        NodeType::NetworkHostNode { host_id, .. } => {
+            if let Some(host) = view.hosts.get(&host_id) {
+                lines.extend(host.base.hostname.clone());
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        lines.push("Unknown host".to_string());
+    }
+    lines
+}
+
+/// Group edges are labelled with their group so a chain of hops reads as one path; other
+/// edges keep the label the edge builder gave them
+fn edge_label(view: &TopologyView, edge: &Edge) -> Option<String> {
+    view.edge_group(edge)
+        .map(|g| g.base.name.clone())
+        .or_else(|| edge.label.clone())
+        .filter(|l| !l.is_empty())
+}
+
+/// Edge type name, e.g. `RequestPath`
+fn edge_kind(edge: &Edge) -> &'static str {
+    edge.edge_type.id()
+}
+
+/// Group id of an edge, for formats that can carry it as data
+fn edge_group_id(view: &TopologyView, edge: &Edge) -> Option<Uuid> {
+    view.edge_group(edge).map(|g| g.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        bindings::r#impl::base::{Binding, BindingType},
+        topology::types::{
+            edges::{EdgeHandle, EdgeType, EdgeTypeDiscriminants},
+            layout::{Ixy, Uxy},
+        },
+    };
+    use crate::tests;
+
+    /// Two hosts on one subnet joined by a request path group, plus a host on a subnet with no
+    /// node of its own
+    pub(super) fn sample_topology() -> Topology {
+        let network_id = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+        let mut group = tests::group(&network_id);
+        group.base.name = "Web \"frontend\"".to_string();
+
+        let mut topology = Topology::default();
+        topology.base.name = "Home <Lab>".to_string();
+
+        let mut nodes = vec![Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: subnet.id,
+            position: Ixy { x: 100, y: 200 },
+            size: Uxy { x: 600, y: 300 },
+            header: None,
+        }];
+
+        for (index, name) in ["web & proxy", "db"].iter().enumerate() {
+            let mut host = tests::host(&network_id);
+            host.base.name = name.to_string();
+            let mut interface = tests::interface(&network_id, &subnet.id);
+            interface.base.host_id = host.id;
+
+            let mut service = tests::service(&network_id, &host.id);
+            service.base.name = format!("{} service", name);
+            service.base.bindings = vec![Binding::new_serviceless(BindingType::Interface {
+                interface_id: interface.id,
+            })];
+
+            nodes.push(Node {
+                node_type: NodeType::InterfaceNode {
+                    subnet_id: subnet.id,
+                    host_id: host.id,
+                    interface_id: Some(interface.id),
+                    is_infra: false,
+                },
+                id: interface.id,
+                position: Ixy {
+                    x: 25 + 300 * index as isize,
+                    y: 50,
+                },
+                size: Uxy { x: 250, y: 100 },
+                header: None,
+            });
+
+            topology.base.hosts.push(host);
+            topology.base.interfaces.push(interface);
+            topology.base.services.push(service);
+        }
+
+        let orphan_host = tests::host(&network_id);
+        nodes.push(Node {
+            node_type: NodeType::InterfaceNode {
+                subnet_id: Uuid::new_v4(),
+                host_id: orphan_host.id,
+                interface_id: None,
+                is_infra: false,
+            },
+            id: Uuid::new_v4(),
+            position: Ixy { x: 800, y: 200 },
+            size: Uxy { x: 250, y: 100 },
+            header: Some("VM: hypervisor".to_string()),
+        });
+        topology.base.hosts.push(orphan_host);
+
+        topology.base.edges = vec![
+            Edge {
+                id: Uuid::new_v4(),
+                source: nodes[1].id,
+                target: nodes[2].id,
+                edge_type: EdgeType::RequestPath {
+                    group_id: group.id,
+                    source_binding_id: Uuid::new_v4(),
+                    target_binding_id: Uuid::new_v4(),
+                },
+                label: None,
+                source_handle: EdgeHandle::Right,
+                target_handle: EdgeHandle::Left,
+                is_multi_hop: false,
+            },
+            Edge {
+                id: Uuid::new_v4(),
+                source: nodes[1].id,
+                target: nodes[3].id,
+                edge_type: EdgeType::Interface {
+                    host_id: Uuid::new_v4(),
+                },
+                label: None,
+                source_handle: EdgeHandle::Bottom,
+                target_handle: EdgeHandle::Top,
+                is_multi_hop: false,
+            },
+        ];
+
+        topology.base.nodes = nodes;
+        topology.base.subnets.push(subnet);
+        topology.base.groups.push(group);
+        topology
+    }
+
+    #[test]
+    fn test_clusters_and_node_lines() {
+        let topology = sample_topology();
+        let view = TopologyView::new(&topology, &topology.base.options);
+
+        let (clusters, top_level) = clusters(&view);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].label, "Test Subnet: 192.168.1.0/24");
+        assert_eq!(clusters[0].children.len(), 2);
+        assert_eq!(top_level.len(), 1);
+
+        assert_eq!(
+            node_lines(&view, clusters[0].children[0]),
+            vec!["web & proxy", "web & proxy service", "eth0: 192.168.1.100"]
+        );
+        assert_eq!(
+            node_lines(&view, top_level[0]),
+            vec!["VM: hypervisor", "Test Host"]
+        );
+    }
+
+    #[test]
+    fn test_group_edges_labelled_with_group_name() {
+        let topology = sample_topology();
+        let view = TopologyView::new(&topology, &topology.base.options);
+        let edges = view.visible_edges();
+
+        assert_eq!(
+            edge_label(&view, edges[0]).as_deref(),
+            Some("Web \"frontend\"")
+        );
+        assert_eq!(edge_label(&view, edges[1]), None);
+        assert_eq!(edge_kind(edges[0]), "RequestPath");
+    }
+
+    #[test]
+    fn test_hidden_edge_types_are_not_exported() {
+        let topology = sample_topology();
+        let mut options = topology.base.options.clone();
+        options
+            .local
+            .hide_edge_types
+            .push(EdgeTypeDiscriminants::Interface);
+
+        for format in [
+            ExportFormat::Dot,
+            ExportFormat::Drawio,
+            ExportFormat::Mermaid,
+            ExportFormat::GraphMl,
+        ] {
+            let all = export(&topology, format, &topology.base.options);
+            let filtered = export(&topology, format, &options);
+            assert!(filtered.len() < all.len(), "{:?}", format);
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use anyhow::Error;
 use async_trait::async_trait;
@@ -13,6 +16,8 @@ use crate::server::{
     groups::{r#impl::base::Group, service::GroupService},
     hosts::{r#impl::base::Host, service::HostService},
     interfaces::{r#impl::base::Interface, service::InterfaceService},
+    network_links::r#impl::base::NetworkLink,
+    networks::r#impl::Network,
     ports::{r#impl::base::Port, service::PortService},
     services::{r#impl::base::Service, service::ServiceService},
     shared::{
@@ -33,16 +38,30 @@ use crate::server::{
     subnets::{r#impl::base::Subnet, service::SubnetService},
     topology::{
         service::{
-            context::TopologyContext, edge_builder::EdgeBuilder,
-            optimizer::main::TopologyOptimizer,
-            planner::subnet_layout_planner::SubnetLayoutPlanner,
+            context::TopologyContext,
+            edge_builder::EdgeBuilder,
+            optimizer::{child_positioner::ChildPositioner, main::TopologyOptimizer},
+            planner::{
+                host_focus_planner::HostFocusPlanner,
+                layout_reuse::PreviousSubnetLayout,
+                organization_planner::{OrganizationGraphParams, OrganizationPlanner},
+                route_planner::RoutePlanner,
+                routed_layout_planner::RoutedLayoutPlanner,
+                service_graph_planner::ServiceGraphPlanner,
+                stub_planner::StubPlanner,
+                subnet_layout_planner::SubnetLayoutPlanner,
+                utils::PlannerUtils,
+            },
         },
         types::{
-            base::{Topology, TopologyOptions},
+            base::{
+                SetEntitiesParams, Topology, TopologyBase, TopologyLayoutMode, TopologyOptions,
+            },
             edges::{Edge, EdgeHandle},
             nodes::Node,
         },
     },
+    traceroutes::{r#impl::base::Traceroute, service::TracerouteService},
 };
 
 pub struct TopologyService {
@@ -54,6 +73,7 @@ pub struct TopologyService {
     service_service: Arc<ServiceService>,
     port_service: Arc<PortService>,
     binding_service: Arc<BindingService>,
+    traceroute_service: Arc<TracerouteService>,
     event_bus: Arc<EventBus>,
     pub staleness_tx: broadcast::Sender<Topology>,
 }
@@ -93,13 +113,16 @@ impl CrudService<Topology> for TopologyService {
             entity
         };
 
-        let (hosts, interfaces, subnets, groups, ports, bindings) =
-            self.get_entity_data(topology.base.network_id).await?;
+        let (hosts, interfaces, subnets, groups, ports, bindings) = self
+            .get_entity_data(topology.base.network_id, &topology.base.options)
+            .await?;
 
         let services = self
-            .get_service_data(topology.base.network_id, &topology.base.options)
+            .get_service_data(topology.base.network_id, &topology.base.options, &hosts)
             .await?;
 
+        let traceroutes = self.get_traceroute_data(topology.base.network_id).await?;
+
         let params = BuildGraphParams {
             hosts: &hosts,
             interfaces: &interfaces,
@@ -108,12 +131,13 @@ impl CrudService<Topology> for TopologyService {
             groups: &groups,
             ports: &ports,
             bindings: &bindings,
+            traceroutes: &traceroutes,
             old_edges: &[],
             old_nodes: &[],
             options: &topology.base.options,
         };
 
-        let (nodes, edges) = self.build_graph(params);
+        let (nodes, edges) = Self::build_graph(params);
 
         topology.base.edges = edges;
         topology.base.nodes = nodes;
@@ -156,6 +180,7 @@ pub struct BuildGraphParams<'a> {
     pub groups: &'a [Group],
     pub ports: &'a [Port],
     pub bindings: &'a [Binding],
+    pub traceroutes: &'a [Traceroute],
     pub old_nodes: &'a [Node],
     pub old_edges: &'a [Edge],
 }
@@ -170,6 +195,7 @@ impl TopologyService {
         service_service: Arc<ServiceService>,
         port_service: Arc<PortService>,
         binding_service: Arc<BindingService>,
+        traceroute_service: Arc<TracerouteService>,
         storage: Arc<GenericPostgresStorage<Topology>>,
         event_bus: Arc<EventBus>,
     ) -> Self {
@@ -183,6 +209,7 @@ impl TopologyService {
             storage,
             port_service,
             binding_service,
+            traceroute_service,
             event_bus,
             staleness_tx,
         }
@@ -192,9 +219,12 @@ impl TopologyService {
         self.staleness_tx.subscribe()
     }
 
+    /// Entities for the topology, narrowed by its filter. Groups and bindings are kept whole so
+    /// edges into filtered out parts of the network can still be drawn as stubs.
     pub async fn get_entity_data(
         &self,
         network_id: Uuid,
+        options: &TopologyOptions,
     ) -> Result<
         (
             Vec<Host>,
@@ -223,15 +253,69 @@ impl TopologyService {
         let ports = self.port_service.get_all(network_filter.clone()).await?;
         let bindings = self.binding_service.get_all(network_filter.clone()).await?;
 
+        let filter = &options.request.filter;
+        if !filter.is_active() {
+            return Ok((hosts, interfaces, subnets, groups, ports, bindings));
+        }
+
+        // Hosts can match on the names of their services
+        let services = if filter.search_term().is_some() {
+            self.service_service.get_all(network_filter.clone()).await?
+        } else {
+            Vec::new()
+        };
+
+        let subnets: Vec<Subnet> = subnets
+            .into_iter()
+            .filter(|s| filter.subnet_matches(s))
+            .collect();
+        let subnet_ids: HashSet<Uuid> = subnets.iter().map(|s| s.id).collect();
+
+        let hosts: Vec<Host> = hosts
+            .into_iter()
+            .filter(|h| filter.host_matches(h, &services))
+            .filter(|h| {
+                !filter.filters_subnets()
+                    || interfaces
+                        .iter()
+                        .any(|i| i.base.host_id == h.id && subnet_ids.contains(&i.base.subnet_id))
+            })
+            .collect();
+        let host_ids: HashSet<Uuid> = hosts.iter().map(|h| h.id).collect();
+
+        let interfaces = interfaces
+            .into_iter()
+            .filter(|i| {
+                host_ids.contains(&i.base.host_id) && subnet_ids.contains(&i.base.subnet_id)
+            })
+            .collect();
+        let ports = ports
+            .into_iter()
+            .filter(|p| host_ids.contains(&p.base.host_id))
+            .collect();
+
         Ok((hosts, interfaces, subnets, groups, ports, bindings))
     }
 
+    /// Paths traced by the network's daemons. They aren't narrowed by the topology's filter;
+    /// hops that aren't drawn are skipped over when the path is.
+    pub async fn get_traceroute_data(&self, network_id: Uuid) -> Result<Vec<Traceroute>, Error> {
+        self.traceroute_service
+            .get_all(EntityFilter::unfiltered().network_ids(&[network_id]))
+            .await
+    }
+
+    /// Services for the topology. `hosts` are the hosts returned by `get_entity_data`; when the
+    /// topology is filtered, services on any other host are left out.
     pub async fn get_service_data(
         &self,
         network_id: Uuid,
         options: &TopologyOptions,
+        hosts: &[Host],
     ) -> Result<Vec<Service>, Error> {
         let network_filter = EntityFilter::unfiltered().network_ids(&[network_id]);
+        let filter = &options.request.filter;
+        let hosts_by_id: HashMap<Uuid, &Host> = hosts.iter().map(|h| (h.id, h)).collect();
 
         Ok(self
             .service_service
@@ -244,11 +328,82 @@ impl TopologyService {
                     .hide_service_categories
                     .contains(&s.base.service_definition.category())
             })
+            .filter(|s| {
+                if !filter.is_active() {
+                    return true;
+                }
+                let host = hosts_by_id.get(&s.base.host_id).copied();
+                host.is_some() && filter.service_matches(s, host)
+            })
             .cloned()
             .collect())
     }
 
-    pub fn build_graph(&self, params: BuildGraphParams) -> (Vec<Node>, Vec<Edge>) {
+    /// The organization topology across `networks`, which should be the networks the user has
+    /// access to. It's built on request and never stored, so it has no id of its own.
+    pub async fn build_organization_topology(
+        &self,
+        name: String,
+        networks: &[Network],
+        links: &[NetworkLink],
+    ) -> Result<Topology, Error> {
+        let network_ids: Vec<Uuid> = networks.iter().map(|n| n.id).collect();
+        let network_filter = EntityFilter::unfiltered().network_ids(&network_ids);
+
+        let hosts = self
+            .host_service
+            .get_all(network_filter.clone().hidden_is(false))
+            .await?;
+        let interfaces = self
+            .interface_service
+            .get_all(network_filter.clone())
+            .await?;
+        let subnets = self.subnet_service.get_all(network_filter).await?;
+
+        let (nodes, edges) =
+            OrganizationPlanner::create_nodes_and_edges(&OrganizationGraphParams {
+                networks,
+                hosts: &hosts,
+                interfaces: &interfaces,
+                subnets: &subnets,
+                links,
+            });
+
+        // Only the entities that are drawn
+        let node_ids: HashSet<Uuid> = nodes.iter().map(|n| n.id).collect();
+        let hosts: Vec<Host> = hosts
+            .into_iter()
+            .filter(|h| node_ids.contains(&h.id))
+            .collect();
+        let interfaces = interfaces
+            .into_iter()
+            .filter(|i| node_ids.contains(&i.base.host_id))
+            .collect();
+        let subnets = subnets
+            .into_iter()
+            .filter(|s| node_ids.contains(&s.id))
+            .collect();
+
+        let mut topology = Topology {
+            base: TopologyBase::new(name, Uuid::nil()),
+            ..Default::default()
+        };
+        topology.set_graph(nodes, edges);
+        topology.set_entities(SetEntitiesParams {
+            hosts,
+            interfaces,
+            subnets,
+            services: Vec::new(),
+            groups: Vec::new(),
+            ports: Vec::new(),
+            bindings: Vec::new(),
+        });
+        topology.clear_stale();
+
+        Ok(topology)
+    }
+
+    pub fn build_graph(params: BuildGraphParams) -> (Vec<Node>, Vec<Edge>) {
         let BuildGraphParams {
             hosts,
             interfaces,
@@ -257,6 +412,7 @@ impl TopologyService {
             groups,
             ports,
             bindings,
+            traceroutes,
             old_edges,
             old_nodes,
             options,
@@ -267,37 +423,30 @@ impl TopologyService {
             hosts, interfaces, subnets, services, groups, ports, bindings, options,
         );
 
-        // Create all edges (needed for anchor analysis)
-        let mut all_edges = Vec::new();
-
-        all_edges.extend(EdgeBuilder::create_interface_edges(&ctx));
-
-        all_edges.extend(EdgeBuilder::create_group_edges(&ctx));
-        all_edges.extend(EdgeBuilder::create_vm_host_edges(&ctx));
-        let (container_edges, docker_bridge_host_subnet_id_to_group_on) =
-            EdgeBuilder::create_containerized_service_edges(
-                &ctx,
-                options.request.group_docker_bridges_by_host,
-            );
-
-        all_edges.extend(container_edges);
-
-        // Create nodes with layout
-        let mut layout_planner = SubnetLayoutPlanner::new();
-        let (subnet_layouts, child_nodes) = layout_planner.create_subnet_child_nodes(
-            &ctx,
-            &mut all_edges,
-            options.request.group_docker_bridges_by_host,
-            docker_bridge_host_subnet_id_to_group_on,
-        );
-
-        let subnet_nodes = layout_planner.create_subnet_nodes(&ctx, &subnet_layouts);
+        let (mut all_nodes, mut optimized_edges) = match options.request.layout_mode {
+            TopologyLayoutMode::Subnets => Self::plan_subnet_layout(&ctx, old_nodes),
+            TopologyLayoutMode::Routed => Self::carry_over_positions(
+                RoutedLayoutPlanner::create_nodes_and_edges(&ctx),
+                old_nodes,
+            ),
+            TopologyLayoutMode::ServiceDependencies => Self::carry_over_positions(
+                ServiceGraphPlanner::create_nodes_and_edges(&ctx),
+                old_nodes,
+            ),
+            TopologyLayoutMode::HostFocus => Self::carry_over_positions(
+                HostFocusPlanner::create_nodes_and_edges(&ctx, options.request.focus_host_id),
+                old_nodes,
+            ),
+        };
 
-        // Optimize node positions and handle edge adjustments
-        let optimizer = TopologyOptimizer::new(&ctx);
-        let mut all_nodes: Vec<Node> = subnet_nodes.into_iter().chain(child_nodes).collect();
+        // The routed layout has no group edges to cut off
+        if options.request.filter.is_active()
+            && options.request.layout_mode != TopologyLayoutMode::Routed
+        {
+            StubPlanner::add_stubs(&ctx, &mut all_nodes, &mut optimized_edges);
+        }
 
-        let optimized_edges = optimizer.optimize_graph(&mut all_nodes, &all_edges);
+        RoutePlanner::add_route_edges(&ctx, traceroutes, &all_nodes, &mut optimized_edges);
 
         // Build graph
         let mut graph: Graph<Node, Edge> = Graph::new();
@@ -377,4 +526,67 @@ impl TopologyService {
             graph.edge_weights().cloned().collect(),
         )
     }
+
+    /// Subnets as containers of interface nodes, optimized to cut down on crossings. Everything
+    /// is planned again on every build, but when only a few subnets changed since the previous
+    /// build the optimizer is skipped and the planned nodes are merged into the previous layout.
+    fn plan_subnet_layout(ctx: &TopologyContext, old_nodes: &[Node]) -> (Vec<Node>, Vec<Edge>) {
+        let options = ctx.options;
+
+        // Create all edges (needed for anchor analysis)
+        let mut all_edges = Vec::new();
+
+        all_edges.extend(EdgeBuilder::create_interface_edges(ctx));
+
+        all_edges.extend(EdgeBuilder::create_group_edges(ctx));
+        all_edges.extend(EdgeBuilder::create_vm_host_edges(ctx));
+        let (container_edges, docker_bridge_host_subnet_id_to_group_on) =
+            EdgeBuilder::create_containerized_service_edges(
+                ctx,
+                options.request.group_docker_bridges_by_host,
+            );
+
+        all_edges.extend(container_edges);
+
+        // Create nodes with layout
+        let mut layout_planner = SubnetLayoutPlanner::new();
+        let (subnet_layouts, child_nodes) = layout_planner.create_subnet_child_nodes(
+            ctx,
+            &mut all_edges,
+            options.request.group_docker_bridges_by_host,
+            docker_bridge_host_subnet_id_to_group_on,
+        );
+
+        let subnet_nodes = layout_planner.create_subnet_nodes(ctx, &subnet_layouts);
+
+        if let Some(previous) = PreviousSubnetLayout::new(old_nodes) {
+            let child_positioner = ChildPositioner::new(ctx);
+            let mut child_nodes = child_nodes.clone();
+            child_positioner.compress_vertical_spacing(&mut child_nodes);
+
+            if let Some(all_nodes) = previous.apply(&subnet_nodes, &child_nodes) {
+                let edges = child_positioner.fix_intra_subnet_handles(&all_edges, &all_nodes);
+                return (all_nodes, edges);
+            }
+        }
+
+        // Optimize node positions and handle edge adjustments
+        let optimizer = TopologyOptimizer::new(ctx);
+        let mut all_nodes: Vec<Node> = subnet_nodes.into_iter().chain(child_nodes).collect();
+
+        let optimized_edges = optimizer.optimize_graph(&mut all_nodes, &all_edges);
+
+        (all_nodes, optimized_edges)
+    }
+
+    /// The other layouts are deterministic, so only nodes the user moved differ from a fresh
+    /// layout. Keep those positions, then point edges at wherever their nodes ended up.
+    fn carry_over_positions(
+        (mut nodes, mut edges): (Vec<Node>, Vec<Edge>),
+        old_nodes: &[Node],
+    ) -> (Vec<Node>, Vec<Edge>) {
+        PlannerUtils::keep_previous_positions(&mut nodes, old_nodes);
+        PlannerUtils::assign_edge_handles(&nodes, &mut edges);
+        (nodes, edges)
+    }
 }
@@ -1,6 +1,10 @@
 pub mod context;
 pub mod edge_builder;
+pub mod export;
 pub mod main;
 pub mod optimizer;
 pub mod planner;
+pub mod raster;
+pub mod render;
 pub mod subscriber;
+pub mod view;
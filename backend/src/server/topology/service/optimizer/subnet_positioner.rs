@@ -306,6 +306,13 @@ impl<'a> SubnetPositioner<'a> {
                                         }
                                     }
                                 }
+                                // Only subnets and their interfaces are in the subnet layout
+                                NodeType::HostNode { .. }
+                                | NodeType::ServiceNode { .. }
+                                | NodeType::StubNode { .. }
+                                | NodeType::NetworkNode { .. }
+                                | NodeType::NetworkSubnetNode { .. }
+                                | NodeType::NetworkHostNode { .. } => continue,
                             };
 
                             // Calculate what our subnet.x should be to align our node's handle
@@ -342,6 +349,12 @@ impl<'a> SubnetPositioner<'a> {
                                         }
                                     }
                                 }
+                                NodeType::HostNode { .. }
+                                | NodeType::ServiceNode { .. }
+                                | NodeType::StubNode { .. }
+                                | NodeType::NetworkNode { .. }
+                                | NodeType::NetworkSubnetNode { .. }
+                                | NodeType::NetworkHostNode { .. } => continue,
                             };
 
                             neighbor_positions.push((desired_subnet_x as f64, weight));
@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use uuid::Uuid;
+
+use crate::server::{
+    hosts::r#impl::base::Host,
+    services::r#impl::base::Service,
+    topology::{
+        service::{
+            context::TopologyContext,
+            edge_builder::EdgeBuilder,
+            planner::utils::{COLLAPSED_SUBNET_SIZE, NODE_PADDING, PlannerUtils, SUBNET_PADDING},
+        },
+        types::{
+            edges::{Edge, EdgeHandle, EdgeType},
+            layout::{Ixy, NodeLayout, Uxy},
+            nodes::{Node, NodeType},
+        },
+    },
+};
+
+/// Services per row inside the focused host
+const SERVICES_PER_ROW: usize = 3;
+
+/// Lays out the host focus view: the focused host as a container of its services, with
+/// containers in rows under the runtime that runs them. The subnets the host has interfaces
+/// on form a column to its left, and the hosts it talks to or shares a hypervisor link with
+/// form a column to its right.
+pub struct HostFocusPlanner;
+
+impl HostFocusPlanner {
+    /// Empty when no host is selected or the host is no longer in the topology
+    pub fn create_nodes_and_edges(
+        ctx: &TopologyContext,
+        host_id: Option<Uuid>,
+    ) -> (Vec<Node>, Vec<Edge>) {
+        let Some(host) = host_id.and_then(|id| ctx.get_host_by_id(id)) else {
+            return (Vec::new(), Vec::new());
+        };
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+
+        let host_size = Self::create_service_nodes(ctx, host, &mut nodes, &mut edges);
+        let subnet_ids = Self::create_subnet_edges(ctx, host, &mut edges);
+        let neighbours = Self::create_neighbour_edges(ctx, host, &mut edges);
+
+        // Subnets on the left, the host in the middle, neighbours on the right, each column
+        // centered on the host
+        let column = |count: usize, size: Uxy, x: isize| -> Vec<Ixy> {
+            let height = count * size.y + count.saturating_sub(1) * NODE_PADDING.y;
+            let top = (host_size.y as isize - height as isize) / 2;
+            (0..count)
+                .map(|i| Ixy {
+                    x,
+                    y: top + (i * (size.y + NODE_PADDING.y)) as isize,
+                })
+                .collect()
+        };
+
+        let host_x = if subnet_ids.is_empty() {
+            0
+        } else {
+            (COLLAPSED_SUBNET_SIZE.x + SUBNET_PADDING.x * 2) as isize
+        };
+        let neighbour_x = host_x + (host_size.x + SUBNET_PADDING.x * 2) as isize;
+
+        nodes.push(Node {
+            node_type: NodeType::HostNode { host_id: host.id },
+            id: host.id,
+            position: Ixy { x: host_x, y: 0 },
+            size: host_size,
+            header: None,
+        });
+
+        let subnet_positions = column(subnet_ids.len(), COLLAPSED_SUBNET_SIZE, 0);
+        for (subnet_id, position) in subnet_ids.iter().zip(subnet_positions) {
+            nodes.push(Node {
+                node_type: NodeType::SubnetNode { infra_width: 0 },
+                id: *subnet_id,
+                position,
+                size: COLLAPSED_SUBNET_SIZE,
+                header: None,
+            });
+        }
+
+        let neighbour_size = Uxy::default_subnet_child_size();
+        let neighbour_positions = column(neighbours.len(), neighbour_size, neighbour_x);
+        for (neighbour, position) in neighbours.iter().zip(neighbour_positions) {
+            nodes.push(Node {
+                node_type: NodeType::HostNode {
+                    host_id: neighbour.id,
+                },
+                id: neighbour.id,
+                position,
+                size: neighbour_size,
+                header: None,
+            });
+        }
+
+        (nodes, edges)
+    }
+
+    /// Add a node for each of the host's services, positioned inside the host, and an edge
+    /// from each container runtime to its containers. Returns the size of the host node.
+    fn create_service_nodes(
+        ctx: &TopologyContext,
+        host: &Host,
+        nodes: &mut Vec<Node>,
+        edges: &mut Vec<Edge>,
+    ) -> Uxy {
+        let services: Vec<_> = ctx
+            .get_services_for_host(host.id)
+            .into_iter()
+            .sorted_by_key(|s| (s.base.position, s.id))
+            .collect();
+
+        // Containers whose runtime is also on this host are grouped under it
+        let runtime_of = |service_id: Uuid| {
+            ctx.get_service_is_containerized_by(&service_id)
+                .filter(|runtime| runtime.base.host_id == host.id && runtime.id != service_id)
+        };
+        let (containers, top_level): (Vec<_>, Vec<_>) =
+            services.iter().partition(|s| runtime_of(s.id).is_some());
+
+        let size = Uxy::service_node_size(false);
+        let layout = |id: Uuid| {
+            (
+                id,
+                NodeLayout {
+                    size,
+                    position: Ixy::default(),
+                },
+            )
+        };
+
+        let mut rows: Vec<Vec<(Uuid, NodeLayout)>> = top_level
+            .chunks(SERVICES_PER_ROW)
+            .map(|chunk: &[&Service]| chunk.iter().map(|s| layout(s.id)).collect())
+            .collect();
+
+        for runtime in &top_level {
+            let runs: Vec<_> = containers
+                .iter()
+                .filter(|c| runtime_of(c.id).is_some_and(|r| r.id == runtime.id))
+                .collect();
+
+            rows.extend(
+                runs.chunks(SERVICES_PER_ROW)
+                    .map(|chunk| chunk.iter().map(|c| layout(c.id)).collect()),
+            );
+
+            edges.extend(runs.iter().map(|container| Edge {
+                id: Uuid::new_v4(),
+                source: runtime.id,
+                target: container.id,
+                edge_type: EdgeType::ServiceVirtualization {
+                    containerizing_service_id: runtime.id,
+                    host_id: host.id,
+                },
+                label: None,
+                source_handle: EdgeHandle::Bottom,
+                target_handle: EdgeHandle::Top,
+                is_multi_hop: false,
+            }));
+        }
+
+        let (positions, host_size) = PlannerUtils::calculate_container_size(rows, &NODE_PADDING);
+
+        nodes.extend(services.iter().filter_map(|service| {
+            Some(Node {
+                node_type: NodeType::ServiceNode {
+                    service_id: service.id,
+                    host_id: host.id,
+                    parent_id: Some(host.id),
+                },
+                id: service.id,
+                position: *positions.get(&service.id)?,
+                size,
+                header: None,
+            })
+        }));
+
+        host_size
+    }
+
+    /// Add an edge from the host to each subnet it has an interface on, labelled with the
+    /// interface. Returns the subnets in interface order.
+    fn create_subnet_edges(ctx: &TopologyContext, host: &Host, edges: &mut Vec<Edge>) -> Vec<Uuid> {
+        let mut subnet_ids = Vec::new();
+
+        for interface in ctx
+            .get_interfaces_for_host(host.id)
+            .into_iter()
+            .filter(|i| ctx.get_subnet_by_id(i.base.subnet_id).is_some())
+            .sorted_by_key(|i| (i.base.position, i.id))
+        {
+            if !subnet_ids.contains(&interface.base.subnet_id) {
+                subnet_ids.push(interface.base.subnet_id);
+            }
+
+            edges.push(Edge {
+                id: Uuid::new_v4(),
+                source: host.id,
+                target: interface.base.subnet_id,
+                edge_type: EdgeType::Interface { host_id: host.id },
+                label: Some(PlannerUtils::interface_label(interface)),
+                source_handle: EdgeHandle::Left,
+                target_handle: EdgeHandle::Right,
+                is_multi_hop: false,
+            });
+        }
+
+        subnet_ids
+    }
+
+    /// Add edges to the hosts this host is linked to: through a group binding on either end,
+    /// or as the hypervisor or a VM of this host. Returns those hosts ordered by name.
+    fn create_neighbour_edges<'a>(
+        ctx: &TopologyContext<'a>,
+        host: &Host,
+        edges: &mut Vec<Edge>,
+    ) -> Vec<&'a Host> {
+        let mut neighbours: HashMap<Uuid, &'a Host> = HashMap::new();
+        let mut link = |neighbour_id: Uuid, edge: Edge| {
+            if let Some(neighbour) = ctx.get_host_by_id(neighbour_id) {
+                neighbours.insert(neighbour.id, neighbour);
+                edges.push(edge);
+            }
+        };
+
+        for group in ctx.groups {
+            for (source_binding_id, target_binding_id) in EdgeBuilder::group_binding_pairs(group) {
+                let (Some(source), Some(target)) = (
+                    ctx.get_service_by_binding_id(source_binding_id),
+                    ctx.get_service_by_binding_id(target_binding_id),
+                ) else {
+                    continue;
+                };
+
+                // Service nodes stand in on this host's side, host nodes on the other
+                let (source_node, target_node, neighbour_id) = match (
+                    source.base.host_id == host.id,
+                    target.base.host_id == host.id,
+                ) {
+                    (true, false) => (source.id, target.base.host_id, target.base.host_id),
+                    (false, true) => (source.base.host_id, target.id, source.base.host_id),
+                    _ => continue,
+                };
+
+                link(
+                    neighbour_id,
+                    Edge {
+                        id: Uuid::new_v4(),
+                        source: source_node,
+                        target: target_node,
+                        edge_type: EdgeBuilder::group_edge_type(
+                            group,
+                            source_binding_id,
+                            target_binding_id,
+                        ),
+                        label: Some(group.base.name.to_string()),
+                        source_handle: EdgeHandle::Right,
+                        target_handle: EdgeHandle::Left,
+                        is_multi_hop: false,
+                    },
+                );
+            }
+        }
+
+        let vm_edge = |source: Uuid, target: Uuid, vm_service_id: Uuid| Edge {
+            id: Uuid::new_v4(),
+            source,
+            target,
+            edge_type: EdgeType::HostVirtualization { vm_service_id },
+            label: None,
+            source_handle: EdgeHandle::Right,
+            target_handle: EdgeHandle::Left,
+            is_multi_hop: false,
+        };
+
+        // The hypervisor running this host
+        if let Some(hypervisor) = ctx.get_host_is_virtualized_by(&host.id)
+            && hypervisor.base.host_id != host.id
+        {
+            link(
+                hypervisor.base.host_id,
+                vm_edge(hypervisor.base.host_id, host.id, hypervisor.id),
+            );
+        }
+
+        // VMs run by this host's services
+        for vm in ctx.hosts.iter().filter(|h| h.id != host.id) {
+            if let Some(hypervisor) = ctx.get_host_is_virtualized_by(&vm.id)
+                && hypervisor.base.host_id == host.id
+            {
+                link(vm.id, vm_edge(hypervisor.id, vm.id, hypervisor.id));
+            }
+        }
+
+        neighbours
+            .into_values()
+            .sorted_by_key(|h| (h.base.name.clone(), h.id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        hosts::r#impl::virtualization::{HostVirtualization, ProxmoxVirtualization},
+        topology::types::base::TopologyOptions,
+    };
+    use crate::tests;
+
+    #[test]
+    fn test_focus_without_host_is_empty() {
+        let options = TopologyOptions::default();
+        let ctx = TopologyContext::new(&[], &[], &[], &[], &[], &[], &[], &options);
+
+        let (nodes, edges) = HostFocusPlanner::create_nodes_and_edges(&ctx, None);
+        assert!(nodes.is_empty() && edges.is_empty());
+
+        let (nodes, _) = HostFocusPlanner::create_nodes_and_edges(&ctx, Some(Uuid::new_v4()));
+        assert!(nodes.is_empty());
+    }
+
+    #[test]
+    fn test_focus_places_services_inside_host_with_subnets_and_vms_around() {
+        let network_id = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+
+        let hypervisor = tests::host(&network_id);
+        let mut interface = tests::interface(&network_id, &subnet.id);
+        interface.base.host_id = hypervisor.id;
+        let services: Vec<_> = (0..4)
+            .map(|position| {
+                let mut service = tests::service(&network_id, &hypervisor.id);
+                service.base.position = position;
+                service
+            })
+            .collect();
+
+        let mut vm = tests::host(&network_id);
+        vm.base.virtualization = Some(HostVirtualization::Proxmox(ProxmoxVirtualization {
+            vm_name: None,
+            vm_id: None,
+            service_id: services[0].id,
+            node: None,
+            guest_type: None,
+            status: None,
+        }));
+
+        let hosts = vec![hypervisor.clone(), vm.clone()];
+        let interfaces = vec![interface.clone()];
+        let subnets = vec![subnet.clone()];
+        let options = TopologyOptions::default();
+        let ctx = TopologyContext::new(
+            &hosts,
+            &interfaces,
+            &subnets,
+            &services,
+            &[],
+            &[],
+            &[],
+            &options,
+        );
+
+        let (nodes, edges) = HostFocusPlanner::create_nodes_and_edges(&ctx, Some(hypervisor.id));
+
+        let node = |id: Uuid| nodes.iter().find(|n| n.id == id).unwrap();
+        let host_node = node(hypervisor.id);
+        assert!(
+            services
+                .iter()
+                .all(|s| node(s.id).parent_id() == Some(hypervisor.id))
+        );
+        // Four services make two rows
+        assert!(node(services[3].id).position.y > node(services[0].id).position.y);
+        assert!(node(subnet.id).position.x < host_node.position.x);
+        assert!(node(vm.id).position.x > host_node.position.x);
+
+        assert!(edges.iter().any(|e| e.source == hypervisor.id
+            && e.target == subnet.id
+            && e.label.as_deref() == Some("eth0: 192.168.1.100")));
+        assert!(edges.iter().any(|e| e.source == services[0].id
+            && e.target == vm.id
+            && matches!(e.edge_type, EdgeType::HostVirtualization { .. })));
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::planner::utils::{NODE_PADDING, SUBNET_PADDING},
+    types::layout::{Ixy, Uxy},
+};
+
+/// Number of barycenter passes (down then up) used to order nodes within layers
+const ORDERING_SWEEPS: usize = 4;
+
+/// Layered placement shared by the layout modes that don't nest nodes in subnets.
+///
+/// Nodes are given a layer, ordered within each layer to cut down on crossings, then laid
+/// out as centered rows. Every step breaks ties by the order nodes were passed in, so the
+/// same input always gives the same positions.
+pub struct LayeredLayout<'a> {
+    nodes: &'a [(Uuid, Uxy)],
+    links: Vec<(Uuid, Uuid)>,
+    index: HashMap<Uuid, usize>,
+}
+
+impl<'a> LayeredLayout<'a> {
+    /// `nodes` should already be in a stable order. Links between unknown nodes and self
+    /// links are dropped.
+    pub fn new(nodes: &'a [(Uuid, Uxy)], links: &[(Uuid, Uuid)]) -> Self {
+        let index: HashMap<Uuid, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, (id, _))| (*id, i))
+            .collect();
+
+        let mut seen = HashSet::new();
+        let links = links
+            .iter()
+            .filter(|(s, t)| s != t && index.contains_key(s) && index.contains_key(t))
+            .filter(|link| seen.insert(**link))
+            .copied()
+            .collect();
+
+        Self {
+            nodes,
+            links,
+            index,
+        }
+    }
+
+    /// Layer each node by the longest path leading to it, so every link points down.
+    /// Cycles are broken by ignoring links back to a node still being visited.
+    pub fn layer_by_longest_path(&self) -> HashMap<Uuid, usize> {
+        let successors = self.successors();
+
+        // Depth-first in input order to find the links that close a cycle
+        let mut back_links: HashSet<(Uuid, Uuid)> = HashSet::new();
+        let mut visited: HashSet<Uuid> = HashSet::new();
+        for (start, _) in self.nodes {
+            if visited.contains(start) {
+                continue;
+            }
+            let mut on_stack: HashSet<Uuid> = HashSet::from([*start]);
+            let mut stack: Vec<(Uuid, usize)> = vec![(*start, 0)];
+            visited.insert(*start);
+
+            while let Some((node, next)) = stack.last_mut() {
+                let node = *node;
+                match successors[&node].get(*next) {
+                    Some(&child) => {
+                        *next += 1;
+                        if on_stack.contains(&child) {
+                            back_links.insert((node, child));
+                        } else if visited.insert(child) {
+                            on_stack.insert(child);
+                            stack.push((child, 0));
+                        }
+                    }
+                    None => {
+                        on_stack.remove(&node);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        // Kahn's algorithm over the remaining links, releasing nodes in input order
+        let forward: Vec<(Uuid, Uuid)> = self
+            .links
+            .iter()
+            .filter(|link| !back_links.contains(link))
+            .copied()
+            .collect();
+
+        let mut in_degree: HashMap<Uuid, usize> =
+            self.nodes.iter().map(|(id, _)| (*id, 0)).collect();
+        for (_, target) in &forward {
+            *in_degree.entry(*target).or_default() += 1;
+        }
+
+        let mut layers: HashMap<Uuid, usize> = HashMap::new();
+        let mut ready: Vec<Uuid> = self
+            .nodes
+            .iter()
+            .filter(|(id, _)| in_degree[id] == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        ready.reverse();
+
+        while let Some(node) = ready.pop() {
+            let layer = *layers.entry(node).or_insert(0);
+            let mut released = Vec::new();
+            for (_, target) in forward.iter().filter(|(source, _)| *source == node) {
+                let target_layer = layers.entry(*target).or_insert(0);
+                *target_layer = (*target_layer).max(layer + 1);
+
+                let degree = in_degree.get_mut(target).expect("target is a known node");
+                *degree -= 1;
+                if *degree == 0 {
+                    released.push(*target);
+                }
+            }
+            released.sort_by_key(|id| std::cmp::Reverse(self.index[id]));
+            ready.extend(released);
+        }
+
+        layers
+    }
+
+    /// Layer each node by its distance from the nearest root, ignoring link direction.
+    /// Components are walked from the first root they contain, or from their first node if
+    /// they have none.
+    pub fn layer_by_distance(&self, roots: &[Uuid]) -> HashMap<Uuid, usize> {
+        let neighbours = self.neighbours();
+        let mut layers: HashMap<Uuid, usize> = HashMap::new();
+
+        let starts = roots
+            .iter()
+            .filter(|id| self.index.contains_key(id))
+            .chain(self.nodes.iter().map(|(id, _)| id));
+
+        for start in starts {
+            if layers.contains_key(start) {
+                continue;
+            }
+            layers.insert(*start, 0);
+            let mut queue = VecDeque::from([*start]);
+
+            while let Some(node) = queue.pop_front() {
+                let layer = layers[&node];
+                for next in &neighbours[&node] {
+                    if !layers.contains_key(next) {
+                        layers.insert(*next, layer + 1);
+                        queue.push_back(*next);
+                    }
+                }
+            }
+        }
+
+        layers
+    }
+
+    /// Positions for every node. Linked nodes are laid out in rows by layer; nodes with no
+    /// links are placed in a grid below them.
+    pub fn positions(&self, layers: &HashMap<Uuid, usize>) -> HashMap<Uuid, Ixy> {
+        let linked: HashSet<Uuid> = self.links.iter().flat_map(|(s, t)| [*s, *t]).collect();
+        let sizes: HashMap<Uuid, Uxy> = self.nodes.iter().copied().collect();
+
+        let mut rows: Vec<Vec<Uuid>> = Vec::new();
+        for (id, _) in self.nodes.iter().filter(|(id, _)| linked.contains(id)) {
+            let layer = layers.get(id).copied().unwrap_or(0);
+            if rows.len() <= layer {
+                rows.resize(layer + 1, Vec::new());
+            }
+            rows[layer].push(*id);
+        }
+        rows.retain(|row| !row.is_empty());
+        self.order_rows(&mut rows);
+
+        let isolated: Vec<Uuid> = self
+            .nodes
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| !linked.contains(id))
+            .collect();
+        if !isolated.is_empty() {
+            let columns = (isolated.len() as f64).sqrt().ceil() as usize;
+            rows.extend(isolated.chunks(columns).map(|chunk| chunk.to_vec()));
+        }
+
+        let row_width = |row: &Vec<Uuid>| -> usize {
+            row.iter().map(|id| sizes[id].x).sum::<usize>()
+                + NODE_PADDING.x * row.len().saturating_sub(1)
+        };
+        let max_width = rows.iter().map(row_width).max().unwrap_or(0);
+
+        let mut positions = HashMap::new();
+        let mut y = 0;
+        for row in &rows {
+            let mut x = ((max_width - row_width(row)) / 2) as isize;
+            let mut row_height = 0;
+            for id in row {
+                let size = sizes[id];
+                positions.insert(*id, Ixy { x, y });
+                x += (size.x + NODE_PADDING.x) as isize;
+                row_height = row_height.max(size.y);
+            }
+            y += (row_height + SUBNET_PADDING.y) as isize;
+        }
+
+        positions
+    }
+
+    /// Reorder each row by the average position of its neighbours in the row above (going
+    /// down) or below (going up). Nodes with no such neighbours keep their place.
+    fn order_rows(&self, rows: &mut [Vec<Uuid>]) {
+        let neighbours = self.neighbours();
+
+        let reorder = |row: &mut Vec<Uuid>, reference: &[Uuid]| {
+            let reference: HashMap<Uuid, usize> = reference
+                .iter()
+                .enumerate()
+                .map(|(i, id)| (*id, i))
+                .collect();
+
+            let mut keyed: Vec<(f64, usize, Uuid)> = row
+                .iter()
+                .enumerate()
+                .map(|(current, id)| {
+                    let placed: Vec<usize> = neighbours[id]
+                        .iter()
+                        .filter_map(|n| reference.get(n).copied())
+                        .collect();
+                    let barycenter = if placed.is_empty() {
+                        current as f64
+                    } else {
+                        placed.iter().sum::<usize>() as f64 / placed.len() as f64
+                    };
+                    (barycenter, self.index[id], *id)
+                })
+                .collect();
+
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            *row = keyed.into_iter().map(|(_, _, id)| id).collect();
+        };
+
+        for _ in 0..ORDERING_SWEEPS / 2 {
+            for i in 1..rows.len() {
+                let (above, below) = rows.split_at_mut(i);
+                reorder(&mut below[0], &above[i - 1]);
+            }
+            for i in (0..rows.len().saturating_sub(1)).rev() {
+                let (above, below) = rows.split_at_mut(i + 1);
+                reorder(&mut above[i], &below[0]);
+            }
+        }
+    }
+
+    fn successors(&self) -> HashMap<Uuid, Vec<Uuid>> {
+        let mut successors: HashMap<Uuid, Vec<Uuid>> =
+            self.nodes.iter().map(|(id, _)| (*id, Vec::new())).collect();
+        for (source, target) in &self.links {
+            successors.entry(*source).or_default().push(*target);
+        }
+        for targets in successors.values_mut() {
+            targets.sort_by_key(|id| self.index[id]);
+        }
+        successors
+    }
+
+    fn neighbours(&self) -> HashMap<Uuid, Vec<Uuid>> {
+        let mut neighbours: HashMap<Uuid, Vec<Uuid>> =
+            self.nodes.iter().map(|(id, _)| (*id, Vec::new())).collect();
+        for (source, target) in &self.links {
+            neighbours.entry(*source).or_default().push(*target);
+            neighbours.entry(*target).or_default().push(*source);
+        }
+        for adjacent in neighbours.values_mut() {
+            adjacent.sort_by_key(|id| self.index[id]);
+            adjacent.dedup();
+        }
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nodes(count: usize) -> Vec<(Uuid, Uxy)> {
+        (0..count)
+            .map(|_| (Uuid::new_v4(), Uxy { x: 100, y: 50 }))
+            .collect()
+    }
+
+    #[test]
+    fn test_longest_path_layers_break_cycles() {
+        let nodes = nodes(4);
+        let [a, b, c, d] = [nodes[0].0, nodes[1].0, nodes[2].0, nodes[3].0];
+        // a -> b -> c -> a is a cycle, a -> c also makes c two layers below a
+        let layout = LayeredLayout::new(&nodes, &[(a, b), (b, c), (c, a), (a, c), (c, d)]);
+
+        let layers = layout.layer_by_longest_path();
+        assert_eq!(layers[&a], 0);
+        assert_eq!(layers[&b], 1);
+        assert_eq!(layers[&c], 2);
+        assert_eq!(layers[&d], 3);
+    }
+
+    #[test]
+    fn test_distance_layers_start_from_roots() {
+        let nodes = nodes(4);
+        let [a, b, c, d] = [nodes[0].0, nodes[1].0, nodes[2].0, nodes[3].0];
+        let layout = LayeredLayout::new(&nodes, &[(a, b), (b, c)]);
+
+        let layers = layout.layer_by_distance(&[c]);
+        assert_eq!(layers[&c], 0);
+        assert_eq!(layers[&b], 1);
+        assert_eq!(layers[&a], 2);
+        assert_eq!(layers[&d], 0);
+    }
+
+    #[test]
+    fn test_positions_are_deterministic_and_isolated_nodes_go_below() {
+        let nodes = nodes(5);
+        let links = [(nodes[0].0, nodes[1].0), (nodes[0].0, nodes[2].0)];
+        let layout = LayeredLayout::new(&nodes, &links);
+        let layers = layout.layer_by_longest_path();
+
+        let positions = layout.positions(&layers);
+        assert_eq!(positions, layout.positions(&layers));
+        assert_eq!(positions.len(), 5);
+
+        let linked_bottom = positions[&nodes[1].0].y;
+        assert!(positions[&nodes[3].0].y > linked_bottom);
+        assert!(positions[&nodes[4].0].y > linked_bottom);
+        assert!(positions[&nodes[1].0].x < positions[&nodes[2].0].x);
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::planner::utils::SUBNET_PADDING,
+    types::{
+        layout::{Ixy, NodeBounds, Uxy},
+        nodes::{Node, NodeType},
+    },
+};
+
+/// Share of subnets that may be added, removed or changed before a rebuild gives up on the
+/// previous layout and lays everything out from scratch
+pub const MAX_CHANGED_SUBNET_RATIO: f64 = 0.25;
+
+/// What a subnet holds, compared between builds to tell whether it changed
+type SubnetContents<'a> = Vec<(Uuid, &'a NodeType, Uxy, Option<&'a String>)>;
+
+/// Reuses the previous subnet layout so a rebuild can skip the optimizer.
+///
+/// Every subnet and child is still planned from scratch; this only replaces the optimizer
+/// pass, which is where most of the build time goes on large networks. Subnets holding the
+/// same children as last time are kept exactly as they were, including anything the user
+/// moved or resized. Subnets whose children changed take their freshly planned contents but
+/// stay where they were, and new subnets are added at the end of the row they were planned in.
+/// Unchanged subnets never move; changed and new ones are pushed right or down until they are
+/// clear of everything else.
+pub struct PreviousSubnetLayout<'a> {
+    old_subnets: HashMap<Uuid, &'a Node>,
+    old_children: HashMap<Uuid, Vec<&'a Node>>,
+}
+
+impl<'a> PreviousSubnetLayout<'a> {
+    /// Returns `None` when there is no previous subnet layout to build on, e.g. on the first
+    /// build or after switching from another layout mode.
+    pub fn new(old_nodes: &'a [Node]) -> Option<Self> {
+        let mut old_subnets = HashMap::new();
+        let mut old_children: HashMap<Uuid, Vec<&Node>> = HashMap::new();
+
+        for node in old_nodes {
+            match node.node_type {
+                NodeType::SubnetNode { .. } => {
+                    old_subnets.insert(node.id, node);
+                }
+                NodeType::InterfaceNode { subnet_id, .. } => {
+                    old_children.entry(subnet_id).or_default().push(node);
+                }
+                // Placed again after every build
+                NodeType::StubNode { .. } => {}
+                _ => return None,
+            }
+        }
+
+        if old_subnets.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            old_subnets,
+            old_children,
+        })
+    }
+
+    /// Merge freshly planned subnets and children into the previous layout. Returns `None` when
+    /// too much changed for the previous layout to be worth keeping, in which case the caller
+    /// should run the full layout.
+    pub fn apply(&self, subnet_nodes: &[Node], child_nodes: &[Node]) -> Option<Vec<Node>> {
+        let mut planned_children: HashMap<Uuid, Vec<&Node>> = HashMap::new();
+        for child in child_nodes {
+            if let Some(subnet_id) = child.parent_id() {
+                planned_children.entry(subnet_id).or_default().push(child);
+            }
+        }
+
+        let unchanged: HashSet<Uuid> = subnet_nodes
+            .iter()
+            .filter(|subnet| {
+                self.old_subnets.contains_key(&subnet.id)
+                    && Self::contents(self.old_children.get(&subnet.id))
+                        == Self::contents(planned_children.get(&subnet.id))
+            })
+            .map(|subnet| subnet.id)
+            .collect();
+
+        let planned_ids: HashSet<Uuid> = subnet_nodes.iter().map(|s| s.id).collect();
+        let removed = self
+            .old_subnets
+            .keys()
+            .filter(|id| !planned_ids.contains(id))
+            .count();
+        let changed = subnet_nodes.len() - unchanged.len() + removed;
+        let total = subnet_nodes.len().max(self.old_subnets.len());
+
+        if changed as f64 > total as f64 * MAX_CHANGED_SUBNET_RATIO {
+            tracing::debug!(
+                changed,
+                total,
+                "Too many subnets changed, falling back to full layout"
+            );
+            return None;
+        }
+
+        let mut subnets: Vec<Node> = Vec::with_capacity(subnet_nodes.len());
+        let mut children: Vec<Node> = Vec::with_capacity(child_nodes.len());
+
+        for subnet in subnet_nodes {
+            let old = self.old_subnets.get(&subnet.id);
+
+            if unchanged.contains(&subnet.id) {
+                // Unchanged subnets always have a previous node, but a full layout beats a panic
+                let old = old?;
+                subnets.push(Node {
+                    header: subnet.header.clone(),
+                    ..(*old).clone()
+                });
+                children.extend(
+                    self.old_children
+                        .get(&subnet.id)
+                        .into_iter()
+                        .flatten()
+                        .map(|n| (*n).clone()),
+                );
+            } else {
+                let mut node = subnet.clone();
+                if let Some(old) = old {
+                    node.position = old.position;
+                }
+                subnets.push(node);
+                children.extend(
+                    planned_children
+                        .get(&subnet.id)
+                        .into_iter()
+                        .flatten()
+                        .map(|n| (*n).clone()),
+                );
+            }
+        }
+
+        let new_ids: HashSet<Uuid> = subnet_nodes
+            .iter()
+            .filter(|s| !self.old_subnets.contains_key(&s.id))
+            .map(|s| s.id)
+            .collect();
+        Self::place_new_subnets(&mut subnets, subnet_nodes, &new_ids);
+        Self::clear_overlaps(&mut subnets, &unchanged);
+
+        Some(subnets.into_iter().chain(children).collect())
+    }
+
+    fn contents<'n>(children: Option<&Vec<&'n Node>>) -> SubnetContents<'n> {
+        let mut contents: SubnetContents = children
+            .into_iter()
+            .flatten()
+            .map(|n| (n.id, &n.node_type, n.size, n.header.as_ref()))
+            .collect();
+        contents.sort_by_key(|(id, ..)| *id);
+        contents
+    }
+
+    /// Put each new subnet at the end of the row it was planned in, next to the subnets it
+    /// shares that row with. Subnets with nobody else in their row start a row below
+    /// everything else.
+    fn place_new_subnets(subnets: &mut [Node], planned: &[Node], new_ids: &HashSet<Uuid>) {
+        let planned_y: HashMap<Uuid, isize> =
+            planned.iter().map(|n| (n.id, n.position.y)).collect();
+
+        let mut order: Vec<usize> = (0..subnets.len())
+            .filter(|&i| new_ids.contains(&subnets[i].id))
+            .collect();
+        order.sort_by_key(|&i| (subnets[i].position.y, subnets[i].position.x, subnets[i].id));
+
+        let mut placed: HashSet<Uuid> = subnets
+            .iter()
+            .map(|n| n.id)
+            .filter(|id| !new_ids.contains(id))
+            .collect();
+
+        for i in order {
+            let row = planned_y[&subnets[i].id];
+            let row_end = subnets
+                .iter()
+                .filter(|n| placed.contains(&n.id) && planned_y.get(&n.id) == Some(&row))
+                .map(|n| NodeBounds::new(n.position, n.size))
+                .max_by_key(|b| (b.right(), b.y));
+
+            let placed_bounds = || {
+                subnets
+                    .iter()
+                    .filter(|n| placed.contains(&n.id))
+                    .map(|n| NodeBounds::new(n.position, n.size))
+            };
+
+            let position = match row_end {
+                Some(end) => Ixy {
+                    x: end.right() + SUBNET_PADDING.x as isize,
+                    y: end.y,
+                },
+                None => Ixy {
+                    x: placed_bounds().map(|b| b.x).min().unwrap_or(0),
+                    y: placed_bounds()
+                        .map(|b| b.bottom() + SUBNET_PADDING.y as isize)
+                        .max()
+                        .unwrap_or(0),
+                },
+            };
+
+            subnets[i].position = position;
+            placed.insert(subnets[i].id);
+        }
+    }
+
+    /// Push changed and new subnets right or down, whichever is the shorter move, until they
+    /// no longer overlap anything settled before them. Unchanged subnets are settled first
+    /// and stay put.
+    fn clear_overlaps(subnets: &mut [Node], unchanged: &HashSet<Uuid>) {
+        let mut settled: Vec<NodeBounds> = subnets
+            .iter()
+            .filter(|n| unchanged.contains(&n.id))
+            .map(|n| NodeBounds::new(n.position, n.size))
+            .collect();
+
+        let mut order: Vec<usize> = (0..subnets.len())
+            .filter(|&i| !unchanged.contains(&subnets[i].id))
+            .collect();
+        order.sort_by_key(|&i| (subnets[i].position.y, subnets[i].position.x, subnets[i].id));
+
+        for i in order {
+            let node = &mut subnets[i];
+            while let Some(other) = settled
+                .iter()
+                .find(|other| NodeBounds::new(node.position, node.size).overlaps(other))
+            {
+                let right = other.right() + SUBNET_PADDING.x as isize - node.position.x;
+                let down = other.bottom() + SUBNET_PADDING.y as isize - node.position.y;
+                if right < down {
+                    node.position.x += right;
+                } else {
+                    node.position.y += down;
+                }
+            }
+            settled.push(NodeBounds::new(node.position, node.size));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subnet(x: isize, y: isize) -> Node {
+        Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: Uuid::new_v4(),
+            position: Ixy { x, y },
+            size: Uxy { x: 400, y: 300 },
+            header: None,
+        }
+    }
+
+    fn child(subnet: &Node, y: isize) -> Node {
+        Node {
+            node_type: NodeType::InterfaceNode {
+                subnet_id: subnet.id,
+                host_id: Uuid::new_v4(),
+                interface_id: Some(Uuid::new_v4()),
+                is_infra: false,
+            },
+            id: Uuid::new_v4(),
+            position: Ixy { x: 50, y },
+            size: Uxy { x: 250, y: 100 },
+            header: None,
+        }
+    }
+
+    fn find(nodes: &[Node], id: Uuid) -> &Node {
+        nodes.iter().find(|n| n.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_unchanged_subnets_keep_previous_layout() {
+        let planned_subnets: Vec<Node> = (0..5).map(|i| subnet(i * 525, 125)).collect();
+        let planned_children: Vec<Node> = planned_subnets.iter().map(|s| child(s, 50)).collect();
+
+        // The user moved every subnet and child since the last build
+        let mut old_nodes: Vec<Node> = planned_subnets
+            .iter()
+            .chain(&planned_children)
+            .cloned()
+            .collect();
+        for node in &mut old_nodes {
+            node.position.y += 1000;
+        }
+
+        // One more host shows up in the first subnet, which grows to fit it
+        let mut subnets = planned_subnets.clone();
+        subnets[0].size.y += 150;
+        let mut children = planned_children.clone();
+        children.push(child(&subnets[0], 200));
+
+        let layout = PreviousSubnetLayout::new(&old_nodes).unwrap();
+        let nodes = layout.apply(&subnets, &children).unwrap();
+
+        assert_eq!(nodes.len(), 11);
+        for i in 1..5 {
+            assert_eq!(find(&nodes, subnets[i].id).position.y, 1125);
+            assert_eq!(find(&nodes, children[i].id).position.y, 1050);
+        }
+
+        // Changed subnet stays where it was but takes its new size and children
+        let changed = find(&nodes, subnets[0].id);
+        assert_eq!(changed.position, old_nodes[0].position);
+        assert_eq!(changed.size.y, 450);
+        assert_eq!(find(&nodes, children[0].id).position.y, 50);
+        assert_eq!(find(&nodes, children[5].id).position.y, 200);
+    }
+
+    #[test]
+    fn test_new_subnet_joins_its_row_clear_of_kept_ones() {
+        let planned_subnets: Vec<Node> = (0..4).map(|i| subnet(i * 525, 125)).collect();
+        let planned_children: Vec<Node> = planned_subnets.iter().map(|s| child(s, 50)).collect();
+        let old_nodes: Vec<Node> = planned_subnets
+            .iter()
+            .chain(&planned_children)
+            .cloned()
+            .collect();
+
+        // A new subnet is planned into the same row, on top of an existing one
+        let mut subnets = planned_subnets.clone();
+        subnets.push(subnet(525, 125));
+        let mut children = planned_children.clone();
+        children.push(child(&subnets[4], 50));
+
+        let layout = PreviousSubnetLayout::new(&old_nodes).unwrap();
+        let nodes = layout.apply(&subnets, &children).unwrap();
+
+        for subnet in &planned_subnets {
+            assert_eq!(find(&nodes, subnet.id).position, subnet.position);
+        }
+        let new = find(&nodes, subnets[4].id);
+        assert_eq!(new.position.y, 125);
+        assert_eq!(new.position.x, 3 * 525 + 400 + SUBNET_PADDING.x as isize);
+    }
+
+    #[test]
+    fn test_large_changes_fall_back_to_full_layout() {
+        let planned_subnets: Vec<Node> = (0..4).map(|i| subnet(i * 525, 125)).collect();
+        let planned_children: Vec<Node> = planned_subnets.iter().map(|s| child(s, 50)).collect();
+        let old_nodes: Vec<Node> = planned_subnets
+            .iter()
+            .chain(&planned_children)
+            .cloned()
+            .collect();
+
+        // Two of four subnets gain a child
+        let mut children = planned_children.clone();
+        children.push(child(&planned_subnets[0], 200));
+        children.push(child(&planned_subnets[1], 200));
+
+        let layout = PreviousSubnetLayout::new(&old_nodes).unwrap();
+        assert!(layout.apply(&planned_subnets, &children).is_none());
+
+        // Nodes from another layout mode can't be built on
+        let host_node = Node {
+            node_type: NodeType::HostNode {
+                host_id: Uuid::new_v4(),
+            },
+            ..subnet(0, 0)
+        };
+        assert!(PreviousSubnetLayout::new(&[host_node]).is_none());
+        assert!(PreviousSubnetLayout::new(&[]).is_none());
+    }
+}
@@ -1,4 +1,12 @@
 pub mod anchor_planner;
 pub mod child_planner;
+pub mod host_focus_planner;
+pub mod layered;
+pub mod layout_reuse;
+pub mod organization_planner;
+pub mod route_planner;
+pub mod routed_layout_planner;
+pub mod service_graph_planner;
+pub mod stub_planner;
 pub mod subnet_layout_planner;
 pub mod utils;
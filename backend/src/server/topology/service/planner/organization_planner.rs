@@ -0,0 +1,482 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use itertools::Itertools;
+use uuid::Uuid;
+
+use crate::server::{
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    network_links::r#impl::base::NetworkLink,
+    networks::r#impl::Network,
+    subnets::r#impl::{base::Subnet, types::SubnetType},
+    topology::{
+        service::planner::utils::{
+            COLLAPSED_SUBNET_SIZE, NETWORK_MEMBER_SIZE, NODE_PADDING, PlannerUtils, SUBNET_PADDING,
+        },
+        types::{
+            edges::{Edge, EdgeHandle, EdgeType},
+            layout::{Ixy, Uxy},
+            nodes::{Node, NodeType},
+        },
+    },
+};
+
+/// Members placed side by side in a network before starting a new row
+const MEMBER_COLUMNS: usize = 3;
+
+pub struct OrganizationGraphParams<'a> {
+    pub networks: &'a [Network],
+    pub hosts: &'a [Host],
+    pub interfaces: &'a [Interface],
+    pub subnets: &'a [Subnet],
+    pub links: &'a [NetworkLink],
+}
+
+/// Lays out the organization topology: every network as a container, holding the subnets and
+/// hosts it shares with other networks. Networks are connected through remote and VPN subnets
+/// with the same CIDR, hosts with the same MAC address or hostname, and links users add.
+pub struct OrganizationPlanner;
+
+impl OrganizationPlanner {
+    pub fn create_nodes_and_edges(params: &OrganizationGraphParams) -> (Vec<Node>, Vec<Edge>) {
+        let networks: Vec<&Network> = params
+            .networks
+            .iter()
+            .sorted_by_key(|n| (n.base.name.clone(), n.id))
+            .collect();
+        let network_ids: HashSet<Uuid> = networks.iter().map(|n| n.id).collect();
+
+        let mut edges = Vec::new();
+        let shared_subnets = Self::shared_subnets(params, &network_ids, &mut edges);
+        let shared_hosts = Self::shared_hosts(params, &network_ids, &mut edges);
+
+        for link in params.links {
+            if !network_ids.contains(&link.base.network_id)
+                || !network_ids.contains(&link.base.target_network_id)
+            {
+                continue;
+            }
+
+            edges.push(Self::edge(
+                link.base.network_id,
+                link.base.target_network_id,
+                EdgeType::NetworkLink {
+                    network_link_id: link.id,
+                },
+                link.base.label.clone(),
+            ));
+        }
+
+        let mut nodes = Vec::new();
+        let columns = (networks.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let mut y = 0;
+
+        for row in networks.chunks(columns) {
+            let mut x = 0;
+            let mut row_height = 0;
+
+            for network in row {
+                let members: Vec<(NodeType, Uuid, String)> = shared_subnets
+                    .iter()
+                    .filter(|s| s.base.network_id == network.id)
+                    .map(|s| {
+                        (
+                            NodeType::NetworkSubnetNode {
+                                network_id: network.id,
+                                subnet_id: s.id,
+                            },
+                            s.id,
+                            s.base.name.clone(),
+                        )
+                    })
+                    .chain(
+                        shared_hosts
+                            .iter()
+                            .filter(|h| h.base.network_id == network.id)
+                            .map(|h| {
+                                (
+                                    NodeType::NetworkHostNode {
+                                        network_id: network.id,
+                                        host_id: h.id,
+                                    },
+                                    h.id,
+                                    h.base.name.clone(),
+                                )
+                            }),
+                    )
+                    .collect();
+
+                let size = Self::network_size(members.len());
+
+                nodes.push(Node {
+                    node_type: NodeType::NetworkNode {
+                        network_id: network.id,
+                    },
+                    id: network.id,
+                    position: Ixy { x, y },
+                    size,
+                    header: Some(network.base.name.clone()),
+                });
+
+                for (i, (node_type, id, header)) in members.into_iter().enumerate() {
+                    let (column, row) = (i % MEMBER_COLUMNS, i / MEMBER_COLUMNS);
+                    nodes.push(Node {
+                        node_type,
+                        id,
+                        position: Ixy {
+                            x: (NODE_PADDING.x + column * (NETWORK_MEMBER_SIZE.x + NODE_PADDING.x))
+                                as isize,
+                            y: (NODE_PADDING.y + row * (NETWORK_MEMBER_SIZE.y + NODE_PADDING.y))
+                                as isize,
+                        },
+                        size: NETWORK_MEMBER_SIZE,
+                        header: Some(header),
+                    });
+                }
+
+                x += (size.x + SUBNET_PADDING.x) as isize;
+                row_height = row_height.max(size.y);
+            }
+
+            y += (row_height + SUBNET_PADDING.y) as isize;
+        }
+
+        PlannerUtils::assign_edge_handles(&nodes, &mut edges);
+
+        (nodes, edges)
+    }
+
+    /// Remote and VPN subnets whose CIDR turns up in more than one network. Adds an edge
+    /// between every pair of them in different networks.
+    fn shared_subnets<'a>(
+        params: &OrganizationGraphParams<'a>,
+        network_ids: &HashSet<Uuid>,
+        edges: &mut Vec<Edge>,
+    ) -> Vec<&'a Subnet> {
+        let mut by_cidr: BTreeMap<String, Vec<&Subnet>> = BTreeMap::new();
+        for subnet in params.subnets.iter().filter(|s| {
+            network_ids.contains(&s.base.network_id)
+                && matches!(
+                    s.base.subnet_type,
+                    SubnetType::Remote | SubnetType::VpnTunnel
+                )
+        }) {
+            by_cidr
+                .entry(subnet.base.cidr.to_string())
+                .or_default()
+                .push(subnet);
+        }
+
+        let mut shared = Vec::new();
+        for (cidr, subnets) in by_cidr {
+            let pairs: Vec<(&Subnet, &Subnet)> = subnets
+                .iter()
+                .tuple_combinations()
+                .filter(|(a, b)| a.base.network_id != b.base.network_id)
+                .map(|(a, b)| (*a, *b))
+                .collect();
+            if pairs.is_empty() {
+                continue;
+            }
+
+            for (a, b) in pairs {
+                edges.push(Self::edge(
+                    a.id,
+                    b.id,
+                    EdgeType::SharedSubnet {
+                        source_subnet_id: a.id,
+                        target_subnet_id: b.id,
+                    },
+                    Some(cidr.clone()),
+                ));
+            }
+            shared.extend(subnets);
+        }
+
+        shared
+    }
+
+    /// Hosts that share a MAC address or hostname with a host in another network. Adds one
+    /// edge per pair of hosts, labelled with the first thing they were matched on.
+    fn shared_hosts<'a>(
+        params: &OrganizationGraphParams<'a>,
+        network_ids: &HashSet<Uuid>,
+        edges: &mut Vec<Edge>,
+    ) -> Vec<&'a Host> {
+        let hosts: HashMap<Uuid, &Host> = params
+            .hosts
+            .iter()
+            .filter(|h| !h.base.hidden && network_ids.contains(&h.base.network_id))
+            .map(|h| (h.id, h))
+            .collect();
+
+        let mut by_key: BTreeMap<String, Vec<&Host>> = BTreeMap::new();
+        for interface in params.interfaces {
+            if let (Some(mac), Some(&host)) = (
+                interface.base.mac_address,
+                hosts.get(&interface.base.host_id),
+            ) {
+                by_key.entry(mac.to_string()).or_default().push(host);
+            }
+        }
+        for &host in hosts.values() {
+            if let Some(hostname) = host
+                .base
+                .hostname
+                .as_deref()
+                .map(|h| h.trim().to_lowercase())
+                .filter(|h| !h.is_empty())
+            {
+                by_key.entry(hostname).or_default().push(host);
+            }
+        }
+
+        let mut matched: HashSet<(Uuid, Uuid)> = HashSet::new();
+        let mut shared: Vec<&Host> = Vec::new();
+
+        for (key, matches) in by_key {
+            let matches: Vec<&Host> = matches
+                .into_iter()
+                .unique_by(|h| h.id)
+                .sorted_by_key(|h| (h.base.name.clone(), h.id))
+                .collect();
+
+            for (a, b) in matches.into_iter().tuple_combinations() {
+                if a.base.network_id == b.base.network_id || !matched.insert((a.id, b.id)) {
+                    continue;
+                }
+
+                edges.push(Self::edge(
+                    a.id,
+                    b.id,
+                    EdgeType::SharedHost {
+                        source_host_id: a.id,
+                        target_host_id: b.id,
+                    },
+                    Some(key.clone()),
+                ));
+                shared.extend([a, b]);
+            }
+        }
+
+        shared
+            .into_iter()
+            .unique_by(|h| h.id)
+            .sorted_by_key(|h| (h.base.name.clone(), h.id))
+            .collect()
+    }
+
+    fn network_size(member_count: usize) -> Uxy {
+        if member_count == 0 {
+            return COLLAPSED_SUBNET_SIZE;
+        }
+
+        let columns = member_count.min(MEMBER_COLUMNS);
+        let rows = member_count.div_ceil(MEMBER_COLUMNS);
+        Uxy {
+            x: NODE_PADDING.x + columns * (NETWORK_MEMBER_SIZE.x + NODE_PADDING.x),
+            y: NODE_PADDING.y + rows * (NETWORK_MEMBER_SIZE.y + NODE_PADDING.y),
+        }
+    }
+
+    fn edge(source: Uuid, target: Uuid, edge_type: EdgeType, label: Option<String>) -> Edge {
+        Edge {
+            id: Uuid::new_v4(),
+            source,
+            target,
+            edge_type,
+            label,
+            source_handle: EdgeHandle::Right,
+            target_handle: EdgeHandle::Left,
+            is_multi_hop: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::network_links::r#impl::base::NetworkLinkBase;
+    use crate::server::shared::storage::traits::StorableEntity;
+    use crate::tests;
+
+    fn remote_subnet(network_id: &Uuid) -> Subnet {
+        let mut subnet = tests::subnet(network_id);
+        subnet.base.subnet_type = SubnetType::VpnTunnel;
+        subnet
+    }
+
+    fn named_host(network_id: &Uuid, name: &str) -> Host {
+        let mut host = tests::host(network_id);
+        host.base.name = name.to_string();
+        host.base.hostname = Some(format!("{}.local", name));
+        host
+    }
+
+    #[test]
+    fn test_networks_connect_through_shared_vpn_subnets() {
+        let organization_id = Uuid::new_v4();
+        let (office, cloud) = (
+            tests::network(&organization_id),
+            tests::network(&organization_id),
+        );
+
+        let office_vpn = remote_subnet(&office.id);
+        let cloud_vpn = remote_subnet(&cloud.id);
+        // Same CIDR, but a LAN in each network isn't evidence of a connection
+        let office_lan = tests::subnet(&office.id);
+        let cloud_lan = tests::subnet(&cloud.id);
+
+        let networks = [office.clone(), cloud.clone()];
+        let subnets = [office_vpn.clone(), cloud_vpn.clone(), office_lan, cloud_lan];
+        let (nodes, edges) =
+            OrganizationPlanner::create_nodes_and_edges(&OrganizationGraphParams {
+                networks: &networks,
+                hosts: &[],
+                interfaces: &[],
+                subnets: &subnets,
+                links: &[],
+            });
+
+        assert_eq!(edges.len(), 1);
+        assert!(matches!(edges[0].edge_type, EdgeType::SharedSubnet { .. }));
+        assert_eq!(
+            HashSet::from([edges[0].source, edges[0].target]),
+            HashSet::from([office_vpn.id, cloud_vpn.id])
+        );
+
+        let vpn_node = nodes.iter().find(|n| n.id == office_vpn.id).unwrap();
+        assert_eq!(vpn_node.parent_id(), Some(office.id));
+        assert_eq!(nodes.len(), 4);
+    }
+
+    #[test]
+    fn test_hosts_match_across_networks_by_mac_or_hostname() {
+        let organization_id = Uuid::new_v4();
+        let (site_a, site_b) = (
+            tests::network(&organization_id),
+            tests::network(&organization_id),
+        );
+        let subnet_a = tests::subnet(&site_a.id);
+        let subnet_b = tests::subnet(&site_b.id);
+
+        // Same hardware seen by both daemons
+        let router_a = named_host(&site_a.id, "router");
+        let mut router_b = named_host(&site_b.id, "edge");
+        router_b.base.hostname = None;
+        let mut interface_a = tests::interface(&site_a.id, &subnet_a.id);
+        interface_a.base.host_id = router_a.id;
+        let mut interface_b = tests::interface(&site_b.id, &subnet_b.id);
+        interface_b.base.host_id = router_b.id;
+        interface_b.base.mac_address = interface_a.base.mac_address;
+
+        // Same hostname, different case
+        let nas_a = named_host(&site_a.id, "nas");
+        let mut nas_b = named_host(&site_b.id, "storage");
+        nas_b.base.hostname = Some("NAS.local".to_string());
+
+        // Same hostname within one network only
+        let printer = named_host(&site_a.id, "printer");
+        let mut printer_copy = named_host(&site_a.id, "printer-2");
+        printer_copy.base.hostname = printer.base.hostname.clone();
+
+        let networks = [site_a.clone(), site_b.clone()];
+        let hosts = [
+            router_a.clone(),
+            router_b.clone(),
+            nas_a.clone(),
+            nas_b.clone(),
+            printer.clone(),
+            printer_copy.clone(),
+        ];
+        let interfaces = [interface_a, interface_b];
+        let (nodes, edges) =
+            OrganizationPlanner::create_nodes_and_edges(&OrganizationGraphParams {
+                networks: &networks,
+                hosts: &hosts,
+                interfaces: &interfaces,
+                subnets: &[],
+                links: &[],
+            });
+
+        let pairs: HashSet<(Uuid, Uuid)> = edges
+            .iter()
+            .map(|e| {
+                assert!(matches!(e.edge_type, EdgeType::SharedHost { .. }));
+                (e.source.min(e.target), e.source.max(e.target))
+            })
+            .collect();
+        assert_eq!(
+            pairs,
+            HashSet::from([
+                (router_a.id.min(router_b.id), router_a.id.max(router_b.id)),
+                (nas_a.id.min(nas_b.id), nas_a.id.max(nas_b.id)),
+            ])
+        );
+
+        assert!(
+            !nodes
+                .iter()
+                .any(|n| n.id == printer.id || n.id == printer_copy.id)
+        );
+        let nas_node = nodes.iter().find(|n| n.id == nas_b.id).unwrap();
+        assert_eq!(
+            nas_node.node_type,
+            NodeType::NetworkHostNode {
+                network_id: site_b.id,
+                host_id: nas_b.id
+            }
+        );
+    }
+
+    #[test]
+    fn test_links_need_both_networks() {
+        let organization_id = Uuid::new_v4();
+        let (a, b) = (
+            tests::network(&organization_id),
+            tests::network(&organization_id),
+        );
+        let hidden_network_id = Uuid::new_v4();
+
+        let link = NetworkLink::new(NetworkLinkBase {
+            network_id: a.id,
+            target_network_id: b.id,
+            label: Some("MPLS".to_string()),
+            description: None,
+        });
+        let inaccessible = NetworkLink::new(NetworkLinkBase {
+            network_id: a.id,
+            target_network_id: hidden_network_id,
+            label: None,
+            description: None,
+        });
+
+        let networks = [a.clone(), b.clone()];
+        let links = [link.clone(), inaccessible];
+        let (nodes, edges) =
+            OrganizationPlanner::create_nodes_and_edges(&OrganizationGraphParams {
+                networks: &networks,
+                hosts: &[],
+                interfaces: &[],
+                subnets: &[],
+                links: &links,
+            });
+
+        assert_eq!(nodes.len(), 2);
+        assert!(
+            nodes
+                .iter()
+                .all(|n| matches!(n.node_type, NodeType::NetworkNode { .. }))
+        );
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!((edges[0].source, edges[0].target), (a.id, b.id));
+        assert_eq!(edges[0].label, Some("MPLS".to_string()));
+        assert_eq!(
+            edges[0].edge_type,
+            EdgeType::NetworkLink {
+                network_link_id: link.id
+            }
+        );
+    }
+}
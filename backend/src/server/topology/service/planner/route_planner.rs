@@ -0,0 +1,356 @@
+use std::{collections::HashSet, net::IpAddr};
+
+use uuid::Uuid;
+
+use crate::server::{
+    subnets::r#impl::types::SubnetType,
+    topology::{
+        service::{context::TopologyContext, planner::utils::PlannerUtils},
+        types::{
+            edges::{Edge, EdgeHandle, EdgeType},
+            nodes::Node,
+        },
+    },
+    traceroutes::r#impl::base::Traceroute,
+};
+
+/// A node on a traced path and what was measured getting there
+struct RoutePoint {
+    node_id: Uuid,
+    rtt_us: Option<u32>,
+    /// Hops before this one answered from addresses that aren't in the topology
+    skipped_hops: bool,
+}
+
+/// Draws the layer 3 paths daemons measured with traceroute. Each hop that answered from an
+/// interface in the topology becomes a point on the path, which ends at the subnet that was
+/// traced. Edges are labelled with the round trip time to the hop they lead to.
+pub struct RoutePlanner;
+
+impl RoutePlanner {
+    pub fn add_route_edges(
+        ctx: &TopologyContext,
+        traceroutes: &[Traceroute],
+        nodes: &[Node],
+        edges: &mut Vec<Edge>,
+    ) {
+        let node_ids: HashSet<Uuid> = nodes.iter().map(|n| n.id).collect();
+        // Paths to different targets usually share their first few hops
+        let mut drawn: HashSet<(Uuid, Uuid)> = HashSet::new();
+        let mut route_edges: Vec<Edge> = Vec::new();
+
+        for traceroute in traceroutes {
+            let points = Self::route_points(ctx, &node_ids, nodes, traceroute);
+
+            for pair in points.windows(2) {
+                let (from, to) = (&pair[0], &pair[1]);
+                if !drawn.insert((from.node_id, to.node_id)) {
+                    continue;
+                }
+
+                route_edges.push(Edge {
+                    id: Uuid::new_v4(),
+                    source: from.node_id,
+                    target: to.node_id,
+                    edge_type: EdgeType::Route {
+                        traceroute_id: traceroute.id,
+                    },
+                    label: to.rtt_us.map(Self::format_rtt),
+                    source_handle: EdgeHandle::Bottom,
+                    target_handle: EdgeHandle::Top,
+                    is_multi_hop: to.skipped_hops,
+                });
+            }
+        }
+
+        PlannerUtils::assign_edge_handles(nodes, &mut route_edges);
+        edges.extend(route_edges);
+    }
+
+    fn route_points(
+        ctx: &TopologyContext,
+        node_ids: &HashSet<Uuid>,
+        nodes: &[Node],
+        traceroute: &Traceroute,
+    ) -> Vec<RoutePoint> {
+        let mut points: Vec<RoutePoint> = Vec::new();
+        let mut skipped_hops = false;
+
+        for hop in &traceroute.base.hops {
+            let Some(node_id) = hop.ip.and_then(|ip| Self::hop_node(ctx, node_ids, ip)) else {
+                // The target answering isn't a hop in between
+                skipped_hops |= hop.ip != Some(traceroute.base.target);
+                continue;
+            };
+
+            // A router can answer more than one TTL, e.g. when it rewrites the TTL itself
+            if points.last().is_some_and(|p| p.node_id == node_id) {
+                continue;
+            }
+
+            points.push(RoutePoint {
+                node_id,
+                rtt_us: hop.rtt_us,
+                skipped_hops,
+            });
+            skipped_hops = false;
+        }
+
+        let Some(subnet_id) = Self::destination(ctx, node_ids, traceroute) else {
+            return points;
+        };
+
+        // Nothing to draw when the path already ends inside the subnet, e.g. at its gateway
+        if points
+            .last()
+            .is_some_and(|p| ctx.get_node_subnet(p.node_id, nodes) == Some(subnet_id))
+        {
+            return points;
+        }
+
+        let rtt_us = traceroute
+            .base
+            .reached
+            .then(|| traceroute.base.hops.last().and_then(|h| h.rtt_us))
+            .flatten();
+
+        points.push(RoutePoint {
+            node_id: subnet_id,
+            rtt_us,
+            skipped_hops,
+        });
+
+        points
+    }
+
+    /// Node for the address a hop answered from: its interface where interfaces are nodes,
+    /// otherwise its host
+    fn hop_node(ctx: &TopologyContext, node_ids: &HashSet<Uuid>, ip: IpAddr) -> Option<Uuid> {
+        ctx.interfaces
+            .iter()
+            .filter(|i| i.base.ip_address == ip)
+            .flat_map(|i| [i.id, i.base.host_id])
+            .find(|id| node_ids.contains(id))
+    }
+
+    /// Subnet the trace was aimed at. Traces to external targets end at the most specific
+    /// subnet containing the target, or at the internet.
+    fn destination(
+        ctx: &TopologyContext,
+        node_ids: &HashSet<Uuid>,
+        traceroute: &Traceroute,
+    ) -> Option<Uuid> {
+        if let Some(subnet_id) = traceroute.base.subnet_id {
+            return node_ids.contains(&subnet_id).then_some(subnet_id);
+        }
+
+        let drawn_subnets = || ctx.subnets.iter().filter(|s| node_ids.contains(&s.id));
+
+        drawn_subnets()
+            .filter(|s| s.base.cidr.contains(&traceroute.base.target))
+            .max_by_key(|s| s.base.cidr.network_length())
+            .or_else(|| drawn_subnets().find(|s| s.base.subnet_type == SubnetType::Internet))
+            .map(|s| s.id)
+    }
+
+    fn format_rtt(rtt_us: u32) -> String {
+        format!("{:.1} ms", rtt_us as f64 / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use cidr::{IpCidr, Ipv4Cidr};
+
+    use super::*;
+    use crate::server::{
+        interfaces::r#impl::base::Interface,
+        shared::storage::traits::StorableEntity,
+        subnets::r#impl::base::Subnet,
+        topology::types::{
+            base::TopologyOptions,
+            layout::{Ixy, Uxy},
+            nodes::NodeType,
+        },
+        traceroutes::r#impl::base::{TracerouteBase, TracerouteHop},
+    };
+    use crate::tests;
+
+    fn ip(octets: [u8; 4]) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::from(octets))
+    }
+
+    fn hop(ttl: u8, ip: Option<IpAddr>, rtt_us: u32) -> TracerouteHop {
+        TracerouteHop {
+            ttl,
+            ip,
+            rtt_us: ip.map(|_| rtt_us),
+        }
+    }
+
+    fn subnet_node(subnet: &Subnet, y: isize) -> Node {
+        Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: subnet.id,
+            position: Ixy { x: 0, y },
+            size: Uxy { x: 600, y: 400 },
+            header: None,
+        }
+    }
+
+    fn interface_node(interface: &Interface) -> Node {
+        Node {
+            node_type: NodeType::InterfaceNode {
+                subnet_id: interface.base.subnet_id,
+                host_id: interface.base.host_id,
+                interface_id: Some(interface.id),
+                is_infra: true,
+            },
+            id: interface.id,
+            position: Ixy { x: 100, y: 100 },
+            size: Uxy { x: 250, y: 100 },
+            header: None,
+        }
+    }
+
+    #[test]
+    fn test_route_from_gateway_to_remote_subnet() {
+        let network_id = Uuid::new_v4();
+        let lan = tests::subnet(&network_id);
+        let mut remote = tests::subnet(&network_id);
+        remote.base.cidr = IpCidr::V4(Ipv4Cidr::new(Ipv4Addr::new(10, 20, 0, 0), 16).unwrap());
+
+        let router = tests::host(&network_id);
+        let mut gateway = tests::interface(&network_id, &lan.id);
+        gateway.base.host_id = router.id;
+        gateway.base.ip_address = ip([192, 168, 1, 1]);
+
+        let traceroute = Traceroute::new(TracerouteBase {
+            network_id,
+            subnet_id: Some(remote.id),
+            target: ip([10, 20, 0, 1]),
+            hops: vec![
+                hop(1, Some(ip([192, 168, 1, 1])), 800),
+                hop(2, None, 0),
+                hop(3, Some(ip([10, 20, 0, 1])), 12_400),
+            ],
+            reached: true,
+            ..Default::default()
+        });
+
+        let options = TopologyOptions::default();
+        let hosts = [router];
+        let interfaces = [gateway.clone()];
+        let subnets = [lan.clone(), remote.clone()];
+        let ctx = TopologyContext::new(&hosts, &interfaces, &subnets, &[], &[], &[], &[], &options);
+
+        let nodes = vec![
+            subnet_node(&lan, 0),
+            interface_node(&gateway),
+            subnet_node(&remote, 800),
+        ];
+        let mut edges = Vec::new();
+
+        RoutePlanner::add_route_edges(&ctx, std::slice::from_ref(&traceroute), &nodes, &mut edges);
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].source, gateway.id);
+        assert_eq!(edges[0].target, remote.id);
+        assert_eq!(edges[0].label, Some("12.4 ms".to_string()));
+        assert!(edges[0].is_multi_hop);
+        assert_eq!(
+            edges[0].edge_type,
+            EdgeType::Route {
+                traceroute_id: traceroute.id
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_route_to_directly_connected_subnet() {
+        let network_id = Uuid::new_v4();
+        let lan = tests::subnet(&network_id);
+
+        let router = tests::host(&network_id);
+        let mut gateway = tests::interface(&network_id, &lan.id);
+        gateway.base.host_id = router.id;
+        gateway.base.ip_address = ip([192, 168, 1, 1]);
+
+        // The gateway itself was the target, one hop away
+        let traceroute = Traceroute::new(TracerouteBase {
+            network_id,
+            subnet_id: Some(lan.id),
+            target: ip([192, 168, 1, 1]),
+            hops: vec![hop(1, Some(ip([192, 168, 1, 1])), 500)],
+            reached: true,
+            ..Default::default()
+        });
+
+        let options = TopologyOptions::default();
+        let hosts = [router];
+        let interfaces = [gateway.clone()];
+        let subnets = [lan.clone()];
+        let ctx = TopologyContext::new(&hosts, &interfaces, &subnets, &[], &[], &[], &[], &options);
+
+        let nodes = vec![subnet_node(&lan, 0), interface_node(&gateway)];
+        let mut edges = Vec::new();
+
+        RoutePlanner::add_route_edges(&ctx, &[traceroute], &nodes, &mut edges);
+
+        assert!(edges.is_empty());
+    }
+
+    #[test]
+    fn test_shared_hops_are_drawn_once() {
+        let network_id = Uuid::new_v4();
+        let lan = tests::subnet(&network_id);
+        let mut internet = tests::subnet(&network_id);
+        internet.base.cidr = IpCidr::V4(Ipv4Cidr::new(Ipv4Addr::UNSPECIFIED, 0).unwrap());
+        internet.base.subnet_type = SubnetType::Internet;
+
+        let router = tests::host(&network_id);
+        let mut gateway = tests::interface(&network_id, &lan.id);
+        gateway.base.host_id = router.id;
+        gateway.base.ip_address = ip([192, 168, 1, 1]);
+
+        let external = |target: [u8; 4]| {
+            Traceroute::new(TracerouteBase {
+                network_id,
+                target: ip(target),
+                hops: vec![
+                    hop(1, Some(ip([192, 168, 1, 1])), 700),
+                    hop(2, Some(ip(target)), 9_000),
+                ],
+                reached: true,
+                ..Default::default()
+            })
+        };
+
+        let options = TopologyOptions::default();
+        let hosts = [router];
+        let interfaces = [gateway.clone()];
+        let subnets = [lan.clone(), internet.clone()];
+        let ctx = TopologyContext::new(&hosts, &interfaces, &subnets, &[], &[], &[], &[], &options);
+
+        let nodes = vec![
+            subnet_node(&lan, 0),
+            interface_node(&gateway),
+            subnet_node(&internet, 800),
+        ];
+        let mut edges = Vec::new();
+
+        RoutePlanner::add_route_edges(
+            &ctx,
+            &[external([1, 1, 1, 1]), external([8, 8, 8, 8])],
+            &nodes,
+            &mut edges,
+        );
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].source, gateway.id);
+        assert_eq!(edges[0].target, internet.id);
+        assert!(!edges[0].is_multi_hop);
+    }
+}
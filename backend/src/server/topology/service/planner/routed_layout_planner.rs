@@ -0,0 +1,164 @@
+use itertools::Itertools;
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::{
+        context::TopologyContext,
+        planner::{
+            layered::LayeredLayout,
+            utils::{COLLAPSED_SUBNET_SIZE, PlannerUtils},
+        },
+    },
+    types::{
+        edges::{Edge, EdgeHandle, EdgeType},
+        layout::Uxy,
+        nodes::{Node, NodeType},
+    },
+};
+
+/// Lays out the routed view: routers (hosts running a gateway, core network or network
+/// security service) as nodes, each linked to the subnets it has an interface on. Subnets are
+/// layered outwards from the most external one in each connected part of the network.
+pub struct RoutedLayoutPlanner;
+
+impl RoutedLayoutPlanner {
+    pub fn create_nodes_and_edges(ctx: &TopologyContext) -> (Vec<Node>, Vec<Edge>) {
+        let subnets: Vec<_> = ctx
+            .subnets
+            .iter()
+            .filter(|s| !s.base.subnet_type.is_docker_bridge())
+            .sorted_by_key(|s| {
+                (
+                    s.base.subnet_type.vertical_order(),
+                    s.base.subnet_type.horizontal_order(),
+                    s.base.cidr.to_string(),
+                    s.id,
+                )
+            })
+            .collect();
+
+        let routers: Vec<_> = ctx
+            .hosts
+            .iter()
+            .filter(|h| ctx.host_is_router(h.id))
+            .sorted_by_key(|h| (h.base.name.clone(), h.id))
+            .collect();
+
+        let edges: Vec<Edge> = routers
+            .iter()
+            .flat_map(|router| {
+                ctx.get_interfaces_for_host(router.id)
+                    .into_iter()
+                    .filter(|i| subnets.iter().any(|s| s.id == i.base.subnet_id))
+                    .sorted_by_key(|i| (i.base.position, i.id))
+                    .map(|interface| Edge {
+                        id: Uuid::new_v4(),
+                        source: router.id,
+                        target: interface.base.subnet_id,
+                        edge_type: EdgeType::Interface { host_id: router.id },
+                        label: Some(PlannerUtils::interface_label(interface)),
+                        source_handle: EdgeHandle::Bottom,
+                        target_handle: EdgeHandle::Top,
+                        is_multi_hop: false,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let sizes: Vec<(Uuid, Uxy)> = subnets
+            .iter()
+            .map(|s| (s.id, COLLAPSED_SUBNET_SIZE))
+            .chain(
+                routers
+                    .iter()
+                    .map(|r| (r.id, Uxy::default_subnet_child_size())),
+            )
+            .collect();
+
+        let links: Vec<(Uuid, Uuid)> = edges.iter().map(|e| (e.source, e.target)).collect();
+        let layout = LayeredLayout::new(&sizes, &links);
+
+        // Subnets come first in `sizes`, so each part of the network starts from its most
+        // external subnet
+        let roots: Vec<Uuid> = subnets.iter().map(|s| s.id).collect();
+        let positions = layout.positions(&layout.layer_by_distance(&roots));
+
+        let subnet_nodes = subnets.iter().map(|subnet| Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: subnet.id,
+            position: positions[&subnet.id],
+            size: COLLAPSED_SUBNET_SIZE,
+            header: None,
+        });
+
+        let router_nodes = routers.iter().map(|router| Node {
+            node_type: NodeType::HostNode { host_id: router.id },
+            id: router.id,
+            position: positions[&router.id],
+            size: Uxy::default_subnet_child_size(),
+            header: None,
+        });
+
+        (subnet_nodes.chain(router_nodes).collect(), edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        services::definitions::ServiceDefinitionRegistry, subnets::r#impl::types::SubnetType,
+        topology::types::base::TopologyOptions,
+    };
+    use crate::tests;
+
+    #[test]
+    fn test_routers_link_subnets_they_have_interfaces_on() {
+        let network_id = Uuid::new_v4();
+        let mut wan = tests::subnet(&network_id);
+        wan.base.subnet_type = SubnetType::Internet;
+        let lan = tests::subnet(&network_id);
+
+        let router = tests::host(&network_id);
+        let mut gateway = tests::service(&network_id, &router.id);
+        gateway.base.service_definition =
+            ServiceDefinitionRegistry::find_by_id("Gateway").expect("Gateway definition");
+        let mut router_wan = tests::interface(&network_id, &wan.id);
+        router_wan.base.host_id = router.id;
+        let mut router_lan = tests::interface(&network_id, &lan.id);
+        router_lan.base.host_id = router.id;
+
+        // A plain host on the LAN is not drawn
+        let workstation = tests::host(&network_id);
+        let mut workstation_lan = tests::interface(&network_id, &lan.id);
+        workstation_lan.base.host_id = workstation.id;
+
+        let hosts = vec![router.clone(), workstation];
+        let interfaces = vec![router_wan, router_lan, workstation_lan];
+        let subnets = vec![lan.clone(), wan.clone()];
+        let services = vec![gateway];
+        let options = TopologyOptions::default();
+        let ctx = TopologyContext::new(
+            &hosts,
+            &interfaces,
+            &subnets,
+            &services,
+            &[],
+            &[],
+            &[],
+            &options,
+        );
+
+        let (nodes, edges) = RoutedLayoutPlanner::create_nodes_and_edges(&ctx);
+
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(edges.len(), 2);
+        assert!(edges.iter().all(|e| e.source == router.id
+            && matches!(e.edge_type, EdgeType::Interface { host_id } if host_id == router.id)));
+
+        // Internet subnet on top, router below it, LAN below the router
+        let y = |id: Uuid| nodes.iter().find(|n| n.id == id).unwrap().position.y;
+        assert!(y(wan.id) < y(router.id));
+        assert!(y(router.id) < y(lan.id));
+    }
+}
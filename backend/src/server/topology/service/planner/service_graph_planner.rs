@@ -0,0 +1,172 @@
+use itertools::Itertools;
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::{
+        context::TopologyContext, edge_builder::EdgeBuilder, planner::layered::LayeredLayout,
+    },
+    types::{
+        edges::{Edge, EdgeHandle},
+        layout::Uxy,
+        nodes::{Node, NodeType},
+    },
+};
+
+/// Lays out the service dependency view: one node per service, with hosts collapsed into the
+/// node header. Group edges run between the services they bind, and services are layered so
+/// each edge points down. Services outside any group are placed below.
+pub struct ServiceGraphPlanner;
+
+impl ServiceGraphPlanner {
+    pub fn create_nodes_and_edges(ctx: &TopologyContext) -> (Vec<Node>, Vec<Edge>) {
+        let host_name = |host_id: Uuid| {
+            ctx.get_host_by_id(host_id)
+                .map(|h| h.base.name.clone())
+                .unwrap_or_default()
+        };
+
+        let services: Vec<_> = ctx
+            .services
+            .iter()
+            .sorted_by_key(|s| {
+                (
+                    host_name(s.base.host_id),
+                    s.base.host_id,
+                    s.base.position,
+                    s.id,
+                )
+            })
+            .collect();
+
+        let edges: Vec<Edge> = ctx
+            .groups
+            .iter()
+            .flat_map(|group| {
+                EdgeBuilder::group_binding_pairs(group)
+                    .into_iter()
+                    .filter_map(|(source_binding_id, target_binding_id)| {
+                        let source = ctx.get_service_by_binding_id(source_binding_id)?;
+                        let target = ctx.get_service_by_binding_id(target_binding_id)?;
+                        if source.id == target.id {
+                            return None;
+                        }
+
+                        Some(Edge {
+                            id: Uuid::new_v4(),
+                            source: source.id,
+                            target: target.id,
+                            edge_type: EdgeBuilder::group_edge_type(
+                                group,
+                                source_binding_id,
+                                target_binding_id,
+                            ),
+                            label: Some(group.base.name.to_string()),
+                            source_handle: EdgeHandle::Bottom,
+                            target_handle: EdgeHandle::Top,
+                            is_multi_hop: false,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let size = Uxy::service_node_size(true);
+        let sizes: Vec<(Uuid, Uxy)> = services.iter().map(|s| (s.id, size)).collect();
+        let links: Vec<(Uuid, Uuid)> = edges.iter().map(|e| (e.source, e.target)).collect();
+        let layout = LayeredLayout::new(&sizes, &links);
+        let positions = layout.positions(&layout.layer_by_longest_path());
+
+        let nodes = services
+            .iter()
+            .map(|service| Node {
+                node_type: NodeType::ServiceNode {
+                    service_id: service.id,
+                    host_id: service.base.host_id,
+                    parent_id: None,
+                },
+                id: service.id,
+                position: positions[&service.id],
+                size,
+                header: Some(host_name(service.base.host_id)),
+            })
+            .collect();
+
+        (nodes, edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        bindings::r#impl::base::{Binding, BindingType},
+        groups::r#impl::types::GroupType,
+        topology::types::{base::TopologyOptions, edges::EdgeType},
+    };
+    use crate::tests;
+
+    #[test]
+    fn test_group_edges_join_services_across_hosts() {
+        let network_id = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+
+        let mut hosts = Vec::new();
+        let mut services = Vec::new();
+        for name in ["proxy", "app", "db"] {
+            let mut host = tests::host(&network_id);
+            host.base.name = name.to_string();
+            let mut interface = tests::interface(&network_id, &subnet.id);
+            interface.base.host_id = host.id;
+
+            let mut service = tests::service(&network_id, &host.id);
+            service.base.bindings = vec![Binding::new_serviceless(BindingType::Interface {
+                interface_id: interface.id,
+            })];
+            hosts.push(host);
+            services.push(service);
+        }
+        let idle = tests::service(&network_id, &hosts[0].id);
+        services.push(idle.clone());
+
+        let mut group = tests::group(&network_id);
+        group.base.group_type = GroupType::RequestPath;
+        group.base.binding_ids = services[..3]
+            .iter()
+            .map(|s| s.base.bindings[0].id())
+            .collect();
+
+        let groups = vec![group.clone()];
+        let subnets = vec![subnet];
+        let options = TopologyOptions::default();
+        let ctx = TopologyContext::new(
+            &hosts,
+            &[],
+            &subnets,
+            &services,
+            &groups,
+            &[],
+            &[],
+            &options,
+        );
+
+        let (nodes, edges) = ServiceGraphPlanner::create_nodes_and_edges(&ctx);
+
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(edges.len(), 2);
+        assert_eq!(
+            (edges[0].source, edges[0].target),
+            (services[0].id, services[1].id)
+        );
+        assert!(matches!(
+            edges[1].edge_type,
+            EdgeType::RequestPath { group_id, .. } if group_id == group.id
+        ));
+
+        let node = |id: Uuid| nodes.iter().find(|n| n.id == id).unwrap();
+        assert_eq!(node(services[1].id).header.as_deref(), Some("app"));
+        assert!(node(services[0].id).position.y < node(services[1].id).position.y);
+        assert!(node(services[1].id).position.y < node(services[2].id).position.y);
+        // Ungrouped services sit below the layered ones
+        assert!(node(idle.id).position.y > node(services[2].id).position.y);
+    }
+}
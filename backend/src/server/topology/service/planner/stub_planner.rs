@@ -0,0 +1,265 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::{
+        context::TopologyContext,
+        edge_builder::EdgeBuilder,
+        planner::utils::{NODE_PADDING, PlannerUtils, STUB_NODE_SIZE, SUBNET_PADDING},
+    },
+    types::{
+        edges::{Edge, EdgeHandle},
+        layout::Ixy,
+        nodes::{Node, NodeType},
+    },
+};
+
+/// Keeps group edges that leave a filtered topology visible. When one end of a group edge was
+/// filtered out, that end becomes a stub node in a row below the rest of the layout, placed
+/// under the node it connects to.
+pub struct StubPlanner;
+
+impl StubPlanner {
+    pub fn add_stubs(ctx: &TopologyContext, nodes: &mut Vec<Node>, edges: &mut Vec<Edge>) {
+        let node_ids: HashSet<Uuid> = nodes.iter().map(|n| n.id).collect();
+
+        // Stub id (the filtered out binding) -> node it first connects to
+        let mut anchors: Vec<(Uuid, Uuid)> = Vec::new();
+        let mut stub_edges: Vec<Edge> = Vec::new();
+
+        for group in ctx.groups {
+            for (source_binding_id, target_binding_id) in EdgeBuilder::group_binding_pairs(group) {
+                let source = Self::binding_node(ctx, &node_ids, source_binding_id);
+                let target = Self::binding_node(ctx, &node_ids, target_binding_id);
+
+                let (source, target, stub_id, anchor) = match (source, target) {
+                    (Some(source), None) if Self::is_filtered_out(ctx, target_binding_id) => {
+                        (source, target_binding_id, target_binding_id, source)
+                    }
+                    (None, Some(target)) if Self::is_filtered_out(ctx, source_binding_id) => {
+                        (source_binding_id, target, source_binding_id, target)
+                    }
+                    _ => continue,
+                };
+
+                if !anchors.iter().any(|(id, _)| *id == stub_id) {
+                    anchors.push((stub_id, anchor));
+                }
+
+                stub_edges.push(Edge {
+                    id: Uuid::new_v4(),
+                    source,
+                    target,
+                    edge_type: EdgeBuilder::group_edge_type(
+                        group,
+                        source_binding_id,
+                        target_binding_id,
+                    ),
+                    label: Some(group.base.name.to_string()),
+                    source_handle: EdgeHandle::Bottom,
+                    target_handle: EdgeHandle::Top,
+                    is_multi_hop: false,
+                });
+            }
+        }
+
+        if anchors.is_empty() {
+            return;
+        }
+
+        let stubs = Self::place_stubs(nodes, &anchors);
+        nodes.extend(stubs);
+
+        PlannerUtils::assign_edge_handles(nodes, &mut stub_edges);
+        edges.extend(stub_edges);
+    }
+
+    /// Node drawn for a binding: its interface where interfaces are nodes, otherwise its
+    /// service, otherwise its host
+    fn binding_node(
+        ctx: &TopologyContext,
+        node_ids: &HashSet<Uuid>,
+        binding_id: Uuid,
+    ) -> Option<Uuid> {
+        let service = ctx.get_service_by_binding_id(binding_id)?;
+        let interface_id = service
+            .get_binding(binding_id)
+            .and_then(|b| b.interface_id());
+
+        [interface_id, Some(service.id), Some(service.base.host_id)]
+            .into_iter()
+            .flatten()
+            .find(|id| node_ids.contains(id))
+    }
+
+    /// A binding that exists in the network but whose service isn't in the topology
+    fn is_filtered_out(ctx: &TopologyContext, binding_id: Uuid) -> bool {
+        ctx.get_service_by_binding_id(binding_id).is_none()
+            && ctx.bindings.iter().any(|b| b.id() == binding_id)
+    }
+
+    /// One row below every top-level node. Each stub sits under its anchor where it can,
+    /// shifted right past the stub before it.
+    fn place_stubs(nodes: &[Node], anchors: &[(Uuid, Uuid)]) -> Vec<Node> {
+        let by_id: HashMap<Uuid, &Node> = nodes.iter().map(|n| (n.id, n)).collect();
+        let anchor_x = |id: &Uuid| -> isize {
+            let Some(node) = by_id.get(id) else {
+                return 0;
+            };
+            let offset = node
+                .parent_id()
+                .and_then(|p| by_id.get(&p))
+                .map(|p| p.position.x)
+                .unwrap_or_default();
+            offset + node.position.x + node.size.x as isize / 2 - STUB_NODE_SIZE.x as isize / 2
+        };
+
+        let y = nodes
+            .iter()
+            .filter(|n| n.parent_id().is_none())
+            .map(|n| n.position.y + n.size.y as isize)
+            .max()
+            .unwrap_or_default()
+            + SUBNET_PADDING.y as isize;
+
+        let mut ordered: Vec<(Uuid, isize)> = anchors
+            .iter()
+            .map(|(stub_id, anchor)| (*stub_id, anchor_x(anchor)))
+            .collect();
+        ordered.sort_by_key(|(_, x)| *x);
+
+        let mut next_x = isize::MIN;
+        ordered
+            .into_iter()
+            .map(|(binding_id, x)| {
+                let x = x.max(next_x);
+                next_x = x + (STUB_NODE_SIZE.x + NODE_PADDING.x) as isize;
+                Node {
+                    node_type: NodeType::StubNode { binding_id },
+                    id: binding_id,
+                    position: Ixy { x, y },
+                    size: STUB_NODE_SIZE,
+                    header: None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        bindings::r#impl::base::Binding,
+        groups::r#impl::types::GroupType,
+        topology::types::{base::TopologyOptions, edges::EdgeType, layout::Uxy},
+    };
+    use crate::tests;
+
+    #[test]
+    fn test_stub_replaces_filtered_out_group_endpoint() {
+        let network_id = Uuid::new_v4();
+        let subnet = tests::subnet(&network_id);
+
+        let host = tests::host(&network_id);
+        let mut interface = tests::interface(&network_id, &subnet.id);
+        interface.base.host_id = host.id;
+        let mut service = tests::service(&network_id, &host.id);
+        let binding = Binding::new_interface(service.id, network_id, interface.id);
+        service.base.bindings = vec![binding];
+
+        // Its host didn't pass the filter, so only the binding made it into the topology
+        let hidden_host = tests::host(&network_id);
+        let hidden_service = tests::service(&network_id, &hidden_host.id);
+        let hidden_binding = Binding::new_interface(hidden_service.id, network_id, Uuid::new_v4());
+
+        let mut group = tests::group(&network_id);
+        group.base.group_type = GroupType::RequestPath;
+        group.base.binding_ids = vec![binding.id(), hidden_binding.id()];
+
+        let options = TopologyOptions::default();
+        let hosts = [host.clone()];
+        let interfaces = [interface.clone()];
+        let subnets = [subnet.clone()];
+        let services = [service];
+        let groups = [group.clone()];
+        let bindings = [binding, hidden_binding];
+        let ctx = TopologyContext::new(
+            &hosts,
+            &interfaces,
+            &subnets,
+            &services,
+            &groups,
+            &[],
+            &bindings,
+            &options,
+        );
+
+        let subnet_node = Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: subnet.id,
+            position: Ixy { x: 0, y: 0 },
+            size: Uxy { x: 600, y: 400 },
+            header: None,
+        };
+        let interface_node = Node {
+            node_type: NodeType::InterfaceNode {
+                subnet_id: subnet.id,
+                host_id: host.id,
+                interface_id: Some(interface.id),
+                is_infra: false,
+            },
+            id: interface.id,
+            position: Ixy { x: 100, y: 100 },
+            size: Uxy { x: 250, y: 100 },
+            header: None,
+        };
+        let mut nodes = vec![subnet_node, interface_node];
+        let mut edges = Vec::new();
+
+        StubPlanner::add_stubs(&ctx, &mut nodes, &mut edges);
+
+        let stub = nodes
+            .iter()
+            .find(|n| n.id == hidden_binding.id())
+            .expect("stub for the hidden binding");
+        assert_eq!(
+            stub.node_type,
+            NodeType::StubNode {
+                binding_id: hidden_binding.id()
+            }
+        );
+        assert_eq!(stub.position.y, 400 + SUBNET_PADDING.y as isize);
+        // Centered under the interface node
+        assert_eq!(stub.position.x, 100 + 125 - STUB_NODE_SIZE.x as isize / 2);
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].source, interface.id);
+        assert_eq!(edges[0].target, hidden_binding.id());
+        assert_eq!(edges[0].label, Some(group.base.name.clone()));
+        assert_eq!(
+            (edges[0].source_handle, edges[0].target_handle),
+            (EdgeHandle::Bottom, EdgeHandle::Top)
+        );
+        assert!(matches!(edges[0].edge_type, EdgeType::RequestPath { .. }));
+    }
+
+    #[test]
+    fn test_no_stubs_when_both_ends_are_missing() {
+        let network_id = Uuid::new_v4();
+        let mut group = tests::group(&network_id);
+        group.base.binding_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+
+        let options = TopologyOptions::default();
+        let groups = [group];
+        let ctx = TopologyContext::new(&[], &[], &[], &[], &groups, &[], &[], &options);
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        StubPlanner::add_stubs(&ctx, &mut nodes, &mut edges);
+
+        assert!(nodes.is_empty());
+        assert!(edges.is_empty());
+    }
+}
@@ -1,10 +1,23 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
-use crate::server::topology::types::layout::{Ixy, NodeLayout, Uxy};
+use crate::server::{
+    interfaces::r#impl::base::Interface,
+    topology::types::{
+        edges::{Edge, EdgeHandle},
+        layout::{Ixy, NodeBounds, NodeLayout, Uxy},
+        nodes::Node,
+    },
+};
 
 pub const SUBNET_PADDING: Uxy = Uxy { x: 125, y: 125 };
 pub const NODE_PADDING: Uxy = Uxy { x: 50, y: 50 };
+/// Subnets drawn without children, as in the routed and host focus layouts
+pub const COLLAPSED_SUBNET_SIZE: Uxy = Uxy { x: 300, y: 100 };
+/// Placeholders for group endpoints that a topology filter left out
+pub const STUB_NODE_SIZE: Uxy = Uxy { x: 200, y: 50 };
+/// Shared subnets and hosts inside a network in the organization topology
+pub const NETWORK_MEMBER_SIZE: Uxy = Uxy { x: 250, y: 75 };
 pub struct PlannerUtils;
 
 impl PlannerUtils {
@@ -78,4 +91,174 @@ impl PlannerUtils {
 
         (child_positions, container_size)
     }
+
+    /// Label for an edge standing in for an interface, e.g. `eth0: 10.0.0.1`
+    pub fn interface_label(interface: &Interface) -> String {
+        match &interface.base.name {
+            Some(name) => format!("{}: {}", name, interface.base.ip_address),
+            None => interface.base.ip_address.to_string(),
+        }
+    }
+
+    /// Keep the position a top-level node had in the previous build when it is the same kind
+    /// of node at the same size, so a rebuild doesn't throw away the user's arrangement.
+    /// If anything new lands on a kept node, all new nodes move below the kept ones together.
+    pub fn keep_previous_positions(nodes: &mut [Node], old_nodes: &[Node]) {
+        let old_by_id: HashMap<Uuid, &Node> = old_nodes.iter().map(|n| (n.id, n)).collect();
+        let mut kept: HashSet<Uuid> = HashSet::new();
+
+        for node in nodes.iter_mut().filter(|n| n.parent_id().is_none()) {
+            if let Some(old) = old_by_id.get(&node.id)
+                && old.node_type == node.node_type
+                && old.size == node.size
+            {
+                node.position = old.position;
+                kept.insert(node.id);
+            }
+        }
+
+        let (kept_nodes, new_nodes): (Vec<&Node>, Vec<&Node>) = nodes
+            .iter()
+            .filter(|n| n.parent_id().is_none())
+            .partition(|n| kept.contains(&n.id));
+        let bounds = |n: &&Node| NodeBounds::new(n.position, n.size);
+
+        let collides = new_nodes
+            .iter()
+            .map(bounds)
+            .any(|new| kept_nodes.iter().map(bounds).any(|old| new.overlaps(&old)));
+
+        if let (true, Some(kept_bottom), Some(new_top)) = (
+            collides,
+            kept_nodes.iter().map(|n| bounds(n).bottom()).max(),
+            new_nodes.iter().map(|n| n.position.y).min(),
+        ) {
+            let shift = kept_bottom + SUBNET_PADDING.y as isize - new_top;
+            for node in nodes
+                .iter_mut()
+                .filter(|n| n.parent_id().is_none() && !kept.contains(&n.id))
+            {
+                node.position.y += shift;
+            }
+        }
+    }
+
+    /// Point each edge out of the side of its source that faces its target. Children are
+    /// measured at their absolute position.
+    pub fn assign_edge_handles(nodes: &[Node], edges: &mut [Edge]) {
+        let by_id: HashMap<Uuid, &Node> = nodes.iter().map(|n| (n.id, n)).collect();
+        let center = |id: &Uuid| -> Option<(isize, isize)> {
+            let node = by_id.get(id)?;
+            let offset = node
+                .parent_id()
+                .and_then(|p| by_id.get(&p))
+                .map(|p| p.position)
+                .unwrap_or_default();
+            Some((
+                offset.x + node.position.x + node.size.x as isize / 2,
+                offset.y + node.position.y + node.size.y as isize / 2,
+            ))
+        };
+
+        for edge in edges.iter_mut() {
+            let (Some(source), Some(target)) = (center(&edge.source), center(&edge.target)) else {
+                continue;
+            };
+            let (dx, dy) = (target.0 - source.0, target.1 - source.1);
+
+            (edge.source_handle, edge.target_handle) = if dy.abs() >= dx.abs() {
+                if dy >= 0 {
+                    (EdgeHandle::Bottom, EdgeHandle::Top)
+                } else {
+                    (EdgeHandle::Top, EdgeHandle::Bottom)
+                }
+            } else if dx >= 0 {
+                (EdgeHandle::Right, EdgeHandle::Left)
+            } else {
+                (EdgeHandle::Left, EdgeHandle::Right)
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::types::{edges::EdgeType, nodes::NodeType};
+
+    fn host_node(x: isize, y: isize) -> Node {
+        let host_id = Uuid::new_v4();
+        Node {
+            node_type: NodeType::HostNode { host_id },
+            id: host_id,
+            position: Ixy { x, y },
+            size: Uxy { x: 250, y: 75 },
+            header: None,
+        }
+    }
+
+    #[test]
+    fn test_keep_previous_positions_moves_new_nodes_clear_of_kept_ones() {
+        let mut moved = host_node(0, 0);
+        let old_moved = Node {
+            position: Ixy { x: 400, y: 600 },
+            ..moved.clone()
+        };
+        let mut resized = host_node(300, 0);
+        let old_resized = Node {
+            position: Ixy { x: 900, y: 900 },
+            size: Uxy { x: 100, y: 100 },
+            ..resized.clone()
+        };
+        // Lands on the kept node once that is back at its old position
+        let new = host_node(400, 600);
+
+        let mut nodes = vec![moved.clone(), resized.clone(), new];
+        PlannerUtils::keep_previous_positions(&mut nodes, &[old_moved, old_resized]);
+
+        moved.position = Ixy { x: 400, y: 600 };
+        assert_eq!(nodes[0], moved);
+        // Every node that wasn't kept shifts by the same amount, to below the kept node
+        let shift = 600 + 75 + SUBNET_PADDING.y as isize;
+        resized.position.y += shift;
+        assert_eq!(nodes[1], resized);
+        assert_eq!(
+            nodes[2].position,
+            Ixy {
+                x: 400,
+                y: 600 + shift
+            }
+        );
+    }
+
+    #[test]
+    fn test_assign_edge_handles_faces_target() {
+        let left = host_node(0, 0);
+        let right = host_node(500, 0);
+        let below = host_node(0, 400);
+        let mut edges: Vec<Edge> = [(left.id, right.id), (below.id, left.id)]
+            .into_iter()
+            .map(|(source, target)| Edge {
+                id: Uuid::new_v4(),
+                source,
+                target,
+                edge_type: EdgeType::Interface { host_id: source },
+                label: None,
+                source_handle: EdgeHandle::Top,
+                target_handle: EdgeHandle::Top,
+                is_multi_hop: false,
+            })
+            .collect();
+
+        PlannerUtils::assign_edge_handles(&[left, right, below], &mut edges);
+
+        assert_eq!(
+            (edges[0].source_handle, edges[0].target_handle),
+            (EdgeHandle::Right, EdgeHandle::Left)
+        );
+        assert_eq!(
+            (edges[1].source_handle, edges[1].target_handle),
+            (EdgeHandle::Top, EdgeHandle::Bottom)
+        );
+    }
 }
@@ -0,0 +1,289 @@
+use anyhow::{Error, Result, anyhow};
+use flate2::{Compression, write::ZlibEncoder};
+use resvg::{tiny_skia, usvg};
+use std::io::Write;
+use std::sync::{Arc, OnceLock};
+
+use crate::server::{shared::types::api::ValidationError, topology::types::render::RenderPageSize};
+
+/// Upper bound on rasterized pixels (about 200 MB of RGBA)
+const MAX_PIXELS: u64 = 50_000_000;
+/// PDF points per diagram pixel, i.e. the diagram is printed at 96 DPI
+const POINTS_PER_PIXEL: f32 = 0.75;
+const PAGE_MARGIN: f32 = 36.0;
+
+static FONT_DATABASE: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+
+/// System fonts for labels plus the lucide font used for icons. Loading system fonts is slow,
+/// so the database is built once.
+fn font_database() -> Arc<usvg::fontdb::Database> {
+    FONT_DATABASE
+        .get_or_init(|| {
+            let mut database = usvg::fontdb::Database::new();
+            database.load_system_fonts();
+            database.load_font_data(lucide_icons::LUCIDE_FONT_BYTES.to_vec());
+            Arc::new(database)
+        })
+        .clone()
+}
+
+fn rasterize(svg: &str, scale: f32) -> Result<tiny_skia::Pixmap, Error> {
+    let options = usvg::Options {
+        fontdb: font_database(),
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &options)?;
+
+    let width = (tree.size().width() * scale).ceil() as u32;
+    let height = (tree.size().height() * scale).ceil() as u32;
+
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(ValidationError::new(format!(
+            "Rendered image would be {}x{} pixels; use a smaller scale",
+            width, height
+        ))
+        .into());
+    }
+
+    let mut pixmap = tiny_skia::Pixmap::new(width.max(1), height.max(1))
+        .ok_or_else(|| anyhow!("Could not allocate a {}x{} image", width, height))?;
+    // Keeps the output opaque so PDF pages don't need an alpha channel
+    pixmap.fill(tiny_skia::Color::WHITE);
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    Ok(pixmap)
+}
+
+pub fn rasterize_png(svg: &str, scale: f32) -> Result<Vec<u8>, Error> {
+    let pixmap = rasterize(svg, scale)?;
+    Ok(pixmap.encode_png()?)
+}
+
+/// Rasterize and split across landscape pages, reading left to right then top to bottom
+pub fn rasterize_pdf(svg: &str, scale: f32, page_size: RenderPageSize) -> Result<Vec<u8>, Error> {
+    let pixmap = rasterize(svg, scale)?;
+
+    let (page_width, page_height) = page_size.dimensions();
+    let tile_width = ((page_width - PAGE_MARGIN * 2.0) / POINTS_PER_PIXEL * scale).floor() as u32;
+    let tile_height = ((page_height - PAGE_MARGIN * 2.0) / POINTS_PER_PIXEL * scale).floor() as u32;
+
+    let pages = page_tiles(pixmap.width(), pixmap.height(), tile_width, tile_height)
+        .into_iter()
+        .map(|tile| -> Result<PdfImagePage, Error> {
+            let mut rgb = Vec::with_capacity(tile.width as usize * tile.height as usize * 3);
+            for row in tile.y..tile.y + tile.height {
+                let start = (row * pixmap.width() + tile.x) as usize * 4;
+                let end = start + tile.width as usize * 4;
+                // Pixels are opaque, so premultiplied RGBA is plain RGBA
+                for pixel in pixmap.data()[start..end].chunks_exact(4) {
+                    rgb.extend_from_slice(&pixel[..3]);
+                }
+            }
+
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&rgb)?;
+
+            Ok(PdfImagePage {
+                pixel_width: tile.width,
+                pixel_height: tile.height,
+                data: encoder.finish()?,
+                draw_width: tile.width as f32 / scale * POINTS_PER_PIXEL,
+                draw_height: tile.height as f32 / scale * POINTS_PER_PIXEL,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(write_pdf(&pages, page_width, page_height))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tile {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+fn page_tiles(width: u32, height: u32, tile_width: u32, tile_height: u32) -> Vec<Tile> {
+    let tile_width = tile_width.max(1);
+    let tile_height = tile_height.max(1);
+
+    let mut tiles = Vec::new();
+    for y in (0..height).step_by(tile_height as usize) {
+        for x in (0..width).step_by(tile_width as usize) {
+            tiles.push(Tile {
+                x,
+                y,
+                width: tile_width.min(width - x),
+                height: tile_height.min(height - y),
+            });
+        }
+    }
+    tiles
+}
+
+/// A page holding one zlib-compressed RGB image, drawn from the top-left margin
+struct PdfImagePage {
+    pixel_width: u32,
+    pixel_height: u32,
+    data: Vec<u8>,
+    draw_width: f32,
+    draw_height: f32,
+}
+
+/// Minimal PDF 1.4 writer: a page tree where every page shows a single image
+fn write_pdf(pages: &[PdfImagePage], page_width: f32, page_height: f32) -> Vec<u8> {
+    // Objects 1 and 2 are the catalog and page tree; each page takes three more
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..pages.len())
+                .map(|i| format!("{} 0 R", 3 + i * 3))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+    ];
+
+    for (i, page) in pages.iter().enumerate() {
+        let page_id = 3 + i * 3;
+        let content_id = page_id + 1;
+        let image_id = page_id + 2;
+
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /XObject << /Im0 {} 0 R >> >> /Contents {} 0 R >>",
+                page_width, page_height, image_id, content_id
+            )
+            .into_bytes(),
+        );
+
+        let content = format!(
+            "q {:.2} 0 0 {:.2} {:.2} {:.2} cm /Im0 Do Q",
+            page.draw_width,
+            page.draw_height,
+            PAGE_MARGIN,
+            page_height - PAGE_MARGIN - page.draw_height
+        );
+        objects.push(stream_object("", content.as_bytes()));
+
+        objects.push(stream_object(
+            &format!(
+                "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode",
+                page.pixel_width, page.pixel_height
+            ),
+            &page.data,
+        ));
+    }
+
+    let mut pdf: Vec<u8> = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        )
+        .as_bytes(),
+    );
+
+    pdf
+}
+
+fn stream_object(dictionary: &str, data: &[u8]) -> Vec<u8> {
+    let mut object = if dictionary.is_empty() {
+        format!("<< /Length {} >>\nstream\n", data.len())
+    } else {
+        format!("<< {} /Length {} >>\nstream\n", dictionary, data.len())
+    }
+    .into_bytes();
+    object.extend_from_slice(data);
+    object.extend_from_slice(b"\nendstream");
+    object
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_tiles_cover_image() {
+        let tiles = page_tiles(250, 120, 100, 100);
+
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(
+            tiles[2],
+            Tile {
+                x: 200,
+                y: 0,
+                width: 50,
+                height: 100
+            }
+        );
+        assert_eq!(
+            tiles[5],
+            Tile {
+                x: 200,
+                y: 100,
+                width: 50,
+                height: 20
+            }
+        );
+
+        let area: u32 = tiles.iter().map(|t| t.width * t.height).sum();
+        assert_eq!(area, 250 * 120);
+    }
+
+    #[test]
+    fn test_write_pdf_xref_points_at_objects() {
+        let page = |data: &[u8]| PdfImagePage {
+            pixel_width: 1,
+            pixel_height: 1,
+            data: data.to_vec(),
+            draw_width: 10.0,
+            draw_height: 10.0,
+        };
+        let pdf = write_pdf(&[page(b"abc"), page(b"defg")], 842.0, 595.0);
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(text.contains("/Kids [3 0 R 6 0 R] /Count 2"));
+        assert_eq!(text.matches("/Type /Page ").count(), 2);
+
+        // Every xref entry must point at the start of its object
+        let xref_start: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|s| s.lines().next())
+            .and_then(|s| s.parse().ok())
+            .unwrap();
+        let xref = String::from_utf8_lossy(&pdf[xref_start..]);
+        assert!(xref.starts_with("xref\n0 9\n"));
+
+        for (i, entry) in xref.lines().skip(3).take(8).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", i + 1).as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,1068 @@
+use anyhow::{Error, Result};
+use std::collections::BTreeSet;
+
+use crate::server::{
+    shared::{
+        concepts::Concept,
+        entities::EntityDiscriminants,
+        types::{Color, Icon, metadata::EntityMetadataProvider},
+    },
+    topology::{
+        service::{
+            raster,
+            view::{Point, Rect, TopologyView, escape_xml},
+        },
+        types::{
+            base::{Topology, TopologyOptions},
+            edges::{Edge, EdgeHandle, EdgeStyle},
+            nodes::{Node, NodeType, STUB_LABEL},
+            render::{RenderFormat, RenderParams},
+        },
+    },
+};
+
+const CANVAS_PADDING: f64 = 40.0;
+/// Subnet labels sit above the subnet container, like in the UI
+const SUBNET_LABEL_OFFSET: f64 = 40.0;
+const SUBNET_LABEL_HEIGHT: f64 = 30.0;
+const NODE_HEADER_HEIGHT: f64 = 25.0;
+const NODE_FOOTER_HEIGHT: f64 = 25.0;
+const EDGE_OFFSET: f64 = 20.0;
+const MULTI_HOP_EDGE_OFFSET: f64 = 100.0;
+const EDGE_CORNER_RADIUS: f64 = 10.0;
+
+const FONT_FAMILY: &str = "Inter, 'Helvetica Neue', Arial, 'DejaVu Sans', sans-serif";
+/// Family name of the bundled lucide font, which maps icons to private-use codepoints
+pub const ICON_FONT_FAMILY: &str = "lucide";
+
+const BACKGROUND: &str = "#111827";
+const SUBNET_FILL: &str = "#1a1d29";
+const CARD_FILL: &str = "#1f2937";
+const CARD_STROKE: &str = "#374151";
+const TEXT_SECONDARY: &str = "#d1d5db";
+const TEXT_TERTIARY: &str = "#9ca3af";
+
+/// Render a topology in the requested format
+pub fn render(topology: &Topology, params: &RenderParams) -> Result<Vec<u8>, Error> {
+    let svg = render_svg(topology, &params.options);
+
+    match params.format {
+        RenderFormat::Svg => Ok(svg.into_bytes()),
+        RenderFormat::Png => raster::rasterize_png(&svg, params.scale),
+        RenderFormat::Pdf => raster::rasterize_pdf(&svg, params.scale, params.page_size),
+    }
+}
+
+/// Rendering is CPU bound, so it runs on the blocking pool
+pub async fn render_blocking(topology: Topology, params: RenderParams) -> Result<Vec<u8>, Error> {
+    tokio::task::spawn_blocking(move || render(&topology, &params)).await?
+}
+
+/// Render the stored graph of a topology to a standalone SVG document. Nodes and edges are
+/// drawn where the layout placed them; options only hide things.
+pub fn render_svg(topology: &Topology, options: &TopologyOptions) -> String {
+    SvgRenderer::new(topology, options).render()
+}
+
+/// Unit vector pointing away from the node at a handle
+fn outward(handle: EdgeHandle) -> (f64, f64) {
+    match handle {
+        EdgeHandle::Top => (0.0, -1.0),
+        EdgeHandle::Bottom => (0.0, 1.0),
+        EdgeHandle::Left => (-1.0, 0.0),
+        EdgeHandle::Right => (1.0, 0.0),
+    }
+}
+
+struct SvgRenderer<'a> {
+    view: TopologyView<'a>,
+}
+
+impl<'a> SvgRenderer<'a> {
+    fn new(topology: &'a Topology, options: &'a TopologyOptions) -> Self {
+        Self {
+            view: TopologyView::new(topology, options),
+        }
+    }
+
+    fn render(&self) -> String {
+        let subnet_nodes = self.view.subnet_nodes();
+        let interface_nodes = self.view.interface_nodes();
+        let host_nodes = self.view.host_nodes();
+        let service_nodes = self.view.service_nodes();
+        let stub_nodes = self.view.stub_nodes();
+        let edges = self.view.visible_edges();
+
+        let canvas = self.canvas();
+        let mut svg = String::new();
+
+        svg.push_str(&format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}" font-family="{font}">"#,
+            x = fmt_num(canvas.x),
+            y = fmt_num(canvas.y),
+            w = fmt_num(canvas.width),
+            h = fmt_num(canvas.height),
+            font = FONT_FAMILY,
+        ));
+        svg.push_str(&format!(
+            "<title>{}</title>",
+            escape_xml(&self.view.topology.base.name)
+        ));
+        svg.push_str(&self.render_markers(&edges));
+        svg.push_str(&format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+            fmt_num(canvas.x),
+            fmt_num(canvas.y),
+            fmt_num(canvas.width),
+            fmt_num(canvas.height),
+            BACKGROUND
+        ));
+
+        if self.view.topology.base.nodes.is_empty() {
+            svg.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="14" text-anchor="middle">This topology has no nodes yet</text>"#,
+                fmt_num(canvas.x + canvas.width / 2.0),
+                fmt_num(canvas.y + canvas.height / 2.0),
+                TEXT_TERTIARY
+            ));
+        }
+
+        for node in &subnet_nodes {
+            svg.push_str(&self.render_subnet(node));
+        }
+
+        for node in &host_nodes {
+            svg.push_str(&self.render_host(node));
+        }
+
+        let mut labels = String::new();
+        for edge in &edges {
+            let (path, label) = self.render_edge(edge);
+            svg.push_str(&path);
+            labels.push_str(&label);
+        }
+
+        for node in &interface_nodes {
+            svg.push_str(&self.render_interface(node));
+        }
+
+        for node in &service_nodes {
+            svg.push_str(&self.render_service(node));
+        }
+
+        for node in &stub_nodes {
+            svg.push_str(&self.render_stub(node));
+        }
+
+        svg.push_str(&labels);
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Area covering every node and subnet label
+    fn canvas(&self) -> Rect {
+        if self.view.bounds.is_empty() {
+            return Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 400.0,
+                height: 200.0,
+            };
+        }
+
+        let mut min_x = f64::MAX;
+        let mut min_y = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut max_y = f64::MIN;
+
+        for node in &self.view.topology.base.nodes {
+            let Some(rect) = self.view.bounds.get(&node.id) else {
+                continue;
+            };
+            let top = match node.node_type {
+                NodeType::SubnetNode { .. } | NodeType::NetworkNode { .. } => {
+                    rect.y - SUBNET_LABEL_OFFSET
+                }
+                NodeType::InterfaceNode { .. }
+                | NodeType::HostNode { .. }
+                | NodeType::ServiceNode { .. }
+                | NodeType::StubNode { .. }
+                | NodeType::NetworkSubnetNode { .. }
+                | NodeType::NetworkHostNode { .. } => rect.y,
+            };
+            min_x = min_x.min(rect.x);
+            min_y = min_y.min(top);
+            max_x = max_x.max(rect.right());
+            max_y = max_y.max(rect.bottom());
+        }
+
+        Rect {
+            x: min_x - CANVAS_PADDING,
+            y: min_y - CANVAS_PADDING,
+            width: max_x - min_x + CANVAS_PADDING * 2.0,
+            height: max_y - min_y + CANVAS_PADDING * 2.0,
+        }
+    }
+
+    fn render_markers(&self, edges: &[&Edge]) -> String {
+        let colors: BTreeSet<String> = edges
+            .iter()
+            .map(|e| self.view.edge_appearance(e))
+            .filter(|a| a.has_end_marker)
+            .map(|a| a.color.hex())
+            .collect();
+
+        if colors.is_empty() {
+            return String::new();
+        }
+
+        let mut defs = String::from("<defs>");
+        for color in colors {
+            defs.push_str(&format!(
+                r#"<marker id="arrow-{id}" viewBox="0 0 10 10" refX="9" refY="5" markerWidth="6" markerHeight="6" orient="auto-start-reverse"><path d="M 0 0 L 10 5 L 0 10 z" fill="{color}"/></marker>"#,
+                id = color.trim_start_matches('#'),
+                color = color
+            ));
+        }
+        defs.push_str("</defs>");
+        defs
+    }
+
+    fn render_subnet(&self, node: &Node) -> String {
+        let NodeType::SubnetNode { infra_width } = node.node_type else {
+            return String::new();
+        };
+        let Some(rect) = self.view.bounds.get(&node.id) else {
+            return String::new();
+        };
+
+        let mut out = format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="12" fill="{}"/>"#,
+            fmt_num(rect.x),
+            fmt_num(rect.y),
+            fmt_num(rect.width),
+            fmt_num(rect.height),
+            SUBNET_FILL
+        );
+
+        if infra_width > 0 {
+            let zone_width = (infra_width as f64 + 20.0).min(rect.width);
+            out.push_str(&format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="12" fill="{}" fill-opacity="0.2"/>"#,
+                fmt_num(rect.x),
+                fmt_num(rect.y),
+                fmt_num(zone_width),
+                fmt_num(rect.height),
+                Color::Gray.hex()
+            ));
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="8" font-weight="600" text-anchor="middle">{}</text>"#,
+                fmt_num(rect.x + zone_width / 2.0),
+                fmt_num(rect.y + 10.0),
+                TEXT_TERTIARY,
+                escape_xml(&self.view.options.local.left_zone_title)
+            ));
+        }
+
+        let Some(subnet) = self.view.subnets.get(&node.id) else {
+            return out;
+        };
+
+        let subnet_type = subnet.base.subnet_type;
+        let Some(label) = self.view.subnet_label(node) else {
+            return out;
+        };
+
+        let label = truncate(&label, rect.width - 40.0, 13.0);
+        let label_width = text_width(&label, 13.0) + 40.0;
+        let label_y = rect.y - SUBNET_LABEL_OFFSET;
+
+        out.push_str(&format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="{}" stroke="{}"/>"#,
+            fmt_num(rect.x),
+            fmt_num(label_y),
+            fmt_num(label_width),
+            fmt_num(SUBNET_LABEL_HEIGHT),
+            CARD_FILL,
+            CARD_STROKE
+        ));
+        out.push_str(&icon_glyph(
+            subnet_type.icon(),
+            subnet_type.color(),
+            rect.x + 18.0,
+            label_y + SUBNET_LABEL_HEIGHT / 2.0,
+            18.0,
+        ));
+        out.push_str(&format!(
+            r#"<text x="{}" y="{}" fill="{}" font-size="13" font-weight="500">{}</text>"#,
+            fmt_num(rect.x + 32.0),
+            fmt_num(label_y + SUBNET_LABEL_HEIGHT / 2.0 + 4.5),
+            TEXT_SECONDARY,
+            escape_xml(&label)
+        ));
+
+        out
+    }
+
+    fn render_interface(&self, node: &Node) -> String {
+        let NodeType::InterfaceNode {
+            host_id,
+            interface_id,
+            subnet_id,
+            ..
+        } = node.node_type
+        else {
+            return String::new();
+        };
+        let (Some(rect), Some(host)) = (
+            self.view.bounds.get(&node.id),
+            self.view.hosts.get(&host_id),
+        ) else {
+            return String::new();
+        };
+
+        let is_virtualized = host.base.virtualization.is_some();
+        let virtualization_color = Concept::Virtualization.color();
+        let host_color = EntityDiscriminants::Host.color();
+
+        let mut out = format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="{}" stroke="{}"/>"#,
+            fmt_num(rect.x),
+            fmt_num(rect.y),
+            fmt_num(rect.width),
+            fmt_num(rect.height),
+            CARD_FILL,
+            if is_virtualized {
+                virtualization_color.hex()
+            } else {
+                CARD_STROKE.to_string()
+            }
+        );
+
+        let center_x = rect.x + rect.width / 2.0;
+        let inner_width = rect.width - 24.0;
+        let mut body_top = rect.y;
+
+        if let Some(header) = &node.header {
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="11" font-weight="500" text-anchor="middle">{}</text>"#,
+                fmt_num(center_x),
+                fmt_num(rect.y + 16.0),
+                if is_virtualized {
+                    virtualization_color.hex()
+                } else {
+                    TEXT_TERTIARY.to_string()
+                },
+                escape_xml(&truncate(header, inner_width, 11.0))
+            ));
+            body_top += NODE_HEADER_HEIGHT;
+        }
+
+        let footer = self.view.interface_label(subnet_id, interface_id);
+
+        let body_bottom = rect.bottom() - NODE_FOOTER_HEIGHT;
+        let services = self.view.services_for_node(host_id, interface_id);
+
+        if services.is_empty() {
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="13" text-anchor="middle">{}</text>"#,
+                fmt_num(center_x),
+                fmt_num((body_top + body_bottom) / 2.0 + 4.5),
+                TEXT_SECONDARY,
+                escape_xml(&truncate(&host.base.name, inner_width, 13.0))
+            ));
+        } else {
+            let slot_height = (body_bottom - body_top).max(0.0) / services.len() as f64;
+
+            for (index, service) in services.iter().enumerate() {
+                let slot_center = body_top + slot_height * (index as f64 + 0.5);
+                let ports = self.view.ports_for_service(service, interface_id);
+                let name_y = if ports.is_empty() {
+                    slot_center
+                } else {
+                    slot_center - 8.0
+                };
+
+                let name = truncate(&service.base.name, inner_width - 24.0, 13.0);
+                let row_width = 24.0 + text_width(&name, 13.0);
+                let row_left = center_x - row_width / 2.0;
+
+                out.push_str(&icon_glyph(
+                    service.base.service_definition.icon(),
+                    host_color,
+                    row_left + 10.0,
+                    name_y,
+                    20.0,
+                ));
+                out.push_str(&format!(
+                    r#"<text x="{}" y="{}" fill="{}" font-size="13">{}</text>"#,
+                    fmt_num(row_left + 24.0),
+                    fmt_num(name_y + 4.5),
+                    TEXT_SECONDARY,
+                    escape_xml(&name)
+                ));
+
+                if !ports.is_empty() {
+                    out.push_str(&format!(
+                        r#"<text x="{}" y="{}" fill="{}" font-size="11" text-anchor="middle">{}</text>"#,
+                        fmt_num(center_x),
+                        fmt_num(name_y + 20.0),
+                        TEXT_TERTIARY,
+                        escape_xml(&truncate(&ports.join(", "), inner_width, 11.0))
+                    ));
+                }
+            }
+        }
+
+        if let Some(footer) = footer {
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="11" text-anchor="middle">{}</text>"#,
+                fmt_num(center_x),
+                fmt_num(rect.bottom() - 9.0),
+                TEXT_TERTIARY,
+                escape_xml(&truncate(&footer, inner_width, 11.0))
+            ));
+        }
+
+        out
+    }
+
+    /// A host drawn as a whole. With services inside it, it is a container titled with the
+    /// host name; otherwise a card with the host name and hostname.
+    fn render_host(&self, node: &Node) -> String {
+        let NodeType::HostNode { host_id } = node.node_type else {
+            return String::new();
+        };
+        let (Some(rect), Some(host)) = (
+            self.view.bounds.get(&node.id),
+            self.view.hosts.get(&host_id),
+        ) else {
+            return String::new();
+        };
+
+        let is_virtualized = host.base.virtualization.is_some();
+        let stroke = if is_virtualized {
+            Concept::Virtualization.color().hex()
+        } else {
+            CARD_STROKE.to_string()
+        };
+        let host_entity = EntityDiscriminants::Host;
+
+        if self.view.has_children(node) {
+            let mut out = format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="12" fill="{}" stroke="{}"/>"#,
+                fmt_num(rect.x),
+                fmt_num(rect.y),
+                fmt_num(rect.width),
+                fmt_num(rect.height),
+                SUBNET_FILL,
+                stroke
+            );
+            out.push_str(&icon_glyph(
+                host_entity.icon(),
+                host_entity.color(),
+                rect.x + 20.0,
+                rect.y + 22.0,
+                18.0,
+            ));
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="13" font-weight="500">{}</text>"#,
+                fmt_num(rect.x + 36.0),
+                fmt_num(rect.y + 26.5),
+                TEXT_SECONDARY,
+                escape_xml(&truncate(&host.base.name, rect.width - 56.0, 13.0))
+            ));
+            return out;
+        }
+
+        let mut out = format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="{}" stroke="{}"/>"#,
+            fmt_num(rect.x),
+            fmt_num(rect.y),
+            fmt_num(rect.width),
+            fmt_num(rect.height),
+            CARD_FILL,
+            stroke
+        );
+
+        let center_x = rect.x + rect.width / 2.0;
+        let inner_width = rect.width - 24.0;
+        let name = truncate(&host.base.name, inner_width - 24.0, 13.0);
+        let row_width = 24.0 + text_width(&name, 13.0);
+        let row_left = center_x - row_width / 2.0;
+        let name_y = rect.y + (rect.height - NODE_FOOTER_HEIGHT) / 2.0;
+
+        out.push_str(&icon_glyph(
+            host_entity.icon(),
+            host_entity.color(),
+            row_left + 10.0,
+            name_y,
+            20.0,
+        ));
+        out.push_str(&format!(
+            r#"<text x="{}" y="{}" fill="{}" font-size="13">{}</text>"#,
+            fmt_num(row_left + 24.0),
+            fmt_num(name_y + 4.5),
+            TEXT_SECONDARY,
+            escape_xml(&name)
+        ));
+
+        if let Some(hostname) = &host.base.hostname {
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="11" text-anchor="middle">{}</text>"#,
+                fmt_num(center_x),
+                fmt_num(rect.bottom() - 9.0),
+                TEXT_TERTIARY,
+                escape_xml(&truncate(hostname, inner_width, 11.0))
+            ));
+        }
+
+        out
+    }
+
+    /// A single service card, with the header the layout gave it (the host, in the service
+    /// dependency view)
+    fn render_service(&self, node: &Node) -> String {
+        let NodeType::ServiceNode { service_id, .. } = node.node_type else {
+            return String::new();
+        };
+        let (Some(rect), Some(service)) = (
+            self.view.bounds.get(&node.id),
+            self.view.services.get(&service_id),
+        ) else {
+            return String::new();
+        };
+
+        let is_containerized = service.base.virtualization.is_some();
+        let virtualization_color = Concept::Virtualization.color();
+
+        let mut out = format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="{}" stroke="{}"/>"#,
+            fmt_num(rect.x),
+            fmt_num(rect.y),
+            fmt_num(rect.width),
+            fmt_num(rect.height),
+            CARD_FILL,
+            if is_containerized {
+                virtualization_color.hex()
+            } else {
+                CARD_STROKE.to_string()
+            }
+        );
+
+        let center_x = rect.x + rect.width / 2.0;
+        let inner_width = rect.width - 24.0;
+        let mut body_top = rect.y;
+
+        if let Some(header) = &node.header {
+            out.push_str(&format!(
+                r#"<text x="{}" y="{}" fill="{}" font-size="11" font-weight="500" text-anchor="middle">{}</text>"#,
+                fmt_num(center_x),
+                fmt_num(rect.y + 16.0),
+                TEXT_TERTIARY,
+                escape_xml(&truncate(header, inner_width, 11.0))
+            ));
+            body_top += NODE_HEADER_HEIGHT;
+        }
+
+        let name = truncate(&service.base.name, inner_width - 24.0, 13.0);
+        let row_width = 24.0 + text_width(&name, 13.0);
+        let row_left = center_x - row_width / 2.0;
+        let name_y = (body_top + rect.bottom() - NODE_FOOTER_HEIGHT) / 2.0;
+
+        out.push_str(&icon_glyph(
+            service.base.service_definition.icon(),
+            EntityDiscriminants::Host.color(),
+            row_left + 10.0,
+            name_y,
+            20.0,
+        ));
+        out.push_str(&format!(
+            r#"<text x="{}" y="{}" fill="{}" font-size="13">{}</text>"#,
+            fmt_num(row_left + 24.0),
+            fmt_num(name_y + 4.5),
+            TEXT_SECONDARY,
+            escape_xml(&name)
+        ));
+        out.push_str(&format!(
+            r#"<text x="{}" y="{}" fill="{}" font-size="11" text-anchor="middle">{}</text>"#,
+            fmt_num(center_x),
+            fmt_num(rect.bottom() - 9.0),
+            TEXT_TERTIARY,
+            escape_xml(&truncate(
+                service.base.service_definition.name(),
+                inner_width,
+                11.0
+            ))
+        ));
+
+        out
+    }
+
+    /// Dashed placeholder where a group edge leaves the filtered topology
+    fn render_stub(&self, node: &Node) -> String {
+        let Some(rect) = self.view.bounds.get(&node.id) else {
+            return String::new();
+        };
+
+        format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="none" stroke="{}" stroke-dasharray="5 5"/><text x="{}" y="{}" fill="{}" font-size="12" text-anchor="middle">{}</text>"#,
+            fmt_num(rect.x),
+            fmt_num(rect.y),
+            fmt_num(rect.width),
+            fmt_num(rect.height),
+            CARD_STROKE,
+            fmt_num(rect.x + rect.width / 2.0),
+            fmt_num(rect.y + rect.height / 2.0 + 4.0),
+            TEXT_TERTIARY,
+            STUB_LABEL
+        )
+    }
+
+    /// Returns the edge path and, separately, its label so labels can be drawn above nodes
+    fn render_edge(&self, edge: &Edge) -> (String, String) {
+        let (Some(source_rect), Some(target_rect)) = (
+            self.view.bounds.get(&edge.source),
+            self.view.bounds.get(&edge.target),
+        ) else {
+            return (String::new(), String::new());
+        };
+
+        let appearance = self.view.edge_appearance(edge);
+        let source = source_rect.handle(edge.source_handle);
+        let target = target_rect.handle(edge.target_handle);
+
+        let (d, label_point) = match appearance.style {
+            EdgeStyle::Straight => (
+                format!(
+                    "M {} {} L {} {}",
+                    fmt_num(source.x),
+                    fmt_num(source.y),
+                    fmt_num(target.x),
+                    fmt_num(target.y)
+                ),
+                Point {
+                    x: (source.x + target.x) / 2.0,
+                    y: (source.y + target.y) / 2.0,
+                },
+            ),
+            EdgeStyle::Bezier | EdgeStyle::SimpleBezier => {
+                bezier_path(source, edge.source_handle, target, edge.target_handle)
+            }
+            EdgeStyle::Step | EdgeStyle::SmoothStep => {
+                let offset = if edge.is_multi_hop {
+                    self.multi_hop_offset(source, target, edge)
+                } else {
+                    EDGE_OFFSET
+                };
+                let points = step_points(
+                    source,
+                    edge.source_handle,
+                    target,
+                    edge.target_handle,
+                    offset,
+                );
+                let radius = if appearance.style == EdgeStyle::SmoothStep {
+                    EDGE_CORNER_RADIUS
+                } else {
+                    0.0
+                };
+                (
+                    rounded_polyline(&points, radius),
+                    polyline_midpoint(&points),
+                )
+            }
+        };
+
+        let color = appearance.color.hex();
+        let mut path = format!(
+            r#"<path d="{}" fill="none" stroke="{}" stroke-width="2""#,
+            d, color
+        );
+        if appearance.is_dashed {
+            path.push_str(r#" stroke-dasharray="5 5""#);
+        }
+        if appearance.has_end_marker {
+            path.push_str(&format!(
+                r#" marker-end="url(#arrow-{})""#,
+                color.trim_start_matches('#')
+            ));
+        }
+        path.push_str("/>");
+
+        let label = match edge.label.as_deref().filter(|l| !l.is_empty()) {
+            Some(label) => {
+                let label = truncate(label, 240.0, 11.0);
+                let width = text_width(&label, 11.0) + 16.0;
+                format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="20" rx="6" fill="{}" stroke="{}"/><text x="{}" y="{}" fill="{}" font-size="11" text-anchor="middle">{}</text>"#,
+                    fmt_num(label_point.x - width / 2.0),
+                    fmt_num(label_point.y - 10.0),
+                    fmt_num(width),
+                    CARD_FILL,
+                    color,
+                    fmt_num(label_point.x),
+                    fmt_num(label_point.y + 4.0),
+                    TEXT_SECONDARY,
+                    escape_xml(&label)
+                )
+            }
+            None => String::new(),
+        };
+
+        (path, label)
+    }
+
+    /// Multi-hop edges route around the subnets between their endpoints
+    fn multi_hop_offset(&self, source: Point, target: Point, edge: &Edge) -> f64 {
+        let routing_left =
+            edge.source_handle == EdgeHandle::Left || edge.target_handle == EdgeHandle::Left;
+        let min_x = source.x.min(target.x);
+        let max_x = source.x.max(target.x);
+        let min_y = source.y.min(target.y);
+        let max_y = source.y.max(target.y);
+
+        let outcrop = self
+            .view
+            .subnet_nodes()
+            .into_iter()
+            .filter_map(|n| self.view.bounds.get(&n.id))
+            .filter(|r| r.y > min_y && r.y < max_y)
+            .map(|r| {
+                if routing_left {
+                    min_x - r.x
+                } else {
+                    r.right() - max_x
+                }
+            })
+            .fold(0.0, f64::max);
+
+        MULTI_HOP_EDGE_OFFSET.max(outcrop + 50.0)
+    }
+}
+
+fn bezier_path(
+    source: Point,
+    source_handle: EdgeHandle,
+    target: Point,
+    target_handle: EdgeHandle,
+) -> (String, Point) {
+    let control = |from: Point, handle: EdgeHandle, to: Point| {
+        let (dx, dy) = outward(handle);
+        let distance = if dx != 0.0 {
+            (to.x - from.x) * dx
+        } else {
+            (to.y - from.y) * dy
+        };
+        // Same curvature rule as the UI: half the distance when the target is ahead of the
+        // handle, a gentle loop when it is behind
+        let offset = if distance >= 0.0 {
+            distance * 0.5
+        } else {
+            0.25 * 25.0 * (-distance).sqrt()
+        };
+        Point {
+            x: from.x + dx * offset,
+            y: from.y + dy * offset,
+        }
+    };
+
+    let c1 = control(source, source_handle, target);
+    let c2 = control(target, target_handle, source);
+
+    let midpoint = Point {
+        x: 0.125 * source.x + 0.375 * c1.x + 0.375 * c2.x + 0.125 * target.x,
+        y: 0.125 * source.y + 0.375 * c1.y + 0.375 * c2.y + 0.125 * target.y,
+    };
+
+    (
+        format!(
+            "M {} {} C {} {} {} {} {} {}",
+            fmt_num(source.x),
+            fmt_num(source.y),
+            fmt_num(c1.x),
+            fmt_num(c1.y),
+            fmt_num(c2.x),
+            fmt_num(c2.y),
+            fmt_num(target.x),
+            fmt_num(target.y)
+        ),
+        midpoint,
+    )
+}
+
+/// Orthogonal route leaving the source and entering the target perpendicular to their handles
+fn step_points(
+    source: Point,
+    source_handle: EdgeHandle,
+    target: Point,
+    target_handle: EdgeHandle,
+    offset: f64,
+) -> Vec<Point> {
+    let (sdx, sdy) = outward(source_handle);
+    let (tdx, tdy) = outward(target_handle);
+    let start = Point {
+        x: source.x + sdx * offset,
+        y: source.y + sdy * offset,
+    };
+    let end = Point {
+        x: target.x + tdx * offset,
+        y: target.y + tdy * offset,
+    };
+
+    let mut points = vec![source, start];
+
+    match (source_handle.is_vertical(), target_handle.is_vertical()) {
+        (true, true) => {
+            // Handles on the same side route around the outside; opposite sides meet halfway
+            let y = match (source_handle, target_handle) {
+                (EdgeHandle::Top, EdgeHandle::Top) => start.y.min(end.y),
+                (EdgeHandle::Bottom, EdgeHandle::Bottom) => start.y.max(end.y),
+                _ => (start.y + end.y) / 2.0,
+            };
+            points.push(Point { x: start.x, y });
+            points.push(Point { x: end.x, y });
+        }
+        (false, false) => {
+            let x = match (source_handle, target_handle) {
+                (EdgeHandle::Left, EdgeHandle::Left) => start.x.min(end.x),
+                (EdgeHandle::Right, EdgeHandle::Right) => start.x.max(end.x),
+                _ => (start.x + end.x) / 2.0,
+            };
+            points.push(Point { x, y: start.y });
+            points.push(Point { x, y: end.y });
+        }
+        (true, false) => points.push(Point {
+            x: start.x,
+            y: end.y,
+        }),
+        (false, true) => points.push(Point {
+            x: end.x,
+            y: start.y,
+        }),
+    }
+
+    points.push(end);
+    points.push(target);
+
+    // Drop repeated and collinear points so corners are only drawn where the route turns
+    let mut simplified: Vec<Point> = Vec::with_capacity(points.len());
+    for point in points {
+        if simplified.last() == Some(&point) {
+            continue;
+        }
+        if simplified.len() >= 2 {
+            let a = simplified[simplified.len() - 2];
+            let b = simplified[simplified.len() - 1];
+            let collinear = (a.x == b.x && b.x == point.x) || (a.y == b.y && b.y == point.y);
+            if collinear {
+                simplified.pop();
+            }
+        }
+        simplified.push(point);
+    }
+    simplified
+}
+
+fn rounded_polyline(points: &[Point], radius: f64) -> String {
+    let Some(first) = points.first() else {
+        return String::new();
+    };
+
+    let mut d = format!("M {} {}", fmt_num(first.x), fmt_num(first.y));
+
+    for (i, &current) in points.iter().enumerate().skip(1) {
+        if radius > 0.0 && i + 1 < points.len() {
+            let previous = points[i - 1];
+            let next = points[i + 1];
+            let r = radius
+                .min(distance(previous, current) / 2.0)
+                .min(distance(current, next) / 2.0);
+
+            let before = towards(current, previous, r);
+            let after = towards(current, next, r);
+            d.push_str(&format!(
+                " L {} {} Q {} {} {} {}",
+                fmt_num(before.x),
+                fmt_num(before.y),
+                fmt_num(current.x),
+                fmt_num(current.y),
+                fmt_num(after.x),
+                fmt_num(after.y)
+            ));
+        } else {
+            d.push_str(&format!(" L {} {}", fmt_num(current.x), fmt_num(current.y)));
+        }
+    }
+
+    d
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt()
+}
+
+/// Point `length` along the segment from `from` to `to`
+fn towards(from: Point, to: Point, length: f64) -> Point {
+    let total = distance(from, to);
+    if total == 0.0 {
+        return from;
+    }
+    Point {
+        x: from.x + (to.x - from.x) * length / total,
+        y: from.y + (to.y - from.y) * length / total,
+    }
+}
+
+/// Point halfway along a polyline, measured by length
+fn polyline_midpoint(points: &[Point]) -> Point {
+    let total: f64 = points.windows(2).map(|w| distance(w[0], w[1])).sum();
+    let mut remaining = total / 2.0;
+
+    for w in points.windows(2) {
+        let length = distance(w[0], w[1]);
+        if remaining <= length {
+            return towards(w[0], w[1], remaining);
+        }
+        remaining -= length;
+    }
+
+    points.last().copied().unwrap_or(Point { x: 0.0, y: 0.0 })
+}
+
+fn icon_glyph(icon: Icon, color: Color, center_x: f64, center_y: f64, size: f64) -> String {
+    format!(
+        r#"<text x="{}" y="{}" fill="{}" font-family="{}" font-size="{}" text-anchor="middle" dominant-baseline="central">&#x{:x};</text>"#,
+        fmt_num(center_x),
+        fmt_num(center_y),
+        color.hex(),
+        ICON_FONT_FAMILY,
+        fmt_num(size),
+        u32::from(char::from(icon))
+    )
+}
+
+/// Rough width of proportional sans-serif text, good enough to size labels and truncate
+fn text_width(text: &str, font_size: f64) -> f64 {
+    text.chars().count() as f64 * font_size * 0.6
+}
+
+fn truncate(text: &str, max_width: f64, font_size: f64) -> String {
+    if text_width(text, font_size) <= max_width {
+        return text.to_string();
+    }
+
+    let max_chars = ((max_width / (font_size * 0.6)) as usize).saturating_sub(1);
+    let mut truncated: String = text.chars().take(max_chars).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Coordinates are whole pixels in practice; avoid printing trailing zeros
+fn fmt_num(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::types::layout::{Ixy, Uxy};
+    use uuid::Uuid;
+
+    fn point(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    #[test]
+    fn test_step_points_between_layers() {
+        let points = step_points(
+            point(100.0, 100.0),
+            EdgeHandle::Bottom,
+            point(300.0, 300.0),
+            EdgeHandle::Top,
+            20.0,
+        );
+
+        assert_eq!(
+            points,
+            vec![
+                point(100.0, 100.0),
+                point(100.0, 200.0),
+                point(300.0, 200.0),
+                point(300.0, 300.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_step_points_multi_hop_routes_outside() {
+        let points = step_points(
+            point(100.0, 100.0),
+            EdgeHandle::Left,
+            point(150.0, 500.0),
+            EdgeHandle::Left,
+            100.0,
+        );
+
+        // Both legs leave to the left and meet at the leftmost offset
+        assert!(points.iter().all(|p| p.x <= 150.0));
+        assert_eq!(points[1], point(0.0, 100.0));
+        assert_eq!(points[2], point(0.0, 500.0));
+    }
+
+    #[test]
+    fn test_polyline_midpoint() {
+        let mid = polyline_midpoint(&[point(0.0, 0.0), point(0.0, 10.0), point(10.0, 10.0)]);
+        assert_eq!(mid, point(0.0, 10.0));
+    }
+
+    #[test]
+    fn test_truncate_and_fmt_num() {
+        assert_eq!(truncate("short", 100.0, 10.0), "short");
+        assert_eq!(truncate("a much longer label", 60.0, 10.0), "a much lo…");
+        assert_eq!(fmt_num(12.0), "12");
+        assert_eq!(fmt_num(-0.5), "-0.5");
+    }
+
+    #[test]
+    fn test_render_svg_covers_subnet_labels() {
+        let subnet_id = Uuid::new_v4();
+        let interface_node = Uuid::new_v4();
+
+        let mut topology = Topology::default();
+        topology.base.name = "Home & Lab".to_string();
+        topology.base.nodes = vec![
+            Node {
+                node_type: NodeType::SubnetNode { infra_width: 0 },
+                id: subnet_id,
+                position: Ixy { x: 100, y: 200 },
+                size: Uxy { x: 600, y: 300 },
+                header: None,
+            },
+            Node {
+                node_type: NodeType::InterfaceNode {
+                    subnet_id,
+                    host_id: Uuid::new_v4(),
+                    interface_id: None,
+                    is_infra: false,
+                },
+                id: interface_node,
+                position: Ixy { x: 25, y: 50 },
+                size: Uxy { x: 250, y: 75 },
+                header: None,
+            },
+        ];
+
+        let svg = render_svg(&topology, &topology.base.options);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains("<title>Home &amp; Lab</title>"));
+        // Canvas covers the subnet label above the container plus padding
+        assert!(svg.contains(r#"viewBox="60 120 680 420""#));
+    }
+}
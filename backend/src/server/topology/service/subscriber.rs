@@ -11,7 +11,7 @@ use crate::server::{
         services::traits::CrudService,
         storage::filter::EntityFilter as StorageFilter,
     },
-    topology::service::main::TopologyService,
+    topology::{service::main::TopologyService, types::base::SetEntitiesParams},
 };
 use anyhow::Error;
 use async_trait::async_trait;
@@ -49,6 +49,7 @@ impl EventSubscriber for TopologyService {
             (EntityDiscriminants::Group, None),
             (EntityDiscriminants::Port, None),
             (EntityDiscriminants::Binding, None),
+            (EntityDiscriminants::Traceroute, None),
             (
                 EntityDiscriminants::Topology,
                 Some(vec![EntityOperation::Created, EntityOperation::Updated]),
@@ -85,6 +86,11 @@ impl EventSubscriber for TopologyService {
                     .and_then(|v| serde_json::from_value::<bool>(v.clone()).ok())
                     .unwrap_or(false);
 
+                // Traceroutes aren't stored on the topology, so only a changed path matters
+                if matches!(entity_event.entity_type, Entity::Traceroute(_)) && !trigger_stale {
+                    continue;
+                }
+
                 // Topology updates from changes to options should be applied immediately and not processed alongside
                 // other changes, otherwise another call to topology_service.update will be made which will trigger
                 // an infinite loop
@@ -96,10 +102,24 @@ impl EventSubscriber for TopologyService {
                         topology.base.is_stale = true;
                     }
 
-                    topology.base.services = self
-                        .get_service_data(network_id, &topology.base.options)
+                    // Options decide which entities are in the topology
+                    let (hosts, interfaces, subnets, groups, ports, bindings) = self
+                        .get_entity_data(network_id, &topology.base.options)
+                        .await?;
+                    let services = self
+                        .get_service_data(network_id, &topology.base.options, &hosts)
                         .await?;
 
+                    topology.set_entities(SetEntitiesParams {
+                        hosts,
+                        services,
+                        interfaces,
+                        subnets,
+                        groups,
+                        ports,
+                        bindings,
+                    });
+
                     let _ = self.staleness_tx.send(topology).inspect_err(|e| {
                         tracing::debug!("Staleness notification skipped (no receivers): {}", e)
                     });
@@ -156,13 +176,14 @@ impl EventSubscriber for TopologyService {
             let network_filter = StorageFilter::unfiltered().network_ids(&[network_id]);
             let topologies = self.get_all(network_filter).await?;
 
-            let (hosts, interfaces, subnets, groups, ports, bindings) =
-                self.get_entity_data(network_id).await?;
-
             if let Some(changes) = topology_updates.get(&network_id) {
                 for mut topology in topologies {
+                    // Each topology filters the network its own way
+                    let (hosts, interfaces, subnets, groups, ports, bindings) = self
+                        .get_entity_data(network_id, &topology.base.options)
+                        .await?;
                     let services = self
-                        .get_service_data(network_id, &topology.base.options)
+                        .get_service_data(network_id, &topology.base.options, &hosts)
                         .await?;
 
                     // Apply removed entities
@@ -213,11 +234,11 @@ impl EventSubscriber for TopologyService {
                     }
 
                     if changes.updated_hosts {
-                        topology.base.hosts = hosts.clone()
+                        topology.base.hosts = hosts
                     }
 
                     if changes.updated_interfaces {
-                        topology.base.interfaces = interfaces.clone()
+                        topology.base.interfaces = interfaces
                     }
 
                     if changes.updated_services {
@@ -225,19 +246,19 @@ impl EventSubscriber for TopologyService {
                     }
 
                     if changes.updated_subnets {
-                        topology.base.subnets = subnets.clone()
+                        topology.base.subnets = subnets
                     }
 
                     if changes.updated_groups {
-                        topology.base.groups = groups.clone();
+                        topology.base.groups = groups;
                     }
 
                     if changes.updated_ports {
-                        topology.base.ports = ports.clone();
+                        topology.base.ports = ports;
                     }
 
                     if changes.updated_bindings {
-                        topology.base.bindings = bindings.clone();
+                        topology.base.bindings = bindings;
                     }
 
                     // Update topology in database
@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::server::{
+    groups::r#impl::base::Group,
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    ports::r#impl::base::Port,
+    services::r#impl::{base::Service, definitions::ServiceDefinition},
+    shared::types::{
+        Color,
+        metadata::{EntityMetadataProvider, TypeMetadataProvider},
+    },
+    subnets::r#impl::base::Subnet,
+    topology::types::{
+        base::{Topology, TopologyOptions},
+        edges::{Edge, EdgeHandle, EdgeStyle, EdgeType, EdgeTypeDiscriminants},
+        nodes::{Node, NodeType},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    pub fn handle(&self, handle: EdgeHandle) -> Point {
+        match handle {
+            EdgeHandle::Top => Point {
+                x: self.x + self.width / 2.0,
+                y: self.y,
+            },
+            EdgeHandle::Bottom => Point {
+                x: self.x + self.width / 2.0,
+                y: self.bottom(),
+            },
+            EdgeHandle::Left => Point {
+                x: self.x,
+                y: self.y + self.height / 2.0,
+            },
+            EdgeHandle::Right => Point {
+                x: self.right(),
+                y: self.y + self.height / 2.0,
+            },
+        }
+    }
+}
+
+/// How an edge is drawn, after group overrides
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeAppearance {
+    pub color: Color,
+    pub style: EdgeStyle,
+    pub is_dashed: bool,
+    pub has_end_marker: bool,
+}
+
+/// Read-only view over a built topology with the lookups shared by the renderer and the
+/// exporters. Options only hide things; positions are the ones the layout computed.
+pub struct TopologyView<'a> {
+    pub topology: &'a Topology,
+    pub options: &'a TopologyOptions,
+    /// Absolute bounds of every node, keyed by node id
+    pub bounds: HashMap<Uuid, Rect>,
+    pub hosts: HashMap<Uuid, &'a Host>,
+    pub interfaces: HashMap<Uuid, &'a Interface>,
+    pub subnets: HashMap<Uuid, &'a Subnet>,
+    pub services: HashMap<Uuid, &'a Service>,
+    pub ports: HashMap<Uuid, &'a Port>,
+    pub groups: HashMap<Uuid, &'a Group>,
+}
+
+impl<'a> TopologyView<'a> {
+    pub fn new(topology: &'a Topology, options: &'a TopologyOptions) -> Self {
+        let nodes = &topology.base.nodes;
+
+        let mut bounds: HashMap<Uuid, Rect> = HashMap::new();
+        for node in nodes {
+            let parent = node
+                .parent_id()
+                .and_then(|parent_id| nodes.iter().find(|n| n.id == parent_id));
+
+            // Interface nodes are positioned relative to their subnet, service nodes to
+            // their host when they are drawn inside it
+            let (offset_x, offset_y) = parent
+                .map(|p| (p.position.x as f64, p.position.y as f64))
+                .unwrap_or((0.0, 0.0));
+
+            bounds.insert(
+                node.id,
+                Rect {
+                    x: offset_x + node.position.x as f64,
+                    y: offset_y + node.position.y as f64,
+                    width: node.size.x as f64,
+                    height: node.size.y as f64,
+                },
+            );
+        }
+
+        Self {
+            topology,
+            options,
+            bounds,
+            hosts: topology.base.hosts.iter().map(|h| (h.id, h)).collect(),
+            interfaces: topology.base.interfaces.iter().map(|i| (i.id, i)).collect(),
+            subnets: topology.base.subnets.iter().map(|s| (s.id, s)).collect(),
+            services: topology.base.services.iter().map(|s| (s.id, s)).collect(),
+            ports: topology.base.ports.iter().map(|p| (p.id, p)).collect(),
+            groups: topology.base.groups.iter().map(|g| (g.id, g)).collect(),
+        }
+    }
+
+    pub fn subnet_nodes(&self) -> Vec<&'a Node> {
+        self.topology
+            .base
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::SubnetNode { .. }))
+            .collect()
+    }
+
+    pub fn interface_nodes(&self) -> Vec<&'a Node> {
+        self.topology
+            .base
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::InterfaceNode { .. }))
+            .collect()
+    }
+
+    pub fn host_nodes(&self) -> Vec<&'a Node> {
+        self.topology
+            .base
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::HostNode { .. }))
+            .collect()
+    }
+
+    pub fn service_nodes(&self) -> Vec<&'a Node> {
+        self.topology
+            .base
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::ServiceNode { .. }))
+            .collect()
+    }
+
+    pub fn stub_nodes(&self) -> Vec<&'a Node> {
+        self.topology
+            .base
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.node_type, NodeType::StubNode { .. }))
+            .collect()
+    }
+
+    /// Whether other nodes are positioned inside this one
+    pub fn has_children(&self, node: &Node) -> bool {
+        self.topology
+            .base
+            .nodes
+            .iter()
+            .any(|n| n.parent_id() == Some(node.id))
+    }
+
+    /// Edges not hidden by type whose endpoints are both in the graph
+    pub fn visible_edges(&self) -> Vec<&'a Edge> {
+        self.topology
+            .base
+            .edges
+            .iter()
+            .filter(|e| {
+                !self
+                    .options
+                    .local
+                    .hide_edge_types
+                    .contains(&EdgeTypeDiscriminants::from(&e.edge_type))
+                    && self.bounds.contains_key(&e.source)
+                    && self.bounds.contains_key(&e.target)
+            })
+            .collect()
+    }
+
+    /// The group a request path or hub-and-spoke edge belongs to
+    pub fn edge_group(&self, edge: &Edge) -> Option<&'a Group> {
+        match &edge.edge_type {
+            EdgeType::RequestPath { group_id, .. } | EdgeType::HubAndSpoke { group_id, .. } => {
+                self.groups.get(group_id).copied()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn edge_appearance(&self, edge: &Edge) -> EdgeAppearance {
+        let metadata = edge.edge_type.metadata();
+        let metadata_style = match metadata["edge_style"].as_str() {
+            Some("Straight") => EdgeStyle::Straight,
+            Some("Step") => EdgeStyle::Step,
+            Some("Bezier") => EdgeStyle::Bezier,
+            Some("SimpleBezier") => EdgeStyle::SimpleBezier,
+            _ => EdgeStyle::SmoothStep,
+        };
+
+        // Group edges take the color and style configured on the group
+        let group = self.edge_group(edge);
+
+        EdgeAppearance {
+            color: group
+                .map(|g| g.base.color)
+                .unwrap_or_else(|| edge.edge_type.color()),
+            style: group.map(|g| g.base.edge_style).unwrap_or(metadata_style),
+            is_dashed: metadata["is_dashed"].as_bool().unwrap_or(false),
+            has_end_marker: metadata["has_end_marker"].as_bool().unwrap_or(false),
+        }
+    }
+
+    /// Subnet title as shown above the container: the node header if the layout set one,
+    /// otherwise the subnet name (or type) and CIDR
+    pub fn subnet_label(&self, node: &Node) -> Option<String> {
+        if let Some(header) = &node.header {
+            return Some(header.clone());
+        }
+
+        let subnet = self.subnets.get(&node.id)?;
+        let cidr = subnet.base.cidr.to_string();
+        let name = if subnet.base.name != cidr {
+            subnet.base.name.clone()
+        } else {
+            subnet.base.subnet_type.name().to_string()
+        };
+
+        Some(if subnet.is_organizational_subnet() {
+            name
+        } else {
+            format!("{}: {}", name, cidr)
+        })
+    }
+
+    /// Interface name and address shown in an interface node's footer. Organizational
+    /// subnets have no meaningful address, so they get none.
+    pub fn interface_label(&self, subnet_id: Uuid, interface_id: Option<Uuid>) -> Option<String> {
+        let is_organizational = self
+            .subnets
+            .get(&subnet_id)
+            .is_some_and(|s| s.is_organizational_subnet());
+
+        interface_id
+            .and_then(|id| self.interfaces.get(&id))
+            .filter(|_| !is_organizational)
+            .map(|i| match &i.base.name {
+                Some(name) => format!("{}: {}", name, i.base.ip_address),
+                None => i.base.ip_address.to_string(),
+            })
+    }
+
+    /// Services shown on an interface node, in the order the UI lists them
+    pub fn services_for_node(&self, host_id: Uuid, interface_id: Option<Uuid>) -> Vec<&'a Service> {
+        let hidden = &self.options.request.hide_service_categories;
+
+        self.topology
+            .base
+            .services
+            .iter()
+            .filter(|s| s.base.host_id == host_id)
+            .filter(|s| !hidden.contains(&ServiceDefinition::category(&s.base.service_definition)))
+            .filter(|s| {
+                s.base.bindings.iter().any(|b| {
+                    b.interface_id().is_none()
+                        || (interface_id.is_some() && b.interface_id() == interface_id)
+                })
+            })
+            .collect()
+    }
+
+    /// Port labels such as `443/tcp` for a service's bindings on an interface. Empty when
+    /// ports are hidden.
+    pub fn ports_for_service(&self, service: &Service, interface_id: Option<Uuid>) -> Vec<String> {
+        if self.options.request.hide_ports {
+            return Vec::new();
+        }
+
+        service
+            .base
+            .bindings
+            .iter()
+            .filter(|b| b.interface_id().is_none() || b.interface_id() == interface_id)
+            .filter_map(|b| b.port_id())
+            .filter_map(|id| self.ports.get(&id))
+            .map(|p| p.base.port_type.to_string())
+            .collect()
+    }
+}
+
+pub fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\n' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::topology::types::layout::{Ixy, Uxy};
+
+    #[test]
+    fn test_view_positions_children_relative_to_subnet() {
+        let subnet_id = Uuid::new_v4();
+        let interface_node = Uuid::new_v4();
+
+        let mut topology = Topology::default();
+        topology.base.nodes = vec![
+            Node {
+                node_type: NodeType::SubnetNode { infra_width: 0 },
+                id: subnet_id,
+                position: Ixy { x: 100, y: 200 },
+                size: Uxy { x: 600, y: 300 },
+                header: None,
+            },
+            Node {
+                node_type: NodeType::InterfaceNode {
+                    subnet_id,
+                    host_id: Uuid::new_v4(),
+                    interface_id: None,
+                    is_infra: false,
+                },
+                id: interface_node,
+                position: Ixy { x: 25, y: 50 },
+                size: Uxy { x: 250, y: 75 },
+                header: None,
+            },
+        ];
+
+        let view = TopologyView::new(&topology, &topology.base.options);
+        assert_eq!(
+            view.bounds[&interface_node],
+            Rect {
+                x: 125.0,
+                y: 250.0,
+                width: 250.0,
+                height: 75.0
+            }
+        );
+        assert_eq!(view.subnet_nodes().len(), 1);
+        assert_eq!(view.interface_nodes().len(), 1);
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a<b & \"c\"\u{7}"), "a&lt;b &amp; &quot;c&quot;");
+    }
+}
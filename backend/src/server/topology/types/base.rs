@@ -9,6 +9,7 @@ use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::subnets::r#impl::base::Subnet;
 use crate::server::topology::types::edges::Edge;
 use crate::server::topology::types::edges::EdgeTypeDiscriminants;
+use crate::server::topology::types::filter::TopologyFilter;
 use crate::server::topology::types::nodes::Node;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -214,6 +215,31 @@ pub struct TopologyRequestOptions {
     pub left_zone_service_categories: Vec<ServiceCategory>,
     pub hide_service_categories: Vec<ServiceCategory>,
     pub show_gateway_in_left_zone: bool,
+    #[serde(default)]
+    #[schema(required)]
+    pub layout_mode: TopologyLayoutMode,
+    /// Host shown by the host focus layout. Nothing is drawn in that mode until one is set.
+    #[serde(default)]
+    #[schema(required)]
+    pub focus_host_id: Option<Uuid>,
+    /// Which part of the network to draw. Applied to entities before anything is laid out.
+    #[serde(default)]
+    #[schema(required)]
+    pub filter: TopologyFilter,
+}
+
+/// How the graph is built and laid out
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ToSchema)]
+pub enum TopologyLayoutMode {
+    /// Subnets as containers with interface nodes inside
+    #[default]
+    Subnets,
+    /// Gateways and routers as nodes with the subnets they route hanging off them
+    Routed,
+    /// Services only, layered by the groups that connect them. Hosts are collapsed.
+    ServiceDependencies,
+    /// One host with its interfaces, services and containers, and the hosts it talks to
+    HostFocus,
 }
 
 impl Default for TopologyRequestOptions {
@@ -225,6 +251,9 @@ impl Default for TopologyRequestOptions {
             left_zone_service_categories: vec![ServiceCategory::DNS, ServiceCategory::ReverseProxy],
             hide_service_categories: Vec::new(),
             show_gateway_in_left_zone: true,
+            layout_mode: TopologyLayoutMode::default(),
+            focus_host_id: None,
+            filter: TopologyFilter::default(),
         }
     }
 }
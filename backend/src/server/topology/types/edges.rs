@@ -282,6 +282,24 @@ pub enum EdgeType {
         source_binding_id: Uuid,
         target_binding_id: Uuid,
     },
+    /// The same remote or VPN subnet seen from two networks
+    SharedSubnet {
+        source_subnet_id: Uuid,
+        target_subnet_id: Uuid,
+    },
+    /// The same host, matched by MAC address or hostname, found in two networks
+    SharedHost {
+        source_host_id: Uuid,
+        target_host_id: Uuid,
+    },
+    /// A link between two networks added by a user
+    NetworkLink {
+        network_link_id: Uuid,
+    },
+    /// One leg of a path measured by traceroute, from one hop to the next
+    Route {
+        traceroute_id: Uuid,
+    },
 }
 
 impl HasId for EdgeType {
@@ -298,6 +316,10 @@ impl EntityMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => EntityDiscriminants::Host.color(),
             EdgeType::HostVirtualization { .. } => Concept::Virtualization.color(),
             EdgeType::ServiceVirtualization { .. } => Concept::Virtualization.color(),
+            EdgeType::SharedSubnet { .. } => EntityDiscriminants::Subnet.color(),
+            EdgeType::SharedHost { .. } => EntityDiscriminants::Host.color(),
+            EdgeType::NetworkLink { .. } => EntityDiscriminants::NetworkLink.color(),
+            EdgeType::Route { .. } => EntityDiscriminants::Traceroute.color(),
         }
     }
 
@@ -308,6 +330,10 @@ impl EntityMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => EntityDiscriminants::Host.icon(),
             EdgeType::HostVirtualization { .. } => Concept::Virtualization.icon(),
             EdgeType::ServiceVirtualization { .. } => Concept::Virtualization.icon(),
+            EdgeType::SharedSubnet { .. } => EntityDiscriminants::Subnet.icon(),
+            EdgeType::SharedHost { .. } => EntityDiscriminants::Host.icon(),
+            EdgeType::NetworkLink { .. } => EntityDiscriminants::NetworkLink.icon(),
+            EdgeType::Route { .. } => EntityDiscriminants::Traceroute.icon(),
         }
     }
 }
@@ -320,6 +346,10 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => "Host Interface",
             EdgeType::HostVirtualization { .. } => "Virtualized Host",
             EdgeType::ServiceVirtualization { .. } => "Virtualized Service",
+            EdgeType::SharedSubnet { .. } => "Shared Subnet",
+            EdgeType::SharedHost { .. } => "Shared Host",
+            EdgeType::NetworkLink { .. } => "Network Link",
+            EdgeType::Route { .. } => "Route",
         }
     }
 
@@ -330,6 +360,10 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => EdgeStyle::SmoothStep.into(),
             EdgeType::HostVirtualization { .. } => EdgeStyle::Straight.into(),
             EdgeType::ServiceVirtualization { .. } => EdgeStyle::SmoothStep.into(),
+            EdgeType::SharedSubnet { .. } => EdgeStyle::Bezier.into(),
+            EdgeType::SharedHost { .. } => EdgeStyle::Bezier.into(),
+            EdgeType::NetworkLink { .. } => EdgeStyle::Straight.into(),
+            EdgeType::Route { .. } => EdgeStyle::Bezier.into(),
         };
 
         let is_dashed = match &self {
@@ -338,6 +372,10 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => true,
             EdgeType::HostVirtualization { .. } => true,
             EdgeType::ServiceVirtualization { .. } => true,
+            EdgeType::SharedSubnet { .. } => true,
+            EdgeType::SharedHost { .. } => true,
+            EdgeType::NetworkLink { .. } => false,
+            EdgeType::Route { .. } => true,
         };
 
         let has_start_marker = false;
@@ -348,6 +386,10 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => false,
             EdgeType::HostVirtualization { .. } => false,
             EdgeType::ServiceVirtualization { .. } => false,
+            EdgeType::SharedSubnet { .. } => false,
+            EdgeType::SharedHost { .. } => false,
+            EdgeType::NetworkLink { .. } => false,
+            EdgeType::Route { .. } => true,
         };
 
         let is_host_edge = matches!(
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::server::topology::types::base::TopologyOptions;
+
+/// Diagram format for editing in other tools: Graphviz DOT, draw.io, Mermaid flowchart or
+/// GraphML
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Dot,
+    Drawio,
+    Mermaid,
+    GraphMl,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Dot => "text/vnd.graphviz",
+            ExportFormat::Drawio => "application/vnd.jgraph.mxfile",
+            ExportFormat::Mermaid => "text/vnd.mermaid",
+            ExportFormat::GraphMl => "application/graphml+xml",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Dot => "dot",
+            ExportFormat::Drawio => "drawio",
+            ExportFormat::Mermaid => "mmd",
+            ExportFormat::GraphMl => "graphml",
+        }
+    }
+}
+
+/// Export a stored topology as an editable diagram
+#[derive(Deserialize, Debug, Clone, Default, ToSchema)]
+pub struct ExportTopologyRequest {
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Overrides the options saved with the topology. Edge type and service category filters
+    /// and `hide_ports` are applied to the stored graph; layout is not recomputed.
+    #[serde(default)]
+    pub options: Option<TopologyOptions>,
+}
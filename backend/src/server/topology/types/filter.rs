@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    hosts::r#impl::base::Host,
+    services::r#impl::{base::Service, categories::ServiceCategory},
+    subnets::r#impl::{base::Subnet, types::SubnetType},
+};
+
+/// Narrows a topology to a slice of its network. Every criterion is optional: empty lists and
+/// an empty search leave that criterion out.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(default)]
+pub struct TopologyFilter {
+    /// Only hosts with at least one of these tags
+    pub include_tag_ids: Vec<Uuid>,
+    /// Hosts and services with any of these tags are left out
+    pub exclude_tag_ids: Vec<Uuid>,
+    /// Only these subnets, and hosts with an interface on one of them
+    pub subnet_ids: Vec<Uuid>,
+    /// Only subnets of these types, and hosts with an interface on one of them
+    pub subnet_types: Vec<SubnetType>,
+    /// Only services in these categories
+    pub service_categories: Vec<ServiceCategory>,
+    /// Case-insensitive match against host names, hostnames and service names. A host matching
+    /// the search keeps all of its services; otherwise only matching services are kept.
+    pub search: Option<String>,
+}
+
+impl TopologyFilter {
+    pub fn is_active(&self) -> bool {
+        !self.include_tag_ids.is_empty()
+            || !self.exclude_tag_ids.is_empty()
+            || self.filters_subnets()
+            || !self.service_categories.is_empty()
+            || self.search_term().is_some()
+    }
+
+    /// Whether hosts need an interface on a matching subnet to be kept
+    pub fn filters_subnets(&self) -> bool {
+        !self.subnet_ids.is_empty() || !self.subnet_types.is_empty()
+    }
+
+    pub fn subnet_matches(&self, subnet: &Subnet) -> bool {
+        (self.subnet_ids.is_empty() || self.subnet_ids.contains(&subnet.id))
+            && (self.subnet_types.is_empty()
+                || self.subnet_types.contains(&subnet.base.subnet_type))
+    }
+
+    /// Tags and search only. `services` may hold the whole network's services; only those on
+    /// this host are searched.
+    pub fn host_matches(&self, host: &Host, services: &[Service]) -> bool {
+        if self.has_excluded_tag(&host.base.tags) {
+            return false;
+        }
+
+        if !self.include_tag_ids.is_empty()
+            && !host
+                .base
+                .tags
+                .iter()
+                .any(|t| self.include_tag_ids.contains(t))
+        {
+            return false;
+        }
+
+        match self.search_term() {
+            Some(term) => {
+                Self::host_name_contains(host, &term)
+                    || services
+                        .iter()
+                        .filter(|s| s.base.host_id == host.id)
+                        .any(|s| s.base.name.to_lowercase().contains(&term))
+            }
+            None => true,
+        }
+    }
+
+    /// Tags, category and search. `host` is the service's host, if it is in the topology.
+    pub fn service_matches(&self, service: &Service, host: Option<&Host>) -> bool {
+        if self.has_excluded_tag(&service.base.tags) {
+            return false;
+        }
+
+        if !self.service_categories.is_empty()
+            && !self
+                .service_categories
+                .contains(&service.base.service_definition.category())
+        {
+            return false;
+        }
+
+        match self.search_term() {
+            Some(term) => {
+                service.base.name.to_lowercase().contains(&term)
+                    || host.is_some_and(|h| Self::host_name_contains(h, &term))
+            }
+            None => true,
+        }
+    }
+
+    /// The search, trimmed and lowercased, if there is anything to search for
+    pub fn search_term(&self) -> Option<String> {
+        self.search
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_lowercase)
+    }
+
+    fn has_excluded_tag(&self, tags: &[Uuid]) -> bool {
+        tags.iter().any(|t| self.exclude_tag_ids.contains(t))
+    }
+
+    fn host_name_contains(host: &Host, term: &str) -> bool {
+        host.base.name.to_lowercase().contains(term)
+            || host
+                .base
+                .hostname
+                .as_ref()
+                .is_some_and(|h| h.to_lowercase().contains(term))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests;
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let network_id = Uuid::new_v4();
+        let filter = TopologyFilter::default();
+        let host = tests::host(&network_id);
+        let service = tests::service(&network_id, &host.id);
+
+        assert!(!filter.is_active());
+        assert!(filter.subnet_matches(&tests::subnet(&network_id)));
+        assert!(filter.host_matches(&host, std::slice::from_ref(&service)));
+        assert!(filter.service_matches(&service, Some(&host)));
+    }
+
+    #[test]
+    fn test_tags_include_and_exclude_hosts() {
+        let network_id = Uuid::new_v4();
+        let (production, legacy) = (Uuid::new_v4(), Uuid::new_v4());
+        let filter = TopologyFilter {
+            include_tag_ids: vec![production],
+            exclude_tag_ids: vec![legacy],
+            ..Default::default()
+        };
+
+        let mut tagged = tests::host(&network_id);
+        tagged.base.tags = vec![production];
+        let mut both = tests::host(&network_id);
+        both.base.tags = vec![production, legacy];
+        let untagged = tests::host(&network_id);
+
+        assert!(filter.host_matches(&tagged, &[]));
+        assert!(!filter.host_matches(&both, &[]));
+        assert!(!filter.host_matches(&untagged, &[]));
+
+        let mut service = tests::service(&network_id, &tagged.id);
+        assert!(filter.service_matches(&service, Some(&tagged)));
+        service.base.tags = vec![legacy];
+        assert!(!filter.service_matches(&service, Some(&tagged)));
+    }
+
+    #[test]
+    fn test_search_keeps_matching_hosts_and_services() {
+        let network_id = Uuid::new_v4();
+        let filter = TopologyFilter {
+            search: Some("  Postgres ".to_string()),
+            ..Default::default()
+        };
+
+        let mut db_host = tests::host(&network_id);
+        db_host.base.name = "postgres-01".to_string();
+        let mut app_host = tests::host(&network_id);
+        app_host.base.name = "app-01".to_string();
+
+        let mut app_service = tests::service(&network_id, &app_host.id);
+        app_service.base.name = "Web".to_string();
+        let mut app_db = tests::service(&network_id, &app_host.id);
+        app_db.base.name = "PostgreSQL".to_string();
+        let mut db_service = tests::service(&network_id, &db_host.id);
+        db_service.base.name = "Exporter".to_string();
+
+        let services = vec![app_service.clone(), app_db.clone(), db_service.clone()];
+
+        assert!(filter.is_active());
+        assert!(filter.host_matches(&db_host, &services));
+        assert!(filter.host_matches(&app_host, &services));
+
+        // Every service on a matching host, only matching services elsewhere
+        assert!(filter.service_matches(&db_service, Some(&db_host)));
+        assert!(filter.service_matches(&app_db, Some(&app_host)));
+        assert!(!filter.service_matches(&app_service, Some(&app_host)));
+    }
+
+    #[test]
+    fn test_subnet_ids_and_types_both_apply() {
+        let network_id = Uuid::new_v4();
+        let lan = tests::subnet(&network_id);
+        let mut dmz = tests::subnet(&network_id);
+        dmz.base.subnet_type = SubnetType::Dmz;
+
+        let filter = TopologyFilter {
+            subnet_ids: vec![lan.id, dmz.id],
+            subnet_types: vec![SubnetType::Dmz],
+            ..Default::default()
+        };
+
+        assert!(filter.filters_subnets());
+        assert!(!filter.subnet_matches(&lan));
+        assert!(filter.subnet_matches(&dmz));
+        assert!(!filter.subnet_matches(&tests::subnet(&network_id)));
+    }
+}
@@ -2,6 +2,7 @@ use crate::server::{
     config::AppState,
     shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
     topology::{service::main::TopologyService, types::base::Topology},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
 };
 
 impl CrudHandlers for Topology {
@@ -12,3 +13,7 @@ impl CrudHandlers for Topology {
         &state.services.topology_service
     }
 }
+
+impl ScopedResource for Topology {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Topology;
+}
@@ -26,6 +26,20 @@ impl Uxy {
         }
     }
 
+    /// Size of a node showing a single service, with room for a header if it has one
+    pub fn service_node_size(has_header: bool) -> Self {
+        Self {
+            x: SUBNET_CHILD_WIDTH,
+            y: HEIGHT_PER_SERVICE_IN_SUBNET_CHILD
+                + SUBNET_CHILD_FOOTER_HEIGHT
+                + if has_header {
+                    SUBNET_CHILD_HEADER_HEIGHT
+                } else {
+                    0
+                },
+        }
+    }
+
     pub fn subnet_child_size_from_service_count(
         services: &[&Service],
         interface_id: Uuid,
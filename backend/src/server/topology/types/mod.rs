@@ -1,7 +1,10 @@
 pub mod api;
 pub mod base;
 pub mod edges;
+pub mod export;
+pub mod filter;
 pub mod handlers;
 pub mod layout;
 pub mod nodes;
+pub mod render;
 pub mod storage;
@@ -6,6 +6,9 @@ use strum_macros::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Text shown on stub nodes wherever topologies are drawn
+pub const STUB_LABEL: &str = "Filtered out";
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, ToSchema)]
 pub struct Node {
     #[serde(flatten)]
@@ -40,6 +43,52 @@ pub enum NodeType {
         interface_id: Option<Uuid>,
         is_infra: bool,
     },
+    /// A whole host as one node: a router in the routed layout, or the focused host and its
+    /// neighbours in the host focus layout
+    HostNode {
+        host_id: Uuid,
+    },
+    /// A single service. Placed inside its host's node when `parent_id` is set.
+    ServiceNode {
+        service_id: Uuid,
+        host_id: Uuid,
+        parent_id: Option<Uuid>,
+    },
+    /// Stands in for a group binding whose service was filtered out of the topology, so the
+    /// group's edge still has somewhere to go
+    StubNode {
+        binding_id: Uuid,
+    },
+    /// A network as a container in the organization topology
+    NetworkNode {
+        network_id: Uuid,
+    },
+    /// A remote or VPN subnet, inside its network, that another network shares
+    NetworkSubnetNode {
+        network_id: Uuid,
+        subnet_id: Uuid,
+    },
+    /// A host, inside its network, that also turns up in another network
+    NetworkHostNode {
+        network_id: Uuid,
+        host_id: Uuid,
+    },
+}
+
+impl Node {
+    /// Node this one is positioned relative to
+    pub fn parent_id(&self) -> Option<Uuid> {
+        match self.node_type {
+            NodeType::InterfaceNode { subnet_id, .. } => Some(subnet_id),
+            NodeType::ServiceNode { parent_id, .. } => parent_id,
+            NodeType::NetworkSubnetNode { network_id, .. }
+            | NodeType::NetworkHostNode { network_id, .. } => Some(network_id),
+            NodeType::SubnetNode { .. }
+            | NodeType::HostNode { .. }
+            | NodeType::StubNode { .. }
+            | NodeType::NetworkNode { .. } => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
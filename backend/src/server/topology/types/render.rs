@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::server::{shared::types::api::ValidationError, topology::types::base::TopologyOptions};
+
+/// Smallest and largest rasterization scale accepted by the render endpoints
+pub const MIN_RENDER_SCALE: f32 = 0.25;
+pub const MAX_RENDER_SCALE: f32 = 4.0;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderFormat {
+    #[default]
+    Svg,
+    Png,
+    Pdf,
+}
+
+impl RenderFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            RenderFormat::Svg => "image/svg+xml",
+            RenderFormat::Png => "image/png",
+            RenderFormat::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RenderFormat::Svg => "svg",
+            RenderFormat::Png => "png",
+            RenderFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// Paper size for PDF output. Pages are landscape; diagrams larger than one page are split
+/// across several.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ToSchema)]
+pub enum RenderPageSize {
+    #[default]
+    A4,
+    A3,
+    Letter,
+}
+
+impl RenderPageSize {
+    /// Landscape (width, height) in PDF points
+    pub fn dimensions(&self) -> (f32, f32) {
+        match self {
+            RenderPageSize::A4 => (842.0, 595.0),
+            RenderPageSize::A3 => (1191.0, 842.0),
+            RenderPageSize::Letter => (792.0, 612.0),
+        }
+    }
+}
+
+/// Render a stored topology to an image
+#[derive(Deserialize, Debug, Clone, Default, ToSchema)]
+pub struct RenderTopologyRequest {
+    #[serde(default)]
+    pub format: RenderFormat,
+    /// Overrides the options saved with the topology. Edge type and service category filters
+    /// and `hide_ports` are applied to the stored graph; layout is not recomputed.
+    #[serde(default)]
+    pub options: Option<TopologyOptions>,
+    /// Pixel density for PNG and PDF output. Defaults to 2.
+    #[serde(default)]
+    pub scale: Option<f32>,
+    #[serde(default)]
+    pub page_size: RenderPageSize,
+}
+
+/// Query parameters for static share images
+#[derive(Deserialize, Debug, Clone, Default, IntoParams)]
+pub struct RenderQuery {
+    /// Output format. Defaults to SVG.
+    #[serde(default)]
+    pub format: RenderFormat,
+    /// Pixel density for PNG and PDF output. Defaults to 2.
+    #[serde(default)]
+    pub scale: Option<f32>,
+    /// Paper size for PDF output. Defaults to A4.
+    #[serde(default)]
+    pub page_size: RenderPageSize,
+}
+
+/// Everything the renderer needs besides the topology itself
+#[derive(Debug, Clone)]
+pub struct RenderParams {
+    pub format: RenderFormat,
+    pub options: TopologyOptions,
+    pub scale: f32,
+    pub page_size: RenderPageSize,
+}
+
+impl RenderParams {
+    pub const DEFAULT_SCALE: f32 = 2.0;
+
+    pub fn new(
+        format: RenderFormat,
+        options: TopologyOptions,
+        scale: Option<f32>,
+        page_size: RenderPageSize,
+    ) -> Result<Self, ValidationError> {
+        let scale = scale.unwrap_or(Self::DEFAULT_SCALE);
+        if !(MIN_RENDER_SCALE..=MAX_RENDER_SCALE).contains(&scale) {
+            return Err(ValidationError::new(format!(
+                "Scale must be between {} and {}",
+                MIN_RENDER_SCALE, MAX_RENDER_SCALE
+            )));
+        }
+
+        Ok(Self {
+            format,
+            options,
+            scale,
+            page_size,
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State};
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::server::{
+    auth::middleware::permissions::{Authorized, IsDaemon},
+    config::AppState,
+    shared::{
+        services::traits::CrudService,
+        storage::filter::EntityFilter,
+        types::api::{ApiError, ApiErrorResponse, ApiResponse, ApiResult},
+    },
+    traceroutes::r#impl::base::Traceroute,
+};
+
+// Generated handlers for generic CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_get_all_handler!(Traceroute, "traceroutes", "traceroute");
+    crate::crud_get_by_id_handler!(Traceroute, "traceroutes", "traceroute");
+    crate::crud_delete_handler!(Traceroute, "traceroutes", "traceroute");
+    crate::crud_bulk_delete_handler!(Traceroute, "traceroutes");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(generated::get_all))
+        .routes(routes!(generated::get_by_id, generated::delete))
+        .routes(routes!(generated::bulk_delete))
+        .routes(routes!(create_traceroute_discovery))
+}
+
+/// Internal endpoint for daemon discovery
+///
+/// Used by daemons to report a traced path. A daemon keeps one traceroute per target,
+/// so tracing the same target again replaces the hops recorded last time.
+///
+/// Tagged as "internal" - included in OpenAPI spec for client generation
+/// but hidden from public documentation.
+#[utoipa::path(
+    post,
+    path = "/discovery",
+    tags = ["traceroutes", "internal"],
+    request_body = Traceroute,
+    responses(
+        (status = 200, description = "Traceroute discovered/updated successfully", body = ApiResponse<Traceroute>),
+        (status = 400, description = "Subnet is not on the daemon's network", body = ApiErrorResponse),
+        (status = 403, description = "Daemon cannot report traceroutes for other networks or daemons", body = ApiErrorResponse),
+    ),
+    security(("daemon_api_key" = []))
+)]
+async fn create_traceroute_discovery(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<IsDaemon>,
+    Json(traceroute): Json<Traceroute>,
+) -> ApiResult<Json<ApiResponse<Traceroute>>> {
+    let daemon_network_id = auth
+        .network_ids()
+        .first()
+        .copied()
+        .ok_or_else(|| ApiError::forbidden("Daemon has no network assignment"))?;
+    let daemon_id = auth.daemon_id().expect("IsDaemon ensures daemon_id exists");
+
+    if traceroute.base.network_id != daemon_network_id {
+        return Err(ApiError::forbidden(
+            "Daemon cannot report traceroutes on networks it's not assigned to",
+        ));
+    }
+
+    if traceroute.base.daemon_id != daemon_id {
+        return Err(ApiError::forbidden(
+            "Daemon cannot report traceroutes for another daemon",
+        ));
+    }
+
+    if let Some(subnet_id) = traceroute.base.subnet_id {
+        let subnet_filter = EntityFilter::unfiltered()
+            .entity_id(&subnet_id)
+            .network_ids(&[daemon_network_id]);
+        if state
+            .services
+            .subnet_service
+            .get_one(subnet_filter)
+            .await?
+            .is_none()
+        {
+            return Err(ApiError::bad_request(
+                "Traceroute references a subnet which is not on the daemon's network",
+            ));
+        }
+    }
+
+    let service = &state.services.traceroute_service;
+
+    let existing_filter = EntityFilter::unfiltered()
+        .network_ids(&[daemon_network_id])
+        .uuid_column("daemon_id", &daemon_id);
+    let existing = service
+        .get_all(existing_filter)
+        .await?
+        .into_iter()
+        .find(|t| t.base.target == traceroute.base.target);
+
+    let result = match existing {
+        Some(mut existing) => {
+            existing.base.subnet_id = traceroute.base.subnet_id;
+            existing.base.protocol = traceroute.base.protocol;
+            existing.base.hops = traceroute.base.hops;
+            existing.base.reached = traceroute.base.reached;
+            service.update(&mut existing, auth.into_entity()).await?
+        }
+        None => service.create(traceroute, auth.into_entity()).await?,
+    };
+
+    Ok(Json(ApiResponse::success(result)))
+}
@@ -0,0 +1,182 @@
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr};
+
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Kind of probe a traceroute sends. Routers answer all of them the same way, but firewalls
+/// often let one through where another is dropped.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Display,
+    EnumString,
+    ToSchema,
+)]
+pub enum TracerouteProtocol {
+    /// ICMP echo requests
+    #[default]
+    Icmp,
+    /// UDP datagrams to high ports, as classic traceroute sends
+    Udp,
+    /// TCP connection attempts to port 443
+    Tcp,
+}
+
+/// One TTL step of a traceroute
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct TracerouteHop {
+    pub ttl: u8,
+    /// Router that answered. Missing when nothing answered at this TTL.
+    #[schema(value_type = Option<String>)]
+    pub ip: Option<IpAddr>,
+    /// Round trip time in microseconds
+    pub rtt_us: Option<u32>,
+}
+
+#[derive(Debug, Clone, Validate, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct TracerouteBase {
+    pub network_id: Uuid,
+    /// Daemon the trace was run from
+    pub daemon_id: Uuid,
+    /// Subnet whose gateway was traced. Missing for external targets.
+    pub subnet_id: Option<Uuid>,
+    #[schema(value_type = String)]
+    pub target: IpAddr,
+    pub protocol: TracerouteProtocol,
+    pub hops: Vec<TracerouteHop>,
+    /// Whether the target itself answered
+    pub reached: bool,
+}
+
+impl Default for TracerouteBase {
+    fn default() -> Self {
+        Self {
+            network_id: Uuid::nil(),
+            daemon_id: Uuid::nil(),
+            subnet_id: None,
+            target: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            protocol: TracerouteProtocol::default(),
+            hops: Vec::new(),
+            reached: false,
+        }
+    }
+}
+
+/// The path from a daemon to a target as measured by traceroute
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema, Validate,
+)]
+pub struct Traceroute {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: TracerouteBase,
+}
+
+impl Traceroute {
+    fn hop_ips(&self) -> impl Iterator<Item = Option<IpAddr>> + '_ {
+        self.base.hops.iter().map(|hop| hop.ip)
+    }
+}
+
+// Round trip times change on every run, so only a different path marks topologies stale
+impl ChangeTriggersTopologyStaleness<Traceroute> for Traceroute {
+    fn triggers_staleness(&self, other: Option<Traceroute>) -> bool {
+        let Some(other) = other else {
+            return true;
+        };
+
+        self.base.reached != other.base.reached
+            || self.base.subnet_id != other.base.subnet_id
+            || !self.hop_ips().eq(other.hop_ips())
+    }
+}
+
+impl Display for Traceroute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Traceroute {}: {} via {} ({} hops)",
+            self.id,
+            self.base.target,
+            self.base.protocol,
+            self.base.hops.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hop(ttl: u8, ip: [u8; 4], rtt_us: u32) -> TracerouteHop {
+        TracerouteHop {
+            ttl,
+            ip: Some(IpAddr::V4(Ipv4Addr::from(ip))),
+            rtt_us: Some(rtt_us),
+        }
+    }
+
+    fn traceroute(hops: Vec<TracerouteHop>) -> Traceroute {
+        Traceroute {
+            base: TracerouteBase {
+                hops,
+                reached: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_latency_changes_do_not_trigger_staleness() {
+        let before = traceroute(vec![
+            hop(1, [10, 0, 0, 1], 900),
+            hop(2, [10, 1, 0, 1], 4000),
+        ]);
+        let after = traceroute(vec![
+            hop(1, [10, 0, 0, 1], 1100),
+            hop(2, [10, 1, 0, 1], 3500),
+        ]);
+
+        assert!(!after.triggers_staleness(Some(before)));
+    }
+
+    #[test]
+    fn test_path_changes_trigger_staleness() {
+        let before = traceroute(vec![
+            hop(1, [10, 0, 0, 1], 900),
+            hop(2, [10, 1, 0, 1], 4000),
+        ]);
+        let after = traceroute(vec![
+            hop(1, [10, 0, 0, 1], 900),
+            hop(2, [10, 2, 0, 1], 4000),
+        ]);
+
+        assert!(after.triggers_staleness(Some(before.clone())));
+        assert!(before.triggers_staleness(None));
+    }
+}
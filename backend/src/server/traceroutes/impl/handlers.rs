@@ -0,0 +1,20 @@
+use crate::server::{
+    config::AppState,
+    shared::handlers::{query::NetworkFilterQuery, traits::CrudHandlers},
+    traceroutes::{r#impl::base::Traceroute, service::TracerouteService},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
+};
+
+impl CrudHandlers for Traceroute {
+    type Service = TracerouteService;
+    type FilterQuery = NetworkFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.traceroute_service
+    }
+}
+
+// Traceroutes are only ever written by discovery
+impl ScopedResource for Traceroute {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Discovery;
+}
@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use ipnetwork::IpNetwork;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{SqlValue, StorableEntity},
+    },
+    traceroutes::r#impl::base::{Traceroute, TracerouteBase, TracerouteHop, TracerouteProtocol},
+};
+
+impl StorableEntity for Traceroute {
+    type BaseData = TracerouteBase;
+
+    fn table_name() -> &'static str {
+        "traceroutes"
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::Traceroute
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    network_id,
+                    daemon_id,
+                    subnet_id,
+                    target,
+                    protocol,
+                    hops,
+                    reached,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "network_id",
+                "daemon_id",
+                "subnet_id",
+                "target",
+                "protocol",
+                "hops",
+                "reached",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(network_id),
+                SqlValue::Uuid(daemon_id),
+                SqlValue::OptionalUuid(subnet_id),
+                SqlValue::IpAddr(target),
+                SqlValue::String(protocol.to_string()),
+                SqlValue::JsonValue(serde_json::to_value(hops)?),
+                SqlValue::Bool(reached),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let target: IpNetwork = row
+            .try_get("target")
+            .map_err(|e| anyhow::anyhow!("Failed to read target: {}", e))?;
+        let protocol = TracerouteProtocol::from_str(&row.get::<String, _>("protocol"))
+            .map_err(|e| anyhow::anyhow!("Failed to parse protocol: {}", e))?;
+        let hops: Vec<TracerouteHop> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("hops"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize hops: {}", e))?;
+
+        Ok(Traceroute {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: TracerouteBase {
+                network_id: row.get("network_id"),
+                daemon_id: row.get("daemon_id"),
+                subnet_id: row.get("subnet_id"),
+                target: target.ip(),
+                protocol,
+                hops,
+                reached: row.get("reached"),
+            },
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::server::{
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::generic::GenericPostgresStorage,
+    },
+    traceroutes::r#impl::base::Traceroute,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct TracerouteService {
+    storage: Arc<GenericPostgresStorage<Traceroute>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<Traceroute> for TracerouteService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &Traceroute) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+    fn get_organization_id(&self, _entity: &Traceroute) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<Traceroute> for TracerouteService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<Traceroute>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::shared::services::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl TracerouteService {
+    pub fn new(storage: Arc<GenericPostgresStorage<Traceroute>>, event_bus: Arc<EventBus>) -> Self {
+        Self { storage, event_bus }
+    }
+}
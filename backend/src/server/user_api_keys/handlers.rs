@@ -14,7 +14,7 @@ use crate::server::{
         },
         services::traits::CrudService,
         types::api::{
-            ApiError, ApiErrorResponse, ApiResponse, ApiResult, EmptyApiResponse,
+            ApiError, ApiErrorResponse, ApiJson, ApiResponse, ApiResult, EmptyApiResponse,
             PaginatedApiResponse,
         },
     },
@@ -32,6 +32,7 @@ use axum_extra::{TypedHeader, headers::UserAgent};
 use std::sync::Arc;
 use utoipa_axum::{router::OpenApiRouter, routes};
 use uuid::Uuid;
+use validator::Validate;
 
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
@@ -115,7 +116,7 @@ pub async fn create_user_api_key(
     auth: Authorized<IsUser>,
     _feature: RequireFeature<ApiKeyFeature>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
-    Json(mut api_key): Json<UserApiKey>,
+    ApiJson(mut api_key): ApiJson<UserApiKey>,
 ) -> ApiResult<Json<ApiResponse<UserApiKeyResponse>>> {
     let user_id = auth.require_user_id()?;
     let organization_id = auth.require_organization_id()?;
@@ -129,6 +130,10 @@ pub async fn create_user_api_key(
         "User API key create request received"
     );
 
+    api_key
+        .validate()
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
     // Validate permissions don't exceed user's permissions
     UserApiKeyService::validate_permissions(api_key.base.permissions, user_permissions)
         .map_err(|e| ApiError::forbidden(&e))?;
@@ -174,6 +179,7 @@ pub async fn create_user_api_key(
     params(("id" = Uuid, Path, description = "API key ID")),
     responses(
         (status = 200, description = "API key updated", body = ApiResponse<UserApiKey>),
+        (status = 400, description = "Invalid scopes, allowed IPs or rate limit", body = ApiErrorResponse),
         (status = 403, description = "Not authorized to update this key", body = ApiErrorResponse),
         (status = 404, description = "API key not found", body = ApiErrorResponse),
     ),
@@ -185,7 +191,7 @@ pub async fn update_user_api_key(
     _feature: RequireFeature<ApiKeyFeature>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     Path(id): Path<Uuid>,
-    Json(mut request): Json<UserApiKey>,
+    ApiJson(mut request): ApiJson<UserApiKey>,
 ) -> ApiResult<Json<ApiResponse<UserApiKey>>> {
     let user_id = auth.require_user_id()?;
     let user_permissions = auth.require_permissions()?;
@@ -204,6 +210,10 @@ pub async fn update_user_api_key(
         return Err(ApiError::forbidden("You don't own this API key"));
     }
 
+    request
+        .validate()
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
     // Validate permissions don't exceed user's permissions
     UserApiKeyService::validate_permissions(request.base.permissions, user_permissions)
         .map_err(|e| ApiError::forbidden(&e))?;
@@ -3,8 +3,10 @@ use std::fmt::Display;
 use crate::server::shared::api_key_common::{ApiKeyCommon, ApiKeyType};
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::types::api::serialize_sensitive_info;
+use crate::server::user_api_keys::r#impl::scopes::ApiKeyScope;
 use crate::server::users::r#impl::permissions::UserOrgPermissions;
 use chrono::{DateTime, Utc};
+use cidr::IpCidr;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -35,6 +37,19 @@ pub struct UserApiKeyBase {
     /// Network IDs this key has access to (hydrated from junction table)
     #[serde(default)]
     pub network_ids: Vec<Uuid>,
+    /// Restricts the key to these `resource:action` grants, e.g. `hosts:read`.
+    /// Empty means the permission level applies to every endpoint.
+    #[serde(default)]
+    #[schema(value_type = Vec<String>, required, example = json!(["hosts:read", "discovery:trigger"]))]
+    pub scopes: Vec<ApiKeyScope>,
+    /// Client addresses allowed to use this key, as CIDRs. Empty allows any address.
+    #[serde(default)]
+    #[schema(value_type = Vec<String>, required, example = json!(["10.0.0.0/8"]))]
+    pub allowed_ips: Vec<IpCidr>,
+    /// Requests per minute allowed for this key, on top of the account-wide limit
+    #[serde(default)]
+    #[validate(range(min = 1, max = 100000))]
+    pub rate_limit_per_minute: Option<u32>,
 }
 
 #[derive(
@@ -65,6 +80,15 @@ impl UserApiKey {
             && self.base.is_enabled == other.base.is_enabled
             && self.base.permissions == other.base.permissions
             && self.base.network_ids == other.base.network_ids
+            && self.base.scopes == other.base.scopes
+            && self.base.allowed_ips == other.base.allowed_ips
+            && self.base.rate_limit_per_minute == other.base.rate_limit_per_minute
+    }
+
+    /// Whether a client address may use this key
+    pub fn allows_ip(&self, ip: &std::net::IpAddr) -> bool {
+        self.base.allowed_ips.is_empty()
+            || self.base.allowed_ips.iter().any(|cidr| cidr.contains(ip))
     }
 }
 
@@ -2,4 +2,5 @@ pub mod api;
 pub mod base;
 pub mod handlers;
 pub mod network_access;
+pub mod scopes;
 pub mod storage;
@@ -0,0 +1,236 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{Error, anyhow};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+use utoipa::ToSchema;
+
+/// Resource a user API key scope applies to. Names match the API path segments.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+    EnumIter,
+    IntoStaticStr,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScopeResource {
+    Hosts,
+    Services,
+    Subnets,
+    Interfaces,
+    Ports,
+    Bindings,
+    Groups,
+    Networks,
+    Daemons,
+    DaemonApiKeys,
+    Discovery,
+    Topology,
+    Tags,
+    Shares,
+    Credentials,
+    Users,
+    Invites,
+}
+
+/// Operation a user API key scope allows
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+    EnumIter,
+    IntoStaticStr,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScopeAction {
+    Read,
+    Write,
+    Delete,
+    /// Start or cancel work, e.g. discovery sessions or topology rebuilds
+    Trigger,
+}
+
+/// A `resource:action` grant on a user API key, e.g. `hosts:read` or
+/// `discovery:trigger`. Either side may be `*` to match anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ApiKeyScope {
+    /// `None` matches every resource
+    pub resource: Option<ScopeResource>,
+    /// `None` matches every action
+    pub action: Option<ScopeAction>,
+}
+
+impl ApiKeyScope {
+    pub fn new(resource: ScopeResource, action: ScopeAction) -> Self {
+        Self {
+            resource: Some(resource),
+            action: Some(action),
+        }
+    }
+
+    pub fn allows(&self, resource: ScopeResource, action: ScopeAction) -> bool {
+        self.resource.is_none_or(|r| r == resource) && self.action.is_none_or(|a| a == action)
+    }
+}
+
+impl Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let resource: &str = self.resource.map(|r| r.into()).unwrap_or("*");
+        let action: &str = self.action.map(|a| a.into()).unwrap_or("*");
+        write!(f, "{}:{}", resource, action)
+    }
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (resource, action) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Invalid scope '{}': expected 'resource:action'", s))?;
+
+        let resource = match resource {
+            "*" => None,
+            r => Some(
+                r.parse::<ScopeResource>()
+                    .map_err(|_| anyhow!("Invalid scope '{}': unknown resource '{}'", s, r))?,
+            ),
+        };
+        let action = match action {
+            "*" => None,
+            a => Some(
+                a.parse::<ScopeAction>()
+                    .map_err(|_| anyhow!("Invalid scope '{}': unknown action '{}'", s, a))?,
+            ),
+        };
+
+        Ok(Self { resource, action })
+    }
+}
+
+impl TryFrom<String> for ApiKeyScope {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ApiKeyScope> for String {
+    fn from(value: ApiKeyScope) -> Self {
+        value.to_string()
+    }
+}
+
+/// Whether a key with these scopes may perform `action` on `resource`.
+/// Keys without scopes are limited only by their permission level.
+pub fn scopes_allow(scopes: &[ApiKeyScope], resource: ScopeResource, action: ScopeAction) -> bool {
+    scopes.is_empty() || scopes.iter().any(|s| s.allows(resource, action))
+}
+
+/// Entities that API key scopes can be granted on
+pub trait ScopedResource {
+    const SCOPE_RESOURCE: ScopeResource;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_round_trip() {
+        for raw in [
+            "hosts:read",
+            "discovery:trigger",
+            "*:read",
+            "services:*",
+            "*:*",
+        ] {
+            let scope: ApiKeyScope = raw.parse().unwrap();
+            assert_eq!(scope.to_string(), raw);
+        }
+
+        let scope: ApiKeyScope = serde_json::from_str("\"daemon_api_keys:write\"").unwrap();
+        assert_eq!(
+            scope,
+            ApiKeyScope::new(ScopeResource::DaemonApiKeys, ScopeAction::Write)
+        );
+        assert_eq!(
+            serde_json::to_string(&scope).unwrap(),
+            "\"daemon_api_keys:write\""
+        );
+    }
+
+    #[test]
+    fn test_invalid_scopes_rejected() {
+        for raw in ["hosts", "hosts:fly", "widgets:read", "", ":"] {
+            assert!(
+                raw.parse::<ApiKeyScope>().is_err(),
+                "{raw} should not parse"
+            );
+        }
+        assert!(serde_json::from_str::<ApiKeyScope>("\"hosts:nope\"").is_err());
+    }
+
+    #[test]
+    fn test_scopes_allow() {
+        let scopes: Vec<ApiKeyScope> = ["hosts:read", "services:*", "*:delete"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        assert!(scopes_allow(
+            &scopes,
+            ScopeResource::Hosts,
+            ScopeAction::Read
+        ));
+        assert!(!scopes_allow(
+            &scopes,
+            ScopeResource::Hosts,
+            ScopeAction::Write
+        ));
+        assert!(scopes_allow(
+            &scopes,
+            ScopeResource::Services,
+            ScopeAction::Write
+        ));
+        assert!(scopes_allow(
+            &scopes,
+            ScopeResource::Tags,
+            ScopeAction::Delete
+        ));
+        assert!(!scopes_allow(
+            &scopes,
+            ScopeResource::Discovery,
+            ScopeAction::Trigger
+        ));
+
+        // Unscoped keys keep their full permission level
+        assert!(scopes_allow(
+            &[],
+            ScopeResource::Discovery,
+            ScopeAction::Trigger
+        ));
+    }
+}
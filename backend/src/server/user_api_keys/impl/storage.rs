@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use cidr::IpCidr;
 use sqlx::Row;
 use sqlx::postgres::PgRow;
 use uuid::Uuid;
@@ -8,7 +9,10 @@ use crate::server::{
         entities::EntityDiscriminants,
         storage::traits::{SqlValue, StorableEntity},
     },
-    user_api_keys::r#impl::base::{UserApiKey, UserApiKeyBase},
+    user_api_keys::r#impl::{
+        base::{UserApiKey, UserApiKeyBase},
+        scopes::ApiKeyScope,
+    },
     users::r#impl::permissions::UserOrgPermissions,
 };
 
@@ -108,6 +112,9 @@ impl StorableEntity for UserApiKey {
                     is_enabled,
                     tags: _,        // Stored in entity_tags junction table
                     network_ids: _, // Stored in junction table, not here
+                    scopes,
+                    allowed_ips,
+                    rate_limit_per_minute,
                 },
         } = self.clone();
 
@@ -124,6 +131,9 @@ impl StorableEntity for UserApiKey {
                 "last_used",
                 "expires_at",
                 "is_enabled",
+                "scopes",
+                "allowed_ips",
+                "rate_limit_per_minute",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -137,6 +147,9 @@ impl StorableEntity for UserApiKey {
                 SqlValue::OptionTimestamp(last_used),
                 SqlValue::OptionTimestamp(expires_at),
                 SqlValue::Bool(is_enabled),
+                SqlValue::StringArray(scopes.iter().map(|s| s.to_string()).collect()),
+                SqlValue::StringArray(allowed_ips.iter().map(|c| c.to_string()).collect()),
+                SqlValue::OptionalI32(rate_limit_per_minute.map(|l| l as i32)),
             ],
         ))
     }
@@ -147,6 +160,18 @@ impl StorableEntity for UserApiKey {
             .parse::<UserOrgPermissions>()
             .unwrap_or_default();
 
+        let scopes = row
+            .get::<Vec<String>, _>("scopes")
+            .iter()
+            .map(|s| s.parse::<ApiKeyScope>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let allowed_ips = row
+            .get::<Vec<String>, _>("allowed_ips")
+            .iter()
+            .map(|c| c.parse::<IpCidr>())
+            .collect::<Result<Vec<_>, _>>()?;
+
         Ok(UserApiKey {
             id: row.get("id"),
             created_at: row.get("created_at"),
@@ -162,6 +187,11 @@ impl StorableEntity for UserApiKey {
                 is_enabled: row.get("is_enabled"),
                 tags: Vec::new(),        // Hydrated from entity_tags junction table
                 network_ids: Vec::new(), // Hydrated separately from junction table
+                scopes,
+                allowed_ips,
+                rate_limit_per_minute: row
+                    .get::<Option<i32>, _>("rate_limit_per_minute")
+                    .map(|l| l as u32),
             },
         })
     }
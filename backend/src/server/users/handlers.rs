@@ -1,5 +1,9 @@
+use crate::server::auth::r#impl::api::{RevokeSessionsResponse, UserSessionResponse};
+use crate::server::auth::middleware::auth::AuthenticatedEntity;
 use crate::server::auth::middleware::features::{BlockedInDemoMode, RequireFeature};
-use crate::server::auth::middleware::permissions::{Admin, Authorized, IsUser, Member};
+use crate::server::auth::middleware::permissions::{
+    Admin, And, Authorized, Delete, IsUser, Member, Read, Scope, Write,
+};
 use crate::server::shared::extractors::Query;
 use crate::server::shared::handlers::query::{FilterQueryExtractor, NoFilterQuery};
 use crate::server::shared::handlers::traits::{BulkDeleteResponse, CrudHandlers, delete_handler};
@@ -19,6 +23,8 @@ use crate::server::{
 use anyhow::anyhow;
 use axum::extract::{Path, State};
 use axum::response::Json;
+use axum_client_ip::ClientIp;
+use axum_extra::{TypedHeader, headers::UserAgent};
 use std::sync::Arc;
 use utoipa_axum::{router::OpenApiRouter, routes};
 use uuid::Uuid;
@@ -28,6 +34,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         .routes(routes!(get_all_users))
         .routes(routes!(get_user_by_id, update_user, delete_user))
         .routes(routes!(admin_update_user))
+        .routes(routes!(get_user_sessions, revoke_user_sessions))
         .routes(routes!(bulk_delete_users))
 }
 
@@ -85,11 +92,12 @@ pub async fn get_user_by_id(
     responses(
         (status = 200, description = "List of users", body = PaginatedApiResponse<User>),
     ),
+     extensions(("x-required-scope" = json!(Scope::<User, Read>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn get_all_users(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<User, Read>>>,
     query: Query<NoFilterQuery>,
 ) -> ApiResult<Json<PaginatedApiResponse<User>>> {
     let organization_id = auth
@@ -172,11 +180,12 @@ pub async fn get_all_users(
         (status = 403, description = "Cannot delete user with higher permissions", body = ApiErrorResponse),
         (status = 409, description = "Cannot delete the only owner", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<User, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn delete_user(
     state: State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<User, Delete>>>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     id: Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
@@ -297,11 +306,12 @@ pub async fn update_user(
         (status = 403, description = "Cannot update user with higher permissions", body = ApiErrorResponse),
         (status = 404, description = "User not found", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<User, Write>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 async fn admin_update_user(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<User, Write>>>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     Path(id): Path<Uuid>,
     Json(mut request): Json<User>,
@@ -386,6 +396,121 @@ async fn admin_update_user(
     Ok(Json(ApiResponse::success(updated)))
 }
 
+/// Resolve the target of an admin session action: another user in the same
+/// organization, with lower permissions than the caller.
+async fn managed_user(
+    state: &AppState,
+    entity: &AuthenticatedEntity,
+    id: &Uuid,
+) -> ApiResult<User> {
+    let (admin_user_id, organization_id, admin_permissions) = match entity {
+        AuthenticatedEntity::User {
+            user_id,
+            organization_id,
+            permissions,
+            ..
+        }
+        | AuthenticatedEntity::ApiKey {
+            user_id,
+            organization_id,
+            permissions,
+            ..
+        } => (*user_id, *organization_id, *permissions),
+        _ => return Err(ApiError::forbidden("User or API key required")),
+    };
+
+    if admin_user_id == *id {
+        return Err(ApiError::forbidden(
+            "Use your account settings to manage your own sessions",
+        ));
+    }
+
+    let user = state
+        .services
+        .user_service
+        .get_by_id(id)
+        .await?
+        .filter(|u| u.base.organization_id == organization_id)
+        .ok_or_else(|| ApiError::not_found(format!("User '{}' not found", id)))?;
+
+    if user.base.permissions >= admin_permissions {
+        return Err(ApiError::forbidden(
+            "You can only manage sessions of users with lower permissions than you",
+        ));
+    }
+
+    Ok(user)
+}
+
+/// List a user's sessions
+#[utoipa::path(
+    get,
+    path = "/{id}/sessions",
+    tags = ["users", "internal"],
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "User's signed-in sessions", body = ApiResponse<Vec<UserSessionResponse>>),
+        (status = 403, description = "Cannot manage user with higher permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<User, Read>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_user_sessions(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<And<Admin, Scope<User, Read>>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<Vec<UserSessionResponse>>>> {
+    let user = managed_user(&state, &auth.entity, &id).await?;
+
+    let sessions = state
+        .services
+        .session_service
+        .list_for_user(&user.id)
+        .await?
+        .into_iter()
+        .map(|s| UserSessionResponse::new(s, None))
+        .collect();
+
+    Ok(Json(ApiResponse::success(sessions)))
+}
+
+/// Sign a user out of all sessions
+#[utoipa::path(
+    delete,
+    path = "/{id}/sessions",
+    tags = ["users", "internal"],
+    params(("id" = Uuid, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "Sessions revoked", body = ApiResponse<RevokeSessionsResponse>),
+        (status = 403, description = "Cannot manage user with higher permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+    ),
+     extensions(("x-required-scope" = json!(Scope::<User, Write>::name()))),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn revoke_user_sessions(
+    State(state): State<Arc<AppState>>,
+    ClientIp(ip): ClientIp,
+    user_agent: Option<TypedHeader<UserAgent>>,
+    auth: Authorized<And<Admin, Scope<User, Write>>>,
+    _demo_check: RequireFeature<BlockedInDemoMode>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<RevokeSessionsResponse>>> {
+    let user = managed_user(&state, &auth.entity, &id).await?;
+    let user_agent = user_agent.map(|u| u.to_string());
+
+    let revoked = state
+        .services
+        .session_service
+        .revoke_all(&user.id, None, ip, user_agent, auth.into_entity())
+        .await?;
+
+    Ok(Json(ApiResponse::success(RevokeSessionsResponse {
+        revoked,
+    })))
+}
+
 /// Bulk delete users
 #[utoipa::path(
     post,
@@ -396,11 +521,12 @@ async fn admin_update_user(
         (status = 200, description = "Users deleted successfully", body = ApiResponse<BulkDeleteResponse>),
         (status = 403, description = "Cannot delete users with higher permissions", body = ApiErrorResponse),
     ),
+     extensions(("x-required-scope" = json!(Scope::<User, Delete>::name()))),
      security(("user_api_key" = []), ("session" = []))
 )]
 pub async fn bulk_delete_users(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Admin>,
+    auth: Authorized<And<Admin, Scope<User, Delete>>>,
     _demo_check: RequireFeature<BlockedInDemoMode>,
     Json(ids): Json<Vec<Uuid>>,
 ) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
@@ -39,6 +39,10 @@ pub struct UserBase {
     #[serde(default)]
     #[schema(read_only)]
     pub terms_accepted_at: Option<DateTime<Utc>>,
+    /// Set when the account is deactivated (e.g. by SCIM provisioning). Deactivated users can't sign in.
+    #[serde(default)]
+    #[schema(read_only)]
+    pub deactivated_at: Option<DateTime<Utc>>,
 }
 
 impl Default for UserBase {
@@ -53,6 +57,7 @@ impl Default for UserBase {
             oidc_subject: None,
             network_ids: vec![],
             terms_accepted_at: None,
+            deactivated_at: None,
         }
     }
 }
@@ -77,6 +82,7 @@ impl UserBase {
             oidc_subject: Some(oidc_subject),
             network_ids,
             terms_accepted_at,
+            deactivated_at: None,
         }
     }
 
@@ -98,6 +104,7 @@ impl UserBase {
             oidc_subject: None,
             network_ids,
             terms_accepted_at,
+            deactivated_at: None,
         }
     }
 }
@@ -121,6 +128,10 @@ pub struct User {
 }
 
 impl User {
+    pub fn is_deactivated(&self) -> bool {
+        self.base.deactivated_at.is_some()
+    }
+
     pub fn set_password(&mut self, password_hash: String) {
         self.base.password_hash = Some(password_hash);
         self.updated_at = Utc::now();
@@ -195,6 +206,7 @@ impl StorableEntity for User {
 
     fn preserve_immutable_fields(&mut self, existing: &Self) {
         self.base.terms_accepted_at = existing.base.terms_accepted_at;
+        self.base.deactivated_at = existing.base.deactivated_at;
     }
 
     fn entity_type() -> EntityDiscriminants {
@@ -216,6 +228,7 @@ impl StorableEntity for User {
                     oidc_provider,
                     oidc_subject,
                     terms_accepted_at,
+                    deactivated_at,
                     ..
                 },
         } = self.clone();
@@ -234,6 +247,7 @@ impl StorableEntity for User {
                 "permissions",
                 "organization_id",
                 "terms_accepted_at",
+                "deactivated_at",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -247,6 +261,7 @@ impl StorableEntity for User {
                 SqlValue::UserOrgPermissions(permissions),
                 SqlValue::Uuid(organization_id),
                 SqlValue::OptionTimestamp(terms_accepted_at),
+                SqlValue::OptionTimestamp(deactivated_at),
             ],
         ))
     }
@@ -275,6 +290,7 @@ impl StorableEntity for User {
                 oidc_subject: row.get("oidc_subject"),
                 network_ids: vec![],
                 terms_accepted_at: row.get("terms_accepted_at"),
+                deactivated_at: row.get("deactivated_at"),
             },
         })
     }
@@ -1,6 +1,7 @@
 use crate::server::{
     config::AppState,
     shared::handlers::{query::NoFilterQuery, traits::CrudHandlers},
+    user_api_keys::r#impl::scopes::{ScopeResource, ScopedResource},
     users::{r#impl::base::User, service::UserService},
 };
 
@@ -12,3 +13,7 @@ impl CrudHandlers for User {
         &state.services.user_service
     }
 }
+
+impl ScopedResource for User {
+    const SCOPE_RESOURCE: ScopeResource = ScopeResource::Users;
+}
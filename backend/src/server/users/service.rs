@@ -156,6 +156,52 @@ impl UserService {
             .await
     }
 
+    /// Sync a user's role and network access with an external identity source (OIDC
+    /// claims, LDAP groups). `None` leaves that part of the user's access unchanged.
+    pub async fn apply_external_access(
+        &self,
+        mut user: User,
+        permissions: Option<UserOrgPermissions>,
+        network_ids: Option<Vec<Uuid>>,
+        source: &str,
+    ) -> Result<User> {
+        if let Some(network_ids) = &network_ids {
+            self.set_network_ids(&user.id, network_ids).await?;
+        }
+
+        if let Some(permissions) = permissions
+            && permissions != user.base.permissions
+        {
+            // Never leave an organization without an owner
+            let is_last_owner = user.base.permissions == UserOrgPermissions::Owner
+                && self
+                    .get_organization_owners(&user.base.organization_id)
+                    .await?
+                    .len()
+                    <= 1;
+
+            if is_last_owner {
+                tracing::warn!(
+                    user_id = %user.id,
+                    mapped = %permissions,
+                    "Not applying {} role mapping to the organization's only owner",
+                    source
+                );
+            } else {
+                user.base.permissions = permissions;
+                user.updated_at = Utc::now();
+                user = self.update(&mut user, AuthenticatedEntity::System).await?;
+            }
+        }
+
+        match network_ids {
+            Some(network_ids) => user.base.network_ids = network_ids,
+            None => self.hydrate_network_ids(&mut user).await?,
+        }
+
+        Ok(user)
+    }
+
     /// Hydrate network_ids for a single user
     pub async fn hydrate_network_ids(&self, user: &mut User) -> Result<()> {
         user.base.network_ids = self.network_access_storage.get_for_user(&user.id).await?;
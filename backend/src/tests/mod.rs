@@ -71,7 +71,9 @@ pub async fn setup_test_db() -> (PgPool, String, ContainerAsync<GenericImage>) {
 pub async fn test_storage() -> (StorageFactory, ContainerAsync<GenericImage>) {
     let (pool, database_url, _container) = setup_test_db().await;
     pool.close().await;
-    let factory = StorageFactory::new(&database_url, false).await.unwrap();
+    let factory = StorageFactory::new(&database_url, false, 7 * 24 * 60)
+        .await
+        .unwrap();
     (factory, _container)
 }
 
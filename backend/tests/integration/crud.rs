@@ -33,6 +33,7 @@ pub async fn run_crud_tests(ctx: &TestContext) -> Result<(), String> {
     test_api_key_crud(ctx).await?;
     test_user_api_key_crud(ctx).await?;
     test_user_api_key_authentication(ctx).await?;
+    test_user_api_key_scopes(ctx).await?;
     test_user_api_key_permission_escalation(ctx).await?;
     test_user_api_key_rotation(ctx).await?;
     test_user_api_key_expired_disabled(ctx).await?;
@@ -387,6 +388,9 @@ async fn test_user_api_key_crud(ctx: &TestContext) -> Result<(), String> {
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: vec!["hosts:read".parse().unwrap()],
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     // User API keys are at /api/v1/auth/keys
@@ -443,6 +447,9 @@ async fn test_user_api_key_authentication(ctx: &TestContext) -> Result<(), Strin
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &api_key).await?;
@@ -519,6 +526,113 @@ async fn test_user_api_key_authentication(ctx: &TestContext) -> Result<(), Strin
     Ok(())
 }
 
+/// Test that scoped keys only reach endpoints their scopes cover, and that the
+/// IP allowlist is enforced
+async fn test_user_api_key_scopes(ctx: &TestContext) -> Result<(), String> {
+    println!("Testing User API Key Scopes...");
+
+    let scoped_key = UserApiKey::new(UserApiKeyBase {
+        key: String::new(),
+        name: "Scoped Key".to_string(),
+        user_id: Uuid::nil(),
+        organization_id: ctx.organization_id,
+        permissions: UserOrgPermissions::Member,
+        last_used: None,
+        expires_at: None,
+        is_enabled: true,
+        tags: Vec::new(),
+        network_ids: vec![ctx.network_id],
+        scopes: vec!["hosts:read".parse().unwrap()],
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
+    });
+
+    let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &scoped_key).await?;
+    let scoped_key_id = created.api_key.id;
+    let api_key_client = reqwest::Client::new();
+
+    let get_status = |path: &'static str, key: String| {
+        let client = api_key_client.clone();
+        async move {
+            client
+                .get(format!("{}{}", BASE_URL, path))
+                .header("Authorization", format!("Bearer {}", key))
+                .send()
+                .await
+                .map(|r| r.status())
+                .map_err(|e| format!("API key request failed: {}", e))
+        }
+    };
+
+    let status = get_status("/api/v1/hosts", created.key.clone()).await?;
+    assert!(
+        status.is_success(),
+        "hosts:read key should list hosts, got {}",
+        status
+    );
+    println!("  ✓ Scoped key can access endpoints its scopes cover");
+
+    let status = get_status("/api/v1/subnets", created.key.clone()).await?;
+    assert_eq!(
+        status,
+        StatusCode::FORBIDDEN,
+        "hosts:read key should not list subnets, got {}",
+        status
+    );
+    println!("  ✓ Scoped key denied endpoints outside its scopes");
+
+    let status = get_status("/api/v1/organizations", created.key.clone()).await?;
+    assert_eq!(
+        status,
+        StatusCode::FORBIDDEN,
+        "Scoped key should not reach endpoints without a declared scope, got {}",
+        status
+    );
+    println!("  ✓ Scoped key denied endpoints that declare no scope");
+
+    let mut ip_restricted = scoped_key.clone();
+    ip_restricted.base.name = "IP Restricted Key".to_string();
+    ip_restricted.base.scopes = Vec::new();
+    ip_restricted.base.allowed_ips = vec!["203.0.113.0/24".parse().unwrap()];
+    let restricted: UserApiKeyResponse =
+        ctx.client.post("/api/v1/auth/keys", &ip_restricted).await?;
+
+    let status = get_status("/api/v1/hosts", restricted.key.clone()).await?;
+    assert_eq!(
+        status,
+        StatusCode::FORBIDDEN,
+        "Key should be refused outside its allowed IPs, got {}",
+        status
+    );
+    println!("  ✓ Key refused from addresses outside its allowlist");
+
+    let mut invalid = scoped_key.clone();
+    invalid.base.scopes = Vec::new();
+    let mut invalid_json = serde_json::to_value(&invalid).map_err(|e| e.to_string())?;
+    invalid_json["scopes"] = serde_json::json!(["hosts:fly"]);
+    let result = ctx
+        .client
+        .post_expect_status("/api/v1/auth/keys", &invalid_json, StatusCode::BAD_REQUEST)
+        .await;
+    assert!(
+        result.is_ok(),
+        "Should reject unknown scopes: {:?}",
+        result.err()
+    );
+    println!("  ✓ Unknown scopes rejected");
+
+    // Cleanup
+    ctx.client
+        .delete_no_content(&format!("/api/v1/auth/keys/{}", scoped_key_id))
+        .await?;
+    ctx.client
+        .delete_no_content(&format!("/api/v1/auth/keys/{}", restricted.api_key.id))
+        .await?;
+
+    println!("✅ User API Key Scopes passed");
+    Ok(())
+}
+
 /// Test that users cannot create API keys with higher permissions than their own
 async fn test_user_api_key_permission_escalation(ctx: &TestContext) -> Result<(), String> {
     use crate::infra::exec_sql;
@@ -540,6 +654,9 @@ async fn test_user_api_key_permission_escalation(ctx: &TestContext) -> Result<()
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let result = ctx
@@ -569,6 +686,9 @@ async fn test_user_api_key_permission_escalation(ctx: &TestContext) -> Result<()
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &api_key_admin).await?;
@@ -600,6 +720,9 @@ async fn test_user_api_key_rotation(ctx: &TestContext) -> Result<(), String> {
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &api_key).await?;
@@ -681,6 +804,9 @@ async fn test_user_api_key_expired_disabled(ctx: &TestContext) -> Result<(), Str
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &api_key).await?;
@@ -798,6 +924,9 @@ async fn test_user_api_key_network_access(ctx: &TestContext) -> Result<(), Strin
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id], // Only first network
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &api_key).await?;
@@ -853,6 +982,9 @@ async fn test_user_api_key_network_access(ctx: &TestContext) -> Result<(), Strin
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id, other_network.id], // Includes network user shouldn't access
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let result = ctx
@@ -899,6 +1031,9 @@ async fn test_user_api_key_owner_isolation(ctx: &TestContext) -> Result<(), Stri
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
 
     let created: UserApiKeyResponse = ctx.client.post("/api/v1/auth/keys", &api_key).await?;
@@ -922,6 +1057,9 @@ async fn test_user_api_key_owner_isolation(ctx: &TestContext) -> Result<(), Stri
         is_enabled: true,
         tags: Vec::new(),
         network_ids: vec![ctx.network_id],
+        scopes: Vec::new(),
+        allowed_ips: Vec::new(),
+        rate_limit_per_minute: None,
     });
     let other_key = ctx.insert_entity(&other_key).await?;
     println!(
@@ -3,7 +3,7 @@
 use email_address::EmailAddress;
 use reqwest::StatusCode;
 use scanopy::server::auth::r#impl::api::{
-    LoginRequest, NetworkSetup, RegisterRequest, SetupRequest, SetupResponse,
+    LoginRequest, LoginResponse, NetworkSetup, RegisterRequest, SetupRequest, SetupResponse,
 };
 use scanopy::server::daemons::r#impl::base::Daemon;
 use scanopy::server::networks::r#impl::Network;
@@ -157,7 +157,10 @@ impl TestClient {
             .await
             .map_err(|e| format!("Login request failed: {}", e))?;
 
-        self.parse_response(response, "login").await
+        match self.parse_response(response, "login").await? {
+            LoginResponse::Authenticated { user } => Ok(user),
+            other => Err(format!("Login requires a second factor: {:?}", other)),
+        }
     }
 
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
@@ -50,7 +50,9 @@ export const queryClient = createQueryClient();
 export const queryKeys = {
 	auth: {
 		all: ['auth'] as const,
-		currentUser: () => [...queryKeys.auth.all, 'currentUser'] as const
+		currentUser: () => [...queryKeys.auth.all, 'currentUser'] as const,
+		mfa: () => [...queryKeys.auth.all, 'mfa'] as const,
+		sessions: () => [...queryKeys.auth.all, 'sessions'] as const
 	},
 	invites: {
 		all: ['invites'] as const,
@@ -105,6 +107,10 @@ export const queryKeys = {
 		all: ['organizations'] as const,
 		current: () => [...queryKeys.organizations.all, 'current'] as const
 	},
+	scim: {
+		all: ['scim'] as const,
+		settings: () => [...queryKeys.scim.all, 'settings'] as const
+	},
 	daemons: {
 		all: ['daemons'] as const,
 		detail: (id: string) => [...queryKeys.daemons.all, 'detail', id] as const
@@ -128,7 +134,8 @@ export const queryKeys = {
 	},
 	topology: {
 		all: ['topology'] as const,
-		detail: (id: string) => [...queryKeys.topology.all, 'detail', id] as const
+		detail: (id: string) => [...queryKeys.topology.all, 'detail', id] as const,
+		organization: () => [...queryKeys.topology.all, 'organization'] as const
 	},
 	billing: {
 		all: ['billing'] as const,
@@ -136,7 +143,12 @@ export const queryKeys = {
 	},
 	shares: {
 		all: ['shares'] as const,
-		detail: (id: string) => [...queryKeys.shares.all, 'detail', id] as const
+		detail: (id: string) => [...queryKeys.shares.all, 'detail', id] as const,
+		tokens: (id: string) => [...queryKeys.shares.all, 'tokens', id] as const,
+		views: (id: string) => [...queryKeys.shares.all, 'views', id] as const
+	},
+	credentials: {
+		all: ['credentials'] as const
 	},
 	config: {
 		all: ['config'] as const
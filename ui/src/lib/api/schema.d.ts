@@ -35,6 +35,22 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/auth/ldap/login": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["ldap_login"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/auth/login": {
         parameters: {
             query?: never;
@@ -83,6 +99,150 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/auth/mfa": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get: operations["get_mfa_status"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/credentials/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post?: never;
+        delete: operations["delete_mfa_credential"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/passkeys": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["begin_passkey_registration"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/passkeys/confirm": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["finish_passkey_registration"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/recovery-codes": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["regenerate_recovery_codes"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/totp": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["begin_totp_enrollment"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/totp/confirm": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["confirm_totp_enrollment"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/verify": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["verify_mfa"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/mfa/verify/passkey": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["begin_passkey_authentication"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/auth/oidc/{slug}/unlink": {
         parameters: {
             query?: never;
@@ -131,6 +291,54 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/auth/sessions": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get: operations["list_sessions"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/sessions/revoke-others": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post: operations["revoke_other_sessions"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/auth/sessions/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        post?: never;
+        delete: operations["revoke_session"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/auth/setup": {
         parameters: {
             query?: never;
@@ -623,6 +831,66 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/credentials": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all credentials */
+        get: operations["list_credentials"];
+        put?: never;
+        /**
+         * Create a new credential
+         * @description The secret is encrypted at rest and is never returned by the API.
+         */
+        post: operations["create_credential"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/credentials/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete credentials */
+        post: operations["bulk_delete_credentials"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/credentials/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get credential by ID */
+        get: operations["get_credential_by_id"];
+        /**
+         * Update a credential
+         * @description Omit `secret` to keep the stored secret.
+         */
+        put: operations["update_credential"];
+        post?: never;
+        /** Delete credential */
+        delete: operations["delete_credential"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/daemons": {
         parameters: {
             query?: never;
@@ -786,6 +1054,27 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/discovery/{session_id}/credentials": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Fetch credentials for a discovery session
+         * @description Internal endpoint for daemons to collect the decrypted credentials attached to a
+         *     discovery when the session starts. Credentials can only be fetched once per session.
+         */
+        post: operations["get_session_credentials"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/discovery/{session_id}/update": {
         parameters: {
             query?: never;
@@ -1127,25 +1416,28 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/v1/networks": {
+    "/api/v1/network-links": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** List all networks */
-        get: operations["list_networks"];
+        /** List all network_links */
+        get: operations["list_network_links"];
         put?: never;
-        /** Create a new network */
-        post: operations["create_network"];
+        /**
+         * Create a network link
+         * @description Connects two networks in the organization topology. You need access to both networks.
+         */
+        post: operations["create_network_link"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/networks/bulk-delete": {
+    "/api/v1/network-links/bulk-delete": {
         parameters: {
             query?: never;
             header?: never;
@@ -1154,25 +1446,82 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Bulk delete networks */
-        post: operations["bulk_delete_networks"];
+        /** Bulk delete network_links */
+        post: operations["bulk_delete_network_links"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/networks/{id}": {
+    "/api/v1/network-links/{id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get network by ID */
-        get: operations["get_network_by_id"];
-        /** Update a network */
-        put: operations["update_network"];
+        /** Get network_link by ID */
+        get: operations["get_network_link_by_id"];
+        /**
+         * Update a network link
+         * @description You need access to both networks the link connects.
+         */
+        put: operations["update_network_link"];
+        post?: never;
+        /** Delete network_link */
+        delete: operations["delete_network_link"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/networks": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all networks */
+        get: operations["list_networks"];
+        put?: never;
+        /** Create a new network */
+        post: operations["create_network"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/networks/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete networks */
+        post: operations["bulk_delete_networks"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/networks/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get network by ID */
+        get: operations["get_network_by_id"];
+        /** Update a network */
+        put: operations["update_network"];
         post?: never;
         /** Delete a network */
         delete: operations["delete_network"];
@@ -1215,6 +1564,23 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/organizations/{id}/mfa-policy": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /** Require a second factor for all members' password logins */
+        put: operations["update_mfa_policy"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/organizations/{id}/populate-demo": {
         parameters: {
             query?: never;
@@ -1303,6 +1669,65 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/scim": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get SCIM provisioning settings */
+        get: operations["get_scim_settings"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/scim/groups/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /**
+         * Map a SCIM group onto a role and network access
+         * @description Members of mapped groups get the highest role across their groups and the union
+         *     of their networks.
+         */
+        put: operations["update_scim_group_mapping"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/scim/token": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Generate a SCIM token, replacing any existing one
+         * @description The token is only returned once.
+         */
+        post: operations["rotate_scim_token"];
+        /** Revoke the SCIM token, disabling provisioning */
+        delete: operations["revoke_scim_token"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/services": {
         parameters: {
             query?: never;
@@ -1439,6 +1864,27 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/shares/public/{id}/image": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get a rendered image of a public share
+         * @description Static image URL for reports, wikis and emails. Not available for password-protected
+         *     shares or shares with export disabled.
+         */
+        get: operations["get_share_image"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/shares/public/{id}/verify": {
         parameters: {
             query?: never;
@@ -1448,7 +1894,7 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Verify password for a password-protected share (returns success/failure only) */
+        /** Verify the password or an access token for a protected share (returns success/failure only) */
         post: operations["verify_share_password"];
         delete?: never;
         options?: never;
@@ -1475,29 +1921,25 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/v1/subnets": {
+    "/api/v1/shares/{id}/tokens": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /**
-         * List all subnets
-         * @description Returns all subnets accessible to the authenticated user or daemon.
-         *     Daemons can only access subnets within their assigned network.
-         */
-        get: operations["list_subnets"];
+        /** List a share's access tokens */
+        get: operations["list_share_tokens"];
         put?: never;
-        /** Create a new subnet */
-        post: operations["create_subnet"];
+        /** Create an access token for a share */
+        post: operations["create_share_token"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/subnets/bulk-delete": {
+    "/api/v1/shares/{id}/tokens/{token_id}/revoke": {
         parameters: {
             query?: never;
             header?: never;
@@ -1506,64 +1948,54 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Bulk delete subnets */
-        post: operations["bulk_delete_subnets"];
+        /** Revoke a share access token */
+        post: operations["revoke_share_token"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/subnets/{id}": {
+    "/api/v1/shares/{id}/views": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get subnet by ID */
-        get: operations["get_subnet_by_id"];
-        /**
-         * Update a subnet
-         * @description Updates subnet properties. If the CIDR is being changed, validates that
-         *     all existing interfaces on this subnet have IPs within the new CIDR range.
-         */
-        put: operations["update_subnet"];
+        /** Get view statistics for a share */
+        get: operations["get_share_view_stats"];
+        put?: never;
         post?: never;
-        /** Delete subnet */
-        delete: operations["delete_subnet"];
+        delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/tags": {
+    "/api/v1/snapshots": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** List all tags */
-        get: operations["list_tags"];
+        /** List all snapshots */
+        get: operations["list_snapshots"];
         put?: never;
         /**
-         * Create a new tag
-         * @description Creates a tag scoped to your organization. Tag names must be unique within the organization.
-         *
-         *     ### Validation
-         *
-         *     - Name must be 1-100 characters (empty names are rejected)
-         *     - Name must be unique within your organization
+         * Take a snapshot of a topology
+         * @description Copies the topology's graph and entities as currently stored. Manual snapshots are
+         *     kept until deleted.
          */
-        post: operations["create_tag"];
+        post: operations["create_snapshot"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/tags/assign": {
+    "/api/v1/snapshots/bulk-delete": {
         parameters: {
             query?: never;
             header?: never;
@@ -1571,75 +2003,55 @@ export interface paths {
             cookie?: never;
         };
         get?: never;
-        /**
-         * Set all tags for an entity
-         * @description Replaces all tags on an entity with the provided list.
-         *
-         *     ### Validation
-         *
-         *     - Entity type must be taggable (Host, Service, Subnet, Group, Network, Discovery, Daemon, DaemonApiKey, UserApiKey)
-         *     - All tags must exist and belong to your organization
-         */
-        put: operations["set_entity_tags"];
-        post?: never;
+        put?: never;
+        /** Bulk delete snapshots */
+        post: operations["bulk_delete_snapshots"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/tags/assign/bulk-add": {
+    "/api/v1/snapshots/diff": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        put?: never;
         /**
-         * Bulk add a tag to multiple entities
-         * @description Adds a single tag to multiple entities of the same type. This is useful for batch tagging operations.
-         *
-         *     ### Validation
-         *
-         *     - Entity type must be taggable (Host, Service, Subnet, Group, Network, Discovery, Daemon, DaemonApiKey, UserApiKey)
-         *     - Tag must exist and belong to your organization
-         *     - Entities that already have the tag are silently skipped
+         * Compare two snapshots
+         * @description Returns the nodes and edges added, removed and changed between two snapshots of the same
+         *     topology, with the hosts, interfaces, subnets, services and groups they draw.
          */
-        post: operations["bulk_add_tag"];
+        get: operations["diff_snapshots"];
+        put?: never;
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/tags/assign/bulk-remove": {
+    "/api/v1/snapshots/{id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
+        /** Get snapshot by ID */
+        get: operations["get_snapshot_by_id"];
         put?: never;
-        /**
-         * Bulk remove a tag from multiple entities
-         * @description Removes a single tag from multiple entities of the same type.
-         *
-         *     ### Validation
-         *
-         *     - Entity type must be taggable (Host, Service, Subnet, Group, Network, Discovery, Daemon, DaemonApiKey, UserApiKey)
-         *     - Entities that don't have the tag are silently skipped
-         */
-        post: operations["bulk_remove_tag"];
-        delete?: never;
+        post?: never;
+        /** Delete snapshot */
+        delete: operations["delete_snapshot"];
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/tags/bulk-delete": {
+    "/api/v1/snapshots/{id}/export": {
         parameters: {
             query?: never;
             header?: never;
@@ -1648,104 +2060,128 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Bulk delete tags */
-        post: operations["bulk_delete_tags"];
+        /**
+         * Export a snapshot
+         * @description Converts the snapshot to Graphviz DOT, draw.io, Mermaid or GraphML, like exporting a
+         *     topology.
+         */
+        post: operations["export_snapshot"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/tags/{id}": {
+    "/api/v1/snapshots/{id}/render": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get tag by ID */
-        get: operations["get_tag_by_id"];
-        /** Update tag */
-        put: operations["update_tag"];
-        post?: never;
-        /** Delete tag */
-        delete: operations["delete_tag"];
+        get?: never;
+        put?: never;
+        /**
+         * Render a snapshot
+         * @description Draws the snapshot as SVG, PNG or PDF, like rendering a topology. Options in the request
+         *     override the ones saved with the snapshot.
+         */
+        post: operations["render_snapshot"];
+        delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/topology": {
+    "/api/v1/subnets": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get all topologies */
-        get: operations["get_all_topologies"];
+        /**
+         * List all subnets
+         * @description Returns all subnets accessible to the authenticated user or daemon.
+         *     Daemons can only access subnets within their assigned network.
+         */
+        get: operations["list_subnets"];
         put?: never;
-        /** Create topology */
-        post: operations["create_topology"];
+        /** Create a new subnet */
+        post: operations["create_subnet"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/topology/{id}": {
+    "/api/v1/subnets/bulk-delete": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get topology by ID */
-        get: operations["get_topology_by_id"];
-        put: operations["update_topology"];
-        post?: never;
-        /** Delete topology */
-        delete: operations["delete_topology"];
+        get?: never;
+        put?: never;
+        /** Bulk delete subnets */
+        post: operations["bulk_delete_subnets"];
+        delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/topology/{id}/lock": {
+    "/api/v1/subnets/{id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        put?: never;
-        /** Lock a topology */
-        post: operations["lock"];
-        delete?: never;
+        /** Get subnet by ID */
+        get: operations["get_subnet_by_id"];
+        /**
+         * Update a subnet
+         * @description Updates subnet properties. If the CIDR is being changed, validates that
+         *     all existing interfaces on this subnet have IPs within the new CIDR range.
+         */
+        put: operations["update_subnet"];
+        post?: never;
+        /** Delete subnet */
+        delete: operations["delete_subnet"];
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/topology/{id}/rebuild": {
+    "/api/v1/tags": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
+        /** List all tags */
+        get: operations["list_tags"];
         put?: never;
-        /** Rebuild topology layout */
-        post: operations["rebuild"];
+        /**
+         * Create a new tag
+         * @description Creates a tag scoped to your organization. Tag names must be unique within the organization.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-100 characters (empty names are rejected)
+         *     - Name must be unique within your organization
+         */
+        post: operations["create_tag"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/topology/{id}/refresh": {
+    "/api/v1/tags/assign": {
         parameters: {
             query?: never;
             header?: never;
@@ -1753,16 +2189,24 @@ export interface paths {
             cookie?: never;
         };
         get?: never;
-        put?: never;
-        /** Refresh topology data */
-        post: operations["refresh"];
+        /**
+         * Set all tags for an entity
+         * @description Replaces all tags on an entity with the provided list.
+         *
+         *     ### Validation
+         *
+         *     - Entity type must be taggable (Host, Service, Subnet, Group, Network, Discovery, Daemon, DaemonApiKey, UserApiKey)
+         *     - All tags must exist and belong to your organization
+         */
+        put: operations["set_entity_tags"];
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/topology/{id}/unlock": {
+    "/api/v1/tags/assign/bulk-add": {
         parameters: {
             query?: never;
             header?: never;
@@ -1771,32 +2215,49 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Unlock a topology */
-        post: operations["unlock"];
+        /**
+         * Bulk add a tag to multiple entities
+         * @description Adds a single tag to multiple entities of the same type. This is useful for batch tagging operations.
+         *
+         *     ### Validation
+         *
+         *     - Entity type must be taggable (Host, Service, Subnet, Group, Network, Discovery, Daemon, DaemonApiKey, UserApiKey)
+         *     - Tag must exist and belong to your organization
+         *     - Entities that already have the tag are silently skipped
+         */
+        post: operations["bulk_add_tag"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/users": {
+    "/api/v1/tags/assign/bulk-remove": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** List all users */
-        get: operations["get_all_users"];
+        get?: never;
         put?: never;
-        post?: never;
+        /**
+         * Bulk remove a tag from multiple entities
+         * @description Removes a single tag from multiple entities of the same type.
+         *
+         *     ### Validation
+         *
+         *     - Entity type must be taggable (Host, Service, Subnet, Group, Network, Discovery, Daemon, DaemonApiKey, UserApiKey)
+         *     - Entities that don't have the tag are silently skipped
+         */
+        post: operations["bulk_remove_tag"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/users/bulk-delete": {
+    "/api/v1/tags/bulk-delete": {
         parameters: {
             query?: never;
             header?: never;
@@ -1805,59 +2266,65 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Bulk delete users */
-        post: operations["bulk_delete_users"];
+        /** Bulk delete tags */
+        post: operations["bulk_delete_tags"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/users/{id}": {
+    "/api/v1/tags/{id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get user by ID */
-        get: operations["get_user_by_id"];
-        /** Update your own user record */
-        put: operations["update_user"];
+        /** Get tag by ID */
+        get: operations["get_tag_by_id"];
+        /** Update tag */
+        put: operations["update_tag"];
         post?: never;
-        /** Delete a user */
-        delete: operations["delete_user"];
+        /** Delete tag */
+        delete: operations["delete_tag"];
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/users/{id}/admin": {
+    "/api/v1/topology": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        /** Admin update user (for changing permissions) */
-        put: operations["admin_update_user"];
-        post?: never;
+        /** Get all topologies */
+        get: operations["get_all_topologies"];
+        put?: never;
+        /** Create topology */
+        post: operations["create_topology"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/version": {
+    "/api/v1/topology/organization": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get API version information */
-        get: operations["get_version"];
+        /**
+         * Get the organization topology
+         * @description Every network you have access to as a container, connected through shared remote and VPN
+         *     subnets, hosts found in more than one network, and network links. Built on each request
+         *     and never stored.
+         */
+        get: operations["get_organization_topology"];
         put?: never;
         post?: never;
         delete?: never;
@@ -1866,87 +2333,365 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-}
-export type webhooks = Record<string, never>;
-export interface components {
-    schemas: {
-        /** @description Error response type for API errors (no data field) */
-        ApiErrorResponse: {
-            error?: string | null;
-            success: boolean;
+    "/api/v1/topology/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get topology by ID */
+        get: operations["get_topology_by_id"];
+        put: operations["update_topology"];
+        post?: never;
+        /** Delete topology */
+        delete: operations["delete_topology"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology/{id}/export": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
+        get?: never;
+        put?: never;
         /**
-         * @description API metadata included in all responses
-         * @example {
-         *       "api_version": 1,
-         *       "server_version": "0.13.2"
-         *     }
+         * Export a topology
+         * @description Converts the stored nodes and edges to Graphviz DOT, draw.io, Mermaid or GraphML for
+         *     editing in other tools. draw.io and GraphML keep the computed layout. Options in the
+         *     request override the ones saved with the topology.
          */
-        ApiMeta: {
-            /**
-             * Format: int32
-             * @description API version (integer, increments on breaking changes)
-             */
-            api_version: number;
-            /**
-             * @description Server version (semver)
-             * @example 0.13.2
-             */
-            server_version: string;
+        post: operations["export_topology"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology/{id}/lock": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
-        ApiResponse: {
-            data?: null;
-            error?: string | null;
-            meta: components["schemas"]["ApiMeta"];
-            success: boolean;
+        get?: never;
+        put?: never;
+        /** Lock a topology */
+        post: operations["lock"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology/{id}/rebuild": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
-        ApiResponse_Binding: {
-            /**
-             * @description Association between a service and a port / interface that the service is listening on
-             * @example {
-             *       "created_at": "2026-01-08T02:40:35.790992Z",
-             *       "id": "5491e7b4-10fc-4bf1-bdff-c1029a74398d",
-             *       "interface_id": "550e8400-e29b-41d4-a716-446655440005",
-             *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
-             *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
-             *       "service_id": "550e8400-e29b-41d4-a716-446655440007",
-             *       "type": "Port",
-             *       "updated_at": "2026-01-08T02:40:35.790992Z"
-             *     }
-             */
-            data?: components["schemas"]["BindingBase"] & {
-                /** Format: date-time */
-                readonly created_at: string;
-                /** Format: uuid */
-                readonly id: string;
-                /** Format: date-time */
-                readonly updated_at: string;
-            };
-            error?: string | null;
-            meta: components["schemas"]["ApiMeta"];
-            success: boolean;
+        get?: never;
+        put?: never;
+        /** Rebuild topology layout */
+        post: operations["rebuild"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology/{id}/refresh": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
-        ApiResponse_BulkDeleteResponse: {
-            data?: {
-                deleted_count: number;
-                requested_count: number;
-            };
-            error?: string | null;
-            meta: components["schemas"]["ApiMeta"];
-            success: boolean;
+    "/api/v1/topology/{id}/render": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
-        ApiResponse_BulkTagResponse: {
-            /** @description Response for bulk tag operations */
-            data?: {
-                /** @description Number of entities affected */
-                affected_count: number;
-            };
-            error?: string | null;
+        get?: never;
+        put?: never;
+        /**
+         * Render a topology
+         * @description Draws the stored nodes and edges as SVG, or rasterizes them to PNG or a paginated PDF.
+         *     Options in the request override the ones saved with the topology.
+         */
+        post: operations["render_topology"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+        get?: never;
+        put?: never;
+        /** Refresh topology data */
+        post: operations["refresh"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology/{id}/unlock": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Unlock a topology */
+        post: operations["unlock"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/traceroutes": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all traceroutes */
+        get: operations["list_traceroutes"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/traceroutes/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete traceroutes */
+        post: operations["bulk_delete_traceroutes"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/traceroutes/discovery": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Internal endpoint for daemon discovery
+         * @description Used by daemons to report a traced path. A daemon keeps one traceroute per target,
+         *     so tracing the same target again replaces the hops recorded last time.
+         *
+         *     Tagged as "internal" - included in OpenAPI spec for client generation
+         *     but hidden from public documentation.
+         */
+        post: operations["create_traceroute_discovery"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/traceroutes/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get traceroute by ID */
+        get: operations["get_traceroute_by_id"];
+        put?: never;
+        post?: never;
+        /** Delete traceroute */
+        delete: operations["delete_traceroute"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/users": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all users */
+        get: operations["get_all_users"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/users/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete users */
+        post: operations["bulk_delete_users"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/users/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get user by ID */
+        get: operations["get_user_by_id"];
+        /** Update your own user record */
+        put: operations["update_user"];
+        post?: never;
+        /** Delete a user */
+        delete: operations["delete_user"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/users/{id}/admin": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /** Admin update user (for changing permissions) */
+        put: operations["admin_update_user"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/users/{id}/sessions": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List a user's sessions */
+        get: operations["get_user_sessions"];
+        put?: never;
+        post?: never;
+        /** Sign a user out of all sessions */
+        delete: operations["revoke_user_sessions"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/version": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get API version information */
+        get: operations["get_version"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+}
+export type webhooks = Record<string, never>;
+export interface components {
+    schemas: {
+        /** @description Error response type for API errors (no data field) */
+        ApiErrorResponse: {
+            error?: string | null;
+            success: boolean;
+        };
+        /**
+         * @description API metadata included in all responses
+         * @example {
+         *       "api_version": 1,
+         *       "server_version": "0.13.2"
+         *     }
+         */
+        ApiMeta: {
+            /**
+             * Format: int32
+             * @description API version (integer, increments on breaking changes)
+             */
+            api_version: number;
+            /**
+             * @description Server version (semver)
+             * @example 0.13.2
+             */
+            server_version: string;
+        };
+        ApiResponse: {
+            data?: null;
+            error?: string | null;
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
-        ApiResponse_DaemonApiKey: {
-            data?: components["schemas"]["DaemonApiKeyBase"] & {
+        ApiResponse_Binding: {
+            /**
+             * @description Association between a service and a port / interface that the service is listening on
+             * @example {
+             *       "created_at": "2026-01-08T02:40:35.790992Z",
+             *       "id": "5491e7b4-10fc-4bf1-bdff-c1029a74398d",
+             *       "interface_id": "550e8400-e29b-41d4-a716-446655440005",
+             *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+             *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
+             *       "service_id": "550e8400-e29b-41d4-a716-446655440007",
+             *       "type": "Port",
+             *       "updated_at": "2026-01-08T02:40:35.790992Z"
+             *     }
+             */
+            data?: components["schemas"]["BindingBase"] & {
                 /** Format: date-time */
                 readonly created_at: string;
                 /** Format: uuid */
@@ -1958,17 +2703,62 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
-        ApiResponse_DaemonApiKeyResponse: {
+        ApiResponse_BulkDeleteResponse: {
             data?: {
-                api_key: components["schemas"]["DaemonApiKey"];
-                key: string;
+                deleted_count: number;
+                requested_count: number;
             };
             error?: string | null;
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
-        ApiResponse_DaemonRegistrationResponse: {
-            /** @description Daemon registration response from server to daemon */
+        ApiResponse_BulkTagResponse: {
+            /** @description Response for bulk tag operations */
+            data?: {
+                /** @description Number of entities affected */
+                affected_count: number;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_Credential: {
+            data?: components["schemas"]["CredentialBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_DaemonApiKey: {
+            data?: components["schemas"]["DaemonApiKeyBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_DaemonApiKeyResponse: {
+            data?: {
+                api_key: components["schemas"]["DaemonApiKey"];
+                key: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_DaemonRegistrationResponse: {
+            /** @description Daemon registration response from server to daemon */
             data?: {
                 daemon: components["schemas"]["Daemon"];
                 /** Format: uuid */
@@ -2182,6 +2972,12 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_LoginResponse: {
+            data?: components["schemas"]["LoginResponse"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_MetadataRegistry: {
             data?: {
                 billing_plans: components["schemas"]["TypeMetadata"][];
@@ -2200,6 +2996,21 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_MfaEnrollmentResponse: {
+            /** @description Returned after enrolling a factor. Recovery codes are only generated with the first factor. */
+            data?: {
+                recovery_codes?: string[] | null;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_MfaStatusResponse: {
+            data?: components["schemas"]["MfaStatusResponse"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Network: {
             /**
              * @example {
@@ -2223,6 +3034,19 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_NetworkLink: {
+            data?: components["schemas"]["NetworkLinkBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Organization: {
             data?: components["schemas"]["OrganizationBase"] & {
                 /** Format: date-time */
@@ -2236,6 +3060,15 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_PasskeyChallengeResponse: {
+            /** @description WebAuthn options to pass to `navigator.credentials.create()` or `.get()` */
+            data?: {
+                options: Record<string, never>;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Port: {
             /**
              * @description Port entity with custom serialization that flattens PortType fields.
@@ -2270,6 +3103,7 @@ export interface components {
                 has_email_opt_in: boolean;
                 has_email_service: boolean;
                 has_integrated_daemon: boolean;
+                ldap?: null | components["schemas"]["LdapProviderMetadata"];
                 needs_cookie_consent: boolean;
                 oidc_providers: components["schemas"]["OidcProviderMetadata"][];
                 plunk_key?: string | null;
@@ -2295,6 +3129,52 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_RecoveryCodesResponse: {
+            data?: {
+                codes: string[];
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_RevokeSessionsResponse: {
+            data?: {
+                /** @description Number of sessions signed out */
+                revoked: number;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ScimGroup: {
+            /**
+             * @description A group pushed by the identity provider over SCIM. Owners map each group onto
+             *     a role and network access, which is applied to its members.
+             */
+            data?: components["schemas"]["ScimGroupBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ScimSettingsResponse: {
+            data?: components["schemas"]["ScimSettingsResponse"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ScimTokenResponse: {
+            data?: components["schemas"]["ScimTokenResponse"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_ServerCapabilities: {
             /** @description Server capabilities returned on startup/registration */
             data?: {
@@ -2373,6 +3253,29 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_ShareToken: {
+            data?: components["schemas"]["ShareTokenBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ShareTokenResponse: {
+            data?: components["schemas"]["ShareTokenResponse"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ShareViewStats: {
+            data?: components["schemas"]["ShareViewStats"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_String: {
             data?: string;
             error?: string | null;
@@ -2445,6 +3348,48 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_TopologyDiff: {
+            data?: components["schemas"]["TopologyDiff"];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_TopologySnapshot: {
+            data?: components["schemas"]["TopologySnapshotBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_TotpEnrollmentResponse: {
+            /** @description TOTP secret to load into an authenticator app; not active until confirmed */
+            data?: {
+                otpauth_url: string;
+                secret: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_Traceroute: {
+            data?: components["schemas"]["TracerouteBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_User: {
             data?: components["schemas"]["UserBase"] & {
                 /** Format: date-time */
@@ -2515,6 +3460,17 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_Vec_DiscoveryCredential: {
+            data?: {
+                /** Format: uuid */
+                id: string;
+                name: string;
+                secret: components["schemas"]["CredentialSecret"];
+            }[];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Vec_DiscoveryUpdatePayload: {
             data?: {
                 /** Format: uuid */
@@ -2550,6 +3506,34 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_Vec_ShareToken: {
+            data?: (components["schemas"]["ShareTokenBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_Vec_UserSessionResponse: {
+            data?: {
+                /** Format: date-time */
+                created_at: string;
+                /** Format: uuid */
+                id: string;
+                ip_address: string;
+                /** @description Whether this is the session making the request */
+                is_current: boolean;
+                /** Format: date-time */
+                last_activity_at: string;
+                user_agent?: string | null;
+            }[];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_VersionInfo: {
             /** @description Version information for API compatibility checking */
             data?: {
@@ -2722,6 +3706,12 @@ export interface components {
         };
         /** @enum {string} */
         Color: "Pink" | "Rose" | "Red" | "Orange" | "Green" | "Emerald" | "Teal" | "Cyan" | "Blue" | "Indigo" | "Purple" | "Gray" | "Yellow";
+        ConfirmTotpRequest: {
+            code: string;
+            name?: string | null;
+        };
+        /** @enum {string} */
+        ContainerRuntime: "Docker" | "Podman" | "Containerd";
         /**
          * @description Input for creating a binding with a service.
          *     `service_id` and `network_id` are assigned by the server after the service is created.
@@ -2836,10 +3826,68 @@ export interface components {
             tags: string[];
             virtualization?: null | components["schemas"]["ServiceVirtualization"];
         };
+        /** @description Take a snapshot of a topology as it's currently stored */
+        CreateShareTokenRequest: {
+            name: string;
+        };
+        CreateSnapshotRequest: {
+            /** @description Defaults to the topology name and the current time */
+            name?: string | null;
+            /** Format: uuid */
+            topology_id: string;
+        };
+        CreateUpdateCredentialRequest: {
+            credential: components["schemas"]["Credential"];
+            /** @description Required when creating. When updating, omit to keep the stored secret. */
+            secret?: null | components["schemas"]["CredentialSecret"];
+        };
         CreateUpdateShareRequest: {
             password?: string | null;
             share: components["schemas"]["Share"];
         };
+        /** @description A secret used by discovery, such as an SNMP community or API token, encrypted at rest */
+        Credential: components["schemas"]["CredentialBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        CredentialBase: {
+            /** @description Set from the secret when the credential is created */
+            readonly credential_type: components["schemas"]["CredentialType"];
+            description: string | null;
+            name: string;
+            /** Format: uuid */
+            network_id: string;
+        };
+        /**
+         * @description Secret material for a credential. Only ever accepted from clients and sent to
+         *     daemons at the start of a discovery session; it is never returned by the API.
+         */
+        CredentialSecret: {
+            community: string;
+            /** @enum {string} */
+            type: "SnmpCommunity";
+        } | {
+            token: string;
+            /** @enum {string} */
+            type: "ApiToken";
+        } | {
+            passphrase?: string | null;
+            private_key: string;
+            /** @enum {string} */
+            type: "SshKey";
+            username: string;
+        } | {
+            password: string;
+            /** @enum {string} */
+            type: "UsernamePassword";
+            username: string;
+        };
+        /** @enum {string} */
+        CredentialType: "SnmpCommunity" | "ApiToken" | "SshKey" | "UsernamePassword";
         Daemon: components["schemas"]["DaemonBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -2985,6 +4033,8 @@ export interface components {
             readonly updated_at: string;
         };
         DiscoveryBase: {
+            /** @description Credentials handed to the daemon when a session of this discovery starts */
+            credential_ids: string[];
             /** Format: uuid */
             daemon_id: string;
             discovery_type: components["schemas"]["DiscoveryType"];
@@ -3002,6 +4052,12 @@ export interface components {
         DiscoveryHostRequest: {
             host: components["schemas"]["Host"];
             interfaces: components["schemas"]["Interface"][];
+            /**
+             * @description MAC addresses known to belong to the host that have no IP address to build an
+             *     interface from, e.g. NICs of a powered-off VM read from its hypervisor config.
+             *     Only used to match the host against existing hosts.
+             */
+            mac_addresses: string[];
             ports: components["schemas"]["Port"][];
             services: components["schemas"]["Service"][];
         };
@@ -3010,6 +4066,11 @@ export interface components {
             daemon_id: string;
             /** Format: date-time */
             date: string;
+            /**
+             * @description What was discovered, for entities whose names aren't unique on a network, such as
+             *     "k8s:ingress:default/web:example.com/api". Used to find the entity on later runs.
+             */
+            identity?: string | null;
         };
         /** @enum {string} */
         DiscoveryPhase: "Pending" | "Starting" | "Started" | "Scanning" | "Complete" | "Failed" | "Cancelled";
@@ -3029,9 +4090,56 @@ export interface components {
             host_naming_fallback: components["schemas"]["HostNamingFallback"];
             /** @enum {string} */
             type: "Docker";
+        } | {
+            host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /** @description Namespaces to discover. Discovers all namespaces if not set. */
+            namespaces: string[] | null;
+            /** @enum {string} */
+            type: "Kubernetes";
+        } | {
+            /** @description Base URL of the Proxmox VE API, e.g. https://pve.example.com:8006 */
+            api_url: string;
+            /** @description Accept self-signed certificates, which Proxmox VE uses out of the box */
+            skip_tls_verify: boolean;
+            /** @enum {string} */
+            type: "Proxmox";
+        } | {
+            /** Format: int32 */
+            port: number;
+            /**
+             * @description Addresses of the hosts to log into. Keys or passwords come from the discovery's
+             *     SSH key and username/password credentials, tried in order.
+             */
+            targets: string[];
+            /** @enum {string} */
+            type: "SshInventory";
+        } | {
+            skip_tls_verify: boolean;
+            /**
+             * @description Addresses of the Windows hosts to query. Accounts come from the discovery's
+             *     username/password credentials, as `DOMAIN\user` or `user@domain`, tried in order.
+             */
+            targets: string[];
+            /** @enum {string} */
+            type: "WinRm";
+            /**
+             * @description Connect over HTTPS on 5986 rather than HTTP on 5985. Plain HTTP only works on hosts
+             *     that allow unencrypted WinRM traffic.
+             */
+            use_https: boolean;
+        } | {
+            /** @description Addresses outside the network to trace, on top of each subnet's gateway */
+            external_targets: string[];
+            /** Format: int32 */
+            max_hops: number;
+            protocol: components["schemas"]["TracerouteProtocol"];
+            /** @enum {string} */
+            type: "Traceroute";
         };
         /** @description Progress update from daemon to server during discovery */
         DiscoveryUpdatePayload: {
+            /** @description Credentials the daemon should fetch when it starts this session */
+            credential_ids?: string[];
             /** Format: uuid */
             daemon_id: string;
             discovery_type: components["schemas"]["DiscoveryType"];
@@ -3051,6 +4159,13 @@ export interface components {
         DockerVirtualization: {
             container_id?: string | null;
             container_name?: string | null;
+            /** @description Pod the container shares its network namespace with, if any */
+            pod_name: string | null;
+            /**
+             * @description Runtime the container was discovered through. Podman and containerd containers are
+             *     discovered with the same Docker-compatible models as Docker containers.
+             */
+            runtime: components["schemas"]["ContainerRuntime"];
             /** Format: uuid */
             service_id: string;
         };
@@ -3066,6 +4181,13 @@ export interface components {
             target: string;
             target_handle: components["schemas"]["EdgeHandle"];
         };
+        EdgeDiff: {
+            /** @description Empty for added and removed edges */
+            changes: components["schemas"]["FieldChange"][];
+            edge: components["schemas"]["Edge"];
+            /** @description Group a request path or hub-and-spoke edge belongs to */
+            group: null | components["schemas"]["Group"];
+        };
         /** @enum {string} */
         EdgeHandle: "Top" | "Bottom" | "Left" | "Right";
         /** @enum {string} */
@@ -3105,17 +4227,41 @@ export interface components {
             source_binding_id: string;
             /** Format: uuid */
             target_binding_id: string;
-        };
-        /** @enum {string} */
-        EdgeTypeDiscriminants: "Interface" | "HostVirtualization" | "ServiceVirtualization" | "RequestPath" | "HubAndSpoke";
-        /** @enum {string} */
-        EntityDiscriminants: "Organization" | "Invite" | "Share" | "Network" | "DaemonApiKey" | "UserApiKey" | "User" | "Tag" | "Discovery" | "Daemon" | "Host" | "Service" | "Port" | "Binding" | "Interface" | "Subnet" | "Group" | "Topology" | "GroupBinding" | "EntityTag" | "UserApiKeyNetworkAccess" | "UserNetworkAccess" | "Unknown";
-        EntityMetadata: {
-            color: components["schemas"]["Color"];
-            icon: string;
-            id: string;
-        };
-        EntitySource: {
+        } | {
+            /** @enum {string} */
+            edge_type: "SharedSubnet";
+            /** Format: uuid */
+            source_subnet_id: string;
+            /** Format: uuid */
+            target_subnet_id: string;
+        } | {
+            /** @enum {string} */
+            edge_type: "SharedHost";
+            /** Format: uuid */
+            source_host_id: string;
+            /** Format: uuid */
+            target_host_id: string;
+        } | {
+            /** @enum {string} */
+            edge_type: "NetworkLink";
+            /** Format: uuid */
+            network_link_id: string;
+        } | {
+            /** @enum {string} */
+            edge_type: "Route";
+            /** Format: uuid */
+            traceroute_id: string;
+        };
+        /** @enum {string} */
+        EdgeTypeDiscriminants: "Interface" | "HostVirtualization" | "ServiceVirtualization" | "RequestPath" | "HubAndSpoke" | "SharedSubnet" | "SharedHost" | "NetworkLink" | "Route";
+        /** @enum {string} */
+        EntityDiscriminants: "Organization" | "Invite" | "Share" | "Credential" | "Network" | "NetworkLink" | "DaemonApiKey" | "UserApiKey" | "User" | "Tag" | "Discovery" | "Traceroute" | "Daemon" | "Host" | "Service" | "Port" | "Binding" | "Interface" | "Subnet" | "Group" | "Topology" | "TopologySnapshot" | "GroupBinding" | "EntityTag" | "UserApiKeyNetworkAccess" | "UserNetworkAccess" | "UserMfaCredential" | "UserSession" | "ScimToken" | "ScimGroup" | "ShareToken" | "ShareView" | "Unknown";
+        EntityMetadata: {
+            color: components["schemas"]["Color"];
+            icon: string;
+            id: string;
+        };
+        EntitySource: {
             /** @enum {string} */
             type: "Manual";
         } | {
@@ -3134,6 +4280,32 @@ export interface components {
             /** @enum {string} */
             type: "Unknown";
         };
+        /**
+         * @description Diagram format for editing in other tools: Graphviz DOT, draw.io, Mermaid flowchart or
+         *     GraphML
+         * @enum {string}
+         */
+        ExportFormat: "dot" | "drawio" | "mermaid" | "graphml";
+        /** @description Export a stored topology as an editable diagram */
+        ExportTopologyRequest: {
+            format?: components["schemas"]["ExportFormat"];
+            /**
+             * @description Overrides the options saved with the topology. Edge type and service category filters
+             *     and `hide_ports` are applied to the stored graph; layout is not recomputed.
+             */
+            options?: null | components["schemas"]["TopologyOptions"];
+        };
+        /** @description An attribute of a node or edge that differs between the two snapshots */
+        FieldChange: {
+            after: string | null;
+            before: string | null;
+            /** @description Attribute name, such as `position`, `host.name` or `services` */
+            field: string;
+        };
+        FinishPasskeyRegistrationRequest: {
+            credential: Record<string, never>;
+            name?: string | null;
+        };
         ForgotPasswordRequest: {
             /** Format: email */
             email: string;
@@ -3383,12 +4555,54 @@ export interface components {
             x: number;
             y: number;
         };
+        KubernetesVirtualization: {
+            container_name?: string | null;
+            namespace: string;
+            node_name?: string | null;
+            pod_name?: string | null;
+            /** Format: uuid */
+            service_id: string;
+            /**
+             * @description Name of the controller that owns the pod (Deployment, StatefulSet, DaemonSet...)
+             *     Pods are replaced on every rollout, so this is what identifies a workload across runs
+             */
+            workload_name?: string | null;
+        };
+        LdapLoginRequest: {
+            /** @description Directory username, e.g. `jdoe` or `jdoe@corp.example.com` */
+            username: string;
+            password: string;
+        };
+        /** @description LDAP login details shown on the login page */
+        LdapProviderMetadata: {
+            name: string;
+        };
         /** @description Login request from client */
         LoginRequest: {
             /** Format: email */
             email: string;
             password: string;
         };
+        /** @description Result of a password login: either a signed-in user, or a pending second-factor step */
+        LoginResponse: {
+            /** @enum {string} */
+            status: "authenticated";
+            user: components["schemas"]["UserBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+        } | {
+            methods: components["schemas"]["MfaCredentialType"][];
+            /** @enum {string} */
+            status: "mfa_required";
+        } | {
+            /** @enum {string} */
+            status: "mfa_enrollment_required";
+        };
         /** @enum {string} */
         MatchConfidence: "NotApplicable" | "Low" | "Medium" | "High" | "Certain";
         MatchDetails: {
@@ -3419,6 +4633,45 @@ export interface components {
             service_definitions: components["schemas"]["TypeMetadata"][];
             subnet_types: components["schemas"]["TypeMetadata"][];
         };
+        /** @description An enrolled second factor, as shown in account settings */
+        MfaCredentialSummary: {
+            /** Format: date-time */
+            created_at: string;
+            credential_type: components["schemas"]["MfaCredentialType"];
+            /** Format: uuid */
+            id: string;
+            /** Format: date-time */
+            last_used_at?: string | null;
+            name: string;
+        };
+        /**
+         * @description Kind of second factor stored in a credential record
+         * @enum {string}
+         */
+        MfaCredentialType: "Totp" | "Passkey" | "RecoveryCode";
+        /** @description MFA state for the current user */
+        MfaStatusResponse: {
+            credentials: components["schemas"]["MfaCredentialSummary"][];
+            enabled: boolean;
+            passkeys_available: boolean;
+            recovery_codes_remaining: number;
+            /** @description Whether the user's organization requires MFA */
+            required: boolean;
+        };
+        /** @description Second-factor verification during login */
+        MfaVerifyRequest: {
+            code: string;
+            /** @enum {string} */
+            method: "totp";
+        } | {
+            code: string;
+            /** @enum {string} */
+            method: "recovery_code";
+        } | {
+            credential: Record<string, never>;
+            /** @enum {string} */
+            method: "passkey";
+        };
         /**
          * @example {
          *       "created_at": "2026-01-15T10:30:00Z",
@@ -3443,6 +4696,22 @@ export interface components {
             organization_id: string;
             tags: string[];
         };
+        NetworkLink: components["schemas"]["NetworkLinkBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        NetworkLinkBase: {
+            description?: string | null;
+            label?: string | null;
+            /** Format: uuid */
+            network_id: string;
+            /** Format: uuid */
+            target_network_id: string;
+        };
         /** @description Network configuration for setup */
         NetworkSetup: {
             name: string;
@@ -3454,6 +4723,20 @@ export interface components {
             position: components["schemas"]["Ixy"];
             size: components["schemas"]["Uxy"];
         };
+        /**
+         * @description A node with the entities it draws. Removed nodes carry the older snapshot's entities,
+         *     added and changed nodes the newer snapshot's.
+         */
+        NodeDiff: {
+            /** @description Empty for added and removed nodes */
+            changes: components["schemas"]["FieldChange"][];
+            host: null | components["schemas"]["Host"];
+            interface: null | components["schemas"]["Interface"];
+            node: components["schemas"]["Node"];
+            /** @description Services shown on the node */
+            services: components["schemas"]["Service"][];
+            subnet: null | components["schemas"]["Subnet"];
+        };
         NodeType: {
             infra_width: number;
             /** @enum {string} */
@@ -3468,6 +4751,44 @@ export interface components {
             node_type: "InterfaceNode";
             /** Format: uuid */
             subnet_id: string;
+        } | {
+            /** Format: uuid */
+            host_id: string;
+            /** @enum {string} */
+            node_type: "HostNode";
+        } | {
+            /** Format: uuid */
+            host_id: string;
+            /** @enum {string} */
+            node_type: "ServiceNode";
+            /** Format: uuid */
+            parent_id?: string | null;
+            /** Format: uuid */
+            service_id: string;
+        } | {
+            /** Format: uuid */
+            binding_id: string;
+            /** @enum {string} */
+            node_type: "StubNode";
+        } | {
+            /** Format: uuid */
+            network_id: string;
+            /** @enum {string} */
+            node_type: "NetworkNode";
+        } | {
+            /** Format: uuid */
+            network_id: string;
+            /** @enum {string} */
+            node_type: "NetworkSubnetNode";
+            /** Format: uuid */
+            subnet_id: string;
+        } | {
+            /** Format: uuid */
+            host_id: string;
+            /** Format: uuid */
+            network_id: string;
+            /** @enum {string} */
+            node_type: "NetworkHostNode";
         };
         OidcProviderMetadata: {
             logo?: string | null;
@@ -3487,6 +4808,8 @@ export interface components {
             onboarding: components["schemas"]["TelemetryOperation"][];
             plan: null | components["schemas"]["BillingPlan"];
             readonly plan_status: string | null;
+            /** @description Require members to complete a second factor when logging in with a password */
+            readonly require_mfa: boolean;
             readonly stripe_customer_id: string | null;
         };
         /**
@@ -3733,9 +5056,18 @@ export interface components {
             /** @description Auto-derived from number+protocol; optional on create */
             type?: string;
         };
+        /** @enum {string} */
+        ProxmoxGuestStatus: "Running" | "Stopped" | "Paused";
+        /** @enum {string} */
+        ProxmoxGuestType: "Qemu" | "Lxc";
         ProxmoxVirtualization: {
+            guest_type: null | components["schemas"]["ProxmoxGuestType"];
+            /** @description Cluster node the guest was last seen on */
+            node: string | null;
             /** Format: uuid */
             service_id: string;
+            /** @description Power state reported by the Proxmox VE API at the last discovery */
+            status: null | components["schemas"]["ProxmoxGuestStatus"];
             vm_id?: string | null;
             vm_name?: string | null;
         };
@@ -3746,6 +5078,7 @@ export interface components {
             has_email_opt_in: boolean;
             has_email_service: boolean;
             has_integrated_daemon: boolean;
+            ldap?: null | components["schemas"]["LdapProviderMetadata"];
             needs_cookie_consent: boolean;
             oidc_providers: components["schemas"]["OidcProviderMetadata"][];
             plunk_key?: string | null;
@@ -3769,6 +5102,29 @@ export interface components {
             password: string;
             terms_accepted: boolean;
         };
+        /** @enum {string} */
+        RenderFormat: "svg" | "png" | "pdf";
+        /**
+         * @description Paper size for PDF output. Pages are landscape; diagrams larger than one page are split
+         *     across several.
+         * @enum {string}
+         */
+        RenderPageSize: "A4" | "A3" | "Letter";
+        /** @description Render a stored topology to an image */
+        RenderTopologyRequest: {
+            format?: components["schemas"]["RenderFormat"];
+            /**
+             * @description Overrides the options saved with the topology. Edge type and service category filters
+             *     and `hide_ports` are applied to the stored graph; layout is not recomputed.
+             */
+            options?: null | components["schemas"]["TopologyOptions"];
+            page_size?: components["schemas"]["RenderPageSize"];
+            /**
+             * Format: float
+             * @description Pixel density for PNG and PDF output. Defaults to 2.
+             */
+            scale?: number | null;
+        };
         ResetPasswordRequest: {
             password: string;
             token: string;
@@ -3790,6 +5146,48 @@ export interface components {
             /** @enum {string} */
             type: "AdHoc";
         };
+        /**
+         * @description A group pushed by the identity provider over SCIM. Owners map each group onto
+         *     a role and network access, which is applied to its members.
+         */
+        ScimGroup: components["schemas"]["ScimGroupBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        /** @description The base data for a ScimGroup record */
+        ScimGroupBase: {
+            display_name: string;
+            external_id?: string | null;
+            /** @description Users in this group, as pushed by the identity provider */
+            member_ids?: string[];
+            /** @description Networks members get access to */
+            network_ids?: string[];
+            /** Format: uuid */
+            organization_id: string;
+            /** @description Role granted to members. The highest role across a user's groups applies. */
+            permissions?: null | components["schemas"]["UserOrgPermissions"];
+        };
+        /** @description SCIM provisioning status for the current organization */
+        ScimSettingsResponse: {
+            /** @description SCIM base URL to configure in the identity provider */
+            base_url: string;
+            /** @description Whether a SCIM token exists */
+            enabled: boolean;
+            groups: components["schemas"]["ScimGroup"][];
+            /** Format: date-time */
+            token_created_at?: string | null;
+            /** Format: date-time */
+            token_last_used?: string | null;
+        };
+        /** @description Newly generated SCIM token - only shown once */
+        ScimTokenResponse: {
+            base_url: string;
+            token: string;
+        };
         /** @description Server capabilities returned on startup/registration */
         ServerCapabilities: {
             /** @description Deprecation warnings for the daemon */
@@ -3890,6 +5288,10 @@ export interface components {
             details: components["schemas"]["DockerVirtualization"];
             /** @enum {string} */
             type: "Docker";
+        } | {
+            details: components["schemas"]["KubernetesVirtualization"];
+            /** @enum {string} */
+            type: "Kubernetes";
         };
         /** @description Request body for setting all tags on an entity */
         SetTagsRequest: {
@@ -3931,15 +5333,107 @@ export interface components {
             /** Format: uuid */
             network_id: string;
             options: components["schemas"]["ShareOptions"];
+            redaction: components["schemas"]["ShareRedaction"];
             /** Format: uuid */
             topology_id: string;
         };
         /** @description Share display options */
         ShareOptions: {
+            /** @description Push topology refreshes and staleness to viewers as they happen */
+            live_updates: boolean;
             show_export_button: boolean;
             show_inspect_panel: boolean;
             show_zoom_controls: boolean;
         };
+        /**
+         * @description What a share leaves out of the topology it serves. Applied on the server, so hidden data
+         *     never reaches viewers.
+         */
+        ShareRedaction: {
+            /** @description Names to show instead of the real ones, by host, subnet, service or group ID */
+            aliases: {
+                [key: string]: string;
+            };
+            hidden_host_tags: string[];
+            hide_ip_addresses: boolean;
+            hide_mac_addresses: boolean;
+        };
+        /**
+         * @description A named token granting access to a share in place of its password. Each one can be
+         *     revoked on its own, and views made with it are attributed to it.
+         */
+        ShareToken: components["schemas"]["ShareTokenBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+        };
+        ShareTokenBase: {
+            /** @description SHA-256 hash of the token */
+            readonly key: string;
+            /** Format: date-time */
+            readonly last_used: string | null;
+            name: string;
+            /** Format: date-time */
+            readonly revoked_at: string | null;
+            /** Format: uuid */
+            share_id: string;
+        };
+        /** @description A newly created share token. The plaintext key is only returned here. */
+        ShareTokenResponse: {
+            key: string;
+            token: components["schemas"]["ShareToken"];
+        };
+        /** @description A single load of a share's topology or image */
+        ShareView: components["schemas"]["ShareViewBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+        };
+        ShareViewBase: {
+            /** @description Country reported by a CDN in front of the server, if there is one */
+            country?: string | null;
+            embed: boolean;
+            /** @description The viewer's network rather than their address: a /24 for IPv4, a /48 for IPv6 */
+            ip_bucket: string;
+            /** @description Domain of the page the share was opened or embedded from */
+            referrer_domain?: string | null;
+            /** Format: uuid */
+            share_id: string;
+            /**
+             * Format: uuid
+             * @description Token the share was opened with, if any
+             */
+            token_id?: string | null;
+        };
+        /** @description Number of views for one referrer, country or token */
+        ShareViewCount: {
+            count: number;
+            key: string;
+        };
+        /** @description Aggregate view counters for a share */
+        ShareViewStats: {
+            countries: components["schemas"]["ShareViewCount"][];
+            /** @description Views with no referrer, such as links opened directly */
+            direct_views: number;
+            embed_views: number;
+            /** Format: date-time */
+            last_viewed_at?: string | null;
+            /** @description Latest views, newest first */
+            recent: components["schemas"]["ShareView"][];
+            referrers: components["schemas"]["ShareViewCount"][];
+            /** @description Views per access token, by token name */
+            tokens: components["schemas"]["ShareViewCount"][];
+            total_views: number;
+            /** @description Distinct viewer networks, as a rough count of visitors */
+            unique_visitors: number;
+        };
+        /**
+         * @description What caused a snapshot to be taken. Only automatic snapshots are pruned by retention.
+         * @enum {string}
+         */
+        SnapshotTrigger: "Manual" | "Discovery" | "Schedule";
         /**
          * @example {
          *       "cidr": "192.168.1.0/24",
@@ -4046,6 +5540,54 @@ export interface components {
             subnets: components["schemas"]["Subnet"][];
             tags: string[];
         };
+        /**
+         * @description Changes between two snapshots of the same topology. Nodes are matched by id and edges by
+         *     endpoints and type, since edge ids are regenerated on every rebuild. A node or edge whose
+         *     entity is listed as removed in the newer snapshot counts as removed even if the graph
+         *     hadn't been rebuilt yet.
+         */
+        TopologyDiff: {
+            added_edges: components["schemas"]["EdgeDiff"][];
+            added_nodes: components["schemas"]["NodeDiff"][];
+            changed_edges: components["schemas"]["EdgeDiff"][];
+            changed_nodes: components["schemas"]["NodeDiff"][];
+            /** Format: date-time */
+            from_created_at: string;
+            /** Format: uuid */
+            from_snapshot_id: string;
+            removed_edges: components["schemas"]["EdgeDiff"][];
+            removed_nodes: components["schemas"]["NodeDiff"][];
+            /** Format: date-time */
+            to_created_at: string;
+            /** Format: uuid */
+            to_snapshot_id: string;
+        };
+        /**
+         * @description Narrows a topology to a slice of its network. Every criterion is optional: empty lists and
+         *     an empty search leave that criterion out.
+         */
+        TopologyFilter: {
+            /** @description Hosts and services with any of these tags are left out */
+            exclude_tag_ids?: string[];
+            /** @description Only hosts with at least one of these tags */
+            include_tag_ids?: string[];
+            /**
+             * @description Case-insensitive match against host names, hostnames and service names. A host matching
+             *     the search keeps all of its services; otherwise only matching services are kept.
+             */
+            search?: string | null;
+            /** @description Only services in these categories */
+            service_categories?: components["schemas"]["ServiceCategory"][];
+            /** @description Only these subnets, and hosts with an interface on one of them */
+            subnet_ids?: string[];
+            /** @description Only subnets of these types, and hosts with an interface on one of them */
+            subnet_types?: components["schemas"]["SubnetType"][];
+        };
+        /**
+         * @description How the graph is built and laid out
+         * @enum {string}
+         */
+        TopologyLayoutMode: "Subnets" | "Routed" | "ServiceDependencies" | "HostFocus";
         TopologyLocalOptions: {
             hide_edge_types: components["schemas"]["EdgeTypeDiscriminants"][];
             hide_resize_handles: boolean;
@@ -4057,51 +5599,147 @@ export interface components {
             request: components["schemas"]["TopologyRequestOptions"];
         };
         TopologyRequestOptions: {
+            /**
+             * Format: uuid
+             * @description Host shown by the host focus layout. Nothing is drawn in that mode until one is set.
+             */
+            focus_host_id: string | null;
+            filter: components["schemas"]["TopologyFilter"];
             group_docker_bridges_by_host: boolean;
             hide_ports: boolean;
             hide_service_categories: components["schemas"]["ServiceCategory"][];
             hide_vm_title_on_docker_container: boolean;
+            layout_mode: components["schemas"]["TopologyLayoutMode"];
             left_zone_service_categories: components["schemas"]["ServiceCategory"][];
             show_gateway_in_left_zone: boolean;
         };
-        /** @enum {string} */
-        TransportProtocol: "Udp" | "Tcp";
-        TypeMetadata: {
-            category: string | null;
-            color: components["schemas"]["Color"];
-            description: string | null;
-            icon: string | null;
-            id: string;
-            metadata: unknown;
-            name: string | null;
-        };
-        UpdateEmailPasswordRequest: {
-            /** Format: email */
-            email?: string | null;
-            password?: string | null;
+        /** @description Immutable copy of a topology at a point in time */
+        TopologySnapshot: components["schemas"]["TopologySnapshotBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
         };
-        /**
-         * @description Request type for updating a host with its children.
-         *     Uses the same input types as CreateHostRequest.
-         *     Server will sync children (create new, update existing, delete removed) only if provided.
-         */
-        UpdateHostRequest: {
-            description?: string | null;
+        TopologySnapshotBase: {
+            bindings: components["schemas"]["Binding"][];
             /**
-             * Format: date-time
-             * @description Optional: expected updated_at timestamp for optimistic locking.
+             * Format: uuid
+             * @description User who took a manual snapshot
              */
-            expected_updated_at?: string | null;
-            hidden: boolean;
-            hostname?: string | null;
+            created_by: string | null;
+            edges: components["schemas"]["Edge"][];
+            groups: components["schemas"]["Group"][];
+            hosts: components["schemas"]["Host"][];
+            interfaces: components["schemas"]["Interface"][];
+            name: string;
             /** Format: uuid */
-            id: string;
+            network_id: string;
+            nodes: components["schemas"]["Node"][];
+            options: components["schemas"]["TopologyOptions"];
+            ports: components["schemas"]["Port"][];
+            removed_bindings: string[];
+            removed_groups: string[];
             /**
-             * @description Interfaces to sync with this host.
-             *     If Some, server will create/update/delete to match this list.
-             *     If None, existing interfaces are preserved.
+             * @description Entities deleted since the topology's graph was last rebuilt. Their nodes and edges
+             *     are still in the graph.
              */
-            interfaces?: components["schemas"]["InterfaceInput"][] | null;
+            removed_hosts: string[];
+            removed_interfaces: string[];
+            removed_ports: string[];
+            removed_services: string[];
+            removed_subnets: string[];
+            services: components["schemas"]["Service"][];
+            subnets: components["schemas"]["Subnet"][];
+            /** Format: uuid */
+            topology_id: string;
+            trigger: components["schemas"]["SnapshotTrigger"];
+        };
+        /** @description The path from a daemon to a target as measured by traceroute */
+        Traceroute: components["schemas"]["TracerouteBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        TracerouteBase: {
+            /**
+             * Format: uuid
+             * @description Daemon the trace was run from
+             */
+            daemon_id: string;
+            hops: components["schemas"]["TracerouteHop"][];
+            /** Format: uuid */
+            network_id: string;
+            protocol: components["schemas"]["TracerouteProtocol"];
+            /** @description Whether the target itself answered */
+            reached: boolean;
+            /**
+             * Format: uuid
+             * @description Subnet whose gateway was traced. Missing for external targets.
+             */
+            subnet_id?: string | null;
+            target: string;
+        };
+        /** @description One TTL step of a traceroute */
+        TracerouteHop: {
+            /** @description Router that answered. Missing when nothing answered at this TTL. */
+            ip?: string | null;
+            /**
+             * Format: int32
+             * @description Round trip time in microseconds
+             */
+            rtt_us?: number | null;
+            /** Format: int32 */
+            ttl: number;
+        };
+        /**
+         * @description Kind of probe a traceroute sends. Routers answer all of them the same way, but firewalls
+         *     often let one through where another is dropped.
+         * @enum {string}
+         */
+        TracerouteProtocol: "Icmp" | "Udp" | "Tcp";
+        /** @enum {string} */
+        TransportProtocol: "Udp" | "Tcp";
+        TypeMetadata: {
+            category: string | null;
+            color: components["schemas"]["Color"];
+            description: string | null;
+            icon: string | null;
+            id: string;
+            metadata: unknown;
+            name: string | null;
+        };
+        UpdateEmailPasswordRequest: {
+            /** Format: email */
+            email?: string | null;
+            password?: string | null;
+        };
+        /**
+         * @description Request type for updating a host with its children.
+         *     Uses the same input types as CreateHostRequest.
+         *     Server will sync children (create new, update existing, delete removed) only if provided.
+         */
+        UpdateHostRequest: {
+            description?: string | null;
+            /**
+             * Format: date-time
+             * @description Optional: expected updated_at timestamp for optimistic locking.
+             */
+            expected_updated_at?: string | null;
+            hidden: boolean;
+            hostname?: string | null;
+            /** Format: uuid */
+            id: string;
+            /**
+             * @description Interfaces to sync with this host.
+             *     If Some, server will create/update/delete to match this list.
+             *     If None, existing interfaces are preserved.
+             */
+            interfaces?: components["schemas"]["InterfaceInput"][] | null;
             name: string;
             /**
              * @description Ports to sync with this host.
@@ -4118,6 +5756,14 @@ export interface components {
             tags: string[];
             virtualization?: null | components["schemas"]["HostVirtualization"];
         };
+        UpdateMfaPolicyRequest: {
+            require_mfa: boolean;
+        };
+        /** @description Role and network access granted to members of a SCIM group */
+        UpdateScimGroupMappingRequest: {
+            network_ids?: string[];
+            permissions?: null | components["schemas"]["UserOrgPermissions"];
+        };
         User: components["schemas"]["UserBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -4135,6 +5781,13 @@ export interface components {
             readonly updated_at: string;
         };
         UserApiKeyBase: {
+            /**
+             * @description Client addresses allowed to use this key, as CIDRs. Empty allows any address.
+             * @example [
+             *       "10.0.0.0/8"
+             *     ]
+             */
+            allowed_ips: string[];
             /** Format: date-time */
             expires_at?: string | null;
             is_enabled?: boolean;
@@ -4147,6 +5800,20 @@ export interface components {
             /** Format: uuid */
             organization_id: string;
             permissions?: components["schemas"]["UserOrgPermissions"];
+            /**
+             * Format: int32
+             * @description Requests per minute allowed for this key, on top of the account-wide limit
+             */
+            rate_limit_per_minute?: number | null;
+            /**
+             * @description Restricts the key to these `resource:action` grants, e.g. `hosts:read`.
+             *     Empty means the permission level applies to every endpoint.
+             * @example [
+             *       "hosts:read",
+             *       "discovery:trigger"
+             *     ]
+             */
+            scopes: string[];
             tags: string[];
             /** Format: uuid */
             user_id: string;
@@ -4161,6 +5828,12 @@ export interface components {
             key: string;
         };
         UserBase: {
+            /**
+             * Format: date-time
+             * @description Set when the account is deactivated (e.g. by SCIM deprovisioning). Deactivated
+             *     users can't sign in and their API keys are disabled.
+             */
+            readonly deactivated_at?: string | null;
             email: string;
             network_ids: string[];
             /** Format: date-time */
@@ -4175,6 +5848,19 @@ export interface components {
         };
         /** @enum {string} */
         UserOrgPermissions: "Owner" | "Admin" | "Member" | "Viewer";
+        /** @description A signed-in session, as shown to its user or an admin */
+        UserSessionResponse: {
+            /** Format: date-time */
+            created_at: string;
+            /** Format: uuid */
+            id: string;
+            ip_address: string;
+            /** @description Whether this is the session making the request */
+            is_current: boolean;
+            /** Format: date-time */
+            last_activity_at: string;
+            user_agent?: string | null;
+        };
         Uxy: {
             x: number;
             y: number;
@@ -4200,38 +5886,1459 @@ export interface components {
             server_version: string;
         };
     };
-    responses: never;
-    parameters: never;
-    requestBodies: never;
-    headers: never;
-    pathItems: never;
-}
-export type $defs = Record<string, never>;
-export interface operations {
-    daemon_setup: {
+    responses: never;
+    parameters: never;
+    requestBodies: never;
+    headers: never;
+    pathItems: never;
+}
+export type $defs = Record<string, never>;
+export interface operations {
+    daemon_setup: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonSetupRequest"];
+            };
+        };
+        responses: {
+            /** @description Daemon setup data stored */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonSetupResponse"];
+                };
+            };
+            /** @description Invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    forgot_password: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ForgotPasswordRequest"];
+            };
+        };
+        responses: {
+            /** @description Password reset email sent */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+        };
+    };
+    ldap_login: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["LdapLoginRequest"];
+            };
+        };
+        responses: {
+            /** @description Login successful, or second factor required */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_LoginResponse"];
+                };
+            };
+            /** @description Invalid credentials */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Login forbidden */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    login: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["LoginRequest"];
+            };
+        };
+        responses: {
+            /** @description Login successful, or second factor required */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Invalid credentials */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Login forbidden */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    logout: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Logout successful */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+        };
+    };
+    get_current_user: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Current user */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    unlink_oidc_account: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description OIDC provider slug */
+                slug: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description OIDC account unlinked */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Blocked in demo mode */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Provider not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    register: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["RegisterRequest"];
+            };
+        };
+        responses: {
+            /** @description User registered successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Registration disabled */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Email already exists */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    reset_password: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ResetPasswordRequest"];
+            };
+        };
+        responses: {
+            /** @description Password reset successful, or second factor required */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Invalid or expired token */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_mfa_status: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description MFA status for the current user */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_MfaStatusResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    begin_totp_enrollment: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description TOTP secret generated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_TotpEnrollmentResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    confirm_totp_enrollment: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ConfirmTotpRequest"];
+            };
+        };
+        responses: {
+            /** @description Authenticator app enabled */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_MfaEnrollmentResponse"];
+                };
+            };
+            /** @description Invalid code */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    begin_passkey_registration: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Passkey registration options */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_PasskeyChallengeResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    finish_passkey_registration: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["FinishPasskeyRegistrationRequest"];
+            };
+        };
+        responses: {
+            /** @description Passkey registered */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_MfaEnrollmentResponse"];
+                };
+            };
+            /** @description Registration failed */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_mfa_credential: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Credential ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Credential removed */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Credential required by organization policy */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    regenerate_recovery_codes: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description New recovery codes; previous codes are invalidated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_RecoveryCodesResponse"];
+                };
+            };
+            /** @description MFA not enabled */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    begin_passkey_authentication: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Passkey authentication options */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_PasskeyChallengeResponse"];
+                };
+            };
+            /** @description No login awaiting verification */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    verify_mfa: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["MfaVerifyRequest"];
+            };
+        };
+        responses: {
+            /** @description Second factor accepted, session issued */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Verification failed */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_sessions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Signed-in sessions for the current user */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Vec_UserSessionResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    revoke_session: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Session ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Session revoked */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Session not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    revoke_other_sessions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description All other sessions revoked */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_RevokeSessionsResponse"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    setup: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["SetupRequest"];
+            };
+        };
+        responses: {
+            /** @description Setup data stored */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_SetupResponse"];
+                };
+            };
+            /** @description Invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_password_auth: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["UpdateEmailPasswordRequest"];
+            };
+        };
+        responses: {
+            /** @description Password updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_User"];
+                };
+            };
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Blocked in demo mode */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    create_checkout_session: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateCheckoutRequest"];
+            };
+        };
+        responses: {
+            /** @description Checkout session URL */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_String"];
+                };
+            };
+            /** @description Invalid plan or billing not enabled */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_billing_plans: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of available billing plans */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Vec_BillingPlan"];
+                };
+            };
+            /** @description Billing not enabled */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    create_portal_session: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "text/plain": string;
+            };
+        };
+        responses: {
+            /** @description Portal session URL */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_String"];
+                };
+            };
+            /** @description Billing not enabled */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    handle_webhook: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Webhook processed */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Invalid signature or billing not enabled */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_public_config: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Public server configuration */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_PublicConfigResponse"];
+                };
+            };
+        };
+    };
+    register_daemon: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonRegistrationRequest"];
+            };
+        };
+        responses: {
+            /** @description Daemon registered successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonRegistrationResponse"];
+                };
+            };
+            /** @description Daemon registration disabled in demo mode */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    receive_heartbeat: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonHeartbeatPayload"];
+            };
+        };
+        responses: {
+            /** @description Heartbeat received */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    receive_work_request: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonHeartbeatPayload"];
+            };
+        };
+        responses: {
+            /** @description Work request processed - returns (Option<DiscoveryUpdatePayload>, bool) */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    daemon_startup: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonStartupRequest"];
+            };
+        };
+        responses: {
+            /** @description Startup acknowledged */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_ServerCapabilities"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_capabilities: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonCapabilities"];
+            };
+        };
+        responses: {
+            /** @description Capabilities updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_stars: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description GitHub star count */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_u32"];
+                };
+            };
+        };
+    };
+    get_metadata_registry: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Metadata registry */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_MetadataRegistry"];
+                };
+            };
+        };
+    };
+    list_daemon_api_keys: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of daemon_api_keys */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["DaemonApiKey"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_daemon_api_key: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonApiKey"];
+            };
+        };
+        responses: {
+            /** @description Daemon API key created */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonApiKeyResponse"];
+                };
+            };
+            /** @description Bad request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Insufficient permissions (member+ required) */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Internal server error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_daemon_api_keys: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of daemon_api_keys IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description DaemonApiKeys deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+        };
+    };
+    get_daemon_api_key_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description DaemonApiKey ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description DaemonApiKey found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                };
+            };
+            /** @description DaemonApiKey not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_daemon_api_key: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon API key ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonApiKey"];
+            };
+        };
+        responses: {
+            /** @description Daemon API key updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                };
+            };
+            /** @description Daemon API key not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_daemon_api_key: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description DaemonApiKey ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description DaemonApiKey deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description DaemonApiKey not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    rotate_key_handler: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Daemon API key ID */
+                id: string;
+            };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DaemonSetupRequest"];
+        requestBody?: never;
+        responses: {
+            /** @description Daemon API key rotated, returns new key */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_String"];
+                };
+            };
+            /** @description Daemon API key not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_all_user_api_keys: {
+        parameters: {
+            query?: {
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Daemon setup data stored */
+            /** @description List of user API keys */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonSetupResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_UserApiKey"];
                 };
             };
-            /** @description Invalid request */
-            400: {
+            /** @description Not authenticated */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Internal server error */
+            500: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4241,7 +7348,7 @@ export interface operations {
             };
         };
     };
-    forgot_password: {
+    create_user_api_key: {
         parameters: {
             query?: never;
             header?: never;
@@ -4250,54 +7357,96 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["ForgotPasswordRequest"];
+                "application/json": components["schemas"]["UserApiKey"];
             };
         };
         responses: {
-            /** @description Password reset email sent */
+            /** @description API key created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_UserApiKeyResponse"];
+                };
+            };
+            /** @description Bad request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Invalid permissions or network access */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Internal server error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    login: {
+    bulk_delete_user_api_keys: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
+        /** @description Array of API key IDs to delete */
         requestBody: {
             content: {
-                "application/json": components["schemas"]["LoginRequest"];
+                "application/json": string[];
             };
         };
         responses: {
-            /** @description Login successful */
+            /** @description API keys deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Invalid credentials */
-            401: {
+        };
+    };
+    get_user_api_key_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description API key ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description API key found */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_UserApiKey"];
                 };
             };
-            /** @description Login forbidden */
-            403: {
+            /** @description API key not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4307,46 +7456,74 @@ export interface operations {
             };
         };
     };
-    logout: {
+    update_user_api_key: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description API key ID */
+                id: string;
+            };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["UserApiKey"];
+            };
+        };
         responses: {
-            /** @description Logout successful */
+            /** @description API key updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_UserApiKey"];
+                };
+            };
+            /** @description Not authorized to update this key */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description API key not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_current_user: {
+    delete_user_api_key: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description API key ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Current user */
+            /** @description API key deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Not authenticated */
-            401: {
+            /** @description API key not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4356,29 +7533,29 @@ export interface operations {
             };
         };
     };
-    unlink_oidc_account: {
+    rotate_user_api_key: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description OIDC provider slug */
-                slug: string;
+                /** @description API key ID */
+                id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description OIDC account unlinked */
+            /** @description API key rotated, returns new key */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
+                    "application/json": components["schemas"]["ApiResponse_String"];
                 };
             };
-            /** @description Not authenticated */
-            401: {
+            /** @description Not authorized to rotate this key */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4386,8 +7563,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Blocked in demo mode */
-            403: {
+            /** @description API key not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4395,18 +7572,47 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Provider not found */
-            404: {
+        };
+    };
+    list_bindings: {
+        parameters: {
+            query?: {
+                /** @description Filter by service ID */
+                service_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by port ID */
+                port_id?: string | null;
+                /** @description Filter by interface ID */
+                interface_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of bindings */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Binding"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    register: {
+    create_binding: {
         parameters: {
             query?: never;
             header?: never;
@@ -4415,20 +7621,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["RegisterRequest"];
+                "application/json": components["schemas"]["Binding"];
             };
         };
         responses: {
-            /** @description User registered successfully */
+            /** @description Binding created (superseded bindings may be removed) */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
+                    "application/json": components["schemas"]["ApiResponse_Binding"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Referenced port or interface does not exist */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -4437,16 +7643,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Registration disabled */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description Email already exists */
+            /** @description Conflict with existing binding type */
             409: {
                 headers: {
                     [name: string]: unknown;
@@ -4457,63 +7654,54 @@ export interface operations {
             };
         };
     };
-    reset_password: {
+    bulk_delete_bindings: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
+        /** @description Array of bindings IDs to delete */
         requestBody: {
             content: {
-                "application/json": components["schemas"]["ResetPasswordRequest"];
+                "application/json": string[];
             };
         };
         responses: {
-            /** @description Password reset successful */
+            /** @description Bindings deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
-                };
-            };
-            /** @description Invalid or expired token */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    setup: {
+    get_binding_by_id: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["SetupRequest"];
+            path: {
+                /** @description Binding ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Setup data stored */
+            /** @description Binding found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_SetupResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Binding"];
                 };
             };
-            /** @description Invalid request */
-            400: {
+            /** @description Binding not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4523,30 +7711,33 @@ export interface operations {
             };
         };
     };
-    update_password_auth: {
+    update_binding: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Binding ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["UpdateEmailPasswordRequest"];
+                "application/json": components["schemas"]["Binding"];
             };
         };
         responses: {
-            /** @description Password updated */
+            /** @description Binding updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
+                    "application/json": components["schemas"]["ApiResponse_Binding"];
                 };
             };
-            /** @description Not authenticated */
-            401: {
+            /** @description Referenced port or interface does not exist */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4554,8 +7745,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Blocked in demo mode */
-            403: {
+            /** @description Conflict with existing binding type */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4565,30 +7756,29 @@ export interface operations {
             };
         };
     };
-    create_checkout_session: {
+    delete_binding: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["CreateCheckoutRequest"];
+            path: {
+                /** @description Binding ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Checkout session URL */
+            /** @description Binding deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_String"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Invalid plan or billing not enabled */
-            400: {
+            /** @description Binding not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4598,36 +7788,41 @@ export interface operations {
             };
         };
     };
-    get_billing_plans: {
+    list_credentials: {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
             header?: never;
             path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description List of available billing plans */
+            /** @description List of credentials */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Vec_BillingPlan"];
-                };
-            };
-            /** @description Billing not enabled */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Credential"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    create_portal_session: {
+    create_credential: {
         parameters: {
             query?: never;
             header?: never;
@@ -4636,20 +7831,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "text/plain": string;
+                "application/json": components["schemas"]["CreateUpdateCredentialRequest"];
             };
         };
         responses: {
-            /** @description Portal session URL */
+            /** @description Credential created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_String"];
+                    "application/json": components["schemas"]["ApiResponse_Credential"];
                 };
             };
-            /** @description Billing not enabled */
+            /** @description Missing secret or credential storage not configured */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -4660,79 +7855,90 @@ export interface operations {
             };
         };
     };
-    handle_webhook: {
+    bulk_delete_credentials: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        /** @description Array of credentials IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description Webhook processed */
+            /** @description Credentials deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
-                };
-            };
-            /** @description Invalid signature or billing not enabled */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    get_public_config: {
+    get_credential_by_id: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Credential ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Public server configuration */
+            /** @description Credential found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_PublicConfigResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Credential"];
+                };
+            };
+            /** @description Credential not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    register_daemon: {
+    update_credential: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Credential ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DaemonRegistrationRequest"];
+                "application/json": components["schemas"]["CreateUpdateCredentialRequest"];
             };
         };
         responses: {
-            /** @description Daemon registered successfully */
+            /** @description Credential updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonRegistrationResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Credential"];
                 };
             };
-            /** @description Daemon registration disabled in demo mode */
-            403: {
+            /** @description Credential not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -4742,23 +7948,19 @@ export interface operations {
             };
         };
     };
-    receive_heartbeat: {
+    delete_credential: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon ID */
+                /** @description Credential ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DaemonHeartbeatPayload"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Heartbeat received */
+            /** @description Credential deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -4767,7 +7969,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Daemon not found */
+            /** @description Credential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -4778,77 +7980,61 @@ export interface operations {
             };
         };
     };
-    receive_work_request: {
+    get_daemons: {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Daemon ID */
-                id: string;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DaemonHeartbeatPayload"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Work request processed - returns (Option<DiscoveryUpdatePayload>, bool) */
+            /** @description List of daemons */
             200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content?: never;
-            };
-            /** @description Daemon not found */
-            404: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_DaemonResponse"];
                 };
             };
         };
     };
-    daemon_startup: {
+    bulk_delete_daemons: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Daemon ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
+        /** @description Array of daemons IDs to delete */
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DaemonStartupRequest"];
+                "application/json": string[];
             };
         };
         responses: {
-            /** @description Startup acknowledged */
+            /** @description Daemons deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_ServerCapabilities"];
-                };
-            };
-            /** @description Daemon not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    update_capabilities: {
+    get_daemon_by_id: {
         parameters: {
             query?: never;
             header?: never;
@@ -4858,19 +8044,24 @@ export interface operations {
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DaemonCapabilities"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Capabilities updated */
+            /** @description Daemon found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_DaemonResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
             /** @description Daemon not found */
@@ -4884,53 +8075,45 @@ export interface operations {
             };
         };
     };
-    get_stars: {
+    delete_daemon: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description GitHub star count */
+            /** @description Daemon deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_u32"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-        };
-    };
-    get_metadata_registry: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Metadata registry */
-            200: {
+            /** @description Daemon not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_MetadataRegistry"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    list_daemon_api_keys: {
+    list_discoveries: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
+                /** @description Filter by daemon ID */
+                daemon_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -4942,14 +8125,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of daemon_api_keys */
+            /** @description List of discoveries */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["DaemonApiKey"][];
+                        data: components["schemas"]["Discovery"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -4958,7 +8141,7 @@ export interface operations {
             };
         };
     };
-    create_daemon_api_key: {
+    create_discovery: {
         parameters: {
             query?: never;
             header?: never;
@@ -4967,20 +8150,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DaemonApiKey"];
+                "application/json": components["schemas"]["Discovery"];
             };
         };
         responses: {
-            /** @description Daemon API key created */
+            /** @description Discovery created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonApiKeyResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
                 };
             };
-            /** @description Bad request */
+            /** @description Can't create historical discovery */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -4989,73 +8172,108 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Insufficient permissions (member+ required) */
-            403: {
+        };
+    };
+    get_active_sessions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of active discovery sessions */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Vec_DiscoveryUpdatePayload"];
                 };
             };
-            /** @description Internal server error */
-            500: {
+        };
+    };
+    bulk_delete_discoveries: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of discoveries IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Discoverys deleted */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    bulk_delete_daemon_api_keys: {
+    start_session: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of daemon_api_keys IDs to delete */
         requestBody: {
             content: {
-                "application/json": string[];
+                "text/plain": string;
             };
         };
         responses: {
-            /** @description DaemonApiKeys deleted */
+            /** @description Discovery session started */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_DiscoveryUpdatePayload"];
+                };
+            };
+            /** @description Discovery not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_daemon_api_key_by_id: {
+    get_discovery_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description DaemonApiKey ID */
+                /** @description Discovery ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description DaemonApiKey found */
+            /** @description Discovery found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
                 };
             };
-            /** @description DaemonApiKey not found */
+            /** @description Discovery not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5066,33 +8284,33 @@ export interface operations {
             };
         };
     };
-    update_daemon_api_key: {
+    update_discovery: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon API key ID */
+                /** @description Discovery ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DaemonApiKey"];
+                "application/json": components["schemas"]["Discovery"];
             };
         };
         responses: {
-            /** @description Daemon API key updated */
+            /** @description Discovery updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
                 };
             };
-            /** @description Daemon API key not found */
-            404: {
+            /** @description Can't update historical discovery */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5102,19 +8320,19 @@ export interface operations {
             };
         };
     };
-    delete_daemon_api_key: {
+    delete_discovery: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description DaemonApiKey ID */
+                /** @description Discovery ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description DaemonApiKey deleted */
+            /** @description Discovery deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -5123,7 +8341,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description DaemonApiKey not found */
+            /** @description Discovery not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5134,63 +8352,52 @@ export interface operations {
             };
         };
     };
-    rotate_key_handler: {
+    cancel_discovery: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon API key ID */
-                id: string;
+                /** @description Session ID */
+                session_id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon API key rotated, returns new key */
+            /** @description Discovery session cancelled */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_String"];
-                };
-            };
-            /** @description Daemon API key not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
         };
     };
-    get_all_user_api_keys: {
+    get_session_credentials: {
         parameters: {
-            query?: {
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
+            query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Discovery session ID */
+                session_id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description List of user API keys */
+            /** @description Session credentials */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_UserApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_Vec_DiscoveryCredential"];
                 };
             };
-            /** @description Not authenticated */
-            401: {
+            /** @description Session belongs to another daemon */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5198,8 +8405,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Internal server error */
-            500: {
+            /** @description Session not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5209,48 +8416,91 @@ export interface operations {
             };
         };
     };
-    create_user_api_key: {
+    receive_discovery_update: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Discovery session ID */
+                session_id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["UserApiKey"];
+                "application/json": components["schemas"]["DiscoveryUpdatePayload"];
             };
         };
         responses: {
-            /** @description API key created */
+            /** @description Update received */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_UserApiKeyResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Bad request */
-            400: {
+        };
+    };
+    list_groups: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of groups */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Group"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
-            /** @description Invalid permissions or network access */
-            403: {
+        };
+    };
+    create_group: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Group"];
+            };
+        };
+        responses: {
+            /** @description Group created successfully */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Group"];
                 };
-            };
-            /** @description Internal server error */
-            500: {
+            };
+            /** @description Invalid request */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5260,21 +8510,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_user_api_keys: {
+    bulk_delete_groups: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of API key IDs to delete */
+        /** @description Array of groups IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description API keys deleted */
+            /** @description Groups deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -5285,28 +8535,28 @@ export interface operations {
             };
         };
     };
-    get_user_api_key_by_id: {
+    get_group_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description API key ID */
+                /** @description Group ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description API key found */
+            /** @description Group found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_UserApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_Group"];
                 };
             };
-            /** @description API key not found */
+            /** @description Group not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5317,33 +8567,33 @@ export interface operations {
             };
         };
     };
-    update_user_api_key: {
+    update_group: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description API key ID */
+                /** @description Group ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["UserApiKey"];
+                "application/json": components["schemas"]["Group"];
             };
         };
         responses: {
-            /** @description API key updated */
+            /** @description Group updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_UserApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_Group"];
                 };
             };
-            /** @description Not authorized to update this key */
-            403: {
+            /** @description Invalid request */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5351,7 +8601,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description API key not found */
+            /** @description Group not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5362,19 +8612,19 @@ export interface operations {
             };
         };
     };
-    delete_user_api_key: {
+    delete_group: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description API key ID */
+                /** @description Group ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description API key deleted */
+            /** @description Group deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -5383,48 +8633,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description API key not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-        };
-    };
-    rotate_user_api_key: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description API key ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description API key rotated, returns new key */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_String"];
-                };
-            };
-            /** @description Not authorized to rotate this key */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description API key not found */
+            /** @description Group not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5435,17 +8644,13 @@ export interface operations {
             };
         };
     };
-    list_bindings: {
+    get_all_hosts: {
         parameters: {
             query?: {
-                /** @description Filter by service ID */
-                service_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by port ID */
-                port_id?: string | null;
-                /** @description Filter by interface ID */
-                interface_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -5457,23 +8662,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of bindings */
+            /** @description List of hosts with their children */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Binding"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_HostResponse"];
                 };
             };
         };
     };
-    create_binding: {
+    create_host: {
         parameters: {
             query?: never;
             header?: never;
@@ -5482,20 +8682,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Binding"];
+                "application/json": components["schemas"]["CreateHostRequest"];
             };
         };
         responses: {
-            /** @description Binding created (superseded bindings may be removed) */
+            /** @description Host created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Binding"];
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
                 };
             };
-            /** @description Referenced port or interface does not exist */
+            /** @description Validation error: network not found, subnet mismatch, or invalid tags */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -5504,8 +8704,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Conflict with existing binding type */
-            409: {
+            /** @description No access to the specified network */
+            401: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5515,21 +8715,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_bindings: {
+    bulk_delete_hosts: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of bindings IDs to delete */
+        /** @description Array of host IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Bindings deleted */
+            /** @description Hosts deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -5538,31 +8738,41 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
+            /** @description One or more hosts has an associated daemon - delete daemons first */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    get_binding_by_id: {
+    create_host_discovery: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Binding ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DiscoveryHostRequest"];
+            };
+        };
         responses: {
-            /** @description Binding found */
+            /** @description Host discovered/updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Binding"];
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
                 };
             };
-            /** @description Binding not found */
-            404: {
+            /** @description Daemon cannot create hosts on other networks */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5572,32 +8782,30 @@ export interface operations {
             };
         };
     };
-    update_binding: {
+    consolidate_hosts: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Binding ID */
-                id: string;
+                /** @description Destination host ID - will receive all children */
+                destination_host: string;
+                /** @description Host to merge into destination - will be deleted */
+                other_host: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Binding"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Binding updated */
+            /** @description Hosts consolidated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Binding"];
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
                 };
             };
-            /** @description Referenced port or interface does not exist */
+            /** @description Validation error: same host, has daemon, or different networks */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -5606,8 +8814,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Conflict with existing binding type */
-            409: {
+            /** @description One or both hosts not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5617,28 +8825,28 @@ export interface operations {
             };
         };
     };
-    delete_binding: {
+    get_host_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Binding ID */
+                /** @description Host ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Binding deleted */
+            /** @description Host found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
                 };
             };
-            /** @description Binding not found */
+            /** @description Host not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5649,83 +8857,33 @@ export interface operations {
             };
         };
     };
-    get_daemons: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description List of daemons */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_DaemonResponse"];
-                };
-            };
-        };
-    };
-    bulk_delete_daemons: {
+    update_host: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
             cookie?: never;
         };
-        /** @description Array of daemons IDs to delete */
         requestBody: {
             content: {
-                "application/json": string[];
-            };
-        };
-        responses: {
-            /** @description Daemons deleted */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
-                };
-            };
-        };
-    };
-    get_daemon_by_id: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Daemon ID */
-                id: string;
+                "application/json": components["schemas"]["UpdateHostRequest"];
             };
-            cookie?: never;
         };
-        requestBody?: never;
         responses: {
-            /** @description Daemon found */
+            /** @description Host updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonResponse"];
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
                 };
             };
-            /** @description Access denied */
-            403: {
+            /** @description Validation error: invalid tags */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5733,7 +8891,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Daemon not found */
+            /** @description Host not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5744,19 +8902,19 @@ export interface operations {
             };
         };
     };
-    delete_daemon: {
+    delete_host: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon ID */
+                /** @description Host ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon deleted */
+            /** @description Host deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -5765,7 +8923,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Daemon not found */
+            /** @description Host not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5774,15 +8932,26 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Host has associated daemon */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    list_discoveries: {
+    list_interfaces: {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by subnet ID */
+                subnet_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by daemon ID */
-                daemon_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -5794,14 +8963,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of discoveries */
+            /** @description List of interfaces */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Discovery"][];
+                        data: components["schemas"]["Interface"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -5810,7 +8979,7 @@ export interface operations {
             };
         };
     };
-    create_discovery: {
+    create_interface: {
         parameters: {
             query?: never;
             header?: never;
@@ -5819,20 +8988,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Discovery"];
+                "application/json": components["schemas"]["Interface"];
             };
         };
         responses: {
-            /** @description Discovery created successfully */
+            /** @description Interface created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
                 };
             };
-            /** @description Can't create historical discovery */
+            /** @description Network mismatch or invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -5843,74 +9012,106 @@ export interface operations {
             };
         };
     };
-    get_active_sessions: {
+    bulk_delete_interfaces: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description List of active discovery sessions */
+            /** @description Interfaces deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Vec_DiscoveryUpdatePayload"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+            /** @description No IDs provided */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    bulk_delete_discoveries: {
+    get_interface_by_id: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        /** @description Array of discoveries IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
+            path: {
+                /** @description Interface ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Discoverys deleted */
+            /** @description Interface found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                };
+            };
+            /** @description Interface not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    start_session: {
+    update_interface: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Interface ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "text/plain": string;
+                "application/json": components["schemas"]["Interface"];
             };
         };
         responses: {
-            /** @description Discovery session started */
+            /** @description Interface updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DiscoveryUpdatePayload"];
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
                 };
             };
-            /** @description Discovery not found */
+            /** @description Network mismatch or invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Interface not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5921,28 +9122,28 @@ export interface operations {
             };
         };
     };
-    get_discovery_by_id: {
+    delete_interface: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Discovery ID */
+                /** @description Interface ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Discovery found */
+            /** @description Interface deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Discovery not found */
+            /** @description Interface not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -5953,33 +9154,50 @@ export interface operations {
             };
         };
     };
-    update_discovery: {
+    get_invites: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Discovery ID */
-                id: string;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of active invites */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Vec_Invite"];
+                };
             };
+        };
+    };
+    create_invite: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Discovery"];
+                "application/json": components["schemas"]["CreateInviteRequest"];
             };
         };
         responses: {
-            /** @description Discovery updated successfully */
+            /** @description Invite created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse_Invite"];
                 };
             };
-            /** @description Can't update historical discovery */
-            400: {
+            /** @description Cannot create invite with higher permissions */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5989,29 +9207,38 @@ export interface operations {
             };
         };
     };
-    delete_discovery: {
+    get_invite: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Discovery ID */
+                /** @description Invite ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Discovery deleted */
+            /** @description Invite details */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Invite"];
                 };
             };
-            /** @description Discovery not found */
-            404: {
+            /** @description Invalid or expired invite */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6021,19 +9248,19 @@ export interface operations {
             };
         };
     };
-    cancel_discovery: {
+    revoke_invite: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Session ID */
-                session_id: string;
+                /** @description Invite ID */
+                id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Discovery session cancelled */
+            /** @description Invite revoked */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6042,42 +9269,31 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-        };
-    };
-    receive_discovery_update: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Discovery session ID */
-                session_id: string;
-            };
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DiscoveryUpdatePayload"];
+            /** @description Invalid invite */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
             };
-        };
-        responses: {
-            /** @description Update received */
-            200: {
+            /** @description Cannot revoke this invite */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    list_groups: {
+    list_network_links: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -6089,14 +9305,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of groups */
+            /** @description List of network_links */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Group"][];
+                        data: components["schemas"]["NetworkLink"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -6105,7 +9321,7 @@ export interface operations {
             };
         };
     };
-    create_group: {
+    create_network_link: {
         parameters: {
             query?: never;
             header?: never;
@@ -6114,20 +9330,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Group"];
+                "application/json": components["schemas"]["NetworkLink"];
             };
         };
         responses: {
-            /** @description Group created successfully */
+            /** @description Network link created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Group"];
+                    "application/json": components["schemas"]["ApiResponse_NetworkLink"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Both ends are the same network */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -6136,23 +9352,32 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description No access to one of the networks */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    bulk_delete_groups: {
+    bulk_delete_network_links: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of groups IDs to delete */
+        /** @description Array of network_links IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Groups deleted */
+            /** @description NetworkLinks deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6163,28 +9388,28 @@ export interface operations {
             };
         };
     };
-    get_group_by_id: {
+    get_network_link_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description NetworkLink ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Group found */
+            /** @description NetworkLink found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Group"];
+                    "application/json": components["schemas"]["ApiResponse_NetworkLink"];
                 };
             };
-            /** @description Group not found */
+            /** @description NetworkLink not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6195,33 +9420,42 @@ export interface operations {
             };
         };
     };
-    update_group: {
+    update_network_link: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description NetworkLink ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Group"];
+                "application/json": components["schemas"]["NetworkLink"];
             };
         };
         responses: {
-            /** @description Group updated successfully */
+            /** @description Network link updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Group"];
+                    "application/json": components["schemas"]["ApiResponse_NetworkLink"];
+                };
+            };
+            /** @description Both ends are the same network */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Invalid request */
-            400: {
+            /** @description No access to one of the networks */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6229,7 +9463,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Group not found */
+            /** @description Network link not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6240,19 +9474,19 @@ export interface operations {
             };
         };
     };
-    delete_group: {
+    delete_network_link: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description NetworkLink ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Group deleted */
+            /** @description NetworkLink deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6261,7 +9495,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Group not found */
+            /** @description NetworkLink not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6272,13 +9506,9 @@ export interface operations {
             };
         };
     };
-    get_all_hosts: {
+    list_networks: {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -6290,18 +9520,23 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of hosts with their children */
+            /** @description List of networks */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_HostResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Network"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    create_host: {
+    create_network: {
         parameters: {
             query?: never;
             header?: never;
@@ -6310,30 +9545,46 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateHostRequest"];
+                "application/json": components["schemas"]["Network"];
             };
         };
         responses: {
-            /** @description Host created successfully */
+            /** @description Network created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description Validation error: network not found, subnet mismatch, or invalid tags */
-            400: {
+        };
+    };
+    bulk_delete_networks: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of network IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Networks deleted successfully */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description No access to the specified network */
-            401: {
+            /** @description User not admin */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6343,31 +9594,29 @@ export interface operations {
             };
         };
     };
-    bulk_delete_hosts: {
+    get_network_by_id: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        /** @description Array of host IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
+            path: {
+                /** @description Network ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Hosts deleted successfully */
+            /** @description Network found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description One or more hosts has an associated daemon - delete daemons first */
-            409: {
+            /** @description Network not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6377,29 +9626,32 @@ export interface operations {
             };
         };
     };
-    create_host_discovery: {
+    update_network: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Network ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DiscoveryHostRequest"];
+                "application/json": components["schemas"]["Network"];
             };
         };
         responses: {
-            /** @description Host discovered/updated successfully */
+            /** @description Network updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description Daemon cannot create hosts on other networks */
+            /** @description User not admin */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -6408,33 +9660,40 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Network not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    consolidate_hosts: {
+    delete_network: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Destination host ID - will receive all children */
-                destination_host: string;
-                /** @description Host to merge into destination - will be deleted */
-                other_host: string;
+                /** @description Network ID */
+                id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Hosts consolidated successfully */
+            /** @description Network deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Validation error: same host, has daemon, or different networks */
-            400: {
+            /** @description User not admin */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6442,7 +9701,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description One or both hosts not found */
+            /** @description Network not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6453,28 +9712,70 @@ export interface operations {
             };
         };
     };
-    get_host_by_id: {
+    get_organization: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Organization details */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
+                };
+            };
+            /** @description Organization not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_org_name: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "text/plain": string;
+            };
+        };
         responses: {
-            /** @description Host found */
+            /** @description Organization updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
                 };
             };
-            /** @description Host not found */
+            /** @description Only owners can update organization */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Organization not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6485,33 +9786,33 @@ export interface operations {
             };
         };
     };
-    update_host: {
+    update_mfa_policy: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["UpdateHostRequest"];
+                "application/json": components["schemas"]["UpdateMfaPolicyRequest"];
             };
         };
         responses: {
-            /** @description Host updated */
+            /** @description MFA policy updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
                 };
             };
-            /** @description Validation error: invalid tags */
-            400: {
+            /** @description Only owners can change the MFA policy */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6519,7 +9820,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host not found */
+            /** @description Organization not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6530,19 +9831,19 @@ export interface operations {
             };
         };
     };
-    delete_host: {
+    populate_demo_data: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Host deleted */
+            /** @description Demo data populated */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6551,7 +9852,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Host not found */
+            /** @description Only available for demo organizations */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Organization not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6560,8 +9870,40 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host has associated daemon */
-            409: {
+        };
+    };
+    reset: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Organization ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Organization reset */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Cannot reset another organization */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Organization not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6571,15 +9913,15 @@ export interface operations {
             };
         };
     };
-    list_interfaces: {
+    list_ports: {
         parameters: {
             query?: {
                 /** @description Filter by host ID */
                 host_id?: string | null;
-                /** @description Filter by subnet ID */
-                subnet_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -6591,14 +9933,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of interfaces */
+            /** @description List of ports */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Interface"][];
+                        data: components["schemas"]["Port"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -6607,7 +9949,7 @@ export interface operations {
             };
         };
     };
-    create_interface: {
+    create_port: {
         parameters: {
             query?: never;
             header?: never;
@@ -6616,20 +9958,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Interface"];
+                "application/json": components["schemas"]["Port"];
             };
         };
         responses: {
-            /** @description Interface created successfully */
+            /** @description Port created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description Network mismatch or invalid request */
+            /** @description Network mismatch or duplicate port */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -6640,20 +9982,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_interfaces: {
+    bulk_delete_ports: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
+        /** @description Array of ports IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Interfaces deleted successfully */
+            /** @description Ports deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6662,39 +10005,30 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description No IDs provided */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    get_interface_by_id: {
+    get_port_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Interface found */
+            /** @description Port found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description Interface not found */
+            /** @description Port not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6705,29 +10039,29 @@ export interface operations {
             };
         };
     };
-    update_interface: {
+    update_port: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Interface"];
+                "application/json": components["schemas"]["Port"];
             };
         };
         responses: {
-            /** @description Interface updated successfully */
+            /** @description Port updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
             /** @description Network mismatch or invalid request */
@@ -6739,7 +10073,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Interface not found */
+            /** @description Port not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6750,19 +10084,19 @@ export interface operations {
             };
         };
     };
-    delete_interface: {
+    delete_port: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Interface deleted successfully */
+            /** @description Port deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6771,7 +10105,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Interface not found */
+            /** @description Port not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6782,7 +10116,7 @@ export interface operations {
             };
         };
     };
-    get_invites: {
+    get_scim_settings: {
         parameters: {
             query?: never;
             header?: never;
@@ -6791,40 +10125,52 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of active invites */
+            /** @description SCIM settings */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Vec_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_ScimSettingsResponse"];
+                };
+            };
+            /** @description Only owners can manage SCIM provisioning */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_invite: {
+    update_scim_group_mapping: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description SCIM group ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateInviteRequest"];
+                "application/json": components["schemas"]["UpdateScimGroupMappingRequest"];
             };
         };
         responses: {
-            /** @description Invite created */
+            /** @description Group mapping updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_ScimGroup"];
                 };
             };
-            /** @description Cannot create invite with higher permissions */
+            /** @description Only owners can manage SCIM provisioning */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -6833,39 +10179,36 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Group not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    get_invite: {
+    rotate_scim_token: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Invite ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Invite details */
+            /** @description Token generated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Invite"];
-                };
-            };
-            /** @description Invalid or expired invite */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_ScimTokenResponse"];
                 };
             };
-            /** @description Access denied */
+            /** @description Only owners can manage SCIM provisioning */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -6876,19 +10219,16 @@ export interface operations {
             };
         };
     };
-    revoke_invite: {
+    revoke_scim_token: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Invite ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Invite revoked */
+            /** @description Token revoked */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6897,16 +10237,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Invalid invite */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description Cannot revoke this invite */
+            /** @description Only owners can manage SCIM provisioning */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -6917,9 +10248,15 @@ export interface operations {
             };
         };
     };
-    list_networks: {
+    list_services: {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -6931,14 +10268,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of networks */
+            /** @description List of services */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Network"][];
+                        data: components["schemas"]["Service"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -6947,7 +10284,7 @@ export interface operations {
             };
         };
     };
-    create_network: {
+    create_service: {
         parameters: {
             query?: never;
             header?: never;
@@ -6956,36 +10293,45 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Network"];
+                "application/json": components["schemas"]["CreateServiceRequest"];
             };
         };
         responses: {
-            /** @description Network created */
+            /** @description Service created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
+                };
+            };
+            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    bulk_delete_networks: {
+    bulk_delete_services: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of network IDs to delete */
+        /** @description Array of services IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Networks deleted successfully */
+            /** @description Services deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6994,39 +10340,30 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description User not admin */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    get_network_by_id: {
+    get_service_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Network found */
+            /** @description Service found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Network not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7037,33 +10374,33 @@ export interface operations {
             };
         };
     };
-    update_network: {
+    update_service: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Network"];
+                "application/json": components["schemas"]["Service"];
             };
         };
         responses: {
-            /** @description Network updated */
+            /** @description Service updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description User not admin */
-            403: {
+            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7071,7 +10408,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Network not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7082,19 +10419,19 @@ export interface operations {
             };
         };
     };
-    delete_network: {
+    delete_service: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Network deleted */
+            /** @description Service deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7103,8 +10440,75 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description User not admin */
-            403: {
+            /** @description Service not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_shares: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by topology ID */
+                topology_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of shares */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["Share"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_share: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+            };
+        };
+        responses: {
+            /** @description Share created */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Share"];
+                };
+            };
+            /** @description Invalid request */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7112,36 +10516,55 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Network not found */
-            404: {
+        };
+    };
+    bulk_delete_shares: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of shares IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Shares deleted */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    get_organization: {
+    get_public_share_metadata: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Organization details */
+            /** @description Share metadata */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Organization"];
+                    "application/json": components["schemas"]["ApiResponse_PublicShareMetadata"];
                 };
             };
-            /** @description Organization not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7152,12 +10575,12 @@ export interface operations {
             };
         };
     };
-    update_org_name: {
+    verify_share_password: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
@@ -7168,17 +10591,17 @@ export interface operations {
             };
         };
         responses: {
-            /** @description Organization updated */
+            /** @description Password verified */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Organization"];
+                    "application/json": components["schemas"]["ApiResponse_bool"];
                 };
             };
-            /** @description Only owners can update organization */
-            403: {
+            /** @description Invalid password */
+            401: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7186,7 +10609,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Organization not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7197,29 +10620,35 @@ export interface operations {
             };
         };
     };
-    populate_demo_data: {
+    get_share_image: {
         parameters: {
-            query?: never;
+            query?: {
+                format?: components["schemas"]["RenderFormat"];
+                scale?: number | null;
+                page_size?: components["schemas"]["RenderPageSize"];
+            };
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Demo data populated */
+            /** @description Rendered topology */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "image/svg+xml": string;
+                    "image/png": number[];
+                    "application/pdf": number[];
                 };
             };
-            /** @description Only available for demo organizations */
-            403: {
+            /** @description Invalid render options */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7227,8 +10656,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Organization not found */
-            404: {
+            /** @description Share is password protected */
+            401: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7236,30 +10665,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    reset: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Organization ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Organization reset */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse"];
-                };
-            };
-            /** @description Cannot reset another organization */
+            /** @description Export disabled for this share */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -7268,7 +10674,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Organization not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7279,66 +10685,65 @@ export interface operations {
             };
         };
     };
-    list_ports: {
+    get_share_by_id: {
         parameters: {
-            query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
+            query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description List of ports */
+            /** @description Share found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Port"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["ApiResponse_Share"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_port: {
+    update_share: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Port"];
+                "application/json": components["schemas"]["CreateUpdateShareRequest"];
             };
         };
         responses: {
-            /** @description Port created successfully */
+            /** @description Share updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_Share"];
                 };
             };
-            /** @description Network mismatch or duplicate port */
-            400: {
+            /** @description Share not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7348,53 +10753,60 @@ export interface operations {
             };
         };
     };
-    bulk_delete_ports: {
+    delete_share: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        /** @description Array of ports IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
+            path: {
+                /** @description Share ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Ports deleted */
+            /** @description Share deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_port_by_id: {
+    list_share_tokens: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Port found */
+            /** @description Share tokens */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_Vec_ShareToken"];
                 };
             };
-            /** @description Port not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7405,32 +10817,32 @@ export interface operations {
             };
         };
     };
-    update_port: {
+    create_share_token: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Port"];
+                "application/json": components["schemas"]["CreateShareTokenRequest"];
             };
         };
         responses: {
-            /** @description Port updated successfully */
+            /** @description Share token created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_ShareTokenResponse"];
                 };
             };
-            /** @description Network mismatch or invalid request */
+            /** @description Invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7439,7 +10851,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Port not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7450,28 +10862,30 @@ export interface operations {
             };
         };
     };
-    delete_port: {
+    revoke_share_token: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Share ID */
                 id: string;
+                /** @description Share token ID */
+                token_id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Port deleted */
+            /** @description Share token revoked */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_ShareToken"];
                 };
             };
-            /** @description Port not found */
+            /** @description Share or token not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7482,11 +10896,41 @@ export interface operations {
             };
         };
     };
-    list_services: {
+    get_share_view_stats: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Share view statistics */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_ShareViewStats"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_subnets: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
                 /** @description Filter by specific entity IDs (for selective loading) */
@@ -7502,23 +10946,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of services */
+            /** @description List of subnets */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Service"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_Subnet"];
                 };
             };
         };
     };
-    create_service: {
+    create_subnet: {
         parameters: {
             query?: never;
             header?: never;
@@ -7527,20 +10966,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateServiceRequest"];
+                "application/json": components["schemas"]["Subnet"];
             };
         };
         responses: {
-            /** @description Service created successfully */
+            /** @description Subnet created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_Subnet"];
                 };
             };
-            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            /** @description Invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7551,21 +10990,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_services: {
+    bulk_delete_subnets: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of services IDs to delete */
+        /** @description Array of subnets IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Services deleted */
+            /** @description Subnets deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7576,28 +11015,28 @@ export interface operations {
             };
         };
     };
-    get_service_by_id: {
+    get_subnet_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description Subnet ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Service found */
+            /** @description Subnet found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_Subnet"];
                 };
             };
-            /** @description Service not found */
+            /** @description Subnet not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7608,32 +11047,32 @@ export interface operations {
             };
         };
     };
-    update_service: {
+    update_subnet: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description Subnet ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Service"];
+                "application/json": components["schemas"]["Subnet"];
             };
         };
         responses: {
-            /** @description Service updated */
+            /** @description Subnet updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_Subnet"];
                 };
             };
-            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            /** @description CIDR change would orphan existing interfaces */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7642,7 +11081,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Service not found */
+            /** @description Subnet not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7653,19 +11092,19 @@ export interface operations {
             };
         };
     };
-    delete_service: {
+    delete_subnet: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description Subnet ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Service deleted */
+            /** @description Subnet deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7674,7 +11113,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Service not found */
+            /** @description Subnet not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7685,13 +11124,9 @@ export interface operations {
             };
         };
     };
-    list_shares: {
+    list_tags: {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by topology ID */
-                topology_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -7703,14 +11138,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of shares */
+            /** @description List of tags */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Share"][];
+                        data: components["schemas"]["Tag"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -7719,7 +11154,7 @@ export interface operations {
             };
         };
     };
-    create_share: {
+    create_tag: {
         parameters: {
             query?: never;
             header?: never;
@@ -7728,20 +11163,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+                "application/json": components["schemas"]["Tag"];
             };
         };
         responses: {
-            /** @description Share created */
+            /** @description Tag created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Validation error: name empty or too long */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7750,55 +11185,91 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Tag name already exists in this organization */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    bulk_delete_shares: {
+    set_entity_tags: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of shares IDs to delete */
         requestBody: {
             content: {
-                "application/json": string[];
+                "application/json": components["schemas"]["SetTagsRequest"];
             };
         };
         responses: {
-            /** @description Shares deleted */
+            /** @description Tags set successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Invalid entity type or tag */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Tag not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_public_share_metadata: {
+    bulk_add_tag: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Share ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["BulkTagRequest"];
+            };
+        };
         responses: {
-            /** @description Share metadata */
+            /** @description Tag added successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_PublicShareMetadata"];
+                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
                 };
             };
-            /** @description Share not found */
+            /** @description Invalid entity type or tag */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7809,33 +11280,30 @@ export interface operations {
             };
         };
     };
-    verify_share_password: {
+    bulk_remove_tag: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Share ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody: {
             content: {
-                "text/plain": string;
+                "application/json": components["schemas"]["BulkTagRequest"];
             };
         };
         responses: {
-            /** @description Password verified */
+            /** @description Tag removed successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_bool"];
+                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
                 };
             };
-            /** @description Invalid password */
-            401: {
+            /** @description Invalid entity type */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7843,39 +11311,55 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Share not found */
-            404: {
+        };
+    };
+    bulk_delete_tags: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of tags IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Tags deleted */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    get_share_by_id: {
+    get_tag_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description Tag ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Share found */
+            /** @description Tag found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
                 };
             };
-            /** @description Share not found */
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7886,32 +11370,32 @@ export interface operations {
             };
         };
     };
-    update_share: {
+    update_tag: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description Tag ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+                "application/json": components["schemas"]["Tag"];
             };
         };
         responses: {
-            /** @description Share updated */
+            /** @description Tag updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
                 };
             };
-            /** @description Share not found */
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7922,19 +11406,19 @@ export interface operations {
             };
         };
     };
-    delete_share: {
+    delete_tag: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description Tag ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Share deleted */
+            /** @description Tag deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7943,7 +11427,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Share not found */
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7954,7 +11438,7 @@ export interface operations {
             };
         };
     };
-    list_subnets: {
+    get_all_topologies: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
@@ -7972,18 +11456,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of subnets */
+            /** @description List of topologies */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Subnet"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_Topology"];
                 };
             };
         };
     };
-    create_subnet: {
+    create_topology: {
         parameters: {
             query?: never;
             header?: never;
@@ -7992,20 +11476,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Subnet"];
+                "application/json": components["schemas"]["Topology"];
             };
         };
         responses: {
-            /** @description Subnet created successfully */
+            /** @description Topology created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Subnet"];
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Validation failed */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8016,53 +11500,116 @@ export interface operations {
             };
         };
     };
-    bulk_delete_subnets: {
+    get_organization_topology: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of subnets IDs to delete */
+        requestBody?: never;
+        responses: {
+            /** @description Organization topology */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
+            };
+        };
+    };
+    get_topology_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Topology found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
+            };
+            /** @description Topology not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_topology: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
+            cookie?: never;
+        };
         requestBody: {
             content: {
-                "application/json": string[];
+                "application/json": components["schemas"]["Topology"];
             };
         };
         responses: {
-            /** @description Subnets deleted */
+            /** @description Topology updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
+            };
+            /** @description Topology not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_subnet_by_id: {
+    delete_topology: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Subnet ID */
+                /** @description Topology ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Subnet found */
+            /** @description Topology deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Subnet"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Subnet not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8073,33 +11620,29 @@ export interface operations {
             };
         };
     };
-    update_subnet: {
+    lock: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Subnet ID */
+                /** @description Topology ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Subnet"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Subnet updated */
+            /** @description Topology locked */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Subnet"];
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
                 };
             };
-            /** @description CIDR change would orphan existing interfaces */
-            400: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8107,7 +11650,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Subnet not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8118,19 +11661,23 @@ export interface operations {
             };
         };
     };
-    delete_subnet: {
+    rebuild: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Subnet ID */
+                /** @description Topology ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Topology"];
+            };
+        };
         responses: {
-            /** @description Subnet deleted */
+            /** @description Topology rebuilt */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8139,8 +11686,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Subnet not found */
-            404: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8150,60 +11697,65 @@ export interface operations {
             };
         };
     };
-    list_tags: {
+    refresh: {
         parameters: {
-            query?: {
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
+            query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Topology"];
+            };
+        };
         responses: {
-            /** @description List of tags */
+            /** @description Topology refreshed */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Tag"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_tag: {
+    unlock: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Tag"];
+            path: {
+                /** @description Topology ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Tag created successfully */
+            /** @description Topology unlocked */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
                 };
             };
-            /** @description Validation error: name empty or too long */
-            400: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8211,8 +11763,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Tag name already exists in this organization */
-            409: {
+            /** @description Topology not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8222,29 +11774,34 @@ export interface operations {
             };
         };
     };
-    set_entity_tags: {
+    render_topology: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["SetTagsRequest"];
+                "application/json": components["schemas"]["RenderTopologyRequest"];
             };
         };
         responses: {
-            /** @description Tags set successfully */
+            /** @description Rendered topology */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "image/svg+xml": string;
+                    "image/png": number[];
+                    "application/pdf": number[];
                 };
             };
-            /** @description Invalid entity type or tag */
+            /** @description Invalid render options */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8253,7 +11810,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8264,30 +11830,36 @@ export interface operations {
             };
         };
     };
-    bulk_add_tag: {
+    export_topology: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["BulkTagRequest"];
+                "application/json": components["schemas"]["ExportTopologyRequest"];
             };
         };
         responses: {
-            /** @description Tag added successfully */
+            /** @description Exported topology */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
+                    "text/vnd.graphviz": string;
+                    "application/vnd.jgraph.mxfile": string;
+                    "text/vnd.mermaid": string;
+                    "application/graphml+xml": string;
                 };
             };
-            /** @description Invalid entity type or tag */
-            400: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8295,7 +11867,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8306,86 +11878,72 @@ export interface operations {
             };
         };
     };
-    bulk_remove_tag: {
+    list_snapshots: {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by topology ID */
+                topology_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["BulkTagRequest"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Tag removed successfully */
+            /** @description List of snapshots */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
-                };
-            };
-            /** @description Invalid entity type */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": {
+                        data: components["schemas"]["TopologySnapshot"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    bulk_delete_tags: {
+    create_snapshot: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of tags IDs to delete */
         requestBody: {
             content: {
-                "application/json": string[];
+                "application/json": components["schemas"]["CreateSnapshotRequest"];
             };
         };
         responses: {
-            /** @description Tags deleted */
+            /** @description Snapshot created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_TopologySnapshot"];
                 };
             };
-        };
-    };
-    get_tag_by_id: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Tag ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Tag found */
-            200: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8396,32 +11954,28 @@ export interface operations {
             };
         };
     };
-    update_tag: {
+    get_snapshot_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description TopologySnapshot ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Tag"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Tag updated */
+            /** @description TopologySnapshot found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                    "application/json": components["schemas"]["ApiResponse_TopologySnapshot"];
                 };
             };
-            /** @description Tag not found */
+            /** @description TopologySnapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8432,19 +11986,19 @@ export interface operations {
             };
         };
     };
-    delete_tag: {
+    delete_snapshot: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description TopologySnapshot ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Tag deleted */
+            /** @description TopologySnapshot deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8453,69 +12007,66 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description TopologySnapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-        };
-    };
-    get_all_topologies: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
             };
+        };
+    };
+    bulk_delete_snapshots: {
+        parameters: {
+            query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        /** @description Array of snapshots IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description List of topologies */
+            /** @description TopologySnapshots deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    create_topology: {
+    diff_snapshots: {
         parameters: {
-            query?: never;
+            query: {
+                /** @description Snapshot ID */
+                from: string;
+                /** @description Snapshot ID */
+                to: string;
+            };
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Topology"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Topology created */
+            /** @description Snapshot diff */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse_TopologyDiff"];
                 };
             };
-            /** @description Validation failed */
+            /** @description Snapshots belong to different topologies */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8524,30 +12075,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    get_topology_by_id: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Topology ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Topology found */
-            200: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description Snapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8558,33 +12095,35 @@ export interface operations {
             };
         };
     };
-    update_topology: {
+    render_snapshot: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description Snapshot ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Topology"];
+                "application/json": components["schemas"]["RenderTopologyRequest"];
             };
         };
         responses: {
-            /** @description Topology updated */
+            /** @description Rendered snapshot */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "image/svg+xml": string;
+                    "image/png": number[];
+                    "application/pdf": number[];
                 };
             };
-            /** @description Topology not found */
-            404: {
+            /** @description Invalid render options */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8592,30 +12131,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    delete_topology: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Topology ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Topology deleted */
-            200: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description Snapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8626,25 +12151,32 @@ export interface operations {
             };
         };
     };
-    lock: {
+    export_snapshot: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description Snapshot ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ExportTopologyRequest"];
+            };
+        };
         responses: {
-            /** @description Topology locked */
+            /** @description Exported snapshot */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "text/vnd.graphviz": string;
+                    "application/vnd.jgraph.mxfile": string;
+                    "text/vnd.mermaid": string;
+                    "application/graphml+xml": string;
                 };
             };
             /** @description Access denied */
@@ -8656,7 +12188,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description Snapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8667,68 +12199,95 @@ export interface operations {
             };
         };
     };
-    rebuild: {
+    list_traceroutes: {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Topology ID */
-                id: string;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Topology"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Topology rebuilt */
+            /** @description List of traceroutes */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Traceroute"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
-            /** @description Access denied */
-            403: {
+        };
+    };
+    bulk_delete_traceroutes: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of traceroutes IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Traceroutes deleted */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    refresh: {
+    create_traceroute_discovery: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Topology ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Topology"];
+                "application/json": components["schemas"]["Traceroute"];
             };
         };
         responses: {
-            /** @description Topology refreshed */
+            /** @description Traceroute discovered/updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Traceroute"];
                 };
             };
-            /** @description Access denied */
+            /** @description Subnet is not on the daemon's network */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Daemon cannot report traceroutes for other networks or daemons */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -8739,29 +12298,29 @@ export interface operations {
             };
         };
     };
-    unlock: {
+    get_traceroute_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description Traceroute ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Topology unlocked */
+            /** @description Traceroute found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse_Traceroute"];
                 };
             };
-            /** @description Access denied */
-            403: {
+            /** @description Traceroute not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8769,7 +12328,30 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
+        };
+    };
+    delete_traceroute: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Traceroute ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Traceroute deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Traceroute not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9020,6 +12602,88 @@ export interface operations {
             };
         };
     };
+    get_user_sessions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description User ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description User's signed-in sessions */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Vec_UserSessionResponse"];
+                };
+            };
+            /** @description Cannot manage user with higher permissions */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description User not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    revoke_user_sessions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description User ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Sessions revoked */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_RevokeSessionsResponse"];
+                };
+            };
+            /** @description Cannot manage user with higher permissions */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description User not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
     get_version: {
         parameters: {
             query?: never;
@@ -12,12 +12,16 @@ import type {
 	DaemonSetupRequest,
 	DaemonSetupResponse,
 	ForgotPasswordRequest,
+	LdapLoginRequest,
 	LoginRequest,
+	LoginResponse,
+	MfaVerifyRequest,
 	RegisterRequest,
 	ResetPasswordRequest,
 	SetupRequest,
 	SetupResponse
 } from './types/base';
+import { createPasskey, getPasskey } from './webauthn';
 
 /**
  * Query hook for fetching current authenticated user
@@ -53,13 +57,38 @@ export function useLoginMutation() {
 			}
 			return data.data;
 		},
-		onSuccess: (user: User) => {
-			queryClient.setQueryData(queryKeys.auth.currentUser(), user);
-			// Mark that user has an account (for redirect logic after logout)
-			if (typeof localStorage !== 'undefined') {
-				localStorage.setItem('hasAccount', 'true');
+		onSuccess: (result: LoginResponse) => {
+			// A second factor may still be required before the session is issued
+			if (result.status === 'authenticated') {
+				setSignedInUser(queryClient, result.user as User);
+				pushSuccess(`Welcome back, ${result.user.email}!`);
+			}
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for signing in with directory (LDAP) credentials
+ */
+export function useLdapLoginMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (request: LdapLoginRequest) => {
+			const { data } = await apiClient.POST('/api/auth/ldap/login', { body: request });
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Login failed. Please check your credentials.');
+			}
+			return data.data;
+		},
+		onSuccess: (result: LoginResponse) => {
+			if (result.status === 'authenticated') {
+				setSignedInUser(queryClient, result.user as User);
+				pushSuccess(`Welcome back, ${result.user.email}!`);
 			}
-			pushSuccess(`Welcome back, ${user.email}!`);
 		},
 		onError: (error: Error) => {
 			pushError(error.message);
@@ -157,14 +186,12 @@ export function useResetPasswordMutation() {
 			}
 			return data.data;
 		},
-		onSuccess: (user: User) => {
-			queryClient.setQueryData(queryKeys.auth.currentUser(), user);
-			// Mark that user has an account (for redirect logic after logout)
-			if (typeof localStorage !== 'undefined') {
-				localStorage.setItem('hasAccount', 'true');
-			}
+		onSuccess: (result: LoginResponse) => {
 			pushSuccess('Your password has been reset');
-			pushSuccess(`Welcome, ${user.email}!`);
+			if (result.status === 'authenticated') {
+				setSignedInUser(queryClient, result.user as User);
+				pushSuccess(`Welcome, ${result.user.email}!`);
+			}
 		}
 	}));
 }
@@ -205,6 +232,279 @@ export function useDaemonSetupMutation() {
 	}));
 }
 
+/**
+ * Query hook for the current user's MFA status and enrolled factors
+ */
+export function useMfaStatusQuery() {
+	return createQuery(() => ({
+		queryKey: queryKeys.auth.mfa(),
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/auth/mfa', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to load MFA status');
+			}
+			return data.data;
+		}
+	}));
+}
+
+/**
+ * Mutation hook for completing a login with a TOTP or recovery code
+ */
+export function useVerifyMfaMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (request: MfaVerifyRequest) => {
+			const { data } = await apiClient.POST('/api/auth/mfa/verify', { body: request });
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Verification failed');
+			}
+			return data.data;
+		},
+		onSuccess: (user: User) => {
+			setSignedInUser(queryClient, user);
+			pushSuccess(`Welcome back, ${user.email}!`);
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for completing a login with a passkey
+ */
+export function useVerifyPasskeyMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async () => {
+			const { data: challenge } = await apiClient.POST('/api/auth/mfa/verify/passkey', {});
+			if (!challenge?.success || !challenge.data) {
+				throw new Error(challenge?.error || 'Failed to start passkey sign-in');
+			}
+
+			const credential = await getPasskey(challenge.data.options);
+
+			const { data } = await apiClient.POST('/api/auth/mfa/verify', {
+				body: { method: 'passkey', credential: credential as Record<string, never> }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Passkey verification failed');
+			}
+			return data.data;
+		},
+		onSuccess: (user: User) => {
+			setSignedInUser(queryClient, user);
+			pushSuccess(`Welcome back, ${user.email}!`);
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for generating a TOTP secret to enrol an authenticator app
+ */
+export function useBeginTotpEnrollmentMutation() {
+	return createMutation(() => ({
+		mutationFn: async () => {
+			const { data } = await apiClient.POST('/api/auth/mfa/totp', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to start authenticator setup');
+			}
+			return data.data;
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for confirming an authenticator app with its first code.
+ * Resolves to recovery codes when this is the user's first factor.
+ */
+export function useConfirmTotpEnrollmentMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (request: { code: string; name?: string | null }) => {
+			const { data } = await apiClient.POST('/api/auth/mfa/totp/confirm', { body: request });
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Invalid code');
+			}
+			return data.data.recovery_codes ?? null;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.auth.mfa() });
+			pushSuccess('Authenticator app enabled');
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for registering a passkey on this device.
+ * Resolves to recovery codes when this is the user's first factor.
+ */
+export function useRegisterPasskeyMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (name: string | null) => {
+			const { data: challenge } = await apiClient.POST('/api/auth/mfa/passkeys', {});
+			if (!challenge?.success || !challenge.data) {
+				throw new Error(challenge?.error || 'Failed to start passkey registration');
+			}
+
+			const credential = await createPasskey(challenge.data.options);
+
+			const { data } = await apiClient.POST('/api/auth/mfa/passkeys/confirm', {
+				body: { name, credential: credential as Record<string, never> }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Passkey registration failed');
+			}
+			return data.data.recovery_codes ?? null;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.auth.mfa() });
+			pushSuccess('Passkey added');
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for removing an authenticator app or passkey
+ */
+export function useDeleteMfaCredentialMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (id: string) => {
+			const { data } = await apiClient.DELETE('/api/auth/mfa/credentials/{id}', {
+				params: { path: { id } }
+			});
+			if (!data?.success) {
+				throw new Error(data?.error || 'Failed to remove credential');
+			}
+			return id;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.auth.mfa() });
+			pushSuccess('Credential removed');
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for replacing all recovery codes
+ */
+export function useRegenerateRecoveryCodesMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async () => {
+			const { data } = await apiClient.POST('/api/auth/mfa/recovery-codes', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to generate recovery codes');
+			}
+			return data.data.codes;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.auth.mfa() });
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Query hook for the current user's signed-in sessions
+ */
+export function useSessionsQuery() {
+	return createQuery(() => ({
+		queryKey: queryKeys.auth.sessions(),
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/auth/sessions', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to load sessions');
+			}
+			return data.data;
+		}
+	}));
+}
+
+/**
+ * Mutation hook for signing out one of the current user's sessions
+ */
+export function useRevokeSessionMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (id: string) => {
+			const { data } = await apiClient.DELETE('/api/auth/sessions/{id}', {
+				params: { path: { id } }
+			});
+			if (!data?.success) {
+				throw new Error(data?.error || 'Failed to revoke session');
+			}
+			return id;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.auth.sessions() });
+			pushSuccess('Session signed out');
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+/**
+ * Mutation hook for signing out every session except the current one
+ */
+export function useRevokeOtherSessionsMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async () => {
+			const { data } = await apiClient.POST('/api/auth/sessions/revoke-others', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to sign out other sessions');
+			}
+			return data.data.revoked;
+		},
+		onSuccess: (revoked: number) => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.auth.sessions() });
+			pushSuccess(`Signed out ${revoked} other session${revoked === 1 ? '' : 's'}`);
+		},
+		onError: (error: Error) => {
+			pushError(error.message);
+		}
+	}));
+}
+
+function setSignedInUser(queryClient: ReturnType<typeof useQueryClient>, user: User) {
+	queryClient.setQueryData(queryKeys.auth.currentUser(), user);
+	// Mark that user has an account (for redirect logic after logout)
+	if (typeof localStorage !== 'undefined') {
+		localStorage.setItem('hasAccount', 'true');
+	}
+}
+
 // Helper to check if user is authenticated from query data
 export function isAuthenticated(user: User | null | undefined): boolean {
 	return user !== null && user !== undefined;
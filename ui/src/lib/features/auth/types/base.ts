@@ -14,6 +14,7 @@ import {
 
 // Re-export generated types
 export type LoginRequest = components['schemas']['LoginRequest'];
+export type LdapLoginRequest = components['schemas']['LdapLoginRequest'];
 export type RegisterRequest = components['schemas']['RegisterRequest'];
 export type SetupRequest = components['schemas']['SetupRequest'];
 export type SetupResponse = components['schemas']['SetupResponse'];
@@ -21,6 +22,12 @@ export type DaemonSetupRequest = components['schemas']['DaemonSetupRequest'];
 export type DaemonSetupResponse = components['schemas']['DaemonSetupResponse'];
 export type ForgotPasswordRequest = components['schemas']['ForgotPasswordRequest'];
 export type ResetPasswordRequest = components['schemas']['ResetPasswordRequest'];
+export type LoginResponse = components['schemas']['LoginResponse'];
+export type MfaCredentialType = components['schemas']['MfaCredentialType'];
+export type MfaCredentialSummary = components['schemas']['MfaCredentialSummary'];
+export type MfaStatusResponse = components['schemas']['MfaStatusResponse'];
+export type UserSessionResponse = components['schemas']['UserSessionResponse'];
+export type MfaVerifyRequest = components['schemas']['MfaVerifyRequest'];
 
 // NetworkSetup extended with optional id (assigned after setup API returns network_ids)
 export type NetworkSetup = components['schemas']['NetworkSetup'] & {
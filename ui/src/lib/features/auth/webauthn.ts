@@ -0,0 +1,111 @@
+/**
+ * Browser glue for passkeys. The server sends WebAuthn options with binary fields
+ * encoded as base64url, and expects credentials back in the same encoding.
+ */
+
+type JsonObject = Record<string, unknown>;
+
+function base64UrlToBuffer(value: string): ArrayBuffer {
+	const base64 = value.replace(/-/g, '+').replace(/_/g, '/');
+	const padded = base64 + '='.repeat((4 - (base64.length % 4)) % 4);
+	const binary = atob(padded);
+	const bytes = new Uint8Array(binary.length);
+	for (let i = 0; i < binary.length; i++) {
+		bytes[i] = binary.charCodeAt(i);
+	}
+	return bytes.buffer;
+}
+
+function bufferToBase64Url(buffer: ArrayBuffer | null): string | null {
+	if (!buffer) return null;
+	const bytes = new Uint8Array(buffer);
+	let binary = '';
+	for (const byte of bytes) {
+		binary += String.fromCharCode(byte);
+	}
+	return btoa(binary).replace(/\+/g, '-').replace(/\//g, '_').replace(/=+$/, '');
+}
+
+function decodeCredentialList(list: unknown): PublicKeyCredentialDescriptor[] | undefined {
+	if (!Array.isArray(list)) return undefined;
+	return list.map((c: JsonObject) => ({
+		...(c as unknown as PublicKeyCredentialDescriptor),
+		id: base64UrlToBuffer(c.id as string)
+	}));
+}
+
+export function isPasskeySupported(): boolean {
+	return typeof window !== 'undefined' && typeof window.PublicKeyCredential !== 'undefined';
+}
+
+/**
+ * Run `navigator.credentials.create()` with server registration options
+ */
+export async function createPasskey(options: JsonObject): Promise<JsonObject> {
+	const publicKey = options.publicKey as JsonObject;
+	const user = publicKey.user as JsonObject;
+
+	const credential = (await navigator.credentials.create({
+		publicKey: {
+			...(publicKey as unknown as PublicKeyCredentialCreationOptions),
+			challenge: base64UrlToBuffer(publicKey.challenge as string),
+			user: {
+				...(user as unknown as PublicKeyCredentialUserEntity),
+				id: base64UrlToBuffer(user.id as string)
+			},
+			excludeCredentials: decodeCredentialList(publicKey.excludeCredentials)
+		}
+	})) as PublicKeyCredential | null;
+
+	if (!credential) {
+		throw new Error('Passkey registration was cancelled');
+	}
+
+	const response = credential.response as AuthenticatorAttestationResponse;
+
+	return {
+		id: credential.id,
+		rawId: bufferToBase64Url(credential.rawId),
+		type: credential.type,
+		response: {
+			attestationObject: bufferToBase64Url(response.attestationObject),
+			clientDataJSON: bufferToBase64Url(response.clientDataJSON),
+			transports: response.getTransports?.() ?? []
+		},
+		extensions: credential.getClientExtensionResults()
+	};
+}
+
+/**
+ * Run `navigator.credentials.get()` with server authentication options
+ */
+export async function getPasskey(options: JsonObject): Promise<JsonObject> {
+	const publicKey = options.publicKey as JsonObject;
+
+	const credential = (await navigator.credentials.get({
+		publicKey: {
+			...(publicKey as unknown as PublicKeyCredentialRequestOptions),
+			challenge: base64UrlToBuffer(publicKey.challenge as string),
+			allowCredentials: decodeCredentialList(publicKey.allowCredentials)
+		}
+	})) as PublicKeyCredential | null;
+
+	if (!credential) {
+		throw new Error('Passkey sign-in was cancelled');
+	}
+
+	const response = credential.response as AuthenticatorAssertionResponse;
+
+	return {
+		id: credential.id,
+		rawId: bufferToBase64Url(credential.rawId),
+		type: credential.type,
+		response: {
+			authenticatorData: bufferToBase64Url(response.authenticatorData),
+			clientDataJSON: bufferToBase64Url(response.clientDataJSON),
+			signature: bufferToBase64Url(response.signature),
+			userHandle: bufferToBase64Url(response.userHandle)
+		},
+		extensions: credential.getClientExtensionResults()
+	};
+}
@@ -0,0 +1,25 @@
+/**
+ * TanStack Query hooks for Credentials
+ */
+
+import { createQuery } from '@tanstack/svelte-query';
+import { queryKeys } from '$lib/api/query-client';
+import { apiClient } from '$lib/api/client';
+
+/**
+ * Query hook for fetching all credentials. Secrets are never returned, only names and types.
+ */
+export function useCredentialsQuery() {
+	return createQuery(() => ({
+		queryKey: queryKeys.credentials.all,
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/v1/credentials', {
+				params: { query: { limit: 0 } }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to fetch credentials');
+			}
+			return data.data;
+		}
+	}));
+}
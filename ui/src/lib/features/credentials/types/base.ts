@@ -0,0 +1,5 @@
+// Re-export generated types from OpenAPI schema
+import type { components } from '$lib/api/schema';
+
+export type Credential = components['schemas']['Credential'];
+export type CredentialType = components['schemas']['CredentialType'];
@@ -189,6 +189,45 @@ export const fieldDefs: FieldDef[] = [
 		section: 'Docker Proxy',
 		validators: []
 	},
+	{
+		id: 'kubeconfig',
+		label: 'Kubeconfig',
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--kubeconfig',
+		envVar: 'SCANOPY_KUBECONFIG',
+		helpText:
+			'Path to kubeconfig for Kubernetes discovery. If not set, uses the in-cluster service account when running in a pod, then KUBECONFIG or ~/.kube/config',
+		placeholder: '/root/.kube/config',
+		section: 'Kubernetes',
+		validators: []
+	},
+	{
+		id: 'npm_credentials',
+		label: 'Nginx Proxy Manager Credentials',
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--npm-credentials',
+		envVar: 'SCANOPY_NPM_CREDENTIALS',
+		helpText:
+			'Nginx Proxy Manager login as email:password, used to read proxy hosts when discovering reverse proxy routes',
+		placeholder: 'admin@example.com:password',
+		section: 'Reverse Proxies',
+		validators: []
+	},
+	{
+		id: 'proxmox_token',
+		label: 'Proxmox API Token',
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--proxmox-token',
+		envVar: 'SCANOPY_PROXMOX_TOKEN',
+		helpText:
+			'Proxmox VE API token as user@realm!tokenid=secret, used by Proxmox discovery to read nodes, VMs and containers',
+		placeholder: 'root@pam!scanopy=00000000-0000-0000-0000-000000000000',
+		section: 'Proxmox',
+		validators: []
+	},
 	{
 		id: 'arp_retries',
 		label: 'Arp Retries',
@@ -142,6 +142,7 @@ export function createEmptyDiscoveryFormData(daemon: Daemon | null): Discovery {
 		created_at: utcTimeZoneSentinel,
 		updated_at: utcTimeZoneSentinel,
 		tags: [],
+		credential_ids: [],
 		discovery_type: {
 			type: 'Network',
 			subnet_ids: daemon ? daemon.capabilities.interfaced_subnet_ids : [],
@@ -9,6 +9,11 @@ export type HostNamingFallback = components['schemas']['HostNamingFallback'];
 export type SelfReportDiscovery = Extract<DiscoveryType, { type: 'SelfReport' }>;
 export type NetworkDiscovery = Extract<DiscoveryType, { type: 'Network' }>;
 export type DockerDiscovery = Extract<DiscoveryType, { type: 'Docker' }>;
+export type KubernetesDiscovery = Extract<DiscoveryType, { type: 'Kubernetes' }>;
+export type ProxmoxDiscovery = Extract<DiscoveryType, { type: 'Proxmox' }>;
+export type SshInventoryDiscovery = Extract<DiscoveryType, { type: 'SshInventory' }>;
+export type WinRmDiscovery = Extract<DiscoveryType, { type: 'WinRm' }>;
+export type TracerouteDiscovery = Extract<DiscoveryType, { type: 'Traceroute' }>;
 
 // Frontend-specific types for WebSocket updates (not from backend API schema)
 export interface DiscoveryUpdatePayload {
@@ -76,6 +76,29 @@ export function useUpdateOrganizationMutation() {
 	}));
 }
 
+/**
+ * Mutation hook for requiring two-factor authentication for password sign-in
+ */
+export function useUpdateMfaPolicyMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async ({ id, require_mfa }: { id: string; require_mfa: boolean }) => {
+			const { data } = await apiClient.PUT('/api/v1/organizations/{id}/mfa-policy', {
+				params: { path: { id } },
+				body: { require_mfa }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to update two-factor policy');
+			}
+			return data.data;
+		},
+		onSuccess: (updatedOrg: Organization) => {
+			queryClient.setQueryData(queryKeys.organizations.current(), updatedOrg);
+		}
+	}));
+}
+
 /**
  * Mutation hook for creating an invite
  */
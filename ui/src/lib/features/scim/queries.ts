@@ -0,0 +1,87 @@
+/**
+ * TanStack Query hooks for SCIM provisioning
+ */
+
+import { createQuery, createMutation, useQueryClient } from '@tanstack/svelte-query';
+import { queryKeys } from '$lib/api/query-client';
+import { apiClient } from '$lib/api/client';
+import type { UpdateScimGroupMappingRequest } from './types';
+
+/**
+ * Query hook for the organization's SCIM token status and provisioned groups
+ */
+export function useScimSettingsQuery() {
+	return createQuery(() => ({
+		queryKey: queryKeys.scim.settings(),
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/v1/scim', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to load SCIM settings');
+			}
+			return data.data;
+		}
+	}));
+}
+
+/**
+ * Mutation hook for generating a SCIM token, replacing any existing one
+ */
+export function useRotateScimTokenMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async () => {
+			const { data } = await apiClient.POST('/api/v1/scim/token', {});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to generate SCIM token');
+			}
+			return data.data;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.scim.settings() });
+		}
+	}));
+}
+
+/**
+ * Mutation hook for revoking the SCIM token
+ */
+export function useRevokeScimTokenMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async () => {
+			const { data } = await apiClient.DELETE('/api/v1/scim/token', {});
+			if (!data?.success) {
+				throw new Error(data?.error || 'Failed to revoke SCIM token');
+			}
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.scim.settings() });
+		}
+	}));
+}
+
+/**
+ * Mutation hook for mapping a SCIM group onto a role and networks
+ */
+export function useUpdateScimGroupMappingMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async ({ id, ...body }: UpdateScimGroupMappingRequest & { id: string }) => {
+			const { data } = await apiClient.PUT('/api/v1/scim/groups/{id}', {
+				params: { path: { id } },
+				body
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to update group mapping');
+			}
+			return data.data;
+		},
+		onSuccess: () => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.scim.settings() });
+			queryClient.invalidateQueries({ queryKey: queryKeys.users.all });
+		}
+	}));
+}
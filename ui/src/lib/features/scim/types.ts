@@ -0,0 +1,7 @@
+import type { components } from '$lib/api/schema';
+
+// Re-export generated types
+export type ScimGroup = components['schemas']['ScimGroup'];
+export type ScimSettings = components['schemas']['ScimSettingsResponse'];
+export type ScimTokenResponse = components['schemas']['ScimTokenResponse'];
+export type UpdateScimGroupMappingRequest = components['schemas']['UpdateScimGroupMappingRequest'];
@@ -121,6 +121,89 @@ export function useBulkDeleteSharesMutation() {
 	}));
 }
 
+/**
+ * Query hook for a share's access tokens
+ */
+export function useShareTokensQuery(shareId: () => string) {
+	return createQuery(() => ({
+		queryKey: queryKeys.shares.tokens(shareId()),
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/v1/shares/{id}/tokens', {
+				params: { path: { id: shareId() } }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to fetch share tokens');
+			}
+			return data.data;
+		},
+		enabled: () => !!shareId()
+	}));
+}
+
+/**
+ * Mutation hook for creating a share access token. The key is only returned here.
+ */
+export function useCreateShareTokenMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async ({ shareId, name }: { shareId: string; name: string }) => {
+			const { data } = await apiClient.POST('/api/v1/shares/{id}/tokens', {
+				params: { path: { id: shareId } },
+				body: { name }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to create share token');
+			}
+			return data.data;
+		},
+		onSuccess: (_, { shareId }) => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.shares.tokens(shareId) });
+		}
+	}));
+}
+
+/**
+ * Mutation hook for revoking a share access token
+ */
+export function useRevokeShareTokenMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async ({ shareId, tokenId }: { shareId: string; tokenId: string }) => {
+			const { data } = await apiClient.POST('/api/v1/shares/{id}/tokens/{token_id}/revoke', {
+				params: { path: { id: shareId, token_id: tokenId } }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to revoke share token');
+			}
+			return data.data;
+		},
+		onSuccess: (_, { shareId }) => {
+			queryClient.invalidateQueries({ queryKey: queryKeys.shares.tokens(shareId) });
+		}
+	}));
+}
+
+/**
+ * Query hook for a share's view statistics
+ */
+export function useShareViewStatsQuery(shareId: () => string) {
+	return createQuery(() => ({
+		queryKey: queryKeys.shares.views(shareId()),
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/v1/shares/{id}/views', {
+				params: { path: { id: shareId() } }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to fetch share views');
+			}
+			return data.data;
+		},
+		enabled: () => !!shareId()
+	}));
+}
+
 import type { PublicShareMetadata, ShareWithTopology } from './types/base';
 
 // ============================================================================
@@ -226,6 +309,13 @@ export function generateShareUrl(shareId: string): string {
 	return `/share/${shareId}`;
 }
 
+/**
+ * Generate a share URL that opens with an access token instead of the password
+ */
+export function generateShareTokenUrl(shareId: string, token: string): string {
+	return `${generateShareUrl(shareId)}?token=${encodeURIComponent(token)}`;
+}
+
 /**
  * Generate embed URL for a share
  */
@@ -236,6 +326,29 @@ export function generateEmbedUrl(shareId: string): string {
 	return `/share/${shareId}?embed=true`;
 }
 
+/**
+ * Generate a static image URL for a share
+ */
+export function generateShareImageUrl(
+	shareId: string,
+	format: 'svg' | 'png' | 'pdf' = 'png'
+): string {
+	const path = `/api/v1/shares/public/${shareId}/image?format=${format}`;
+	if (typeof window !== 'undefined') {
+		return `${window.location.origin}${path}`;
+	}
+	return path;
+}
+
+/**
+ * Generate the live update stream URL for a share
+ */
+export function generateShareStreamUrl(shareId: string, embed = false): string {
+	return embed
+		? `/api/v1/shares/public/${shareId}/stream?embed=true`
+		: `/api/v1/shares/public/${shareId}/stream`;
+}
+
 /**
  * Generate embed code for a share
  */
@@ -5,8 +5,12 @@ import { utcTimeZoneSentinel, uuidv4Sentinel } from '$lib/shared/utils/formattin
 
 export type Share = components['schemas']['Share'];
 export type ShareOptions = components['schemas']['ShareOptions'];
+export type ShareRedaction = components['schemas']['ShareRedaction'];
 export type CreateUpdateShareRequest = components['schemas']['CreateUpdateShareRequest'];
 export type PublicShareMetadata = components['schemas']['PublicShareMetadata'];
+export type ShareToken = components['schemas']['ShareToken'];
+export type ShareTokenResponse = components['schemas']['ShareTokenResponse'];
+export type ShareViewStats = components['schemas']['ShareViewStats'];
 
 // Frontend-specific type: combines share metadata with topology data
 export interface ShareWithTopology {
@@ -17,7 +21,15 @@ export interface ShareWithTopology {
 export const defaultShareOptions: ShareOptions = {
 	show_inspect_panel: true,
 	show_zoom_controls: true,
-	show_export_button: true
+	show_export_button: true,
+	live_updates: false
+};
+
+export const defaultShareRedaction: ShareRedaction = {
+	hide_ip_addresses: false,
+	hide_mac_addresses: false,
+	hidden_host_tags: [],
+	aliases: {}
 };
 
 export function createEmptyShare(topology_id: string, network_id: string): Share {
@@ -32,6 +44,7 @@ export function createEmptyShare(topology_id: string, network_id: string): Share
 		allowed_domains: null,
 		name: '',
 		is_enabled: true,
-		options: { ...defaultShareOptions }
+		options: { ...defaultShareOptions },
+		redaction: { ...defaultShareRedaction, hidden_host_tags: [], aliases: {} }
 	};
 }
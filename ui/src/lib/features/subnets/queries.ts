@@ -134,6 +134,21 @@ export function isContainerSubnet(subnet: Subnet): boolean {
 	return subnet.cidr === '0.0.0.0/0' && subnet.source.type === 'System';
 }
 
+/**
+ * Check if a CIDR was hidden by a share's redaction (unspecified network, prefix length kept)
+ */
+export function isHiddenCidr(cidr: string): boolean {
+	const [network, prefix] = cidr.split('/');
+	return (network === '0.0.0.0' || network === '::') && prefix !== '0';
+}
+
+/**
+ * Check if an IP address was hidden by a share's redaction
+ */
+export function isHiddenIpAddress(ip: string): boolean {
+	return ip === '0.0.0.0' || ip === '::';
+}
+
 /**
  * Get a subnet by ID from a list of subnets
  */
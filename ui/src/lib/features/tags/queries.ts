@@ -163,7 +163,10 @@ const entityTypeToQueryKeyName: Record<EntityDiscriminants, keyof typeof queryKe
 	GroupBinding: null,
 	EntityTag: null,
 	UserApiKeyNetworkAccess: null,
-	UserNetworkAccess: null
+	UserNetworkAccess: null,
+	UserMfaCredential: null,
+	ScimToken: null,
+	ScimGroup: null
 };
 
 /**
@@ -110,6 +110,15 @@ export function updateConnectedNodes(
 			});
 		}
 
+		if (nodeData.node_type == 'HostNode') {
+			allNodes.forEach((n) => {
+				const nd = n.data as TopologyNode;
+				if (nd.node_type == 'ServiceNode' && nd.parent_id == nodeData.id) {
+					connected.add(nd.id);
+				}
+			});
+		}
+
 		for (const edge of allEdges) {
 			const edgeData = edge.data as TopologyEdge | undefined;
 			if (!edgeData) continue;
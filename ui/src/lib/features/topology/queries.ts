@@ -28,7 +28,17 @@ export const defaultTopologyOptions: TopologyOptions = {
 		hide_vm_title_on_docker_container: false,
 		show_gateway_in_left_zone: true,
 		left_zone_service_categories: ['DNS', 'ReverseProxy'],
-		hide_service_categories: []
+		hide_service_categories: [],
+		layout_mode: 'Subnets',
+		focus_host_id: null,
+		filter: {
+			include_tag_ids: [],
+			exclude_tag_ids: [],
+			subnet_ids: [],
+			subnet_types: [],
+			service_categories: [],
+			search: null
+		}
 	}
 };
 
@@ -73,6 +83,23 @@ export function useTopologyQuery(id: () => string | undefined) {
 	}));
 }
 
+/**
+ * Query hook for the organization topology, built across every network the user can see
+ */
+export function useOrganizationTopologyQuery(options?: { enabled?: () => boolean }) {
+	return createQuery(() => ({
+		queryKey: queryKeys.topology.organization(),
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/v1/topology/organization');
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to fetch organization topology');
+			}
+			return data.data;
+		},
+		enabled: options?.enabled?.() ?? true
+	}));
+}
+
 /**
  * Mutation hook for creating a topology
  */
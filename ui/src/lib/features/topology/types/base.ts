@@ -9,6 +9,8 @@ export type TopologyBase = components['schemas']['TopologyBase'];
 export type TopologyOptions = components['schemas']['TopologyOptions'];
 export type TopologyLocalOptions = components['schemas']['TopologyLocalOptions'];
 export type TopologyRequestOptions = components['schemas']['TopologyRequestOptions'];
+export type TopologyLayoutMode = components['schemas']['TopologyLayoutMode'];
+export type TopologyFilter = components['schemas']['TopologyFilter'];
 export type TopologyEdge = components['schemas']['Edge'];
 export type TopologyNode = components['schemas']['Node'];
 export type EdgeHandle = components['schemas']['EdgeHandle'];
@@ -16,6 +18,12 @@ export type EdgeHandle = components['schemas']['EdgeHandle'];
 // Variant types from Node union
 export type InterfaceNode = Extract<TopologyNode, { node_type: 'InterfaceNode' }>;
 export type SubnetNode = Extract<TopologyNode, { node_type: 'SubnetNode' }>;
+export type HostNode = Extract<TopologyNode, { node_type: 'HostNode' }>;
+export type ServiceNode = Extract<TopologyNode, { node_type: 'ServiceNode' }>;
+export type StubNode = Extract<TopologyNode, { node_type: 'StubNode' }>;
+export type NetworkNode = Extract<TopologyNode, { node_type: 'NetworkNode' }>;
+export type NetworkSubnetNode = Extract<TopologyNode, { node_type: 'NetworkSubnetNode' }>;
+export type NetworkHostNode = Extract<TopologyNode, { node_type: 'NetworkHostNode' }>;
 
 // Frontend-specific render types (not from backend)
 export interface NodeRenderData {
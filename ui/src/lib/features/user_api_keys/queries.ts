@@ -171,6 +171,9 @@ export function createEmptyUserApiKeyFormData(): UserApiKey {
 		user_id: uuidv4Sentinel,
 		organization_id: uuidv4Sentinel,
 		permissions: 'Viewer',
-		network_ids: []
+		network_ids: [],
+		scopes: [],
+		allowed_ips: [],
+		rate_limit_per_minute: null
 	};
 }
@@ -57,6 +57,23 @@ export function useUpdateUserAsAdminMutation() {
 	}));
 }
 
+/**
+ * Mutation hook for signing a user out of all their sessions as admin
+ */
+export function useRevokeUserSessionsMutation() {
+	return createMutation(() => ({
+		mutationFn: async (id: string) => {
+			const { data } = await apiClient.DELETE('/api/v1/users/{id}/sessions', {
+				params: { path: { id } }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to sign out user');
+			}
+			return data.data.revoked;
+		}
+	}));
+}
+
 /**
  * Mutation hook for deleting a user
  */
@@ -12,12 +12,17 @@ export interface OidcProviderMetadata {
 	logo: string;
 }
 
+export interface LdapProviderMetadata {
+	name: string;
+}
+
 export type DeploymentType = 'cloud' | 'commercial' | 'community';
 
 export interface PublicServerConfig {
 	server_port: number;
 	disable_registration: boolean;
 	oidc_providers: OidcProviderMetadata[];
+	ldap: LdapProviderMetadata | null;
 	billing_enabled: boolean;
 	has_integrated_daemon: boolean;
 	has_email_service: boolean;